@@ -9,6 +9,7 @@ use crate::plugins::assets::plugin::AssetsPlugin;
 use crate::plugins::configs::plugin::ConfigsPlugin;
 use crate::plugins::equipment_icons::plugin::EquipmentIconsPlugin;
 use crate::plugins::exports::plugin::ExportsPlugin;
+use crate::plugins::particles::plugin::ParticlesPlugin;
 use crate::plugins::spawn::plugin::SpawnPlugin;
 use crate::plugins::system::plugin::SystemPlugin;
 use crate::plugins::translations::plugin::TranslationsPlugin;
@@ -27,6 +28,7 @@ pub fn run() {
     .plugin(SpawnPlugin::init())
     .plugin(ConfigsPlugin::init())
     .plugin(EquipmentIconsPlugin::init())
+    .plugin(ParticlesPlugin::init())
     .plugin(SystemPlugin::init())
     .plugin(TranslationsPlugin::init())
     .plugin(VisualsPlugin::init())
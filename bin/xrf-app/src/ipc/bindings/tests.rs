@@ -14,6 +14,7 @@ use crate::plugins::assets::plugin::AssetsPlugin;
 use crate::plugins::configs::plugin::ConfigsPlugin;
 use crate::plugins::equipment_icons::plugin::EquipmentIconsPlugin;
 use crate::plugins::exports::plugin::ExportsPlugin;
+use crate::plugins::particles::plugin::ParticlesPlugin;
 use crate::plugins::spawn::plugin::SpawnPlugin;
 use crate::plugins::system::plugin::SystemPlugin;
 use crate::plugins::translations::plugin::TranslationsPlugin;
@@ -30,6 +31,7 @@ fn command_modules<R: tauri::Runtime>() -> Vec<(&'static str, tauri_specta::Buil
     (ConfigsPlugin::NAME, ConfigsPlugin::specta_builder::<R>()),
     (EquipmentIconsPlugin::NAME, EquipmentIconsPlugin::specta_builder::<R>()),
     (ExportsPlugin::NAME, ExportsPlugin::specta_builder::<R>()),
+    (ParticlesPlugin::NAME, ParticlesPlugin::specta_builder::<R>()),
     (SpawnPlugin::NAME, SpawnPlugin::specta_builder::<R>()),
     (SystemPlugin::NAME, SystemPlugin::specta_builder::<R>()),
     (TranslationsPlugin::NAME, TranslationsPlugin::specta_builder::<R>()),
@@ -46,6 +46,21 @@ macro_rules! for_each_tauri_command_domain {
         reopen_sprite => crate::plugins::equipment_icons::commands::reopen_sprite::equipment_icons_reopen_sprite,
         pack_sprite => crate::plugins::equipment_icons::commands::pack_sprite::equipment_icons_pack_sprite,
       }
+      particles => "particles" {
+        close_file => crate::plugins::particles::commands::close_file::particles_close_file,
+        get_effect => crate::plugins::particles::commands::get_effect::particles_get_effect,
+        get_file => crate::plugins::particles::commands::get_file::particles_get_file,
+        get_group => crate::plugins::particles::commands::get_group::particles_get_group,
+        has_file => crate::plugins::particles::commands::has_file::particles_has_file,
+        insert_action => crate::plugins::particles::commands::insert_action::particles_insert_action,
+        open_file => crate::plugins::particles::commands::open_file::particles_open_file,
+        remove_action => crate::plugins::particles::commands::remove_action::particles_remove_action,
+        save_file => crate::plugins::particles::commands::save_file::particles_save_file,
+        update_action => crate::plugins::particles::commands::update_action::particles_update_action,
+        update_effect => crate::plugins::particles::commands::update_effect::particles_update_effect,
+        update_group => crate::plugins::particles::commands::update_group::particles_update_group,
+        verify_file => crate::plugins::particles::commands::verify_file::particles_verify_file,
+      }
       spawn => "spawn" {
         save_unpacked_directory => crate::plugins::spawn::commands::save_unpacked_directory::spawn_save_unpacked_directory,
         close_file => crate::plugins::spawn::commands::close_file::spawn_close_file,
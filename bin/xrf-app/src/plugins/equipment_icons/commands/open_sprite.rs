@@ -24,8 +24,12 @@ pub async fn equipment_icons_open_sprite(
 
   log::info!("Opened equipment dds file");
 
+  // Only icons of the equipment sheet are drawn over its preview, other atlases are separate textures.
   let descriptors: Vec<InventorySpriteDescriptor> =
-    InventorySpriteDescriptor::new_list_from_ltx(&Ltx::read_from_file_full(system_ltx_path).map_err(error_to_string)?);
+    InventorySpriteDescriptor::new_list_from_ltx(&Ltx::read_from_file_full(system_ltx_path).map_err(error_to_string)?)
+      .into_iter()
+      .filter(|descriptor| descriptor.custom_icon.is_none())
+      .collect();
 
   let response = EquipmentSpriteMetadata {
     system_ltx_path: system_ltx_path.into(),
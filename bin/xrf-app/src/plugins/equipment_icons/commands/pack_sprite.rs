@@ -18,7 +18,6 @@ pub async fn equipment_icons_pack_sprite(
     source: source_path.into(),
     output: OutputOptions::default(),
     output_path: output_path.into(),
-    gamedata: None,
     dds_compression_format: ImageFormat::BC3RgbaUnorm,
     is_strict: false,
   };
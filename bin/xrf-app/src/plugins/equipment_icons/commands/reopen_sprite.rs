@@ -34,8 +34,12 @@ pub async fn equipment_icons_reopen_sprite(
     .and_then(|dds| dds.to_png())
     .map_err(|error| format!("Failed to open provided image file: {}", error))?;
 
+  // Only icons of the equipment sheet are drawn over its preview, other atlases are separate textures.
   let descriptors: Vec<InventorySpriteDescriptor> =
-    InventorySpriteDescriptor::new_list_from_ltx(&Ltx::read_from_file_full(ltx_path).map_err(error_to_string)?);
+    InventorySpriteDescriptor::new_list_from_ltx(&Ltx::read_from_file_full(ltx_path).map_err(error_to_string)?)
+      .into_iter()
+      .filter(|descriptor| descriptor.custom_icon.is_none())
+      .collect();
 
   let response = EquipmentSpriteMetadata {
     system_ltx_path: ltx_path.into(),
@@ -9,6 +9,7 @@ pub mod assets;
 pub mod configs;
 pub mod equipment_icons;
 pub mod exports;
+pub mod particles;
 pub mod spawn;
 pub mod system;
 pub mod translations;
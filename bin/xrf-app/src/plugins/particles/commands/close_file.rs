@@ -0,0 +1,23 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::ParticlesFile;
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "close_file"))]
+#[tauri::command(rename = "close_file")]
+pub fn particles_close_file(state: State<'_, ParticlesFileState>) -> TauriResult {
+  log::info!("Closing particles file");
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+
+  if lock.is_some() {
+    *lock = None;
+  }
+
+  *state.path.lock().unwrap() = None;
+
+  Ok(())
+}
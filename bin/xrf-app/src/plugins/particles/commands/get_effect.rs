@@ -0,0 +1,21 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleEffect, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "get_effect"))]
+#[tauri::command(rename = "get_effect")]
+pub async fn particles_get_effect(name: &str, state: State<'_, ParticlesFileState>) -> TauriResult<ParticleEffect> {
+  log::debug!("Getting particle effect: {}", name);
+
+  let lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &ParticlesFile = lock.as_ref().ok_or("No particles file open")?;
+
+  file
+    .find_effect(name)
+    .cloned()
+    .ok_or_else(|| format!("Particle effect '{name}' is not in the open file"))
+}
@@ -0,0 +1,22 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::ParticlesFile;
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::{ParticlesFileState, ParticlesFileSummary};
+
+/// Names of effects and groups of the open particles file, or null when nothing is open.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "get_file"))]
+#[tauri::command(rename = "get_file")]
+pub async fn particles_get_file(state: State<'_, ParticlesFileState>) -> TauriResult<Option<ParticlesFileSummary>> {
+  log::debug!("Getting particles file");
+
+  let lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+
+  Ok(
+    lock
+      .as_ref()
+      .map(|file| ParticlesFileSummary::new(file, state.path.lock().unwrap().clone())),
+  )
+}
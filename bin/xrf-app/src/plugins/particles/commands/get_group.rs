@@ -0,0 +1,21 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleGroup, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "get_group"))]
+#[tauri::command(rename = "get_group")]
+pub async fn particles_get_group(name: &str, state: State<'_, ParticlesFileState>) -> TauriResult<ParticleGroup> {
+  log::debug!("Getting particle group: {}", name);
+
+  let lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &ParticlesFile = lock.as_ref().ok_or("No particles file open")?;
+
+  file
+    .find_group(name)
+    .cloned()
+    .ok_or_else(|| format!("Particle group '{name}' is not in the open file"))
+}
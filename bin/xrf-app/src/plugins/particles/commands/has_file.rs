@@ -0,0 +1,12 @@
+use tauri::State;
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "has_file"))]
+#[tauri::command(rename = "has_file")]
+pub fn particles_has_file(state: State<'_, ParticlesFileState>) -> TauriResult<bool> {
+  log::debug!("Checking particles file presence");
+
+  Ok(state.file.lock().unwrap().is_some())
+}
@@ -0,0 +1,31 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleAction, ParticleEffect, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Insert an action into a particle effect before provided index, or append it when the index is the action count.
+///
+/// Undone with `remove_action` at the same index.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "insert_action"))]
+#[tauri::command(rename = "insert_action")]
+pub async fn particles_insert_action(
+  effect: &str,
+  index: u32,
+  action: ParticleAction,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult {
+  log::info!("Inserting action {} into particle effect: {}", index, effect);
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &mut ParticlesFile = lock.as_mut().ok_or("No particles file open")?;
+  let particle: &mut ParticleEffect = file
+    .find_effect_mut(effect)
+    .ok_or_else(|| format!("Particle effect '{effect}' is not in the open file"))?;
+
+  particle
+    .insert_action(index as usize, action)
+    .map_err(|error| error.to_string())
+}
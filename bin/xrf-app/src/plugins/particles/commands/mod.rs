@@ -0,0 +1,13 @@
+pub mod close_file;
+pub mod get_effect;
+pub mod get_file;
+pub mod get_group;
+pub mod has_file;
+pub mod insert_action;
+pub mod open_file;
+pub mod remove_action;
+pub mod save_file;
+pub mod update_action;
+pub mod update_effect;
+pub mod update_group;
+pub mod verify_file;
@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use xrf_db::{ParticlesFile, XRayByteOrder};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::{ParticlesFileState, ParticlesFileSummary};
+
+/// Read a packed particles file into the application session.
+///
+/// Answers with the names of effects and groups rather than the whole file: the editor reads them one at a time, so
+/// serialising every action here only to have it re-requested is waste.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "open_file"))]
+#[tauri::command(rename = "open_file")]
+pub async fn particles_open_file(
+  path: &str,
+  state: tauri::State<'_, ParticlesFileState>,
+) -> TauriResult<ParticlesFileSummary> {
+  log::info!("Opening particles file");
+
+  match ParticlesFile::read_from_path::<XRayByteOrder, _>(&Path::new(path)) {
+    Ok(file) => {
+      log::info!("Opened particles file");
+
+      let summary: ParticlesFileSummary = ParticlesFileSummary::new(&file, Some(String::from(path)));
+
+      *state.file.lock().unwrap() = Some(file);
+      *state.path.lock().unwrap() = Some(String::from(path));
+
+      Ok(summary)
+    }
+    Err(error) => Err(format!("Failed to open provided particles file: {}", error)),
+  }
+}
@@ -0,0 +1,30 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleAction, ParticleEffect, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Remove an action from a particle effect and answer with it.
+///
+/// Undone with `insert_action` of the returned action at the same index.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "remove_action"))]
+#[tauri::command(rename = "remove_action")]
+pub async fn particles_remove_action(
+  effect: &str,
+  index: u32,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult<ParticleAction> {
+  log::info!("Removing action {} from particle effect: {}", index, effect);
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &mut ParticlesFile = lock.as_mut().ok_or("No particles file open")?;
+  let particle: &mut ParticleEffect = file
+    .find_effect_mut(effect)
+    .ok_or_else(|| format!("Particle effect '{effect}' is not in the open file"))?;
+
+  particle
+    .remove_action(index as usize)
+    .map_err(|error| error.to_string())
+}
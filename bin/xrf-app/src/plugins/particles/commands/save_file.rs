@@ -0,0 +1,32 @@
+use std::path::Path;
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticlesFile, XRayByteOrder};
+
+use crate::core::error::error_to_string;
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Write the open particles file, edits included, to provided path.
+///
+/// The saved path becomes the one the session reports, same as a "save as" would.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "save_file"))]
+#[tauri::command(rename = "save_file")]
+pub fn particles_save_file(path: &str, state: State<'_, ParticlesFileState>) -> TauriResult {
+  log::info!("Saving particles file");
+
+  let lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+
+  if let Some(file) = lock.as_ref() {
+    file
+      .write_to_path::<XRayByteOrder, _>(&Path::new(path))
+      .map_err(error_to_string)?;
+
+    *state.path.lock().unwrap() = Some(String::from(path));
+
+    Ok(())
+  } else {
+    Err(String::from("No particles file open for saving"))
+  }
+}
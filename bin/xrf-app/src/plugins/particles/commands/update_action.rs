@@ -0,0 +1,32 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleAction, ParticleEffect, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Replace one action of a particle effect and answer with the action it replaced.
+///
+/// Editing an action alone keeps every change small enough to undo on its own: the editor sends the returned action
+/// back to the same index rather than resubmitting the whole effect.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "update_action"))]
+#[tauri::command(rename = "update_action")]
+pub async fn particles_update_action(
+  effect: &str,
+  index: u32,
+  action: ParticleAction,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult<ParticleAction> {
+  log::info!("Updating action {} of particle effect: {}", index, effect);
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &mut ParticlesFile = lock.as_mut().ok_or("No particles file open")?;
+  let particle: &mut ParticleEffect = file
+    .find_effect_mut(effect)
+    .ok_or_else(|| format!("Particle effect '{effect}' is not in the open file"))?;
+
+  particle
+    .update_action(index as usize, action)
+    .map_err(|error| error.to_string())
+}
@@ -0,0 +1,27 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleEffect, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Replace the named particle effect and answer with what it was before.
+///
+/// The previous effect travels back so the editor can undo by sending it with the new name. Renaming is allowed as
+/// long as no other effect holds the name and no group plays it already, and groups playing the effect follow it to
+/// the new name.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "update_effect"))]
+#[tauri::command(rename = "update_effect")]
+pub async fn particles_update_effect(
+  name: &str,
+  effect: ParticleEffect,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult<ParticleEffect> {
+  log::info!("Updating particle effect: {}", name);
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &mut ParticlesFile = lock.as_mut().ok_or("No particles file open")?;
+
+  file.update_effect(name, effect).map_err(|error| error.to_string())
+}
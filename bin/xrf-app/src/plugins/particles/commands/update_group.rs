@@ -0,0 +1,26 @@
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::{ParticleGroup, ParticlesFile};
+
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::ParticlesFileState;
+
+/// Replace the named particle group and answer with what it was before.
+///
+/// Mirrors `update_effect`: the previous group is what the editor sends back to undo, and a rename must not collide with
+/// another group.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "update_group"))]
+#[tauri::command(rename = "update_group")]
+pub async fn particles_update_group(
+  name: &str,
+  group: ParticleGroup,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult<ParticleGroup> {
+  log::info!("Updating particle group: {}", name);
+
+  let mut lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &mut ParticlesFile = lock.as_mut().ok_or("No particles file open")?;
+
+  file.update_group(name, group).map_err(|error| error.to_string())
+}
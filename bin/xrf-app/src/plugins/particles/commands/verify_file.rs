@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use std::sync::MutexGuard;
+
+use tauri::State;
+use xrf_db::ParticlesFile;
+use xrf_gamedata::{Finding, GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions};
+
+use crate::core::error::error_to_string;
+use crate::core::types::TauriResult;
+use crate::plugins::particles::state::{ParticlesFileState, ParticlesVerificationFinding};
+
+/// Check the open particles file, edits included, against provided gamedata root.
+///
+/// Runs the same particles verifier as gamedata verification, so the editor reports what a full check would without
+/// saving first. The file is checked as if it were the project's own particles library.
+#[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "verify_file"))]
+#[tauri::command(rename = "verify_file")]
+pub async fn particles_verify_file(
+  gamedata_path: &str,
+  state: State<'_, ParticlesFileState>,
+) -> TauriResult<Vec<ParticlesVerificationFinding>> {
+  log::info!("Verifying particles file against gamedata: {}", gamedata_path);
+
+  let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+    root: PathBuf::from(gamedata_path),
+    ..Default::default()
+  })
+  .map_err(error_to_string)?;
+
+  let lock: MutexGuard<Option<ParticlesFile>> = state.file.lock().unwrap();
+  let file: &ParticlesFile = lock.as_ref().ok_or("No particles file open for verification")?;
+  let path: String = state
+    .path
+    .lock()
+    .unwrap()
+    .clone()
+    .unwrap_or_else(|| String::from("particles.xr"));
+
+  let findings: Vec<Finding> = project.verify_particle(&GamedataProjectVerifyOptions::default(), file, &path);
+
+  Ok(
+    findings
+      .iter()
+      .map(|finding| ParticlesVerificationFinding {
+        rule: String::from(finding.rule_id().as_str()),
+        subject: finding.subject().map(String::from),
+        message: String::from(finding.message()),
+      })
+      .collect(),
+  )
+}
@@ -0,0 +1,6 @@
+pub mod commands;
+pub mod plugin;
+pub mod state;
+
+#[cfg(test)]
+mod tests;
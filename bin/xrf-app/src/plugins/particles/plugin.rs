@@ -0,0 +1,29 @@
+use tauri::plugin::TauriPlugin;
+use tauri::{Manager, Runtime};
+
+use crate::plugins::particles::state::ParticlesFileState;
+
+pub struct ParticlesPlugin {}
+
+impl ParticlesPlugin {
+  pub const NAME: &'static str = crate::ipc::registry::particles::NAME;
+
+  pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    tauri::plugin::Builder::new(Self::NAME)
+      .setup(|application, _| {
+        application.manage(ParticlesFileState::new());
+
+        Ok(())
+      })
+      .invoke_handler(crate::core::logging::warn_on_unhandled_command(
+        Self::NAME,
+        crate::ipc::registry::particles::handler(),
+      ))
+      .build()
+  }
+
+  #[cfg(feature = "typescript-bindings")]
+  pub(crate) fn specta_builder<R: Runtime>() -> tauri_specta::Builder<R> {
+    crate::ipc::registry::particles::specta_builder()
+  }
+}
@@ -0,0 +1,55 @@
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use xrf_db::ParticlesFile;
+
+pub struct ParticlesFileState {
+  pub file: Arc<Mutex<Option<ParticlesFile>>>,
+  /// Where the open file came from.
+  pub path: Arc<Mutex<Option<String>>>,
+}
+
+impl ParticlesFileState {
+  pub fn new() -> Self {
+    Self {
+      file: Arc::new(Mutex::new(None)),
+      path: Arc::new(Mutex::new(None)),
+    }
+  }
+}
+
+/// What the open particles file holds, listed by name.
+///
+/// Effects and groups are requested one at a time by the editor, so the listing is all that travels on open: a
+/// stock particles.xr serialises to megabytes of actions nobody is looking at yet.
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticlesFileSummary {
+  pub path: Option<String>,
+  pub version: u16,
+  pub effects: Vec<String>,
+  pub groups: Vec<String>,
+}
+
+impl ParticlesFileSummary {
+  pub fn new(file: &ParticlesFile, path: Option<String>) -> Self {
+    Self {
+      path,
+      version: file.header.version,
+      effects: file.effects.effects.iter().map(|effect| effect.name.clone()).collect(),
+      groups: file.groups.groups.iter().map(|group| group.name.clone()).collect(),
+    }
+  }
+}
+
+/// Problem the gamedata particles verifier found in the open file.
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticlesVerificationFinding {
+  pub rule: String,
+  /// Asset the finding is about, such as a missing texture.
+  pub subject: Option<String>,
+  pub message: String,
+}
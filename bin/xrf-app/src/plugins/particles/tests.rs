@@ -0,0 +1,124 @@
+use tauri::async_runtime::block_on;
+use tauri::test::{MockRuntime, mock_app};
+use tauri::{App, Manager};
+use xrf_db::{
+  ParticleEffect, ParticleEffectSprite, ParticleGroup, ParticleGroupEffect, ParticlesEffectsChunk, ParticlesFile,
+  ParticlesGroupsChunk, ParticlesHeaderChunk,
+};
+
+use crate::plugins::particles::commands::get_file::particles_get_file;
+use crate::plugins::particles::commands::get_group::particles_get_group;
+use crate::plugins::particles::commands::update_effect::particles_update_effect;
+use crate::plugins::particles::state::{ParticlesFileState, ParticlesFileSummary};
+
+fn effect(name: &str) -> ParticleEffect {
+  ParticleEffect {
+    version: 1,
+    name: String::from(name),
+    max_particles: 5,
+    actions: Vec::new(),
+    flags: 0,
+    frame: None,
+    sprite: ParticleEffectSprite {
+      shader_name: String::from("particles\\add"),
+      texture_name: String::from("fx\\fx_spark"),
+    },
+    time_limit: None,
+    collision: None,
+    velocity_scale: None,
+    description: None,
+    rotation: None,
+    editor_data: None,
+  }
+}
+
+/// Application with a particles file open, `fx\spark` played by `explosions\grenade` that also names a missing effect.
+fn application() -> App<MockRuntime> {
+  let application: App<MockRuntime> = mock_app();
+  let state: ParticlesFileState = ParticlesFileState::new();
+
+  *state.file.lock().unwrap() = Some(ParticlesFile {
+    header: ParticlesHeaderChunk { version: 1 },
+    effects: ParticlesEffectsChunk {
+      effects: vec![effect("fx\\spark"), effect("fx\\smoke")],
+    },
+    groups: ParticlesGroupsChunk {
+      groups: vec![ParticleGroup {
+        version: 3,
+        name: String::from("explosions\\grenade"),
+        flags: 0,
+        time_limit: 0.0,
+        effects: vec![ParticleGroupEffect {
+          name: String::from("fx\\spark"),
+          on_play_child_name: String::from("fx\\fire"),
+          on_birth_child_name: String::from("fx\\smoke"),
+          on_dead_child_name: String::new(),
+          time_0: 0.0,
+          time_1: 1.0,
+          flags: 0,
+        }],
+        description: None,
+        effects_old: None,
+      }],
+    },
+  });
+
+  application.manage(state);
+
+  application
+}
+
+fn played_effects(application: &App<MockRuntime>) -> Vec<String> {
+  block_on(particles_get_group("explosions\\grenade", application.state()))
+    .expect("expect group to be read")
+    .effects
+    .into_iter()
+    .map(|effect| effect.name)
+    .collect()
+}
+
+#[test]
+fn renames_effects_with_their_groups_and_undoes_the_rename() {
+  let application: App<MockRuntime> = application();
+
+  let previous: ParticleEffect = block_on(particles_update_effect(
+    "fx\\spark",
+    effect("fx\\sparks"),
+    application.state(),
+  ))
+  .expect("expect effect to be renamed");
+
+  let summary: ParticlesFileSummary = block_on(particles_get_file(application.state()))
+    .expect("expect file to be listed")
+    .expect("expect file to be open");
+
+  assert_eq!(summary.effects, ["fx\\sparks", "fx\\smoke"]);
+  assert_eq!(played_effects(&application), ["fx\\sparks"]);
+
+  block_on(particles_update_effect("fx\\sparks", previous, application.state())).expect("expect rename to be undone");
+
+  assert_eq!(played_effects(&application), ["fx\\spark"]);
+}
+
+#[test]
+fn refuses_renames_to_names_groups_already_play() {
+  let application: App<MockRuntime> = application();
+
+  assert!(
+    block_on(particles_update_effect(
+      "fx\\spark",
+      effect("fx\\fire"),
+      application.state()
+    ))
+    .is_err()
+  );
+  assert!(
+    block_on(particles_update_effect(
+      "fx\\spark",
+      effect("fx\\smoke"),
+      application.state()
+    ))
+    .is_err()
+  );
+  assert_eq!(played_effects(&application), ["fx\\spark"]);
+}
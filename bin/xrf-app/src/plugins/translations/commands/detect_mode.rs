@@ -3,12 +3,15 @@ use std::path::Path;
 use xrf_translation::{TranslationProjectMode, detect_mode};
 
 use crate::core::types::TauriResult;
+use crate::plugins::translations::languages::read_languages;
 
 /// Report which layout a directory looks like, for the open form to preselect.
+///
+/// `languages` is the path of a JSON language configuration adding to or replacing the shipped ones.
 #[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "detect_mode"))]
 #[tauri::command(rename = "detect_mode")]
-pub async fn translations_detect_mode(path: &str) -> TauriResult<TranslationProjectMode> {
-  let mode: TranslationProjectMode = detect_mode(Path::new(path));
+pub async fn translations_detect_mode(path: &str, languages: Option<String>) -> TauriResult<TranslationProjectMode> {
+  let mode: TranslationProjectMode = detect_mode(Path::new(path), &read_languages(languages.as_deref())?);
 
   log::info!("Detected translations layout at {}: {:?}", path, mode);
 
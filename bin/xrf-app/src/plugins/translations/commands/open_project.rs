@@ -1,28 +1,38 @@
 use tauri::State;
-use xrf_translation::{TranslationProjectDescriptor, TranslationProjectMode, read_gamedata, read_source};
+use xrf_translation::{
+  TranslationLanguages, TranslationProjectDescriptor, TranslationProjectMode, read_gamedata, read_source,
+};
 
 use crate::core::error::error_to_string;
 use crate::core::types::TauriResult;
+use crate::plugins::translations::languages::read_languages;
 use crate::plugins::translations::state::TranslationProjectState;
 
+/// Read a translations root in the given layout.
+///
+/// `languages` is the path of a JSON language configuration adding to or replacing the shipped ones,
+/// kept for the project so later saves resolve encodings the same way.
 #[cfg_attr(feature = "typescript-bindings", specta::specta(rename = "open_project"))]
 #[tauri::command(rename = "open_project")]
 pub async fn translations_open_project(
   path: &str,
   mode: TranslationProjectMode,
+  languages: Option<String>,
   state: State<'_, TranslationProjectState>,
 ) -> TauriResult<TranslationProjectDescriptor> {
   log::info!("Opening translations project: {} ({:?})", path, mode);
 
   // The caller's mode is obeyed, not re-derived: the two layouts save to different files, so a guess
   // acted on here would decide what a later save overwrites.
+  let languages: TranslationLanguages = read_languages(languages.as_deref())?;
   let descriptor: TranslationProjectDescriptor = match mode {
-    TranslationProjectMode::Source => read_source(path),
-    TranslationProjectMode::Gamedata => read_gamedata(path),
+    TranslationProjectMode::Source => read_source(path, &languages),
+    TranslationProjectMode::Gamedata => read_gamedata(path, &languages),
   }
   .map_err(error_to_string)?;
 
   *state.project.lock().unwrap() = Some(descriptor.clone());
+  *state.languages.lock().unwrap() = languages;
 
   Ok(descriptor)
 }
@@ -3,8 +3,8 @@ use std::path::Path;
 
 use tauri::State;
 use xrf_translation::{
-  TranslationEdit, TranslationFile, TranslationProjectDescriptor, TranslationProjectMode, apply_edits, read_gamedata,
-  read_source,
+  TranslationEdit, TranslationFile, TranslationLanguages, TranslationProjectDescriptor, TranslationProjectMode,
+  apply_edits, read_gamedata, read_source,
 };
 
 use crate::core::error::error_to_string;
@@ -36,6 +36,8 @@ pub async fn translations_save_file(
     (descriptor.root.clone(), descriptor.mode, entry.sources.clone())
   };
 
+  let languages: TranslationLanguages = state.languages.lock().unwrap().clone();
+
   for (language, language_edits) in &edits {
     if language_edits.is_empty() {
       continue;
@@ -47,14 +49,14 @@ pub async fn translations_save_file(
 
     log::info!("Saving {} edits to {} ({})", language_edits.len(), path, language);
 
-    apply_edits(Path::new(path), language, language_edits).map_err(error_to_string)?;
+    apply_edits(Path::new(path), language, language_edits, &languages).map_err(error_to_string)?;
   }
 
   // Re-read rather than patch the cached copy: what is on disk now is the only version worth showing,
   // and a write can add or drop entries the caller did not predict.
   let refreshed: TranslationProjectDescriptor = match mode {
-    TranslationProjectMode::Source => read_source(&root),
-    TranslationProjectMode::Gamedata => read_gamedata(&root),
+    TranslationProjectMode::Source => read_source(&root, &languages),
+    TranslationProjectMode::Gamedata => read_gamedata(&root, &languages),
   }
   .map_err(error_to_string)?;
 
@@ -0,0 +1,14 @@
+use std::path::Path;
+
+use xrf_translation::TranslationLanguages;
+
+use crate::core::error::error_to_string;
+use crate::core::types::TauriResult;
+
+/// Languages a command works with: the shipped ones, overridden by the configuration at `path` when given.
+pub fn read_languages(path: Option<&str>) -> TauriResult<TranslationLanguages> {
+  match path {
+    Some(path) => TranslationLanguages::read(Path::new(path)).map_err(error_to_string),
+    None => Ok(TranslationLanguages::default()),
+  }
+}
@@ -1,3 +1,4 @@
 pub mod commands;
+pub mod languages;
 pub mod plugin;
 pub mod state;
@@ -1,16 +1,19 @@
 use std::sync::{Arc, Mutex};
 
-use xrf_translation::TranslationProjectDescriptor;
+use xrf_translation::{TranslationLanguages, TranslationProjectDescriptor};
 
 /// The open translations root.
 pub struct TranslationProjectState {
   pub project: Arc<Mutex<Option<TranslationProjectDescriptor>>>,
+  /// Languages the open project was read with, so saves resolve encodings the same way.
+  pub languages: Arc<Mutex<TranslationLanguages>>,
 }
 
 impl TranslationProjectState {
   pub fn new() -> Self {
     Self {
       project: Arc::new(Mutex::new(None)),
+      languages: Arc::new(Mutex::new(TranslationLanguages::default())),
     }
   }
 }
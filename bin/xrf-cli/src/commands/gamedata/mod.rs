@@ -1 +1,14 @@
+pub(crate) mod texture_usage;
 pub(crate) mod verify_gamedata;
+
+/// Entries of a gamedata repository that are not gamedata, ignored unless the caller lists its own.
+pub(crate) const DEFAULT_IGNORED_ENTRIES: [&str; 8] = [
+  ".git",
+  ".idea",
+  "particles_unpacked",
+  "textures_unpacked",
+  ".gitignore",
+  ".gitattributes",
+  "README.md",
+  "LICENSE",
+];
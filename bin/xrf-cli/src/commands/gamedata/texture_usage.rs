@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::XrfError;
+use xrf_gamedata::{
+  GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions, GamedataTextureReferrer,
+  GamedataTextureUsageResult,
+};
+use xrf_output::OutputOptions;
+
+use crate::commands::gamedata::DEFAULT_IGNORED_ENTRIES;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct TextureUsageCommand;
+
+impl GenericCommand for TextureUsageCommand {
+  fn name(&self) -> &'static str {
+    "texture-usage"
+  }
+
+  /// Create command to report texture usage of gamedata.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to report textures nothing references and assets using provided textures")
+      .arg(
+        Arg::new("root")
+          .help("Path to assembled gamedata root")
+          .required(true)
+          .value_name("ROOT")
+          .num_args(1)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("texture")
+          .help("Texture reference or logical path to list users of, instead of listing orphans")
+          .short('t')
+          .long("texture")
+          .required(false)
+          .num_args(1..)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("ignore")
+          .help("Ignored assets in the gamedata root")
+          .short('i')
+          .long("ignore")
+          .required(false)
+          .value_delimiter(',')
+          .num_args(1..=10)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Turn off logging")
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Collect texture usage of gamedata and print orphans or users of requested textures.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let root: PathBuf = matches
+      .get_one::<PathBuf>("root")
+      .expect("Expected a valid gamedata root to be provided")
+      .clone();
+
+    let textures: Vec<String> = matches
+      .get_many::<String>("texture")
+      .map(|it| it.cloned().collect::<Vec<String>>())
+      .unwrap_or_default();
+
+    let ignored: Vec<String> = matches
+      .get_many::<String>("ignore")
+      .map(|it| it.cloned().collect::<Vec<String>>())
+      .unwrap_or_else(|| DEFAULT_IGNORED_ENTRIES.map(String::from).to_vec());
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let project: Box<GamedataProject> = Box::new(GamedataProject::open(&GamedataProjectReadOptions {
+      root,
+      ignored,
+      output: output.clone(),
+      is_strict: false,
+    })?);
+
+    let usage: GamedataTextureUsageResult = project.collect_texture_usage(&GamedataProjectVerifyOptions {
+      output: output.clone(),
+      ..Default::default()
+    })?;
+
+    if textures.is_empty() {
+      let orphans: Vec<&str> = usage.get_orphan_textures();
+
+      xrf_output::heading!(output, "Orphan textures:");
+
+      for texture in &orphans {
+        xrf_output::info!(output, "- {texture}");
+      }
+
+      xrf_output::info!(
+        output,
+        "{} of {} textures are not reached by any mesh, level, config, script or particle",
+        orphans.len(),
+        usage.textures.len()
+      );
+
+      return Ok(());
+    }
+
+    for reference in &textures {
+      let Some(texture) = usage.find_texture(reference) else {
+        return Err(XrfError::new_not_found_error(format!("Texture '{reference}' is not in gamedata")).into());
+      };
+
+      xrf_output::heading!(output, "Users of {texture}:");
+
+      let referrers: Vec<&GamedataTextureReferrer> = usage.get_referrers(texture);
+
+      if referrers.is_empty() {
+        xrf_output::warning!(output, "Nothing references the texture");
+      }
+
+      for referrer in referrers {
+        match referrer {
+          GamedataTextureReferrer::Asset(path) => xrf_output::info!(output, "- {path}"),
+          GamedataTextureReferrer::Texture(path) => xrf_output::info!(output, "- {path} (texture)"),
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
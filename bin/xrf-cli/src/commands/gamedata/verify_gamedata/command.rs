@@ -9,6 +9,8 @@ use xrf_gamedata::{
 use xrf_output::OutputOptions;
 
 use super::verification_report::GamedataVerificationReportWriter;
+use crate::commands::gamedata::DEFAULT_IGNORED_ENTRIES;
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
 use crate::core::command_error::CommandError;
 use crate::core::generic_command::{CommandResult, GenericCommand};
 use crate::core::output::TerminalOutput;
@@ -60,6 +62,7 @@ impl GenericCommand for VerifyGamedataCommand {
           .num_args(1..)
           .value_parser(value_parser!(GamedataVerificationType)),
       )
+      .arg(languages_arg())
       .arg(
         Arg::new("silent")
           .help("Turn off logging")
@@ -77,7 +80,7 @@ impl GenericCommand for VerifyGamedataCommand {
       )
       .arg(
         Arg::new("strict")
-          .help("Fully validate expensive asset payloads")
+          .help("Fully validate expensive asset payloads and compile renderer shaders")
           .short('s')
           .long("strict")
           .required(false)
@@ -96,18 +99,7 @@ impl GenericCommand for VerifyGamedataCommand {
     let ignored: Vec<String> = matches
       .get_many::<String>("ignore")
       .map(|it| it.cloned().collect::<Vec<String>>())
-      .unwrap_or_else(|| {
-        vec![
-          String::from(".git"),
-          String::from(".idea"),
-          String::from("particles_unpacked"),
-          String::from("textures_unpacked"),
-          String::from(".gitignore"),
-          String::from(".gitattributes"),
-          String::from("README.md"),
-          String::from("LICENSE"),
-        ]
-      });
+      .unwrap_or_else(|| DEFAULT_IGNORED_ENTRIES.map(String::from).to_vec());
 
     let checks: Vec<GamedataVerificationType> = matches
       .get_many::<GamedataVerificationType>("checks")
@@ -128,6 +120,7 @@ impl GenericCommand for VerifyGamedataCommand {
       output,
       is_strict,
       checks,
+      languages: read_languages(matches)?,
     };
 
     xrf_output::heading!(open_options.output, "Opening gamedata project");
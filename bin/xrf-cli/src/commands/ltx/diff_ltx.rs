@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::XrfError;
+use xrf_ltx::{Ltx, LtxDiff, LtxDiffChange, LtxDiffValue, LtxFieldDiff};
+use xrf_output::OutputOptions;
+
+use crate::commands::ltx::ltx_installation::open_config_project;
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct DiffLtxCommand;
+
+impl GenericCommand for DiffLtxCommand {
+  fn name(&self) -> &'static str {
+    "diff-ltx"
+  }
+
+  /// Add command for semantic comparison of ltx files.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to compare ltx configs after resolving includes and inheritance")
+      .arg(
+        Arg::new("old")
+          .help("Old ltx file, folder with ltx files, or game installation root")
+          .long("old")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("new")
+          .help("New ltx file, folder with ltx files, or game installation root")
+          .long("new")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("file")
+          .help("Config to compare when folders are provided, relative to the configs root")
+          .short('f')
+          .long("file")
+          .default_value("system.ltx")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("sources")
+          .help("List the file each compared value comes from")
+          .long("sources")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if configs differ")
+          .long("strict")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Turn off logging")
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Compare ltx files or folders based on provided arguments.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let old: &PathBuf = matches
+      .get_one::<PathBuf>("old")
+      .expect("Expected valid old path to be provided");
+    let new: &PathBuf = matches
+      .get_one::<PathBuf>("new")
+      .expect("Expected valid new path to be provided");
+    let file: &String = matches
+      .get_one::<String>("file")
+      .expect("Expected valid config file to be provided");
+    let is_with_sources: bool = matches.get_flag("sources");
+    let is_strict: bool = matches.get_flag("strict");
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let diff: LtxDiff = if old.is_file() && new.is_file() {
+      let diff: LtxDiff = LtxDiff::between(&Ltx::read_from_file_full(old)?, &Ltx::read_from_file_full(new)?);
+
+      if is_with_sources {
+        diff.with_sources(&Ltx::read_sources_from_file(old)?, &Ltx::read_sources_from_file(new)?)
+      } else {
+        diff
+      }
+    } else if old.is_dir() && new.is_dir() {
+      open_config_project(old, Default::default())?.diff_config(
+        &open_config_project(new, Default::default())?,
+        file,
+        is_with_sources,
+      )?
+    } else {
+      return Err(
+        XrfError::new_read_error("Expected --old and --new to both be ltx files or both be configs directories").into(),
+      );
+    };
+
+    if diff.is_empty() {
+      xrf_output::info!(output, "No differences found");
+
+      return Ok(());
+    }
+
+    for section in &diff.sections {
+      xrf_output::info!(
+        output,
+        "[{}] {}",
+        section.name,
+        match section.change {
+          LtxDiffChange::Added => "added",
+          LtxDiffChange::Removed => "removed",
+          LtxDiffChange::Changed => "changed",
+        }
+      );
+
+      for field in &section.fields {
+        xrf_output::info!(output, "  {}", Self::describe_field(field));
+      }
+    }
+
+    xrf_output::info!(output, "{} section(s) differ", diff.sections.len());
+
+    if is_strict {
+      return Err(CommandError::new_check_failed(diff.sections.len()));
+    }
+
+    Ok(())
+  }
+}
+
+impl DiffLtxCommand {
+  /// One line per field: `~` for a changed value, `+` and `-` for added and removed keys.
+  fn describe_field(field: &LtxFieldDiff) -> String {
+    match (&field.old, &field.new) {
+      (Some(old), Some(new)) => format!(
+        "~ {} = {} -> {}",
+        field.name,
+        Self::describe_value(old),
+        Self::describe_value(new)
+      ),
+      (None, Some(new)) => format!("+ {} = {}", field.name, Self::describe_value(new)),
+      (Some(old), None) => format!("- {} = {}", field.name, Self::describe_value(old)),
+      (None, None) => format!("? {}", field.name),
+    }
+  }
+
+  fn describe_value(value: &LtxDiffValue) -> String {
+    match &value.source {
+      Some(source) => format!("{} ({})", value.value, source.display()),
+      None => value.value.clone(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use super::DiffLtxCommand;
+  use crate::core::command_error::CommandError;
+  use crate::core::generic_command::{CommandResult, GenericCommand};
+
+  fn diff(root: &Path, extra: &[&str]) -> CommandResult {
+    let old: String = root.join("old.ltx").display().to_string();
+    let new: String = root.join("new.ltx").display().to_string();
+    let mut arguments: Vec<&str> = vec!["diff-ltx", "--old", &old, "--new", &new, "--silent"];
+
+    arguments.extend_from_slice(extra);
+
+    DiffLtxCommand.execute(&DiffLtxCommand.init().try_get_matches_from(arguments)?)
+  }
+
+  #[test]
+  fn fails_strict_check_only_when_configs_differ() -> CommandResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-cli-diff-ltx-{}", std::process::id()));
+
+    fs::create_dir_all(&root)?;
+    fs::write(root.join("old.ltx"), "[section]\nvalue = 1\n")?;
+    fs::write(root.join("new.ltx"), "[section]\nvalue = 1\n")?;
+
+    diff(&root, &["--strict"])?;
+
+    fs::write(root.join("new.ltx"), "[section]\nvalue = 2\n")?;
+
+    diff(&root, &[])?;
+
+    assert!(matches!(
+      diff(&root, &["--strict"]),
+      Err(CommandError::CheckFailed { findings: 1 })
+    ));
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
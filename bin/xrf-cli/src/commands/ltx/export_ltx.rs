@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::XrfError;
+use xrf_ltx::{LtxDocument, LtxDocumentFormat, LtxProject, LtxProjectOptions};
+use xrf_vfs::XrayLogicalPath;
+
+use crate::commands::ltx::ltx_installation::open_config_project;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+
+#[derive(Default)]
+pub struct ExportLtxCommand;
+
+impl GenericCommand for ExportLtxCommand {
+  fn name(&self) -> &'static str {
+    "export-ltx"
+  }
+
+  /// Add command for exporting of ltx files as JSON or TOML documents.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to export ltx config as JSON or TOML document")
+      .arg(
+        Arg::new("path")
+          .help("Path to a folder with ltx files, or to a game installation root holding fsgame.ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("file")
+          .help("Config to export, relative to the configs root")
+          .short('f')
+          .long("file")
+          .default_value("system.ltx")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to the exported document, its extension picks the format unless --format is set")
+          .short('o')
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("format")
+          .help("Document format, json or toml")
+          .long("format")
+          .required(false)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("as-written")
+          .help("Export the config as written, keeping includes and parents instead of resolving them")
+          .long("as-written")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Export ltx config based on provided arguments.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid input path to be provided");
+    let file: &String = matches
+      .get_one::<String>("file")
+      .expect("Expected valid config file to be provided");
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output path to be provided");
+
+    if !path.is_dir() {
+      return Err(XrfError::new_read_error("Expected configs root directory path as --path parameter").into());
+    }
+
+    let format: LtxDocumentFormat = match matches.get_one::<String>("format") {
+      Some(format) => LtxDocumentFormat::from_name(format)?,
+      None => LtxDocumentFormat::from_path(destination)?,
+    };
+
+    let project: LtxProject = open_config_project(
+      path,
+      LtxProjectOptions {
+        is_with_schemes_check: true,
+        is_strict_check: false,
+      },
+    )?;
+
+    let config: XrayLogicalPath = project.config_path(file)?;
+    let document: LtxDocument = if matches.get_flag("as-written") {
+      project.export_as_written(&config)?
+    } else {
+      project.export_resolved(&config)?
+    };
+
+    std::fs::write(destination, document.write_to_string(format)?)?;
+
+    log::info!(
+      "Exported {} sections of {} to {}",
+      document.sections.len(),
+      project.path_of(&config).display(),
+      destination.display()
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgMatches, Command, value_parser};
+use xrf_ltx::{Ltx, LtxDocument, LtxDocumentFormat};
+
+use crate::core::generic_command::{CommandResult, GenericCommand};
+
+#[derive(Default)]
+pub struct ImportLtxCommand;
+
+impl GenericCommand for ImportLtxCommand {
+  fn name(&self) -> &'static str {
+    "import-ltx"
+  }
+
+  /// Add command for importing of ltx files from JSON or TOML documents.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to write ltx config from JSON or TOML document")
+      .arg(
+        Arg::new("path")
+          .help("Path to the document exported with export-ltx")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to the ltx file to write")
+          .short('o')
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("format")
+          .help("Document format, json or toml, picked by the document extension when omitted")
+          .long("format")
+          .required(false)
+          .value_parser(value_parser!(String)),
+      )
+  }
+
+  /// Import ltx config based on provided arguments.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid input path to be provided");
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output path to be provided");
+
+    let format: LtxDocumentFormat = match matches.get_one::<String>("format") {
+      Some(format) => LtxDocumentFormat::from_name(format)?,
+      None => LtxDocumentFormat::from_path(path)?,
+    };
+
+    let document: LtxDocument = LtxDocument::read_from_str(&std::fs::read_to_string(path)?, format)?;
+
+    Ltx::from_document(&document).write_to_path(destination)?;
+
+    log::info!(
+      "Imported {} sections from {} to {}",
+      document.sections.len(),
+      path.display(),
+      destination.display()
+    );
+
+    Ok(())
+  }
+}
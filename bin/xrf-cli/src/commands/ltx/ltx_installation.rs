@@ -1,11 +1,15 @@
 use std::path::Path;
 
 use xrf_error::XrfResult;
-use xrf_vfs::{XrayMountMode, XrayVfs};
+use xrf_ltx::{LtxProject, LtxProjectOptions};
+use xrf_vfs::{XrayLookupScope, XrayMountMode, XrayVfs};
+
+/// Directory holding the config tree inside an installation's VFS.
+const CONFIGS_DIRECTORY: &str = "configs";
 
 /// Mounts a game installation's declared sources, or returns `None` when `path` is not one.
 ///
-/// Both LTX commands treat only a path directly holding `fsgame.ltx` as an installation, and share the rule from here so
+/// LTX commands treat only a path directly holding `fsgame.ltx` as an installation, and share the rule from here so
 /// they cannot drift. A named directory stays a directory: widening `--path <install>\gamedata\configs` to the whole game
 /// would read or rewrite thousands of configs nobody asked about.
 ///
@@ -20,3 +24,23 @@ pub fn mount_installation(path: &Path) -> XrfResult<Option<XrayVfs>> {
 
   Ok(Some(XrayVfs::open(XrayMountMode::Installation, path)?))
 }
+
+/// Opens the config tree a path names: the `configs` directory of an installation, or the directory itself.
+///
+/// Commands reading one config by its name in the tree use this, so `system.ltx` means the same file whether the path is a
+/// loose configs directory or a game root with archived configs.
+///
+/// # Errors
+///
+/// Returns an error when the installation cannot be mounted or the project cannot be assembled.
+pub fn open_config_project(path: &Path, options: LtxProjectOptions) -> XrfResult<LtxProject> {
+  match mount_installation(path)? {
+    Some(vfs) => LtxProject::open_at_scope_opt(
+      path.join(CONFIGS_DIRECTORY),
+      vfs,
+      XrayLookupScope::all().with_prefix(CONFIGS_DIRECTORY)?,
+      options,
+    ),
+    None => LtxProject::open_at_path_opt(path, options),
+  }
+}
@@ -1,3 +1,6 @@
+pub(crate) mod diff_ltx;
+pub(crate) mod export_ltx;
 pub(crate) mod format_ltx;
+pub(crate) mod import_ltx;
 pub(crate) mod ltx_installation;
 pub(crate) mod verify_ltx;
@@ -7,6 +7,7 @@ pub(crate) mod ltx;
 pub(crate) mod ogf;
 pub(crate) mod omf;
 pub(crate) mod particle;
+pub(crate) mod sound;
 pub(crate) mod spawn;
 pub(crate) mod texture;
 pub(crate) mod thm;
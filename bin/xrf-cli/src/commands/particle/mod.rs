@@ -2,5 +2,6 @@ pub(crate) mod info_particles;
 pub(crate) mod pack_particles;
 pub(crate) mod re_unpack_particles;
 pub(crate) mod repack_particles;
+pub(crate) mod simulate_particles;
 pub(crate) mod unpack_particles;
 pub(crate) mod verify_particles;
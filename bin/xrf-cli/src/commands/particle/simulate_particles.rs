@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_db::{
+  ParticleEffect, ParticleSimulation, ParticleSimulationOptions, ParticleSimulationResult, ParticlesFile, XRayByteOrder,
+};
+use xrf_error::XrfError;
+use xrf_output::OutputOptions;
+
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct SimulateParticlesCommand;
+
+impl GenericCommand for SimulateParticlesCommand {
+  fn name(&self) -> &'static str {
+    "simulate-particles"
+  }
+
+  /// Create command for simulating particle effect without the game.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to simulate particle effect on the CPU and write its particles per frame as JSON")
+      .arg(
+        Arg::new("path")
+          .help("Path to particle file")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("effect")
+          .help("Name of particle effect to simulate")
+          .short('e')
+          .long("effect")
+          .required(true)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to JSON file with simulated frames")
+          .short('o')
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("duration")
+          .help("Simulated time in seconds, 2 by default")
+          .long("duration")
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("time-step")
+          .help("Seconds advanced by each frame, 1/30 by default")
+          .long("time-step")
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("seed")
+          .help("Seed of the random source, the same seed replays the same run")
+          .long("seed")
+          .value_parser(value_parser!(u64)),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Simulate particle effect and write its frames.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let name: &String = matches
+      .get_one::<String>("effect")
+      .expect("Expected particle effect name to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output path to be provided");
+
+    let defaults: ParticleSimulationOptions = ParticleSimulationOptions::default();
+    let options: ParticleSimulationOptions = ParticleSimulationOptions {
+      duration: matches.get_one::<f32>("duration").copied().unwrap_or(defaults.duration),
+      time_step: matches
+        .get_one::<f32>("time-step")
+        .copied()
+        .unwrap_or(defaults.time_step),
+      seed: matches.get_one::<u64>("seed").copied().unwrap_or(defaults.seed),
+    };
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    xrf_output::info!(output, "Read particle file {}", path.display());
+
+    let particles_file: Box<ParticlesFile> = Box::new(ParticlesFile::read_from_path::<XRayByteOrder, _>(path)?);
+
+    let effect: &ParticleEffect = particles_file
+      .find_effect(name)
+      .ok_or_else(|| XrfError::new_not_found_error(format!("Particle effect '{name}' is not in the particle file")))?;
+
+    let result: ParticleSimulationResult = ParticleSimulation::simulate(effect, &options)?;
+
+    std::fs::write(destination, format!("{}\n", serde_json::to_string_pretty(&result)?))?;
+
+    xrf_output::info!(
+      output,
+      "Simulated {} frames of {}, peak {} of {} particles, {} born and {} died",
+      result.frames.len(),
+      result.effect,
+      result.peak_count,
+      result.max_particles,
+      result.total_born,
+      result.total_died
+    );
+
+    if let Some((min, max)) = result.bounds {
+      xrf_output::info!(output, "Particles stayed within {:?} - {:?}", min, max);
+    }
+
+    for warning in &result.warnings {
+      xrf_output::warning!(output, "{warning}");
+    }
+
+    xrf_output::info!(output, "Written simulation to {}", destination.display());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_output::OutputOptions;
+use xrf_sound::{SoundFile, SoundParametersRow, SoundParametersTable};
+use xrf_utils::ParametersTable;
+use xrf_vfs::{XrayAsset, XrayAssetType, XrayLookupScope, XrayVfs};
+
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+use crate::core::vfs_source::{open_source_vfs, source_arg};
+
+#[derive(Default)]
+pub struct ExportSoundParametersCommand;
+
+impl GenericCommand for ExportSoundParametersCommand {
+  fn name(&self) -> &'static str {
+    "export-sound-parameters"
+  }
+
+  /// Create command for exporting the parameters of every sound into a table.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to export audio properties and X-Ray parameters of every sound into a CSV or JSON table")
+      .arg(
+        Arg::new("path")
+          .help("Path to a game installation or a gamedata tree")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to the resulting table, .csv or .json")
+          .short('o')
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(source_arg())
+      .arg(
+        Arg::new("prefix")
+          .help("Limit to one logical subtree, such as sounds\\weapons")
+          .long("prefix")
+          .default_value("sounds")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if any sound cannot be read")
+          .long("strict")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Read every sound the path resolves and write one table row per readable sound.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let destination: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output path to be provided");
+
+    let prefix: &String = matches.get_one::<String>("prefix").expect("Expected prefix to default");
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let started_at: Instant = Instant::now();
+    let vfs: XrayVfs = open_source_vfs(path, matches)?;
+    let scope: XrayLookupScope = XrayLookupScope::all().with_prefix(prefix)?;
+    let sounds: Vec<XrayAsset> = vfs.scoped(&scope).list_entries_of_type(XrayAssetType::Ogg);
+
+    let mut table: SoundParametersTable = SoundParametersTable::default();
+    let mut unreadable_count: usize = 0;
+
+    for sound in &sounds {
+      let logical_path: &str = sound.get_logical_path().as_str();
+
+      xrf_output::verbose!(output, "Export sound: {logical_path}");
+
+      match vfs
+        .read_asset(sound)
+        .and_then(|bytes| SoundFile::read_from_bytes(&bytes))
+      {
+        Ok(file) => table.rows.push(SoundParametersRow::new(logical_path, &file)),
+        Err(error) => {
+          unreadable_count += 1;
+
+          xrf_output::warning!(output, "Skipped sound {logical_path}: {error}");
+        }
+      }
+    }
+
+    table.write_to_path(destination)?;
+
+    xrf_output::info!(
+      output,
+      "Exported parameters of {} sounds into {} in {}, {} unreadable",
+      table.rows.len(),
+      destination.display(),
+      xrf_utils::format_duration(started_at.elapsed()),
+      unreadable_count
+    );
+
+    if matches.get_flag("strict") && unreadable_count > 0 {
+      return Err(CommandError::new_check_failed(unreadable_count));
+    }
+
+    Ok(())
+  }
+}
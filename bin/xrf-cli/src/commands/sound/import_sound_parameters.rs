@@ -0,0 +1,180 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::{XrfError, XrfResult};
+use xrf_output::OutputOptions;
+use xrf_sound::{SoundFile, SoundMetadata, SoundParametersRow, SoundParametersTable};
+use xrf_utils::ParametersTable;
+use xrf_vfs::{XrayAsset, XrayVfs};
+
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+use crate::core::vfs_source::{asset_write_target, open_source_vfs, source_arg};
+
+/// What applying one row of a table did to its sound.
+enum SoundRowOutcome {
+  Patched,
+  Unchanged,
+}
+
+#[derive(Default)]
+pub struct ImportSoundParametersCommand;
+
+impl GenericCommand for ImportSoundParametersCommand {
+  fn name(&self) -> &'static str {
+    "import-sound-parameters"
+  }
+
+  /// Create command for applying an edited table of sound parameters.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to apply X-Ray parameters of an edited CSV or JSON table back onto the sounds")
+      .arg(
+        Arg::new("path")
+          .help("Path to a game installation or a gamedata tree")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("input")
+          .help("Path to the edited table, .csv or .json")
+          .short('i')
+          .long("input")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help(
+            "Gamedata folder to write changed sounds into under their logical paths, defaults to in place rewrite of \
+             loose sounds; archived sounds can only be written this way",
+          )
+          .short('d')
+          .long("dest")
+          .required(false)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(source_arg())
+      .arg(
+        Arg::new("dry-run")
+          .help("Validate the changes and report the result without writing any file")
+          .long("dry-run")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if any row is refused")
+          .long("strict")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Patch every sound whose parameters differ from its row, refusing rows that cannot be applied.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let input: &PathBuf = matches
+      .get_one::<PathBuf>("input")
+      .expect("Expected valid input path to be provided");
+
+    let destination: Option<&PathBuf> = matches.get_one::<PathBuf>("dest");
+    let is_dry_run: bool = matches.get_flag("dry-run");
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let started_at: Instant = Instant::now();
+    let table: SoundParametersTable = SoundParametersTable::read_from_path(input)?;
+    let vfs: XrayVfs = open_source_vfs(path, matches)?;
+
+    let mut patched_count: usize = 0;
+    let mut unchanged_count: usize = 0;
+    let mut refused_count: usize = 0;
+
+    for row in &table.rows {
+      match Self::apply_row(&vfs, row, destination.map(PathBuf::as_path), is_dry_run) {
+        Ok(SoundRowOutcome::Patched) => {
+          patched_count += 1;
+
+          xrf_output::verbose!(output, "Patched sound: {}", row.path);
+        }
+        Ok(SoundRowOutcome::Unchanged) => unchanged_count += 1,
+        Err(error) => {
+          refused_count += 1;
+
+          xrf_output::warning!(output, "Refused sound {}: {error}", row.path);
+        }
+      }
+    }
+
+    xrf_output::info!(
+      output,
+      "{} {} sounds in {}, {} unchanged, {} refused",
+      if is_dry_run { "Dry run, would patch" } else { "Patched" },
+      patched_count,
+      xrf_utils::format_duration(started_at.elapsed()),
+      unchanged_count,
+      refused_count
+    );
+
+    if matches.get_flag("strict") && refused_count > 0 {
+      return Err(CommandError::new_check_failed(refused_count));
+    }
+
+    Ok(())
+  }
+}
+
+impl ImportSoundParametersCommand {
+  /// Apply one row, writing the sound only when the engine would read different parameters from it.
+  fn apply_row(
+    vfs: &XrayVfs,
+    row: &SoundParametersRow,
+    destination: Option<&Path>,
+    is_dry_run: bool,
+  ) -> XrfResult<SoundRowOutcome> {
+    let asset: XrayAsset = vfs
+      .find(&row.path)?
+      .ok_or_else(|| XrfError::new_not_found_error("Sound is not in any mount"))?;
+    let bytes: Vec<u8> = vfs.read_asset(&asset)?;
+    let current: SoundMetadata = SoundFile::read_from_bytes(&bytes)?.metadata;
+
+    let Some((version, parameters)) = row.resolve_patch(&current)? else {
+      return Ok(SoundRowOutcome::Unchanged);
+    };
+
+    let target: PathBuf = asset_write_target(&asset, destination)?;
+
+    let patched: Vec<u8> = SoundFile::patch_bytes(&bytes, version, &parameters)?;
+
+    if !is_dry_run {
+      if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+      }
+
+      fs::write(&target, patched)?;
+    }
+
+    Ok(SoundRowOutcome::Patched)
+  }
+}
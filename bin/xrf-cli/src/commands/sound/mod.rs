@@ -0,0 +1,3 @@
+pub(crate) mod export_sound_parameters;
+pub(crate) mod import_sound_parameters;
+pub(crate) mod patch_sound;
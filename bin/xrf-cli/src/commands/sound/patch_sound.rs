@@ -0,0 +1,313 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::{XrfError, XrfResult};
+use xrf_output::OutputOptions;
+use xrf_sound::{SoundFile, SoundMetadata, SoundPatchReport, XRaySoundCommentVersion, XRaySoundParameters};
+
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct PatchSoundCommand;
+
+impl GenericCommand for PatchSoundCommand {
+  fn name(&self) -> &'static str {
+    "patch-sound"
+  }
+
+  /// Create command for rewriting the X-Ray parameters of an ogg file.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to rewrite the X-Ray source parameters of provided ogg file without re-encoding it")
+      .arg(
+        Arg::new("path")
+          .help("Path to ogg file")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to resulting ogg file, defaults to in place rewrite of the source file")
+          .short('d')
+          .long("dest")
+          .required(false)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("comment-version")
+          .help("X-Ray comment version to write, defaults to the version the file has or 3 for a file without one")
+          .long("comment-version")
+          .required(false)
+          .value_parser(["1", "2", "3"]),
+      )
+      .arg(
+        Arg::new("min-distance")
+          .help("Distance the sound starts to fade at")
+          .long("min-distance")
+          .required(false)
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("max-distance")
+          .help("Distance the sound can no longer be heard at")
+          .long("max-distance")
+          .required(false)
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("base-volume")
+          .help("Volume the sound is played at, stored from version 2")
+          .long("base-volume")
+          .required(false)
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("game-type")
+          .help("Sound type flags the AI perceives the sound as")
+          .long("game-type")
+          .required(false)
+          .value_parser(value_parser!(u32)),
+      )
+      .arg(
+        Arg::new("ai-distance")
+          .help("Distance the AI can hear the sound at, stored from version 3")
+          .long("ai-distance")
+          .required(false)
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("dry-run")
+          .help("Validate the change and report the result without writing any file")
+          .long("dry-run")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Rewrite or insert the X-Ray comment of provided ogg file, keeping parameters not given.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid input path to be provided");
+
+    let destination: &Path = matches
+      .get_one::<PathBuf>("dest")
+      .map_or(path.as_path(), |it| it.as_path());
+
+    if ![
+      "comment-version",
+      "min-distance",
+      "max-distance",
+      "base-volume",
+      "game-type",
+      "ai-distance",
+    ]
+    .iter()
+    .any(|id| matches.contains_id(id))
+    {
+      return Err(
+        XrfError::new_invalid_error("Expected at least one sound parameter or comment version to write").into(),
+      );
+    }
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let (existing_version, existing): (Option<XRaySoundCommentVersion>, XRaySoundParameters) =
+      match SoundFile::read_from_path(path)?.metadata {
+        SoundMetadata::EngineDefaults => (None, XRaySoundParameters::default()),
+        SoundMetadata::XRay { version, parameters } => (Some(version), parameters),
+      };
+
+    let requested_version: Option<XRaySoundCommentVersion> =
+      match matches.get_one::<String>("comment-version").map(String::as_str) {
+        Some("1") => Some(XRaySoundCommentVersion::V1),
+        Some("2") => Some(XRaySoundCommentVersion::V2),
+        Some(_) => Some(XRaySoundCommentVersion::V3),
+        None => None,
+      };
+
+    let parameters: XRaySoundParameters = XRaySoundParameters {
+      min_distance: matches
+        .get_one::<f32>("min-distance")
+        .copied()
+        .unwrap_or(existing.min_distance),
+      max_distance: matches
+        .get_one::<f32>("max-distance")
+        .copied()
+        .unwrap_or(existing.max_distance),
+      base_volume: matches
+        .get_one::<f32>("base-volume")
+        .copied()
+        .unwrap_or(existing.base_volume),
+      game_type: matches
+        .get_one::<u32>("game-type")
+        .copied()
+        .unwrap_or(existing.game_type),
+      max_ai_distance: matches
+        .get_one::<f32>("ai-distance")
+        .copied()
+        .unwrap_or(existing.max_ai_distance),
+    };
+
+    let given_fields: Vec<&str> = [("base-volume", "base_volume"), ("ai-distance", "max_ai_distance")]
+      .into_iter()
+      .filter(|(id, _)| matches.contains_id(id))
+      .map(|(_, field)| field)
+      .collect();
+    let version: XRaySoundCommentVersion =
+      Self::resolve_version(requested_version, existing_version, &parameters, &given_fields)?;
+
+    if requested_version.is_none() && existing_version.is_some_and(|existing| existing != version) {
+      xrf_output::info!(
+        output,
+        "Upgrading X-Ray comment to v{} to store the given parameters",
+        version.get_number()
+      );
+    }
+
+    let report: SoundPatchReport =
+      SoundFile::patch_to_path(path, destination, version, &parameters, matches.get_flag("dry-run"))?;
+
+    let stored: XRaySoundParameters = parameters.as_stored(version);
+    let outcome: String = format!(
+      "v{} min {} max {} volume {} type {} ai {}",
+      version.get_number(),
+      stored.min_distance,
+      stored.max_distance,
+      stored.base_volume,
+      stored.game_type,
+      stored.max_ai_distance
+    );
+
+    if report.is_dry_run {
+      xrf_output::info!(
+        output,
+        "Dry run, nothing written, {} would receive {} and {} bytes instead of {}",
+        destination.display(),
+        outcome,
+        report.patched_size,
+        report.original_size
+      );
+
+      return Ok(());
+    }
+
+    xrf_output::info!(
+      output,
+      "Patched sound parameters to {}, written into {}",
+      outcome,
+      destination.display()
+    );
+
+    Ok(())
+  }
+}
+
+impl PatchSoundCommand {
+  /// Comment version to write: the requested one, else the one the file has or 3 for a file without one.
+  ///
+  /// A version left to the file is raised to 3 when it could not store a parameter that was given,
+  /// a requested version that cannot store one is refused rather than dropping it.
+  fn resolve_version(
+    requested: Option<XRaySoundCommentVersion>,
+    existing: Option<XRaySoundCommentVersion>,
+    parameters: &XRaySoundParameters,
+    given_fields: &[&str],
+  ) -> XrfResult<XRaySoundCommentVersion> {
+    let version: XRaySoundCommentVersion = requested.or(existing).unwrap_or(XRaySoundCommentVersion::V3);
+    let unstored: Vec<&str> = parameters
+      .get_unstored_fields(version)
+      .into_iter()
+      .filter(|field| given_fields.contains(field))
+      .collect();
+
+    match (unstored.is_empty(), requested) {
+      (true, _) => Ok(version),
+      (false, None) => Ok(XRaySoundCommentVersion::V3),
+      (false, Some(_)) => Err(XrfError::new_invalid_error(format!(
+        "X-Ray comment version {} cannot store {}, write version 3 to keep it",
+        version.get_number(),
+        unstored.join(", ")
+      ))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_sound::{XRaySoundCommentVersion, XRaySoundParameters};
+
+  use crate::commands::sound::patch_sound::PatchSoundCommand;
+
+  fn parameters() -> XRaySoundParameters {
+    XRaySoundParameters {
+      min_distance: 1.0,
+      max_distance: 50.0,
+      base_volume: 0.5,
+      game_type: 0,
+      max_ai_distance: 20.0,
+    }
+  }
+
+  #[test]
+  fn keeps_version_of_file_when_it_stores_given_parameters() {
+    assert_eq!(
+      PatchSoundCommand::resolve_version(None, Some(XRaySoundCommentVersion::V2), &parameters(), &["base_volume"])
+        .unwrap(),
+      XRaySoundCommentVersion::V2
+    );
+    assert_eq!(
+      PatchSoundCommand::resolve_version(None, None, &parameters(), &["max_ai_distance"]).unwrap(),
+      XRaySoundCommentVersion::V3
+    );
+  }
+
+  #[test]
+  fn upgrades_version_of_file_that_cannot_store_given_parameters() {
+    assert_eq!(
+      PatchSoundCommand::resolve_version(
+        None,
+        Some(XRaySoundCommentVersion::V2),
+        &parameters(),
+        &["max_ai_distance"]
+      )
+      .unwrap(),
+      XRaySoundCommentVersion::V3
+    );
+    assert_eq!(
+      PatchSoundCommand::resolve_version(None, Some(XRaySoundCommentVersion::V1), &parameters(), &["base_volume"])
+        .unwrap(),
+      XRaySoundCommentVersion::V3
+    );
+  }
+
+  #[test]
+  fn refuses_requested_version_that_cannot_store_given_parameters() {
+    let error: String = PatchSoundCommand::resolve_version(
+      Some(XRaySoundCommentVersion::V2),
+      Some(XRaySoundCommentVersion::V3),
+      &parameters(),
+      &["max_ai_distance"],
+    )
+    .unwrap_err()
+    .to_string();
+
+    assert!(error.contains("max_ai_distance"), "{error}");
+  }
+}
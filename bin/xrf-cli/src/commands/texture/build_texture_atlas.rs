@@ -0,0 +1,139 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_dds::ImageFormat;
+use xrf_output::OutputOptions;
+use xrf_texture::{BuildAtlasOptions, BuildAtlasProcessor, BuildAtlasResult};
+
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct BuildTextureAtlasCommand;
+
+impl GenericCommand for BuildTextureAtlasCommand {
+  fn name(&self) -> &'static str {
+    "build-texture-atlas"
+  }
+
+  /// Create command for building ui atlases from loose sprites.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to pack folder of png sprites into dds atlases with matching texture description xml")
+      .arg(
+        Arg::new("source")
+          .help("Path to folder with png sprites, each named by its texture id")
+          .long("source")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("name")
+          .help("Texture name of the atlas, such as ui\\ui_hud_custom; further atlases get an index suffix")
+          .long("name")
+          .required(true),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to textures folder the atlas name is resolved against")
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("description")
+          .help("Path to texture description xml file to write")
+          .long("description")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("max-size")
+          .help("Largest atlas side in pixels, a power of two")
+          .long("max-size")
+          .default_value("2048")
+          .value_parser(value_parser!(u32)),
+      )
+      .arg(
+        Arg::new("padding")
+          .help("Transparent pixels kept between sprites")
+          .long("padding")
+          .default_value("2")
+          .value_parser(value_parser!(u32)),
+      )
+      .arg(
+        Arg::new("format")
+          .help("Compression format of atlases")
+          .long("format")
+          .default_value("bc3")
+          .value_parser(["bc1", "bc2", "bc3"]),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Turn off logging")
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Pack sprites of provided folder into atlases and describe them.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let source: &PathBuf = matches
+      .get_one::<PathBuf>("source")
+      .expect("Expected valid source folder to be provided");
+
+    let name: &String = matches
+      .get_one::<String>("name")
+      .expect("Expected atlas texture name to be provided");
+
+    let output: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output textures folder to be provided");
+
+    let description: &PathBuf = matches
+      .get_one::<PathBuf>("description")
+      .expect("Expected valid description path to be provided");
+
+    let output_options: OutputOptions =
+      TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let options: BuildAtlasOptions = BuildAtlasOptions {
+      source: source.clone(),
+      name: name.clone(),
+      output: output_options.clone(),
+      output_path: output.clone(),
+      description_path: description.clone(),
+      max_size: *matches
+        .get_one::<u32>("max-size")
+        .expect("Expected max size to default"),
+      padding: *matches.get_one::<u32>("padding").expect("Expected padding to default"),
+      dds_compression_format: match matches.get_one::<String>("format").map(String::as_str) {
+        Some("bc1") => ImageFormat::BC1RgbaUnorm,
+        Some("bc2") => ImageFormat::BC2RgbaUnorm,
+        _ => ImageFormat::BC3RgbaUnorm,
+      },
+    };
+
+    log::info!("Building texture atlas from: {}", source.display());
+
+    let result: BuildAtlasResult = BuildAtlasProcessor::build(&options)?;
+
+    xrf_output::info!(
+      output_options,
+      "Built {} atlases with {} sprites",
+      result.pages.len(),
+      result.pages.iter().map(|page| page.file.sprites.len()).sum::<usize>()
+    );
+
+    Ok(())
+  }
+}
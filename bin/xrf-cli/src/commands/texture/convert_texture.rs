@@ -0,0 +1,437 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use walkdir::WalkDir;
+use xrf_db::{ThmFile, ThmTextureParamsChunk, ThmTextureTypeChunk, XRayByteOrder};
+use xrf_dds::{DdsFile, DdsMetadata, ImageFormat};
+use xrf_error::{XrfError, XrfResult};
+use xrf_output::OutputOptions;
+use xrf_texture::{ConvertTextureOptions, ConvertTextureProcessor, ConvertTextureResult};
+
+use crate::commands::thm::create_thm::CreateThmCommand;
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+/// Alpha test reference assumed for descriptors marking binary alpha when none is provided.
+const DEFAULT_ALPHA_COVERAGE_REFERENCE: f32 = 0.5;
+
+/// Source extensions, in order of preference when several share a stem.
+const SOURCE_EXTENSIONS: [&str; 3] = ["png", "tga", "dds"];
+
+/// What converting one source did.
+enum ConvertTextureOutcome {
+  Converted,
+  Existing,
+}
+
+/// Conversion settings overridden from the command line, the rest comes from descriptors.
+struct ConvertTextureOverrides {
+  format: Option<ImageFormat>,
+  texture_type: Option<u32>,
+  alpha_coverage_reference: Option<f32>,
+  is_mipmapped: bool,
+  is_forced: bool,
+  is_dry_run: bool,
+}
+
+#[derive(Default)]
+pub struct ConvertTextureCommand;
+
+impl GenericCommand for ConvertTextureCommand {
+  fn name(&self) -> &'static str {
+    "convert-texture"
+  }
+
+  /// Create command for converting images into dds textures the engine loads.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to convert png, tga or dds sources into engine compatible dds textures with regenerated mips")
+      .arg(
+        Arg::new("path")
+          .help("Path to png, tga or dds file or to a folder to convert every source in")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help(
+            "Path to resulting dds file, or folder to write converted textures into under their relative paths; \
+             defaults to the source path with dds extension",
+          )
+          .short('d')
+          .long("dest")
+          .required(false)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("format")
+          .help("Compression format, defaults to the one the thm declares, then to bc1 for opaque and bc3 otherwise")
+          .long("format")
+          .required(false)
+          .value_parser(["bc1", "bc2", "bc3"]),
+      )
+      .arg(
+        Arg::new("texture-type")
+          .help(
+            "Texture type to convert as, defaults to the one the thm declares, then to bump for '_bump' textures, \
+             terrain for level terrain and image otherwise",
+          )
+          .long("texture-type")
+          .required(false)
+          .value_parser(["image", "bump", "normal", "terrain"]),
+      )
+      .arg(
+        Arg::new("alpha-reference")
+          .help(
+            "Alpha test reference from 0 to 1 whose coverage every mip keeps, for foliage and other cut outs; \
+             defaults to 0.5 for textures the thm marks as binary alpha",
+          )
+          .long("alpha-reference")
+          .required(false)
+          .value_parser(value_parser!(f32)),
+      )
+      .arg(
+        Arg::new("no-mipmaps")
+          .help("Write the base level only")
+          .long("no-mipmaps")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("force")
+          .help("Convert sources whose texture is up to date, re-encoding dds sources that are compatible already")
+          .long("force")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("dry-run")
+          .help("Resolve the conversions and report the result without writing any file")
+          .long("dry-run")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if any texture of a folder fails to convert")
+          .long("strict")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Convert provided source, or every source of provided folder.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid input path to be provided");
+    let destination: Option<&PathBuf> = matches.get_one::<PathBuf>("dest");
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let alpha_coverage_reference: Option<f32> = matches.get_one::<f32>("alpha-reference").copied();
+
+    if alpha_coverage_reference.is_some_and(|reference| !(0.0..=1.0).contains(&reference)) {
+      return Err(XrfError::new_invalid_error("Expected --alpha-reference between 0 and 1").into());
+    }
+
+    let overrides: ConvertTextureOverrides = ConvertTextureOverrides {
+      format: matches.get_one::<String>("format").map(|format| match format.as_str() {
+        "bc2" => ImageFormat::BC2RgbaUnorm,
+        "bc3" => ImageFormat::BC3RgbaUnorm,
+        _ => ImageFormat::BC1RgbaUnorm,
+      }),
+      texture_type: matches
+        .get_one::<String>("texture-type")
+        .map(|texture_type| match texture_type.as_str() {
+          "bump" => ThmTextureTypeChunk::TYPE_BUMP_MAP,
+          "normal" => ThmTextureTypeChunk::TYPE_NORMAL_MAP,
+          "terrain" => ThmTextureTypeChunk::TYPE_TERRAIN,
+          _ => ThmTextureTypeChunk::TYPE_IMAGE,
+        }),
+      alpha_coverage_reference,
+      is_mipmapped: !matches.get_flag("no-mipmaps"),
+      is_forced: matches.get_flag("force"),
+      is_dry_run: matches.get_flag("dry-run"),
+    };
+
+    let started_at: Instant = Instant::now();
+
+    if !path.is_dir() {
+      let output_path: PathBuf = destination.map_or_else(|| path.with_extension("dds"), PathBuf::clone);
+
+      if let ConvertTextureOutcome::Existing = Self::convert_file(&output, path, &output_path, &overrides)? {
+        xrf_output::info!(
+          output,
+          "Texture {} is up to date, provide --force to convert it anyway",
+          output_path.display()
+        );
+      }
+
+      return Ok(());
+    }
+
+    let mut converted_count: usize = 0;
+    let mut existing_count: usize = 0;
+    let mut failed_count: usize = 0;
+
+    for source in Self::collect_sources(path) {
+      let output_path: PathBuf = match destination {
+        Some(destination) => destination
+          .join(source.strip_prefix(path).unwrap_or(&source))
+          .with_extension("dds"),
+        None => source.with_extension("dds"),
+      };
+
+      match Self::convert_file(&output, &source, &output_path, &overrides) {
+        Ok(ConvertTextureOutcome::Converted) => converted_count += 1,
+        Ok(ConvertTextureOutcome::Existing) => existing_count += 1,
+        Err(error) => {
+          failed_count += 1;
+
+          xrf_output::warning!(output, "Skipped texture {}: {error}", source.display());
+        }
+      }
+    }
+
+    xrf_output::info!(
+      output,
+      "{} {} textures in {}, {} up to date, {} failed",
+      if overrides.is_dry_run {
+        "Dry run, would convert"
+      } else {
+        "Converted"
+      },
+      converted_count,
+      xrf_utils::format_duration(started_at.elapsed()),
+      existing_count,
+      failed_count
+    );
+
+    if matches.get_flag("strict") && failed_count > 0 {
+      return Err(CommandError::new_check_failed(failed_count));
+    }
+
+    Ok(())
+  }
+}
+
+impl ConvertTextureCommand {
+  /// Resolve the settings of a single source from its descriptor and convert it.
+  fn convert_file(
+    output: &OutputOptions,
+    source: &Path,
+    output_path: &Path,
+    overrides: &ConvertTextureOverrides,
+  ) -> XrfResult<ConvertTextureOutcome> {
+    if !overrides.is_forced && Self::is_up_to_date(source, output_path, overrides.is_mipmapped)? {
+      xrf_output::verbose!(output, "Texture is up to date: {}", output_path.display());
+
+      return Ok(ConvertTextureOutcome::Existing);
+    }
+
+    let descriptor_path: PathBuf = source.with_extension("thm");
+    let descriptor: Option<ThmFile> = if descriptor_path.is_file() {
+      Some(ThmFile::read_from_path::<XRayByteOrder, _>(&descriptor_path)?)
+    } else {
+      None
+    };
+    let params: Option<&ThmTextureParamsChunk> = descriptor.as_ref().and_then(|descriptor| descriptor.params.as_ref());
+
+    let texture_type: u32 = overrides
+      .texture_type
+      .or_else(|| {
+        descriptor
+          .as_ref()
+          .and_then(|descriptor| descriptor.texture_type.as_ref())
+          .map(|texture_type| texture_type.texture_type)
+      })
+      .unwrap_or_else(|| ThmTextureTypeChunk::type_for_texture_name(&CreateThmCommand::texture_name(source)));
+
+    if texture_type == ThmTextureTypeChunk::TYPE_CUBE_MAP {
+      return Err(XrfError::new_not_implemented_error(
+        "Converting cube maps is not supported",
+      ));
+    }
+
+    let is_data: bool = matches!(
+      texture_type,
+      ThmTextureTypeChunk::TYPE_BUMP_MAP | ThmTextureTypeChunk::TYPE_NORMAL_MAP
+    );
+
+    // Bump maps keep height and gloss in alpha, so they need a format with alpha whatever the pixels hold.
+    let format: Option<ImageFormat> = overrides
+      .format
+      .or_else(|| params.and_then(|params| Self::format_from_descriptor(params.format)))
+      .or_else(|| is_data.then_some(ImageFormat::BC3RgbaUnorm));
+
+    let alpha_coverage_reference: Option<f32> = overrides.alpha_coverage_reference.or_else(|| {
+      params
+        .filter(|params| params.has_flag(ThmTextureParamsChunk::FLAG_BINARY_ALPHA))
+        .map(|_| DEFAULT_ALPHA_COVERAGE_REFERENCE)
+    });
+
+    let is_mipmapped: bool = overrides.is_mipmapped
+      && params.is_none_or(|params| params.has_flag(ThmTextureParamsChunk::FLAG_GENERATE_MIPMAPS));
+
+    xrf_output::verbose!(
+      output,
+      "{} {} to {}: type {}, format {}, mips {}, alpha coverage {}",
+      if overrides.is_dry_run {
+        "Would convert"
+      } else {
+        "Convert"
+      },
+      source.display(),
+      output_path.display(),
+      texture_type,
+      format.map_or_else(|| String::from("auto"), |format| format!("{format:?}")),
+      is_mipmapped,
+      alpha_coverage_reference.map_or_else(|| String::from("off"), |reference| reference.to_string())
+    );
+
+    if overrides.is_dry_run {
+      return Ok(ConvertTextureOutcome::Converted);
+    }
+
+    if let Some(parent) = output_path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    let result: ConvertTextureResult = ConvertTextureProcessor::convert(&ConvertTextureOptions {
+      source: source.to_path_buf(),
+      output_path: output_path.to_path_buf(),
+      output: output.clone(),
+      dds_compression_format: format,
+      is_mipmapped,
+      is_color: !is_data,
+      alpha_coverage_reference,
+    })?;
+
+    xrf_output::info!(
+      output,
+      "Converted {} to {}: {}x{} {:?}, {} mip levels",
+      source.display(),
+      output_path.display(),
+      result.width,
+      result.height,
+      result.format,
+      result.mipmap_levels
+    );
+
+    Ok(ConvertTextureOutcome::Converted)
+  }
+
+  /// Whether the output can be kept as it is.
+  ///
+  /// An output written from another source is kept while it is newer than the source. A dds converted in
+  /// place is only re-encoded when the engine cannot read it or it lacks mips, since every pass through
+  /// block compression loses detail.
+  fn is_up_to_date(source: &Path, output_path: &Path, is_mipmapped: bool) -> XrfResult<bool> {
+    if !output_path.exists() {
+      return Ok(false);
+    }
+
+    if source != output_path {
+      return Ok(fs::metadata(output_path)?.modified()? >= fs::metadata(source)?.modified()?);
+    }
+
+    let file: DdsFile = DdsFile::read_from_path(source)?;
+    let metadata: DdsMetadata = file.metadata();
+
+    Ok(
+      file.is_xray_compatible()
+        && (!is_mipmapped || metadata.mipmap_levels > 1 || metadata.width.max(metadata.height) == 1),
+    )
+  }
+
+  /// Compression format a descriptor asks for, when it is one the converter writes.
+  fn format_from_descriptor(format: u32) -> Option<ImageFormat> {
+    match format {
+      ThmTextureParamsChunk::FORMAT_DXT1 | ThmTextureParamsChunk::FORMAT_DXT1_ALPHA => Some(ImageFormat::BC1RgbaUnorm),
+      ThmTextureParamsChunk::FORMAT_DXT3 => Some(ImageFormat::BC2RgbaUnorm),
+      ThmTextureParamsChunk::FORMAT_DXT5 => Some(ImageFormat::BC3RgbaUnorm),
+      _ => None,
+    }
+  }
+
+  /// Every source of a folder, a dds counting only when no image with its stem sits next to it.
+  ///
+  /// A png or tga next to a dds of the same name is taken as what the dds was made from.
+  fn collect_sources(path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(path)
+      .into_iter()
+      .filter_map(Result::ok)
+      .map(|it| it.into_path())
+      .filter(|it| it.is_file())
+      .filter(|it| {
+        let Some(extension) = it.extension().and_then(|it| it.to_str()) else {
+          return false;
+        };
+
+        match SOURCE_EXTENSIONS
+          .iter()
+          .position(|source| source.eq_ignore_ascii_case(extension))
+        {
+          Some(rank) => SOURCE_EXTENSIONS[..rank]
+            .iter()
+            .all(|preferred| !it.with_extension(preferred).is_file()),
+          None => false,
+        }
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use super::ConvertTextureCommand;
+  use crate::core::command_error::CommandError;
+  use crate::core::generic_command::{CommandResult, GenericCommand};
+
+  #[test]
+  fn fails_strict_folder_conversion_with_failed_textures() -> CommandResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-cli-convert-texture-{}", std::process::id()));
+    let path: String = root.display().to_string();
+
+    fs::create_dir_all(&root)?;
+    fs::write(root.join("broken.png"), "not an image")?;
+
+    let convert = |extra: &[&str]| -> CommandResult {
+      let mut arguments: Vec<&str> = vec!["convert-texture", "--path", &path, "--silent"];
+
+      arguments.extend_from_slice(extra);
+
+      ConvertTextureCommand.execute(&ConvertTextureCommand.init().try_get_matches_from(arguments)?)
+    };
+
+    convert(&[])?;
+
+    assert!(matches!(
+      convert(&["--strict"]),
+      Err(CommandError::CheckFailed { findings: 1 })
+    ));
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod build_texture_atlas;
+pub(crate) mod convert_texture;
 pub(crate) mod crop_dds;
 pub(crate) mod info_dds;
 pub(crate) mod pack_equipment_icons;
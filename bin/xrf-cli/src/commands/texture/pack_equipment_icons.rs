@@ -6,7 +6,7 @@ use xrf_dds::ImageFormat;
 use xrf_error::XrfError;
 use xrf_ltx::Ltx;
 use xrf_output::OutputOptions;
-use xrf_texture::{PackEquipmentOptions, PackEquipmentProcessor};
+use xrf_texture::{InventorySpriteDescriptor, PackEquipmentOptions, PackEquipmentProcessor, PlaceEquipmentProcessor};
 
 use crate::core::generic_command::{CommandResult, GenericCommand};
 use crate::core::output::TerminalOutput;
@@ -32,7 +32,7 @@ impl GenericCommand for PackEquipmentIconsCommand {
       )
       .arg(
         Arg::new("source")
-          .help("Path to source folder with section icons")
+          .help("Path to source folder with section icons, icons of other atlases in folders named after them")
           .long("source")
           .required(true)
           .value_parser(value_parser!(PathBuf)),
@@ -45,11 +45,22 @@ impl GenericCommand for PackEquipmentIconsCommand {
           .value_parser(value_parser!(PathBuf)),
       )
       .arg(
-        Arg::new("gamedata")
-          .help("Path to gamedata folder for resources usage")
-          .long("gamedata")
+        Arg::new("allocate")
+          .help(
+            "Place icons of sections declaring no inv_grid_x/inv_grid_y into free cells and write the positions into \
+             the ltx files declaring them, leaving the rest of those files as written",
+          )
+          .long("allocate")
           .required(false)
-          .value_parser(value_parser!(PathBuf)),
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("columns")
+          .help("Minimum number of grid columns to allocate within, defaults to the columns the sheet spans already")
+          .long("columns")
+          .required(false)
+          .requires("allocate")
+          .value_parser(value_parser!(u32)),
       )
       .arg(
         Arg::new("silent")
@@ -86,8 +97,6 @@ impl GenericCommand for PackEquipmentIconsCommand {
       .get_one::<PathBuf>("source")
       .expect("Expected valid source path to be provided");
 
-    let gamedata: Option<&PathBuf> = matches.get_one::<PathBuf>("gamedata");
-
     let output: &PathBuf = matches
       .get_one::<PathBuf>("output")
       .expect("Expected valid output path to be provided");
@@ -115,20 +124,53 @@ impl GenericCommand for PackEquipmentIconsCommand {
     let started_at: Instant = Instant::now();
     let system_ltx: Ltx = Ltx::read_from_file_full(system_ltx_path)?;
 
-    let options = PackEquipmentOptions {
+    let mut options = PackEquipmentOptions {
       ltx: system_ltx,
       source: source.into(),
       output: output_options.clone(),
       output_path: output.into(),
-      gamedata: gamedata.cloned(),
       dds_compression_format: ImageFormat::BC3RgbaUnorm,
       is_strict,
     };
 
     log::info!("DDS format: {}", options.dds_compression_format);
 
+    let placements: Vec<InventorySpriteDescriptor> = if matches.get_flag("allocate") {
+      let placements: Vec<InventorySpriteDescriptor> =
+        PlaceEquipmentProcessor::place(&options, matches.get_one::<u32>("columns").copied().unwrap_or_default());
+
+      PlaceEquipmentProcessor::apply(&mut options.ltx, &placements);
+
+      placements
+    } else {
+      Vec::new()
+    };
+
+    // Declaring files are resolved before the sheet is packed, so a placement that cannot be written fails the
+    // command without leaving a packed sheet the configs do not match.
+    let edits: Vec<(PathBuf, Vec<u8>)> = if placements.is_empty() {
+      Vec::new()
+    } else {
+      PlaceEquipmentProcessor::edit_sources(system_ltx_path, &placements)?
+    };
+
     PackEquipmentProcessor::pack_sprites(options)?;
 
+    if !placements.is_empty() {
+      let edited: Vec<PathBuf> = PlaceEquipmentProcessor::write_sources(edits)?;
+
+      xrf_output::info!(
+        output_options,
+        "Placed {} new icons, wrote their grid positions into {} ltx files",
+        placements.len(),
+        edited.len()
+      );
+
+      for path in &edited {
+        xrf_output::verbose!(output_options, "Updated grid positions in {}", path.display());
+      }
+    }
+
     xrf_output::info!(
       output_options,
       "Saved resulting file with combined icons {}",
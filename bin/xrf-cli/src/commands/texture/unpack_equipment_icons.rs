@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use std::time::Instant;
 
 use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
-use xrf_dds::{DdsFile, ImageFormat};
+use xrf_dds::ImageFormat;
 use xrf_ltx::Ltx;
 use xrf_output::OutputOptions;
 use xrf_texture::{UnpackEquipmentOptions, UnpackEquipmentProcessor};
@@ -32,7 +32,7 @@ impl GenericCommand for UnpackEquipmentIconsCommand {
       )
       .arg(
         Arg::new("source")
-          .help("Path to source dds file")
+          .help("Path to source equipment dds file, other icon atlases are resolved relative to it")
           .long("source")
           .required(true)
           .value_parser(value_parser!(PathBuf)),
@@ -79,21 +79,6 @@ impl GenericCommand for UnpackEquipmentIconsCommand {
 
     let started_at: Instant = Instant::now();
 
-    xrf_output::info!(output_options, "Opening DDS file: {}", source.display());
-
-    let source_file: DdsFile = DdsFile::read_from_path(source)?;
-    let metadata = source_file.metadata();
-
-    xrf_output::info!(
-      output_options,
-      "Source DDS file details: {}x{}, mip-maps: {}, format: {:?}",
-      metadata.width,
-      metadata.height,
-      metadata.declared_mipmap_levels.unwrap_or(0),
-      metadata.dx10_format
-    );
-
-    let source_dds = source_file.decode_rgba(0)?;
     let system_ltx: Ltx = Ltx::read_from_file_full(system_ltx_path)?;
 
     xrf_output::info!(
@@ -106,7 +91,7 @@ impl GenericCommand for UnpackEquipmentIconsCommand {
 
     UnpackEquipmentProcessor::unpack_sprites(UnpackEquipmentOptions {
       ltx: system_ltx,
-      source: source_dds,
+      source: source.into(),
       output: output_options.clone(),
       output_path: output.into(),
       dds_compression_format: ImageFormat::BC3RgbaUnorm,
@@ -81,7 +81,11 @@ impl GenericCommand for VerifyEquipmentIconsCommand {
     for overlap in &overlaps {
       xrf_output::error!(
         output,
-        "Overlapping icon rects at {}:{}, {} cell(s) shared by '{}' and '{}'",
+        "Overlapping icon rects on {} at {}:{}, {} cell(s) shared by '{}' and '{}'",
+        overlap
+          .atlas
+          .as_deref()
+          .map_or_else(|| String::from("equipment sheet"), |atlas| format!("atlas '{atlas}'")),
         overlap.cell.0,
         overlap.cell.1,
         overlap.overlapping_cells,
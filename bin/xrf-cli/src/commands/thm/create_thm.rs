@@ -0,0 +1,275 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use walkdir::WalkDir;
+use xrf_db::{ThmBumpChunk, ThmFile, ThmTextureParamsChunk, ThmTextureTypeChunk, XRayByteOrder};
+use xrf_dds::DdsFile;
+use xrf_error::{XrfError, XrfResult};
+use xrf_output::OutputOptions;
+
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+/// What creating a descriptor for one texture did.
+enum CreateThmOutcome {
+  Created,
+  Existing,
+}
+
+#[derive(Default)]
+pub struct CreateThmCommand;
+
+impl GenericCommand for CreateThmCommand {
+  fn name(&self) -> &'static str {
+    "create-thm"
+  }
+
+  /// Create command for deriving thm descriptors of dds textures.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to create thm descriptors for dds textures that have none, derived from the dds itself")
+      .arg(
+        Arg::new("path")
+          .help("Path to dds file or to a folder to create descriptors for every dds without one")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("dest")
+          .help("Path to resulting thm file, defaults to the dds path with thm extension; single file only")
+          .short('d')
+          .long("dest")
+          .required(false)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("texture-type")
+          .help("Texture type to declare, defaults to bump for '_bump' textures, terrain for level terrain and image otherwise")
+          .long("texture-type")
+          .required(false)
+          .value_parser(["image", "cube", "bump", "normal", "terrain"]),
+      )
+      .arg(
+        Arg::new("bump")
+          .help(
+            "Bump texture reference to declare, engine style without extension; defaults to the '_bump' sibling of the \
+             texture when it exists",
+          )
+          .long("bump")
+          .required(false),
+      )
+      .arg(
+        Arg::new("force")
+          .help("Overwrite descriptors that already exist")
+          .long("force")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("dry-run")
+          .help("Derive the descriptors and report the result without writing any file")
+          .long("dry-run")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  /// Create a descriptor for provided dds, or for every dds of provided folder lacking one.
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid input path to be provided");
+
+    let output: OutputOptions = TerminalOutput::from_options(matches.get_flag("silent"), matches.get_flag("verbose"));
+
+    let texture_type: Option<u32> =
+      matches
+        .get_one::<String>("texture-type")
+        .map(|texture_type| match texture_type.as_str() {
+          "cube" => ThmTextureTypeChunk::TYPE_CUBE_MAP,
+          "bump" => ThmTextureTypeChunk::TYPE_BUMP_MAP,
+          "normal" => ThmTextureTypeChunk::TYPE_NORMAL_MAP,
+          "terrain" => ThmTextureTypeChunk::TYPE_TERRAIN,
+          _ => ThmTextureTypeChunk::TYPE_IMAGE,
+        });
+    let bump: Option<&str> = matches.get_one::<String>("bump").map(String::as_str);
+    let is_forced: bool = matches.get_flag("force");
+    let is_dry_run: bool = matches.get_flag("dry-run");
+
+    if !path.is_dir() {
+      let destination: PathBuf = matches
+        .get_one::<PathBuf>("dest")
+        .map_or_else(|| path.with_extension("thm"), PathBuf::clone);
+
+      return match Self::create_file(&output, path, &destination, texture_type, bump, is_forced, is_dry_run)? {
+        CreateThmOutcome::Created => Ok(()),
+        CreateThmOutcome::Existing => Err(
+          XrfError::new_invalid_error(format!(
+            "Descriptor {} already exists, provide --force to overwrite it",
+            destination.display()
+          ))
+          .into(),
+        ),
+      };
+    }
+
+    if matches.contains_id("dest") {
+      return Err(XrfError::new_invalid_error("Expected no --dest when creating descriptors for a folder").into());
+    }
+
+    let mut created_count: usize = 0;
+    let mut existing_count: usize = 0;
+    let mut failed_count: usize = 0;
+
+    for texture in Self::collect_textures(path) {
+      match Self::create_file(
+        &output,
+        &texture,
+        &texture.with_extension("thm"),
+        texture_type,
+        bump,
+        is_forced,
+        is_dry_run,
+      ) {
+        Ok(CreateThmOutcome::Created) => created_count += 1,
+        Ok(CreateThmOutcome::Existing) => existing_count += 1,
+        Err(error) => {
+          failed_count += 1;
+
+          xrf_output::warning!(output, "Skipped texture {}: {error}", texture.display());
+        }
+      }
+    }
+
+    xrf_output::info!(
+      output,
+      "{} {} descriptors, {} already existing, {} failed",
+      if is_dry_run { "Dry run, would create" } else { "Created" },
+      created_count,
+      existing_count,
+      failed_count
+    );
+
+    Ok(())
+  }
+}
+
+impl CreateThmCommand {
+  /// Derive and write the descriptor of a single texture.
+  fn create_file(
+    output: &OutputOptions,
+    path: &Path,
+    destination: &Path,
+    texture_type: Option<u32>,
+    bump: Option<&str>,
+    is_forced: bool,
+    is_dry_run: bool,
+  ) -> XrfResult<CreateThmOutcome> {
+    if destination.exists() && !is_forced {
+      xrf_output::verbose!(output, "Descriptor exists: {}", destination.display());
+
+      return Ok(CreateThmOutcome::Existing);
+    }
+
+    let name: String = Self::texture_name(path);
+    let texture_type: u32 = texture_type.unwrap_or_else(|| ThmTextureTypeChunk::type_for_texture_name(&name));
+    let mut file: ThmFile = ThmFile::from_dds_metadata(&DdsFile::read_metadata_from_path(path)?, texture_type)?;
+
+    let bump: Option<String> = match bump {
+      Some(bump) => Some(bump.to_owned()),
+      None if texture_type == ThmTextureTypeChunk::TYPE_IMAGE => Self::find_sibling_bump(path, &name),
+      None => None,
+    };
+
+    if let (Some(bump), Some(declaration)) = (&bump, file.bump.as_mut()) {
+      declaration.mode = ThmBumpChunk::MODE_USE;
+      declaration.name = bump.clone();
+    }
+
+    let params: &ThmTextureParamsChunk = file
+      .params
+      .as_ref()
+      .expect("Expected derived descriptor to declare texture params");
+
+    xrf_output::verbose!(
+      output,
+      "{} {}: format {}, {}x{}, type {}, bump '{}'",
+      if is_dry_run { "Would create" } else { "Create" },
+      destination.display(),
+      params.format,
+      params.width,
+      params.height,
+      texture_type,
+      bump.as_deref().unwrap_or_default()
+    );
+
+    if !is_dry_run {
+      file.write_to_path::<XRayByteOrder, _>(&destination)?;
+    }
+
+    Ok(CreateThmOutcome::Created)
+  }
+
+  /// Engine name of a texture: its path under the nearest `textures` folder without extension.
+  ///
+  /// Falls back to the bare file stem for a texture kept outside of any gamedata tree.
+  pub(crate) fn texture_name(path: &Path) -> String {
+    let stem: PathBuf = path.with_extension("");
+    let root: Option<&Path> = stem.ancestors().skip(1).find(|ancestor| {
+      ancestor
+        .file_name()
+        .is_some_and(|name| name.eq_ignore_ascii_case("textures"))
+    });
+
+    match root.and_then(|root| stem.strip_prefix(root).ok()) {
+      Some(relative) => relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("\\"),
+      None => stem
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default(),
+    }
+  }
+
+  /// Bump reference for a texture whose `_bump` sibling sits next to it.
+  fn find_sibling_bump(path: &Path, name: &str) -> Option<String> {
+    let stem: &str = path.file_stem()?.to_str()?;
+
+    path
+      .with_file_name(format!("{stem}_bump.dds"))
+      .is_file()
+      .then(|| format!("{name}_bump"))
+  }
+
+  fn collect_textures(path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(path)
+      .into_iter()
+      .filter_map(Result::ok)
+      .map(|it| it.into_path())
+      .filter(|it| {
+        it.is_file()
+          && it
+            .extension()
+            .and_then(|it| it.to_str())
+            .is_some_and(|it| it.eq_ignore_ascii_case("dds"))
+      })
+      .collect()
+  }
+}
@@ -1 +1,4 @@
+pub(crate) mod apply_thm_table;
+pub(crate) mod create_thm;
+pub(crate) mod export_thm_table;
 pub(crate) mod patch_thm_bump;
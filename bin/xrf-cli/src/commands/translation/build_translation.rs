@@ -1,11 +1,10 @@
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
-use xrf_error::XrfError;
 use xrf_output::OutputOptions;
-use xrf_translation::{ProjectBuildOptions, ProjectBuildResult, TranslationLanguage, build_dir, build_file};
+use xrf_translation::{ProjectBuildOptions, ProjectBuildResult, TranslationLanguages, build_dir, build_file};
 
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
 use crate::core::generic_command::{CommandResult, GenericCommand};
 use crate::core::output::TerminalOutput;
 
@@ -46,6 +45,7 @@ impl GenericCommand for BuildTranslationCommand {
           .default_value("all")
           .value_parser(value_parser!(String)),
       )
+      .arg(languages_arg())
       .arg(
         Arg::new("silent")
           .help("Disable any logging")
@@ -83,6 +83,7 @@ impl GenericCommand for BuildTranslationCommand {
     let language: &String = matches
       .get_one::<String>("language")
       .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
 
     let is_silent: bool = matches.get_flag("silent");
     let is_verbose: bool = matches.get_flag("verbose");
@@ -103,7 +104,8 @@ impl GenericCommand for BuildTranslationCommand {
       output,
       path: path.clone(),
       output_dir: output_dir.clone(),
-      language: TranslationLanguage::from_str(language).map_err(XrfError::new_unknown_language_error)?,
+      language: languages.select(language)?,
+      languages,
     };
 
     let result: ProjectBuildResult = if path.is_dir() {
@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_output::OutputOptions;
+use xrf_translation::{
+  ProjectCoverageOptions, ProjectCoverageResult, TranslationLanguageCoverage, TranslationLanguages, coverage_dir,
+  coverage_file,
+};
+
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct CoverageTranslationCommand;
+
+impl GenericCommand for CoverageTranslationCommand {
+  fn name(&self) -> &'static str {
+    "coverage-translation"
+  }
+
+  /// Create command for reporting translation coverage.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to report translation coverage and stale translations per language")
+      .arg(
+        Arg::new("path")
+          .help("Path to translation folder or file")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("language")
+          .help("Language to report")
+          .short('l')
+          .long("language")
+          .required(false)
+          .default_value("all")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("accept")
+          .help("Record translated entries as reviewed against the current English text")
+          .long("accept")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("report")
+          .help("Write the coverage report as JSON")
+          .long("report")
+          .required(false)
+          .value_name("PATH")
+          .num_args(1)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if translations are stale")
+          .long("strict")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(languages_arg())
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let language: &String = matches
+      .get_one::<String>("language")
+      .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
+
+    let is_silent: bool = matches.get_flag("silent");
+    let is_verbose: bool = matches.get_flag("verbose");
+    let is_strict: bool = matches.get_flag("strict");
+    let report_path: Option<PathBuf> = matches.get_one::<PathBuf>("report").cloned();
+
+    let output: OutputOptions = TerminalOutput::from_options(is_silent, is_verbose);
+
+    let options: ProjectCoverageOptions = ProjectCoverageOptions {
+      is_accepting: matches.get_flag("accept"),
+      output,
+      language: languages.select(language)?,
+      languages,
+    };
+
+    let result: ProjectCoverageResult = if path.is_dir() {
+      coverage_dir(path, &options)
+    } else {
+      coverage_file(path, &options)
+    }?;
+
+    for file in &result.files {
+      xrf_output::heading!(options.output, "{}", file.path);
+
+      for coverage in &file.languages {
+        Self::print_coverage(&options.output, coverage);
+
+        for id in &coverage.stale_ids {
+          xrf_output::warning!(options.output, "  stale {} {}", coverage.language, id);
+        }
+      }
+    }
+
+    xrf_output::heading!(options.output, "Total");
+
+    for coverage in result.languages() {
+      Self::print_coverage(&options.output, &coverage);
+    }
+
+    if options.is_accepting {
+      xrf_output::info!(
+        options.output,
+        "Accepted {} translations as reviewed",
+        result.accepted_count
+      );
+    }
+
+    if let Some(report_path) = report_path {
+      Self::write_report(&result, &report_path)?;
+    }
+
+    xrf_output::info!(
+      options.output,
+      "Reported translation coverage in {}, {} stale",
+      xrf_utils::format_duration(result.duration),
+      result.stale_count()
+    );
+
+    if is_strict && result.stale_count() > 0 {
+      return Err(CommandError::new_check_failed(result.stale_count() as usize));
+    }
+
+    Ok(())
+  }
+}
+
+impl CoverageTranslationCommand {
+  fn print_coverage(output: &OutputOptions, coverage: &TranslationLanguageCoverage) {
+    xrf_output::info!(
+      output,
+      "{} {:>6.2}% - {} translated, {} missing, {} empty, {} stale",
+      coverage.language,
+      coverage.percent(),
+      coverage.translated_count,
+      coverage.missing_count,
+      coverage.empty_count,
+      coverage.stale_count
+    );
+  }
+
+  fn write_report(result: &ProjectCoverageResult, report_path: &Path) -> CommandResult {
+    let json: String = serde_json::to_string_pretty(result)?;
+
+    std::fs::write(report_path, format!("{json}\n"))?;
+
+    Ok(())
+  }
+}
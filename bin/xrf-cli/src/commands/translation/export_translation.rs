@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_error::XrfError;
+use xrf_output::OutputOptions;
+use xrf_translation::{
+  ProjectExportOptions, ProjectExportResult, TranslationInterchangeFormat, TranslationLanguages, export_dir,
+};
+
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct ExportTranslationCommand;
+
+impl GenericCommand for ExportTranslationCommand {
+  fn name(&self) -> &'static str {
+    "export-translation"
+  }
+
+  /// Create command for exporting translations to PO or XLIFF.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to export translation sources as gettext PO/POT or XLIFF for translator tools")
+      .arg(
+        Arg::new("path")
+          .help("Path to translation folder")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("output")
+          .help("Path to the exported file")
+          .short('o')
+          .long("output")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("language")
+          .help("Language to fill translations from, not needed for a POT template")
+          .short('l')
+          .long("language")
+          .required(false)
+          .default_value("all")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("format")
+          .help("Format to export: pot, po, xliff-1.2 or xliff-2.0, told from the output extension by default")
+          .short('f')
+          .long("format")
+          .required(false)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(languages_arg())
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let output_file: &PathBuf = matches
+      .get_one::<PathBuf>("output")
+      .expect("Expected valid output path to be provided");
+
+    let language: &String = matches
+      .get_one::<String>("language")
+      .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
+
+    let format: TranslationInterchangeFormat = match matches.get_one::<String>("format") {
+      Some(format) => TranslationInterchangeFormat::from_str(format).map_err(XrfError::new_invalid_error)?,
+      None => TranslationInterchangeFormat::from_path(output_file).ok_or_else(|| {
+        XrfError::new_invalid_error(format!(
+          "Cannot tell the format of '{}', pass --format",
+          output_file.display()
+        ))
+      })?,
+    };
+
+    let is_silent: bool = matches.get_flag("silent");
+    let is_verbose: bool = matches.get_flag("verbose");
+
+    let output: OutputOptions = TerminalOutput::from_options(is_silent, is_verbose);
+
+    let options: ProjectExportOptions = ProjectExportOptions {
+      format,
+      language: languages.select(language)?,
+      output,
+      output_file: output_file.clone(),
+    };
+
+    let result: ProjectExportResult = export_dir(path, &options)?;
+
+    xrf_output::info!(
+      options.output,
+      "Exported {} strings as {} in {}, {} translated, {} to review",
+      result.exported_count,
+      options.format,
+      xrf_utils::format_duration(result.duration),
+      result.translated_count,
+      result.stale_count
+    );
+
+    Ok(())
+  }
+}
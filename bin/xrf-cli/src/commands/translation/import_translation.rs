@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use xrf_output::OutputOptions;
+use xrf_translation::{ProjectImportOptions, ProjectImportResult, TranslationLanguages, import_file};
+
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
+use crate::core::command_error::CommandError;
+use crate::core::generic_command::{CommandResult, GenericCommand};
+use crate::core::output::TerminalOutput;
+
+#[derive(Default)]
+pub struct ImportTranslationCommand;
+
+impl GenericCommand for ImportTranslationCommand {
+  fn name(&self) -> &'static str {
+    "import-translation"
+  }
+
+  /// Create command for importing translated PO or XLIFF files.
+  fn init(&self) -> Command {
+    Command::new(self.name())
+      .about("Command to import a translated PO or XLIFF file into translation sources")
+      .arg(
+        Arg::new("path")
+          .help("Path to translation folder")
+          .short('p')
+          .long("path")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("input")
+          .help("Path to the translated PO or XLIFF file")
+          .short('i')
+          .long("input")
+          .required(true)
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(
+        Arg::new("language")
+          .help("Language the file translates into")
+          .short('l')
+          .long("language")
+          .required(true)
+          .value_parser(value_parser!(String)),
+      )
+      .arg(
+        Arg::new("strict")
+          .help("Fail with non 0 error code if any translation is refused")
+          .long("strict")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(languages_arg())
+      .arg(
+        Arg::new("silent")
+          .help("Disable any logging")
+          .short('s')
+          .long("silent")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("verbose")
+          .help("Turn on verbose logging")
+          .short('v')
+          .long("verbose")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+  }
+
+  fn execute(&self, matches: &ArgMatches) -> CommandResult {
+    let path: &PathBuf = matches
+      .get_one::<PathBuf>("path")
+      .expect("Expected valid path to be provided");
+
+    let input: &PathBuf = matches
+      .get_one::<PathBuf>("input")
+      .expect("Expected valid input path to be provided");
+
+    let language: &String = matches
+      .get_one::<String>("language")
+      .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
+
+    let is_silent: bool = matches.get_flag("silent");
+    let is_verbose: bool = matches.get_flag("verbose");
+    let is_strict: bool = matches.get_flag("strict");
+
+    let output: OutputOptions = TerminalOutput::from_options(is_silent, is_verbose);
+
+    let options: ProjectImportOptions = ProjectImportOptions {
+      output,
+      language: languages.get_single(language)?,
+      languages,
+    };
+
+    let result: ProjectImportResult = import_file(path, input, &options)?;
+
+    for finding in &result.findings {
+      xrf_output::warning!(
+        options.output,
+        "[{}] {}: {}",
+        finding.rule,
+        finding.subject.as_deref().unwrap_or_default(),
+        finding.message
+      );
+    }
+
+    xrf_output::info!(
+      options.output,
+      "Imported {} translations in {}, {} skipped, {} refused",
+      result.imported_count,
+      xrf_utils::format_duration(result.duration),
+      result.skipped_count,
+      result.rejected_count
+    );
+
+    if is_strict && result.rejected_count > 0 {
+      return Err(CommandError::new_check_failed(result.rejected_count as usize));
+    }
+
+    Ok(())
+  }
+}
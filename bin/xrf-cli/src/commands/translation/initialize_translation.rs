@@ -2,8 +2,11 @@ use std::path::PathBuf;
 
 use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
 use xrf_output::OutputOptions;
-use xrf_translation::{ProjectInitializeOptions, ProjectInitializeResult, initialize_dir, initialize_file};
+use xrf_translation::{
+  ProjectInitializeOptions, ProjectInitializeResult, TranslationLanguages, initialize_dir, initialize_file,
+};
 
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
 use crate::core::generic_command::{CommandResult, GenericCommand};
 use crate::core::output::TerminalOutput;
 
@@ -27,6 +30,16 @@ impl GenericCommand for InitializeTranslationCommand {
           .required(true)
           .value_parser(value_parser!(PathBuf)),
       )
+      .arg(
+        Arg::new("language")
+          .help("Language to add missing entries for")
+          .short('l')
+          .long("language")
+          .required(false)
+          .default_value("all")
+          .value_parser(value_parser!(String)),
+      )
+      .arg(languages_arg())
       .arg(
         Arg::new("silent")
           .help("Disable any logging")
@@ -50,6 +63,11 @@ impl GenericCommand for InitializeTranslationCommand {
       .get_one::<PathBuf>("path")
       .expect("Expected valid path to be provided");
 
+    let language: &String = matches
+      .get_one::<String>("language")
+      .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
+
     let is_silent: bool = matches.get_flag("silent");
     let is_verbose: bool = matches.get_flag("verbose");
 
@@ -60,6 +78,8 @@ impl GenericCommand for InitializeTranslationCommand {
     let options: ProjectInitializeOptions = ProjectInitializeOptions {
       output,
       path: path.clone(),
+      language: languages.select(language)?,
+      languages,
     };
 
     let result: ProjectInitializeResult = if path.is_dir() {
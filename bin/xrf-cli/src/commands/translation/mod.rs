@@ -1,4 +1,8 @@
 pub(crate) mod build_translation;
+pub(crate) mod coverage_translation;
+pub(crate) mod export_translation;
+pub(crate) mod import_translation;
 pub(crate) mod initialize_translation;
 pub(crate) mod parse_translation;
+pub(crate) mod translation_languages;
 pub(crate) mod verify_translation;
@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgMatches, value_parser};
+use xrf_error::XrfResult;
+use xrf_translation::TranslationLanguages;
+
+/// The `--languages` argument every translation command shares, so each accepts the same configuration.
+pub fn languages_arg() -> Arg {
+  Arg::new("languages")
+    .help("Path to a JSON list of languages adding to or replacing the shipped ones, kept outside the sources")
+    .long("languages")
+    .required(false)
+    .value_parser(value_parser!(PathBuf))
+}
+
+/// Reads the languages a command works with: the shipped ones, overridden by `--languages` when given.
+///
+/// # Errors
+///
+/// Returns an error when the configuration cannot be read or names a language that cannot be built.
+pub fn read_languages(matches: &ArgMatches) -> XrfResult<TranslationLanguages> {
+  match matches.get_one::<PathBuf>("languages") {
+    Some(path) => TranslationLanguages::read(path),
+    None => Ok(TranslationLanguages::default()),
+  }
+}
@@ -1,12 +1,15 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
 use xrf_error::{XrfError, XrfResult};
 use xrf_output::OutputOptions;
-use xrf_translation::{ProjectVerifyOptions, ProjectVerifyResult, TranslationLanguage, verify_dir, verify_file};
+use xrf_translation::{
+  ProjectVerifyOptions, ProjectVerifyResult, TranslationLanguages, read_color_definitions, verify_dir, verify_file,
+};
 
 use super::translation_verification_report::TranslationVerificationReportWriter;
+use crate::commands::translation::translation_languages::{languages_arg, read_languages};
 use crate::core::command_error::CommandError;
 use crate::core::generic_command::{CommandResult, GenericCommand};
 use crate::core::output::TerminalOutput;
@@ -56,6 +59,22 @@ impl GenericCommand for VerifyTranslationCommand {
           .required(false)
           .action(ArgAction::SetTrue),
       )
+      .arg(
+        Arg::new("markup")
+          .help("Check placeholders, colours and line breaks of translations against the English text")
+          .long("markup")
+          .required(false)
+          .action(ArgAction::SetTrue),
+      )
+      .arg(
+        Arg::new("colors")
+          .help("Path to the UI colour definitions colour tags are checked against, such as ui/color_defs.xml")
+          .long("colors")
+          .required(false)
+          .value_name("PATH")
+          .value_parser(value_parser!(PathBuf)),
+      )
+      .arg(languages_arg())
       .arg(
         Arg::new("silent")
           .help("Disable any logging")
@@ -82,11 +101,16 @@ impl GenericCommand for VerifyTranslationCommand {
     let language: &String = matches
       .get_one::<String>("language")
       .expect("Expected valid language for translation");
+    let languages: TranslationLanguages = read_languages(matches)?;
 
     let is_silent: bool = matches.get_flag("silent");
     let is_verbose: bool = matches.get_flag("verbose");
     let is_strict: bool = matches.get_flag("strict");
     let report_path: Option<PathBuf> = matches.get_one::<PathBuf>("report").cloned();
+    let color_ids: Option<HashSet<String>> = match matches.get_one::<PathBuf>("colors") {
+      Some(path) => Some(read_color_definitions(path)?),
+      None => None,
+    };
 
     let output: OutputOptions = TerminalOutput::from_options(is_silent, is_verbose);
 
@@ -101,7 +125,10 @@ impl GenericCommand for VerifyTranslationCommand {
       is_strict,
       output,
       path: path.clone(),
-      language: TranslationLanguage::from_str(language).map_err(XrfError::new_unknown_language_error)?,
+      language: languages.select(language)?,
+      languages,
+      is_checking_markup: matches.get_flag("markup") || color_ids.is_some(),
+      color_ids,
     };
 
     let verify_result: XrfResult<ProjectVerifyResult> = if path.is_dir() {
@@ -121,22 +148,33 @@ impl GenericCommand for VerifyTranslationCommand {
       }
     };
 
+    for finding in &result.markup_findings {
+      xrf_output::warning!(
+        options.output,
+        "[{}] {}: {}",
+        finding.rule,
+        finding.subject.as_deref().unwrap_or_default(),
+        finding.message
+      );
+    }
+
     if let Some(report_path) = report_path {
       TranslationVerificationReportWriter::new(&result).write(&report_path)?;
     }
 
     xrf_output::info!(
       options.output,
-      "Verified translation files in {}, {} checked, {} missing",
+      "Verified translation files in {}, {} checked, {} missing, {} markup issues",
       xrf_utils::format_duration(result.duration),
       result.checked_translations_count,
-      result.missing_translations_count
+      result.missing_translations_count,
+      result.markup_findings.len()
     );
 
-    if options.is_strict && result.missing_translations_count > 0 {
-      return Err(CommandError::new_check_failed(
-        result.missing_translations_count as usize,
-      ));
+    let failed_count: usize = result.missing_translations_count as usize + result.markup_findings.len();
+
+    if options.is_strict && failed_count > 0 {
+      return Err(CommandError::new_check_failed(failed_count));
     }
 
     Ok(())
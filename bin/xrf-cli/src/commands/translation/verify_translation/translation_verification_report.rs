@@ -2,7 +2,7 @@ use std::path::Path;
 
 use serde::Serialize;
 use xrf_report::{CheckReport, Finding, Report};
-use xrf_translation::ProjectVerifyResult;
+use xrf_translation::{ProjectVerifyResult, TranslationMarkupFinding};
 
 use crate::core::generic_command::CommandResult;
 
@@ -12,6 +12,7 @@ struct TranslationVerificationReportOutput {
   checked_translations_count: u32,
   checks: Vec<TranslationVerificationCheckOutput>,
   duration_ms: u64,
+  markup_findings: Vec<TranslationMarkupFindingOutput>,
   missing_translations_count: u32,
   status: String,
 }
@@ -33,6 +34,16 @@ struct TranslationVerificationFindingOutput {
   subject: Option<String>,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TranslationMarkupFindingOutput {
+  key: String,
+  language: String,
+  message: String,
+  rule_id: String,
+  subject: Option<String>,
+}
+
 pub struct TranslationVerificationReportWriter<'a> {
   result: &'a ProjectVerifyResult,
 }
@@ -59,6 +70,12 @@ impl<'a> TranslationVerificationReportWriter<'a> {
       checked_translations_count: self.result.checked_translations_count,
       checks,
       duration_ms: xrf_utils::duration_to_millis(self.result.duration),
+      markup_findings: self
+        .result
+        .markup_findings
+        .iter()
+        .map(Self::markup_finding_output)
+        .collect(),
       missing_translations_count: self.result.missing_translations_count,
       status: report.status().to_string(),
     }
@@ -73,6 +90,16 @@ impl<'a> TranslationVerificationReportWriter<'a> {
     }
   }
 
+  fn markup_finding_output(finding: &TranslationMarkupFinding) -> TranslationMarkupFindingOutput {
+    TranslationMarkupFindingOutput {
+      key: finding.key.clone(),
+      language: finding.language.clone(),
+      message: finding.message.clone(),
+      rule_id: finding.rule.clone(),
+      subject: finding.subject.clone(),
+    }
+  }
+
   fn finding_output(finding: &Finding) -> TranslationVerificationFindingOutput {
     TranslationVerificationFindingOutput {
       message: finding.message().to_string(),
@@ -88,12 +115,49 @@ mod tests {
   use std::path::PathBuf;
   use std::sync::atomic::{AtomicU64, Ordering};
 
-  use xrf_translation::{ProjectVerifyOptions, TranslationLanguage, verify_file};
+  use xrf_translation::{ProjectVerifyOptions, TranslationLanguages, verify_file};
 
   use super::TranslationVerificationReportWriter;
 
   static NEXT_TEST_DIRECTORY_ID: AtomicU64 = AtomicU64::new(0);
 
+  #[test]
+  fn writes_markup_findings_with_key_and_language() {
+    let unique: u64 = NEXT_TEST_DIRECTORY_ID.fetch_add(1, Ordering::Relaxed);
+    let root: PathBuf = std::env::temp_dir().join(format!(
+      "xrf-cli-translation-verification-report-test-{}-{unique}",
+      std::process::id()
+    ));
+    let translation_path: PathBuf = root.join("dialogs.json");
+    let report_path: PathBuf = root.join("report.json");
+    let languages: TranslationLanguages = TranslationLanguages::default();
+    let options: ProjectVerifyOptions = ProjectVerifyOptions {
+      is_strict: false,
+      output: xrf_output::OutputOptions::default(),
+      language: languages.select("ukr").unwrap(),
+      languages,
+      is_checking_markup: true,
+      color_ids: None,
+      path: root.clone(),
+    };
+
+    fs::create_dir_all(&root).unwrap();
+    fs::write(&translation_path, r#"{"st_take":{"eng":"Take %d","ukr":"Vzyaty"}}"#).unwrap();
+
+    let result = verify_file(&translation_path, &options).unwrap();
+
+    TranslationVerificationReportWriter::new(&result)
+      .write(&report_path)
+      .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&report_path).unwrap()).unwrap();
+
+    fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(json["markupFindings"][0]["ruleId"], "translations.markup-missing");
+    assert_eq!(json["markupFindings"][0]["key"], "st_take");
+    assert_eq!(json["markupFindings"][0]["language"], "ukr");
+  }
+
   #[test]
   fn writes_missing_translations_as_structured_findings() {
     let unique: u64 = NEXT_TEST_DIRECTORY_ID.fetch_add(1, Ordering::Relaxed);
@@ -103,10 +167,14 @@ mod tests {
     ));
     let translation_path: PathBuf = root.join("dialogs.json");
     let report_path: PathBuf = root.join("report.json");
+    let languages: TranslationLanguages = TranslationLanguages::default();
     let options: ProjectVerifyOptions = ProjectVerifyOptions {
       is_strict: false,
       output: xrf_output::OutputOptions::default(),
-      language: TranslationLanguage::Ukrainian,
+      language: languages.select("ukr").unwrap(),
+      languages,
+      is_checking_markup: false,
+      color_ids: None,
       path: root.clone(),
     };
 
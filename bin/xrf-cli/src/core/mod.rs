@@ -4,3 +4,4 @@ pub mod command_error;
 pub mod generic_command;
 pub mod logging;
 pub mod output;
+pub mod vfs_source;
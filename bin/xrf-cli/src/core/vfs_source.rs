@@ -0,0 +1,56 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Arg, ArgMatches};
+use xrf_error::{XrfError, XrfResult};
+use xrf_vfs::{XrayAsset, XrayMountMode, XrayVfs};
+
+/// The `--source` argument of commands reading assets through the vfs, read the way `list-assets` reads it.
+pub fn source_arg() -> Arg {
+  Arg::new("source")
+    .help(
+      "How to read the path: auto treats it as an installation only when it declares one, directory ignores any \
+       declaration, installation requires one, containing-installation searches parent directories for one",
+    )
+    .long("source")
+    .default_value("containing-installation")
+    .value_parser(["auto", "directory", "installation", "containing-installation"])
+}
+
+/// Mounts the installation or gamedata tree the assets are read from.
+///
+/// # Errors
+///
+/// Returns an error when installation metadata cannot be read, decoded, or parsed.
+pub fn open_source_vfs(path: &Path, matches: &ArgMatches) -> XrfResult<XrayVfs> {
+  let mode: XrayMountMode = XrayMountMode::try_from(
+    matches
+      .get_one::<String>("source")
+      .expect("Expected source mode to default")
+      .as_str(),
+  )?;
+
+  XrayVfs::from_plan(&mode.plan(path)?)
+}
+
+/// File an edited asset is written to: its logical path inside `destination`, or the loose file itself.
+///
+/// # Errors
+///
+/// Returns an invalid error for an archived asset without a destination to write it into.
+pub fn asset_write_target(asset: &XrayAsset, destination: Option<&Path>) -> XrfResult<PathBuf> {
+  match destination {
+    Some(destination) => Ok(
+      asset
+        .get_logical_path()
+        .as_str()
+        .split('\\')
+        .fold(destination.to_path_buf(), |target, component| target.join(component)),
+    ),
+    None => asset.to_physical_path().ok_or_else(|| {
+      XrfError::new_invalid_error(format!(
+        "Asset is archived in [{}], provide --dest to write it as a loose override",
+        asset.format_container()
+      ))
+    }),
+  }
+}
@@ -3,8 +3,12 @@ use crate::commands::archive::unpack_archive::UnpackArchiveCommand;
 use crate::commands::assets::list_assets::ListAssetsCommand;
 use crate::commands::docs::generate_docs::GenerateDocsCommand;
 use crate::commands::externs::export_externs::ExportExternsCommand;
+use crate::commands::gamedata::texture_usage::TextureUsageCommand;
 use crate::commands::gamedata::verify_gamedata::VerifyGamedataCommand;
+use crate::commands::ltx::diff_ltx::DiffLtxCommand;
+use crate::commands::ltx::export_ltx::ExportLtxCommand;
 use crate::commands::ltx::format_ltx::FormatLtxCommand;
+use crate::commands::ltx::import_ltx::ImportLtxCommand;
 use crate::commands::ltx::verify_ltx::VerifyLtxCommand;
 use crate::commands::ogf::info_ogf::InfoOgfCommand;
 use crate::commands::ogf::patch_ogf_motion_refs::PatchOgfMotionRefsCommand;
@@ -19,13 +23,19 @@ use crate::commands::particle::info_particles::InfoParticlesCommand;
 use crate::commands::particle::pack_particles::PackParticlesCommand;
 use crate::commands::particle::re_unpack_particles::ReUnpackParticlesCommand;
 use crate::commands::particle::repack_particles::RepackParticlesCommand;
+use crate::commands::particle::simulate_particles::SimulateParticlesCommand;
 use crate::commands::particle::unpack_particles::UnpackParticlesCommand;
 use crate::commands::particle::verify_particles::VerifyParticlesCommand;
+use crate::commands::sound::export_sound_parameters::ExportSoundParametersCommand;
+use crate::commands::sound::import_sound_parameters::ImportSoundParametersCommand;
+use crate::commands::sound::patch_sound::PatchSoundCommand;
 use crate::commands::spawn::info_spawn::InfoSpawnCommand;
 use crate::commands::spawn::pack_spawn::PackSpawnCommand;
 use crate::commands::spawn::repack_spawn::RepackSpawnCommand;
 use crate::commands::spawn::unpack_spawn::UnpackSpawnCommand;
 use crate::commands::spawn::verify_spawn::VerifySpawnCommand;
+use crate::commands::texture::build_texture_atlas::BuildTextureAtlasCommand;
+use crate::commands::texture::convert_texture::ConvertTextureCommand;
 use crate::commands::texture::crop_dds::CropDdsCommand;
 use crate::commands::texture::info_dds::InfoDdsCommand;
 use crate::commands::texture::pack_equipment_icons::PackEquipmentIconsCommand;
@@ -33,8 +43,14 @@ use crate::commands::texture::pack_texture_description::PackTextureDescriptionCo
 use crate::commands::texture::unpack_equipment_icons::UnpackEquipmentIconsCommand;
 use crate::commands::texture::unpack_texture_description::UnpackTextureDescriptionCommand;
 use crate::commands::texture::verify_equipment_icons::VerifyEquipmentIconsCommand;
+use crate::commands::thm::apply_thm_table::ApplyThmTableCommand;
+use crate::commands::thm::create_thm::CreateThmCommand;
+use crate::commands::thm::export_thm_table::ExportThmTableCommand;
 use crate::commands::thm::patch_thm_bump::PatchThmBumpCommand;
 use crate::commands::translation::build_translation::BuildTranslationCommand;
+use crate::commands::translation::coverage_translation::CoverageTranslationCommand;
+use crate::commands::translation::export_translation::ExportTranslationCommand;
+use crate::commands::translation::import_translation::ImportTranslationCommand;
 use crate::commands::translation::initialize_translation::InitializeTranslationCommand;
 use crate::commands::translation::parse_translation::ParseTranslationCommand;
 use crate::commands::translation::verify_translation::VerifyTranslationCommand;
@@ -60,11 +76,17 @@ pub fn setup_command_groups() -> Vec<CommandGroup> {
     },
     CommandGroup {
       name: "Gamedata",
-      commands: vec![VerifyGamedataCommand::new_box()],
+      commands: vec![TextureUsageCommand::new_box(), VerifyGamedataCommand::new_box()],
     },
     CommandGroup {
       name: "LTX",
-      commands: vec![FormatLtxCommand::new_box(), VerifyLtxCommand::new_box()],
+      commands: vec![
+        DiffLtxCommand::new_box(),
+        ExportLtxCommand::new_box(),
+        FormatLtxCommand::new_box(),
+        ImportLtxCommand::new_box(),
+        VerifyLtxCommand::new_box(),
+      ],
     },
     CommandGroup {
       name: "OGF",
@@ -92,10 +114,19 @@ pub fn setup_command_groups() -> Vec<CommandGroup> {
         PackParticlesCommand::new_box(),
         RepackParticlesCommand::new_box(),
         ReUnpackParticlesCommand::new_box(),
+        SimulateParticlesCommand::new_box(),
         UnpackParticlesCommand::new_box(),
         VerifyParticlesCommand::new_box(),
       ],
     },
+    CommandGroup {
+      name: "Sound",
+      commands: vec![
+        ExportSoundParametersCommand::new_box(),
+        ImportSoundParametersCommand::new_box(),
+        PatchSoundCommand::new_box(),
+      ],
+    },
     CommandGroup {
       name: "Spawn",
       commands: vec![
@@ -109,6 +140,8 @@ pub fn setup_command_groups() -> Vec<CommandGroup> {
     CommandGroup {
       name: "Texture",
       commands: vec![
+        BuildTextureAtlasCommand::new_box(),
+        ConvertTextureCommand::new_box(),
         CropDdsCommand::new_box(),
         InfoDdsCommand::new_box(),
         PackEquipmentIconsCommand::new_box(),
@@ -120,12 +153,20 @@ pub fn setup_command_groups() -> Vec<CommandGroup> {
     },
     CommandGroup {
       name: "THM",
-      commands: vec![PatchThmBumpCommand::new_box()],
+      commands: vec![
+        ApplyThmTableCommand::new_box(),
+        CreateThmCommand::new_box(),
+        ExportThmTableCommand::new_box(),
+        PatchThmBumpCommand::new_box(),
+      ],
     },
     CommandGroup {
       name: "Translation",
       commands: vec![
         BuildTranslationCommand::new_box(),
+        CoverageTranslationCommand::new_box(),
+        ExportTranslationCommand::new_box(),
+        ImportTranslationCommand::new_box(),
         InitializeTranslationCommand::new_box(),
         ParseTranslationCommand::new_box(),
         VerifyTranslationCommand::new_box(),
@@ -93,9 +93,12 @@ export class TranslationsService {
   /**
    * Reports the layout a directory looks like, so the open form can preselect it.
    */
-  public async detectMode(path: string): Promise<Nullable<TranslationProjectMode>> {
+  public async detectMode(
+    path: string,
+    languagesPath: Nullable<string> = null
+  ): Promise<Nullable<TranslationProjectMode>> {
     try {
-      return await translationsCommands.detectMode(path);
+      return await translationsCommands.detectMode(path, languagesPath);
     } catch (error) {
       this.log.warn("Could not detect translations layout:", error);
 
@@ -158,13 +161,21 @@ export class TranslationsService {
   }
 
   @BoundAction()
-  public async openProject(translationsPath: string, mode: TranslationProjectMode): Promise<void> {
+  public async openProject(
+    translationsPath: string,
+    mode: TranslationProjectMode,
+    languagesPath: Nullable<string> = null
+  ): Promise<void> {
     this.log.info("Opening translations project:", translationsPath, mode);
 
     try {
       this.project = createLoadable(null, true);
 
-      const response: TranslationProjectDescriptor = await translationsCommands.openProject(translationsPath, mode);
+      const response: TranslationProjectDescriptor = await translationsCommands.openProject(
+        translationsPath,
+        mode,
+        languagesPath
+      );
 
       this.log.info("Translations project opened:", Object.keys(response.files).length, "files");
 
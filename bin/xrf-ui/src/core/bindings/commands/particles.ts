@@ -0,0 +1,82 @@
+// Auto-generated rust bindings. Do not edit it manually.
+
+import { invoke as __TAURI_INVOKE } from "@tauri-apps/api/core";
+
+import { ParticlesFileSummary, ParticlesVerificationFinding } from "@/core/bindings/types/xrf-app";
+import { ParticleAction, ParticleEffect, ParticleGroup } from "@/core/bindings/types/xrf-db";
+
+/** Commands */
+export const particlesCommands = {
+  closeFile: () => __TAURI_INVOKE<null>("plugin:particles|close_file"),
+  getEffect: (name: string) => __TAURI_INVOKE<ParticleEffect>("plugin:particles|get_effect", { name }),
+  /** Names of effects and groups of the open particles file, or null when nothing is open. */
+  getFile: () =>
+    __TAURI_INVOKE<{
+      path: string | null;
+      version: number;
+      effects: Array<string>;
+      groups: Array<string>;
+    } | null>("plugin:particles|get_file"),
+  getGroup: (name: string) => __TAURI_INVOKE<ParticleGroup>("plugin:particles|get_group", { name }),
+  hasFile: () => __TAURI_INVOKE<boolean>("plugin:particles|has_file"),
+  /**
+   * Insert an action into a particle effect before provided index, or append it when the index is the action count.
+   *
+   * Undone with `remove_action` at the same index.
+   */
+  insertAction: (effect: string, index: number, action: ParticleAction) =>
+    __TAURI_INVOKE<null>("plugin:particles|insert_action", { effect, index, action }),
+  /**
+   * Read a packed particles file into the application session.
+   *
+   * Answers with the names of effects and groups rather than the whole file: the editor reads them one at a time, so
+   * serialising every action here only to have it re-requested is waste.
+   */
+  openFile: (path: string) => __TAURI_INVOKE<ParticlesFileSummary>("plugin:particles|open_file", { path }),
+  /**
+   * Remove an action from a particle effect and answer with it.
+   *
+   * Undone with `insert_action` of the returned action at the same index.
+   */
+  removeAction: (effect: string, index: number) =>
+    __TAURI_INVOKE<ParticleAction>("plugin:particles|remove_action", { effect, index }),
+  /**
+   * Write the open particles file, edits included, to provided path.
+   *
+   * The saved path becomes the one the session reports, same as a "save as" would.
+   */
+  saveFile: (path: string) => __TAURI_INVOKE<null>("plugin:particles|save_file", { path }),
+  /**
+   * Replace one action of a particle effect and answer with the action it replaced.
+   *
+   * Editing an action alone keeps every change small enough to undo on its own: the editor sends the returned action
+   * back to the same index rather than resubmitting the whole effect.
+   */
+  updateAction: (effect: string, index: number, action: ParticleAction) =>
+    __TAURI_INVOKE<ParticleAction>("plugin:particles|update_action", { effect, index, action }),
+  /**
+   * Replace the named particle effect and answer with what it was before.
+   *
+   * The previous effect travels back so the editor can undo by sending it with the new name. Renaming is allowed as
+   * long as no other effect holds the name and no group plays it already, and groups playing the effect follow it to
+   * the new name.
+   */
+  updateEffect: (name: string, effect: ParticleEffect) =>
+    __TAURI_INVOKE<ParticleEffect>("plugin:particles|update_effect", { name, effect }),
+  /**
+   * Replace the named particle group and answer with what it was before.
+   *
+   * Mirrors `update_effect`: the previous group is what the editor sends back to undo, and a rename must not collide with
+   * another group.
+   */
+  updateGroup: (name: string, group: ParticleGroup) =>
+    __TAURI_INVOKE<ParticleGroup>("plugin:particles|update_group", { name, group }),
+  /**
+   * Check the open particles file, edits included, against provided gamedata root.
+   *
+   * Runs the same particles verifier as gamedata verification, so the editor reports what a full check would without
+   * saving first. The file is checked as if it were the project's own particles library.
+   */
+  verifyFile: (gamedataPath: string) =>
+    __TAURI_INVOKE<Array<ParticlesVerificationFinding>>("plugin:particles|verify_file", { gamedataPath }),
+};
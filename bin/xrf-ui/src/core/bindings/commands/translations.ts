@@ -13,8 +13,13 @@ import {
 /** Commands */
 export const translationsCommands = {
   closeProject: () => __TAURI_INVOKE<null>("plugin:translations|close_project"),
-  /** Report which layout a directory looks like, for the open form to preselect. */
-  detectMode: (path: string) => __TAURI_INVOKE<TranslationProjectMode>("plugin:translations|detect_mode", { path }),
+  /**
+   * Report which layout a directory looks like, for the open form to preselect.
+   *
+   * `languages` is the path of a JSON language configuration adding to or replacing the shipped ones.
+   */
+  detectMode: (path: string, languages: string | null) =>
+    __TAURI_INVOKE<TranslationProjectMode>("plugin:translations|detect_mode", { path, languages }),
   getProject: () =>
     __TAURI_INVOKE<{
       mode: TranslationProjectMode;
@@ -31,8 +36,14 @@ export const translationsCommands = {
       files: { [key in string]: TranslationFile };
       findings: Array<TranslationFinding>;
     } | null>("plugin:translations|get_project"),
-  openProject: (path: string, mode: TranslationProjectMode) =>
-    __TAURI_INVOKE<TranslationProjectDescriptor>("plugin:translations|open_project", { path, mode }),
+  /**
+   * Read a translations root in the given layout.
+   *
+   * `languages` is the path of a JSON language configuration adding to or replacing the shipped ones,
+   * kept for the project so later saves resolve encodings the same way.
+   */
+  openProject: (path: string, mode: TranslationProjectMode, languages: string | null) =>
+    __TAURI_INVOKE<TranslationProjectDescriptor>("plugin:translations|open_project", { path, mode, languages }),
   /**
    * Write one logical file's pending edits, grouped by the language each belongs to.
    *
@@ -55,6 +55,27 @@ export type EquipmentSpriteMetadata = {
   equipmentDescriptors: Array<InventorySpriteDescriptor>;
 };
 
+/**
+ * What the open particles file holds, listed by name.
+ *
+ * Effects and groups are requested one at a time by the editor, so the listing is all that travels on open: a
+ * stock particles.xr serialises to megabytes of actions nobody is looking at yet.
+ */
+export type ParticlesFileSummary = {
+  path: string | null;
+  version: number;
+  effects: Array<string>;
+  groups: Array<string>;
+};
+
+/** Problem the gamedata particles verifier found in the open file. */
+export type ParticlesVerificationFinding = {
+  rule: string;
+  /** Asset the finding is about, such as a missing texture. */
+  subject: string | null;
+  message: string;
+};
+
 /**
  * What the viewer is showing, paired with where it came from.
  *
@@ -574,6 +574,449 @@ export type GraphVertex = {
   levelPointsCount: number;
 };
 
+export type ParticleAction =
+  | ({
+      type: "Avoid";
+    } & ParticleActionAvoid)
+  | ({
+      type: "Bounce";
+    } & ParticleActionBounce)
+  | ({
+      type: "CopyVertex";
+    } & ParticleActionCopyVertex)
+  | ({
+      type: "Damping";
+    } & ParticleActionDamping)
+  | ({
+      type: "Explosion";
+    } & ParticleActionExplosion)
+  | ({
+      type: "Follow";
+    } & ParticleActionFollow)
+  | ({
+      type: "Gravitate";
+    } & ParticleActionGravitate)
+  | ({
+      type: "Gravity";
+    } & ParticleActionGravity)
+  | ({
+      type: "Jet";
+    } & ParticleActionJet)
+  | ({
+      type: "KillOld";
+    } & ParticleActionKillOld)
+  | ({
+      type: "MatchVelocity";
+    } & ParticleActionMatchVelocity)
+  | ({
+      type: "Move";
+    } & ParticleActionMove)
+  | ({
+      type: "OrbitLine";
+    } & ParticleActionOrbitLine)
+  | ({
+      type: "OrbitPoint";
+    } & ParticleActionOrbitPoint)
+  | ({
+      type: "RandomAccel";
+    } & ParticleActionRandomAcceleration)
+  | ({
+      type: "RandomDisplace";
+    } & ParticleActionRandomDisplace)
+  | ({
+      type: "RandomVelocity";
+    } & ParticleActionRandomVelocity)
+  | ({
+      type: "Restore";
+    } & ParticleActionRestore)
+  | ({
+      type: "Sink";
+    } & ParticleActionSink)
+  | ({
+      type: "SinkVelocity";
+    } & ParticleActionSinkVelocity)
+  | ({
+      type: "Source";
+    } & ParticleActionSource)
+  | ({
+      type: "SpeedLimit";
+    } & ParticleActionSpeedLimit)
+  | ({
+      type: "TargetColor";
+    } & ParticleActionTargetColor)
+  | ({
+      type: "TargetSize";
+    } & ParticleActionTargetSize)
+  | ({
+      type: "TargetRotate";
+    } & ParticleActionTargetRotate)
+  | ({
+      type: "TargetVelocity";
+    } & ParticleActionTargetVelocity)
+  | ({
+      type: "Vortex";
+    } & ParticleActionVortex)
+  | ({
+      type: "Turbulence";
+    } & ParticleActionTurbulence)
+  | ({
+      type: "Scatter";
+    } & ParticleActionScatter);
+
+export type ParticleActionAvoid = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  position: ParticleDomain;
+  lookAhead: number | null;
+  magnitude: number | null;
+  epsilon: number | null;
+};
+
+export type ParticleActionBounce = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  position: ParticleDomain;
+  oneMinusFriction: number | null;
+  resilience: number | null;
+  cutoffSqr: number | null;
+};
+
+export type ParticleActionCopyVertex = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  copyPosition: number;
+};
+
+export type ParticleActionDamping = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  damping: Vector3d;
+  vLowSqr: number | null;
+  vHighSqr: number | null;
+};
+
+export type ParticleActionExplosion = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  center: Vector3d;
+  velocity: number | null;
+  magnitude: number | null;
+  stDev: number | null;
+  age: number | null;
+  epsilon: number | null;
+};
+
+export type ParticleActionFollow = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionGravitate = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionGravity = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  direction: Vector3d;
+};
+
+export type ParticleActionJet = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  center: Vector3d;
+  acc: ParticleDomain;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionKillOld = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  ageLimit: number | null;
+  killLessThan: number;
+};
+
+export type ParticleActionMatchVelocity = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionMove = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+};
+
+export type ParticleActionOrbitLine = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  position: Vector3d;
+  axis: Vector3d;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionOrbitPoint = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  center: Vector3d;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionRandomAcceleration = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  genAcc: ParticleDomain;
+};
+
+export type ParticleActionRandomDisplace = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  genDisp: ParticleDomain;
+};
+
+export type ParticleActionRandomVelocity = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  genVel: ParticleDomain;
+};
+
+export type ParticleActionRestore = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  timeLeft: number | null;
+};
+
+export type ParticleActionScatter = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  center: Vector3d;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleActionSink = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  killInside: number;
+  position: ParticleDomain;
+};
+
+export type ParticleActionSinkVelocity = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  killInside: number;
+  velocity: ParticleDomain;
+};
+
+export type ParticleActionSource = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  position: ParticleDomain;
+  velocity: ParticleDomain;
+  rot: ParticleDomain;
+  size: ParticleDomain;
+  color: ParticleDomain;
+  alpha: number | null;
+  particleRate: number | null;
+  age: number | null;
+  ageSigma: number | null;
+  parentVel: Vector3d;
+  parentMotion: number | null;
+};
+
+export type ParticleActionSpeedLimit = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  minSpeed: number | null;
+  maxSpeed: number | null;
+};
+
+export type ParticleActionTargetColor = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  color: Vector3d;
+  alpha: number | null;
+  scale: number | null;
+  timeFrom: number | null;
+  timeTo: number | null;
+};
+
+export type ParticleActionTargetRotate = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  rot: Vector3d;
+  scale: number | null;
+};
+
+export type ParticleActionTargetSize = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  size: Vector3d;
+  scale: Vector3d;
+};
+
+export type ParticleActionTargetVelocity = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  velocity: Vector3d;
+  scale: number | null;
+};
+
+export type ParticleActionTurbulence = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  frequency: number | null;
+  octaves: number;
+  magnitude: number | null;
+  epsilon: number | null;
+  offset: Vector3d;
+};
+
+export type ParticleActionType =
+  | "Avoid"
+  | "Bounce"
+  | "CallActionList"
+  | "CopyVertex"
+  | "Damping"
+  | "Explosion"
+  | "Follow"
+  | "Gravitate"
+  | "Gravity"
+  | "Jet"
+  | "KillOld"
+  | "MatchVelocity"
+  | "Move"
+  | "OrbitLine"
+  | "OrbitPoint"
+  | "RandomAccel"
+  | "RandomDisplace"
+  | "RandomVelocity"
+  | "Restore"
+  | "Sink"
+  | "SinkVelocity"
+  | "Source"
+  | "SpeedLimit"
+  | "TargetColor"
+  | "TargetSize"
+  | "TargetRotate"
+  | "TargetRotateD"
+  | "TargetVelocity"
+  | "TargetVelocityD"
+  | "Vortex"
+  | "Turbulence"
+  | "Scatter";
+
+export type ParticleActionVortex = {
+  actionFlags: number;
+  actionType: ParticleActionType;
+  center: Vector3d;
+  axis: Vector3d;
+  magnitude: number | null;
+  epsilon: number | null;
+  maxRadius: number | null;
+};
+
+export type ParticleDescription = {
+  creator: string;
+  editor: string;
+  createdTime: number;
+  editTime: number;
+};
+
+export type ParticleDomain = {
+  domainType: number;
+  coordinates: [Vector3d, Vector3d];
+  basis: [Vector3d, Vector3d];
+  radius1: number | null;
+  radius2: number | null;
+  radius1Sqr: number | null;
+  radius2Sqr: number | null;
+};
+
+/** C++ src/Layers/xrRender/ParticleEffectDef.cpp */
+export type ParticleEffect = {
+  version: number;
+  name: string;
+  maxParticles: number;
+  actions: Array<ParticleAction>;
+  flags: number;
+  frame: ParticleEffectFrame | null;
+  sprite: ParticleEffectSprite;
+  timeLimit: number | null;
+  collision: ParticleEffectCollision | null;
+  velocityScale: Vector3d | null;
+  description: ParticleDescription | null;
+  rotation: Vector3d | null;
+  editorData: ParticleEffectEditorData | null;
+};
+
+export type ParticleEffectCollision = {
+  collideOneMinusFriction: number | null;
+  collideResilience: number | null;
+  collideSqrCutoff: number | null;
+};
+
+export type ParticleEffectEditorData = {
+  value: Array<number>;
+};
+
+export type ParticleEffectFrame = {
+  textureSize: [number | null, number | null];
+  reserved: [number | null, number | null];
+  frameDimensionX: number;
+  frameCount: number;
+  frameSpeed: number | null;
+};
+
+export type ParticleEffectSprite = {
+  shaderName: string;
+  textureName: string;
+};
+
+export type ParticleGroup = {
+  version: number;
+  name: string;
+  flags: number;
+  timeLimit: number | null;
+  effects: Array<ParticleGroupEffect>;
+  description: ParticleDescription | null;
+  effectsOld: Array<ParticleGroupEffectOld> | null;
+};
+
+export type ParticleGroupEffect = {
+  name: string;
+  onPlayChildName: string;
+  onBirthChildName: string;
+  onDeadChildName: string;
+  time0: number | null;
+  time1: number | null;
+  flags: number;
+};
+
+export type ParticleGroupEffectOld = {
+  name: string;
+  onPlayChildName: string;
+  time0: number | null;
+  time1: number | null;
+  flags: number;
+};
+
 /**
  * Patrols list is represented by list of samples containing patrol chunk.
  * 0...N, where N is chunk.
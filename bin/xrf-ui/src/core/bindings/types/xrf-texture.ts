@@ -2,6 +2,10 @@
 
 export type InventorySpriteDescriptor = {
   section: string;
+  /**
+   * Texture reference of the atlas holding the icon, from `$inventory_icon_path`, `None` for the
+   * equipment sheet.
+   */
   customIcon: string | null;
   x: number;
   y: number;
@@ -9,6 +13,14 @@ export type InventorySpriteDescriptor = {
   h: number;
 };
 
+export type PackEquipmentAtlasResult = {
+  atlas: string | null;
+  savedAt: string;
+  savedWidth: number;
+  savedHeight: number;
+  packedCount: number;
+};
+
 export type PackEquipmentResult = {
   duration: number;
   savedAt: string;
@@ -16,4 +28,5 @@ export type PackEquipmentResult = {
   savedHeight: number;
   packedCount: number;
   skippedCount: number;
+  atlases: Array<PackEquipmentAtlasResult>;
 };
@@ -9,6 +9,7 @@ use crate::data::particles::particle_domain::ParticleDomain;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleActionBounce {
@@ -8,6 +8,7 @@ use crate::data::particles::particle_action_type::ParticleActionType;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleActionCopyVertex {
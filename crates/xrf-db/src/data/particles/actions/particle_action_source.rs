@@ -10,6 +10,7 @@ use crate::data::particles::particle_domain::ParticleDomain;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleActionSource {
@@ -28,6 +29,23 @@ pub struct ParticleActionSource {
   pub parent_motion: f32,
 }
 
+impl ParticleActionSource {
+  /// Generated positions and velocities follow the rotation of the emitter, shared by every action.
+  pub const FLAG_ALLOW_ROTATE: u32 = 1 << 1;
+  /// Sizes keep the first generated component on every axis.
+  pub const FLAG_SINGLE_SIZE: u32 = 1 << 29;
+  /// Source emits nothing, the engine sets it to stop a playing effect.
+  pub const FLAG_SILENT: u32 = 1 << 30;
+
+  pub fn is_single_size(&self) -> bool {
+    self.action_flags & Self::FLAG_SINGLE_SIZE != 0
+  }
+
+  pub fn is_silent(&self) -> bool {
+    self.action_flags & Self::FLAG_SILENT != 0
+  }
+}
+
 impl ChunkReadWrite for ParticleActionSource {
   fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<ParticleActionSource> {
     Ok(Self {
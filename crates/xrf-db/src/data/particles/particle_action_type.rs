@@ -7,6 +7,7 @@ use xrf_error::XrfResult;
 
 use crate::data::particles::particle_action::ParticleAction;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Copy, Clone, Debug, Enum, PartialEq, FromStr, Display, Serialize, Deserialize)]
 pub enum ParticleActionType {
   #[display("Avoid")]
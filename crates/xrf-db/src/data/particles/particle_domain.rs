@@ -8,6 +8,7 @@ use xrf_error::{XrfError, XrfResult};
 
 use crate::data::generic::vector_3d::Vector3d;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleDomain {
@@ -20,7 +20,8 @@ use crate::export::LtxImportExport;
 use crate::file_import::{read_ltx_field, read_ltx_optional_field};
 
 /// C++ src/Layers/xrRender/ParticleEffectDef.cpp
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleEffect {
   pub version: u16,
@@ -59,6 +60,55 @@ impl ParticleEffect {
   pub const EDITOR_DATA_CHUNK_ID: u32 = 36;
   pub const ROTATION_CHUNK_ID: u32 = 37;
 
+  /// Insert an action before provided index, or append it when the index is the action count.
+  pub fn insert_action(&mut self, index: usize, action: ParticleAction) -> XrfResult {
+    if index > self.actions.len() {
+      return Err(XrfError::new_invalid_error(format!(
+        "Cannot insert action {index} into particle effect '{}' with {} actions",
+        self.name,
+        self.actions.len()
+      )));
+    }
+
+    if self.actions.len() >= Self::EFFECT_ACTIONS_LIMIT {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle effect '{}' already has {} actions allowed",
+        self.name,
+        Self::EFFECT_ACTIONS_LIMIT
+      )));
+    }
+
+    self.actions.insert(index, action);
+
+    Ok(())
+  }
+
+  /// Replace an action and return the one it replaced.
+  pub fn update_action(&mut self, index: usize, action: ParticleAction) -> XrfResult<ParticleAction> {
+    let length: usize = self.actions.len();
+    let existing: &mut ParticleAction = self.actions.get_mut(index).ok_or_else(|| {
+      XrfError::new_not_found_error(format!(
+        "Particle effect '{}' has no action {index}, it has {length}",
+        self.name
+      ))
+    })?;
+
+    Ok(std::mem::replace(existing, action))
+  }
+
+  /// Remove an action and return it.
+  pub fn remove_action(&mut self, index: usize) -> XrfResult<ParticleAction> {
+    if index >= self.actions.len() {
+      return Err(XrfError::new_not_found_error(format!(
+        "Particle effect '{}' has no action {index}, it has {}",
+        self.name,
+        self.actions.len()
+      )));
+    }
+
+    Ok(self.actions.remove(index))
+  }
+
   fn get_action_section(section_name: &str, index: usize) -> String {
     format!("{section_name}.action.{index}")
   }
@@ -559,4 +609,66 @@ mod tests {
 
     Ok(())
   }
+
+  #[test]
+  fn test_insert_update_remove_actions() -> XrfResult {
+    let damping: ParticleAction = ParticleAction::Damping(Box::new(ParticleActionDamping {
+      action_flags: 0,
+      action_type: ParticleActionType::Damping,
+      damping: Vector3d::new_mock(),
+      v_low_sqr: 0.0,
+      v_high_sqr: 1.0,
+    }));
+    let copy_vertex: ParticleAction = ParticleAction::CopyVertex(Box::new(ParticleActionCopyVertex {
+      action_flags: 0,
+      action_type: ParticleActionType::CopyVertex,
+      copy_position: 1,
+    }));
+
+    let mut effect: ParticleEffect = ParticleEffect {
+      version: 1,
+      name: String::from("test-particle-effect"),
+      max_particles: 5,
+      actions: vec![damping.clone()],
+      flags: 0,
+      frame: None,
+      sprite: ParticleEffectSprite {
+        shader_name: String::from("test-shader-name"),
+        texture_name: String::from("test-texture-name"),
+      },
+      time_limit: None,
+      collision: None,
+      velocity_scale: None,
+      description: None,
+      rotation: None,
+      editor_data: None,
+    };
+
+    effect.insert_action(1, copy_vertex.clone())?;
+    effect.insert_action(0, copy_vertex.clone())?;
+
+    assert!(effect.insert_action(4, damping.clone()).is_err());
+    assert_eq!(
+      effect.actions,
+      vec![copy_vertex.clone(), damping.clone(), copy_vertex.clone()]
+    );
+
+    assert_eq!(effect.update_action(0, damping.clone())?, copy_vertex);
+    assert!(effect.update_action(3, damping.clone()).is_err());
+    assert_eq!(
+      effect.actions,
+      vec![damping.clone(), damping.clone(), copy_vertex.clone()]
+    );
+
+    assert_eq!(effect.remove_action(2)?, copy_vertex);
+    assert!(effect.remove_action(2).is_err());
+    assert_eq!(effect.actions, vec![damping.clone(), damping.clone()]);
+
+    effect.actions = vec![damping.clone(); ParticleEffect::EFFECT_ACTIONS_LIMIT];
+
+    assert!(effect.insert_action(0, copy_vertex).is_err());
+    assert_eq!(effect.actions.len(), ParticleEffect::EFFECT_ACTIONS_LIMIT);
+
+    Ok(())
+  }
 }
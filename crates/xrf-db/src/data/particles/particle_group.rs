@@ -15,7 +15,8 @@ use crate::data::particles::particle_group_effect_old::ParticleGroupEffectOld;
 use crate::export::LtxImportExport;
 use crate::file_import::read_ltx_field;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleGroup {
   pub version: u16,
@@ -40,6 +41,45 @@ impl ParticleGroup {
   pub const DESCRIPTION_CHUNK_ID: u32 = 6;
   pub const EFFECTS2_CHUNK_ID: u32 = 7;
 
+  /// Whether any effect or child effect of the group is played under provided name.
+  pub fn references_effect(&self, name: &str) -> bool {
+    self.effects.iter().any(|effect| {
+      effect.name == name
+        || effect.on_play_child_name == name
+        || effect.on_birth_child_name == name
+        || effect.on_dead_child_name == name
+    }) || self
+      .effects_old
+      .iter()
+      .flatten()
+      .any(|effect| effect.name == name || effect.on_play_child_name == name)
+  }
+
+  /// Point every effect and child effect played under provided name to another one, returning how many were renamed.
+  pub fn rename_effect(&mut self, from: &str, to: &str) -> usize {
+    let mut count: usize = 0;
+    let mut rename = |name: &mut String| {
+      if name == from {
+        *name = to.to_owned();
+        count += 1;
+      }
+    };
+
+    for effect in &mut self.effects {
+      rename(&mut effect.name);
+      rename(&mut effect.on_play_child_name);
+      rename(&mut effect.on_birth_child_name);
+      rename(&mut effect.on_dead_child_name);
+    }
+
+    for effect in self.effects_old.iter_mut().flatten() {
+      rename(&mut effect.name);
+      rename(&mut effect.on_play_child_name);
+    }
+
+    count
+  }
+
   fn get_description_section(section_name: &str) -> String {
     format!("{section_name}.description")
   }
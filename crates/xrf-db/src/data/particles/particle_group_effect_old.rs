@@ -8,6 +8,7 @@ use xrf_utils::{assert_equal, assert_length};
 use crate::constants::META_TYPE_FIELD;
 use crate::file_import::read_ltx_field;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticleGroupEffectOld {
@@ -57,6 +57,38 @@ pub use crate::data::{
     graph_level_point::GraphLevelPoint, graph_vertex::GraphVertex,
   },
   meta::cls_id::ClsId,
+  particles::{
+    actions::{
+      particle_action_avoid::ParticleActionAvoid, particle_action_bounce::ParticleActionBounce,
+      particle_action_copy_vertex::ParticleActionCopyVertex, particle_action_damping::ParticleActionDamping,
+      particle_action_explosion::ParticleActionExplosion, particle_action_follow::ParticleActionFollow,
+      particle_action_gravitate::ParticleActionGravitate, particle_action_gravity::ParticleActionGravity,
+      particle_action_jet::ParticleActionJet, particle_action_kill_old::ParticleActionKillOld,
+      particle_action_match_velocity::ParticleActionMatchVelocity, particle_action_move::ParticleActionMove,
+      particle_action_orbit_line::ParticleActionOrbitLine, particle_action_orbit_point::ParticleActionOrbitPoint,
+      particle_action_random_acceleration::ParticleActionRandomAcceleration,
+      particle_action_random_displace::ParticleActionRandomDisplace,
+      particle_action_random_velocity::ParticleActionRandomVelocity, particle_action_restore::ParticleActionRestore,
+      particle_action_scatter::ParticleActionScatter, particle_action_sink::ParticleActionSink,
+      particle_action_sink_velocity::ParticleActionSinkVelocity, particle_action_source::ParticleActionSource,
+      particle_action_speed_limit::ParticleActionSpeedLimit, particle_action_target_color::ParticleActionTargetColor,
+      particle_action_target_rotate::ParticleActionTargetRotate, particle_action_target_size::ParticleActionTargetSize,
+      particle_action_target_velocity::ParticleActionTargetVelocity,
+      particle_action_turbulence::ParticleActionTurbulence, particle_action_vortex::ParticleActionVortex,
+    },
+    particle_action::ParticleAction,
+    particle_action_type::ParticleActionType,
+    particle_domain::ParticleDomain,
+    particle_effect::ParticleEffect,
+    particle_effect_collision::ParticleEffectCollision,
+    particle_effect_description::ParticleDescription,
+    particle_effect_editor_data::ParticleEffectEditorData,
+    particle_effect_frame::ParticleEffectFrame,
+    particle_effect_sprite::ParticleEffectSprite,
+    particle_group::ParticleGroup,
+    particle_group_effect::ParticleGroupEffect,
+    particle_group_effect_old::ParticleGroupEffectOld,
+  },
   patrols::{patrol::Patrol, patrol_link::PatrolLink, patrol_point::PatrolPoint},
 };
 pub use crate::level::level_ai_file::*;
@@ -78,15 +110,26 @@ pub use crate::ogf::ogf_texture_refs_processor::*;
 pub use crate::omf::chunks::omf_motions_chunk::OmfMotionsChunk;
 pub use crate::omf::omf_file::*;
 pub use crate::omf::omf_motions_processor::*;
+pub use crate::particles::chunks::{
+  particles_effects_chunk::ParticlesEffectsChunk, particles_groups_chunk::ParticlesGroupsChunk,
+  particles_header_chunk::ParticlesHeaderChunk,
+};
 pub use crate::particles::particles_file::*;
+pub use crate::particles::simulation::particle_simulation::ParticleSimulation;
+pub use crate::particles::simulation::particle_simulation_options::*;
+pub use crate::particles::simulation::particle_simulation_result::*;
 pub use crate::shader_library::shader_library_file::*;
 pub use crate::spawn::chunks::{
   spawn_alife_spawns_chunk::SpawnALifeSpawnsChunk, spawn_artefact_spawns_chunk::SpawnArtefactSpawnsChunk,
   spawn_graphs_chunk::SpawnGraphsChunk, spawn_header_chunk::SpawnHeaderChunk, spawn_patrols_chunk::SpawnPatrolsChunk,
 };
 pub use crate::spawn::spawn_file::*;
-pub use crate::thm::chunks::thm_bump_chunk::*;
+pub use crate::thm::chunks::{
+  thm_bump_chunk::*, thm_detail_chunk::*, thm_fade_delay_chunk::*, thm_material_chunk::*, thm_normal_map_chunk::*,
+  thm_texture_params_chunk::*, thm_texture_type_chunk::*, thm_type_chunk::*, thm_version_chunk::*,
+};
 pub use crate::thm::thm_bump_patch_report::*;
 pub use crate::thm::thm_bump_processor::*;
 pub use crate::thm::thm_file::*;
+pub use crate::thm::thm_parameters_table::*;
 pub use crate::types::*;
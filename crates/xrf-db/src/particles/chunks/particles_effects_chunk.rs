@@ -12,7 +12,8 @@ use crate::constants::META_TYPE_FIELD;
 use crate::data::particles::particle_effect::ParticleEffect;
 use crate::export::{FileImportExport, LtxImportExport};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticlesEffectsChunk {
   pub effects: Vec<ParticleEffect>,
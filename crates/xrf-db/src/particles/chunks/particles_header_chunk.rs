@@ -11,6 +11,7 @@ use crate::constants::META_TYPE_FIELD;
 use crate::export::FileImportExport;
 use crate::file_import::read_ltx_field;
 
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticlesHeaderChunk {
@@ -1,2 +1,3 @@
 pub(crate) mod chunks;
 pub(crate) mod particles_file;
+pub(crate) mod simulation;
@@ -6,16 +6,19 @@ use std::path::Path;
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
 use xrf_chunk::{ChunkDataSource, ChunkReader, ChunkWriter, find_required_chunk_by_id};
-use xrf_error::XrfResult;
+use xrf_error::{XrfError, XrfResult};
 use xrf_utils::{assert, assert_length, open_export_file};
 
+use crate::data::particles::particle_effect::ParticleEffect;
+use crate::data::particles::particle_group::ParticleGroup;
 use crate::export::FileImportExport;
 use crate::particles::chunks::particles_effects_chunk::ParticlesEffectsChunk;
 use crate::particles::chunks::particles_firstgen_chunk::ParticlesFirstgenChunk;
 use crate::particles::chunks::particles_groups_chunk::ParticlesGroupsChunk;
 use crate::particles::chunks::particles_header_chunk::ParticlesHeaderChunk;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParticlesFile {
   pub header: ParticlesHeaderChunk,
@@ -89,6 +92,92 @@ impl ParticlesFile {
     Ok(())
   }
 
+  /// Find particle effect by its name.
+  pub fn find_effect(&self, name: &str) -> Option<&ParticleEffect> {
+    self.effects.effects.iter().find(|effect| effect.name == name)
+  }
+
+  /// Find particle effect by its name for editing.
+  pub fn find_effect_mut(&mut self, name: &str) -> Option<&mut ParticleEffect> {
+    self.effects.effects.iter_mut().find(|effect| effect.name == name)
+  }
+
+  /// Find particle group by its name.
+  pub fn find_group(&self, name: &str) -> Option<&ParticleGroup> {
+    self.groups.groups.iter().find(|group| group.name == name)
+  }
+
+  /// Find particle group by its name for editing.
+  pub fn find_group_mut(&mut self, name: &str) -> Option<&mut ParticleGroup> {
+    self.groups.groups.iter_mut().find(|group| group.name == name)
+  }
+
+  /// Replace the named particle effect and return what it was before.
+  ///
+  /// A rename must not collide with another effect, and groups playing the effect are pointed to its new name, so
+  /// sending the previous effect back under the new name undoes both. A rename to a name some group already plays is
+  /// refused as well, since undoing it could not tell those references from the renamed ones.
+  pub fn update_effect(&mut self, name: &str, effect: ParticleEffect) -> XrfResult<ParticleEffect> {
+    if effect.name != name && self.find_effect(&effect.name).is_some() {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle effect '{}' already exists",
+        effect.name
+      )));
+    }
+
+    if effect.name != name
+      && self
+        .groups
+        .groups
+        .iter()
+        .any(|group| group.references_effect(&effect.name))
+    {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle effect '{}' is already played by a group",
+        effect.name
+      )));
+    }
+
+    if effect.actions.len() > ParticleEffect::EFFECT_ACTIONS_LIMIT {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle effect '{}' has {} actions, more than {} allowed",
+        effect.name,
+        effect.actions.len(),
+        ParticleEffect::EFFECT_ACTIONS_LIMIT
+      )));
+    }
+
+    let renamed: Option<String> = (effect.name != name).then(|| effect.name.clone());
+    let existing: &mut ParticleEffect = self
+      .find_effect_mut(name)
+      .ok_or_else(|| XrfError::new_not_found_error(format!("Particle effect '{name}' is not in the file")))?;
+    let previous: ParticleEffect = std::mem::replace(existing, effect);
+
+    if let Some(renamed) = renamed {
+      for group in &mut self.groups.groups {
+        group.rename_effect(name, &renamed);
+      }
+    }
+
+    Ok(previous)
+  }
+
+  /// Replace the named particle group and return what it was before, a rename must not collide with another group.
+  pub fn update_group(&mut self, name: &str, group: ParticleGroup) -> XrfResult<ParticleGroup> {
+    if group.name != name && self.find_group(&group.name).is_some() {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle group '{}' already exists",
+        group.name
+      )));
+    }
+
+    let existing: &mut ParticleGroup = self
+      .find_group_mut(name)
+      .ok_or_else(|| XrfError::new_not_found_error(format!("Particle group '{name}' is not in the file")))?;
+
+    Ok(std::mem::replace(existing, group))
+  }
+
   /// Read spawn file from provided path.
   pub fn import_from_path<P: AsRef<Path>>(path: &P) -> XrfResult<Self> {
     log::info!("Importing particles file: {}", path.as_ref().display());
@@ -111,3 +200,180 @@ impl ParticlesFile {
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::data::particles::actions::particle_action_move::ParticleActionMove;
+  use crate::data::particles::particle_action::ParticleAction;
+  use crate::data::particles::particle_action_type::ParticleActionType;
+  use crate::data::particles::particle_effect::ParticleEffect;
+  use crate::data::particles::particle_effect_sprite::ParticleEffectSprite;
+  use crate::data::particles::particle_group::ParticleGroup;
+  use crate::data::particles::particle_group_effect::ParticleGroupEffect;
+  use crate::data::particles::particle_group_effect_old::ParticleGroupEffectOld;
+  use crate::particles::chunks::particles_effects_chunk::ParticlesEffectsChunk;
+  use crate::particles::chunks::particles_groups_chunk::ParticlesGroupsChunk;
+  use crate::particles::chunks::particles_header_chunk::ParticlesHeaderChunk;
+  use crate::particles::particles_file::ParticlesFile;
+
+  fn effect(name: &str) -> ParticleEffect {
+    ParticleEffect {
+      version: 1,
+      name: String::from(name),
+      max_particles: 5,
+      actions: Vec::new(),
+      flags: 0,
+      frame: None,
+      sprite: ParticleEffectSprite {
+        shader_name: String::from("particles\\add"),
+        texture_name: String::from("fx\\fx_spark"),
+      },
+      time_limit: None,
+      collision: None,
+      velocity_scale: None,
+      description: None,
+      rotation: None,
+      editor_data: None,
+    }
+  }
+
+  fn group_effect(name: &str, child_name: &str) -> ParticleGroupEffect {
+    ParticleGroupEffect {
+      name: String::from(name),
+      on_play_child_name: String::new(),
+      on_birth_child_name: String::from(child_name),
+      on_dead_child_name: String::from(child_name),
+      time_0: 0.0,
+      time_1: 1.0,
+      flags: 0,
+    }
+  }
+
+  fn file() -> ParticlesFile {
+    ParticlesFile {
+      header: ParticlesHeaderChunk { version: 1 },
+      effects: ParticlesEffectsChunk {
+        effects: vec![effect("fx\\spark"), effect("fx\\smoke")],
+      },
+      groups: ParticlesGroupsChunk {
+        groups: vec![ParticleGroup {
+          version: 3,
+          name: String::from("explosions\\grenade"),
+          flags: 0,
+          time_limit: 0.0,
+          effects: vec![
+            group_effect("fx\\spark", "fx\\smoke"),
+            group_effect("fx\\smoke", "fx\\spark"),
+          ],
+          description: None,
+          effects_old: Some(vec![ParticleGroupEffectOld {
+            name: String::from("fx\\spark"),
+            on_play_child_name: String::from("fx\\spark"),
+            time_0: 0.0,
+            time_1: 1.0,
+            flags: 0,
+          }]),
+        }],
+      },
+    }
+  }
+
+  fn group_effect_names(file: &ParticlesFile) -> Vec<[&str; 3]> {
+    file.groups.groups[0]
+      .effects
+      .iter()
+      .map(|it| {
+        [
+          it.name.as_str(),
+          it.on_birth_child_name.as_str(),
+          it.on_dead_child_name.as_str(),
+        ]
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_update_effect_renames_group_references() -> XrfResult {
+    let mut file: ParticlesFile = file();
+
+    let previous: ParticleEffect = file.update_effect("fx\\spark", effect("fx\\sparks"))?;
+
+    assert_eq!(previous.name, "fx\\spark");
+    assert!(file.find_effect("fx\\spark").is_none());
+    assert!(file.find_effect("fx\\sparks").is_some());
+    assert_eq!(
+      group_effect_names(&file),
+      vec![
+        ["fx\\sparks", "fx\\smoke", "fx\\smoke"],
+        ["fx\\smoke", "fx\\sparks", "fx\\sparks"]
+      ]
+    );
+
+    let effects_old: &ParticleGroupEffectOld = &file.groups.groups[0].effects_old.as_ref().unwrap()[0];
+
+    assert_eq!(effects_old.name, "fx\\sparks");
+    assert_eq!(effects_old.on_play_child_name, "fx\\sparks");
+
+    file.update_effect("fx\\sparks", previous)?;
+
+    assert_eq!(group_effect_names(&file), group_effect_names(&self::file()));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_update_effect_refuses_collisions_and_missing_effects() -> XrfResult {
+    let mut file: ParticlesFile = file();
+
+    assert!(file.update_effect("fx\\spark", effect("fx\\smoke")).is_err());
+    assert!(file.update_effect("fx\\fire", effect("fx\\fire")).is_err());
+
+    file.groups.groups[0].effects[0].on_play_child_name = String::from("fx\\fire");
+
+    assert!(file.update_effect("fx\\spark", effect("fx\\fire")).is_err());
+    assert!(file.find_effect("fx\\spark").is_some());
+
+    file.groups.groups[0].effects[0].on_play_child_name = String::new();
+
+    let mut oversized: ParticleEffect = effect("fx\\spark");
+
+    oversized.actions = vec![
+      ParticleAction::Move(Box::new(ParticleActionMove {
+        action_flags: 0,
+        action_type: ParticleActionType::Move,
+      }));
+      ParticleEffect::EFFECT_ACTIONS_LIMIT + 1
+    ];
+
+    assert!(file.update_effect("fx\\spark", oversized).is_err());
+
+    let mut edited: ParticleEffect = effect("fx\\spark");
+
+    edited.max_particles = 10;
+
+    assert_eq!(file.update_effect("fx\\spark", edited)?.max_particles, 5);
+    assert_eq!(file.find_effect("fx\\spark").unwrap().max_particles, 10);
+    assert_eq!(group_effect_names(&file), group_effect_names(&self::file()));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_update_group() -> XrfResult {
+    let mut file: ParticlesFile = file();
+    let mut renamed: ParticleGroup = file.groups.groups[0].clone();
+
+    renamed.name = String::from("explosions\\rgd5");
+
+    assert_eq!(
+      file.update_group("explosions\\grenade", renamed.clone())?.name,
+      "explosions\\grenade"
+    );
+    assert!(file.find_group("explosions\\rgd5").is_some());
+    assert!(file.update_group("explosions\\grenade", renamed).is_err());
+
+    Ok(())
+  }
+}
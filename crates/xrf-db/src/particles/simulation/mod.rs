@@ -0,0 +1,7 @@
+pub(crate) mod particle_simulation;
+pub(crate) mod particle_simulation_actions;
+pub(crate) mod particle_simulation_domain;
+pub(crate) mod particle_simulation_options;
+pub(crate) mod particle_simulation_random;
+pub(crate) mod particle_simulation_result;
+pub(crate) mod particle_simulation_vector;
@@ -0,0 +1,503 @@
+use xrf_error::{XrfError, XrfResult};
+
+use crate::data::particles::particle_action::ParticleAction;
+use crate::data::particles::particle_effect::ParticleEffect;
+use crate::particles::simulation::particle_simulation_options::ParticleSimulationOptions;
+use crate::particles::simulation::particle_simulation_random::ParticleSimulationRandom;
+use crate::particles::simulation::particle_simulation_result::{
+  ParticleSimulationFrame, ParticleSimulationParticle, ParticleSimulationResult,
+};
+use crate::particles::simulation::particle_simulation_vector::ParticleSimulationVector;
+
+/// Particle as the engine keeps it while an effect plays.
+#[derive(Clone, Debug)]
+pub(crate) struct ParticleSimulationState {
+  pub position: ParticleSimulationVector,
+  /// Position before the last move, which bounce and avoid test crossings against.
+  pub previous_position: ParticleSimulationVector,
+  /// Position the particle was born at, which restore returns it to.
+  pub origin: ParticleSimulationVector,
+  pub velocity: ParticleSimulationVector,
+  pub size: ParticleSimulationVector,
+  pub rotation: ParticleSimulationVector,
+  pub color: ParticleSimulationVector,
+  pub alpha: f32,
+  pub age: f32,
+}
+
+impl ParticleSimulationState {
+  fn to_particle(&self) -> ParticleSimulationParticle {
+    ParticleSimulationParticle {
+      position: self.position.to_array(),
+      velocity: self.velocity.to_array(),
+      size: self.size.to_array(),
+      rotation: self.rotation.to_array(),
+      color: [self.color.x, self.color.y, self.color.z, self.alpha],
+      age: self.age,
+    }
+  }
+}
+
+/// Headless player of a particle effect, stepping its action list on the CPU without the game.
+///
+/// Actions run in list order once per step, the way the engine runs them, over an emitter standing still at the
+/// origin. Anything needing the game world is left out: there is no level geometry to collide with and no parent to
+/// follow, so effect collision and `CopyVertex` do nothing. Stateful actions work on a copy of the effect, leaving the
+/// caller's one untouched.
+pub struct ParticleSimulation {
+  pub(crate) actions: Vec<ParticleAction>,
+  pub(crate) particles: Vec<ParticleSimulationState>,
+  pub(crate) random: ParticleSimulationRandom,
+  pub(crate) time: f32,
+  pub(crate) time_step: f32,
+  pub(crate) max_particles: usize,
+  /// Seconds after which sources stop emitting, when the effect is time limited.
+  pub(crate) time_limit: Option<f32>,
+  pub(crate) frame: usize,
+  pub(crate) born: usize,
+  pub(crate) died: usize,
+  /// Parts of the effect the run cannot reproduce.
+  pub(crate) warnings: Vec<String>,
+}
+
+impl ParticleSimulation {
+  pub fn new(effect: &ParticleEffect, options: &ParticleSimulationOptions) -> XrfResult<Self> {
+    if !options.time_step.is_finite() || options.time_step <= 0.0 {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle simulation time step must be positive, got {}",
+        options.time_step
+      )));
+    }
+
+    if !options.duration.is_finite() || options.duration < 0.0 {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle simulation duration must not be negative, got {}",
+        options.duration
+      )));
+    }
+
+    if options.get_frames_count() > ParticleSimulationOptions::FRAMES_LIMIT {
+      return Err(XrfError::new_invalid_error(format!(
+        "Particle simulation of {}s with {}s steps needs {} frames, more than {} allowed",
+        options.duration,
+        options.time_step,
+        options.get_frames_count(),
+        ParticleSimulationOptions::FRAMES_LIMIT
+      )));
+    }
+
+    let warnings: Vec<String> = effect
+      .actions
+      .iter()
+      .enumerate()
+      .flat_map(|(index, action)| match action {
+        ParticleAction::Source(action) => Self::get_source_warnings(index, action),
+        _ => Vec::new(),
+      })
+      .collect();
+
+    Ok(Self {
+      actions: effect.actions.clone(),
+      particles: Vec::new(),
+      random: ParticleSimulationRandom::new(options.seed),
+      time: 0.0,
+      time_step: options.time_step,
+      max_particles: effect.max_particles as usize,
+      time_limit: effect.time_limit.filter(|limit| *limit > 0.0),
+      frame: 0,
+      born: 0,
+      died: 0,
+      warnings,
+    })
+  }
+
+  /// Simulate provided effect from its first frame and record every frame of the run.
+  pub fn simulate(effect: &ParticleEffect, options: &ParticleSimulationOptions) -> XrfResult<ParticleSimulationResult> {
+    let mut simulation: Self = Self::new(effect, options)?;
+    let mut frames: Vec<ParticleSimulationFrame> = Vec::with_capacity(options.get_frames_count() + 1);
+
+    frames.push(simulation.get_frame());
+
+    for _ in 0..options.get_frames_count() {
+      frames.push(simulation.step());
+    }
+
+    let mut bounds: Option<(ParticleSimulationVector, ParticleSimulationVector)> = None;
+
+    for particle in frames.iter().flat_map(|frame| &frame.particles) {
+      let position: ParticleSimulationVector =
+        ParticleSimulationVector::new(particle.position[0], particle.position[1], particle.position[2]);
+
+      bounds = Some(match bounds {
+        Some((min, max)) => (min.min(&position), max.max(&position)),
+        None => (position, position),
+      });
+    }
+
+    Ok(ParticleSimulationResult {
+      effect: effect.name.clone(),
+      seed: options.seed,
+      time_step: options.time_step,
+      max_particles: effect.max_particles,
+      peak_count: frames.iter().map(|frame| frame.count).max().unwrap_or(0),
+      total_born: frames.iter().map(|frame| frame.born).sum(),
+      total_died: frames.iter().map(|frame| frame.died).sum(),
+      bounds: bounds.map(|(min, max)| (min.to_array(), max.to_array())),
+      frames,
+      warnings: simulation.warnings,
+    })
+  }
+
+  /// Advance the effect by one time step and describe the frame it ends with.
+  pub fn step(&mut self) -> ParticleSimulationFrame {
+    self.born = 0;
+    self.died = 0;
+
+    let mut actions: Vec<ParticleAction> = std::mem::take(&mut self.actions);
+
+    for action in &mut actions {
+      self.execute_action(action);
+    }
+
+    self.actions = actions;
+    self.frame += 1;
+    // Derived rather than accumulated, so long runs do not drift away from the frame they are at.
+    self.time = self.frame as f32 * self.time_step;
+
+    self.get_frame()
+  }
+
+  /// Describe the particles alive now.
+  pub fn get_frame(&self) -> ParticleSimulationFrame {
+    ParticleSimulationFrame {
+      index: self.frame,
+      time: self.time,
+      count: self.particles.len(),
+      born: self.born,
+      died: self.died,
+      particles: self
+        .particles
+        .iter()
+        .map(ParticleSimulationState::to_particle)
+        .collect(),
+    }
+  }
+
+  /// Whether sources still emit, which a time limited effect stops doing once its time is up.
+  pub fn is_emitting(&self) -> bool {
+    self.time_limit.is_none_or(|limit| self.time < limit)
+  }
+
+  /// Add a particle, unless the effect already has as many as its budget allows.
+  pub(crate) fn spawn_particle(&mut self, particle: ParticleSimulationState) -> bool {
+    if self.particles.len() >= self.max_particles {
+      return false;
+    }
+
+    self.particles.push(particle);
+    self.born += 1;
+
+    true
+  }
+
+  /// Drop every particle the predicate selects, keeping the order of the rest.
+  pub(crate) fn kill_particles<F>(&mut self, mut predicate: F)
+  where
+    F: FnMut(&ParticleSimulationState, &mut ParticleSimulationRandom) -> bool,
+  {
+    let count: usize = self.particles.len();
+    let random: &mut ParticleSimulationRandom = &mut self.random;
+
+    self.particles.retain(|particle| !predicate(particle, random));
+    self.died += count - self.particles.len();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::particles::actions::particle_action_gravity::ParticleActionGravity;
+  use crate::data::particles::actions::particle_action_kill_old::ParticleActionKillOld;
+  use crate::data::particles::actions::particle_action_move::ParticleActionMove;
+  use crate::data::particles::actions::particle_action_source::ParticleActionSource;
+  use crate::data::particles::particle_action::ParticleAction;
+  use crate::data::particles::particle_action_type::ParticleActionType;
+  use crate::data::particles::particle_domain::ParticleDomain;
+  use crate::data::particles::particle_effect::ParticleEffect;
+  use crate::data::particles::particle_effect_sprite::ParticleEffectSprite;
+  use crate::particles::simulation::particle_simulation::ParticleSimulation;
+  use crate::particles::simulation::particle_simulation_domain::ParticleSimulationDomain;
+  use crate::particles::simulation::particle_simulation_options::ParticleSimulationOptions;
+  use crate::particles::simulation::particle_simulation_result::ParticleSimulationResult;
+
+  fn point(x: f32, y: f32, z: f32) -> ParticleDomain {
+    ParticleDomain {
+      domain_type: ParticleSimulationDomain::POINT,
+      coordinates: (Vector3d::new(x, y, z), Vector3d::new(0.0, 0.0, 0.0)),
+      basis: (Vector3d::new(0.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 0.0)),
+      radius1: 0.0,
+      radius2: 0.0,
+      radius1_sqr: 0.0,
+      radius2_sqr: 0.0,
+    }
+  }
+
+  fn sphere(radius: f32) -> ParticleDomain {
+    ParticleDomain {
+      domain_type: ParticleSimulationDomain::SPHERE,
+      radius1: radius,
+      radius1_sqr: radius * radius,
+      ..point(0.0, 0.0, 0.0)
+    }
+  }
+
+  fn source(particle_rate: f32) -> ParticleAction {
+    ParticleAction::Source(Box::new(ParticleActionSource {
+      action_flags: 0,
+      action_type: ParticleActionType::Source,
+      position: sphere(0.5),
+      velocity: sphere(2.0),
+      rot: point(0.0, 0.0, 0.0),
+      size: point(0.1, 0.1, 0.1),
+      color: point(1.0, 0.5, 0.25),
+      alpha: 1.0,
+      particle_rate,
+      age: 0.0,
+      age_sigma: 0.0,
+      parent_vel: Vector3d::new(0.0, 0.0, 0.0),
+      parent_motion: 0.0,
+    }))
+  }
+
+  fn effect(max_particles: u32, actions: Vec<ParticleAction>) -> ParticleEffect {
+    ParticleEffect {
+      version: 1,
+      name: String::from("test\\simulated"),
+      max_particles,
+      actions,
+      flags: 0,
+      frame: None,
+      sprite: ParticleEffectSprite {
+        shader_name: String::from("particles\\add"),
+        texture_name: String::from("fx\\fx_spark"),
+      },
+      time_limit: None,
+      collision: None,
+      velocity_scale: None,
+      description: None,
+      rotation: None,
+      editor_data: None,
+    }
+  }
+
+  fn movement() -> ParticleAction {
+    ParticleAction::Move(Box::new(ParticleActionMove {
+      action_flags: 0,
+      action_type: ParticleActionType::Move,
+    }))
+  }
+
+  #[test]
+  fn keeps_particle_count_within_budget() -> XrfResult {
+    let effect: ParticleEffect = effect(25, vec![source(1000.0), movement()]);
+    let result: ParticleSimulationResult =
+      ParticleSimulation::simulate(&effect, &ParticleSimulationOptions::default())?;
+
+    assert_eq!(result.frames.len(), 61);
+    assert_eq!(result.peak_count, 25);
+    assert_eq!(result.total_born, 25);
+    assert!(result.frames.iter().all(|frame| frame.count <= 25));
+    assert_eq!(result.frames[0].count, 0);
+    assert_eq!(result.frames[60].particles[0].color, [1.0, 0.5, 0.25, 1.0]);
+
+    Ok(())
+  }
+
+  #[test]
+  fn replays_same_run_for_same_seed() -> XrfResult {
+    let effect: ParticleEffect = effect(100, vec![source(45.0), movement()]);
+    let options: ParticleSimulationOptions = ParticleSimulationOptions {
+      seed: 12,
+      ..Default::default()
+    };
+
+    let first: ParticleSimulationResult = ParticleSimulation::simulate(&effect, &options)?;
+
+    assert_eq!(first, ParticleSimulation::simulate(&effect, &options)?);
+    assert_ne!(
+      first.frames,
+      ParticleSimulation::simulate(&effect, &ParticleSimulationOptions { seed: 13, ..options })?.frames
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn ages_particles_only_when_they_move() -> XrfResult {
+    let options: ParticleSimulationOptions = ParticleSimulationOptions::default();
+    let still: ParticleSimulationResult = ParticleSimulation::simulate(&effect(100, vec![source(60.0)]), &options)?;
+    let moving: ParticleSimulationResult =
+      ParticleSimulation::simulate(&effect(100, vec![source(60.0), movement()]), &options)?;
+
+    assert!(still.frames[60].particles.iter().all(|particle| particle.age == 0.0));
+    assert!(
+      moving
+        .frames
+        .iter()
+        .flat_map(|frame| &frame.particles)
+        .all(|particle| particle.age >= options.time_step)
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn kills_particles_past_their_lifetime() -> XrfResult {
+    let kill_old: ParticleAction = ParticleAction::KillOld(Box::new(ParticleActionKillOld {
+      action_flags: 0,
+      action_type: ParticleActionType::KillOld,
+      age_limit: 0.5,
+      kill_less_than: 0,
+    }));
+
+    let options: ParticleSimulationOptions = ParticleSimulationOptions::default();
+    let effect: ParticleEffect = effect(1000, vec![source(60.0), kill_old, movement()]);
+    let result: ParticleSimulationResult = ParticleSimulation::simulate(&effect, &options)?;
+
+    assert!(result.total_died > 0);
+    assert_eq!(result.total_born - result.total_died, result.frames[60].count);
+    // Moving after the kill ages survivors by one more step, they are killed on the next frame.
+    assert!(
+      result
+        .frames
+        .iter()
+        .flat_map(|frame| &frame.particles)
+        .all(|particle| particle.age < 0.5 + options.time_step)
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn moves_particles_by_gravity_and_stops_emitting_after_time_limit() -> XrfResult {
+    let gravity: ParticleAction = ParticleAction::Gravity(Box::new(ParticleActionGravity {
+      action_flags: 0,
+      action_type: ParticleActionType::Gravity,
+      direction: Vector3d::new(0.0, -9.8, 0.0),
+    }));
+
+    let mut effect: ParticleEffect = effect(1000, vec![source(30.0), gravity, movement()]);
+
+    effect.time_limit = Some(1.0);
+
+    let result: ParticleSimulationResult =
+      ParticleSimulation::simulate(&effect, &ParticleSimulationOptions::default())?;
+    let (min, max): ([f32; 3], [f32; 3]) = result.bounds.expect("Particles to be emitted");
+
+    assert!(min[1] < -5.0, "Expected particles to fall, lowest is {}", min[1]);
+    assert!(max[1] < 2.5);
+    assert!(result.frames[31..].iter().all(|frame| frame.born == 0));
+    assert!(result.frames[..31].iter().any(|frame| frame.born > 0));
+
+    Ok(())
+  }
+
+  #[test]
+  fn gives_single_size_sources_equal_sizes_on_every_axis() -> XrfResult {
+    let mut source: ParticleAction = source(60.0);
+
+    if let ParticleAction::Source(source) = &mut source {
+      source.action_flags = ParticleActionSource::FLAG_SINGLE_SIZE;
+      source.size = ParticleDomain {
+        domain_type: ParticleSimulationDomain::BOX,
+        coordinates: (Vector3d::new(0.1, 0.5, 1.0), Vector3d::new(0.2, 0.75, 2.0)),
+        ..point(0.0, 0.0, 0.0)
+      };
+    }
+
+    let result: ParticleSimulationResult =
+      ParticleSimulation::simulate(&effect(100, vec![source]), &ParticleSimulationOptions::default())?;
+    let sizes: Vec<[f32; 3]> = result.frames[60]
+      .particles
+      .iter()
+      .map(|particle| particle.size)
+      .collect();
+
+    assert!(!sizes.is_empty());
+    assert!(
+      sizes
+        .iter()
+        .all(|size| size[0] == size[1] && size[0] == size[2] && (0.1..=0.2).contains(&size[0]))
+    );
+    assert!(result.warnings.is_empty());
+
+    Ok(())
+  }
+
+  #[test]
+  fn keeps_silent_sources_quiet_and_warns_about_unknown_flags() -> XrfResult {
+    let mut silent: ParticleAction = source(60.0);
+    let mut flagged: ParticleAction = source(60.0);
+
+    if let ParticleAction::Source(source) = &mut silent {
+      source.action_flags = ParticleActionSource::FLAG_SILENT | ParticleActionSource::FLAG_ALLOW_ROTATE;
+    }
+
+    let result: ParticleSimulationResult =
+      ParticleSimulation::simulate(&effect(100, vec![silent]), &ParticleSimulationOptions::default())?;
+
+    assert_eq!(result.total_born, 0);
+    assert!(result.warnings.is_empty());
+
+    if let ParticleAction::Source(source) = &mut flagged {
+      source.action_flags = 1 | 1 << 4;
+    }
+
+    let result: ParticleSimulationResult = ParticleSimulation::simulate(
+      &effect(100, vec![movement(), flagged]),
+      &ParticleSimulationOptions::default(),
+    )?;
+
+    assert!(result.total_born > 0);
+    assert_eq!(
+      result.warnings,
+      vec![
+        String::from("Source action 1 has unknown flag 0x00000001, simulated without it"),
+        String::from("Source action 1 has unknown flag 0x00000010, simulated without it"),
+      ]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn rejects_steps_that_cannot_advance() {
+    let effect: ParticleEffect = effect(10, vec![movement()]);
+
+    for time_step in [0.0, -1.0, f32::NAN] {
+      assert!(
+        ParticleSimulation::new(
+          &effect,
+          &ParticleSimulationOptions {
+            time_step,
+            ..Default::default()
+          }
+        )
+        .is_err()
+      );
+    }
+
+    assert!(
+      ParticleSimulation::new(
+        &effect,
+        &ParticleSimulationOptions {
+          duration: 1_000_000.0,
+          ..Default::default()
+        }
+      )
+      .is_err()
+    );
+  }
+}
@@ -0,0 +1,543 @@
+use std::f32::consts::TAU;
+
+use crate::data::particles::actions::particle_action_avoid::ParticleActionAvoid;
+use crate::data::particles::actions::particle_action_bounce::ParticleActionBounce;
+use crate::data::particles::actions::particle_action_damping::ParticleActionDamping;
+use crate::data::particles::actions::particle_action_explosion::ParticleActionExplosion;
+use crate::data::particles::actions::particle_action_jet::ParticleActionJet;
+use crate::data::particles::actions::particle_action_kill_old::ParticleActionKillOld;
+use crate::data::particles::actions::particle_action_restore::ParticleActionRestore;
+use crate::data::particles::actions::particle_action_source::ParticleActionSource;
+use crate::data::particles::actions::particle_action_target_color::ParticleActionTargetColor;
+use crate::data::particles::actions::particle_action_turbulence::ParticleActionTurbulence;
+use crate::data::particles::actions::particle_action_vortex::ParticleActionVortex;
+use crate::data::particles::particle_action::ParticleAction;
+use crate::data::particles::particle_domain::ParticleDomain;
+use crate::particles::simulation::particle_simulation::{ParticleSimulation, ParticleSimulationState};
+use crate::particles::simulation::particle_simulation_domain::ParticleSimulationDomain;
+use crate::particles::simulation::particle_simulation_vector::ParticleSimulationVector;
+
+/// Effect of each action on the particles, after `ParticleActions.cpp` of the engine particle library.
+impl ParticleSimulation {
+  pub(crate) fn execute_action(&mut self, action: &mut ParticleAction) {
+    let dt: f32 = self.time_step;
+
+    match action {
+      ParticleAction::Avoid(action) => self.execute_avoid(action),
+      ParticleAction::Bounce(action) => self.execute_bounce(action),
+      // Copies the emitter's vertices into particles, and a headless run has no emitter mesh.
+      ParticleAction::CopyVertex(_) => {}
+      ParticleAction::Damping(action) => self.execute_damping(action),
+      ParticleAction::Explosion(action) => self.execute_explosion(action),
+      ParticleAction::Follow(action) => {
+        self.execute_towards_neighbours(action.magnitude, action.epsilon, action.max_radius, true, false)
+      }
+      ParticleAction::Gravitate(action) => {
+        self.execute_towards_neighbours(action.magnitude, action.epsilon, action.max_radius, false, false)
+      }
+      ParticleAction::Gravity(action) => {
+        let acceleration: ParticleSimulationVector = ParticleSimulationVector::from(&action.direction) * dt;
+
+        for particle in &mut self.particles {
+          particle.velocity += acceleration;
+        }
+      }
+      ParticleAction::Jet(action) => self.execute_jet(action),
+      ParticleAction::KillOld(action) => self.execute_kill_old(action),
+      ParticleAction::MatchVelocity(action) => {
+        self.execute_towards_neighbours(action.magnitude, action.epsilon, action.max_radius, false, true)
+      }
+      // Particles age as they move, so ages compared by later actions of the frame are the ones moved with.
+      ParticleAction::Move(_) => {
+        for particle in &mut self.particles {
+          particle.age += dt;
+          particle.previous_position = particle.position;
+          particle.position += particle.velocity * dt;
+        }
+      }
+      ParticleAction::OrbitLine(action) => {
+        let position: ParticleSimulationVector = ParticleSimulationVector::from(&action.position);
+        let axis: ParticleSimulationVector = ParticleSimulationVector::from(&action.axis).normalize();
+
+        self.execute_pull(action.magnitude, action.epsilon, action.max_radius, |particle| {
+          let offset: ParticleSimulationVector = particle.position - position;
+
+          axis * offset.dot(&axis) - offset
+        });
+      }
+      ParticleAction::OrbitPoint(action) => {
+        let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.center);
+
+        self.execute_pull(action.magnitude, action.epsilon, action.max_radius, |particle| {
+          center - particle.position
+        });
+      }
+      ParticleAction::RandomAccel(action) => {
+        for index in 0..self.particles.len() {
+          let acceleration: ParticleSimulationVector = self.generate(&action.gen_acc);
+
+          self.particles[index].velocity += acceleration * dt;
+        }
+      }
+      ParticleAction::RandomDisplace(action) => {
+        for index in 0..self.particles.len() {
+          let displacement: ParticleSimulationVector = self.generate(&action.gen_disp);
+
+          self.particles[index].position += displacement * dt;
+        }
+      }
+      ParticleAction::RandomVelocity(action) => {
+        for index in 0..self.particles.len() {
+          self.particles[index].velocity = self.generate(&action.gen_vel);
+        }
+      }
+      ParticleAction::Restore(action) => self.execute_restore(action),
+      ParticleAction::Sink(action) => {
+        let domain: ParticleSimulationDomain = ParticleSimulationDomain::new(&action.position);
+        let kill_inside: bool = action.kill_inside != 0;
+
+        self.kill_particles(|particle, random| domain.contains(&particle.position, random) == kill_inside);
+      }
+      ParticleAction::SinkVelocity(action) => {
+        let domain: ParticleSimulationDomain = ParticleSimulationDomain::new(&action.velocity);
+        let kill_inside: bool = action.kill_inside != 0;
+
+        self.kill_particles(|particle, random| domain.contains(&particle.velocity, random) == kill_inside);
+      }
+      ParticleAction::Source(action) => self.execute_source(action),
+      ParticleAction::SpeedLimit(action) => {
+        for particle in &mut self.particles {
+          let speed: f32 = particle.velocity.length();
+
+          if speed > f32::EPSILON && (speed < action.min_speed || speed > action.max_speed) {
+            particle.velocity = particle.velocity * (speed.clamp(action.min_speed, action.max_speed) / speed);
+          }
+        }
+      }
+      ParticleAction::TargetColor(action) => self.execute_target_color(action),
+      ParticleAction::TargetSize(action) => {
+        let size: ParticleSimulationVector = ParticleSimulationVector::from(&action.size);
+        let scale: ParticleSimulationVector = ParticleSimulationVector::from(&action.scale) * dt;
+
+        for particle in &mut self.particles {
+          particle.size += (size - particle.size).scale(&scale);
+        }
+      }
+      ParticleAction::TargetRotate(action) => {
+        let rotation: ParticleSimulationVector = ParticleSimulationVector::from(&action.rot);
+        let scale: f32 = action.scale * dt;
+
+        for particle in &mut self.particles {
+          particle.rotation += (rotation - particle.rotation) * scale;
+        }
+      }
+      ParticleAction::TargetVelocity(action) => {
+        let velocity: ParticleSimulationVector = ParticleSimulationVector::from(&action.velocity);
+        let scale: f32 = action.scale * dt;
+
+        for particle in &mut self.particles {
+          particle.velocity += (velocity - particle.velocity) * scale;
+        }
+      }
+      ParticleAction::Vortex(action) => self.execute_vortex(action),
+      ParticleAction::Turbulence(action) => self.execute_turbulence(action),
+      ParticleAction::Scatter(action) => {
+        let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.center);
+
+        self.execute_pull(action.magnitude, action.epsilon, action.max_radius, |particle| {
+          particle.position - center
+        });
+      }
+    }
+  }
+
+  /// Describe source flags the simulator does not act on, one warning per flag.
+  ///
+  /// Rotating with the emitter is honoured as is, since the emitter never turns.
+  pub(crate) fn get_source_warnings(index: usize, action: &ParticleActionSource) -> Vec<String> {
+    let known: u32 = ParticleActionSource::FLAG_ALLOW_ROTATE
+      | ParticleActionSource::FLAG_SINGLE_SIZE
+      | ParticleActionSource::FLAG_SILENT;
+
+    (0..u32::BITS)
+      .map(|bit| 1 << bit)
+      .filter(|flag| action.action_flags & flag != 0 && known & flag == 0)
+      .map(|flag| format!("Source action {index} has unknown flag {flag:#010x}, simulated without it"))
+      .collect()
+  }
+
+  fn generate(&mut self, domain: &ParticleDomain) -> ParticleSimulationVector {
+    ParticleSimulationDomain::new(domain).generate(&mut self.random)
+  }
+
+  fn execute_source(&mut self, action: &ParticleActionSource) {
+    if action.is_silent() || !self.is_emitting() {
+      return;
+    }
+
+    // Fractions of a particle are emitted with matching probability, so low rates still average out right.
+    let rate: f32 = (action.particle_rate * self.time_step).max(0.0);
+    let mut count: usize = rate.floor() as usize;
+
+    if self.random.next_f32() < rate.fract() {
+      count += 1;
+    }
+
+    let parent_velocity: ParticleSimulationVector =
+      ParticleSimulationVector::from(&action.parent_vel) * action.parent_motion;
+
+    for _ in 0..count {
+      let position: ParticleSimulationVector = self.generate(&action.position);
+      let velocity: ParticleSimulationVector = self.generate(&action.velocity) + parent_velocity;
+      let rotation: ParticleSimulationVector = self.generate(&action.rot);
+      let mut size: ParticleSimulationVector = self.generate(&action.size);
+
+      if action.is_single_size() {
+        size = ParticleSimulationVector::new(size.x, size.x, size.x);
+      }
+
+      let color: ParticleSimulationVector = self.generate(&action.color);
+      let age: f32 = action.age + self.random.next_normal(action.age_sigma);
+
+      let is_spawned: bool = self.spawn_particle(ParticleSimulationState {
+        position,
+        previous_position: position,
+        origin: position,
+        velocity,
+        size,
+        rotation,
+        color,
+        alpha: action.alpha,
+        age,
+      });
+
+      if !is_spawned {
+        break;
+      }
+    }
+  }
+
+  fn execute_kill_old(&mut self, action: &ParticleActionKillOld) {
+    let kill_less_than: bool = action.kill_less_than != 0;
+    let age_limit: f32 = action.age_limit;
+
+    self.kill_particles(|particle, _| (particle.age < age_limit) == kill_less_than);
+  }
+
+  fn execute_damping(&mut self, action: &ParticleActionDamping) {
+    let dt: f32 = self.time_step;
+    let scale: ParticleSimulationVector = ParticleSimulationVector::new(
+      1.0 - (1.0 - action.damping.x) * dt,
+      1.0 - (1.0 - action.damping.y) * dt,
+      1.0 - (1.0 - action.damping.z) * dt,
+    );
+
+    for particle in &mut self.particles {
+      let speed_sqr: f32 = particle.velocity.length_sqr();
+
+      if speed_sqr >= action.v_low_sqr && speed_sqr <= action.v_high_sqr {
+        particle.velocity = particle.velocity.scale(&scale);
+      }
+    }
+  }
+
+  /// Push particles near a shell expanding from the center, strongest where the shell currently is.
+  fn execute_explosion(&mut self, action: &mut ParticleActionExplosion) {
+    let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.center);
+    let radius: f32 = action.velocity * action.age;
+    let magnitude: f32 = action.magnitude * self.time_step;
+
+    if action.st_dev > 0.0 {
+      let one_over_sigma: f32 = 1.0 / action.st_dev;
+      let inner_exponent: f32 = -0.5 * one_over_sigma * one_over_sigma;
+      let outer_exponent: f32 = one_over_sigma / TAU.sqrt();
+
+      for particle in &mut self.particles {
+        let direction: ParticleSimulationVector = particle.position - center;
+        let distance: f32 = direction.length();
+        let shell_distance: f32 = distance - radius;
+        let density: f32 = (shell_distance * shell_distance * inner_exponent).exp() * outer_exponent;
+
+        particle.velocity += direction * (density * magnitude / (distance * distance * distance + action.epsilon));
+      }
+    }
+
+    action.age += self.time_step;
+  }
+
+  /// Accelerate particles towards others within reach: the next one in the list when following, every other one when
+  /// gravitating, and towards their velocities rather than positions when matching.
+  fn execute_towards_neighbours(
+    &mut self,
+    magnitude: f32,
+    epsilon: f32,
+    max_radius: f32,
+    is_following: bool,
+    is_matching_velocity: bool,
+  ) {
+    let magnitude: f32 = magnitude * self.time_step;
+    let max_radius_sqr: f32 = max_radius * max_radius;
+    let snapshot: Vec<ParticleSimulationState> = self.particles.clone();
+
+    for (index, particle) in self.particles.iter_mut().enumerate() {
+      let neighbours: Box<dyn Iterator<Item = (usize, &ParticleSimulationState)>> = if is_following {
+        Box::new(snapshot.iter().enumerate().skip(index + 1).take(1))
+      } else {
+        Box::new(snapshot.iter().enumerate())
+      };
+
+      for (other_index, other) in neighbours {
+        if other_index == index {
+          continue;
+        }
+
+        let towards: ParticleSimulationVector = other.position - particle.position;
+        let distance_sqr: f32 = towards.length_sqr();
+
+        if distance_sqr >= max_radius_sqr {
+          continue;
+        }
+
+        if is_matching_velocity {
+          particle.velocity += (other.velocity - particle.velocity) * (magnitude / (distance_sqr + epsilon));
+        } else {
+          particle.velocity += towards * (magnitude / (distance_sqr.sqrt() * (distance_sqr + epsilon)));
+        }
+      }
+    }
+  }
+
+  /// Accelerate particles within reach along the vector provided for each, weakening with the square of its length.
+  fn execute_pull<F>(&mut self, magnitude: f32, epsilon: f32, max_radius: f32, direction: F)
+  where
+    F: Fn(&ParticleSimulationState) -> ParticleSimulationVector,
+  {
+    let magnitude: f32 = magnitude * self.time_step;
+    let max_radius_sqr: f32 = max_radius * max_radius;
+
+    for particle in &mut self.particles {
+      let towards: ParticleSimulationVector = direction(particle);
+      let distance_sqr: f32 = towards.length_sqr();
+
+      if distance_sqr < max_radius_sqr {
+        particle.velocity += towards * (magnitude / (distance_sqr.sqrt() * (distance_sqr + epsilon)));
+      }
+    }
+  }
+
+  fn execute_jet(&mut self, action: &ParticleActionJet) {
+    let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.center);
+    let magnitude: f32 = action.magnitude * self.time_step;
+    let max_radius_sqr: f32 = action.max_radius * action.max_radius;
+
+    for index in 0..self.particles.len() {
+      let distance_sqr: f32 = (self.particles[index].position - center).length_sqr();
+
+      if distance_sqr < max_radius_sqr {
+        let acceleration: ParticleSimulationVector = self.generate(&action.acc);
+
+        self.particles[index].velocity += acceleration * (magnitude / (distance_sqr + action.epsilon));
+      }
+    }
+  }
+
+  /// Swirl particles around an axis through the center, within reach of it.
+  fn execute_vortex(&mut self, action: &ParticleActionVortex) {
+    let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.center);
+    let axis: ParticleSimulationVector = ParticleSimulationVector::from(&action.axis).normalize();
+    let magnitude: f32 = action.magnitude * self.time_step;
+    let max_radius_sqr: f32 = action.max_radius * action.max_radius;
+
+    for particle in &mut self.particles {
+      let offset: ParticleSimulationVector = particle.position - center;
+      let radial: ParticleSimulationVector = offset - axis * offset.dot(&axis);
+      let distance_sqr: f32 = radial.length_sqr();
+
+      if distance_sqr < max_radius_sqr && distance_sqr > f32::EPSILON {
+        particle.velocity += axis.cross(&radial).normalize() * (magnitude / (distance_sqr + action.epsilon));
+      }
+    }
+  }
+
+  /// Accelerate particles along the gradient of fractal noise drifting with the offset over time.
+  fn execute_turbulence(&mut self, action: &ParticleActionTurbulence) {
+    if action.epsilon <= 0.0 {
+      return;
+    }
+
+    let magnitude: f32 = action.magnitude * self.time_step / action.epsilon;
+    let drift: ParticleSimulationVector = ParticleSimulationVector::from(&action.offset) * self.time;
+    let octaves: u32 = action.octaves.max(1) as u32;
+
+    for particle in &mut self.particles {
+      let sample: ParticleSimulationVector = particle.position + drift;
+      let noise = |offset: ParticleSimulationVector| fractal_noise(sample + offset, action.frequency, octaves);
+      let base: f32 = noise(ParticleSimulationVector::ZERO);
+      let gradient: ParticleSimulationVector = ParticleSimulationVector::new(
+        noise(ParticleSimulationVector::new(action.epsilon, 0.0, 0.0)) - base,
+        noise(ParticleSimulationVector::new(0.0, action.epsilon, 0.0)) - base,
+        noise(ParticleSimulationVector::new(0.0, 0.0, action.epsilon)) - base,
+      );
+
+      particle.velocity += gradient * magnitude;
+    }
+  }
+
+  fn execute_target_color(&mut self, action: &ParticleActionTargetColor) {
+    let color: ParticleSimulationVector = ParticleSimulationVector::from(&action.color);
+    let scale: f32 = action.scale * self.time_step;
+    // Older formats store no window, leaving both ends at zero: then the target applies at every age.
+    let is_windowed: bool = action.time_to > action.time_from;
+
+    for particle in &mut self.particles {
+      if is_windowed && (particle.age < action.time_from || particle.age > action.time_to) {
+        continue;
+      }
+
+      particle.color += (color - particle.color) * scale;
+      particle.alpha += (action.alpha - particle.alpha) * scale;
+    }
+  }
+
+  /// Bring particles back to where they were born by the time the action runs out.
+  fn execute_restore(&mut self, action: &mut ParticleActionRestore) {
+    let time_left: f32 = action.time_left;
+
+    for particle in &mut self.particles {
+      if time_left <= self.time_step {
+        particle.position = particle.origin;
+        particle.velocity = ParticleSimulationVector::ZERO;
+      } else {
+        particle.velocity = (particle.origin - particle.position) * (1.0 / time_left);
+      }
+    }
+
+    action.time_left = (time_left - self.time_step).max(0.0);
+  }
+
+  /// Bounce particles whose last move crossed the surface of a plane-like or sphere domain.
+  fn execute_bounce(&mut self, action: &ParticleActionBounce) {
+    let domain: ParticleSimulationDomain = ParticleSimulationDomain::new(&action.position);
+    let dt: f32 = self.time_step;
+
+    for particle in &mut self.particles {
+      let next: ParticleSimulationVector = particle.position + particle.velocity * dt;
+
+      let normal: Option<ParticleSimulationVector> = match action.position.domain_type {
+        ParticleSimulationDomain::SPHERE => {
+          let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.position.coordinates.0);
+          let radius_sqr: f32 = action.position.radius1 * action.position.radius1;
+          let is_entering: bool =
+            (particle.position - center).length_sqr() > radius_sqr && (next - center).length_sqr() <= radius_sqr;
+
+          is_entering.then(|| domain.surface_normal(&particle.position)).flatten()
+        }
+        ParticleSimulationDomain::PLANE
+        | ParticleSimulationDomain::TRIANGLE
+        | ParticleSimulationDomain::RECTANGLE
+        | ParticleSimulationDomain::DISC => {
+          let is_crossing: bool =
+            domain.plane_distance(&particle.position) >= 0.0 && domain.plane_distance(&next) < 0.0;
+
+          is_crossing.then(|| domain.surface_normal(&particle.position)).flatten()
+        }
+        _ => None,
+      };
+
+      if let Some(normal) = normal {
+        let normal_velocity: ParticleSimulationVector = normal * particle.velocity.dot(&normal);
+        let mut tangent_velocity: ParticleSimulationVector = particle.velocity - normal_velocity;
+
+        if tangent_velocity.length_sqr() > action.cutoff_sqr {
+          tangent_velocity = tangent_velocity * action.one_minus_friction;
+        }
+
+        particle.velocity = tangent_velocity - normal_velocity * action.resilience;
+      }
+    }
+  }
+
+  /// Steer particles heading into a plane-like or sphere domain within the look ahead time away from it.
+  fn execute_avoid(&mut self, action: &ParticleActionAvoid) {
+    let domain: ParticleSimulationDomain = ParticleSimulationDomain::new(&action.position);
+    let magnitude: f32 = action.magnitude * self.time_step;
+
+    for particle in &mut self.particles {
+      let ahead: ParticleSimulationVector = particle.position + particle.velocity * action.look_ahead;
+
+      let (normal, distance): (ParticleSimulationVector, f32) = match action.position.domain_type {
+        ParticleSimulationDomain::SPHERE => {
+          let center: ParticleSimulationVector = ParticleSimulationVector::from(&action.position.coordinates.0);
+
+          if (ahead - center).length() > action.position.radius1 {
+            continue;
+          }
+
+          (
+            (particle.position - center).normalize(),
+            ((particle.position - center).length() - action.position.radius1).max(0.0),
+          )
+        }
+        ParticleSimulationDomain::PLANE
+        | ParticleSimulationDomain::TRIANGLE
+        | ParticleSimulationDomain::RECTANGLE
+        | ParticleSimulationDomain::DISC => {
+          let distance: f32 = domain.plane_distance(&particle.position);
+
+          if distance < 0.0 || domain.plane_distance(&ahead) >= 0.0 {
+            continue;
+          }
+
+          (domain.surface_normal(&particle.position).unwrap_or_default(), distance)
+        }
+        _ => continue,
+      };
+
+      particle.velocity += normal * (magnitude / (distance * distance + action.epsilon));
+    }
+  }
+}
+
+/// Sum of value noise octaves at provided point, each doubling the frequency and halving the amplitude.
+fn fractal_noise(point: ParticleSimulationVector, frequency: f32, octaves: u32) -> f32 {
+  let mut sum: f32 = 0.0;
+  let mut scale: f32 = frequency;
+  let mut amplitude: f32 = 1.0;
+
+  for _ in 0..octaves {
+    sum += value_noise(point * scale) * amplitude;
+    scale *= 2.0;
+    amplitude *= 0.5;
+  }
+
+  sum
+}
+
+/// Smoothly interpolated noise in `[-1, 1]` over an integer lattice of hashed values.
+fn value_noise(point: ParticleSimulationVector) -> f32 {
+  let floor: [f32; 3] = [point.x.floor(), point.y.floor(), point.z.floor()];
+  let cell: [i32; 3] = floor.map(|value| value as i32);
+  let fraction: [f32; 3] = [point.x - floor[0], point.y - floor[1], point.z - floor[2]];
+  let weight: [f32; 3] = fraction.map(|value| value * value * (3.0 - 2.0 * value));
+
+  let corner = |dx: i32, dy: i32, dz: i32| lattice_value(cell[0] + dx, cell[1] + dy, cell[2] + dz);
+  let lerp = |from: f32, to: f32, weight: f32| from + (to - from) * weight;
+
+  let x00: f32 = lerp(corner(0, 0, 0), corner(1, 0, 0), weight[0]);
+  let x10: f32 = lerp(corner(0, 1, 0), corner(1, 1, 0), weight[0]);
+  let x01: f32 = lerp(corner(0, 0, 1), corner(1, 0, 1), weight[0]);
+  let x11: f32 = lerp(corner(0, 1, 1), corner(1, 1, 1), weight[0]);
+
+  lerp(lerp(x00, x10, weight[1]), lerp(x01, x11, weight[1]), weight[2])
+}
+
+fn lattice_value(x: i32, y: i32, z: i32) -> f32 {
+  let mut hash: u32 =
+    (x as u32).wrapping_mul(0x8DA6_B343) ^ (y as u32).wrapping_mul(0xD816_3841) ^ (z as u32).wrapping_mul(0xCB1A_B31F);
+
+  hash = (hash ^ (hash >> 15)).wrapping_mul(0x2C1B_3C6D);
+  hash = (hash ^ (hash >> 12)).wrapping_mul(0x297A_2D39);
+  hash ^= hash >> 15;
+
+  (hash as f32 / u32::MAX as f32) * 2.0 - 1.0
+}
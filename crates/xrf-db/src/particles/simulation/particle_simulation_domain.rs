@@ -0,0 +1,292 @@
+use std::f32::consts::TAU;
+
+use crate::data::particles::particle_domain::ParticleDomain;
+use crate::particles::simulation::particle_simulation_random::ParticleSimulationRandom;
+use crate::particles::simulation::particle_simulation_vector::ParticleSimulationVector;
+
+/// Sampling and containment tests of a stored particle domain, after `pDomain` of the engine particle library.
+///
+/// Domains are stored as the engine builds them: `coordinates` hold its `p1`/`p2`, `basis` its `u`/`v`, so the
+/// meaning of each depends on the domain type rather than being the values an artist typed in.
+pub(crate) struct ParticleSimulationDomain<'a> {
+  domain: &'a ParticleDomain,
+}
+
+impl<'a> ParticleSimulationDomain<'a> {
+  pub const POINT: u32 = 0;
+  pub const LINE: u32 = 1;
+  pub const TRIANGLE: u32 = 2;
+  pub const PLANE: u32 = 3;
+  pub const BOX: u32 = 4;
+  pub const SPHERE: u32 = 5;
+  pub const CYLINDER: u32 = 6;
+  pub const CONE: u32 = 7;
+  pub const BLOB: u32 = 8;
+  pub const DISC: u32 = 9;
+  pub const RECTANGLE: u32 = 10;
+
+  pub fn new(domain: &'a ParticleDomain) -> Self {
+    Self { domain }
+  }
+
+  fn p1(&self) -> ParticleSimulationVector {
+    ParticleSimulationVector::from(&self.domain.coordinates.0)
+  }
+
+  fn p2(&self) -> ParticleSimulationVector {
+    ParticleSimulationVector::from(&self.domain.coordinates.1)
+  }
+
+  fn u(&self) -> ParticleSimulationVector {
+    ParticleSimulationVector::from(&self.domain.basis.0)
+  }
+
+  fn v(&self) -> ParticleSimulationVector {
+    ParticleSimulationVector::from(&self.domain.basis.1)
+  }
+
+  /// Random point of the domain. Unknown domain types yield their first point, as a point domain would.
+  pub fn generate(&self, random: &mut ParticleSimulationRandom) -> ParticleSimulationVector {
+    let p1: ParticleSimulationVector = self.p1();
+    let radius1: f32 = self.domain.radius1;
+    let radius2: f32 = self.domain.radius2;
+
+    match self.domain.domain_type {
+      Self::LINE => p1 + self.p2() * random.next_f32(),
+      Self::TRIANGLE => {
+        let (first, second): (f32, f32) = (random.next_f32(), random.next_f32());
+
+        if first + second < 1.0 {
+          p1 + self.u() * first + self.v() * second
+        } else {
+          p1 + self.u() * (1.0 - first) + self.v() * (1.0 - second)
+        }
+      }
+      Self::RECTANGLE => p1 + self.u() * random.next_f32() + self.v() * random.next_f32(),
+      Self::BOX => {
+        let random_scale: ParticleSimulationVector =
+          ParticleSimulationVector::new(random.next_f32(), random.next_f32(), random.next_f32());
+
+        p1 + (self.p2() - p1).scale(&random_scale)
+      }
+      Self::SPHERE => {
+        let direction: ParticleSimulationVector = ParticleSimulationVector::new(
+          random.next_f32() - 0.5,
+          random.next_f32() - 0.5,
+          random.next_f32() - 0.5,
+        )
+        .normalize();
+
+        let radius: f32 = if radius1 == radius2 {
+          radius1
+        } else {
+          radius2 + random.next_f32() * (radius1 - radius2)
+        };
+
+        p1 + direction * radius
+      }
+      Self::CYLINDER | Self::CONE => {
+        let distance: f32 = random.next_f32();
+        let theta: f32 = random.next_f32() * TAU;
+        let mut radius: f32 = radius2 + random.next_f32() * (radius1 - radius2);
+
+        if self.domain.domain_type == Self::CONE {
+          radius *= distance;
+        }
+
+        p1 + self.p2() * distance + self.u() * (radius * theta.cos()) + self.v() * (radius * theta.sin())
+      }
+      Self::BLOB => {
+        p1 + ParticleSimulationVector::new(
+          random.next_normal(radius1),
+          random.next_normal(radius1),
+          random.next_normal(radius1),
+        )
+      }
+      Self::DISC => {
+        let theta: f32 = random.next_f32() * TAU;
+        let radius: f32 = radius2 + random.next_f32() * (radius1 - radius2);
+
+        p1 + self.u() * (radius * theta.cos()) + self.v() * (radius * theta.sin())
+      }
+      _ => p1,
+    }
+  }
+
+  /// Whether provided point is inside the domain.
+  ///
+  /// Domains without volume contain nothing. A blob contains a point with the probability its gaussian gives, which is
+  /// why the random source is needed.
+  pub fn contains(&self, position: &ParticleSimulationVector, random: &mut ParticleSimulationRandom) -> bool {
+    let p1: ParticleSimulationVector = self.p1();
+
+    match self.domain.domain_type {
+      Self::PLANE => position.dot(&self.p2()) >= -self.domain.radius1,
+      Self::BOX => {
+        let p2: ParticleSimulationVector = self.p2();
+
+        position.x >= p1.x
+          && position.x <= p2.x
+          && position.y >= p1.y
+          && position.y <= p2.y
+          && position.z >= p1.z
+          && position.z <= p2.z
+      }
+      Self::SPHERE => {
+        let distance_sqr: f32 = (*position - p1).length_sqr();
+
+        distance_sqr <= self.domain.radius1 * self.domain.radius1
+          && distance_sqr >= self.domain.radius2 * self.domain.radius2
+      }
+      Self::CYLINDER | Self::CONE => {
+        let axis: ParticleSimulationVector = self.p2();
+        let axis_length_sqr: f32 = axis.length_sqr();
+
+        if axis_length_sqr <= f32::EPSILON {
+          return false;
+        }
+
+        let offset: ParticleSimulationVector = *position - p1;
+        let distance: f32 = offset.dot(&axis) / axis_length_sqr;
+
+        if !(0.0..=1.0).contains(&distance) {
+          return false;
+        }
+
+        let radial_sqr: f32 = (offset - axis * distance).length_sqr();
+        let scale: f32 = if self.domain.domain_type == Self::CONE {
+          distance
+        } else {
+          1.0
+        };
+
+        radial_sqr <= (self.domain.radius1 * scale).powi(2) && radial_sqr >= (self.domain.radius2 * scale).powi(2)
+      }
+      Self::BLOB => {
+        if self.domain.radius1 <= 0.0 {
+          return false;
+        }
+
+        let one_over_sigma: f32 = 1.0 / self.domain.radius1;
+        let density: f32 =
+          (-0.5 * (*position - p1).length_sqr() * one_over_sigma * one_over_sigma).exp() * one_over_sigma / TAU.sqrt();
+
+        random.next_f32() < density
+      }
+      _ => false,
+    }
+  }
+
+  /// Outward normal of the domain surface nearest to provided point, for domains particles can bounce off or avoid.
+  pub fn surface_normal(&self, position: &ParticleSimulationVector) -> Option<ParticleSimulationVector> {
+    match self.domain.domain_type {
+      Self::PLANE | Self::TRIANGLE | Self::RECTANGLE | Self::DISC => Some(self.plane_normal()),
+      Self::SPHERE => Some((*position - self.p1()).normalize()),
+      _ => None,
+    }
+  }
+
+  /// Signed distance of provided point to the plane of a flat domain, positive on the side its normal points to.
+  pub fn plane_distance(&self, position: &ParticleSimulationVector) -> f32 {
+    (*position - self.p1()).dot(&self.plane_normal())
+  }
+
+  fn plane_normal(&self) -> ParticleSimulationVector {
+    match self.domain.domain_type {
+      Self::TRIANGLE | Self::RECTANGLE => self.u().cross(&self.v()).normalize(),
+      _ => self.p2().normalize(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::data::generic::vector_3d::Vector3d;
+  use crate::data::particles::particle_domain::ParticleDomain;
+  use crate::particles::simulation::particle_simulation_domain::ParticleSimulationDomain;
+  use crate::particles::simulation::particle_simulation_random::ParticleSimulationRandom;
+  use crate::particles::simulation::particle_simulation_vector::ParticleSimulationVector;
+
+  fn domain(domain_type: u32, p1: (f32, f32, f32), p2: (f32, f32, f32), radius1: f32, radius2: f32) -> ParticleDomain {
+    ParticleDomain {
+      domain_type,
+      coordinates: (Vector3d::from(p1), Vector3d::from(p2)),
+      basis: (Vector3d::new(1.0, 0.0, 0.0), Vector3d::new(0.0, 0.0, 1.0)),
+      radius1,
+      radius2,
+      radius1_sqr: radius1 * radius1,
+      radius2_sqr: radius2 * radius2,
+    }
+  }
+
+  #[test]
+  fn generates_points_inside_volume_domains() {
+    let mut random: ParticleSimulationRandom = ParticleSimulationRandom::new(7);
+
+    for domain in [
+      domain(
+        ParticleSimulationDomain::BOX,
+        (-1.0, 0.0, -1.0),
+        (1.0, 2.0, 1.0),
+        0.0,
+        0.0,
+      ),
+      domain(
+        ParticleSimulationDomain::SPHERE,
+        (0.0, 1.0, 0.0),
+        (0.0, 0.0, 0.0),
+        2.0,
+        0.0,
+      ),
+      domain(
+        ParticleSimulationDomain::CYLINDER,
+        (0.0, 0.0, 0.0),
+        (0.0, 3.0, 0.0),
+        1.0,
+        0.0,
+      ),
+      domain(
+        ParticleSimulationDomain::CONE,
+        (0.0, 0.0, 0.0),
+        (0.0, 3.0, 0.0),
+        1.0,
+        0.0,
+      ),
+    ] {
+      let sampled: ParticleSimulationDomain = ParticleSimulationDomain::new(&domain);
+
+      for _ in 0..256 {
+        let point: ParticleSimulationVector = sampled.generate(&mut random);
+
+        // Nudged towards the axis, so a point generated exactly on a boundary is not lost to rounding.
+        let nudged: ParticleSimulationVector = ParticleSimulationVector::new(point.x * 0.999, point.y, point.z * 0.999);
+
+        assert!(
+          sampled.contains(&nudged, &mut random),
+          "Domain {} generated {point:?} outside of itself",
+          domain.domain_type
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn tells_plane_sides_apart() {
+    let mut random: ParticleSimulationRandom = ParticleSimulationRandom::new(0);
+    let plane: ParticleDomain = domain(
+      ParticleSimulationDomain::PLANE,
+      (0.0, 0.0, 0.0),
+      (0.0, 1.0, 0.0),
+      0.0,
+      0.0,
+    );
+    let sampled: ParticleSimulationDomain = ParticleSimulationDomain::new(&plane);
+
+    assert!(sampled.contains(&ParticleSimulationVector::new(3.0, 0.5, -2.0), &mut random));
+    assert!(!sampled.contains(&ParticleSimulationVector::new(3.0, -0.5, -2.0), &mut random));
+    assert_eq!(
+      sampled.plane_distance(&ParticleSimulationVector::new(0.0, -2.0, 0.0)),
+      -2.0
+    );
+  }
+}
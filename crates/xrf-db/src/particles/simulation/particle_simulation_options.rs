@@ -0,0 +1,30 @@
+/// How long and how finely a particle effect is simulated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParticleSimulationOptions {
+  /// Simulated time, in seconds.
+  pub duration: f32,
+  /// Seconds advanced by each frame; every frame is one step of all actions.
+  pub time_step: f32,
+  /// Seed of the random source; the same seed replays the same run.
+  pub seed: u64,
+}
+
+impl ParticleSimulationOptions {
+  /// Longest run accepted, so a typo in duration or step cannot fill the disk with frames.
+  pub const FRAMES_LIMIT: usize = 100_000;
+
+  /// Count of frames the run records after its initial one.
+  pub fn get_frames_count(&self) -> usize {
+    (self.duration / self.time_step).ceil() as usize
+  }
+}
+
+impl Default for ParticleSimulationOptions {
+  fn default() -> Self {
+    Self {
+      duration: 2.0,
+      time_step: 1.0 / 30.0,
+      seed: 0,
+    }
+  }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// State of a single particle at the end of a frame.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticleSimulationParticle {
+  pub position: [f32; 3],
+  pub velocity: [f32; 3],
+  pub size: [f32; 3],
+  /// Rotation as the engine keeps it; sprites only use the first component, an angle in radians.
+  pub rotation: [f32; 3],
+  /// Red, green, blue and alpha.
+  pub color: [f32; 4],
+  /// Seconds since the particle was born, including the age its source starts particles with.
+  pub age: f32,
+}
+
+/// Particles alive at the end of a frame, with what changed since the previous one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticleSimulationFrame {
+  pub index: usize,
+  /// Simulated time at the end of the frame, in seconds.
+  pub time: f32,
+  pub count: usize,
+  pub born: usize,
+  pub died: usize,
+  pub particles: Vec<ParticleSimulationParticle>,
+}
+
+/// Outcome of a particle effect simulation, frame by frame.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticleSimulationResult {
+  pub effect: String,
+  pub seed: u64,
+  pub time_step: f32,
+  /// Particle budget of the effect, which no frame exceeds.
+  pub max_particles: u32,
+  /// Most particles alive at once.
+  pub peak_count: usize,
+  pub total_born: usize,
+  pub total_died: usize,
+  /// Smallest and largest particle position over every frame, absent when no particle was ever alive.
+  pub bounds: Option<([f32; 3], [f32; 3])>,
+  /// Initial state first, then one frame per step.
+  pub frames: Vec<ParticleSimulationFrame>,
+  /// Parts of the effect the simulation ignored, so the frames may differ from the game.
+  pub warnings: Vec<String>,
+}
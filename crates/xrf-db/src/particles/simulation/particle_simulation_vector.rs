@@ -0,0 +1,111 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::data::generic::vector_3d::Vector3d;
+
+/// Copyable vector the simulation does its arithmetic on, converted from and to stored `Vector3d` values.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct ParticleSimulationVector {
+  pub x: f32,
+  pub y: f32,
+  pub z: f32,
+}
+
+impl ParticleSimulationVector {
+  pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+  pub const fn new(x: f32, y: f32, z: f32) -> Self {
+    Self { x, y, z }
+  }
+
+  pub fn dot(&self, other: &Self) -> f32 {
+    self.x * other.x + self.y * other.y + self.z * other.z
+  }
+
+  pub fn cross(&self, other: &Self) -> Self {
+    Self::new(
+      self.y * other.z - self.z * other.y,
+      self.z * other.x - self.x * other.z,
+      self.x * other.y - self.y * other.x,
+    )
+  }
+
+  pub fn length_sqr(&self) -> f32 {
+    self.dot(self)
+  }
+
+  pub fn length(&self) -> f32 {
+    self.length_sqr().sqrt()
+  }
+
+  /// Unit vector of the same direction, or zero for a vector too short to have one.
+  pub fn normalize(&self) -> Self {
+    let length: f32 = self.length();
+
+    if length > f32::EPSILON {
+      *self * (1.0 / length)
+    } else {
+      Self::ZERO
+    }
+  }
+
+  /// Component-wise product, used for per-axis scales.
+  pub fn scale(&self, other: &Self) -> Self {
+    Self::new(self.x * other.x, self.y * other.y, self.z * other.z)
+  }
+
+  pub fn min(&self, other: &Self) -> Self {
+    Self::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+  }
+
+  pub fn max(&self, other: &Self) -> Self {
+    Self::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+  }
+
+  pub fn to_array(self) -> [f32; 3] {
+    [self.x, self.y, self.z]
+  }
+}
+
+impl From<&Vector3d> for ParticleSimulationVector {
+  fn from(vector: &Vector3d) -> Self {
+    Self::new(vector.x, vector.y, vector.z)
+  }
+}
+
+impl Add for ParticleSimulationVector {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+  }
+}
+
+impl AddAssign for ParticleSimulationVector {
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl Sub for ParticleSimulationVector {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+  }
+}
+
+impl Mul<f32> for ParticleSimulationVector {
+  type Output = Self;
+
+  fn mul(self, scale: f32) -> Self {
+    Self::new(self.x * scale, self.y * scale, self.z * scale)
+  }
+}
+
+impl Neg for ParticleSimulationVector {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    Self::new(-self.x, -self.y, -self.z)
+  }
+}
@@ -1 +1,9 @@
 pub(crate) mod thm_bump_chunk;
+pub(crate) mod thm_detail_chunk;
+pub(crate) mod thm_fade_delay_chunk;
+pub(crate) mod thm_material_chunk;
+pub(crate) mod thm_normal_map_chunk;
+pub(crate) mod thm_texture_params_chunk;
+pub(crate) mod thm_texture_type_chunk;
+pub(crate) mod thm_type_chunk;
+pub(crate) mod thm_version_chunk;
@@ -37,6 +37,17 @@ impl ThmBumpChunk {
   }
 }
 
+impl Default for ThmBumpChunk {
+  /// The declaration `STextureParams` gives a texture without a bump.
+  fn default() -> Self {
+    Self {
+      virtual_height: 0.05,
+      mode: Self::MODE_NONE,
+      name: String::new(),
+    }
+  }
+}
+
 impl ChunkReadWrite for ThmBumpChunk {
   fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
     let bump: Self = Self {
@@ -0,0 +1,50 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Detail texture laid over this one up close, `THM_CHUNK_DETAIL_EXT` in the engine
+/// (`ETextureParams.cpp`).
+///
+/// Only used when [`crate::ThmTextureParamsChunk::FLAG_DIFFUSE_DETAIL`] or
+/// [`crate::ThmTextureParamsChunk::FLAG_BUMP_DETAIL`] is set.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmDetailChunk {
+  /// Detail texture path without extension, engine-style with backslashes. Empty when unused.
+  pub name: String,
+  pub scale: f32,
+}
+
+impl Default for ThmDetailChunk {
+  fn default() -> Self {
+    Self {
+      name: String::new(),
+      scale: 1.0,
+    }
+  }
+}
+
+impl ThmDetailChunk {
+  pub const CHUNK_ID: u32 = 0x0815;
+}
+
+impl ChunkReadWrite for ThmDetailChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let detail: Self = Self {
+      name: reader.read_w1251_string()?,
+      scale: reader.read_f32::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm detail chunk")?;
+
+    Ok(detail)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_w1251_string(&self.name)?;
+    writer.write_f32::<T>(self.scale)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,33 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Mip level fading starts at, `THM_CHUNK_FADE_DELAY` in the engine (`ETextureParams.cpp`).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmFadeDelayChunk {
+  pub delay: u8,
+}
+
+impl ThmFadeDelayChunk {
+  pub const CHUNK_ID: u32 = 0x0819;
+}
+
+impl ChunkReadWrite for ThmFadeDelayChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let fade_delay: Self = Self {
+      delay: reader.read_u8()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm fade delay chunk")?;
+
+    Ok(fade_delay)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u8(self.delay)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,54 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Lighting model blend of a texture, `THM_CHUNK_MATERIAL` in the engine (`ETextureParams.cpp`).
+///
+/// The renderer packs the material and its weight into the texture's alpha channel of the
+/// G-buffer, so it only matters for the dynamic lighting renderers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmMaterialChunk {
+  pub material: u32,
+  pub weight: f32,
+}
+
+impl ThmMaterialChunk {
+  pub const CHUNK_ID: u32 = 0x0816;
+
+  /// `STextureParams::ETMaterial`, `ETextureParams.h`.
+  pub const MATERIAL_OREN_NAYAR_BLINN: u32 = 0;
+  pub const MATERIAL_BLINN_PHONG: u32 = 1;
+  pub const MATERIAL_PHONG_METAL: u32 = 2;
+  pub const MATERIAL_METAL_OREN_NAYAR: u32 = 3;
+}
+
+impl Default for ThmMaterialChunk {
+  fn default() -> Self {
+    Self {
+      material: Self::MATERIAL_BLINN_PHONG,
+      weight: 0.5,
+    }
+  }
+}
+
+impl ChunkReadWrite for ThmMaterialChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let material: Self = Self {
+      material: reader.read_u32::<T>()?,
+      weight: reader.read_f32::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm material chunk")?;
+
+    Ok(material)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u32::<T>(self.material)?;
+    writer.write_f32::<T>(self.weight)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,37 @@
+use byteorder::ByteOrder;
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Normal map authored outside the SDK, `THM_CHUNK_EXT_NORMALMAP` in the engine
+/// (`ETextureParams.cpp`).
+///
+/// Read by the SDK when it builds the bump texture, the engine never looks at it.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmNormalMapChunk {
+  /// Normal map path without extension, engine-style with backslashes. Empty when unused.
+  pub name: String,
+}
+
+impl ThmNormalMapChunk {
+  pub const CHUNK_ID: u32 = 0x0818;
+}
+
+impl ChunkReadWrite for ThmNormalMapChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let normal_map: Self = Self {
+      name: reader.read_w1251_string()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm normal map chunk")?;
+
+    Ok(normal_map)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_w1251_string(&self.name)?;
+
+    Ok(())
+  }
+}
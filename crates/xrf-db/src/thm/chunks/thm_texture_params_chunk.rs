@@ -0,0 +1,187 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_dds::{D3DFormat, DdsFormat, DxgiFormat};
+use xrf_error::XrfResult;
+
+/// Compression and mip settings of a texture, `THM_CHUNK_TEXTUREPARAM` in the engine
+/// (`ETextureParams.cpp`).
+///
+/// The only chunk `STextureParams::Load` requires; every other one falls back to a default.
+/// Width and height record the source image the SDK compressed, the engine itself reads them
+/// off the DDS.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmTextureParamsChunk {
+  pub format: u32,
+  pub flags: u32,
+  pub border_color: u32,
+  pub fade_color: u32,
+  pub fade_amount: u32,
+  pub mip_filter: u32,
+  pub width: u32,
+  pub height: u32,
+}
+
+impl ThmTextureParamsChunk {
+  pub const CHUNK_ID: u32 = 0x0812;
+
+  /// `STextureParams::ETFormat`, `ETextureParams.h`.
+  pub const FORMAT_DXT1: u32 = 0;
+  pub const FORMAT_DXT1_ALPHA: u32 = 1;
+  pub const FORMAT_DXT3: u32 = 2;
+  pub const FORMAT_DXT5: u32 = 3;
+  pub const FORMAT_4444: u32 = 4;
+  pub const FORMAT_1555: u32 = 5;
+  pub const FORMAT_565: u32 = 6;
+  pub const FORMAT_RGB: u32 = 7;
+  pub const FORMAT_RGBA: u32 = 8;
+  pub const FORMAT_NVHS: u32 = 9;
+  pub const FORMAT_NVHU: u32 = 10;
+  pub const FORMAT_A8: u32 = 11;
+  pub const FORMAT_L8: u32 = 12;
+  pub const FORMAT_A8L8: u32 = 13;
+
+  /// `STextureParams::ETFlags`.
+  pub const FLAG_GENERATE_MIPMAPS: u32 = 1 << 0;
+  pub const FLAG_BINARY_ALPHA: u32 = 1 << 1;
+  pub const FLAG_ALPHA_BORDER: u32 = 1 << 4;
+  pub const FLAG_COLOR_BORDER: u32 = 1 << 5;
+  pub const FLAG_FADE_TO_COLOR: u32 = 1 << 6;
+  pub const FLAG_FADE_TO_ALPHA: u32 = 1 << 7;
+  pub const FLAG_DITHER_COLOR: u32 = 1 << 8;
+  pub const FLAG_DITHER_EACH_MIP_LEVEL: u32 = 1 << 9;
+  pub const FLAG_DIFFUSE_DETAIL: u32 = 1 << 23;
+  pub const FLAG_IMPLICIT_LIGHTED: u32 = 1 << 24;
+  pub const FLAG_HAS_ALPHA: u32 = 1 << 25;
+  pub const FLAG_BUMP_DETAIL: u32 = 1 << 26;
+
+  /// NVTT mip filters the SDK offers, `kMIPFilter*`.
+  pub const MIP_FILTER_BOX: u32 = 0;
+  pub const MIP_FILTER_CUBIC: u32 = 1;
+  pub const MIP_FILTER_POINT: u32 = 2;
+  pub const MIP_FILTER_TRIANGLE: u32 = 3;
+  pub const MIP_FILTER_QUADRATIC: u32 = 4;
+  pub const MIP_FILTER_ADVANCED: u32 = 5;
+  pub const MIP_FILTER_CATROM: u32 = 6;
+  pub const MIP_FILTER_MITCHELL: u32 = 7;
+  pub const MIP_FILTER_GAUSSIAN: u32 = 8;
+  pub const MIP_FILTER_SINC: u32 = 9;
+  pub const MIP_FILTER_BESSEL: u32 = 10;
+  pub const MIP_FILTER_HANNING: u32 = 11;
+  pub const MIP_FILTER_HAMMING: u32 = 12;
+  pub const MIP_FILTER_BLACKMAN: u32 = 13;
+  pub const MIP_FILTER_KAISER: u32 = 14;
+
+  /// Whether provided texture format keeps an alpha channel.
+  pub fn is_alpha_format(format: u32) -> bool {
+    matches!(
+      format,
+      Self::FORMAT_DXT1_ALPHA
+        | Self::FORMAT_DXT3
+        | Self::FORMAT_DXT5
+        | Self::FORMAT_4444
+        | Self::FORMAT_1555
+        | Self::FORMAT_RGBA
+        | Self::FORMAT_A8
+        | Self::FORMAT_A8L8
+    )
+  }
+
+  /// Texture format the SDK would have compressed provided DDS format with.
+  pub fn format_from_dds(format: DdsFormat) -> Option<u32> {
+    match format {
+      DdsFormat::D3d(format) => match format {
+        D3DFormat::DXT1 => Some(Self::FORMAT_DXT1),
+        D3DFormat::DXT2 | D3DFormat::DXT3 => Some(Self::FORMAT_DXT3),
+        D3DFormat::DXT4 | D3DFormat::DXT5 => Some(Self::FORMAT_DXT5),
+        D3DFormat::A8R8G8B8 | D3DFormat::A8B8G8R8 => Some(Self::FORMAT_RGBA),
+        D3DFormat::X8R8G8B8 | D3DFormat::X8B8G8R8 | D3DFormat::R8G8B8 => Some(Self::FORMAT_RGB),
+        D3DFormat::A4R4G4B4 => Some(Self::FORMAT_4444),
+        D3DFormat::A1R5G5B5 => Some(Self::FORMAT_1555),
+        D3DFormat::R5G6B5 => Some(Self::FORMAT_565),
+        D3DFormat::A8 => Some(Self::FORMAT_A8),
+        D3DFormat::L8 => Some(Self::FORMAT_L8),
+        D3DFormat::A8L8 => Some(Self::FORMAT_A8L8),
+        _ => None,
+      },
+      DdsFormat::Dxgi(format) => match format {
+        DxgiFormat::BC1_UNorm | DxgiFormat::BC1_UNorm_sRGB => Some(Self::FORMAT_DXT1),
+        DxgiFormat::BC2_UNorm | DxgiFormat::BC2_UNorm_sRGB => Some(Self::FORMAT_DXT3),
+        DxgiFormat::BC3_UNorm | DxgiFormat::BC3_UNorm_sRGB => Some(Self::FORMAT_DXT5),
+        DxgiFormat::R8G8B8A8_UNorm
+        | DxgiFormat::R8G8B8A8_UNorm_sRGB
+        | DxgiFormat::B8G8R8A8_UNorm
+        | DxgiFormat::B8G8R8A8_UNorm_sRGB => Some(Self::FORMAT_RGBA),
+        DxgiFormat::B8G8R8X8_UNorm | DxgiFormat::B8G8R8X8_UNorm_sRGB => Some(Self::FORMAT_RGB),
+        DxgiFormat::B4G4R4A4_UNorm => Some(Self::FORMAT_4444),
+        DxgiFormat::B5G5R5A1_UNorm => Some(Self::FORMAT_1555),
+        DxgiFormat::B5G6R5_UNorm => Some(Self::FORMAT_565),
+        DxgiFormat::A8_UNorm => Some(Self::FORMAT_A8),
+        DxgiFormat::R8_UNorm => Some(Self::FORMAT_L8),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  pub fn has_flag(&self, flag: u32) -> bool {
+    self.flags & flag == flag
+  }
+
+  pub fn set_flag(&mut self, flag: u32, is_set: bool) {
+    if is_set {
+      self.flags |= flag;
+    } else {
+      self.flags &= !flag;
+    }
+  }
+}
+
+impl Default for ThmTextureParamsChunk {
+  /// The parameters `STextureParams::Clear` gives a texture the SDK has not seen before.
+  fn default() -> Self {
+    Self {
+      format: Self::FORMAT_DXT1,
+      flags: Self::FLAG_GENERATE_MIPMAPS | Self::FLAG_DITHER_COLOR,
+      border_color: 0,
+      fade_color: 0x0080_8080,
+      fade_amount: 0,
+      mip_filter: Self::MIP_FILTER_BOX,
+      width: 0,
+      height: 0,
+    }
+  }
+}
+
+impl ChunkReadWrite for ThmTextureParamsChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let params: Self = Self {
+      format: reader.read_u32::<T>()?,
+      flags: reader.read_u32::<T>()?,
+      border_color: reader.read_u32::<T>()?,
+      fade_color: reader.read_u32::<T>()?,
+      fade_amount: reader.read_u32::<T>()?,
+      mip_filter: reader.read_u32::<T>()?,
+      width: reader.read_u32::<T>()?,
+      height: reader.read_u32::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm texture params chunk")?;
+
+    Ok(params)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u32::<T>(self.format)?;
+    writer.write_u32::<T>(self.flags)?;
+    writer.write_u32::<T>(self.border_color)?;
+    writer.write_u32::<T>(self.fade_color)?;
+    writer.write_u32::<T>(self.fade_amount)?;
+    writer.write_u32::<T>(self.mip_filter)?;
+    writer.write_u32::<T>(self.width)?;
+    writer.write_u32::<T>(self.height)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,57 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// What a texture is used as, `THM_CHUNK_TEXTURE_TYPE` in the engine (`ETextureParams.cpp`).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmTextureTypeChunk {
+  pub texture_type: u32,
+}
+
+impl ThmTextureTypeChunk {
+  pub const CHUNK_ID: u32 = 0x0814;
+
+  /// `STextureParams::ETType`, `ETextureParams.h`.
+  pub const TYPE_IMAGE: u32 = 0;
+  pub const TYPE_CUBE_MAP: u32 = 1;
+  pub const TYPE_BUMP_MAP: u32 = 2;
+  pub const TYPE_NORMAL_MAP: u32 = 3;
+  pub const TYPE_TERRAIN: u32 = 4;
+
+  /// Texture type the SDK would be set to for a texture with provided engine path.
+  ///
+  /// Bump maps are recognized by the `_bump` and `_bump#` suffixes the engine resolves them with,
+  /// level terrain by living as `terrain\terrain_*`. Anything else is a plain image; cube maps
+  /// cannot be told apart by name and have to be set by hand.
+  pub fn type_for_texture_name(name: &str) -> u32 {
+    let name: String = name.replace('/', "\\").to_lowercase();
+
+    if name.ends_with("_bump") || name.ends_with("_bump#") {
+      Self::TYPE_BUMP_MAP
+    } else if name.starts_with("terrain\\terrain_") && !name.ends_with("_mask") {
+      Self::TYPE_TERRAIN
+    } else {
+      Self::TYPE_IMAGE
+    }
+  }
+}
+
+impl ChunkReadWrite for ThmTextureTypeChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let texture_type: Self = Self {
+      texture_type: reader.read_u32::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm texture type chunk")?;
+
+    Ok(texture_type)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u32::<T>(self.texture_type)?;
+
+    Ok(())
+  }
+}
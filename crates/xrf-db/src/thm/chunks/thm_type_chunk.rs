@@ -0,0 +1,42 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Kind of asset a thumbnail describes, `THM_CHUNK_TYPE` in the engine (`EThumbnail.h`).
+///
+/// The SDK keeps textures, objects, sounds and groups in the same thumbnail format; a texture
+/// descriptor is always [`Self::KIND_TEXTURE`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmTypeChunk {
+  pub kind: u32,
+}
+
+impl ThmTypeChunk {
+  pub const CHUNK_ID: u32 = 0x0813;
+
+  /// `EImageThumbnail::THMType`.
+  pub const KIND_OBJECT: u32 = 0;
+  pub const KIND_TEXTURE: u32 = 1;
+  pub const KIND_SOUND: u32 = 2;
+  pub const KIND_GROUP: u32 = 3;
+}
+
+impl ChunkReadWrite for ThmTypeChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let kind: Self = Self {
+      kind: reader.read_u32::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm type chunk")?;
+
+    Ok(kind)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u32::<T>(self.kind)?;
+
+    Ok(())
+  }
+}
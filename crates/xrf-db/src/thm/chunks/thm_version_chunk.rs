@@ -0,0 +1,36 @@
+use byteorder::{ByteOrder, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_error::XrfResult;
+
+/// Descriptor format version, `THM_CHUNK_VERSION` in the engine (`EThumbnail.h`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmVersionChunk {
+  pub version: u16,
+}
+
+impl ThmVersionChunk {
+  pub const CHUNK_ID: u32 = 0x0810;
+
+  /// `THM_CURRENT_VERSION`, the only version the SDK writes.
+  pub const VERSION_CURRENT: u16 = 0x0012;
+}
+
+impl ChunkReadWrite for ThmVersionChunk {
+  fn read<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
+    let version: Self = Self {
+      version: reader.read_u16::<T>()?,
+    };
+
+    reader.assert_read("Expect all data to be read from thm version chunk")?;
+
+    Ok(version)
+  }
+
+  fn write<T: ByteOrder>(&self, writer: &mut ChunkWriter) -> XrfResult {
+    writer.write_u16::<T>(self.version)?;
+
+    Ok(())
+  }
+}
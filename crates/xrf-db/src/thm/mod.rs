@@ -2,3 +2,4 @@ pub(crate) mod chunks;
 pub mod thm_bump_patch_report;
 pub mod thm_bump_processor;
 pub(crate) mod thm_file;
+pub(crate) mod thm_parameters_table;
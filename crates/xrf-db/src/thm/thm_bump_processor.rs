@@ -221,7 +221,7 @@ mod tests {
 
     let mut file: File = overwrite_generated_test_resource_as_file(filename)?;
 
-    opaque_writer.flush_chunk_into::<XRayByteOrder>(&mut file, 0x0812)?;
+    opaque_writer.flush_chunk_into::<XRayByteOrder>(&mut file, 0x0811)?;
     bump_writer.flush_chunk_into::<XRayByteOrder>(&mut file, ThmBumpChunk::CHUNK_ID)?;
 
     Ok(build_absolute_generated_test_resource_path(filename))
@@ -351,7 +351,7 @@ mod tests {
 
     opaque_writer.write_all(&OPAQUE_PAYLOAD)?;
     opaque_writer
-      .flush_chunk_into::<XRayByteOrder>(&mut overwrite_generated_test_resource_as_file(&filename)?, 0x0812)?;
+      .flush_chunk_into::<XRayByteOrder>(&mut overwrite_generated_test_resource_as_file(&filename)?, 0x0811)?;
 
     let path: PathBuf = build_absolute_generated_test_resource_path(&filename);
 
@@ -1,25 +1,62 @@
+use std::fs;
 use std::fs::File;
+use std::io::Write;
 use std::path::Path;
 
 use byteorder::ByteOrder;
 use serde::{Deserialize, Serialize};
-use xrf_chunk::{ChunkDataSource, ChunkReader, find_optional_chunk_by_id};
+use xrf_chunk::{ChunkDataSource, ChunkReadWrite, ChunkReader, ChunkWriter};
+use xrf_dds::DdsMetadata;
 use xrf_error::{XrfError, XrfResult};
+use xrf_utils::open_export_file;
 
 use crate::thm::chunks::thm_bump_chunk::ThmBumpChunk;
+use crate::thm::chunks::thm_detail_chunk::ThmDetailChunk;
+use crate::thm::chunks::thm_fade_delay_chunk::ThmFadeDelayChunk;
+use crate::thm::chunks::thm_material_chunk::ThmMaterialChunk;
+use crate::thm::chunks::thm_normal_map_chunk::ThmNormalMapChunk;
+use crate::thm::chunks::thm_texture_params_chunk::ThmTextureParamsChunk;
+use crate::thm::chunks::thm_texture_type_chunk::ThmTextureTypeChunk;
+use crate::thm::chunks::thm_type_chunk::ThmTypeChunk;
+use crate::thm::chunks::thm_version_chunk::ThmVersionChunk;
 
 /// Texture descriptor file, `STextureParams` in the engine (`ETextureParams.cpp`).
 ///
-/// Reads only the parts the toolchain needs. A thm carries several chunks of authoring metadata,
-/// but the one with runtime consequences is the bump declaration, so everything else stays
-/// unparsed and any edit patches raw chunks rather than re-serializing, see
-/// [`crate::ThmBumpProcessor`].
-#[derive(Debug, Serialize, Deserialize)]
+/// Every chunk `STextureParams` saves is modelled; a chunk that is absent stays `None`, matching
+/// the engine falling back to its defaults. The editor thumbnail and any chunk this crate does not
+/// know are kept as raw bytes, and the original chunk order is remembered, so writing back a file
+/// that was read and left alone reproduces it byte for byte.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ThmFile {
+  pub version: Option<ThmVersionChunk>,
+  pub kind: Option<ThmTypeChunk>,
+  pub params: Option<ThmTextureParamsChunk>,
+  pub texture_type: Option<ThmTextureTypeChunk>,
+  pub detail: Option<ThmDetailChunk>,
+  pub material: Option<ThmMaterialChunk>,
   pub bump: Option<ThmBumpChunk>,
+  pub normal_map: Option<ThmNormalMapChunk>,
+  pub fade_delay: Option<ThmFadeDelayChunk>,
+  /// Chunks in file order, with the payload of every chunk not modelled above.
+  #[serde(skip)]
+  layout: Vec<(u32, Option<Vec<u8>>)>,
 }
 
 impl ThmFile {
+  /// Chunk order `ETextureThumbnail::Save` writes, used for chunks a file did not have before.
+  const MODELLED_CHUNK_ORDER: [u32; 9] = [
+    ThmVersionChunk::CHUNK_ID,
+    ThmTypeChunk::CHUNK_ID,
+    ThmTextureParamsChunk::CHUNK_ID,
+    ThmTextureTypeChunk::CHUNK_ID,
+    ThmDetailChunk::CHUNK_ID,
+    ThmMaterialChunk::CHUNK_ID,
+    ThmBumpChunk::CHUNK_ID,
+    ThmNormalMapChunk::CHUNK_ID,
+    ThmFadeDelayChunk::CHUNK_ID,
+  ];
+
   pub fn read_from_path<T: ByteOrder, P: AsRef<Path>>(path: &P) -> XrfResult<Self> {
     Self::read_from_file::<T>(File::open(path).map_err(|error| {
       XrfError::new_not_found_error(format!(
@@ -34,20 +71,175 @@ impl ThmFile {
     Self::read_from_chunk::<T, _>(&mut ChunkReader::from_file(file)?)
   }
 
+  pub fn read_from_bytes<T: ByteOrder>(bytes: &[u8]) -> XrfResult<Self> {
+    Self::read_from_chunk::<T, _>(&mut ChunkReader::from_bytes(bytes)?)
+  }
+
   /// Reads a descriptor from a chunk reader over any data source.
   ///
   /// The route an archived descriptor takes: a volume holds no file to slice, only bytes.
+  ///
+  /// Only the first chunk of each modelled id is parsed, which is the one `find_chunk` gives the
+  /// engine; repeated ones are carried along raw.
   pub fn read_from_chunk<T: ByteOrder, D: ChunkDataSource>(reader: &mut ChunkReader<D>) -> XrfResult<Self> {
-    let chunks: Vec<ChunkReader<D>> = reader.read_children()?;
+    let mut file: Self = Self::default();
+
+    for mut chunk in reader.read_children()? {
+      let is_parsed: bool = match chunk.id {
+        ThmVersionChunk::CHUNK_ID if file.version.is_none() => {
+          file.version = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmTypeChunk::CHUNK_ID if file.kind.is_none() => {
+          file.kind = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmTextureParamsChunk::CHUNK_ID if file.params.is_none() => {
+          file.params = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmTextureTypeChunk::CHUNK_ID if file.texture_type.is_none() => {
+          file.texture_type = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmDetailChunk::CHUNK_ID if file.detail.is_none() => {
+          file.detail = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmMaterialChunk::CHUNK_ID if file.material.is_none() => {
+          file.material = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmBumpChunk::CHUNK_ID if file.bump.is_none() => {
+          file.bump = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmNormalMapChunk::CHUNK_ID if file.normal_map.is_none() => {
+          file.normal_map = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        ThmFadeDelayChunk::CHUNK_ID if file.fade_delay.is_none() => {
+          file.fade_delay = Some(chunk.read_xr::<T, _>()?);
+          true
+        }
+        _ => false,
+      };
+
+      file.layout.push(if is_parsed {
+        (chunk.id, None)
+      } else {
+        (chunk.id, Some(chunk.read_remaining()?))
+      });
+    }
+
+    Ok(file)
+  }
+
+  /// Derive a descriptor for a texture that arrived without one.
+  ///
+  /// Starts from what `STextureParams` gives a fresh texture in the SDK and takes the format,
+  /// size, mip generation and alpha from the DDS itself. The bump stays off, declaring one is up
+  /// to the caller since it depends on which textures exist next to this one.
+  ///
+  /// # Errors
+  ///
+  /// Fails for a DDS format the SDK cannot describe, such as floating point or BC4 and above.
+  pub fn from_dds_metadata(metadata: &DdsMetadata, texture_type: u32) -> XrfResult<Self> {
+    let format: u32 = ThmTextureParamsChunk::format_from_dds(metadata.format).ok_or_else(|| {
+      XrfError::new_invalid_error(format!(
+        "DDS format {:?} has no thm texture format counterpart",
+        metadata.format
+      ))
+    })?;
+
+    let mut params: ThmTextureParamsChunk = ThmTextureParamsChunk {
+      format,
+      width: metadata.width,
+      height: metadata.height,
+      ..ThmTextureParamsChunk::default()
+    };
+
+    params.set_flag(ThmTextureParamsChunk::FLAG_GENERATE_MIPMAPS, metadata.mipmap_levels > 1);
+    params.set_flag(
+      ThmTextureParamsChunk::FLAG_HAS_ALPHA,
+      ThmTextureParamsChunk::is_alpha_format(format),
+    );
 
     Ok(Self {
-      bump: match find_optional_chunk_by_id(&chunks, ThmBumpChunk::CHUNK_ID) {
-        Some(mut chunk) => Some(chunk.read_xr::<T, ThmBumpChunk>()?),
-        None => None,
-      },
+      version: Some(ThmVersionChunk {
+        version: ThmVersionChunk::VERSION_CURRENT,
+      }),
+      kind: Some(ThmTypeChunk {
+        kind: ThmTypeChunk::KIND_TEXTURE,
+      }),
+      params: Some(params),
+      texture_type: Some(ThmTextureTypeChunk { texture_type }),
+      detail: Some(ThmDetailChunk::default()),
+      material: Some(ThmMaterialChunk::default()),
+      bump: Some(ThmBumpChunk::default()),
+      normal_map: Some(ThmNormalMapChunk::default()),
+      fade_delay: Some(ThmFadeDelayChunk::default()),
+      layout: Vec::new(),
     })
   }
 
+  /// Write thm file data to the file by provided path.
+  pub fn write_to_path<T: ByteOrder, P: AsRef<Path>>(&self, path: &P) -> XrfResult {
+    if let Some(parent) = path.as_ref().parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    self.write_to::<T>(&mut open_export_file(path)?)
+  }
+
+  /// Write thm file data to the writer.
+  ///
+  /// Chunks keep the order they were read in, chunks cleared since are dropped and chunks set
+  /// since are appended in the order the SDK writes them.
+  pub fn write_to<T: ByteOrder>(&self, writer: &mut dyn Write) -> XrfResult {
+    for (id, raw) in &self.layout {
+      let payload: Option<Vec<u8>> = match raw {
+        Some(raw) => Some(raw.clone()),
+        None => self.write_modelled_chunk::<T>(*id)?,
+      };
+
+      if let Some(payload) = payload {
+        let mut chunk_writer: ChunkWriter = ChunkWriter::new();
+
+        chunk_writer.write_all(&payload)?;
+        chunk_writer.flush_chunk_into::<T>(writer, *id)?;
+      }
+    }
+
+    for id in Self::MODELLED_CHUNK_ORDER {
+      if self
+        .layout
+        .iter()
+        .any(|(existing, raw)| *existing == id && raw.is_none())
+      {
+        continue;
+      }
+
+      if let Some(payload) = self.write_modelled_chunk::<T>(id)? {
+        let mut chunk_writer: ChunkWriter = ChunkWriter::new();
+
+        chunk_writer.write_all(&payload)?;
+        chunk_writer.flush_chunk_into::<T>(writer, id)?;
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Write thm file data into a new buffer.
+  pub fn write_to_buffer<T: ByteOrder>(&self) -> XrfResult<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    self.write_to::<T>(&mut buffer)?;
+
+    Ok(buffer)
+  }
+
   /// Bump texture this descriptor asks the engine to resolve, if any.
   pub fn used_bump_name(&self) -> Option<&str> {
     self
@@ -56,4 +248,195 @@ impl ThmFile {
       .filter(|bump| bump.is_used())
       .map(|bump| bump.name.as_str())
   }
+
+  /// Detail texture this descriptor asks the engine to blend in, if any.
+  ///
+  /// The detail name survives in the file after both detail flags are cleared, so only the flags say whether it is used.
+  pub fn used_detail_name(&self) -> Option<&str> {
+    self
+      .detail
+      .as_ref()
+      .filter(|detail| !detail.name.is_empty())
+      .filter(|_| {
+        self.params.as_ref().is_some_and(|params| {
+          params.has_flag(ThmTextureParamsChunk::FLAG_DIFFUSE_DETAIL)
+            || params.has_flag(ThmTextureParamsChunk::FLAG_BUMP_DETAIL)
+        })
+      })
+      .map(|detail| detail.name.as_str())
+  }
+
+  /// Serialize the modelled chunk with provided id, `None` when the file does not have it.
+  fn write_modelled_chunk<T: ByteOrder>(&self, id: u32) -> XrfResult<Option<Vec<u8>>> {
+    let mut writer: ChunkWriter = ChunkWriter::new();
+
+    match id {
+      ThmVersionChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.version.as_ref()),
+      ThmTypeChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.kind.as_ref()),
+      ThmTextureParamsChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.params.as_ref()),
+      ThmTextureTypeChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.texture_type.as_ref()),
+      ThmDetailChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.detail.as_ref()),
+      ThmMaterialChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.material.as_ref()),
+      ThmBumpChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.bump.as_ref()),
+      ThmNormalMapChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.normal_map.as_ref()),
+      ThmFadeDelayChunk::CHUNK_ID => Self::write_optional::<T, _>(&mut writer, self.fade_delay.as_ref()),
+      _ => Err(XrfError::new_invalid_error(format!(
+        "Chunk {id:#06x} is not a modelled thm chunk"
+      ))),
+    }
+  }
+
+  fn write_optional<T: ByteOrder, C: ChunkReadWrite>(
+    writer: &mut ChunkWriter,
+    chunk: Option<&C>,
+  ) -> XrfResult<Option<Vec<u8>>> {
+    match chunk {
+      Some(chunk) => {
+        chunk.write::<T>(writer)?;
+
+        Ok(Some(writer.flush_raw_into_buffer()?))
+      }
+      None => Ok(None),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs::File;
+  use std::io::Write;
+
+  use xrf_chunk::{ChunkReader, ChunkWriter, XRayByteOrder};
+  use xrf_dds::{DdsEncodeOptions, DdsFile, ImageFormat, Mipmaps, Quality, RgbaImage};
+  use xrf_error::XrfResult;
+  use xrf_test_utils::utils::{build_absolute_generated_test_resource_path, overwrite_generated_test_resource_as_file};
+
+  use crate::thm::chunks::thm_bump_chunk::ThmBumpChunk;
+  use crate::thm::chunks::thm_material_chunk::ThmMaterialChunk;
+  use crate::thm::chunks::thm_texture_params_chunk::ThmTextureParamsChunk;
+  use crate::thm::chunks::thm_texture_type_chunk::ThmTextureTypeChunk;
+  use crate::thm::thm_file::ThmFile;
+
+  /// Payload standing in for the editor thumbnail, which is carried along unparsed.
+  const THUMBNAIL_PAYLOAD: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+  /// Chunks the way the SDK lays them out, with an unknown one in the middle.
+  fn build_sample() -> XrfResult<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::new();
+    let chunks: [(u32, Vec<u8>); 7] = [
+      (0x0810, vec![0x12, 0x00]),
+      (0x0811, THUMBNAIL_PAYLOAD.to_vec()),
+      (0x0813, vec![1, 0, 0, 0]),
+      (
+        0x0812,
+        [3u32, 0x0200_0101, 0, 0x0080_8080, 0, 0, 512, 256]
+          .iter()
+          .flat_map(|value| value.to_le_bytes())
+          .collect(),
+      ),
+      (0x0999, vec![0xAB, 0xCD]),
+      (
+        0x0817,
+        [
+          0.05f32.to_le_bytes().as_slice(),
+          &2u32.to_le_bytes(),
+          b"wpn\\wpn_pm_bump\0",
+        ]
+        .concat(),
+      ),
+      (0x0819, vec![4]),
+    ];
+
+    for (id, payload) in chunks {
+      let mut writer: ChunkWriter = ChunkWriter::new();
+
+      writer.write_all(&payload)?;
+      writer.flush_chunk_into::<XRayByteOrder>(&mut buffer, id)?;
+    }
+
+    Ok(buffer)
+  }
+
+  #[test]
+  fn test_read_write_lossless() -> XrfResult {
+    let original: Vec<u8> = build_sample()?;
+    let file: ThmFile = ThmFile::read_from_chunk::<XRayByteOrder, _>(&mut ChunkReader::from_bytes(&original)?)?;
+
+    let params: &ThmTextureParamsChunk = file.params.as_ref().expect("Texture params chunk");
+
+    assert_eq!(params.format, ThmTextureParamsChunk::FORMAT_DXT5);
+    assert!(params.has_flag(ThmTextureParamsChunk::FLAG_HAS_ALPHA));
+    assert!(params.has_flag(ThmTextureParamsChunk::FLAG_DITHER_COLOR));
+    assert_eq!((params.width, params.height), (512, 256));
+    assert_eq!(file.used_bump_name(), Some("wpn\\wpn_pm_bump"));
+    assert_eq!(file.fade_delay.as_ref().map(|it| it.delay), Some(4));
+    assert!(file.material.is_none());
+
+    assert_eq!(file.write_to_buffer::<XRayByteOrder>()?, original);
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_edited_keeps_raw_chunks() -> XrfResult {
+    let original: Vec<u8> = build_sample()?;
+    let mut file: ThmFile = ThmFile::read_from_chunk::<XRayByteOrder, _>(&mut ChunkReader::from_bytes(&original)?)?;
+
+    file.material = Some(ThmMaterialChunk {
+      material: ThmMaterialChunk::MATERIAL_PHONG_METAL,
+      weight: 0.25,
+    });
+    file.fade_delay = None;
+
+    let written: Vec<u8> = file.write_to_buffer::<XRayByteOrder>()?;
+    let ids: Vec<u32> = ChunkReader::from_bytes(&written)?
+      .read_children()?
+      .iter()
+      .map(|chunk| chunk.id)
+      .collect();
+
+    assert_eq!(ids, vec![0x0810, 0x0811, 0x0813, 0x0812, 0x0999, 0x0817, 0x0816]);
+
+    let read: ThmFile = ThmFile::read_from_chunk::<XRayByteOrder, _>(&mut ChunkReader::from_bytes(&written)?)?;
+
+    assert_eq!(read.material, file.material);
+    assert_eq!(read.bump, file.bump);
+    assert!(read.fade_delay.is_none());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_from_dds_metadata() -> XrfResult {
+    let dds: DdsFile = DdsFile::encode_rgba(
+      &RgbaImage::new(64, 32),
+      DdsEncodeOptions::new(ImageFormat::BC3RgbaUnorm, Quality::Fast, Mipmaps::GeneratedAutomatic),
+    )?;
+
+    let file: ThmFile = ThmFile::from_dds_metadata(&dds.metadata(), ThmTextureTypeChunk::TYPE_IMAGE)?;
+    let params: &ThmTextureParamsChunk = file.params.as_ref().expect("Texture params chunk");
+
+    assert_eq!(params.format, ThmTextureParamsChunk::FORMAT_DXT5);
+    assert_eq!((params.width, params.height), (64, 32));
+    assert!(params.has_flag(ThmTextureParamsChunk::FLAG_GENERATE_MIPMAPS));
+    assert!(params.has_flag(ThmTextureParamsChunk::FLAG_HAS_ALPHA));
+    assert_eq!(file.bump.as_ref().map(|it| it.mode), Some(ThmBumpChunk::MODE_NONE));
+    assert_eq!(file.used_bump_name(), None);
+
+    let mut written: File = overwrite_generated_test_resource_as_file("thm_from_dds.thm")?;
+
+    file.write_to::<XRayByteOrder>(&mut written)?;
+
+    let read: ThmFile =
+      ThmFile::read_from_path::<XRayByteOrder, _>(&build_absolute_generated_test_resource_path("thm_from_dds.thm"))?;
+
+    assert_eq!(read.params, file.params);
+    assert_eq!(read.material, file.material);
+    assert_eq!(
+      read.write_to_buffer::<XRayByteOrder>()?,
+      file.write_to_buffer::<XRayByteOrder>()?
+    );
+
+    Ok(())
+  }
 }
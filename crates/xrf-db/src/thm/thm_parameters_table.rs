@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use xrf_error::{XrfError, XrfResult};
+use xrf_utils::{CsvTableReader, ParametersTable, parse_required_csv_number, write_csv_table};
+
+use crate::thm::chunks::thm_bump_chunk::ThmBumpChunk;
+use crate::thm::chunks::thm_detail_chunk::ThmDetailChunk;
+use crate::thm::chunks::thm_material_chunk::ThmMaterialChunk;
+use crate::thm::chunks::thm_texture_params_chunk::ThmTextureParamsChunk;
+use crate::thm::chunks::thm_texture_type_chunk::ThmTextureTypeChunk;
+use crate::thm::thm_file::ThmFile;
+
+/// Columns of a CSV table, in the order they are written.
+const CSV_COLUMNS: [&str; 10] = [
+  "path",
+  "texture_type",
+  "format",
+  "flags",
+  "material",
+  "material_weight",
+  "detail_name",
+  "detail_scale",
+  "bump_mode",
+  "bump_name",
+];
+
+/// One texture descriptor of a parameters table, the fields material passes edit in bulk.
+///
+/// A descriptor missing a chunk is listed with the value the engine falls back to, and applying a
+/// row only adds the chunk when the row asks for something else.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThmParametersRow {
+  /// Logical path of the descriptor, `textures\wpn\wpn_pm.thm`.
+  pub path: String,
+  pub texture_type: u32,
+  pub format: u32,
+  pub flags: u32,
+  pub material: u32,
+  pub material_weight: f32,
+  pub detail_name: String,
+  pub detail_scale: f32,
+  pub bump_mode: u32,
+  pub bump_name: String,
+}
+
+impl ThmParametersRow {
+  pub fn new(path: &str, file: &ThmFile) -> Self {
+    let params: ThmTextureParamsChunk = file.params.clone().unwrap_or_default();
+    let material: ThmMaterialChunk = file.material.clone().unwrap_or_default();
+    let detail: ThmDetailChunk = file.detail.clone().unwrap_or_default();
+    let bump: ThmBumpChunk = file.bump.clone().unwrap_or_default();
+
+    Self {
+      path: path.to_owned(),
+      texture_type: file.texture_type.clone().unwrap_or_default().texture_type,
+      format: params.format,
+      flags: params.flags,
+      material: material.material,
+      material_weight: material.weight,
+      detail_name: detail.name,
+      detail_scale: detail.scale,
+      bump_mode: bump.mode,
+      bump_name: bump.name,
+    }
+  }
+
+  /// Write the row into provided descriptor, leaving every field the table does not list alone.
+  pub fn apply_to(&self, file: &mut ThmFile) {
+    Self::apply_chunk(
+      &mut file.texture_type,
+      ThmTextureTypeChunk {
+        texture_type: self.texture_type,
+      },
+    );
+
+    let params: ThmTextureParamsChunk = ThmTextureParamsChunk {
+      format: self.format,
+      flags: self.flags,
+      ..file.params.clone().unwrap_or_default()
+    };
+
+    Self::apply_chunk(&mut file.params, params);
+
+    Self::apply_chunk(
+      &mut file.material,
+      ThmMaterialChunk {
+        material: self.material,
+        weight: self.material_weight,
+      },
+    );
+
+    Self::apply_chunk(
+      &mut file.detail,
+      ThmDetailChunk {
+        name: self.detail_name.clone(),
+        scale: self.detail_scale,
+      },
+    );
+
+    let bump: ThmBumpChunk = ThmBumpChunk {
+      mode: self.bump_mode,
+      name: self.bump_name.clone(),
+      ..file.bump.clone().unwrap_or_default()
+    };
+
+    Self::apply_chunk(&mut file.bump, bump);
+  }
+
+  /// Replace an existing chunk, or add a missing one when the engine default would not do.
+  fn apply_chunk<C: Default + PartialEq>(chunk: &mut Option<C>, requested: C) {
+    if chunk.is_some() || requested != C::default() {
+      *chunk = Some(requested);
+    }
+  }
+}
+
+/// Parameters of many texture descriptors, exported to be edited in a spreadsheet and applied back.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThmParametersTable {
+  pub rows: Vec<ThmParametersRow>,
+}
+
+impl ParametersTable for ThmParametersTable {
+  const TABLE_NAME: &'static str = "thm parameters table";
+
+  /// Read a CSV table by its header, so columns can be moved.
+  ///
+  /// Flags are written as hexadecimal, either `0x` prefixed or plain decimal numbers are read back.
+  fn from_csv(content: &str) -> XrfResult<Self> {
+    let reader: CsvTableReader = CsvTableReader::parse(content, Self::TABLE_NAME, &CSV_COLUMNS)?;
+    let mut rows: Vec<ThmParametersRow> = Vec::new();
+
+    for row in reader.rows() {
+      rows.push(ThmParametersRow {
+        path: row.value("path").to_owned(),
+        texture_type: row.required_number("texture_type")?,
+        format: row.required_number("format")?,
+        flags: Self::parse_flags(row.value("flags"), row.line)?,
+        material: row.required_number("material")?,
+        material_weight: row.required_number("material_weight")?,
+        detail_name: row.value("detail_name").to_owned(),
+        detail_scale: row.required_number("detail_scale")?,
+        bump_mode: row.required_number("bump_mode")?,
+        bump_name: row.value("bump_name").to_owned(),
+      });
+    }
+
+    Ok(Self { rows })
+  }
+
+  fn to_csv(&self) -> String {
+    write_csv_table(
+      &CSV_COLUMNS,
+      self.rows.iter().map(|row| {
+        vec![
+          row.path.clone(),
+          row.texture_type.to_string(),
+          row.format.to_string(),
+          format!("{:#010x}", row.flags),
+          row.material.to_string(),
+          row.material_weight.to_string(),
+          row.detail_name.clone(),
+          row.detail_scale.to_string(),
+          row.bump_mode.to_string(),
+          row.bump_name.clone(),
+        ]
+      }),
+    )
+  }
+}
+
+impl ThmParametersTable {
+  fn parse_flags(value: &str, line: usize) -> XrfResult<u32> {
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+      Some(hex) => u32::from_str_radix(hex, 16).map_err(|_| {
+        XrfError::new_parsing_error(format!(
+          "Expected a number in column 'flags' on line {line}, got '{value}'"
+        ))
+      }),
+      None => parse_required_csv_number(value, "flags", line),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_chunk::XRayByteOrder;
+  use xrf_error::XrfResult;
+  use xrf_test_utils::utils::build_absolute_generated_test_resource_path;
+  use xrf_utils::ParametersTable;
+
+  use crate::thm::chunks::thm_bump_chunk::ThmBumpChunk;
+  use crate::thm::chunks::thm_material_chunk::ThmMaterialChunk;
+  use crate::thm::chunks::thm_texture_params_chunk::ThmTextureParamsChunk;
+  use crate::thm::thm_file::ThmFile;
+  use crate::thm::thm_parameters_table::{ThmParametersRow, ThmParametersTable};
+
+  fn table() -> ThmParametersTable {
+    ThmParametersTable {
+      rows: vec![
+        ThmParametersRow {
+          path: String::from("textures\\wpn\\wpn_pm.thm"),
+          texture_type: 0,
+          format: ThmTextureParamsChunk::FORMAT_DXT5,
+          flags: 0x0200_0101,
+          material: ThmMaterialChunk::MATERIAL_PHONG_METAL,
+          material_weight: 0.75,
+          detail_name: String::from("detail\\detail_metal, old"),
+          detail_scale: 4.0,
+          bump_mode: ThmBumpChunk::MODE_USE,
+          bump_name: String::from("wpn\\wpn_pm_bump"),
+        },
+        ThmParametersRow {
+          path: String::from("textures\\terrain\\terrain_escape.thm"),
+          texture_type: 4,
+          format: ThmTextureParamsChunk::FORMAT_DXT1,
+          flags: 0x0000_0101,
+          material: ThmMaterialChunk::MATERIAL_BLINN_PHONG,
+          material_weight: 0.5,
+          detail_name: String::new(),
+          detail_scale: 1.0,
+          bump_mode: ThmBumpChunk::MODE_NONE,
+          bump_name: String::new(),
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn round_trips_csv_and_json() -> XrfResult {
+    assert_eq!(ThmParametersTable::from_csv(&table().to_csv())?, table());
+    assert_eq!(ThmParametersTable::from_json(&table().to_json()?)?, table());
+
+    let path = build_absolute_generated_test_resource_path("thm_parameters_table/textures.csv");
+
+    table().write_to_path(&path)?;
+
+    assert_eq!(ThmParametersTable::read_from_path(&path)?, table());
+
+    Ok(())
+  }
+
+  #[test]
+  fn applies_rows_onto_descriptors() -> XrfResult {
+    let mut file: ThmFile = ThmFile::default();
+
+    ThmParametersRow::new("textures\\a.thm", &file).apply_to(&mut file);
+
+    assert!(file.params.is_none() && file.material.is_none() && file.detail.is_none() && file.bump.is_none());
+
+    let row: ThmParametersRow = table().rows.remove(0);
+
+    row.apply_to(&mut file);
+
+    assert_eq!(file.used_bump_name(), Some("wpn\\wpn_pm_bump"));
+    assert_eq!(
+      file.material,
+      Some(ThmMaterialChunk {
+        material: ThmMaterialChunk::MATERIAL_PHONG_METAL,
+        weight: 0.75,
+      })
+    );
+    assert!(file.texture_type.is_none());
+
+    let read: ThmFile = ThmFile::read_from_bytes::<XRayByteOrder>(&file.write_to_buffer::<XRayByteOrder>()?)?;
+
+    assert_eq!(ThmParametersRow::new(&row.path, &read), row);
+
+    Ok(())
+  }
+
+  #[test]
+  fn refuses_incomplete_csv() {
+    assert!(ThmParametersTable::from_csv("path,format\ntextures\\a.thm,1\n").is_err());
+    assert!(ThmParametersTable::read_from_path("textures.xlsx").is_err());
+  }
+}
@@ -5,7 +5,7 @@ use std::path::Path;
 use ddsfile::{Dds, DxgiFormat};
 use image::codecs::png::PngEncoder;
 use image::{ExtendedColorType, ImageEncoder, RgbaImage};
-use image_dds::{ImageFormat, Mipmaps, Quality, dds_from_image};
+use image_dds::{ImageFormat, Mipmaps, Quality, SurfaceRgba8, dds_from_image, mip_dimension};
 use xrf_error::{XrfError, XrfResult};
 
 use crate::{DdsMetadata, DdsPng};
@@ -98,6 +98,59 @@ impl DdsFile {
   pub fn encode_rgba(image: &RgbaImage, options: DdsEncodeOptions) -> XrfResult<Self> {
     let dds: Dds = dds_from_image(image, options.format, options.quality, options.mipmaps)
       .map_err(|error| XrfError::new_texture_processing_error(error.to_string()))?;
+
+    Self::from_encoded(dds)
+  }
+
+  /// Encode a mip chain built by the caller, level 0 first.
+  ///
+  /// The generated chains of [`Self::encode_rgba`] average in gamma space and know nothing about what the
+  /// texture is used for, so callers that need a specific filter provide the levels themselves. Every level
+  /// has to be half the size of the previous one, rounded down and at least 1.
+  ///
+  /// # Errors
+  ///
+  /// Fails when no level is provided, when a level has unexpected dimensions or when the encoder fails.
+  pub fn encode_rgba_levels(levels: &[RgbaImage], format: ImageFormat, quality: Quality) -> XrfResult<Self> {
+    let base: &RgbaImage = levels
+      .first()
+      .ok_or_else(|| XrfError::new_texture_processing_error("Expected at least one mip level to encode"))?;
+
+    for (level, image) in levels.iter().enumerate() {
+      let level: u32 = u32::try_from(level)
+        .map_err(|_| XrfError::new_texture_processing_error("Mip chain exceeds the supported length"))?;
+      let expected: (u32, u32) = (mip_dimension(base.width(), level), mip_dimension(base.height(), level));
+
+      if image.dimensions() != expected {
+        return Err(XrfError::new_texture_processing_error(format!(
+          "Mip level {level} is {}x{} while {}x{} is expected",
+          image.width(),
+          image.height(),
+          expected.0,
+          expected.1
+        )));
+      }
+    }
+
+    let dds: Dds = SurfaceRgba8 {
+      width: base.width(),
+      height: base.height(),
+      depth: 1,
+      layers: 1,
+      mipmaps: u32::try_from(levels.len())
+        .map_err(|_| XrfError::new_texture_processing_error("Mip chain exceeds the supported length"))?,
+      data: levels
+        .iter()
+        .flat_map(|image| image.as_raw().iter().copied())
+        .collect::<Vec<u8>>(),
+    }
+    .encode_dds(format, quality, Mipmaps::FromSurface)
+    .map_err(|error| XrfError::new_texture_processing_error(error.to_string()))?;
+
+    Self::from_encoded(dds)
+  }
+
+  fn from_encoded(dds: Dds) -> XrfResult<Self> {
     let data_size: u64 = u64::try_from(dds.data.len())
       .map_err(|_| XrfError::new_texture_processing_error("Encoded DDS exceeds the supported size range"))?;
     let metadata_size: u64 = if dds.header10.is_some() {
@@ -252,6 +305,30 @@ mod tests {
     assert_eq!(flat.mipmap_levels, 1);
   }
 
+  #[test]
+  fn encodes_a_provided_mip_chain() {
+    let levels: Vec<RgbaImage> = vec![RgbaImage::new(8, 3), RgbaImage::new(4, 1), RgbaImage::new(2, 1)];
+    let encoded: DdsFile = DdsFile::encode_rgba_levels(&levels, ImageFormat::BC1RgbaUnorm, Quality::Fast)
+      .expect("expect the chain to encode");
+
+    assert_eq!(encoded.metadata().mipmap_levels, 3);
+    assert_eq!(
+      encoded.decode_rgba(2).expect("expect the last level").dimensions(),
+      (2, 1)
+    );
+    assert!(encoded.is_xray_compatible());
+
+    assert!(DdsFile::encode_rgba_levels(&[], ImageFormat::BC1RgbaUnorm, Quality::Fast).is_err());
+    assert!(
+      DdsFile::encode_rgba_levels(
+        &[RgbaImage::new(8, 8), RgbaImage::new(8, 8)],
+        ImageFormat::BC1RgbaUnorm,
+        Quality::Fast
+      )
+      .is_err()
+    );
+  }
+
   #[test]
   fn reads_the_same_metadata_from_the_header_alone() {
     // The header-only read exists so a survey can answer format questions without reading gigabytes of payload. It is
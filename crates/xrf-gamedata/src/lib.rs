@@ -7,5 +7,6 @@ pub use project::gamedata_project_options::*;
 pub use project::gamedata_verification_result::*;
 pub use project::gamedata_verification_rule::*;
 pub use project::gamedata_verification_type::*;
+pub use project::texture_usage::collect_texture_usage_result::*;
 pub use xrf_report::Finding;
 pub use xrf_report::Status as GamedataVerificationStatus;
@@ -0,0 +1,2 @@
+pub(crate) mod verify_dialogs;
+pub(crate) mod verify_dialogs_result;
@@ -0,0 +1,425 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use xrf_error::XrfResult;
+use xrf_gameplay::{GameplayDialog, GameplayDialogPhrase, GameplayDialogs, GameplayDocument};
+use xrf_lua::XRayLuaScriptAnalyzer;
+use xrf_translation::read_string_table_bytes;
+use xrf_vfs::XrayLogicalPath;
+
+use crate::GamedataFindingFactory;
+use crate::project::dialogs::verify_dialogs_result::GamedataDialogsVerificationResult;
+use crate::{Finding, GamedataProject, GamedataProjectVerifyOptions, GamedataVerificationRule};
+
+const GAMEPLAY_DIRECTORY: &str = "configs\\gameplay";
+const TEXT_DIRECTORY: &str = "configs\\text";
+const START_PHRASE_ID: &str = "0";
+
+/// What dialogs reference outside of their own files.
+struct DialogReferences {
+  /// String ids of every language, `None` when the gamedata ships no string tables to check against.
+  texts: Option<HashSet<String>>,
+  /// Declared info portions. Left unchecked when empty, as engines that do not need declarations ship none.
+  info_portions: HashSet<String>,
+  scripts: XRayLuaScriptAnalyzer,
+}
+
+impl GamedataProject {
+  /// Verify dialog trees of `configs\gameplay` and what they reference.
+  ///
+  /// Each phrase has to be reachable from the start phrase, and every `next` has to name a phrase of the dialog. Phrase
+  /// texts have to be string table ids, script hooks functions of the script namespaces, and info portions declared.
+  pub fn verify_dialogs(&self, options: &GamedataProjectVerifyOptions) -> XrfResult<GamedataDialogsVerificationResult> {
+    xrf_output::heading!(options.output, "Verify dialogs:");
+
+    let started_at: Instant = Instant::now();
+    let mut result: GamedataDialogsVerificationResult = GamedataDialogsVerificationResult::default();
+    let mut dialog_files: Vec<(PathBuf, GameplayDialogs)> = Vec::new();
+    let mut info_portions: HashSet<String> = HashSet::new();
+
+    for (path, logical_path) in self.xml_entries_under(GAMEPLAY_DIRECTORY) {
+      match self
+        .read_asset(logical_path.as_str())
+        .and_then(|bytes| GameplayDocument::parse_bytes(&bytes))
+      {
+        Ok(Some(GameplayDocument::Dialogs(dialogs))) => dialog_files.push((path, dialogs)),
+        Ok(Some(GameplayDocument::InfoPortions(declared))) => {
+          info_portions.extend(declared.info_portions.into_iter().map(|info_portion| info_portion.id))
+        }
+        Ok(Some(GameplayDocument::Tasks(_)) | None) => {}
+        Err(error) => {
+          result.unread_files_count += 1;
+          result.findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::DialogsRead,
+            &path,
+            format!("Could not read gameplay XML: {error}"),
+          ));
+        }
+      }
+    }
+
+    let references: DialogReferences = DialogReferences {
+      texts: self.read_dialog_texts(options, &mut result),
+      info_portions,
+      scripts: self.read_script_analyzer(options),
+    };
+
+    for (path, dialogs) in &dialog_files {
+      xrf_output::verbose!(options.output, "Verify dialogs: {}", path.display());
+
+      for dialog in &dialogs.dialogs {
+        let findings_count: usize = result.findings.len();
+
+        Self::verify_dialog(path, dialog, &references, &mut result.findings);
+
+        result.checked_dialogs_count += 1;
+
+        if result.findings.len() > findings_count {
+          result.invalid_dialogs_count += 1;
+        }
+      }
+    }
+
+    result
+      .findings
+      .sort_by(GamedataFindingFactory::cmp_by_asset_path_rule_and_message);
+    result.duration = started_at.elapsed();
+
+    xrf_output::info!(
+      options.output,
+      "Verified gamedata dialogs in {}, {}/{} valid",
+      xrf_utils::format_duration(result.duration),
+      result.checked_dialogs_count - result.invalid_dialogs_count,
+      result.checked_dialogs_count
+    );
+
+    Ok(result)
+  }
+
+  fn verify_dialog(path: &Path, dialog: &GameplayDialog, references: &DialogReferences, findings: &mut Vec<Finding>) {
+    let subject: String = format!("Dialog `{}`", dialog.id);
+
+    Self::verify_dialog_graph(path, dialog, findings);
+    Self::verify_dialog_functions(
+      path,
+      &subject,
+      "precondition",
+      &dialog.conditions.preconditions,
+      references,
+      findings,
+    );
+    Self::verify_dialog_functions(
+      path,
+      &subject,
+      "init_func",
+      dialog.init_func.as_slice(),
+      references,
+      findings,
+    );
+    Self::verify_dialog_info_portions(
+      path,
+      &subject,
+      [&dialog.conditions.has_info, &dialog.conditions.dont_has_info],
+      references,
+      findings,
+    );
+
+    for phrase in &dialog.phrases {
+      let subject: String = format!("Dialog `{}` phrase `{}`", dialog.id, phrase.id);
+
+      if let (Some(text), Some(texts)) = (&phrase.text, &references.texts)
+        && !texts.contains(text)
+      {
+        findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::DialogsMissingText,
+          path,
+          format!("{subject} text `{text}` has no string table entry"),
+        ));
+      }
+
+      Self::verify_dialog_functions(
+        path,
+        &subject,
+        "precondition",
+        &phrase.conditions.preconditions,
+        references,
+        findings,
+      );
+      Self::verify_dialog_functions(path, &subject, "action", &phrase.effects.actions, references, findings);
+      Self::verify_dialog_functions(
+        path,
+        &subject,
+        "script_text",
+        phrase.script_text.as_slice(),
+        references,
+        findings,
+      );
+      Self::verify_dialog_info_portions(
+        path,
+        &subject,
+        [
+          &phrase.conditions.has_info,
+          &phrase.conditions.dont_has_info,
+          &phrase.effects.give_info,
+          &phrase.effects.disable_info,
+        ],
+        references,
+        findings,
+      );
+    }
+  }
+
+  /// Checks `next` links and that every phrase can be reached from the start phrase.
+  fn verify_dialog_graph(path: &Path, dialog: &GameplayDialog, findings: &mut Vec<Finding>) {
+    if dialog.phrases.is_empty() {
+      return;
+    }
+
+    let phrases: HashMap<&str, &GameplayDialogPhrase> = dialog
+      .phrases
+      .iter()
+      .rev()
+      .map(|phrase| (phrase.id.as_str(), phrase))
+      .collect();
+
+    for phrase in &dialog.phrases {
+      for next in phrase.next.iter().filter(|next| !phrases.contains_key(next.as_str())) {
+        findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::DialogsMissingPhrase,
+          path,
+          format!(
+            "Dialog `{}` phrase `{}` leads to missing phrase `{next}`",
+            dialog.id, phrase.id
+          ),
+        ));
+      }
+    }
+
+    if !phrases.contains_key(START_PHRASE_ID) {
+      findings.push(GamedataFindingFactory::for_asset(
+        GamedataVerificationRule::DialogsMissingPhrase,
+        path,
+        format!("Dialog `{}` has no start phrase `{START_PHRASE_ID}`", dialog.id),
+      ));
+
+      return;
+    }
+
+    let mut reached: HashSet<&str> = HashSet::from([START_PHRASE_ID]);
+    let mut queue: VecDeque<&str> = VecDeque::from([START_PHRASE_ID]);
+
+    while let Some(id) = queue.pop_front() {
+      for next in phrases.get(id).into_iter().flat_map(|phrase| &phrase.next) {
+        if phrases.contains_key(next.as_str()) && reached.insert(next) {
+          queue.push_back(next);
+        }
+      }
+    }
+
+    for phrase in dialog
+      .phrases
+      .iter()
+      .filter(|phrase| !reached.contains(phrase.id.as_str()))
+    {
+      findings.push(GamedataFindingFactory::for_asset(
+        GamedataVerificationRule::DialogsUnreachablePhrase,
+        path,
+        format!(
+          "Dialog `{}` phrase `{}` is unreachable from the start phrase",
+          dialog.id, phrase.id
+        ),
+      ));
+    }
+  }
+
+  fn verify_dialog_functions(
+    path: &Path,
+    subject: &str,
+    hook: &str,
+    functions: &[String],
+    references: &DialogReferences,
+    findings: &mut Vec<Finding>,
+  ) {
+    for function in functions {
+      if references.scripts.resolves_function(function) == Some(false) {
+        findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::DialogsScriptFunction,
+          path,
+          format!("{subject} {hook} `{function}` is not defined by any script"),
+        ));
+      }
+    }
+  }
+
+  fn verify_dialog_info_portions<const N: usize>(
+    path: &Path,
+    subject: &str,
+    lists: [&Vec<String>; N],
+    references: &DialogReferences,
+    findings: &mut Vec<Finding>,
+  ) {
+    if references.info_portions.is_empty() {
+      return;
+    }
+
+    for info_portion in lists.into_iter().flatten() {
+      if !references.info_portions.contains(info_portion) {
+        findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::DialogsInfoPortion,
+          path,
+          format!("{subject} references info portion `{info_portion}`, which no gameplay XML declares"),
+        ));
+      }
+    }
+  }
+
+  /// String ids of every language's string tables, read through the VFS.
+  fn read_dialog_texts(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataDialogsVerificationResult,
+  ) -> Option<HashSet<String>> {
+    let mut texts: HashSet<String> = HashSet::new();
+    let mut is_found: bool = false;
+
+    for (path, logical_path) in self.xml_entries_under(TEXT_DIRECTORY) {
+      is_found = true;
+
+      // Language is told from the parent directory, which a backslash-separated logical path does not have here.
+      let language_path: PathBuf = PathBuf::from(logical_path.as_str().replace('\\', "/"));
+
+      match self
+        .read_asset(logical_path.as_str())
+        .and_then(|bytes| read_string_table_bytes(&language_path, &bytes, &options.languages))
+      {
+        Ok(entries) => texts.extend(entries.into_iter().map(|(id, _)| id)),
+        Err(error) => {
+          result.unread_files_count += 1;
+          result.findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::DialogsRead,
+            &path,
+            format!("Could not read string table: {error}"),
+          ));
+        }
+      }
+    }
+
+    is_found.then_some(texts)
+  }
+
+  /// XML entries under a directory, with the path to report and the logical path to read.
+  fn xml_entries_under(&self, directory: &str) -> Vec<(PathBuf, XrayLogicalPath)> {
+    self
+      .entries()
+      .into_iter()
+      .filter(|location| {
+        let logical_path: &XrayLogicalPath = location.get_logical_path();
+
+        logical_path.is_under(directory).unwrap_or(false) && logical_path.has_extension(".xml")
+      })
+      .map(|location| {
+        let logical_path: XrayLogicalPath = location.get_logical_path().clone();
+        let path: PathBuf = location
+          .to_physical_path()
+          .unwrap_or_else(|| PathBuf::from(logical_path.as_str()));
+
+        (path, logical_path)
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use crate::project::tests::fixtures::{project_root, write_project_file};
+  use crate::{
+    GamedataCheckResult, GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions,
+    GamedataVerificationStatus,
+  };
+
+  #[test]
+  fn reports_broken_dialog_graphs_and_references() {
+    let root: PathBuf = project_root("gamedata_dialogs");
+
+    write_project_file(&root, "configs/system.ltx", "");
+    write_project_file(
+      &root,
+      "configs/text/eng/st_dialogs.xml",
+      "<string_table><string id=\"st_hello\"><text>Hello</text></string></string_table>",
+    );
+    write_project_file(
+      &root,
+      "configs/gameplay/info_portions.xml",
+      "<game_information_portions><info_portion id=\"met_trader\"/></game_information_portions>",
+    );
+    write_project_file(
+      &root,
+      "configs/gameplay/dialogs.xml",
+      "<game_dialogs>\
+         <dialog id=\"trader\">\
+           <precondition>dialogs.is_friend</precondition>\
+           <phrase_list>\
+             <phrase id=\"0\"><text>st_hello</text><next>1</next><next>5</next></phrase>\
+             <phrase id=\"1\"><text>st_missing</text><give_info>met_trader</give_info>\
+               <action>dialogs.missing_action</action></phrase>\
+             <phrase id=\"2\"><text>st_hello</text><has_info>unknown_info</has_info></phrase>\
+           </phrase_list>\
+         </dialog>\
+         <dialog id=\"valid\"><phrase_list><phrase id=\"0\"><text>st_hello</text></phrase></phrase_list></dialog>\
+       </game_dialogs>",
+    );
+    write_project_file(
+      &root,
+      "scripts/dialogs.script",
+      "function is_friend(first, second) return true end\n",
+    );
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result = project
+      .verify_dialogs(&GamedataProjectVerifyOptions::default())
+      .expect("dialogs verification completes");
+
+    assert_eq!(result.get_status(), GamedataVerificationStatus::Failed);
+    assert_eq!(result.get_failure_message(), "1/2 dialogs valid; 0 files unread");
+    assert_eq!(
+      result
+        .get_findings()
+        .iter()
+        .map(|finding| (finding.rule_id().as_str(), finding.message()))
+        .collect::<Vec<_>>(),
+      vec![
+        (
+          "dialogs.info-portion",
+          "Dialog `trader` phrase `2` references info portion `unknown_info`, which no gameplay XML declares"
+        ),
+        (
+          "dialogs.missing-phrase",
+          "Dialog `trader` phrase `0` leads to missing phrase `5`"
+        ),
+        (
+          "dialogs.missing-text",
+          "Dialog `trader` phrase `1` text `st_missing` has no string table entry"
+        ),
+        (
+          "dialogs.script-function",
+          "Dialog `trader` phrase `1` action `dialogs.missing_action` is not defined by any script"
+        ),
+        (
+          "dialogs.unreachable-phrase",
+          "Dialog `trader` phrase `2` is unreachable from the start phrase"
+        ),
+      ]
+    );
+
+    fs::remove_dir_all(root).expect("cleanup");
+  }
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use crate::{Finding, GamedataCheckResult, GamedataVerificationStatus};
+
+#[derive(Default)]
+pub struct GamedataDialogsVerificationResult {
+  pub(crate) duration: Duration,
+  pub(crate) findings: Vec<Finding>,
+  pub(crate) checked_dialogs_count: u32,
+  pub(crate) invalid_dialogs_count: u32,
+  pub(crate) unread_files_count: u32,
+}
+
+impl GamedataCheckResult for GamedataDialogsVerificationResult {
+  fn get_duration(&self) -> Option<Duration> {
+    Some(self.duration)
+  }
+
+  /// Broken dialogs fail the check. Otherwise an unreadable gameplay XML or string table reports incomplete: phrase
+  /// texts and info portions were then checked against partial id tables.
+  fn get_status(&self) -> GamedataVerificationStatus {
+    if self.invalid_dialogs_count != 0 {
+      GamedataVerificationStatus::Failed
+    } else if self.unread_files_count != 0 {
+      GamedataVerificationStatus::Incomplete
+    } else {
+      GamedataVerificationStatus::Passed
+    }
+  }
+
+  fn get_failure_message(&self) -> String {
+    format!(
+      "{}/{} dialogs valid; {} files unread",
+      self.checked_dialogs_count - self.invalid_dialogs_count,
+      self.checked_dialogs_count,
+      self.unread_files_count
+    )
+  }
+
+  fn get_findings(&self) -> &[Finding] {
+    &self.findings
+  }
+}
@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 
 use xrf_output::OutputOptions;
+use xrf_translation::TranslationLanguages;
 
 use crate::project::gamedata_verification_type::GamedataVerificationType;
 
@@ -18,6 +19,8 @@ pub struct GamedataProjectVerifyOptions {
   pub output: OutputOptions,
   pub is_strict: bool,
   pub checks: Vec<GamedataVerificationType>,
+  /// Configured translation languages, resolving the encoding of string tables that do not declare one.
+  pub languages: TranslationLanguages,
 }
 
 impl GamedataProjectVerifyOptions {
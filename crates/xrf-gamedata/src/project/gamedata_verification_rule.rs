@@ -12,6 +12,18 @@ pub enum GamedataVerificationRule {
   AnimationsPlayerHud,
   #[display("checks.execution")]
   CheckExecution,
+  #[display("dialogs.info-portion")]
+  DialogsInfoPortion,
+  #[display("dialogs.missing-phrase")]
+  DialogsMissingPhrase,
+  #[display("dialogs.missing-text")]
+  DialogsMissingText,
+  #[display("dialogs.read")]
+  DialogsRead,
+  #[display("dialogs.script-function")]
+  DialogsScriptFunction,
+  #[display("dialogs.unreachable-phrase")]
+  DialogsUnreachablePhrase,
   #[display("levels.ai-guid")]
   LevelsAiGuid,
   #[display("levels.ai-node-count")]
@@ -60,6 +72,12 @@ pub enum GamedataVerificationRule {
   LtxSchema,
   #[display("ltx.verification")]
   LtxVerification,
+  #[display("ltx-usage.read")]
+  LtxUsageRead,
+  #[display("ltx-usage.unreachable-section")]
+  LtxUsageUnreachableSection,
+  #[display("ltx-usage.unused-section")]
+  LtxUsageUnusedSection,
   #[display("meshes.motion-read")]
   MeshesMotionRead,
   #[display("meshes.motion-validation")]
@@ -82,12 +100,30 @@ pub enum GamedataVerificationRule {
   ParticlesUsageSpawn,
   #[display("particles-usage.spawn-custom-data")]
   ParticlesUsageSpawnCustomData,
+  #[display("scripts.call-arity")]
+  ScriptsCallArity,
+  #[display("scripts.info-portion-reference")]
+  ScriptsInfoPortionReference,
   #[display("scripts.path")]
   ScriptsPath,
   #[display("scripts.read")]
   ScriptsRead,
+  #[display("scripts.section-reference")]
+  ScriptsSectionReference,
+  #[display("scripts.sound-reference")]
+  ScriptsSoundReference,
   #[display("scripts.syntax")]
   ScriptsSyntax,
+  #[display("scripts.texture-reference")]
+  ScriptsTextureReference,
+  #[display("scripts.undefined-global")]
+  ScriptsUndefinedGlobal,
+  #[display("scripts.unknown-function")]
+  ScriptsUnknownFunction,
+  #[display("scripts.unknown-namespace")]
+  ScriptsUnknownNamespace,
+  #[display("shaders.compile")]
+  ShadersCompile,
   #[display("shaders.include-cycle")]
   ShadersIncludeCycle,
   #[display("shaders.include-missing")]
@@ -112,10 +148,20 @@ pub enum GamedataVerificationRule {
   SpawnsRead,
   #[display("textures.bump")]
   TexturesBump,
+  #[display("textures.bump-pair")]
+  TexturesBumpPair,
+  #[display("textures.bump-size")]
+  TexturesBumpSize,
+  #[display("textures.detail")]
+  TexturesDetail,
   #[display("textures.path")]
   TexturesPath,
   #[display("textures.read")]
   TexturesRead,
+  #[display("textures.thm-format")]
+  TexturesThmFormat,
+  #[display("textures.thm-missing")]
+  TexturesThmMissing,
   #[display("textures.dds")]
   TexturesValidation,
   #[display("weapons.validation")]
@@ -9,10 +9,14 @@ use crate::{GamedataCheckResult, GamedataProject, GamedataProjectVerifyOptions,
 pub enum GamedataVerificationType {
   #[display("animations")]
   Animations,
+  #[display("dialogs")]
+  Dialogs,
   #[display("levels")]
   Levels,
   #[display("ltx")]
   Ltx,
+  #[display("ltx-usage")]
+  LtxUsage,
   #[display("meshes")]
   Meshes,
   #[display("particles")]
@@ -36,10 +40,12 @@ pub enum GamedataVerificationType {
 }
 
 impl GamedataVerificationType {
-  pub const ALL: [Self; 13] = [
+  pub const ALL: [Self; 15] = [
     Self::Animations,
+    Self::Dialogs,
     Self::Levels,
     Self::Ltx,
+    Self::LtxUsage,
     Self::Meshes,
     Self::Particles,
     Self::ParticlesUsage,
@@ -63,8 +69,10 @@ impl GamedataVerificationType {
   ) -> GamedataVerificationCheckReport {
     match self {
       Self::Animations => Self::check_report(self, project.verify_animations(options)),
+      Self::Dialogs => Self::check_report(self, project.verify_dialogs(options)),
       Self::Levels => Self::check_report(self, project.verify_levels(options)),
       Self::Ltx => Self::check_report(self, project.verify_ltx(options)),
+      Self::LtxUsage => Self::check_report(self, project.verify_ltx_usage(options)),
       Self::Meshes => Self::check_report(self, project.verify_meshes(options)),
       Self::Particles => Self::check_report(self, project.verify_particles(options)),
       Self::ParticlesUsage => Self::check_report(self, project.verify_particles_usage(options)),
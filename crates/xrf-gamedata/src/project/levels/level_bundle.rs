@@ -1,4 +1,4 @@
-use xrf_vfs::XrayAssetType;
+use xrf_vfs::{XrayAsset, XrayAssetType};
 
 use crate::GamedataProject;
 use crate::project::levels::level_engine_constants::LEVELS_DIRECTORY;
@@ -69,28 +69,31 @@ impl<'a> LevelBundle<'a> {
   /// atlases shipped inside the bundle resolve even though they are absent from the shared texture
   /// tree. `$game_saves$` is probed in between, but it is not part of gamedata.
   pub(crate) fn resolves_texture(&self, reference: &str) -> bool {
+    self.resolve_texture(reference).is_some()
+  }
+
+  /// The texture asset a reference resolves to, probed in the same order as [`Self::resolves_texture`].
+  pub(crate) fn resolve_texture(&self, reference: &str) -> Option<XrayAsset> {
     let logical: String = XrayAssetType::Dds
       .get_rules()
       .expect("dds has rules")
       .to_logical_path(reference);
 
-    let in_bundle: bool = self
+    self
       .project
       .vfs()
       .scoped(self.project.scope())
       .find(&format!("{}\\{logical}", self.path()))
       .ok()
       .flatten()
-      .is_some();
-
-    in_bundle
-      || self
-        .project
-        .vfs()
-        .scoped(self.project.scope())
-        .dds_texture(reference)
-        .ok()
-        .flatten()
-        .is_some()
+      .or_else(|| {
+        self
+          .project
+          .vfs()
+          .scoped(self.project.scope())
+          .dds_texture(reference)
+          .ok()
+          .flatten()
+      })
   }
 }
@@ -0,0 +1,2 @@
+pub(crate) mod verify_ltx_usage;
+pub(crate) mod verify_ltx_usage_result;
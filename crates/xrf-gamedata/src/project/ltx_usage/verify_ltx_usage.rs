@@ -0,0 +1,246 @@
+use std::io::Cursor;
+use std::path::Path;
+use std::time::Instant;
+
+use xrf_db::{SpawnFile, XRayByteOrder};
+use xrf_error::{XrfError, XrfResult};
+use xrf_ltx::{Ltx, LtxProjectUsageResult, LtxSectionReferrer, LtxVerifyOptions};
+use xrf_lua::XRayLuaScript;
+use xrf_utils::read_as_string_from_w1251_encoded;
+use xrf_vfs::XrayAssetType as AssetType;
+
+use crate::GamedataFindingFactory;
+use crate::project::levels::level_engine_constants::SPAWNS_DIRECTORY;
+use crate::project::ltx_usage::verify_ltx_usage_result::GamedataLtxUsageVerificationResult;
+use crate::project::scripts::runtime_script::is_runtime_script;
+use crate::{GamedataProject, GamedataProjectVerifyOptions, GamedataVerificationRule};
+
+impl GamedataProject {
+  /// Verify that every config section is used by something the game loads.
+  ///
+  /// A section is used when another live section inherits it or names it in a field, when a spawn file places it, or when
+  /// a script mentions its name as a string literal. Sections nothing references are reported as unused, and sections
+  /// no chain of references from outside the configs leads to as unreachable: both can be deleted without the engine noticing, short of a name built
+  /// at runtime or hardcoded in the engine.
+  pub fn verify_ltx_usage(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+  ) -> XrfResult<GamedataLtxUsageVerificationResult> {
+    xrf_output::heading!(options.output, "Verify LTX sections usage:");
+
+    let started_at: Instant = Instant::now();
+    let mut usage: LtxProjectUsageResult = self.ltx_project.collect_section_usage_opt(LtxVerifyOptions {
+      output: options.output.clone(),
+    })?;
+    let mut result: GamedataLtxUsageVerificationResult = GamedataLtxUsageVerificationResult::default();
+
+    for error in &usage.errors {
+      result.findings.push(GamedataFindingFactory::without_asset(
+        GamedataVerificationRule::LtxUsageRead,
+        error.to_string(),
+      ));
+    }
+
+    self.collect_ltx_usage_in_spawns(options, &mut usage, &mut result);
+    self.collect_ltx_usage_in_scripts(options, &mut usage, &mut result);
+
+    let unused: Vec<&str> = usage.get_unused_sections();
+    let unreachable: Vec<&str> = usage.get_unreachable_sections();
+
+    for section in &unused {
+      result.findings.push(GamedataFindingFactory::for_asset(
+        GamedataVerificationRule::LtxUsageUnusedSection,
+        &usage.declarations[*section],
+        format!("Section [{section}] is never referenced"),
+      ));
+    }
+
+    for section in &unreachable {
+      result.findings.push(GamedataFindingFactory::for_asset(
+        GamedataVerificationRule::LtxUsageUnreachableSection,
+        &usage.declarations[*section],
+        format!("Section [{section}] is referenced only by unused or unreachable sections"),
+      ));
+    }
+
+    result.total_sections_count = Self::usage_count(usage.declarations.len())?;
+    result.unread_sources_count += Self::usage_count(usage.errors.len())?;
+    result.unused_sections_count = Self::usage_count(unused.len())?;
+    result.unreachable_sections_count = Self::usage_count(unreachable.len())?;
+    result
+      .findings
+      .sort_by(GamedataFindingFactory::cmp_by_asset_path_rule_and_message);
+    result.duration = started_at.elapsed();
+
+    xrf_output::info!(
+      options.output,
+      "Verified gamedata LTX sections usage in {}, {} unused and {} unreachable of {} sections",
+      xrf_utils::format_duration(result.duration),
+      result.unused_sections_count,
+      result.unreachable_sections_count,
+      result.total_sections_count
+    );
+
+    Ok(result)
+  }
+
+  /// Records sections placed by spawn files, and any a spawned object's custom data names.
+  fn collect_ltx_usage_in_spawns(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    usage: &mut LtxProjectUsageResult,
+    result: &mut GamedataLtxUsageVerificationResult,
+  ) {
+    for asset in self
+      .entries_of_type(AssetType::Spawn)
+      .into_iter()
+      .filter(|location| location.get_logical_path().is_under(SPAWNS_DIRECTORY).unwrap_or(false))
+    {
+      let spawn_path: String = asset.get_logical_path().to_string();
+      let referrer: LtxSectionReferrer = LtxSectionReferrer::External(spawn_path.clone());
+
+      xrf_output::verbose!(options.output, "Collect section usage in spawn: {spawn_path}");
+
+      let spawn_file: SpawnFile = match self
+        .read_resolved_chunks(&asset)
+        .and_then(|mut chunks| SpawnFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
+      {
+        Ok(spawn_file) => spawn_file,
+        Err(error) => {
+          result.findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::LtxUsageRead,
+            &spawn_path,
+            format!("Could not inspect spawn file for section usage: {error}"),
+          ));
+          result.unread_sources_count += 1;
+          continue;
+        }
+      };
+
+      for object in &spawn_file.alife_spawn.objects {
+        usage.add_reference(&object.section, &referrer);
+
+        let Some(custom_data) = object.inherited.get_custom_data() else {
+          continue;
+        };
+
+        // Custom data names logic sections the same free-form way an unschemed config does.
+        if let Ok(ltx) = Ltx::read_from_str(custom_data) {
+          for (_, section) in &ltx {
+            for (_, value) in section {
+              usage.add_value_references(value, &referrer);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  /// Records sections runtime scripts name as string literals.
+  fn collect_ltx_usage_in_scripts(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    usage: &mut LtxProjectUsageResult,
+    result: &mut GamedataLtxUsageVerificationResult,
+  ) {
+    for asset in self
+      .entries_of_type(AssetType::Script)
+      .into_iter()
+      .filter(|location| is_runtime_script(location.get_logical_path().as_str()))
+    {
+      let script_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect section usage in script: {script_path}");
+
+      let script: XRayLuaScript = match self.read_resolved(&asset).and_then(|bytes| {
+        XRayLuaScript::parse(
+          Path::new(&script_path),
+          &read_as_string_from_w1251_encoded(&mut Cursor::new(bytes))?,
+        )
+      }) {
+        Ok(script) => script,
+        Err(error) => {
+          result.findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::LtxUsageRead,
+            &script_path,
+            format!("Could not inspect script for section usage: {error}"),
+          ));
+          result.unread_sources_count += 1;
+          continue;
+        }
+      };
+
+      let referrer: LtxSectionReferrer = LtxSectionReferrer::External(script_path);
+
+      for literal in script.string_literals() {
+        usage.add_reference(literal.trim(), &referrer);
+      }
+    }
+  }
+
+  fn usage_count(count: usize) -> XrfResult<u32> {
+    u32::try_from(count).map_err(|_| XrfError::new_verify_error("Section count exceeds the supported result range"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use crate::project::tests::fixtures::{project_root, write_project_file};
+  use crate::{
+    Finding, GamedataCheckResult, GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions,
+    GamedataVerificationStatus,
+  };
+
+  #[test]
+  fn reports_sections_no_config_or_script_reaches() {
+    let root: PathBuf = project_root("gamedata_ltx_usage");
+
+    write_project_file(
+      &root,
+      "configs/system.ltx",
+      "[item_base]\n\n[item_live]:item_base\n\n[item_dead]:item_base\nupgrade = upgrade_dead\n\n[upgrade_dead]\n",
+    );
+    write_project_file(
+      &root,
+      "scripts/spawner.script",
+      "alife():create(\"item_live\", position, 0, 0)\n",
+    );
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result = project
+      .verify_ltx_usage(&GamedataProjectVerifyOptions::default())
+      .expect("usage verification completes");
+
+    assert_eq!(result.get_status(), GamedataVerificationStatus::Failed);
+    assert_eq!(
+      result
+        .get_findings()
+        .iter()
+        .map(|finding| (finding.rule_id().as_str(), finding.message()))
+        .collect::<Vec<_>>(),
+      vec![
+        (
+          "ltx-usage.unreachable-section",
+          "Section [upgrade_dead] is referenced only by unused or unreachable sections"
+        ),
+        ("ltx-usage.unused-section", "Section [item_dead] is never referenced"),
+      ]
+    );
+    assert!(
+      result
+        .get_findings()
+        .iter()
+        .all(|finding: &Finding| finding.subject().is_some_and(|subject| subject.ends_with("system.ltx")))
+    );
+
+    fs::remove_dir_all(root).expect("cleanup");
+  }
+}
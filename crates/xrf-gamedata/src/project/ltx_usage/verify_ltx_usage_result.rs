@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use crate::{Finding, GamedataCheckResult, GamedataVerificationStatus};
+
+#[derive(Default)]
+pub struct GamedataLtxUsageVerificationResult {
+  pub(crate) duration: Duration,
+  pub(crate) findings: Vec<Finding>,
+  pub(crate) total_sections_count: u32,
+  pub(crate) unread_sources_count: u32,
+  pub(crate) unreachable_sections_count: u32,
+  pub(crate) unused_sections_count: u32,
+}
+
+impl GamedataCheckResult for GamedataLtxUsageVerificationResult {
+  fn get_duration(&self) -> Option<Duration> {
+    Some(self.duration)
+  }
+
+  /// An unread source can hide the only reference to a section, so it leaves the result incomplete rather than valid.
+  fn get_status(&self) -> GamedataVerificationStatus {
+    if self.unused_sections_count != 0 || self.unreachable_sections_count != 0 {
+      GamedataVerificationStatus::Failed
+    } else if self.unread_sources_count != 0 {
+      GamedataVerificationStatus::Incomplete
+    } else {
+      GamedataVerificationStatus::Passed
+    }
+  }
+
+  fn get_failure_message(&self) -> String {
+    format!(
+      "{} unused and {} unreachable of {} sections; {} sources unread",
+      self.unused_sections_count, self.unreachable_sections_count, self.total_sections_count, self.unread_sources_count,
+    )
+  }
+
+  fn get_findings(&self) -> &[Finding] {
+    &self.findings
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::GamedataLtxUsageVerificationResult;
+  use crate::{GamedataCheckResult, GamedataVerificationStatus};
+
+  #[test]
+  fn dead_sections_fail_and_unread_sources_leave_usage_incomplete() {
+    let dead: GamedataLtxUsageVerificationResult = GamedataLtxUsageVerificationResult {
+      total_sections_count: 3,
+      unreachable_sections_count: 1,
+      unused_sections_count: 1,
+      ..Default::default()
+    };
+    let unread: GamedataLtxUsageVerificationResult = GamedataLtxUsageVerificationResult {
+      unread_sources_count: 1,
+      ..Default::default()
+    };
+
+    assert_eq!(dead.get_status(), GamedataVerificationStatus::Failed);
+    assert_eq!(
+      dead.get_failure_message(),
+      "1 unused and 1 unreachable of 3 sections; 0 sources unread"
+    );
+    assert_eq!(unread.get_status(), GamedataVerificationStatus::Incomplete);
+  }
+}
@@ -1,4 +1,5 @@
 pub(crate) mod animations;
+pub(crate) mod dialogs;
 pub(crate) mod gamedata_check_result;
 pub(crate) mod gamedata_finding_factory;
 pub(crate) mod gamedata_project;
@@ -9,12 +10,14 @@ pub(crate) mod gamedata_verification_rule;
 pub(crate) mod gamedata_verification_type;
 pub(crate) mod levels;
 pub(crate) mod ltx;
+pub(crate) mod ltx_usage;
 pub(crate) mod meshes;
 pub(crate) mod particles;
 pub(crate) mod scripts;
 pub(crate) mod shaders;
 pub(crate) mod sounds;
 pub(crate) mod spawns;
+pub(crate) mod texture_usage;
 pub(crate) mod textures;
 pub(crate) mod weapons;
 pub(crate) mod weathers;
@@ -1,3 +1,4 @@
 pub(crate) mod runtime_script;
+pub(crate) mod script_references_verifier;
 pub(crate) mod verify_scripts;
 pub(crate) mod verify_scripts_result;
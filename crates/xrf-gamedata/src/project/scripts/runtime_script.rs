@@ -1,4 +1,4 @@
-const LUA_HELP_SCRIPT_PATH: &str = r"scripts\lua_help.script";
+pub(crate) const LUA_HELP_SCRIPT_PATH: &str = r"scripts\lua_help.script";
 
 /// Returns whether a script asset contains executable Lua used by the game runtime.
 ///
@@ -0,0 +1,259 @@
+use std::collections::HashSet;
+
+use xrf_ltx::LtxProject;
+use xrf_lua::XRayLuaScript;
+use xrf_vfs::{XrayAssetType, XrayLogicalPath};
+use xrf_xml::{XmlDocument, XmlParseOptions};
+
+use crate::GamedataFindingFactory;
+use crate::project::scripts::verify_scripts_result::GamedataScriptsVerificationResult;
+use crate::{GamedataProject, GamedataProjectVerifyOptions, GamedataVerificationRule};
+
+const GAMEPLAY_DIRECTORY: &str = "configs\\gameplay";
+const TEXTURE_DESCRIPTIONS_DIRECTORY: &str = "configs\\ui\\textures_descr";
+
+/// Methods of `ini_file` whose first argument is a section name.
+const INI_READ_METHODS: [&str; 10] = [
+  "r_bool",
+  "r_clsid",
+  "r_float",
+  "r_s32",
+  "r_string",
+  "r_string_wq",
+  "r_token",
+  "r_u32",
+  "r_vector",
+  "line_count",
+];
+
+/// Methods of `game_object` whose first argument is an info portion.
+const INFO_PORTION_METHODS: [&str; 4] = ["has_info", "dont_has_info", "give_info_portion", "disable_info_portion"];
+
+/// Functions of `_g.script` whose first argument is an info portion.
+const INFO_PORTION_FUNCTIONS: [&str; 3] = ["has_alife_info", "give_info", "disable_info"];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ScriptReferenceKind {
+  Section,
+  Sound,
+  Texture,
+  InfoPortion,
+}
+
+/// A string literal passed to an engine API call that names game data.
+struct ScriptReference<'s> {
+  kind: ScriptReferenceKind,
+  line_number: usize,
+  call: String,
+  value: &'s str,
+}
+
+/// Names game data declares outside of asset files.
+#[derive(Default)]
+struct ScriptReferenceDeclarations {
+  sections: HashSet<String>,
+  texture_descriptions: HashSet<String>,
+  info_portions: HashSet<String>,
+}
+
+/// Resolves literal arguments of known engine API calls against the data they name.
+///
+/// Covered calls are spawning with `alife():create`, reading configs with `ini_file` methods, `sound_object`,
+/// `InitTexture` of UI windows and info portion checks and grants. Only literals are checked: a name built at runtime
+/// cannot be resolved statically.
+pub(crate) struct ScriptReferencesVerifier<'a> {
+  options: &'a GamedataProjectVerifyOptions,
+  project: &'a GamedataProject,
+}
+
+impl<'a> ScriptReferencesVerifier<'a> {
+  pub(crate) fn new(project: &'a GamedataProject, options: &'a GamedataProjectVerifyOptions) -> Self {
+    Self { options, project }
+  }
+
+  pub(crate) fn verify(&self, scripts: &[XRayLuaScript], result: &mut GamedataScriptsVerificationResult) {
+    let declarations: ScriptReferenceDeclarations = self.read_declarations();
+
+    for script in scripts {
+      for reference in Self::collect_references(script) {
+        if reference.kind == ScriptReferenceKind::InfoPortion && declarations.info_portions.is_empty() {
+          continue;
+        }
+
+        result.checked_references_count += 1;
+
+        let Some((rule, problem)) = self.resolve(&declarations, &reference) else {
+          continue;
+        };
+
+        xrf_output::verbose!(
+          self.options.output,
+          "Unresolved script reference in {}: {}",
+          script.path().display(),
+          reference.value
+        );
+
+        result.invalid_references_count += 1;
+        result.findings.push(GamedataFindingFactory::for_asset(
+          rule,
+          script.path(),
+          format!("Line {}: `{}` {problem}", reference.line_number, reference.call),
+        ));
+      }
+    }
+  }
+
+  fn collect_references(script: &XRayLuaScript) -> Vec<ScriptReference<'_>> {
+    let mut references: Vec<ScriptReference> = Vec::new();
+
+    for method in ["create", "create_ammo"] {
+      for call in script
+        .expression_method_calls(method)
+        .into_iter()
+        .filter(|call| call.receiver() == "alife()")
+      {
+        references.extend(call.literal_string_argument(0).map(|value| ScriptReference {
+          kind: ScriptReferenceKind::Section,
+          line_number: call.line_number(),
+          call: format!("{}:{}", call.receiver(), call.method()),
+          value,
+        }));
+      }
+    }
+
+    for (kind, methods) in [
+      (ScriptReferenceKind::Section, INI_READ_METHODS.as_slice()),
+      (ScriptReferenceKind::Texture, ["InitTexture"].as_slice()),
+      (ScriptReferenceKind::InfoPortion, INFO_PORTION_METHODS.as_slice()),
+    ] {
+      for call in methods.iter().flat_map(|method| script.expression_method_calls(method)) {
+        references.extend(call.literal_string_argument(0).map(|value| ScriptReference {
+          kind,
+          line_number: call.line_number(),
+          call: format!("{}:{}", call.receiver(), call.method()),
+          value,
+        }));
+      }
+    }
+
+    for (kind, functions) in [
+      (ScriptReferenceKind::Sound, ["sound_object"].as_slice()),
+      (ScriptReferenceKind::InfoPortion, INFO_PORTION_FUNCTIONS.as_slice()),
+    ] {
+      for call in functions.iter().flat_map(|function| script.function_calls(function)) {
+        references.extend(call.literal_string_argument(0).map(|value| ScriptReference {
+          kind,
+          line_number: call.line_number(),
+          call: String::from(call.function()),
+          value,
+        }));
+      }
+    }
+
+    references.sort_by_key(|reference| reference.line_number);
+    references
+  }
+
+  /// Rule and description of a reference that resolves to nothing, `None` when it resolves.
+  fn resolve(
+    &self,
+    declarations: &ScriptReferenceDeclarations,
+    reference: &ScriptReference,
+  ) -> Option<(GamedataVerificationRule, String)> {
+    let value: &str = reference.value;
+
+    match reference.kind {
+      ScriptReferenceKind::Section => (!declarations.sections.contains(value)).then(|| {
+        (
+          GamedataVerificationRule::ScriptsSectionReference,
+          format!("names section [{value}], which no config declares"),
+        )
+      }),
+      ScriptReferenceKind::Sound => match self.project.resolve(XrayAssetType::Ogg, value) {
+        Ok(Some(_)) => None,
+        Ok(None) => Some((
+          GamedataVerificationRule::ScriptsSoundReference,
+          format!("names sound `{value}`, which resolves to no file under sounds"),
+        )),
+        Err(error) => Some((
+          GamedataVerificationRule::ScriptsSoundReference,
+          format!("names sound `{value}`, which is not a valid sound path: {error}"),
+        )),
+      },
+      ScriptReferenceKind::Texture => {
+        if declarations.texture_descriptions.contains(value) || matches!(self.project.dds_texture(value), Ok(Some(_))) {
+          None
+        } else {
+          Some((
+            GamedataVerificationRule::ScriptsTextureReference,
+            format!("names texture `{value}`, which is neither a texture file nor a UI texture description"),
+          ))
+        }
+      }
+      ScriptReferenceKind::InfoPortion => (!declarations.info_portions.contains(value)).then(|| {
+        (
+          GamedataVerificationRule::ScriptsInfoPortionReference,
+          format!("names info portion `{value}`, which no gameplay XML declares"),
+        )
+      }),
+    }
+  }
+
+  fn read_declarations(&self) -> ScriptReferenceDeclarations {
+    let mut declarations: ScriptReferenceDeclarations = ScriptReferenceDeclarations::default();
+
+    for path in &self.project.ltx_project.ltx_file_entries {
+      if LtxProject::is_ltx_scheme_path(path) {
+        continue;
+      }
+
+      match self.project.ltx_project.read_full(path) {
+        Ok(ltx) => declarations.sections.extend(ltx.sections().map(String::from)),
+        Err(error) => xrf_output::verbose!(
+          self.options.output,
+          "Skipping ltx entry in script reference check: {} - {}",
+          self.project.ltx_project.path_of(path).display(),
+          error
+        ),
+      }
+    }
+
+    for location in self.project.entries() {
+      let logical_path: &XrayLogicalPath = location.get_logical_path();
+
+      if !logical_path.has_extension(".xml") {
+        continue;
+      }
+
+      let (element, target): (&str, &mut HashSet<String>) =
+        if logical_path.is_under(GAMEPLAY_DIRECTORY).unwrap_or(false) {
+          ("info_portion", &mut declarations.info_portions)
+        } else if logical_path.is_under(TEXTURE_DESCRIPTIONS_DIRECTORY).unwrap_or(false) {
+          ("texture", &mut declarations.texture_descriptions)
+        } else {
+          continue;
+        };
+
+      match self
+        .project
+        .read_asset(logical_path.as_str())
+        .and_then(|contents| XmlDocument::parse_bytes(&contents, XmlParseOptions { allow_dtd: true }))
+      {
+        Ok(document) => target.extend(
+          document
+            .elements_named(element)
+            .filter_map(|it| it.attribute("id"))
+            .map(String::from),
+        ),
+        Err(error) => xrf_output::verbose!(
+          self.options.output,
+          "Skipping XML in script reference check: {} - {}",
+          logical_path.as_str(),
+          error
+        ),
+      }
+    }
+
+    declarations
+  }
+}
@@ -1,16 +1,17 @@
 use std::io::Cursor;
 use std::path::Path;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
 use rayon::iter::IntoParallelRefIterator;
 use rayon::prelude::*;
 use xrf_error::{XrfError, XrfResult};
-use xrf_lua::verify_luajit_script;
+use xrf_lua::{XRayLuaDiagnostic, XRayLuaDiagnosticKind, XRayLuaScript, XRayLuaScriptAnalyzer, verify_luajit_script};
 use xrf_utils::read_as_string_from_w1251_encoded;
 use xrf_vfs::XrayAssetType as AssetType;
 
 use crate::GamedataFindingFactory;
-use crate::project::scripts::runtime_script::is_runtime_script;
+use crate::project::scripts::runtime_script::{LUA_HELP_SCRIPT_PATH, is_runtime_script};
+use crate::project::scripts::script_references_verifier::ScriptReferencesVerifier;
 use crate::project::scripts::verify_scripts_result::GamedataScriptsVerificationResult;
 use crate::{Finding, GamedataProject, GamedataProjectVerifyOptions, GamedataVerificationRule};
 
@@ -29,9 +30,9 @@ impl GamedataProject {
     let checked_scripts_count: u32 = u32::try_from(script_paths.len())
       .map_err(|_| XrfError::new_verify_error("Script count exceeds the supported result range"))?;
 
-    let mut findings: Vec<Finding> = script_paths
+    let checked: Vec<Result<XRayLuaScript, Finding>> = script_paths
       .par_iter()
-      .filter_map(|relative_path| {
+      .map(|relative_path| {
         xrf_output::verbose!(options.output, "Verify script: {relative_path}");
 
         let Some(path) = self
@@ -42,76 +43,344 @@ impl GamedataProject {
         else {
           xrf_output::info!(options.output, "Script path not found: {relative_path}");
 
-          return Some(GamedataFindingFactory::for_asset(
+          return Err(GamedataFindingFactory::for_asset(
             GamedataVerificationRule::ScriptsPath,
             Path::new(relative_path),
             "Script path was not found in gamedata roots",
           ));
         };
 
-        match self.verify_script(options, &path) {
-          Ok(true) => None,
-          Ok(false) => {
-            xrf_output::info!(options.output, "Script is not valid: {}", path);
-
-            Some(GamedataFindingFactory::for_asset(
-              GamedataVerificationRule::ScriptsSyntax,
-              &path,
-              "LuaJIT parser rejected the script",
-            ))
-          }
-          Err(error) => {
-            xrf_output::error!(options.output, "Script verification failed: {error}");
-
-            Some(GamedataFindingFactory::for_asset(
-              GamedataVerificationRule::ScriptsRead,
-              &path,
-              error.to_string(),
-            ))
-          }
-        }
+        let code: String = self.read_script_source(&path).map_err(|error| {
+          xrf_output::error!(options.output, "Script verification failed: {error}");
+
+          GamedataFindingFactory::for_asset(GamedataVerificationRule::ScriptsRead, &path, error.to_string())
+        })?;
+
+        XRayLuaScript::parse(&path, &code).map_err(|error| {
+          xrf_output::info!(options.output, "Script is not valid: {path}");
+
+          GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::ScriptsSyntax,
+            &path,
+            format!("LuaJIT parser rejected the script: {error}"),
+          )
+        })
       })
       .collect();
 
-    let duration: Duration = started_at.elapsed();
+    let mut analyzer: XRayLuaScriptAnalyzer = XRayLuaScriptAnalyzer::new();
+    let mut scripts: Vec<XRayLuaScript> = Vec::new();
+    let mut findings: Vec<Finding> = Vec::new();
+
+    for result in checked {
+      match result {
+        Ok(script) => {
+          analyzer.add_script(&script);
+          scripts.push(script);
+        }
+        Err(finding) => findings.push(finding),
+      }
+    }
+
     let invalid_scripts_count: u32 = u32::try_from(findings.len())
       .map_err(|_| XrfError::new_verify_error("Invalid script count exceeds the supported result range"))?;
 
-    findings.sort_by(GamedataFindingFactory::cmp_by_asset_path_and_message);
+    if let Some(lua_help) = self.read_lua_help(options) {
+      analyzer.add_engine_globals(XRayLuaScriptAnalyzer::engine_globals_from_lua_help(&lua_help));
+    } else {
+      xrf_output::verbose!(
+        options.output,
+        "Engine API listing {LUA_HELP_SCRIPT_PATH} not found, skip undefined global checks"
+      );
+    }
+
+    let analysis_findings: Vec<Finding> = analyzer.analyze().iter().map(Self::script_analysis_finding).collect();
+    let analysis_findings_count: u32 = u32::try_from(analysis_findings.len())
+      .map_err(|_| XrfError::new_verify_error("Script analysis finding count exceeds the supported result range"))?;
+
+    findings.extend(analysis_findings);
+
+    let mut result: GamedataScriptsVerificationResult = GamedataScriptsVerificationResult {
+      checked_scripts_count,
+      findings,
+      invalid_scripts_count,
+      analysis_findings_count,
+      ..Default::default()
+    };
+
+    ScriptReferencesVerifier::new(self, options).verify(&scripts, &mut result);
+
+    result.duration = started_at.elapsed();
+    result
+      .findings
+      .sort_by(GamedataFindingFactory::cmp_by_asset_path_rule_and_message);
 
     if checked_scripts_count > 0 {
       xrf_output::info!(
         options.output,
-        "Verified gamedata scripts in {}, {}/{} valid",
-        xrf_utils::format_duration(duration),
+        "Verified gamedata scripts in {}, {}/{} valid, {} analysis findings, {}/{} references valid",
+        xrf_utils::format_duration(result.duration),
         checked_scripts_count - invalid_scripts_count,
-        checked_scripts_count
+        checked_scripts_count,
+        analysis_findings_count,
+        result.checked_references_count - result.invalid_references_count,
+        result.checked_references_count
       );
     } else {
       xrf_output::info!(
         options.output,
         "Check gamedata scripts in {}, no scripts found",
-        xrf_utils::format_duration(duration),
+        xrf_utils::format_duration(result.duration),
       );
     }
 
-    Ok(GamedataScriptsVerificationResult {
-      duration,
-      checked_scripts_count,
-      findings,
-      invalid_scripts_count,
-    })
+    Ok(result)
   }
 
   /// Parses one script, addressed by its logical path.
   ///
   /// Read through the VFS, so an archived script is parsed rather than reported missing.
   pub fn verify_script(&self, _options: &GamedataProjectVerifyOptions, logical_path: &str) -> XrfResult<bool> {
+    verify_luajit_script(&self.read_script_source(logical_path)?, Path::new(logical_path))?;
+
+    Ok(true)
+  }
+
+  /// Namespace table of every runtime script that parses, with engine exports when `lua_help.script` is shipped.
+  ///
+  /// Scripts that fail to read are left out: reporting them is the job of [`Self::verify_scripts`].
+  pub(crate) fn read_script_analyzer(&self, options: &GamedataProjectVerifyOptions) -> XRayLuaScriptAnalyzer {
+    let scripts: Vec<XRayLuaScript> = self
+      .entries_of_type(AssetType::Script)
+      .par_iter()
+      .map(|location| location.get_logical_path().to_string())
+      .filter(|path| is_runtime_script(path))
+      .filter_map(|path| self.read_script(&path).ok())
+      .collect();
+    let mut analyzer: XRayLuaScriptAnalyzer = XRayLuaScriptAnalyzer::new();
+
+    for script in &scripts {
+      analyzer.add_script(script);
+    }
+
+    if let Some(lua_help) = self.read_lua_help(options) {
+      analyzer.add_engine_globals(XRayLuaScriptAnalyzer::engine_globals_from_lua_help(&lua_help));
+    }
+
+    analyzer
+  }
+
+  /// Parses one script for cross-module analysis, addressed by its logical path.
+  fn read_script(&self, logical_path: &str) -> XrfResult<XRayLuaScript> {
+    XRayLuaScript::parse(logical_path, &self.read_script_source(logical_path)?)
+  }
+
+  /// Decoded source of one script, read through the VFS so an archived script is found as well.
+  fn read_script_source(&self, logical_path: &str) -> XrfResult<String> {
     let bytes: Vec<u8> = self.read_asset(logical_path)?;
-    let code: String = read_as_string_from_w1251_encoded(&mut Cursor::new(bytes))?;
 
-    verify_luajit_script(&code, Path::new(logical_path))?;
+    Ok(read_as_string_from_w1251_encoded(&mut Cursor::new(bytes))?)
+  }
 
-    Ok(true)
+  /// Reads the engine API listing, when the gamedata ships one.
+  fn read_lua_help(&self, options: &GamedataProjectVerifyOptions) -> Option<String> {
+    self.find(LUA_HELP_SCRIPT_PATH).ok().flatten()?;
+
+    let source: XrfResult<String> = self
+      .read_asset(LUA_HELP_SCRIPT_PATH)
+      .and_then(|bytes| Ok(read_as_string_from_w1251_encoded(&mut Cursor::new(bytes))?));
+
+    match source {
+      Ok(source) => Some(source),
+      Err(error) => {
+        xrf_output::error!(options.output, "Failed to read {LUA_HELP_SCRIPT_PATH}: {error}");
+
+        None
+      }
+    }
+  }
+
+  fn script_analysis_finding(diagnostic: &XRayLuaDiagnostic) -> Finding {
+    let rule: GamedataVerificationRule = match diagnostic.kind {
+      XRayLuaDiagnosticKind::UndefinedGlobal => GamedataVerificationRule::ScriptsUndefinedGlobal,
+      XRayLuaDiagnosticKind::UnknownNamespace => GamedataVerificationRule::ScriptsUnknownNamespace,
+      XRayLuaDiagnosticKind::UnknownFunction => GamedataVerificationRule::ScriptsUnknownFunction,
+      XRayLuaDiagnosticKind::CallArity => GamedataVerificationRule::ScriptsCallArity,
+    };
+
+    GamedataFindingFactory::for_asset(
+      rule,
+      &diagnostic.path,
+      format!("Line {}: {}", diagnostic.line_number, diagnostic.message),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use crate::project::tests::fixtures::{project_root, write_project_file};
+  use crate::{
+    GamedataCheckResult, GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions,
+    GamedataVerificationStatus,
+  };
+
+  #[test]
+  fn reports_literal_references_no_game_data_declares() {
+    let root: PathBuf = project_root("gamedata_script_references");
+
+    write_project_file(&root, "configs/system.ltx", "[wpn_ak74]\nhud = wpn_ak74_hud\n");
+    write_project_file(
+      &root,
+      "configs/gameplay/info_portions.xml",
+      "<game_information_portions><info_portion id=\"quest_started\"/></game_information_portions>",
+    );
+    write_project_file(
+      &root,
+      "configs/ui/textures_descr/ui_icons.xml",
+      "<w><file name=\"ui\\ui_icons\"><texture id=\"ui_icon_ok\" x=\"0\" y=\"0\" width=\"8\" height=\"8\"/></file></w>",
+    );
+    write_project_file(&root, "sounds/ambient/wind.ogg", "");
+    write_project_file(&root, "textures/ui/ui_logo.dds", "");
+    write_project_file(&root, "scripts/_g.script", "function give_info(info) end\n");
+    write_project_file(
+      &root,
+      "scripts/spawner.script",
+      r#"function run(ini, actor, window)
+  alife():create("wpn_ak74", position, 0, 0)
+  alife():create("wpn_missing", position, 0, 0)
+  ini:r_string("wpn_ak74", "hud")
+  sound_object("ambient\\wind")
+  sound_object("ambient\\missing")
+  window:InitTexture("ui\\ui_logo")
+  window:InitTexture("ui_icon_ok")
+  window:InitTexture("ui_icon_missing")
+  actor:has_info("quest_started")
+  give_info("quest_missing")
+end
+"#,
+    );
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result = project
+      .verify_scripts(&GamedataProjectVerifyOptions::default())
+      .expect("scripts verification completes");
+
+    assert_eq!(result.get_status(), GamedataVerificationStatus::Failed);
+    assert!(
+      result.get_failure_message().ends_with("6/10 script references valid"),
+      "{}",
+      result.get_failure_message()
+    );
+    assert_eq!(
+      result
+        .get_findings()
+        .iter()
+        .map(|finding| (finding.rule_id().as_str(), finding.message()))
+        .collect::<Vec<_>>(),
+      vec![
+        (
+          "scripts.info-portion-reference",
+          "Line 11: `give_info` names info portion `quest_missing`, which no gameplay XML declares"
+        ),
+        (
+          "scripts.section-reference",
+          "Line 3: `alife():create` names section [wpn_missing], which no config declares"
+        ),
+        (
+          "scripts.sound-reference",
+          "Line 6: `sound_object` names sound `ambient\\missing`, which resolves to no file under sounds"
+        ),
+        (
+          "scripts.texture-reference",
+          "Line 9: `window:InitTexture` names texture `ui_icon_missing`, which is neither a texture file nor a UI \
+           texture description"
+        ),
+      ]
+    );
+
+    fs::remove_dir_all(root).expect("cleanup");
+  }
+
+  #[test]
+  fn reports_parse_failures_as_syntax_findings() {
+    let root: PathBuf = project_root("gamedata_script_syntax");
+
+    write_project_file(&root, "configs/system.ltx", "[actor]\n");
+    write_project_file(&root, "scripts/valid.script", "function run() end\n");
+    write_project_file(&root, "scripts/broken.script", "function run(\n");
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result = project
+      .verify_scripts(&GamedataProjectVerifyOptions::default())
+      .expect("scripts verification completes");
+
+    assert_eq!(
+      result
+        .get_findings()
+        .iter()
+        .map(|finding| finding.rule_id().as_str())
+        .collect::<Vec<_>>(),
+      vec!["scripts.syntax"]
+    );
+    assert!(
+      result.get_findings()[0]
+        .message()
+        .starts_with("LuaJIT parser rejected the script: "),
+      "{}",
+      result.get_findings()[0].message()
+    );
+    assert_eq!(result.get_status(), GamedataVerificationStatus::Failed);
+
+    fs::remove_dir_all(root).expect("cleanup");
+  }
+
+  #[test]
+  fn reports_analysis_findings_without_failing_verification() {
+    let root: PathBuf = project_root("gamedata_script_analysis");
+
+    write_project_file(&root, "configs/system.ltx", "[actor]\n");
+    write_project_file(&root, "scripts/lua_help.script", "function time_global();\n");
+    write_project_file(&root, "scripts/helper.script", "function start() end\n");
+    write_project_file(&root, "scripts/caller.script", "function run()\n  helper.stop()\nend\n");
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result = project
+      .verify_scripts(&GamedataProjectVerifyOptions::default())
+      .expect("scripts verification completes");
+
+    assert_eq!(
+      result
+        .get_findings()
+        .iter()
+        .map(|finding| finding.rule_id().as_str())
+        .collect::<Vec<_>>(),
+      vec!["scripts.unknown-function"]
+    );
+    assert_eq!(result.get_status(), GamedataVerificationStatus::Passed);
+    assert!(
+      project
+        .verify_script(&GamedataProjectVerifyOptions::default(), "scripts\\caller.script")
+        .expect("script parses")
+    );
+
+    fs::remove_dir_all(root).expect("cleanup");
   }
 }
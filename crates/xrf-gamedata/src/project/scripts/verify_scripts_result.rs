@@ -7,6 +7,10 @@ pub struct GamedataScriptsVerificationResult {
   pub(crate) duration: Duration,
   pub(crate) invalid_scripts_count: u32,
   pub(crate) checked_scripts_count: u32,
+  /// Cross-module analysis findings, reported alongside the result without failing it.
+  pub(crate) analysis_findings_count: u32,
+  pub(crate) checked_references_count: u32,
+  pub(crate) invalid_references_count: u32,
   pub(crate) findings: Vec<Finding>,
 }
 
@@ -16,14 +20,17 @@ impl GamedataCheckResult for GamedataScriptsVerificationResult {
   }
 
   fn get_status(&self) -> GamedataVerificationStatus {
-    GamedataVerificationStatus::from_is_valid(self.invalid_scripts_count == 0)
+    GamedataVerificationStatus::from_is_valid(self.invalid_scripts_count == 0 && self.invalid_references_count == 0)
   }
 
   fn get_failure_message(&self) -> String {
     format!(
-      "{}/{} scripts valid",
+      "{}/{} scripts valid, {} analysis findings, {}/{} script references valid",
       self.checked_scripts_count - self.invalid_scripts_count,
-      self.checked_scripts_count
+      self.checked_scripts_count,
+      self.analysis_findings_count,
+      self.checked_references_count - self.invalid_references_count,
+      self.checked_references_count
     )
   }
 
@@ -3,7 +3,10 @@ use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use xrf_error::{XrfError, XrfResult};
-use xrf_shaders::{SHADER_SCRIPT_FILE_EXTENSION, ShaderRenderer, XRayShader, XRayShaderScript, is_shader_source_path};
+use xrf_shaders::{
+  SHADER_SCRIPT_FILE_EXTENSION, ShaderRenderer, XRayShader, XRayShaderCompiler, XRayShaderGlslCompiler,
+  XRayShaderHlslCompiler, XRayShaderScript, is_shader_source_path,
+};
 use xrf_vfs::{XrayLookupScope, XrayVfs};
 
 use crate::GamedataFindingFactory;
@@ -212,7 +215,13 @@ impl<'a> ShadersVerifier<'a> {
     }
 
     match XRayShader::load(path, renderer, &self.shaders_root, source_loader) {
-      Ok(shader) => Self::record_checked_shader_sources(&shader, checked_sources, result),
+      Ok(shader) => {
+        Self::record_checked_shader_sources(&shader, checked_sources, result);
+
+        if self.options.is_strict {
+          self.compile_shader_source(&shader, renderer, result);
+        }
+      }
       Err(error) => {
         checked_sources.insert(path.to_path_buf());
         result.increment_checked_sources_count();
@@ -225,6 +234,45 @@ impl<'a> ShadersVerifier<'a> {
     }
   }
 
+  /// Compiler backend validating sources of a renderer.
+  fn compiler(renderer: ShaderRenderer) -> Box<dyn XRayShaderCompiler> {
+    match renderer {
+      ShaderRenderer::OpenGl => Box::new(XRayShaderGlslCompiler::new()),
+      ShaderRenderer::DirectX11 => Box::new(XRayShaderHlslCompiler::new()),
+    }
+  }
+
+  /// Compiles a loaded source with its renderer backend, one finding per reported problem.
+  ///
+  /// Stages a backend does not support are skipped rather than reported, since nothing is wrong with the source.
+  fn compile_shader_source(
+    &self,
+    shader: &XRayShader,
+    renderer: ShaderRenderer,
+    result: &mut GamedataShadersVerificationResult,
+  ) {
+    match Self::compiler(renderer).compile(shader, renderer) {
+      Ok(()) => {}
+      Err(XrfError::NotImplemented { message }) => {
+        xrf_output::verbose!(self.options.output, "Skip compiling: {message}")
+      }
+      Err(error) => {
+        let message: String = match error {
+          XrfError::Parsing { message } => message,
+          error => error.to_string(),
+        };
+
+        for line in message.lines().filter(|line| !line.trim().is_empty()) {
+          result.add_finding(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::ShadersCompile,
+            shader.path(),
+            line,
+          ));
+        }
+      }
+    }
+  }
+
   fn has_extension(path: &Path, extension: &str) -> bool {
     path
       .extension()
@@ -373,6 +421,107 @@ mod tests {
     Ok(())
   }
 
+  #[test]
+  fn compiles_open_gl_sources_only_in_strict_runs() -> XrfResult {
+    let root: PathBuf = create_gamedata_root("open-gl-compile")?;
+    let shaders: PathBuf = root.join(SHADERS_DIRECTORY);
+
+    write_file(&shaders.join("r3/basic.s"), "function normal(s) end\n")?;
+    write_file(
+      &shaders.join("gl/valid.ps"),
+      "layout(location = 0) out vec4 color;\nvoid main() {\n  color = vec4(1.0);\n}\n",
+    )?;
+    write_file(
+      &shaders.join("gl/broken.ps"),
+      "layout(location = 0) out vec4 color;\nvoid main() {\n  color = undefined_value;\n}\n",
+    )?;
+
+    let (vfs, scope) = mount(&root)?;
+    let options: GamedataProjectVerifyOptions = GamedataProjectVerifyOptions {
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    };
+
+    assert!(
+      ShadersVerifier::new(&vfs, &scope, &options)
+        .verify()
+        .get_findings()
+        .is_empty()
+    );
+
+    let options: GamedataProjectVerifyOptions = GamedataProjectVerifyOptions {
+      is_strict: true,
+      ..options
+    };
+    let result = ShadersVerifier::new(&vfs, &scope, &options).verify();
+    let findings: Vec<(String, String)> = result
+      .get_findings()
+      .iter()
+      .map(|finding| {
+        (
+          finding.rule_id().to_string(),
+          finding.subject().unwrap_or_default().to_string(),
+        )
+      })
+      .collect();
+
+    assert!(!findings.is_empty());
+    assert!(findings.iter().all(|(rule_id, subject)| {
+      rule_id == &GamedataVerificationRule::ShadersCompile.to_string() && subject.ends_with("broken.ps")
+    }));
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+
+  #[test]
+  fn compiles_d3d11_sources_in_every_engine_permutation() -> XrfResult {
+    let root: PathBuf = create_gamedata_root("d3d11-compile")?;
+    let shaders: PathBuf = root.join(SHADERS_DIRECTORY);
+    let options: GamedataProjectVerifyOptions = GamedataProjectVerifyOptions {
+      output: xrf_output::OutputOptions::default(),
+      is_strict: true,
+      ..Default::default()
+    };
+
+    write_file(&shaders.join("r3/basic.s"), "function normal(s) end\n")?;
+    write_file(&shaders.join("r3/common.h"), "Texture2D s_base;\nsampler smp_base;\n")?;
+    write_file(
+      &shaders.join("r3/valid.ps"),
+      "#include \"common.h\"\nfloat4 main(float2 tc : TEXCOORD0) : SV_Target\n{\n  return s_base.Sample(smp_base, tc);\n}\n",
+    )?;
+    write_file(
+      &shaders.join("r3/broken.ps"),
+      "float4 main(float2 tc : TEXCOORD0) : SV_Target\n{\n#ifdef USE_MSAA\n  return missing_sample;\n#endif\n  return 0;\n}\n",
+    )?;
+
+    let (vfs, scope) = mount(&root)?;
+    let result = ShadersVerifier::new(&vfs, &scope, &options).verify();
+    let messages: Vec<String> = result
+      .get_findings()
+      .iter()
+      .map(|finding| {
+        assert_eq!(
+          finding.rule_id().to_string(),
+          GamedataVerificationRule::ShadersCompile.to_string()
+        );
+
+        finding.message().to_string()
+      })
+      .collect();
+
+    assert_eq!(messages.len(), 1, "{messages:?}");
+    assert!(
+      messages[0].contains("undefined identifier `missing_sample`"),
+      "{messages:?}"
+    );
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+
   /// Creates a gamedata root holding an empty `shaders` tree, returning the gamedata root.
   ///
   /// The verifier looks under the logical `shaders` directory, so the tree has to sit where a real one does rather than
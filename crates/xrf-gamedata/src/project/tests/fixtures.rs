@@ -0,0 +1,23 @@
+//! Synthetic gamedata trees shared by the verifier tests.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use xrf_test_utils::utils::build_absolute_generated_test_resource_path;
+
+/// Generated gamedata root of a test, cleared of whatever a previous run left there.
+pub(crate) fn project_root(name: &str) -> PathBuf {
+  let root: PathBuf = build_absolute_generated_test_resource_path(&format!("{name}/project"));
+
+  let _ = fs::remove_dir_all(&root);
+
+  root
+}
+
+/// Write a file of a generated gamedata tree, creating the folders leading to it.
+pub(crate) fn write_project_file<C: AsRef<[u8]>>(root: &Path, path: &str, contents: C) {
+  let path: PathBuf = root.join(path);
+
+  fs::create_dir_all(path.parent().expect("parent directory")).expect("directory created");
+  fs::write(path, contents).expect("file written");
+}
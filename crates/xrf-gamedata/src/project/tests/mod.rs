@@ -1 +1,2 @@
+pub(crate) mod fixtures;
 mod opening;
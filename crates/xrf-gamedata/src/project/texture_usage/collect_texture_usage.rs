@@ -0,0 +1,464 @@
+use std::io::Cursor;
+use std::path::Path;
+use std::time::Instant;
+
+use xrf_db::{LevelFile, OgfFile, ParticlesFile, ThmFile, XRayByteOrder};
+use xrf_error::{XrfError, XrfResult};
+use xrf_ltx::Ltx;
+use xrf_lua::XRayLuaScript;
+use xrf_utils::read_as_string_from_w1251_encoded;
+use xrf_vfs::XrayAssetType as AssetType;
+use xrf_xml::{XmlDocument, XmlParseOptions};
+
+use crate::project::gamedata_project::CONFIGS_DIRECTORY;
+use crate::project::levels::level_bundle::LevelBundle;
+use crate::project::levels::level_engine_constants::LEVELS_DIRECTORY;
+use crate::project::scripts::runtime_script::is_runtime_script;
+use crate::project::texture_usage::collect_texture_usage_result::{
+  GamedataTextureReferrer, GamedataTextureUsageResult,
+};
+use crate::{GamedataProject, GamedataProjectVerifyOptions};
+
+/// Characters that cannot be part of a texture name in a value, whatever syntax the value follows.
+const TEXTURE_TOKEN_SEPARATORS: [char; 16] = [
+  ',', ';', '|', '{', '}', '%', '(', ')', '[', ']', '=', ':', '"', '\'', '+', '!',
+];
+
+impl GamedataProject {
+  /// Collect every texture the project holds and everything that references each of them.
+  ///
+  /// References are read from:
+  /// - mesh texture chunks, nested visuals included
+  /// - level shader tables, resolved inside the level bundle first the way the renderer does
+  /// - texture descriptors, for their bump and detail textures
+  /// - particle sprites
+  /// - config values, texture description and UI xml, and runtime script literals that name a texture by path
+  /// - the `_bump` texture a `_bump#` companion is loaded with
+  ///
+  /// Textures named in engine code or built at runtime are invisible here, so orphans in the result are candidates to
+  /// review rather than files safe to delete.
+  pub fn collect_texture_usage(&self, options: &GamedataProjectVerifyOptions) -> XrfResult<GamedataTextureUsageResult> {
+    xrf_output::heading!(options.output, "Collect texture usage:");
+
+    let started_at: Instant = Instant::now();
+    let mut result: GamedataTextureUsageResult = GamedataTextureUsageResult::new();
+
+    result.textures.extend(
+      self
+        .entries_of_type(AssetType::Dds)
+        .into_iter()
+        .map(|asset| asset.get_logical_path().to_string()),
+    );
+
+    self.collect_texture_usage_in_meshes(options, &mut result);
+    self.collect_texture_usage_in_levels(options, &mut result);
+    self.collect_texture_usage_in_descriptors(options, &mut result);
+    self.collect_texture_usage_in_particles(options, &mut result)?;
+    self.collect_texture_usage_in_configs(options, &mut result);
+    self.collect_texture_usage_in_scripts(options, &mut result);
+    Self::collect_texture_usage_in_companions(&mut result);
+
+    result.duration = started_at.elapsed();
+
+    for error in &result.errors {
+      xrf_output::error!(options.output, "{error}");
+    }
+
+    xrf_output::info!(
+      options.output,
+      "Collected usage of {} textures from {} sources in {}",
+      result.textures.len(),
+      result.total_sources,
+      xrf_utils::format_duration(result.duration)
+    );
+
+    Ok(result)
+  }
+
+  /// Records textures of every visual a mesh holds, since skeleton textures live in nested children only.
+  fn collect_texture_usage_in_meshes(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) {
+    for asset in self.entries_of_type(AssetType::Ogf) {
+      let mesh_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in mesh: {mesh_path}");
+
+      result.total_sources += 1;
+
+      match self
+        .read_resolved_chunks(&asset)
+        .and_then(|mut chunks| OgfFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
+      {
+        Ok(ogf) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(mesh_path);
+          let mut visuals: Vec<&OgfFile> = vec![&ogf];
+
+          while let Some(visual) = visuals.pop() {
+            if let Some(texture) = &visual.texture {
+              self.add_texture_reference(result, &texture.texture_name, &referrer);
+            }
+
+            if let Some(children) = &visual.children {
+              visuals.extend(children.nested.iter());
+            }
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read mesh {mesh_path}: {error}"
+        ))),
+      }
+    }
+  }
+
+  /// Records textures of every level shader table entry.
+  fn collect_texture_usage_in_levels(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) {
+    for asset in self.entries_of_type(AssetType::Level) {
+      let Some(bundle_path) = asset.get_logical_path().parent() else {
+        continue;
+      };
+
+      if bundle_path
+        .parent()
+        .is_none_or(|parent| parent.as_str() != LEVELS_DIRECTORY)
+      {
+        continue;
+      }
+
+      let level_path: String = asset.get_logical_path().to_string();
+      let bundle: LevelBundle = LevelBundle::new(self, bundle_path.file_name());
+
+      xrf_output::verbose!(options.output, "Collect texture usage in level: {level_path}");
+
+      result.total_sources += 1;
+
+      match self
+        .read_resolved_chunks(&asset)
+        .and_then(|mut chunks| LevelFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
+      {
+        Ok(level_file) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(level_path);
+
+          for reference in level_file.shaders.iter().flat_map(|shaders| shaders.references()) {
+            for texture in &reference.textures {
+              if let Some(asset) = bundle.resolve_texture(texture) {
+                result.add_reference(asset.get_logical_path().as_str(), &referrer);
+              }
+            }
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read level {level_path}: {error}"
+        ))),
+      }
+    }
+  }
+
+  /// Records bump and detail textures of descriptors, on behalf of the texture each one describes.
+  fn collect_texture_usage_in_descriptors(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) {
+    for asset in self.entries_of_type(AssetType::Thm) {
+      let descriptor_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in descriptor: {descriptor_path}");
+
+      result.total_sources += 1;
+
+      match self
+        .read_resolved_chunks(&asset)
+        .and_then(|mut chunks| ThmFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
+      {
+        Ok(descriptor) => {
+          let Some(stem) = descriptor_path.strip_suffix(".thm") else {
+            continue;
+          };
+
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Texture(format!("{stem}.dds"));
+
+          for texture in [descriptor.used_bump_name(), descriptor.used_detail_name()]
+            .into_iter()
+            .flatten()
+          {
+            self.add_texture_reference(result, texture, &referrer);
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read texture descriptor {descriptor_path}: {error}"
+        ))),
+      }
+    }
+  }
+
+  /// Records sprite textures of every particle effect.
+  fn collect_texture_usage_in_particles(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) -> XrfResult {
+    for asset in self.entries_with_suffix("particles.xr")? {
+      let particles_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in particles: {particles_path}");
+
+      result.total_sources += 1;
+
+      match self
+        .read_resolved_chunks(&asset)
+        .and_then(|mut chunks| ParticlesFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
+      {
+        Ok(particles_file) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(particles_path);
+
+          for effect in &particles_file.effects.effects {
+            for texture in effect.sprite.texture_name.split(',') {
+              self.add_texture_reference(result, texture.trim(), &referrer);
+            }
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read particles {particles_path}: {error}"
+        ))),
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Records textures config values, texture descriptions and UI xml name by path.
+  ///
+  /// Every LTX file is read on its own, so `textures\textures.ltx` and level configs count along with `configs`.
+  fn collect_texture_usage_in_configs(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) {
+    for asset in self.entries_of_type(AssetType::Ltx) {
+      let ltx_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in config: {ltx_path}");
+
+      result.total_sources += 1;
+
+      match Ltx::read_from_vfs(self.vfs(), self.scope(), &ltx_path) {
+        Ok(ltx) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(ltx_path);
+
+          for (_, section) in &ltx {
+            for (_, value) in section {
+              self.add_value_texture_references(result, value, &referrer);
+            }
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read config {ltx_path}: {error}"
+        ))),
+      }
+    }
+
+    for asset in self.entries() {
+      let logical_path = asset.get_logical_path();
+
+      if !logical_path.is_under(CONFIGS_DIRECTORY).unwrap_or(false) || !logical_path.has_extension(".xml") {
+        continue;
+      }
+
+      let xml_path: String = logical_path.to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in xml: {xml_path}");
+
+      result.total_sources += 1;
+
+      match self
+        .read_resolved(&asset)
+        .and_then(|contents| XmlDocument::parse_bytes(&contents, XmlParseOptions { allow_dtd: true }))
+      {
+        Ok(document) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(xml_path);
+
+          for element in document.root().descendants() {
+            for (_, value) in element.attributes() {
+              self.add_value_texture_references(result, value, &referrer);
+            }
+
+            self.add_value_texture_references(result, element.text(), &referrer);
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read xml {xml_path}: {error}"
+        ))),
+      }
+    }
+  }
+
+  /// Records textures runtime scripts name by path in string literals.
+  fn collect_texture_usage_in_scripts(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    result: &mut GamedataTextureUsageResult,
+  ) {
+    for asset in self
+      .entries_of_type(AssetType::Script)
+      .into_iter()
+      .filter(|location| is_runtime_script(location.get_logical_path().as_str()))
+    {
+      let script_path: String = asset.get_logical_path().to_string();
+
+      xrf_output::verbose!(options.output, "Collect texture usage in script: {script_path}");
+
+      result.total_sources += 1;
+
+      match self.read_resolved(&asset).and_then(|bytes| {
+        XRayLuaScript::parse(
+          Path::new(&script_path),
+          &read_as_string_from_w1251_encoded(&mut Cursor::new(bytes))?,
+        )
+      }) {
+        Ok(script) => {
+          let referrer: GamedataTextureReferrer = GamedataTextureReferrer::Asset(script_path);
+
+          for literal in script.string_literals() {
+            self.add_value_texture_references(result, literal, &referrer);
+          }
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read script {script_path}: {error}"
+        ))),
+      }
+    }
+  }
+
+  /// Records the `_bump` texture each `_bump#` companion is loaded with.
+  fn collect_texture_usage_in_companions(result: &mut GamedataTextureUsageResult) {
+    let companions: Vec<(String, String)> = result
+      .textures
+      .iter()
+      .filter_map(|texture| {
+        texture
+          .strip_suffix("#.dds")
+          .map(|stem| (texture.clone(), format!("{stem}.dds")))
+      })
+      .collect();
+
+    for (companion, texture) in companions {
+      if result.textures.contains(&texture) {
+        result.add_reference(&companion, &GamedataTextureReferrer::Texture(texture));
+      }
+    }
+  }
+
+  /// Records the texture a raw engine reference resolves to, if the project holds it.
+  fn add_texture_reference(
+    &self,
+    result: &mut GamedataTextureUsageResult,
+    reference: &str,
+    referrer: &GamedataTextureReferrer,
+  ) {
+    if reference.is_empty() {
+      return;
+    }
+
+    if let Some(asset) = self.dds_texture(reference).ok().flatten() {
+      result.add_reference(asset.get_logical_path().as_str(), referrer);
+    }
+  }
+
+  /// Records every texture a free-form value names by path.
+  ///
+  /// Only tokens with a directory separator are tried: texture references always carry one, while a bare word is far
+  /// more likely to be a section, a sprite id or a sound that happens to share a name with a root texture.
+  fn add_value_texture_references(
+    &self,
+    result: &mut GamedataTextureUsageResult,
+    value: &str,
+    referrer: &GamedataTextureReferrer,
+  ) {
+    for token in value
+      .split(|character: char| character.is_whitespace() || TEXTURE_TOKEN_SEPARATORS.contains(&character))
+      .filter(|token| token.contains('\\'))
+    {
+      self.add_texture_reference(result, token, referrer);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use crate::project::tests::fixtures::{project_root, write_project_file};
+  use crate::{
+    GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions, GamedataTextureReferrer,
+    GamedataTextureUsageResult,
+  };
+
+  #[test]
+  fn collects_texture_references_from_configs_xml_and_scripts() {
+    let root: PathBuf = project_root("gamedata_texture_usage");
+
+    write_project_file(
+      &root,
+      "configs/system.ltx",
+      "[wpn_ak74]\nicon = wpn\\wpn_ak74, 0.5\n\n[hud]\nname = hud_main\n",
+    );
+    write_project_file(
+      &root,
+      "configs/ui/textures_descr/ui_common.xml",
+      "<w>\n<file name=\"ui\\ui_common\">\n<texture id=\"ui_button\" x=\"0\" y=\"0\" width=\"8\" height=\"8\" />\n</file>\n</w>\n",
+    );
+    write_project_file(&root, "scripts/ui_hud.script", "local texture = \"ui\\\\ui_hud\"\n");
+
+    for texture in [
+      "ui/ui_common.dds",
+      "ui/ui_hud.dds",
+      "ui/ui_unused.dds",
+      "wpn/wpn_ak74.dds",
+      "wpn/wpn_ak74_bump.dds",
+      "wpn/wpn_ak74_bump#.dds",
+    ] {
+      write_project_file(&root, &format!("textures/{texture}"), b"DDS ");
+    }
+
+    let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+      root: root.clone(),
+      output: xrf_output::OutputOptions::default(),
+      ..Default::default()
+    })
+    .expect("project opens");
+    let result: GamedataTextureUsageResult = project
+      .collect_texture_usage(&GamedataProjectVerifyOptions::default())
+      .expect("usage collection completes");
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(
+      result.get_referrers("textures\\ui\\ui_common.dds"),
+      vec![&GamedataTextureReferrer::Asset(String::from(
+        "configs\\ui\\textures_descr\\ui_common.xml"
+      ))]
+    );
+    assert_eq!(
+      result.get_referrers("textures\\ui\\ui_hud.dds"),
+      vec![&GamedataTextureReferrer::Asset(String::from("scripts\\ui_hud.script"))]
+    );
+    assert_eq!(
+      result.get_referrers("textures\\wpn\\wpn_ak74.dds"),
+      vec![&GamedataTextureReferrer::Asset(String::from("configs\\system.ltx"))]
+    );
+    assert_eq!(
+      result.get_orphan_textures(),
+      vec![
+        "textures\\ui\\ui_unused.dds",
+        "textures\\wpn\\wpn_ak74_bump#.dds",
+        "textures\\wpn\\wpn_ak74_bump.dds",
+      ]
+    );
+  }
+}
@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+use xrf_error::XrfError;
+use xrf_vfs::XrayAssetType;
+
+/// Where a reference to a texture comes from.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GamedataTextureReferrer {
+  /// Something the game loads on its own, such as a mesh, a level, a config or a script, named by its logical path.
+  Asset(String),
+  /// Another texture, named by its logical path: a descriptor's bump or detail, or the `#` companion of a bump.
+  Texture(String),
+}
+
+impl GamedataTextureReferrer {
+  /// Logical path of the referring asset or texture.
+  pub fn get_path(&self) -> &str {
+    match self {
+      Self::Asset(path) | Self::Texture(path) => path,
+    }
+  }
+}
+
+/// Which textures the project holds, and what references each of them.
+#[derive(Debug, Default)]
+pub struct GamedataTextureUsageResult {
+  /// Logical path of every texture the project holds.
+  pub textures: BTreeSet<String>,
+  /// Everything referencing a texture the project holds. Textures nothing references have no entry.
+  pub references: BTreeMap<String, BTreeSet<GamedataTextureReferrer>>,
+  pub duration: Duration,
+  pub errors: Vec<XrfError>,
+  pub total_sources: usize,
+}
+
+impl GamedataTextureUsageResult {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Records a reference to a texture by its logical path, ignoring paths the project does not hold.
+  ///
+  /// A texture naming itself keeps nothing alive, so such a reference is not recorded either.
+  pub fn add_reference(&mut self, texture: &str, referrer: &GamedataTextureReferrer) {
+    if !self.textures.contains(texture) || matches!(referrer, GamedataTextureReferrer::Texture(path) if path == texture)
+    {
+      return;
+    }
+
+    self
+      .references
+      .entry(String::from(texture))
+      .or_default()
+      .insert(referrer.clone());
+  }
+
+  /// Logical path of the texture a reference names, as configs and shaders spell it or as a logical path.
+  ///
+  /// Lookup is by name only: level-local textures need their full `levels\` path to be told apart.
+  pub fn find_texture(&self, reference: &str) -> Option<&str> {
+    let logical: String = XrayAssetType::Dds
+      .get_rules()
+      .expect("dds has rules")
+      .to_logical_path(&reference.trim().replace('/', "\\").to_lowercase());
+
+    self
+      .textures
+      .get(&logical)
+      .or_else(|| self.textures.get(&format!("textures\\{logical}")))
+      .map(String::as_str)
+  }
+
+  /// Everything referencing a texture, by its logical path.
+  pub fn get_referrers(&self, texture: &str) -> Vec<&GamedataTextureReferrer> {
+    self
+      .references
+      .get(texture)
+      .map(|referrers| referrers.iter().collect())
+      .unwrap_or_default()
+  }
+
+  /// Textures no asset the game loads reaches, sorted by logical path.
+  ///
+  /// Covers textures nothing references as well as those referenced only by other orphans, such as the bump of a diffuse
+  /// texture no model uses. A texture the engine names in code or builds at runtime is reported too, which is why this is
+  /// a list to review rather than one to delete.
+  pub fn get_orphan_textures(&self) -> Vec<&str> {
+    let mut used: BTreeSet<&str> = self
+      .references
+      .iter()
+      .filter(|(_, referrers)| {
+        referrers
+          .iter()
+          .any(|referrer| matches!(referrer, GamedataTextureReferrer::Asset(_)))
+      })
+      .map(|(texture, _)| texture.as_str())
+      .collect();
+
+    loop {
+      let newly_used: Vec<&str> = self
+        .references
+        .iter()
+        .filter(|(texture, _)| !used.contains(texture.as_str()))
+        .filter(|(_, referrers)| {
+          referrers.iter().any(|referrer| match referrer {
+            GamedataTextureReferrer::Asset(_) => false,
+            GamedataTextureReferrer::Texture(path) => used.contains(path.as_str()),
+          })
+        })
+        .map(|(texture, _)| texture.as_str())
+        .collect();
+
+      if newly_used.is_empty() {
+        break;
+      }
+
+      used.extend(newly_used);
+    }
+
+    self
+      .textures
+      .iter()
+      .map(String::as_str)
+      .filter(|texture| !used.contains(texture))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{GamedataTextureReferrer, GamedataTextureUsageResult};
+
+  fn holding(textures: &[&str]) -> GamedataTextureUsageResult {
+    let mut result: GamedataTextureUsageResult = GamedataTextureUsageResult::new();
+
+    result
+      .textures
+      .extend(textures.iter().map(|texture| String::from(*texture)));
+
+    result
+  }
+
+  #[test]
+  fn reports_textures_no_loaded_asset_reaches_as_orphans() {
+    let mut result: GamedataTextureUsageResult = holding(&[
+      "textures\\wpn\\wpn_ak74.dds",
+      "textures\\wpn\\wpn_ak74_bump.dds",
+      "textures\\wpn\\wpn_old.dds",
+      "textures\\wpn\\wpn_old_bump.dds",
+      "textures\\wpn\\wpn_old_bump#.dds",
+    ]);
+
+    result.add_reference(
+      "textures\\wpn\\wpn_ak74.dds",
+      &GamedataTextureReferrer::Asset(String::from("meshes\\wpn\\wpn_ak74.ogf")),
+    );
+    result.add_reference(
+      "textures\\wpn\\wpn_ak74_bump.dds",
+      &GamedataTextureReferrer::Texture(String::from("textures\\wpn\\wpn_ak74.dds")),
+    );
+    result.add_reference(
+      "textures\\wpn\\wpn_old_bump.dds",
+      &GamedataTextureReferrer::Texture(String::from("textures\\wpn\\wpn_old.dds")),
+    );
+    result.add_reference(
+      "textures\\wpn\\wpn_old_bump#.dds",
+      &GamedataTextureReferrer::Texture(String::from("textures\\wpn\\wpn_old_bump.dds")),
+    );
+    result.add_reference(
+      "textures\\wpn\\wpn_missing.dds",
+      &GamedataTextureReferrer::Asset(String::from("meshes\\wpn\\wpn_ak74.ogf")),
+    );
+
+    assert_eq!(
+      result.get_orphan_textures(),
+      vec![
+        "textures\\wpn\\wpn_old.dds",
+        "textures\\wpn\\wpn_old_bump#.dds",
+        "textures\\wpn\\wpn_old_bump.dds",
+      ]
+    );
+    assert!(!result.references.contains_key("textures\\wpn\\wpn_missing.dds"));
+  }
+
+  #[test]
+  fn finds_textures_by_reference_or_logical_path() {
+    let result: GamedataTextureUsageResult = holding(&["textures\\ui\\ui_common.dds", "levels\\l01\\lmap#1_1.dds"]);
+
+    assert_eq!(
+      result.find_texture("ui\\ui_common"),
+      Some("textures\\ui\\ui_common.dds")
+    );
+    assert_eq!(
+      result.find_texture("UI/ui_common.tga"),
+      Some("textures\\ui\\ui_common.dds")
+    );
+    assert_eq!(
+      result.find_texture("textures\\ui\\ui_common.dds"),
+      Some("textures\\ui\\ui_common.dds")
+    );
+    assert_eq!(
+      result.find_texture("levels\\l01\\lmap#1_1"),
+      Some("levels\\l01\\lmap#1_1.dds")
+    );
+    assert_eq!(result.find_texture("ui\\ui_missing"), None);
+  }
+}
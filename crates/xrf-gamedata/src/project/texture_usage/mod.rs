@@ -0,0 +1,2 @@
+pub(crate) mod collect_texture_usage;
+pub(crate) mod collect_texture_usage_result;
@@ -1,2 +1,5 @@
+#[cfg(test)]
+mod tests;
+pub(crate) mod verify_texture_consistency;
 pub(crate) mod verify_textures;
 pub(crate) mod verify_textures_result;
@@ -0,0 +1,162 @@
+//! Cross-checks textures, bump pairs and texture descriptors of a project.
+//!
+//! Every file of these fixtures is valid on its own, the findings come from how they relate.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use xrf_db::{ThmBumpChunk, ThmFile, ThmTextureParamsChunk, ThmTextureTypeChunk, XRayByteOrder};
+use xrf_dds::{DdsEncodeOptions, DdsFile, ImageFormat, Mipmaps, Quality, RgbaImage};
+
+use super::verify_textures_result::GamedataTexturesVerificationResult;
+use crate::{GamedataCheckResult, GamedataProject, GamedataProjectReadOptions, GamedataProjectVerifyOptions};
+
+fn open_project(name: &str, setup: impl FnOnce(&Path)) -> (PathBuf, GamedataProject) {
+  let root: PathBuf = std::env::temp_dir().join(format!("xrf-gamedata-textures-{name}-{}", std::process::id()));
+
+  let _ = fs::remove_dir_all(&root);
+
+  fs::create_dir_all(root.join("configs")).unwrap();
+  fs::create_dir_all(root.join("textures").join("wpn")).unwrap();
+  fs::write(root.join("configs").join("system.ltx"), "").unwrap();
+
+  setup(&root.join("textures"));
+
+  let project: GamedataProject = GamedataProject::open(&GamedataProjectReadOptions {
+    root: root.clone(),
+    output: xrf_output::OutputOptions::default(),
+    ..Default::default()
+  })
+  .unwrap();
+
+  (root, project)
+}
+
+fn write_texture(textures: &Path, name: &str, size: u32, format: ImageFormat) {
+  DdsFile::encode_rgba(
+    &RgbaImage::new(size, size),
+    DdsEncodeOptions::new(format, Quality::Fast, Mipmaps::Disabled),
+  )
+  .unwrap()
+  .write_to_path(&textures.join(format!("{name}.dds")))
+  .unwrap();
+}
+
+fn write_descriptor(textures: &Path, name: &str, edit: impl FnOnce(&mut ThmFile)) {
+  let path: PathBuf = textures.join(format!("{name}.dds"));
+  let mut descriptor: ThmFile = ThmFile::from_dds_metadata(
+    &DdsFile::read_metadata_from_path(&path).unwrap(),
+    ThmTextureTypeChunk::TYPE_IMAGE,
+  )
+  .unwrap();
+
+  edit(&mut descriptor);
+
+  descriptor
+    .write_to_path::<XRayByteOrder, _>(&path.with_extension("thm"))
+    .unwrap();
+}
+
+fn verify(project: &GamedataProject) -> GamedataTexturesVerificationResult {
+  project
+    .verify_textures(&GamedataProjectVerifyOptions::default())
+    .expect("textures verified")
+}
+
+fn rule_ids(result: &GamedataTexturesVerificationResult) -> Vec<(String, String)> {
+  result
+    .get_findings()
+    .iter()
+    .map(|finding| {
+      (
+        finding.rule_id().to_string(),
+        finding.subject().unwrap_or_default().to_owned(),
+      )
+    })
+    .collect()
+}
+
+#[test]
+fn accepts_a_consistent_bump_pair() {
+  let (root, project) = open_project("consistent", |textures| {
+    write_texture(textures, "wpn\\wpn_a", 64, ImageFormat::BC1RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_a_bump", 64, ImageFormat::BC3RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_a_bump#", 64, ImageFormat::BC3RgbaUnorm);
+    write_descriptor(textures, "wpn\\wpn_a", |descriptor| {
+      let bump: &mut ThmBumpChunk = descriptor.bump.as_mut().unwrap();
+
+      bump.mode = ThmBumpChunk::MODE_USE;
+      bump.name = String::from("wpn\\wpn_a_bump");
+    });
+  });
+  let result: GamedataTexturesVerificationResult = verify(&project);
+
+  assert_eq!(rule_ids(&result), vec![]);
+  assert!(
+    result
+      .get_failure_message()
+      .contains("1/1 declared bumps resolved, 0 inconsistencies")
+  );
+
+  fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn reports_broken_bump_pairs_and_descriptors() {
+  let (root, project) = open_project("inconsistent", |textures| {
+    write_texture(textures, "wpn\\wpn_a", 64, ImageFormat::BC1RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_a_bump", 32, ImageFormat::BC3RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_b", 64, ImageFormat::BC1RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_b_bump", 64, ImageFormat::BC3RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_b_bump#", 32, ImageFormat::BC3RgbaUnorm);
+    write_texture(textures, "wpn\\wpn_n", 64, ImageFormat::BC1RgbaUnorm);
+    write_descriptor(textures, "wpn\\wpn_a", |descriptor| {
+      let bump: &mut ThmBumpChunk = descriptor.bump.as_mut().unwrap();
+
+      bump.mode = ThmBumpChunk::MODE_USE;
+      bump.name = String::from("wpn\\wpn_a_bump");
+
+      descriptor
+        .params
+        .as_mut()
+        .unwrap()
+        .set_flag(ThmTextureParamsChunk::FLAG_DIFFUSE_DETAIL, true);
+      descriptor.detail.as_mut().unwrap().name = String::from("detail\\detail_missing");
+    });
+    write_descriptor(textures, "wpn\\wpn_n", |descriptor| {
+      descriptor.texture_type.as_mut().unwrap().texture_type = ThmTextureTypeChunk::TYPE_NORMAL_MAP;
+    });
+  });
+  let mut findings: Vec<(String, String)> = rule_ids(&verify(&project));
+
+  findings.sort();
+
+  assert_eq!(
+    findings,
+    vec![
+      (
+        String::from("textures.bump-pair"),
+        String::from("textures/wpn/wpn_a_bump.dds")
+      ),
+      (
+        String::from("textures.bump-size"),
+        String::from("textures/wpn/wpn_a.thm")
+      ),
+      (
+        String::from("textures.bump-size"),
+        String::from("textures/wpn/wpn_b_bump.dds")
+      ),
+      (String::from("textures.detail"), String::from("textures/wpn/wpn_a.thm")),
+      (
+        String::from("textures.thm-format"),
+        String::from("textures/wpn/wpn_n.thm")
+      ),
+      (
+        String::from("textures.thm-missing"),
+        String::from("textures/wpn/wpn_b.dds")
+      ),
+    ]
+  );
+
+  fs::remove_dir_all(root).unwrap();
+}
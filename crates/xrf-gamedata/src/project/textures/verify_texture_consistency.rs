@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet};
+
+use xrf_db::{ThmFile, ThmTextureParamsChunk, ThmTextureTypeChunk};
+use xrf_dds::DdsMetadata;
+use xrf_ltx::Ltx;
+
+use crate::GamedataFindingFactory;
+use crate::{Finding, GamedataProject, GamedataProjectVerifyOptions, GamedataVerificationRule};
+
+/// Compiled texture descriptors, where a texture can get its bump and detail without a thm.
+const TEXTURES_LTX_PATH: &str = "textures\\textures.ltx";
+
+impl GamedataProject {
+  /// Cross-check textures, their bump pairs and their descriptors against each other.
+  ///
+  /// Each file can be valid on its own while the set is not: the renderer loads `_bump` and
+  /// `_bump#` together and samples them with the diffuse texture coordinates, and everything it
+  /// knows about a texture beyond the pixels comes from its descriptor.
+  ///
+  /// `textures` holds the header of every readable texture by logical path.
+  pub(crate) fn verify_texture_consistency(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    textures: &HashMap<String, DdsMetadata>,
+    descriptors: &[(String, ThmFile)],
+  ) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+
+    findings.extend(self.verify_bump_pairs(textures));
+    findings.extend(self.verify_descriptor_references(textures, descriptors));
+    findings.extend(self.verify_descriptor_formats(textures, descriptors));
+    findings.extend(self.verify_missing_descriptors(options, textures, descriptors));
+    findings.sort_by(GamedataFindingFactory::cmp_by_asset_path_rule_and_message);
+
+    for finding in &findings {
+      xrf_output::info!(options.output, "Texture is not consistent: {}", finding.message());
+    }
+
+    findings
+  }
+
+  /// Every `_bump` needs its `_bump#` companion, and both have to be the same size.
+  ///
+  /// The renderer binds the pair together: a missing `_bump#` is replaced by the default error
+  /// texture, and a size mismatch misplaces the height correction over the whole surface.
+  fn verify_bump_pairs(&self, textures: &HashMap<String, DdsMetadata>) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+
+    for (path, metadata) in textures {
+      let Some(stem) = path.strip_suffix(".dds").filter(|stem| stem.ends_with("_bump")) else {
+        continue;
+      };
+
+      match textures.get(&format!("{stem}#.dds")) {
+        Some(companion) if (companion.width, companion.height) != (metadata.width, metadata.height) => {
+          findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::TexturesBumpSize,
+            path,
+            format!(
+              "Bump is {}x{} while its '_bump#' companion is {}x{}",
+              metadata.width, metadata.height, companion.width, companion.height
+            ),
+          ));
+        }
+        Some(_) => {}
+        None => findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::TexturesBumpPair,
+          path,
+          format!("Bump has no '{}#' companion", Self::texture_reference(stem)),
+        )),
+      }
+    }
+
+    findings
+  }
+
+  /// Declared bumps have to match their diffuse in size, declared details have to resolve.
+  ///
+  /// Bumps that do not resolve at all are reported by [`Self::verify_textures`] already.
+  fn verify_descriptor_references(
+    &self,
+    textures: &HashMap<String, DdsMetadata>,
+    descriptors: &[(String, ThmFile)],
+  ) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = Vec::new();
+
+    for (path, descriptor) in descriptors {
+      let diffuse: Option<&DdsMetadata> = path
+        .strip_suffix(".thm")
+        .and_then(|stem| textures.get(&format!("{stem}.dds")));
+
+      if let (Some(diffuse), Some(bump_name)) = (diffuse, descriptor.used_bump_name()) {
+        let bump: Option<&DdsMetadata> = self
+          .dds_texture(bump_name)
+          .ok()
+          .flatten()
+          .and_then(|asset| textures.get(asset.get_logical_path().as_str()));
+
+        if let Some(bump) = bump.filter(|bump| (bump.width, bump.height) != (diffuse.width, diffuse.height)) {
+          findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::TexturesBumpSize,
+            path,
+            format!(
+              "Bump '{bump_name}' is {}x{} while the texture is {}x{}",
+              bump.width, bump.height, diffuse.width, diffuse.height
+            ),
+          ));
+        }
+      }
+
+      if let Some(detail_name) = descriptor.used_detail_name()
+        && self.dds_texture(detail_name).ok().flatten().is_none()
+      {
+        findings.push(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::TexturesDetail,
+          path,
+          format!("Texture descriptor declares detail texture '{detail_name}' that is not in gamedata"),
+        ));
+      }
+    }
+
+    findings
+  }
+
+  /// A descriptor typed as a bump or normal map needs a texture with alpha.
+  ///
+  /// X-Ray bump maps keep the normal in colour and the height and gloss in alpha, so a texture
+  /// compressed without alpha loses half of what the descriptor promises the shader.
+  fn verify_descriptor_formats(
+    &self,
+    textures: &HashMap<String, DdsMetadata>,
+    descriptors: &[(String, ThmFile)],
+  ) -> Vec<Finding> {
+    descriptors
+      .iter()
+      .filter_map(|(path, descriptor)| {
+        let texture_type: u32 = descriptor.texture_type.as_ref()?.texture_type;
+
+        if !matches!(
+          texture_type,
+          ThmTextureTypeChunk::TYPE_BUMP_MAP | ThmTextureTypeChunk::TYPE_NORMAL_MAP
+        ) {
+          return None;
+        }
+
+        let texture: &DdsMetadata = textures.get(&format!("{}.dds", path.strip_suffix(".thm")?))?;
+
+        if ThmTextureParamsChunk::format_from_dds(texture.format).is_some_and(ThmTextureParamsChunk::is_alpha_format) {
+          return None;
+        }
+
+        Some(GamedataFindingFactory::for_asset(
+          GamedataVerificationRule::TexturesThmFormat,
+          path,
+          format!(
+            "Texture descriptor declares a {} map but the texture is stored as {:?} without alpha",
+            if texture_type == ThmTextureTypeChunk::TYPE_BUMP_MAP {
+              "bump"
+            } else {
+              "normal"
+            },
+            texture.format
+          ),
+        ))
+      })
+      .collect()
+  }
+
+  /// A texture with a `_bump` next to it needs a descriptor to ever use it.
+  ///
+  /// The engine has no naming convention for bumps: it takes them from the thm or from the
+  /// `[specification]` section of `textures.ltx` only, so without either the bump ships unused.
+  fn verify_missing_descriptors(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    textures: &HashMap<String, DdsMetadata>,
+    descriptors: &[(String, ThmFile)],
+  ) -> Vec<Finding> {
+    let described: HashSet<&str> = descriptors
+      .iter()
+      .filter_map(|(path, _)| path.strip_suffix(".thm"))
+      .collect();
+    let specified: HashSet<String> = self.read_texture_specifications(options);
+
+    let mut findings: Vec<Finding> = Vec::new();
+
+    for path in textures.keys() {
+      let Some(stem) = path.strip_suffix(".dds") else {
+        continue;
+      };
+
+      if stem.ends_with("_bump")
+        || stem.ends_with("_bump#")
+        || described.contains(stem)
+        || specified.contains(&Self::texture_reference(stem).to_lowercase())
+        || !textures.contains_key(&format!("{stem}_bump.dds"))
+      {
+        continue;
+      }
+
+      findings.push(GamedataFindingFactory::for_asset(
+        GamedataVerificationRule::TexturesThmMissing,
+        path,
+        format!(
+          "Texture has bump '{}_bump' but no descriptor declaring it, so the engine never uses it",
+          Self::texture_reference(stem)
+        ),
+      ));
+    }
+
+    findings
+  }
+
+  /// Textures `textures.ltx` describes, lowercase and without the `textures` prefix.
+  fn read_texture_specifications(&self, options: &GamedataProjectVerifyOptions) -> HashSet<String> {
+    let Ok(Some(_)) = self.vfs().scoped(self.scope()).find(TEXTURES_LTX_PATH) else {
+      return HashSet::new();
+    };
+
+    // A malformed configuration is reported by the ltx check, not this one.
+    match Ltx::read_from_vfs_full(self.vfs(), self.scope(), TEXTURES_LTX_PATH) {
+      Ok(ltx) => ltx
+        .section("specification")
+        .map(|section| section.iter().map(|(key, _)| key.to_lowercase()).collect())
+        .unwrap_or_default(),
+      Err(error) => {
+        xrf_output::verbose!(
+          options.output,
+          "Skipping unreadable texture specifications: {TEXTURES_LTX_PATH} - {error}"
+        );
+
+        HashSet::new()
+      }
+    }
+  }
+
+  /// Texture reference of a logical path stem, the form descriptors and configs name textures by.
+  fn texture_reference(stem: &str) -> &str {
+    stem.strip_prefix("textures\\").unwrap_or(stem)
+  }
+}
@@ -1,9 +1,10 @@
+use std::collections::HashMap;
 use std::path::Path;
 use std::time::{Duration, Instant};
 
 use rayon::prelude::*;
 use xrf_db::{ThmFile, XRayByteOrder};
-use xrf_dds::DdsFile;
+use xrf_dds::{DdsFile, DdsMetadata};
 use xrf_error::{XrfError, XrfResult};
 use xrf_vfs::XrayAssetType as AssetType;
 
@@ -29,57 +30,72 @@ impl GamedataProject {
     let checked_textures_count: u32 = u32::try_from(texture_paths.len())
       .map_err(|_| XrfError::new_verify_error("Texture count exceeds the supported result range"))?;
 
-    let mut findings: Vec<Finding> = texture_paths
+    let reads: Vec<(String, XrfResult<(bool, DdsMetadata)>)> = texture_paths
       .par_iter()
-      .filter_map(|relative_path| {
+      .map(|relative_path| {
         xrf_output::verbose!(options.output, "Verify texture: {relative_path}");
 
-        let path: &String = relative_path;
-
-        match self.verify_texture_by_path(options, path) {
-          Ok(true) => None,
-          Ok(false) => {
-            xrf_output::info!(options.output, "Texture is not valid: {}", path);
+        (relative_path.clone(), self.read_texture_metadata(relative_path))
+      })
+      .collect();
 
-            Some(GamedataFindingFactory::for_asset(
-              GamedataVerificationRule::TexturesValidation,
-              path,
-              "Texture uses an unsupported format",
-            ))
-          }
-          Err(error) => {
-            xrf_output::info!(options.output, "Texture verification failed: {} - {}", path, error);
+    let mut findings: Vec<Finding> = Vec::new();
+    let mut textures: HashMap<String, DdsMetadata> = HashMap::new();
 
-            Some(GamedataFindingFactory::for_asset(
-              GamedataVerificationRule::TexturesRead,
-              path,
-              error.to_string(),
-            ))
-          }
+    for (path, read) in reads {
+      match read {
+        Ok((true, metadata)) => {
+          textures.insert(path, metadata);
         }
-      })
-      .collect();
+        Ok((false, metadata)) => {
+          xrf_output::info!(options.output, "Texture is not valid: {}", path);
+
+          findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::TexturesValidation,
+            &path,
+            "Texture uses an unsupported format",
+          ));
+          textures.insert(path, metadata);
+        }
+        Err(error) => {
+          xrf_output::info!(options.output, "Texture verification failed: {} - {}", path, error);
+
+          findings.push(GamedataFindingFactory::for_asset(
+            GamedataVerificationRule::TexturesRead,
+            &path,
+            error.to_string(),
+          ));
+        }
+      }
+    }
 
     let invalid_textures_count: u32 = u32::try_from(findings.len())
       .map_err(|_| XrfError::new_verify_error("Invalid texture count exceeds the supported result range"))?;
 
-    let (bump_findings, checked_bumps_count) = self.verify_texture_bumps(options)?;
+    let descriptors: Vec<(String, ThmFile)> = self.read_texture_descriptors(options);
+    let (bump_findings, checked_bumps_count) = self.verify_texture_bumps(options, &descriptors)?;
     let unresolved_bumps_count: u32 = u32::try_from(bump_findings.len())
       .map_err(|_| XrfError::new_verify_error("Unresolved bump count exceeds the supported result range"))?;
 
+    let consistency_findings: Vec<Finding> = self.verify_texture_consistency(options, &textures, &descriptors);
+    let inconsistencies_count: u32 = u32::try_from(consistency_findings.len())
+      .map_err(|_| XrfError::new_verify_error("Texture inconsistency count exceeds the supported result range"))?;
+
     findings.extend(bump_findings);
+    findings.extend(consistency_findings);
     findings.sort_by(GamedataFindingFactory::cmp_by_asset_path_and_message);
 
     let duration: Duration = started_at.elapsed();
 
     xrf_output::info!(
       options.output,
-      "Verified gamedata textures in {}, {}/{} valid, {}/{} declared bumps resolved",
+      "Verified gamedata textures in {}, {}/{} valid, {}/{} declared bumps resolved, {} inconsistencies",
       xrf_utils::format_duration(duration),
       checked_textures_count - invalid_textures_count,
       checked_textures_count,
       checked_bumps_count - unresolved_bumps_count,
-      checked_bumps_count
+      checked_bumps_count,
+      inconsistencies_count
     );
 
     Ok(GamedataTexturesVerificationResult {
@@ -89,37 +105,26 @@ impl GamedataProject {
       checked_textures_count,
       checked_bumps_count,
       unresolved_bumps_count,
+      inconsistencies_count,
     })
   }
 
-  /// Check that every bump a texture descriptor asks for actually exists.
-  ///
-  /// `CTextureDescrMngr::LoadTHM` takes the thm bump name verbatim, with no `_bump` naming
-  /// convention behind it. A name that resolves to nothing does not turn bump mapping off, because
-  /// `bump_exist` only tests that the name is non-empty: the renderer still takes the `_bump`
-  /// shader path and the loader substitutes `ed\ed_dummy_bump`, so the surface is flat anyway and
-  /// the log fills with `! Fallback to default bump map`. Importing a texture under a path
-  /// different from its source is the usual way to produce one, because the copied descriptor
-  /// keeps pointing into the source layout.
-  ///
-  /// Returns the findings and how many descriptors declared a bump at all.
-  fn verify_texture_bumps(&self, options: &GamedataProjectVerifyOptions) -> XrfResult<(Vec<Finding>, u32)> {
+  /// Every texture descriptor the project holds, paired with its logical path.
+  fn read_texture_descriptors(&self, options: &GamedataProjectVerifyOptions) -> Vec<(String, ThmFile)> {
     let descriptor_paths: Vec<String> = self
       .entries_of_type(AssetType::Thm)
       .into_iter()
       .map(|location| location.get_logical_path().to_string())
       .collect();
 
-    let declarations: Vec<(String, String)> = descriptor_paths
+    descriptor_paths
       .par_iter()
       .filter_map(|relative_path| {
         match self
           .read_asset_chunks(relative_path)
           .and_then(|mut chunks| ThmFile::read_from_chunk::<XRayByteOrder, _>(&mut chunks))
         {
-          Ok(descriptor) => descriptor
-            .used_bump_name()
-            .map(|bump_name| (relative_path.clone(), bump_name.to_owned())),
+          Ok(descriptor) => Some((relative_path.clone(), descriptor)),
           Err(error) => {
             // A descriptor that cannot be parsed is reported by its own texture, not silently
             // treated as declaring no bump.
@@ -132,6 +137,28 @@ impl GamedataProject {
           }
         }
       })
+      .collect()
+  }
+
+  /// Check that every bump a texture descriptor asks for actually exists.
+  ///
+  /// `CTextureDescrMngr::LoadTHM` takes the thm bump name verbatim, with no `_bump` naming
+  /// convention behind it. A name that resolves to nothing does not turn bump mapping off, because
+  /// `bump_exist` only tests that the name is non-empty: the renderer still takes the `_bump`
+  /// shader path and the loader substitutes `ed\ed_dummy_bump`, so the surface is flat anyway and
+  /// the log fills with `! Fallback to default bump map`. Importing a texture under a path
+  /// different from its source is the usual way to produce one, because the copied descriptor
+  /// keeps pointing into the source layout.
+  ///
+  /// Returns the findings and how many descriptors declared a bump at all.
+  fn verify_texture_bumps(
+    &self,
+    options: &GamedataProjectVerifyOptions,
+    descriptors: &[(String, ThmFile)],
+  ) -> XrfResult<(Vec<Finding>, u32)> {
+    let declarations: Vec<(&String, &str)> = descriptors
+      .iter()
+      .filter_map(|(relative_path, descriptor)| descriptor.used_bump_name().map(|bump_name| (relative_path, bump_name)))
       .collect();
 
     let checked_bumps_count: u32 = u32::try_from(declarations.len())
@@ -160,6 +187,14 @@ impl GamedataProject {
     Ok((findings, checked_bumps_count))
   }
 
+  /// Parses a texture once for both passes: the compatibility flag for this check, and the size and format kept for
+  /// [`Self::verify_texture_consistency`].
+  fn read_texture_metadata(&self, logical_path: &str) -> XrfResult<(bool, DdsMetadata)> {
+    let dds: DdsFile = DdsFile::read_from_bytes(&self.read_asset(logical_path)?)?;
+
+    Ok((dds.is_xray_compatible(), dds.metadata()))
+  }
+
   /// Whether one texture reads as an X-Ray compatible DDS, addressed by its logical path.
   ///
   /// Reads through the VFS, so an archived texture is inspected rather than skipped.
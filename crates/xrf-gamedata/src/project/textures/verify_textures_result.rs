@@ -11,6 +11,8 @@ pub struct GamedataTexturesVerificationResult {
   /// Texture descriptors that declare a bump the engine can resolve to a file.
   pub(crate) checked_bumps_count: u32,
   pub(crate) unresolved_bumps_count: u32,
+  /// Bump pairs, descriptors and textures that contradict each other.
+  pub(crate) inconsistencies_count: u32,
 }
 
 impl GamedataCheckResult for GamedataTexturesVerificationResult {
@@ -19,16 +21,19 @@ impl GamedataCheckResult for GamedataTexturesVerificationResult {
   }
 
   fn get_status(&self) -> GamedataVerificationStatus {
-    GamedataVerificationStatus::from_is_valid(self.invalid_textures_count == 0 && self.unresolved_bumps_count == 0)
+    GamedataVerificationStatus::from_is_valid(
+      self.invalid_textures_count == 0 && self.unresolved_bumps_count == 0 && self.inconsistencies_count == 0,
+    )
   }
 
   fn get_failure_message(&self) -> String {
     format!(
-      "{}/{} textures valid, {}/{} declared bumps resolved",
+      "{}/{} textures valid, {}/{} declared bumps resolved, {} inconsistencies",
       self.checked_textures_count - self.invalid_textures_count,
       self.checked_textures_count,
       self.checked_bumps_count - self.unresolved_bumps_count,
-      self.checked_bumps_count
+      self.checked_bumps_count,
+      self.inconsistencies_count
     )
   }
 
@@ -0,0 +1,61 @@
+use xrf_xml::XmlElementSpan;
+
+use crate::gameplay_xml::{GameplayXmlWriter, child_texts};
+
+/// Checks gating a dialog or phrase: script predicates and info portions the actor must or must not have.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayConditions {
+  /// Script functions, as `namespace.function`, that all have to return true.
+  pub preconditions: Vec<String>,
+  pub has_info: Vec<String>,
+  pub dont_has_info: Vec<String>,
+}
+
+impl GameplayConditions {
+  pub(crate) fn read(element: &XmlElementSpan) -> Self {
+    Self {
+      preconditions: child_texts(element, "precondition"),
+      has_info: child_texts(element, "has_info"),
+      dont_has_info: child_texts(element, "dont_has_info"),
+    }
+  }
+
+  pub(crate) fn write(&self, writer: &mut GameplayXmlWriter) {
+    writer.elements("precondition", &self.preconditions);
+    writer.elements("has_info", &self.has_info);
+    writer.elements("dont_has_info", &self.dont_has_info);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.preconditions.is_empty() && self.has_info.is_empty() && self.dont_has_info.is_empty()
+  }
+}
+
+/// Consequences of a phrase being said: script actions and info portions given or taken away.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayEffects {
+  /// Script functions, as `namespace.function`, called in order.
+  pub actions: Vec<String>,
+  pub give_info: Vec<String>,
+  pub disable_info: Vec<String>,
+}
+
+impl GameplayEffects {
+  pub(crate) fn read(element: &XmlElementSpan) -> Self {
+    Self {
+      actions: child_texts(element, "action"),
+      give_info: child_texts(element, "give_info"),
+      disable_info: child_texts(element, "disable_info"),
+    }
+  }
+
+  pub(crate) fn write(&self, writer: &mut GameplayXmlWriter) {
+    writer.elements("action", &self.actions);
+    writer.elements("give_info", &self.give_info);
+    writer.elements("disable_info", &self.disable_info);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.actions.is_empty() && self.give_info.is_empty() && self.disable_info.is_empty()
+  }
+}
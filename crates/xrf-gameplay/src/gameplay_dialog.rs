@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use xrf_error::{XrfError, XrfResult};
+use xrf_xml::{XmlElementSpan, XmlSourceDocument};
+
+use crate::gameplay_conditions::{GameplayConditions, GameplayEffects};
+use crate::gameplay_xml::{
+  GameplayXmlWriter, child_text, child_texts, decode_gameplay_xml, encode_gameplay_xml, numeric_attribute,
+  parse_gameplay_xml, required_id,
+};
+
+pub(crate) const DIALOGS_ROOT: &str = "game_dialogs";
+
+/// One line of a dialog tree.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayDialogPhrase {
+  pub id: String,
+  /// String table id of the line, or the line itself.
+  pub text: Option<String>,
+  /// Script function producing the line at runtime, replacing `text`.
+  pub script_text: Option<String>,
+  /// Ids of the phrases that may follow, in the order they are offered.
+  pub next: Vec<String>,
+  pub conditions: GameplayConditions,
+  pub effects: GameplayEffects,
+  /// Byte range of the `<phrase>` element in the parsed source.
+  pub span: Range<usize>,
+}
+
+/// A `<dialog>` definition: its start conditions and the phrase tree rooted at phrase `0`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayDialog {
+  pub id: String,
+  pub priority: Option<i32>,
+  /// Script function building the phrase tree at runtime, in which case `phrases` is usually empty.
+  pub init_func: Option<String>,
+  pub conditions: GameplayConditions,
+  pub phrases: Vec<GameplayDialogPhrase>,
+  /// Byte range of the `<dialog>` element in the parsed source.
+  pub span: Range<usize>,
+}
+
+/// A `game_dialogs` file of `config/gameplay`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayDialogs {
+  pub dialogs: Vec<GameplayDialog>,
+}
+
+impl GameplayDialogPhrase {
+  fn read(element: &XmlElementSpan) -> XrfResult<Self> {
+    Ok(Self {
+      id: required_id(element)?,
+      text: child_text(element, "text"),
+      script_text: child_text(element, "script_text"),
+      next: child_texts(element, "next"),
+      conditions: GameplayConditions::read(element),
+      effects: GameplayEffects::read(element),
+      span: element.element_range().clone(),
+    })
+  }
+
+  fn write(&self, writer: &mut GameplayXmlWriter) {
+    writer.open("phrase", &[("id", self.id.clone())]);
+    writer.optional_element("text", self.text.as_ref());
+    writer.optional_element("script_text", self.script_text.as_ref());
+    self.conditions.write(writer);
+    self.effects.write(writer);
+    writer.elements("next", &self.next);
+    writer.close("phrase");
+  }
+}
+
+impl GameplayDialog {
+  /// Phrase with an id, the first one winning as in the engine.
+  pub fn phrase(&self, id: &str) -> Option<&GameplayDialogPhrase> {
+    self.phrases.iter().find(|phrase| phrase.id == id)
+  }
+
+  fn read(element: &XmlElementSpan) -> XrfResult<Self> {
+    Ok(Self {
+      id: required_id(element)?,
+      priority: numeric_attribute(element, "priority")?,
+      init_func: child_text(element, "init_func"),
+      conditions: GameplayConditions::read(element),
+      phrases: element
+        .children_named("phrase_list")
+        .flat_map(|list| list.children_named("phrase"))
+        .map(GameplayDialogPhrase::read)
+        .collect::<XrfResult<Vec<_>>>()?,
+      span: element.element_range().clone(),
+    })
+  }
+
+  fn write(&self, writer: &mut GameplayXmlWriter) {
+    let mut attributes: Vec<(&str, String)> = vec![("id", self.id.clone())];
+
+    if let Some(priority) = self.priority {
+      attributes.push(("priority", priority.to_string()));
+    }
+
+    writer.open("dialog", &attributes);
+    self.conditions.write(writer);
+    writer.optional_element("init_func", self.init_func.as_ref());
+
+    if !self.phrases.is_empty() {
+      writer.open("phrase_list", &[]);
+
+      for phrase in &self.phrases {
+        phrase.write(writer);
+      }
+
+      writer.close("phrase_list");
+    }
+
+    writer.close("dialog");
+  }
+}
+
+impl GameplayDialogs {
+  /// Parse dialogs from decoded text, spans addressing that text.
+  pub fn parse(source: String) -> XrfResult<Self> {
+    Self::read(&parse_gameplay_xml(source, DIALOGS_ROOT)?)
+  }
+
+  /// Parse dialogs from file bytes, decoded by their declaration.
+  pub fn parse_bytes(bytes: &[u8]) -> XrfResult<Self> {
+    Self::parse(decode_gameplay_xml(bytes)?)
+  }
+
+  /// Dialog with an id, the first one winning as in the engine.
+  pub fn dialog(&self, id: &str) -> Option<&GameplayDialog> {
+    self.dialogs.iter().find(|dialog| dialog.id == id)
+  }
+
+  pub(crate) fn read(document: &XmlSourceDocument) -> XrfResult<Self> {
+    Ok(Self {
+      dialogs: document
+        .root()
+        .children_named("dialog")
+        .map(GameplayDialog::read)
+        .collect::<XrfResult<Vec<_>>>()?,
+    })
+  }
+
+  pub fn to_xml(&self) -> String {
+    let mut writer: GameplayXmlWriter = GameplayXmlWriter::new(DIALOGS_ROOT);
+
+    for dialog in &self.dialogs {
+      dialog.write(&mut writer);
+    }
+
+    writer.finish(DIALOGS_ROOT)
+  }
+
+  /// Serialized file bytes, in the windows-1251 encoding the written declaration names.
+  pub fn to_bytes(&self) -> XrfResult<Vec<u8>> {
+    encode_gameplay_xml(&self.to_xml())
+      .map_err(|error| XrfError::new_serialization_error(format!("Failed to encode dialogs as windows-1251: {error}")))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::{GameplayDialog, GameplayDialogPhrase, GameplayDialogs};
+
+  const SOURCE: &str = r#"<?xml version="1.0" encoding="windows-1251"?>
+<game_dialogs>
+	<dialog id="esc_trader_start" priority="2">
+		<dont_has_info>esc_trader_met</dont_has_info>
+		<precondition>dialogs.is_not_wounded</precondition>
+		<phrase_list>
+			<phrase id="0">
+				<text>esc_trader_start_0</text>
+				<next>1</next>
+				<next>2</next>
+			</phrase>
+			<phrase id="1">
+				<text>esc_trader_start_1</text>
+				<give_info>esc_trader_met</give_info>
+				<action>dialogs.break_dialog</action>
+			</phrase>
+			<phrase id="2">
+				<script_text>dialogs.trader_greeting</script_text>
+				<has_info>esc_quest_done</has_info>
+			</phrase>
+		</phrase_list>
+	</dialog>
+</game_dialogs>
+"#;
+
+  #[test]
+  fn reads_dialog_trees_with_spans() -> XrfResult {
+    let dialogs: GameplayDialogs = GameplayDialogs::parse(String::from(SOURCE))?;
+    let dialog: &GameplayDialog = dialogs.dialog("esc_trader_start").expect("dialog is read");
+    let answer: &GameplayDialogPhrase = dialog.phrase("1").expect("phrase is read");
+
+    assert_eq!(dialog.priority, Some(2));
+    assert_eq!(dialog.conditions.dont_has_info, ["esc_trader_met"]);
+    assert_eq!(dialog.conditions.preconditions, ["dialogs.is_not_wounded"]);
+    assert_eq!(dialog.phrases.len(), 3);
+    assert_eq!(dialog.phrases[0].next, ["1", "2"]);
+    assert_eq!(answer.effects.give_info, ["esc_trader_met"]);
+    assert_eq!(answer.effects.actions, ["dialogs.break_dialog"]);
+    assert_eq!(
+      dialog.phrases[2].script_text.as_deref(),
+      Some("dialogs.trader_greeting")
+    );
+    assert_eq!(dialog.phrases[2].conditions.has_info, ["esc_quest_done"]);
+    assert!(SOURCE[dialog.span.clone()].starts_with("<dialog id=\"esc_trader_start\""));
+    assert!(SOURCE[answer.span.clone()].starts_with("<phrase id=\"1\">"));
+    assert!(SOURCE[answer.span.clone()].ends_with("</phrase>"));
+
+    Ok(())
+  }
+
+  #[test]
+  fn writes_dialogs_back_to_an_equivalent_document() -> XrfResult {
+    let dialogs: GameplayDialogs = GameplayDialogs::parse(String::from(SOURCE))?;
+    let written: GameplayDialogs = GameplayDialogs::parse_bytes(&dialogs.to_bytes()?)?;
+
+    assert_eq!(written.dialogs.len(), 1);
+    assert_eq!(
+      GameplayDialog {
+        span: 0..0,
+        phrases: Vec::new(),
+        ..written.dialogs[0].clone()
+      },
+      GameplayDialog {
+        span: 0..0,
+        phrases: Vec::new(),
+        ..dialogs.dialogs[0].clone()
+      }
+    );
+
+    for (written, read) in written.dialogs[0].phrases.iter().zip(&dialogs.dialogs[0].phrases) {
+      assert_eq!(
+        GameplayDialogPhrase {
+          span: 0..0,
+          ..written.clone()
+        },
+        GameplayDialogPhrase {
+          span: 0..0,
+          ..read.clone()
+        }
+      );
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn rejects_other_documents_and_phrases_without_id() {
+    assert!(GameplayDialogs::parse(String::from("<game_tasks/>")).is_err());
+    assert!(
+      GameplayDialogs::parse(String::from(
+        "<game_dialogs><dialog id=\"d\"><phrase_list><phrase/></phrase_list></dialog></game_dialogs>"
+      ))
+      .is_err()
+    );
+  }
+}
@@ -0,0 +1,64 @@
+use xrf_error::XrfResult;
+use xrf_xml::XmlSourceDocument;
+
+use crate::gameplay_dialog::DIALOGS_ROOT;
+use crate::gameplay_info_portion::INFO_PORTIONS_ROOT;
+use crate::gameplay_task::TASKS_ROOT;
+use crate::gameplay_xml::{decode_gameplay_xml, parse_gameplay_source};
+use crate::{GameplayDialogs, GameplayInfoPortions, GameplayTasks};
+
+/// Any `config/gameplay` file, told apart by its root element the way the engine tells them apart.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameplayDocument {
+  Dialogs(GameplayDialogs),
+  InfoPortions(GameplayInfoPortions),
+  Tasks(GameplayTasks),
+}
+
+impl GameplayDocument {
+  /// Parse decoded text, `None` when its root is none of the modelled kinds, such as character descriptions.
+  pub fn parse(source: String) -> XrfResult<Option<Self>> {
+    let document: XmlSourceDocument = parse_gameplay_source(source)?;
+
+    Ok(match document.root().name() {
+      DIALOGS_ROOT => Some(Self::Dialogs(GameplayDialogs::read(&document)?)),
+      INFO_PORTIONS_ROOT => Some(Self::InfoPortions(GameplayInfoPortions::read(&document)?)),
+      TASKS_ROOT => Some(Self::Tasks(GameplayTasks::read(&document)?)),
+      _ => None,
+    })
+  }
+
+  /// Parse file bytes, decoded by their declaration.
+  pub fn parse_bytes(bytes: &[u8]) -> XrfResult<Option<Self>> {
+    Self::parse(decode_gameplay_xml(bytes)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::GameplayDocument;
+
+  #[test]
+  fn tells_documents_apart_by_root() -> XrfResult {
+    assert!(matches!(
+      GameplayDocument::parse(String::from("<game_dialogs><dialog id=\"d\"/></game_dialogs>"))?,
+      Some(GameplayDocument::Dialogs(dialogs)) if dialogs.dialogs.len() == 1
+    ));
+    assert!(matches!(
+      GameplayDocument::parse(String::from("<game_information_portions/>"))?,
+      Some(GameplayDocument::InfoPortions(_))
+    ));
+    assert!(matches!(
+      GameplayDocument::parse(String::from("<game_tasks/>"))?,
+      Some(GameplayDocument::Tasks(_))
+    ));
+    assert_eq!(
+      GameplayDocument::parse(String::from("<xml><specific_character/></xml>"))?,
+      None
+    );
+
+    Ok(())
+  }
+}
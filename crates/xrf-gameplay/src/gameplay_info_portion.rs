@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use xrf_error::{XrfError, XrfResult};
+use xrf_xml::{XmlElementSpan, XmlSourceDocument};
+
+use crate::gameplay_xml::{
+  GameplayXmlWriter, child_texts, decode_gameplay_xml, encode_gameplay_xml, parse_gameplay_xml, required_id,
+};
+
+pub(crate) const INFO_PORTIONS_ROOT: &str = "game_information_portions";
+
+/// An `<info_portion>` definition: a named fact about the game state the actor can learn.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayInfoPortion {
+  pub id: String,
+  /// Info portions taken away when this one is given.
+  pub disable: Vec<String>,
+  /// Script functions called when this one is given.
+  pub actions: Vec<String>,
+  /// Encyclopedia articles unlocked when this one is given.
+  pub articles: Vec<String>,
+  /// Byte range of the `<info_portion>` element in the parsed source.
+  pub span: Range<usize>,
+}
+
+/// A `game_information_portions` file of `config/gameplay`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayInfoPortions {
+  pub info_portions: Vec<GameplayInfoPortion>,
+}
+
+impl GameplayInfoPortion {
+  fn read(element: &XmlElementSpan) -> XrfResult<Self> {
+    Ok(Self {
+      id: required_id(element)?,
+      disable: child_texts(element, "disable"),
+      actions: child_texts(element, "action"),
+      articles: child_texts(element, "article"),
+      span: element.element_range().clone(),
+    })
+  }
+
+  fn write(&self, writer: &mut GameplayXmlWriter) {
+    writer.open("info_portion", &[("id", self.id.clone())]);
+    writer.elements("disable", &self.disable);
+    writer.elements("action", &self.actions);
+    writer.elements("article", &self.articles);
+    writer.close("info_portion");
+  }
+}
+
+impl GameplayInfoPortions {
+  /// Parse info portions from decoded text, spans addressing that text.
+  pub fn parse(source: String) -> XrfResult<Self> {
+    Self::read(&parse_gameplay_xml(source, INFO_PORTIONS_ROOT)?)
+  }
+
+  /// Parse info portions from file bytes, decoded by their declaration.
+  pub fn parse_bytes(bytes: &[u8]) -> XrfResult<Self> {
+    Self::parse(decode_gameplay_xml(bytes)?)
+  }
+
+  pub fn info_portion(&self, id: &str) -> Option<&GameplayInfoPortion> {
+    self.info_portions.iter().find(|info_portion| info_portion.id == id)
+  }
+
+  pub(crate) fn read(document: &XmlSourceDocument) -> XrfResult<Self> {
+    Ok(Self {
+      info_portions: document
+        .root()
+        .children_named("info_portion")
+        .map(GameplayInfoPortion::read)
+        .collect::<XrfResult<Vec<_>>>()?,
+    })
+  }
+
+  pub fn to_xml(&self) -> String {
+    let mut writer: GameplayXmlWriter = GameplayXmlWriter::new(INFO_PORTIONS_ROOT);
+
+    for info_portion in &self.info_portions {
+      info_portion.write(&mut writer);
+    }
+
+    writer.finish(INFO_PORTIONS_ROOT)
+  }
+
+  /// Serialized file bytes, in the windows-1251 encoding the written declaration names.
+  pub fn to_bytes(&self) -> XrfResult<Vec<u8>> {
+    encode_gameplay_xml(&self.to_xml()).map_err(|error| {
+      XrfError::new_serialization_error(format!("Failed to encode info portions as windows-1251: {error}"))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::{GameplayInfoPortion, GameplayInfoPortions};
+
+  #[test]
+  fn reads_and_writes_info_portions() -> XrfResult {
+    let source: &str = "<game_information_portions>\n  <info_portion id=\"esc_quest_done\">\n    \
+                        <disable>esc_quest_started</disable>\n    <action>xr_effects.reward</action>\n    \
+                        <article>esc_story</article>\n  </info_portion>\n  <info_portion id=\"esc_quest_started\"/>\n\
+                        </game_information_portions>";
+    let info_portions: GameplayInfoPortions = GameplayInfoPortions::parse(String::from(source))?;
+    let done: &GameplayInfoPortion = info_portions
+      .info_portion("esc_quest_done")
+      .expect("info portion is read");
+
+    assert_eq!(done.disable, ["esc_quest_started"]);
+    assert_eq!(done.actions, ["xr_effects.reward"]);
+    assert_eq!(done.articles, ["esc_story"]);
+    assert_eq!(
+      &source[info_portions.info_portions[1].span.clone()],
+      "<info_portion id=\"esc_quest_started\"/>"
+    );
+
+    let written: GameplayInfoPortions = GameplayInfoPortions::parse_bytes(&info_portions.to_bytes()?)?;
+
+    assert_eq!(
+      written
+        .info_portions
+        .into_iter()
+        .map(|info_portion| GameplayInfoPortion {
+          span: 0..0,
+          ..info_portion
+        })
+        .collect::<Vec<_>>(),
+      info_portions
+        .info_portions
+        .into_iter()
+        .map(|info_portion| GameplayInfoPortion {
+          span: 0..0,
+          ..info_portion
+        })
+        .collect::<Vec<_>>()
+    );
+
+    Ok(())
+  }
+}
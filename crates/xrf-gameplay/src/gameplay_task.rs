@@ -0,0 +1,250 @@
+use std::ops::Range;
+
+use xrf_error::{XrfError, XrfResult};
+use xrf_xml::{XmlElementSpan, XmlSourceDocument};
+
+use crate::gameplay_xml::{
+  GameplayXmlWriter, child_text, child_texts, decode_gameplay_xml, encode_gameplay_xml, numeric_attribute,
+  parse_gameplay_xml, required_id,
+};
+
+pub(crate) const TASKS_ROOT: &str = "game_tasks";
+
+/// One stage of a task; the first objective of a task describes the task itself.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayTaskObjective {
+  pub text: Option<String>,
+  pub icon: Option<String>,
+  pub article: Option<String>,
+  pub map_location_type: Option<String>,
+  pub object_story_id: Option<String>,
+  /// Info portions completing the objective once the actor has all of them.
+  pub complete_infos: Vec<String>,
+  /// Info portions failing the objective once the actor has all of them.
+  pub fail_infos: Vec<String>,
+  /// Info portions given when the objective completes.
+  pub set_complete_infos: Vec<String>,
+  /// Info portions given when the objective fails.
+  pub set_fail_infos: Vec<String>,
+  /// Script predicates completing the objective.
+  pub complete_functions: Vec<String>,
+  /// Script predicates failing the objective.
+  pub fail_functions: Vec<String>,
+  /// Script functions called when the objective completes.
+  pub complete_actions: Vec<String>,
+  /// Script functions called when the objective fails.
+  pub fail_actions: Vec<String>,
+  /// Byte range of the `<objective>` element in the parsed source.
+  pub span: Range<usize>,
+}
+
+/// A `<game_task>` definition.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayTask {
+  pub id: String,
+  pub priority: Option<i32>,
+  pub title: Option<String>,
+  pub objectives: Vec<GameplayTaskObjective>,
+  /// Byte range of the `<game_task>` element in the parsed source.
+  pub span: Range<usize>,
+}
+
+/// A `game_tasks` file of `config/gameplay`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GameplayTasks {
+  pub tasks: Vec<GameplayTask>,
+}
+
+impl GameplayTaskObjective {
+  fn read(element: &XmlElementSpan) -> Self {
+    Self {
+      text: child_text(element, "text"),
+      icon: child_text(element, "icon"),
+      article: child_text(element, "article"),
+      map_location_type: child_text(element, "map_location_type"),
+      object_story_id: child_text(element, "object_story_id"),
+      complete_infos: child_texts(element, "infoportion_complete"),
+      fail_infos: child_texts(element, "infoportion_fail"),
+      set_complete_infos: child_texts(element, "infoportion_set_complete"),
+      set_fail_infos: child_texts(element, "infoportion_set_fail"),
+      complete_functions: child_texts(element, "function_complete"),
+      fail_functions: child_texts(element, "function_fail"),
+      complete_actions: child_texts(element, "function_call_complete"),
+      fail_actions: child_texts(element, "function_call_fail"),
+      span: element.element_range().clone(),
+    }
+  }
+
+  fn write(&self, writer: &mut GameplayXmlWriter) {
+    writer.open("objective", &[]);
+    writer.optional_element("text", self.text.as_ref());
+    writer.optional_element("icon", self.icon.as_ref());
+    writer.optional_element("article", self.article.as_ref());
+    writer.optional_element("map_location_type", self.map_location_type.as_ref());
+    writer.optional_element("object_story_id", self.object_story_id.as_ref());
+    writer.elements("infoportion_complete", &self.complete_infos);
+    writer.elements("infoportion_fail", &self.fail_infos);
+    writer.elements("infoportion_set_complete", &self.set_complete_infos);
+    writer.elements("infoportion_set_fail", &self.set_fail_infos);
+    writer.elements("function_complete", &self.complete_functions);
+    writer.elements("function_fail", &self.fail_functions);
+    writer.elements("function_call_complete", &self.complete_actions);
+    writer.elements("function_call_fail", &self.fail_actions);
+    writer.close("objective");
+  }
+
+  /// Info portions the objective reads or gives.
+  pub fn info_portions(&self) -> impl Iterator<Item = &str> {
+    self
+      .complete_infos
+      .iter()
+      .chain(&self.fail_infos)
+      .chain(&self.set_complete_infos)
+      .chain(&self.set_fail_infos)
+      .map(String::as_str)
+  }
+}
+
+impl GameplayTask {
+  fn read(element: &XmlElementSpan) -> XrfResult<Self> {
+    Ok(Self {
+      id: required_id(element)?,
+      priority: numeric_attribute(element, "prio")?,
+      title: child_text(element, "title"),
+      objectives: element
+        .children_named("objective")
+        .map(GameplayTaskObjective::read)
+        .collect(),
+      span: element.element_range().clone(),
+    })
+  }
+
+  fn write(&self, writer: &mut GameplayXmlWriter) {
+    let mut attributes: Vec<(&str, String)> = vec![("id", self.id.clone())];
+
+    if let Some(priority) = self.priority {
+      attributes.push(("prio", priority.to_string()));
+    }
+
+    writer.open("game_task", &attributes);
+    writer.optional_element("title", self.title.as_ref());
+
+    for objective in &self.objectives {
+      objective.write(writer);
+    }
+
+    writer.close("game_task");
+  }
+}
+
+impl GameplayTasks {
+  /// Parse tasks from decoded text, spans addressing that text.
+  pub fn parse(source: String) -> XrfResult<Self> {
+    Self::read(&parse_gameplay_xml(source, TASKS_ROOT)?)
+  }
+
+  /// Parse tasks from file bytes, decoded by their declaration.
+  pub fn parse_bytes(bytes: &[u8]) -> XrfResult<Self> {
+    Self::parse(decode_gameplay_xml(bytes)?)
+  }
+
+  pub fn task(&self, id: &str) -> Option<&GameplayTask> {
+    self.tasks.iter().find(|task| task.id == id)
+  }
+
+  pub(crate) fn read(document: &XmlSourceDocument) -> XrfResult<Self> {
+    Ok(Self {
+      tasks: document
+        .root()
+        .children_named("game_task")
+        .map(GameplayTask::read)
+        .collect::<XrfResult<Vec<_>>>()?,
+    })
+  }
+
+  pub fn to_xml(&self) -> String {
+    let mut writer: GameplayXmlWriter = GameplayXmlWriter::new(TASKS_ROOT);
+
+    for task in &self.tasks {
+      task.write(&mut writer);
+    }
+
+    writer.finish(TASKS_ROOT)
+  }
+
+  /// Serialized file bytes, in the windows-1251 encoding the written declaration names.
+  pub fn to_bytes(&self) -> XrfResult<Vec<u8>> {
+    encode_gameplay_xml(&self.to_xml())
+      .map_err(|error| XrfError::new_serialization_error(format!("Failed to encode tasks as windows-1251: {error}")))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::{GameplayTask, GameplayTaskObjective, GameplayTasks};
+
+  const SOURCE: &str = r#"<game_tasks>
+	<game_task id="esc_kill_bandits" prio="5">
+		<title>esc_kill_bandits_title</title>
+		<objective>
+			<text>esc_kill_bandits_text</text>
+			<icon>ui_icon_task</icon>
+			<infoportion_complete>esc_bandits_dead</infoportion_complete>
+			<function_call_complete>tasks.reward</function_call_complete>
+		</objective>
+		<objective>
+			<text>esc_kill_bandits_report</text>
+			<map_location_type>blue_location</map_location_type>
+			<object_story_id>esc_trader</object_story_id>
+			<infoportion_fail>esc_trader_dead</infoportion_fail>
+			<infoportion_set_fail>esc_task_failed</infoportion_set_fail>
+			<function_fail>tasks.is_trader_dead</function_fail>
+		</objective>
+	</game_task>
+</game_tasks>"#;
+
+  #[test]
+  fn reads_tasks_with_objectives() -> XrfResult {
+    let tasks: GameplayTasks = GameplayTasks::parse(String::from(SOURCE))?;
+    let task: &GameplayTask = tasks.task("esc_kill_bandits").expect("task is read");
+    let report: &GameplayTaskObjective = &task.objectives[1];
+
+    assert_eq!(task.priority, Some(5));
+    assert_eq!(task.title.as_deref(), Some("esc_kill_bandits_title"));
+    assert_eq!(task.objectives[0].complete_infos, ["esc_bandits_dead"]);
+    assert_eq!(task.objectives[0].complete_actions, ["tasks.reward"]);
+    assert_eq!(report.object_story_id.as_deref(), Some("esc_trader"));
+    assert_eq!(report.fail_functions, ["tasks.is_trader_dead"]);
+    assert_eq!(
+      report.info_portions().collect::<Vec<_>>(),
+      ["esc_trader_dead", "esc_task_failed"]
+    );
+    assert!(SOURCE[report.span.clone()].starts_with("<objective>"));
+
+    Ok(())
+  }
+
+  #[test]
+  fn writes_tasks_back_to_an_equivalent_document() -> XrfResult {
+    let tasks: GameplayTasks = GameplayTasks::parse(String::from(SOURCE))?;
+    let written: GameplayTasks = GameplayTasks::parse(tasks.to_xml())?;
+    let without_spans = |task: &GameplayTask| GameplayTask {
+      span: 0..0,
+      objectives: task
+        .objectives
+        .iter()
+        .map(|objective| GameplayTaskObjective {
+          span: 0..0,
+          ..objective.clone()
+        })
+        .collect(),
+      ..task.clone()
+    };
+
+    assert_eq!(without_spans(&written.tasks[0]), without_spans(&tasks.tasks[0]));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,152 @@
+use xrf_error::{XrfError, XrfResult};
+use xrf_utils::{XRayEncoding, decode_bytes_to_string, encode_string_to_bytes, new_windows1251_encoder};
+use xrf_xml::{
+  XmlElementSpan, XmlParseOptions, XmlSourceDocument, declared_xml_encoding, escape_xml_attribute, escape_xml_text,
+};
+
+/// Declaration written at the top of every generated file, matching the encoding the bytes are written in.
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"windows-1251\"?>\n";
+
+/// Decode gameplay XML bytes by their declaration.
+///
+/// Undeclared files are read as windows-1251, which is what the engine assumes for `config/gameplay`.
+pub(crate) fn decode_gameplay_xml(bytes: &[u8]) -> XrfResult<String> {
+  let encoding: XRayEncoding = declared_xml_encoding(bytes)?.unwrap_or_else(new_windows1251_encoder);
+
+  Ok(decode_bytes_to_string(bytes, encoding)?)
+}
+
+/// Encode generated gameplay XML in windows-1251, as its declaration says.
+pub(crate) fn encode_gameplay_xml(source: &str) -> XrfResult<Vec<u8>> {
+  Ok(encode_string_to_bytes(source, new_windows1251_encoder())?)
+}
+
+/// Parse a gameplay document of any kind.
+pub(crate) fn parse_gameplay_source(source: String) -> XrfResult<XmlSourceDocument> {
+  XmlSourceDocument::parse(source, XmlParseOptions { allow_dtd: true })
+}
+
+/// Parse a gameplay document, rejecting a root other than the one the engine expects for the file kind.
+pub(crate) fn parse_gameplay_xml(source: String, root: &str) -> XrfResult<XmlSourceDocument> {
+  let document: XmlSourceDocument = parse_gameplay_source(source)?;
+
+  if document.root().name() != root {
+    return Err(XrfError::new_parsing_error(format!(
+      "Expected <{root}> as the gameplay XML root, found <{}>",
+      document.root().name()
+    )));
+  }
+
+  Ok(document)
+}
+
+/// Trimmed text of every child with a name, in document order, skipping empty ones.
+pub(crate) fn child_texts(element: &XmlElementSpan, name: &str) -> Vec<String> {
+  element
+    .children_named(name)
+    .map(|child| child.text().trim())
+    .filter(|text| !text.is_empty())
+    .map(String::from)
+    .collect()
+}
+
+/// Trimmed text of the first child with a name, when it holds any.
+pub(crate) fn child_text(element: &XmlElementSpan, name: &str) -> Option<String> {
+  child_texts(element, name).into_iter().next()
+}
+
+/// Required `id` attribute of a definition.
+pub(crate) fn required_id(element: &XmlElementSpan) -> XrfResult<String> {
+  element.attribute("id").map(String::from).ok_or_else(|| {
+    XrfError::new_parsing_error(format!(
+      "Missing id attribute of <{}> at byte {}",
+      element.name(),
+      element.element_range().start
+    ))
+  })
+}
+
+/// Optional numeric attribute, rejecting a value the engine would misread.
+pub(crate) fn numeric_attribute(element: &XmlElementSpan, name: &str) -> XrfResult<Option<i32>> {
+  element
+    .attribute(name)
+    .map(|value| {
+      value.trim().parse::<i32>().map_err(|_| {
+        XrfError::new_parsing_error(format!(
+          "Invalid {name} attribute value '{value}' of <{}> at byte {}",
+          element.name(),
+          element.element_range().start
+        ))
+      })
+    })
+    .transpose()
+}
+
+/// Writes gameplay XML the way shipped files are laid out: tab indentation and one element per line.
+pub(crate) struct GameplayXmlWriter {
+  output: String,
+  depth: usize,
+}
+
+impl GameplayXmlWriter {
+  pub(crate) fn new(root: &str) -> Self {
+    let mut writer: Self = Self {
+      output: String::from(XML_DECLARATION),
+      depth: 0,
+    };
+
+    writer.open(root, &[]);
+    writer
+  }
+
+  pub(crate) fn open(&mut self, name: &str, attributes: &[(&str, String)]) {
+    self.indent();
+    self.output.push('<');
+    self.output.push_str(name);
+
+    for (attribute, value) in attributes {
+      self
+        .output
+        .push_str(&format!(" {attribute}=\"{}\"", escape_xml_attribute(value)));
+    }
+
+    self.output.push_str(">\n");
+    self.depth += 1;
+  }
+
+  pub(crate) fn close(&mut self, name: &str) {
+    self.depth = self.depth.saturating_sub(1);
+    self.indent();
+    self.output.push_str(&format!("</{name}>\n"));
+  }
+
+  pub(crate) fn element(&mut self, name: &str, text: &str) {
+    self.indent();
+    self
+      .output
+      .push_str(&format!("<{name}>{}</{name}>\n", escape_xml_text(text)));
+  }
+
+  pub(crate) fn elements(&mut self, name: &str, texts: &[String]) {
+    for text in texts {
+      self.element(name, text);
+    }
+  }
+
+  pub(crate) fn optional_element(&mut self, name: &str, text: Option<&String>) {
+    if let Some(text) = text {
+      self.element(name, text);
+    }
+  }
+
+  pub(crate) fn finish(mut self, root: &str) -> String {
+    self.close(root);
+    self.output
+  }
+
+  fn indent(&mut self) {
+    for _ in 0..self.depth {
+      self.output.push('\t');
+    }
+  }
+}
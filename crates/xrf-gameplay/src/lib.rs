@@ -0,0 +1,17 @@
+//! Typed models of the gameplay XML under `config/gameplay`: dialogs, info portions and tasks.
+//!
+//! Readers keep the byte range of every definition in the text it was parsed from, so editors and verifiers can point
+//! at the source. Writers produce the layout shipped files use, which is not byte-identical to an edited original.
+
+mod gameplay_conditions;
+mod gameplay_dialog;
+mod gameplay_document;
+mod gameplay_info_portion;
+mod gameplay_task;
+mod gameplay_xml;
+
+pub use gameplay_conditions::{GameplayConditions, GameplayEffects};
+pub use gameplay_dialog::{GameplayDialog, GameplayDialogPhrase, GameplayDialogs};
+pub use gameplay_document::GameplayDocument;
+pub use gameplay_info_portion::{GameplayInfoPortion, GameplayInfoPortions};
+pub use gameplay_task::{GameplayTask, GameplayTaskObjective, GameplayTasks};
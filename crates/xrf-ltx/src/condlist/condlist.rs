@@ -30,6 +30,19 @@ impl Condlist {
 
     Ok(Condlist { branches })
   }
+
+  /// Names the branches switch to, without a `delay|` context prefix.
+  ///
+  /// A result of `nil` or `never` keeps the current state and names nothing.
+  pub fn result_names(&self) -> Vec<&str> {
+    self
+      .branches
+      .iter()
+      .filter_map(|branch| branch.result.as_deref())
+      .map(|result| result.rsplit_once('|').map_or(result, |(_, name)| name).trim())
+      .filter(|name| !name.is_empty() && *name != "nil" && *name != "never")
+      .collect()
+  }
 }
 
 #[cfg(test)]
@@ -101,6 +114,14 @@ mod tests {
     assert_eq!(condlist.branches[1].effects.len(), 1);
   }
 
+  #[test]
+  fn lists_result_names_without_context_prefixes() {
+    let condlist = Condlist::parse("{+info} walker@one, 15| guard@two, {=surge_started} nil, %+scene_end%")
+      .expect("Expected valid condlist");
+
+    assert_eq!(condlist.result_names(), vec!["walker@one", "guard@two"]);
+  }
+
   #[test]
   fn rejects_malformed_condlist_syntax() {
     for value in [
@@ -0,0 +1,300 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::{Ltx, ROOT_SECTION, Section};
+
+/// How a section or field differs between two configs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LtxDiffChange {
+  Added,
+  Removed,
+  Changed,
+}
+
+/// One effective value of a diffed field, and the file it came from when sources were asked for.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtxDiffValue {
+  pub value: String,
+  pub source: Option<PathBuf>,
+}
+
+/// A field whose effective value differs.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtxFieldDiff {
+  pub name: String,
+  pub change: LtxDiffChange,
+  pub old: Option<LtxDiffValue>,
+  pub new: Option<LtxDiffValue>,
+}
+
+/// A section that was added, removed, or has differing fields.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtxSectionDiff {
+  pub name: String,
+  pub change: LtxDiffChange,
+  pub fields: Vec<LtxFieldDiff>,
+}
+
+/// Semantic difference of two configs, compared after includes and inheritance are resolved.
+///
+/// Comparing resolved configs is what makes the diff semantic: a key moved to another include, a section reordered by the
+/// formatter or a value now inherited from a parent is no difference, because the engine reads the same thing.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LtxDiff {
+  pub sections: Vec<LtxSectionDiff>,
+}
+
+impl LtxDiff {
+  /// Compare two resolved configs.
+  ///
+  /// Sections are listed in the old config order, followed by sections only the new config has, and fields likewise.
+  pub fn between(old: &Ltx, new: &Ltx) -> Self {
+    let mut diff: Self = Self::default();
+
+    for (name, old_section) in &old.sections {
+      let section: Option<LtxSectionDiff> = match new.sections.get(name) {
+        Some(new_section) => Self::compare_sections(name, old_section, new_section),
+        None => Some(Self::whole_section(name, old_section, LtxDiffChange::Removed)),
+      };
+
+      diff.sections.extend(section);
+    }
+
+    for (name, new_section) in &new.sections {
+      if !old.sections.contains_key(name) {
+        diff
+          .sections
+          .push(Self::whole_section(name, new_section, LtxDiffChange::Added));
+      }
+    }
+
+    // An empty root section is what the parser adds to every file, not something either config declares.
+    diff
+      .sections
+      .retain(|section| section.name != ROOT_SECTION || !section.fields.is_empty());
+
+    diff
+  }
+
+  /// Fill in which file each old and new value came from.
+  ///
+  /// Sources are configs read with [`Ltx::read_sources_from_file`] or [`Ltx::read_sources_from_vfs`], matching the
+  /// compared ones.
+  pub fn with_sources(mut self, old_sources: &Ltx, new_sources: &Ltx) -> Self {
+    for section in &mut self.sections {
+      for field in &mut section.fields {
+        if let Some(old) = &mut field.old {
+          old.source = old_sources
+            .get_from(section.name.as_str(), &field.name)
+            .map(PathBuf::from);
+        }
+
+        if let Some(new) = &mut field.new {
+          new.source = new_sources
+            .get_from(section.name.as_str(), &field.name)
+            .map(PathBuf::from);
+        }
+      }
+    }
+
+    self
+  }
+
+  /// Whether both configs read the same.
+  pub fn is_empty(&self) -> bool {
+    self.sections.is_empty()
+  }
+
+  fn compare_sections(name: &str, old: &Section, new: &Section) -> Option<LtxSectionDiff> {
+    let mut fields: Vec<LtxFieldDiff> = Vec::new();
+
+    for (field, old_value) in old {
+      match new.get(field) {
+        Some(new_value) if new_value == old_value => {}
+        Some(new_value) => fields.push(Self::field(
+          field,
+          LtxDiffChange::Changed,
+          Some(old_value),
+          Some(new_value),
+        )),
+        None => fields.push(Self::field(field, LtxDiffChange::Removed, Some(old_value), None)),
+      }
+    }
+
+    for (field, new_value) in new {
+      if !old.contains_key(field) {
+        fields.push(Self::field(field, LtxDiffChange::Added, None, Some(new_value)));
+      }
+    }
+
+    if fields.is_empty() {
+      None
+    } else {
+      Some(LtxSectionDiff {
+        name: String::from(name),
+        change: LtxDiffChange::Changed,
+        fields,
+      })
+    }
+  }
+
+  fn whole_section(name: &str, section: &Section, change: LtxDiffChange) -> LtxSectionDiff {
+    LtxSectionDiff {
+      name: String::from(name),
+      change,
+      fields: section
+        .iter()
+        .map(|(field, value)| match change {
+          LtxDiffChange::Removed => Self::field(field, change, Some(value), None),
+          _ => Self::field(field, change, None, Some(value)),
+        })
+        .collect(),
+    }
+  }
+
+  fn field(name: &str, change: LtxDiffChange, old: Option<&str>, new: Option<&str>) -> LtxFieldDiff {
+    LtxFieldDiff {
+      name: String::from(name),
+      change,
+      old: old.map(|value| LtxDiffValue {
+        value: String::from(value),
+        source: None,
+      }),
+      new: new.map(|value| LtxDiffValue {
+        value: String::from(value),
+        source: None,
+      }),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use xrf_error::XrfResult;
+
+  use crate::{Ltx, LtxDiff, LtxDiffChange, LtxSectionDiff};
+
+  type SectionSummary<'a> = (&'a str, LtxDiffChange, Vec<(&'a str, LtxDiffChange)>);
+
+  #[test]
+  fn ignores_moved_and_reordered_keys() -> XrfResult {
+    let old: Ltx = Ltx::read_from_str("[base]\ncost = 10\n\n[item]:base\nweight = 1\n")?.into_inherited()?;
+    let new: Ltx = Ltx::read_from_str("[item]\nweight = 1\ncost = 10\n\n[base]\ncost = 10\n")?.into_inherited()?;
+
+    assert!(LtxDiff::between(&old, &new).is_empty());
+
+    Ok(())
+  }
+
+  #[test]
+  fn lists_added_removed_and_changed_keys() -> XrfResult {
+    let old: Ltx =
+      Ltx::read_from_str("[base]\ncost = 10\n\n[item]:base\nweight = 1\n\n[gone]\na = 1\n")?.into_inherited()?;
+    let new: Ltx = Ltx::read_from_str("[base]\ncost = 20\n\n[item]:base\nslot = 2\n\n[fresh]\n")?.into_inherited()?;
+    let diff: LtxDiff = LtxDiff::between(&old, &new);
+
+    let summary: Vec<SectionSummary> = diff
+      .sections
+      .iter()
+      .map(|section: &LtxSectionDiff| {
+        (
+          section.name.as_str(),
+          section.change,
+          section
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.change))
+            .collect(),
+        )
+      })
+      .collect();
+
+    assert_eq!(
+      summary,
+      vec![
+        ("base", LtxDiffChange::Changed, vec![("cost", LtxDiffChange::Changed)]),
+        (
+          "item",
+          LtxDiffChange::Changed,
+          vec![
+            ("cost", LtxDiffChange::Changed),
+            ("weight", LtxDiffChange::Removed),
+            ("slot", LtxDiffChange::Added)
+          ]
+        ),
+        ("gone", LtxDiffChange::Removed, vec![("a", LtxDiffChange::Removed)]),
+        ("fresh", LtxDiffChange::Added, vec![]),
+      ]
+    );
+    assert_eq!(
+      diff.sections[1].fields[0].old.as_ref().map(|old| old.value.as_str()),
+      Some("10")
+    );
+    assert_eq!(
+      diff.sections[1].fields[0].new.as_ref().map(|new| new.value.as_str()),
+      Some("20")
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn reports_sources_of_effective_values() -> XrfResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-ltx-diff-test-{}", std::process::id()));
+    let old: PathBuf = root.join("old");
+    let new: PathBuf = root.join("new");
+
+    if root.exists() {
+      fs::remove_dir_all(&root)?;
+    }
+
+    fs::create_dir_all(&old)?;
+    fs::create_dir_all(&new)?;
+    fs::write(old.join("system.ltx"), "#include \"base.ltx\"\n\n[item]:base\n")?;
+    fs::write(old.join("base.ltx"), "[base]\ncost = 10\n")?;
+    fs::write(
+      new.join("system.ltx"),
+      "#include \"base.ltx\"\n\n[item]:base\ncost = 15\n",
+    )?;
+    fs::write(new.join("base.ltx"), "[base]\ncost = 10\n")?;
+
+    let diff: LtxDiff = LtxDiff::between(
+      &Ltx::read_from_file_full(old.join("system.ltx"))?,
+      &Ltx::read_from_file_full(new.join("system.ltx"))?,
+    )
+    .with_sources(
+      &Ltx::read_sources_from_file(old.join("system.ltx"))?,
+      &Ltx::read_sources_from_file(new.join("system.ltx"))?,
+    );
+
+    assert_eq!(diff.sections.len(), 1);
+    assert_eq!(diff.sections[0].name, "item");
+    assert_eq!(
+      diff.sections[0].fields[0]
+        .old
+        .as_ref()
+        .and_then(|old| old.source.clone()),
+      Some(old.join("base.ltx"))
+    );
+    assert_eq!(
+      diff.sections[0].fields[0]
+        .new
+        .as_ref()
+        .and_then(|new| new.source.clone()),
+      Some(new.join("system.ltx"))
+    );
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,33 @@
+use fxhash::FxBuildHasher;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::document::ltx_document_value::LtxDocumentValue;
+
+/// An LTX file as a structured document, for tools that speak JSON or TOML rather than LTX.
+///
+/// Sections and fields keep their declaration order, so a document written back produces a file that diffs cleanly against
+/// the one it came from.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LtxDocument {
+  /// Include statements, only present in documents exported from a file as written.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub includes: Vec<String>,
+  pub sections: IndexMap<String, LtxDocumentSection, FxBuildHasher>,
+}
+
+/// One section of an [`LtxDocument`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LtxDocumentSection {
+  /// Parent sections, empty once inheritance is resolved.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub inherited: Vec<String>,
+  #[serde(default)]
+  pub fields: IndexMap<String, LtxDocumentValue, FxBuildHasher>,
+}
+
+impl LtxDocument {
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
@@ -0,0 +1,182 @@
+use crate::document::ltx_document::{LtxDocument, LtxDocumentSection};
+use crate::document::ltx_document_value::LtxDocumentValue;
+use crate::file::file_configuration::constants::{LTX_SCHEME_FIELD, LTX_SYMBOL_ANY};
+use crate::file::types::LtxSectionSchemes;
+use crate::scheme::field_scheme::LtxFieldScheme;
+use crate::scheme::section_scheme::LtxSectionScheme;
+use crate::{Ltx, ROOT_SECTION, Section};
+
+impl Ltx {
+  /// Convert to a document with every value kept as a string.
+  pub fn to_document(&self) -> LtxDocument {
+    self.to_document_with_schemes(&Default::default())
+  }
+
+  /// Convert to a document, typing values of fields that section schemes declare.
+  ///
+  /// A section is typed by the scheme its own `$scheme` field names, so a file read with inheritance resolved gets the
+  /// most out of it.
+  pub fn to_document_with_schemes(&self, schemes: &LtxSectionSchemes) -> LtxDocument {
+    let mut document: LtxDocument = LtxDocument::new();
+
+    document.includes = self.includes.clone();
+
+    for (section_name, section) in &self.sections {
+      if section_name == ROOT_SECTION && section.is_empty() {
+        continue;
+      }
+
+      let scheme: Option<&LtxSectionScheme> = section
+        .get(LTX_SCHEME_FIELD)
+        .and_then(|scheme_name| schemes.get(scheme_name));
+      let mut exported: LtxDocumentSection = LtxDocumentSection {
+        inherited: section.inherited.clone(),
+        fields: Default::default(),
+      };
+
+      for (field_name, value) in section {
+        let field: Option<&LtxFieldScheme> = scheme.and_then(|scheme| {
+          scheme
+            .fields
+            .get(field_name)
+            .or_else(|| scheme.fields.get(LTX_SYMBOL_ANY))
+        });
+
+        exported.fields.insert(
+          String::from(field_name),
+          match field {
+            Some(field) => LtxDocumentValue::from_field(field, value),
+            None => LtxDocumentValue::String(String::from(value)),
+          },
+        );
+      }
+
+      document.sections.insert(section_name.clone(), exported);
+    }
+
+    document
+  }
+
+  /// Build LTX back from a document, with values written as LTX text.
+  pub fn from_document(document: &LtxDocument) -> Self {
+    let mut ltx: Self = Self::new();
+
+    for include in &document.includes {
+      ltx.include(include.clone());
+    }
+
+    for (section_name, exported) in &document.sections {
+      let mut section: Section = Section::new();
+
+      section.inherited = exported.inherited.clone();
+
+      for (field_name, value) in &exported.fields {
+        section.insert(field_name, value.to_ltx_value());
+      }
+
+      ltx.sections.insert(section_name.clone(), section);
+    }
+
+    ltx
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::Ltx;
+  use crate::document::ltx_document::LtxDocument;
+  use crate::document::ltx_document_format::LtxDocumentFormat;
+  use crate::document::ltx_document_value::LtxDocumentValue;
+  use crate::file::types::LtxSectionSchemes;
+  use crate::scheme::field_data_type::LtxFieldDataType;
+  use crate::scheme::field_scheme::LtxFieldScheme;
+  use crate::scheme::section_scheme::LtxSectionScheme;
+
+  const CONFIG: &str = "#include \"weapons\\*.ltx\"\n\n[wpn_base]\n$scheme = $item\ncost = 10\n\n[wpn_ak74]:wpn_base\n\
+    $scheme = $item\ncost = 2500\nweight = 3.5\nquest_item = false\nammo_class = ammo_5.45x39_fmj, ammo_5.45x39_ap\n\
+    description = st_wpn_ak74\n";
+
+  fn schemes() -> XrfResult<LtxSectionSchemes> {
+    let mut scheme: LtxSectionScheme = LtxSectionScheme::new("$item");
+
+    for (name, data) in [
+      ("cost", "u32"),
+      ("weight", "f32"),
+      ("quest_item", "bool"),
+      ("ammo_class", "section[]"),
+    ] {
+      let data_type: LtxFieldDataType = LtxFieldDataType::from_field_data(name, "$item", data)?;
+
+      scheme.fields.insert(
+        String::from(name),
+        if LtxFieldDataType::is_field_data_array(data) {
+          LtxFieldScheme::new_with_array_type("$item", name, data_type)
+        } else {
+          LtxFieldScheme::new_with_type("$item", name, data_type)
+        },
+      );
+    }
+
+    let mut schemes: LtxSectionSchemes = Default::default();
+
+    schemes.insert(String::from("$item"), scheme);
+
+    Ok(schemes)
+  }
+
+  #[test]
+  fn exports_scheme_typed_values() -> XrfResult {
+    let document: LtxDocument = Ltx::read_from_str(CONFIG)?.to_document_with_schemes(&schemes()?);
+    let section = &document.sections["wpn_ak74"];
+
+    assert_eq!(document.includes, vec![String::from("weapons\\*.ltx")]);
+    assert_eq!(section.inherited, vec![String::from("wpn_base")]);
+    assert_eq!(section.fields["cost"], LtxDocumentValue::Integer(2500));
+    assert_eq!(section.fields["weight"], LtxDocumentValue::Float(3.5));
+    assert_eq!(section.fields["quest_item"], LtxDocumentValue::Bool(false));
+    assert_eq!(
+      section.fields["description"],
+      LtxDocumentValue::String(String::from("st_wpn_ak74"))
+    );
+    assert!(!document.sections.contains_key(""));
+
+    Ok(())
+  }
+
+  #[test]
+  fn round_trips_through_json_and_toml() -> XrfResult {
+    let ltx: Ltx = Ltx::read_from_str(CONFIG)?;
+    let document: LtxDocument = ltx.to_document_with_schemes(&schemes()?);
+
+    for format in [LtxDocumentFormat::Json, LtxDocumentFormat::Toml] {
+      let serialized: String = document.write_to_string(format)?;
+      let read: LtxDocument = LtxDocument::read_from_str(&serialized, format)?;
+
+      assert_eq!(read, document, "{format:?}: {serialized}");
+
+      let restored: Ltx = Ltx::from_document(&read);
+
+      assert_eq!(restored.sections, ltx.sections, "{format:?}");
+      assert_eq!(restored.get_included(), ltx.get_included(), "{format:?}");
+    }
+
+    Ok(())
+  }
+
+  #[test]
+  fn imports_edited_values() -> XrfResult {
+    let document: LtxDocument = LtxDocument::read_from_str(
+      r#"{ "sections": { "wpn_ak74": { "fields": { "cost": 3000, "weight": 3.75, "ammo_class": ["a", "b"] } } } }"#,
+      LtxDocumentFormat::Json,
+    )?;
+    let ltx: Ltx = Ltx::from_document(&document);
+
+    assert_eq!(ltx.get_from("wpn_ak74", "cost"), Some("3000"));
+    assert_eq!(ltx.get_from("wpn_ak74", "weight"), Some("3.75"));
+    assert_eq!(ltx.get_from("wpn_ak74", "ammo_class"), Some("a, b"));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::Path;
+
+use xrf_error::{XrfError, XrfResult};
+
+use crate::document::ltx_document::LtxDocument;
+
+/// Serialization format of an [`LtxDocument`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LtxDocumentFormat {
+  Json,
+  Toml,
+}
+
+impl LtxDocumentFormat {
+  /// Format named by `json` or `toml`.
+  pub fn from_name(name: &str) -> XrfResult<Self> {
+    match name.to_lowercase().as_str() {
+      "json" => Ok(Self::Json),
+      "toml" => Ok(Self::Toml),
+      _ => Err(XrfError::new_invalid_error(format!(
+        "Unknown LTX document format '{name}', expected 'json' or 'toml'"
+      ))),
+    }
+  }
+
+  /// Format implied by a file extension.
+  pub fn from_path(path: &Path) -> XrfResult<Self> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+      Some(extension) => Self::from_name(extension),
+      None => Err(XrfError::new_invalid_error(format!(
+        "Cannot tell LTX document format of '{}' without an extension",
+        path.display()
+      ))),
+    }
+  }
+}
+
+impl LtxDocument {
+  /// Serialize the document in the given format.
+  pub fn write_to_string(&self, format: LtxDocumentFormat) -> XrfResult<String> {
+    match format {
+      LtxDocumentFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+      LtxDocumentFormat::Toml => {
+        toml::to_string_pretty(self).map_err(|error| XrfError::new_serialization_error(error.to_string()))
+      }
+    }
+  }
+
+  /// Write the document to a file, in the format its extension names.
+  pub fn write_to_path(&self, path: &Path) -> XrfResult {
+    Ok(fs::write(
+      path,
+      self.write_to_string(LtxDocumentFormat::from_path(path)?)?,
+    )?)
+  }
+
+  /// Parse a document serialized in the given format.
+  pub fn read_from_str(data: &str, format: LtxDocumentFormat) -> XrfResult<Self> {
+    match format {
+      LtxDocumentFormat::Json => Ok(serde_json::from_str(data)?),
+      LtxDocumentFormat::Toml => toml::from_str(data).map_err(|error| XrfError::new_parsing_error(error.to_string())),
+    }
+  }
+
+  /// Read a document from a file, in the format its extension names.
+  pub fn read_from_path(path: &Path) -> XrfResult<Self> {
+    Self::read_from_str(&fs::read_to_string(path)?, LtxDocumentFormat::from_path(path)?)
+  }
+}
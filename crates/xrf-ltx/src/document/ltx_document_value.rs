@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scheme::field_data_type::LtxFieldDataType;
+use crate::scheme::field_scheme::LtxFieldScheme;
+use crate::scheme::tuple_separator::TupleSeparator;
+
+/// Value of one field in an [`crate::LtxDocument`].
+///
+/// Only fields a scheme types are exported as anything but strings: without a scheme `1` may as well be a section name.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LtxDocumentValue {
+  Bool(bool),
+  Integer(i64),
+  Float(f64),
+  String(String),
+  Array(Vec<LtxDocumentValue>),
+}
+
+impl LtxDocumentValue {
+  /// Value of a field as its scheme describes it, or the raw string when the value does not match the scheme.
+  ///
+  /// A value failing its scheme is the verifier's finding, and an export keeps it exactly as written.
+  pub fn from_field(field: &LtxFieldScheme, field_data: &str) -> Self {
+    if field.is_array {
+      let entries: Option<Vec<Self>> = field_data
+        .split(',')
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| Self::from_entry(&field.data_type, entry, true))
+        .collect();
+
+      entries.map_or_else(|| Self::String(String::from(field_data)), Self::Array)
+    } else {
+      Self::from_entry(&field.data_type, field_data.trim(), false)
+        .unwrap_or_else(|| Self::String(String::from(field_data)))
+    }
+  }
+
+  /// Typed value of one entry, `None` when the entry does not parse as its type.
+  ///
+  /// Pipe tuples only become arrays inside array fields: a top-level array is joined back with commas, so a standalone pipe
+  /// tuple stays a string to survive the way back.
+  fn from_entry(field_type: &LtxFieldDataType, entry: &str, is_array_entry: bool) -> Option<Self> {
+    match field_type {
+      LtxFieldDataType::TypeBool => match entry {
+        "true" => Some(Self::Bool(true)),
+        "false" => Some(Self::Bool(false)),
+        _ => None,
+      },
+      LtxFieldDataType::TypeI8
+      | LtxFieldDataType::TypeI16
+      | LtxFieldDataType::TypeI32
+      | LtxFieldDataType::TypeU8
+      | LtxFieldDataType::TypeU16
+      | LtxFieldDataType::TypeU32 => Self::from_integer(entry),
+      LtxFieldDataType::TypeF32 => Self::from_number(entry),
+      LtxFieldDataType::TypeRgb | LtxFieldDataType::TypeRgba | LtxFieldDataType::TypeVector if !is_array_entry => entry
+        .split(',')
+        .map(|component| Self::from_number(component.trim()))
+        .collect::<Option<Vec<Self>>>()
+        .map(Self::Array),
+      LtxFieldDataType::TypeTuple(types, _, separator) if (*separator == TupleSeparator::Pipe) == is_array_entry => {
+        let parts: Vec<&str> = entry.split(separator.as_char()).map(|part| part.trim()).collect();
+
+        if parts.len() != types.len() {
+          return None;
+        }
+
+        types
+          .iter()
+          .zip(parts)
+          .map(|(part_type, part)| Self::from_entry(part_type, part, true))
+          .collect::<Option<Vec<Self>>>()
+          .map(Self::Array)
+      }
+      _ => Some(Self::String(String::from(entry))),
+    }
+  }
+
+  /// Integer only when writing it back gives the same text, so `010` stays as written.
+  fn from_integer(entry: &str) -> Option<Self> {
+    entry
+      .parse::<i64>()
+      .ok()
+      .filter(|value| value.to_string() == entry)
+      .map(Self::Integer)
+  }
+
+  /// Integer where the text is one, so `5` does not come back as `5.0`, otherwise a finite float.
+  fn from_number(entry: &str) -> Option<Self> {
+    Self::from_integer(entry).or_else(|| {
+      entry
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(Self::Float)
+    })
+  }
+
+  /// LTX text of the value.
+  ///
+  /// Arrays join with commas, and arrays nested in them with pipes: the only nesting LTX values have is a pipe tuple
+  /// inside an array field.
+  pub fn to_ltx_value(&self) -> String {
+    self.to_ltx_value_joined(", ")
+  }
+
+  fn to_ltx_value_joined(&self, separator: &str) -> String {
+    match self {
+      Self::Bool(value) => value.to_string(),
+      Self::Integer(value) => value.to_string(),
+      Self::Float(value) => value.to_string(),
+      Self::String(value) => value.clone(),
+      Self::Array(values) => values
+        .iter()
+        .map(|value| value.to_ltx_value_joined("|"))
+        .collect::<Vec<String>>()
+        .join(separator),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::document::ltx_document_value::LtxDocumentValue;
+  use crate::scheme::field_data_type::LtxFieldDataType;
+  use crate::scheme::field_scheme::LtxFieldScheme;
+
+  fn field(data: &str) -> XrfResult<LtxFieldScheme> {
+    let data_type: LtxFieldDataType = LtxFieldDataType::from_field_data("field", "$scheme", data)?;
+
+    Ok(if LtxFieldDataType::is_field_data_array(data) {
+      LtxFieldScheme::new_with_array_type("$scheme", "field", data_type)
+    } else {
+      LtxFieldScheme::new_with_type("$scheme", "field", data_type)
+    })
+  }
+
+  #[test]
+  fn renders_scheme_typed_values() -> XrfResult {
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("u32")?, "10"),
+      LtxDocumentValue::Integer(10)
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("f32")?, "0.25"),
+      LtxDocumentValue::Float(0.25)
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("f32")?, "3"),
+      LtxDocumentValue::Integer(3)
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("bool")?, "true"),
+      LtxDocumentValue::Bool(true)
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("vector")?, "1, 0.5, -2"),
+      LtxDocumentValue::Array(vec![
+        LtxDocumentValue::Integer(1),
+        LtxDocumentValue::Float(0.5),
+        LtxDocumentValue::Integer(-2)
+      ])
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("section[]")?, "ammo_a, ammo_b"),
+      LtxDocumentValue::Array(vec![
+        LtxDocumentValue::String(String::from("ammo_a")),
+        LtxDocumentValue::String(String::from("ammo_b"))
+      ])
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("tuple@pipe:section,u32[]")?, "ammo_a|10, ammo_b|20"),
+      LtxDocumentValue::Array(vec![
+        LtxDocumentValue::Array(vec![
+          LtxDocumentValue::String(String::from("ammo_a")),
+          LtxDocumentValue::Integer(10)
+        ]),
+        LtxDocumentValue::Array(vec![
+          LtxDocumentValue::String(String::from("ammo_b")),
+          LtxDocumentValue::Integer(20)
+        ])
+      ])
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn keeps_values_not_matching_scheme_as_written() -> XrfResult {
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("u32")?, "010"),
+      LtxDocumentValue::String(String::from("010"))
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("bool")?, "yes"),
+      LtxDocumentValue::String(String::from("yes"))
+    );
+    assert_eq!(
+      LtxDocumentValue::from_field(&field("vector")?, "1, x, 2"),
+      LtxDocumentValue::String(String::from("1, x, 2"))
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn writes_values_back_as_ltx_text() -> XrfResult {
+    for (data, value) in [
+      ("u32", "10"),
+      ("f32", "0.25"),
+      ("vector", "1, 0.5, -2"),
+      ("section[]", "ammo_a, ammo_b"),
+      ("tuple@pipe:section,u32[]", "ammo_a|10, ammo_b|20"),
+      ("tuple:section,u32", "ammo_a, 10"),
+      ("tuple@pipe:section,u32", "ammo_a|10"),
+    ] {
+      assert_eq!(LtxDocumentValue::from_field(&field(data)?, value).to_ltx_value(), value);
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod ltx_document;
+pub(crate) mod ltx_document_convert;
+pub(crate) mod ltx_document_format;
+pub(crate) mod ltx_document_value;
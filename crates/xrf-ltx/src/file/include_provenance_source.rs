@@ -0,0 +1,56 @@
+use std::path::{Path, PathBuf};
+
+use xrf_error::XrfResult;
+
+use crate::Ltx;
+use crate::file::include::LtxIncludeConvertor;
+use crate::file::include_source::LtxIncludeSource;
+
+/// Wraps an include source so every value read through it is replaced by the path of the file declaring it.
+///
+/// Merging includes and resolving inheritance then carry those paths exactly where they carry values, so the result answers
+/// which file each effective value came from without a second set of merge rules to keep in step.
+pub(crate) struct LtxIncludeProvenanceSource<'a, S: LtxIncludeSource> {
+  source: &'a S,
+}
+
+impl<'a, S: LtxIncludeSource> LtxIncludeProvenanceSource<'a, S> {
+  pub fn new(source: &'a S) -> Self {
+    Self { source }
+  }
+
+  /// Resolves includes and inheritance of an LTX read from the wrapped source, with values replaced by their file paths.
+  pub fn convert(&self, ltx: Ltx) -> XrfResult<Ltx> {
+    LtxIncludeConvertor::convert_with(self.stamp(ltx), self)?.into_inherited()
+  }
+
+  /// Logical paths of a VFS source survive the `PathBuf` round trip, so the path is written as the source recorded it.
+  fn stamp(&self, mut ltx: Ltx) -> Ltx {
+    let path: String = ltx
+      .path
+      .as_ref()
+      .map_or_else(String::new, |path| path.to_string_lossy().into_owned());
+
+    for (_, section) in ltx.sections.iter_mut() {
+      for (_, value) in section.iter_mut() {
+        value.clone_from(&path);
+      }
+    }
+
+    ltx
+  }
+}
+
+impl<S: LtxIncludeSource> LtxIncludeSource for LtxIncludeProvenanceSource<'_, S> {
+  fn resolve(&self, directory: &Path, statement: &str) -> XrfResult<Vec<PathBuf>> {
+    self.source.resolve(directory, statement)
+  }
+
+  fn read(&self, path: &Path) -> XrfResult<Option<Ltx>> {
+    Ok(self.source.read(path)?.map(|ltx| self.stamp(ltx)))
+  }
+
+  fn describe(&self, path: &Path) -> String {
+    self.source.describe(path)
+  }
+}
@@ -4,6 +4,7 @@ pub(crate) mod file_section;
 pub(crate) mod formatter;
 pub(crate) mod include;
 pub(crate) mod include_filesystem_source;
+pub(crate) mod include_provenance_source;
 pub(crate) mod include_source;
 pub(crate) mod include_vfs_source;
 pub(crate) mod inherit;
@@ -10,6 +10,8 @@ use xrf_vfs::{XrayLookupScope, XrayVfs};
 
 use crate::Ltx;
 use crate::file::include::LtxIncludeConvertor;
+use crate::file::include_filesystem_source::LtxIncludeFilesystemSource;
+use crate::file::include_provenance_source::LtxIncludeProvenanceSource;
 use crate::file::include_vfs_source::LtxIncludeVfsSource;
 use crate::file::parser::LtxParser;
 use crate::file::types::LtxIncluded;
@@ -56,6 +58,24 @@ impl Ltx {
     Self::read_from_vfs(vfs, scope, logical_path)?.into_inherited()
   }
 
+  /// Read which file each value of [`Self::read_from_file_full`] comes from.
+  ///
+  /// The result has the same sections and fields, with every value replaced by the path of the file declaring it.
+  pub fn read_sources_from_file<P: AsRef<Path>>(filename: P) -> XrfResult<Self> {
+    LtxIncludeProvenanceSource::new(&LtxIncludeFilesystemSource).convert(Self::read_from_path(filename)?)
+  }
+
+  /// Read which file each value of [`Self::read_from_vfs_full`] comes from, as logical paths.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when reading fails, or when an inherited section cannot be resolved.
+  pub fn read_sources_from_vfs(vfs: &XrayVfs, scope: &XrayLookupScope, logical_path: &str) -> XrfResult<Self> {
+    let source: LtxIncludeVfsSource = LtxIncludeVfsSource::new(vfs, scope);
+
+    LtxIncludeProvenanceSource::new(&source).convert(source.read_ltx(logical_path)?)
+  }
+
   /// Read from a file as generic ltx with LTX descriptor filled.
   pub fn read_from_path<P: AsRef<Path>>(filename: P) -> XrfResult<Self> {
     let mut ltx: Self = Self::read_from(&mut File::open(filename.as_ref())?)?;
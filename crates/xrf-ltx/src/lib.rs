@@ -1,8 +1,14 @@
 pub(crate) mod condlist;
+pub(crate) mod diff;
+pub(crate) mod document;
 pub(crate) mod file;
 pub(crate) mod project;
 pub(crate) mod scheme;
 
+pub use crate::diff::ltx_diff::*;
+pub use crate::document::ltx_document::*;
+pub use crate::document::ltx_document_format::*;
+pub use crate::document::ltx_document_value::*;
 pub use crate::file::check::*;
 pub use crate::file::file_configuration::constants::LTX_EXTENSION;
 pub use crate::file::file_configuration::constants::LTX_SYMBOL_SCHEME;
@@ -15,5 +21,6 @@ pub use crate::project::ltx_format_options::*;
 pub use crate::project::ltx_project::*;
 pub use crate::project::ltx_project_format_result::*;
 pub use crate::project::ltx_project_options::*;
+pub use crate::project::ltx_project_usage_result::*;
 pub use crate::project::ltx_project_verify_result::*;
 pub use crate::project::ltx_verify_options::*;
@@ -0,0 +1,105 @@
+use xrf_error::XrfResult;
+use xrf_vfs::XrayLogicalPath;
+
+use crate::{Ltx, LtxDiff, LtxProject};
+
+impl LtxProject {
+  /// Compare a config of this project, as the old side, with the same config of another project.
+  ///
+  /// `relative_path` names the config the way the config tree does, such as `system.ltx`, so two projects scoped
+  /// differently still compare the same file.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when either config is not in scope or cannot be read, parsed, or resolved.
+  pub fn diff_config(&self, other: &LtxProject, relative_path: &str, is_with_sources: bool) -> XrfResult<LtxDiff> {
+    let old_path: XrayLogicalPath = self.config_path(relative_path)?;
+    let new_path: XrayLogicalPath = other.config_path(relative_path)?;
+    let diff: LtxDiff = LtxDiff::between(&self.read_full(&old_path)?, &other.read_full(&new_path)?);
+
+    if is_with_sources && !diff.is_empty() {
+      Ok(diff.with_sources(&self.read_sources(&old_path)?, &other.read_sources(&new_path)?))
+    } else {
+      Ok(diff)
+    }
+  }
+
+  /// Reads which project file each value of [`Self::read_full`] comes from, as user-facing paths.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the file is not in scope or cannot be read, parsed, or resolved.
+  pub fn read_sources(&self, logical_path: &XrayLogicalPath) -> XrfResult<Ltx> {
+    let mut sources: Ltx = Ltx::read_sources_from_vfs(self.vfs(), self.scope(), logical_path.as_str())?;
+
+    for (_, section) in sources.sections.iter_mut() {
+      for (_, source) in section.iter_mut() {
+        *source = self
+          .path_of(&XrayLogicalPath::new(source)?)
+          .to_string_lossy()
+          .into_owned();
+      }
+    }
+
+    Ok(sources)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use xrf_error::XrfResult;
+
+  use crate::{LtxDiff, LtxDiffChange, LtxProject};
+
+  #[test]
+  fn diffs_project_configs_with_sources() -> XrfResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-ltx-project-diff-test-{}", std::process::id()));
+    let old: PathBuf = root.join("old");
+    let new: PathBuf = root.join("new");
+
+    if root.exists() {
+      fs::remove_dir_all(&root)?;
+    }
+
+    fs::create_dir_all(old.join("items"))?;
+    fs::create_dir_all(new.join("items"))?;
+    fs::write(old.join("system.ltx"), "#include \"items\\*.ltx\"\n")?;
+    fs::write(old.join("items").join("weapons.ltx"), "[wpn]\ncost = 10\nweight = 2\n")?;
+    fs::write(
+      new.join("system.ltx"),
+      "#include \"items\\*.ltx\"\n\n[wpn]\ncost = 12\n",
+    )?;
+    fs::write(new.join("items").join("stats.ltx"), "[wpn_stats]\nweight = 2\n")?;
+
+    let diff: LtxDiff =
+      LtxProject::open_at_path(&old)?.diff_config(&LtxProject::open_at_path(&new)?, "system.ltx", true)?;
+
+    assert_eq!(diff.sections.len(), 2);
+    assert_eq!(diff.sections[0].name, "wpn");
+    assert_eq!(diff.sections[0].fields[0].change, LtxDiffChange::Changed);
+    assert_eq!(
+      diff.sections[0].fields[0]
+        .old
+        .as_ref()
+        .and_then(|old| old.source.clone()),
+      Some(old.join("items").join("weapons.ltx"))
+    );
+    assert_eq!(
+      diff.sections[0].fields[0]
+        .new
+        .as_ref()
+        .and_then(|new| new.source.clone()),
+      Some(new.join("system.ltx"))
+    );
+    assert_eq!(diff.sections[0].fields[1].change, LtxDiffChange::Removed);
+    assert_eq!(diff.sections[1].name, "wpn_stats");
+    assert_eq!(diff.sections[1].change, LtxDiffChange::Added);
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
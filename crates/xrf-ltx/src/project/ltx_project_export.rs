@@ -0,0 +1,92 @@
+use xrf_error::XrfResult;
+use xrf_vfs::XrayLogicalPath;
+
+use crate::file::include_vfs_source::LtxIncludeVfsSource;
+use crate::{LtxDocument, LtxProject};
+
+impl LtxProject {
+  /// Export one config with includes merged and inheritance resolved, typed by the project schemes.
+  ///
+  /// This is the view the engine reads, so every section carries its full set of fields and no parents. Writing it back
+  /// gives one flattened file rather than the tree it came from.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the config is not in scope or cannot be read, parsed, or resolved.
+  pub fn export_resolved(&self, logical_path: &XrayLogicalPath) -> XrfResult<LtxDocument> {
+    Ok(
+      self
+        .read_full(logical_path)?
+        .to_document_with_schemes(&self.ltx_scheme_declarations),
+    )
+  }
+
+  /// Export one config as written, with its include statements and parents kept.
+  ///
+  /// Only sections declaring `$scheme` themselves are typed: a scheme inherited from a parent is not known until
+  /// inheritance is resolved.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the config is not in scope or cannot be read or parsed.
+  pub fn export_as_written(&self, logical_path: &XrayLogicalPath) -> XrfResult<LtxDocument> {
+    Ok(
+      LtxIncludeVfsSource::new(self.vfs(), self.scope())
+        .read_ltx(logical_path.as_str())?
+        .to_document_with_schemes(&self.ltx_scheme_declarations),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use xrf_error::XrfResult;
+
+  use crate::{LtxDocument, LtxDocumentValue, LtxProject, LtxProjectOptions};
+
+  #[test]
+  fn exports_resolved_and_written_views() -> XrfResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-ltx-project-export-test-{}", std::process::id()));
+
+    if root.exists() {
+      fs::remove_dir_all(&root)?;
+    }
+
+    fs::create_dir_all(&root)?;
+    fs::write(root.join("scheme.ltx"), "[$item]\ncost = u32\n")?;
+    fs::write(
+      root.join("system.ltx"),
+      "#include \"items.ltx\"\n\n[wpn_ak74]:wpn_base\n",
+    )?;
+    fs::write(root.join("items.ltx"), "[wpn_base]\n$scheme = $item\ncost = 10\n")?;
+
+    let project: LtxProject = LtxProject::open_at_path_opt(
+      &root,
+      LtxProjectOptions {
+        is_with_schemes_check: true,
+        ..Default::default()
+      },
+    )?;
+
+    let resolved: LtxDocument = project.export_resolved(&project.system_ltx_path()?)?;
+    let written: LtxDocument = project.export_as_written(&project.system_ltx_path()?)?;
+
+    assert!(resolved.includes.is_empty());
+    assert!(resolved.sections["wpn_ak74"].inherited.is_empty());
+    assert_eq!(
+      resolved.sections["wpn_ak74"].fields["cost"],
+      LtxDocumentValue::Integer(10)
+    );
+
+    assert_eq!(written.includes, vec![String::from("items.ltx")]);
+    assert_eq!(written.sections["wpn_ak74"].inherited, vec![String::from("wpn_base")]);
+    assert!(!written.sections.contains_key("wpn_base"));
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,211 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+use xrf_error::{XrfError, XrfResult};
+
+use crate::file::file_configuration::constants::{LTX_SCHEME_FIELD, LTX_SYMBOL_ANY, LTX_SYMBOL_SCHEME};
+use crate::file::include_vfs_source::LtxIncludeVfsSource;
+use crate::project::ltx_verify_options::LtxVerifyOptions;
+use crate::scheme::field_scheme::LtxFieldScheme;
+use crate::scheme::section_scheme::LtxSectionScheme;
+use crate::{Ltx, LtxProject, LtxProjectUsageResult, LtxSectionReferrer, ROOT_SECTION};
+
+impl LtxProject {
+  /// Collect every section the project declares and every reference between them.
+  ///
+  /// References found in configs are:
+  /// - inheritance, from the child to each parent
+  /// - values of scheme fields typed as sections, condlists or tuples of them
+  /// - any declared name mentioned in a key or value that no scheme types
+  ///
+  /// References from outside the configs, such as spawns and scripts, are for the caller to add.
+  pub fn collect_section_usage_opt(&self, options: LtxVerifyOptions) -> XrfResult<LtxProjectUsageResult> {
+    let mut result: LtxProjectUsageResult = LtxProjectUsageResult::new();
+    let started_at: Instant = Instant::now();
+
+    xrf_output::heading!(options.output, "Collect section usage: {}", self.root.display());
+
+    // Declarations and inheritance come from each file as written: once includes are merged the declaring file is gone,
+    // and once inheritance is resolved so are the parents.
+    let source: LtxIncludeVfsSource = LtxIncludeVfsSource::new(self.vfs(), self.scope());
+    let mut files: Vec<Ltx> = Vec::new();
+
+    for path in &self.ltx_files {
+      if Self::is_ltx_scheme_path(path) {
+        continue;
+      }
+
+      result.total_files += 1;
+
+      match source.read_ltx(path.as_str()) {
+        Ok(ltx) => {
+          let reported: PathBuf = self.path_of(path);
+
+          for section_name in ltx.sections() {
+            if Self::is_usage_section(section_name) && !result.declarations.contains_key(section_name) {
+              result.declarations.insert(String::from(section_name), reported.clone());
+            }
+          }
+
+          files.push(ltx);
+        }
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read {}: {error}",
+          self.path_of(path).display()
+        ))),
+      }
+    }
+
+    for ltx in &files {
+      for (section_name, section) in &ltx.sections {
+        let referrer: LtxSectionReferrer = LtxSectionReferrer::Section(section_name.clone());
+
+        for inherited in &section.inherited {
+          result.add_reference(inherited, &referrer);
+        }
+      }
+    }
+
+    // Field values are read fully resolved, so a section's scheme and values apply to it whichever parent declared them.
+    for entry in &self.ltx_file_entries {
+      if Self::is_ltx_scheme_path(entry) {
+        continue;
+      }
+
+      match self.read_full(entry) {
+        Ok(ltx) => self.collect_ltx_section_usage(&ltx, &mut result),
+        Err(error) => result.errors.push(XrfError::new_verify_error(format!(
+          "Cannot read {}: {error}",
+          self.path_of(entry).display()
+        ))),
+      }
+    }
+
+    result.duration = started_at.elapsed();
+
+    for error in &result.errors {
+      xrf_output::error!(options.output, "{error}");
+    }
+
+    xrf_output::info!(
+      options.output,
+      "Collected usage of {} sections from {} files in {}",
+      result.declarations.len(),
+      result.total_files,
+      xrf_utils::format_duration(result.duration)
+    );
+
+    Ok(result)
+  }
+
+  /// Collect section usage with default options.
+  pub fn collect_section_usage(&self) -> XrfResult<LtxProjectUsageResult> {
+    self.collect_section_usage_opt(Default::default())
+  }
+
+  /// Records references made by the fields of one fully read LTX.
+  fn collect_ltx_section_usage(&self, ltx: &Ltx, result: &mut LtxProjectUsageResult) {
+    for (section_name, section) in &ltx.sections {
+      if !Self::is_usage_section(section_name) {
+        continue;
+      }
+
+      let referrer: LtxSectionReferrer = LtxSectionReferrer::Section(section_name.clone());
+      let scheme: Option<&LtxSectionScheme> = section
+        .get(LTX_SCHEME_FIELD)
+        .and_then(|scheme_name| self.ltx_scheme_declarations.get(scheme_name));
+
+      for (field_name, value) in section {
+        if field_name.starts_with(LTX_SYMBOL_SCHEME) {
+          continue;
+        }
+
+        let declared_field: Option<&LtxFieldScheme> = scheme.and_then(|scheme| scheme.fields.get(field_name));
+        let referenced: Option<Vec<String>> = declared_field
+          .or_else(|| scheme.and_then(|scheme| scheme.fields.get(LTX_SYMBOL_ANY)))
+          .and_then(|field| field.referenced_sections(value));
+
+        // A key only an explicit field declaration describes: mapping sections such as trade lists key on section names.
+        if declared_field.is_none() {
+          result.add_reference(field_name, &referrer);
+        }
+
+        match referenced {
+          Some(referenced) => {
+            for referenced in &referenced {
+              result.add_reference(referenced, &referrer);
+            }
+          }
+          None => result.add_value_references(value, &referrer),
+        }
+      }
+    }
+  }
+
+  /// Whether a section takes part in usage analysis: the unnamed root and scheme declarations are not game sections.
+  fn is_usage_section(section_name: &str) -> bool {
+    section_name != ROOT_SECTION && !section_name.starts_with(LTX_SYMBOL_SCHEME)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use xrf_error::XrfResult;
+
+  use crate::{LtxProject, LtxProjectOptions, LtxProjectUsageResult};
+
+  #[test]
+  fn collects_inheritance_scheme_and_free_form_references() -> XrfResult {
+    let root: PathBuf = std::env::temp_dir().join(format!("xrf-ltx-project-usage-test-{}", std::process::id()));
+
+    if root.exists() {
+      fs::remove_dir_all(&root)?;
+    }
+
+    fs::create_dir_all(root.join("items"))?;
+    fs::write(
+      root.join("scheme.ltx"),
+      "[$item]\nammo = section\nlogic = condlist\ncost = u32\n",
+    )?;
+    fs::write(
+      root.join("system.ltx"),
+      "#include \"items\\*.ltx\"\n\n[trader]\nwpn_ak74 = 1, 1\n",
+    )?;
+    fs::write(
+      root.join("items").join("weapons.ltx"),
+      "[wpn_base]\n$scheme = $item\ncost = 10\n\n[wpn_ak74]:wpn_base\nammo = ammo_fmj\nlogic = {+info} state_a, nil\n",
+    )?;
+    fs::write(
+      root.join("items").join("ammo.ltx"),
+      "[ammo_fmj]\n\n[ammo_dead]\n\n[state_a]\n\n[cost_named_section]\n",
+    )?;
+
+    let project: LtxProject = LtxProject::open_at_path_opt(
+      &root,
+      LtxProjectOptions {
+        is_with_schemes_check: true,
+        ..Default::default()
+      },
+    )?;
+    let result: LtxProjectUsageResult = project.collect_section_usage()?;
+
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    assert_eq!(result.declarations.len(), 7);
+    assert_eq!(
+      result.get_unused_sections(),
+      vec!["ammo_dead", "cost_named_section", "trader"]
+    );
+    // Everything else hangs off the trade list, which nothing references.
+    assert_eq!(
+      result.get_unreachable_sections(),
+      vec!["ammo_fmj", "state_a", "wpn_base", "wpn_ak74"]
+    );
+
+    fs::remove_dir_all(root)?;
+
+    Ok(())
+  }
+}
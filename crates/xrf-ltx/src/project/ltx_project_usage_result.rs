@@ -0,0 +1,197 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use fxhash::FxBuildHasher;
+use indexmap::{IndexMap, IndexSet};
+use xrf_error::XrfError;
+
+/// Where a reference to a section comes from.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LtxSectionReferrer {
+  /// Another section of the project: it inherits the section, or one of its fields names it.
+  Section(String),
+  /// Something outside the configs, such as a spawn file or a script, named by its path.
+  External(String),
+}
+
+/// Which project sections are declared, and what references each of them.
+#[derive(Debug, Default)]
+pub struct LtxProjectUsageResult {
+  /// User-facing path of the file declaring each section, in declaration order.
+  pub declarations: IndexMap<String, PathBuf, FxBuildHasher>,
+  /// Everything referencing a declared section. Sections nothing references have no entry.
+  pub references: IndexMap<String, IndexSet<LtxSectionReferrer, FxBuildHasher>, FxBuildHasher>,
+  pub duration: Duration,
+  pub errors: Vec<XrfError>,
+  pub total_files: usize,
+}
+
+/// Characters that cannot be part of a section name in a value, whatever syntax the value follows.
+const SECTION_TOKEN_SEPARATORS: [char; 14] = [',', ';', '|', '{', '}', '%', '(', ')', '=', ':', '"', '\'', '+', '!'];
+
+impl LtxProjectUsageResult {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Whether the project declares a section.
+  pub fn is_declared(&self, section: &str) -> bool {
+    self.declarations.contains_key(section)
+  }
+
+  /// Records a reference to a declared section, ignoring names the project does not declare.
+  ///
+  /// A section naming itself keeps nothing alive, so such a reference is not recorded either.
+  pub fn add_reference(&mut self, section: &str, referrer: &LtxSectionReferrer) {
+    if !self.is_declared(section) || matches!(referrer, LtxSectionReferrer::Section(name) if name == section) {
+      return;
+    }
+
+    self
+      .references
+      .entry(String::from(section))
+      .or_default()
+      .insert(referrer.clone());
+  }
+
+  /// Records every declared section a free-form value mentions.
+  ///
+  /// Used where no scheme says what a value holds. Matching every token errs towards keeping a section alive, which is the
+  /// safe direction for a report whose purpose is deciding what can be deleted.
+  pub fn add_value_references(&mut self, value: &str, referrer: &LtxSectionReferrer) {
+    for token in value
+      .split(|character: char| character.is_whitespace() || SECTION_TOKEN_SEPARATORS.contains(&character))
+      .filter(|token| !token.is_empty())
+    {
+      self.add_reference(token, referrer);
+    }
+  }
+
+  /// Declared sections nothing references, in declaration order.
+  pub fn get_unused_sections(&self) -> Vec<&str> {
+    self
+      .declarations
+      .keys()
+      .filter(|section| !self.references.contains_key(*section))
+      .map(String::as_str)
+      .collect()
+  }
+
+  /// Referenced sections no chain of references from outside the configs leads to, in declaration order.
+  ///
+  /// A base section inherited only by dead items is as removable as the items, and so are sections naming only each
+  /// other, but a plain unused check never reports either while the references are still there.
+  pub fn get_unreachable_sections(&self) -> Vec<&str> {
+    let mut referenced: IndexMap<&str, Vec<&str>, FxBuildHasher> = IndexMap::default();
+    let mut reachable: IndexSet<&str, FxBuildHasher> = IndexSet::default();
+
+    for (section, referrers) in &self.references {
+      for referrer in referrers {
+        match referrer {
+          LtxSectionReferrer::Section(name) => referenced.entry(name.as_str()).or_default().push(section.as_str()),
+          LtxSectionReferrer::External(_) => {
+            reachable.insert(section.as_str());
+          }
+        }
+      }
+    }
+
+    let mut pending: Vec<&str> = reachable.iter().copied().collect();
+
+    while let Some(section) = pending.pop() {
+      for target in referenced.get(section).into_iter().flatten() {
+        if reachable.insert(target) {
+          pending.push(target);
+        }
+      }
+    }
+
+    self
+      .declarations
+      .keys()
+      .map(String::as_str)
+      .filter(|section| self.references.contains_key(*section) && !reachable.contains(section))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::{LtxProjectUsageResult, LtxSectionReferrer};
+
+  fn declared(sections: &[&str]) -> LtxProjectUsageResult {
+    let mut result: LtxProjectUsageResult = LtxProjectUsageResult::new();
+
+    for section in sections {
+      result
+        .declarations
+        .insert(String::from(*section), PathBuf::from("system.ltx"));
+    }
+
+    result
+  }
+
+  #[test]
+  fn reports_sections_without_references_as_unused() {
+    let mut result: LtxProjectUsageResult = declared(&["used", "unused", "self_referenced"]);
+
+    result.add_reference("used", &LtxSectionReferrer::External(String::from("scripts\\a.script")));
+    result.add_reference(
+      "missing",
+      &LtxSectionReferrer::External(String::from("scripts\\a.script")),
+    );
+    result.add_reference(
+      "self_referenced",
+      &LtxSectionReferrer::Section(String::from("self_referenced")),
+    );
+
+    assert_eq!(result.get_unused_sections(), vec!["unused", "self_referenced"]);
+    assert!(!result.references.contains_key("missing"));
+  }
+
+  #[test]
+  fn reports_sections_referenced_only_by_dead_sections_as_unreachable() {
+    let mut result: LtxProjectUsageResult = declared(&["base", "middle", "dead_item", "live_item", "shared"]);
+
+    result.add_reference("middle", &LtxSectionReferrer::Section(String::from("dead_item")));
+    result.add_reference("base", &LtxSectionReferrer::Section(String::from("middle")));
+    result.add_reference("shared", &LtxSectionReferrer::Section(String::from("dead_item")));
+    result.add_reference("shared", &LtxSectionReferrer::Section(String::from("live_item")));
+    result.add_reference(
+      "live_item",
+      &LtxSectionReferrer::External(String::from("spawns\\all.spawn")),
+    );
+
+    assert_eq!(result.get_unused_sections(), vec!["dead_item"]);
+    assert_eq!(result.get_unreachable_sections(), vec!["base", "middle"]);
+  }
+
+  #[test]
+  fn reports_sections_referencing_only_each_other_as_unreachable() {
+    let mut result: LtxProjectUsageResult = declared(&["cycle_a", "cycle_b", "live_a", "live_b"]);
+
+    result.add_reference("cycle_a", &LtxSectionReferrer::Section(String::from("cycle_b")));
+    result.add_reference("cycle_b", &LtxSectionReferrer::Section(String::from("cycle_a")));
+    result.add_reference("live_a", &LtxSectionReferrer::Section(String::from("live_b")));
+    result.add_reference("live_b", &LtxSectionReferrer::Section(String::from("live_a")));
+    result.add_reference(
+      "live_a",
+      &LtxSectionReferrer::External(String::from("scripts\\a.script")),
+    );
+
+    assert!(result.get_unused_sections().is_empty());
+    assert_eq!(result.get_unreachable_sections(), vec!["cycle_a", "cycle_b"]);
+  }
+
+  #[test]
+  fn matches_declared_sections_in_free_form_values() {
+    let mut result: LtxProjectUsageResult = declared(&["walker@one", "wpn_ak74", "ammo_5.45x39_fmj"]);
+    let referrer: LtxSectionReferrer = LtxSectionReferrer::Section(String::from("trader"));
+
+    result.add_value_references("{+info =func(wpn_ak74)} walker@one, ammo_5.45x39_fmj:2", &referrer);
+
+    assert!(result.get_unused_sections().is_empty());
+  }
+}
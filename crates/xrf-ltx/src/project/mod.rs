@@ -1,9 +1,13 @@
 pub mod ltx_files_formatter;
 pub mod ltx_format_options;
 pub mod ltx_project;
+pub mod ltx_project_diff;
+pub mod ltx_project_export;
 pub mod ltx_project_format;
 pub mod ltx_project_format_result;
 pub mod ltx_project_options;
+pub mod ltx_project_usage;
+pub mod ltx_project_usage_result;
 pub mod ltx_project_verify;
 pub mod ltx_project_verify_result;
 pub mod ltx_verify_options;
@@ -164,6 +164,49 @@ impl LtxFieldScheme {
   pub fn is_section(&self) -> bool {
     self.data_type == LtxFieldDataType::TypeSection
   }
+
+  /// Section names a value of this field refers to.
+  ///
+  /// Returns `None` when the type cannot tell: a string or untyped field may hold a section name, and only the caller knows
+  /// whether guessing is acceptable.
+  pub fn referenced_sections(&self, field_data: &str) -> Option<Vec<String>> {
+    if self.is_array {
+      let mut referenced: Vec<String> = Vec::new();
+
+      for entry in field_data.split(',').map(|it| it.trim()).filter(|it| !it.is_empty()) {
+        referenced.extend(Self::referenced_sections_by_type(&self.data_type, entry)?);
+      }
+
+      Some(referenced)
+    } else {
+      Self::referenced_sections_by_type(&self.data_type, field_data)
+    }
+  }
+
+  fn referenced_sections_by_type(field_type: &LtxFieldDataType, field_data: &str) -> Option<Vec<String>> {
+    let field_data: &str = field_data.trim();
+
+    match field_type {
+      LtxFieldDataType::TypeAny | LtxFieldDataType::TypeString | LtxFieldDataType::TypeUnknown => None,
+      LtxFieldDataType::TypeSection if field_data.is_empty() => Some(Vec::new()),
+      LtxFieldDataType::TypeSection => Some(vec![String::from(field_data)]),
+      // A malformed condlist is the verifier's finding, and names nothing here.
+      LtxFieldDataType::TypeCondlist => Some(Condlist::parse(field_data).map_or_else(
+        |_| Vec::new(),
+        |condlist| condlist.result_names().into_iter().map(String::from).collect(),
+      )),
+      LtxFieldDataType::TypeTuple(types, _, separator) => {
+        let mut referenced: Vec<String> = Vec::new();
+
+        for (entry_type, entry) in types.iter().zip(field_data.split(separator.as_char())) {
+          referenced.extend(Self::referenced_sections_by_type(entry_type, entry)?);
+        }
+
+        Some(referenced)
+      }
+      _ => Some(Vec::new()),
+    }
+  }
 }
 
 impl LtxFieldScheme {
@@ -732,4 +775,36 @@ mod tests {
         .is_none()
     );
   }
+
+  #[test]
+  fn test_referenced_sections() {
+    let sections: LtxFieldScheme =
+      LtxFieldScheme::new_with_array_type("test_section", "test_field", LtxFieldDataType::TypeSection);
+    let condlist: LtxFieldScheme =
+      LtxFieldScheme::new_with_type("test_section", "test_field", LtxFieldDataType::TypeCondlist);
+    let tuple: LtxFieldScheme = LtxFieldScheme::new_with_type(
+      "test_section",
+      "test_field",
+      LtxFieldDataType::from_field_data("test_field", "test_section", "tuple:section,f32")
+        .expect("Expected tuple type to parse"),
+    );
+    let string: LtxFieldScheme =
+      LtxFieldScheme::new_with_type("test_section", "test_field", LtxFieldDataType::TypeString);
+    let number: LtxFieldScheme = LtxFieldScheme::new_with_type("test_section", "test_field", LtxFieldDataType::TypeF32);
+
+    assert_eq!(
+      sections.referenced_sections("first, second,"),
+      Some(vec![String::from("first"), String::from("second")])
+    );
+    assert_eq!(
+      condlist.referenced_sections("{+info} walker@one, nil"),
+      Some(vec![String::from("walker@one")])
+    );
+    assert_eq!(
+      tuple.referenced_sections("first, 1.5"),
+      Some(vec![String::from("first")])
+    );
+    assert_eq!(number.referenced_sections("1.5"), Some(Vec::new()));
+    assert_eq!(string.referenced_sections("first"), None);
+  }
 }
@@ -1,8 +1,22 @@
+mod lua_call_arguments;
 mod lua_method_call_collector;
+mod lua_script_symbol_collector;
+mod lua_string_literal_collector;
 mod verify_luajit_script;
+mod xray_lua_diagnostic;
+mod xray_lua_function_call;
 mod xray_lua_method_call;
 mod xray_lua_script;
+mod xray_lua_script_analyzer;
+mod xray_lua_script_symbols;
 
 pub use verify_luajit_script::verify_luajit_script;
+pub use xray_lua_diagnostic::{XRayLuaDiagnostic, XRayLuaDiagnosticKind};
+pub use xray_lua_function_call::XRayLuaFunctionCall;
 pub use xray_lua_method_call::XRayLuaMethodCall;
 pub use xray_lua_script::XRayLuaScript;
+pub use xray_lua_script_analyzer::XRayLuaScriptAnalyzer;
+pub use xray_lua_script_symbols::{
+  XRayLuaArguments, XRayLuaArity, XRayLuaArityMismatch, XRayLuaGlobalCall, XRayLuaGlobalDefinition,
+  XRayLuaScriptSymbols,
+};
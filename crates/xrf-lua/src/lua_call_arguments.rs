@@ -0,0 +1,141 @@
+use full_moon::ast::{Expression, FunctionArgs};
+use full_moon::node::Node;
+use full_moon::tokenizer::{StringLiteralQuoteType, TokenReference, TokenType};
+
+/// Reads call arguments the way the script passes them at runtime.
+pub(crate) struct LuaCallArguments;
+
+impl LuaCallArguments {
+  /// Values of the arguments of a call, `None` for any argument that is not a string literal.
+  ///
+  /// Escapes are resolved, so `"ui\\ui_icon"` reads as the single-backslash path the engine receives.
+  pub(crate) fn literal_strings(arguments: &FunctionArgs) -> Vec<Option<String>> {
+    match arguments {
+      FunctionArgs::Parentheses { arguments, .. } => arguments.iter().map(Self::literal_string).collect(),
+      FunctionArgs::String(string) => vec![Self::token_string(string)],
+      _ => vec![None],
+    }
+  }
+
+  /// Values of the arguments of a call as written in the source, `None` for any argument that is not a string literal.
+  pub(crate) fn raw_literal_strings(arguments: &FunctionArgs) -> Vec<Option<String>> {
+    match arguments {
+      FunctionArgs::Parentheses { arguments, .. } => arguments.iter().map(Self::raw_literal_string).collect(),
+      FunctionArgs::String(string) => vec![Self::raw_token_string(string)],
+      _ => vec![None],
+    }
+  }
+
+  /// Source text of a node without whitespace or comments, used to name receivers such as `alife()` or `db.actor`.
+  pub(crate) fn source_text<N: Node>(node: &N) -> String {
+    node.tokens().map(|token| token.token().to_string()).collect()
+  }
+
+  fn literal_string(expression: &Expression) -> Option<String> {
+    let Expression::String(string) = expression else {
+      return None;
+    };
+
+    Self::token_string(string)
+  }
+
+  fn raw_literal_string(expression: &Expression) -> Option<String> {
+    let Expression::String(string) = expression else {
+      return None;
+    };
+
+    Self::raw_token_string(string)
+  }
+
+  fn raw_token_string(string: &TokenReference) -> Option<String> {
+    let TokenType::StringLiteral { literal, .. } = string.token().token_type() else {
+      return None;
+    };
+
+    Some(literal.to_string())
+  }
+
+  fn token_string(string: &TokenReference) -> Option<String> {
+    Self::literal_value(string.token().token_type())
+  }
+
+  /// Value of a string literal token as the script sees it at runtime, `None` for any other token.
+  ///
+  /// Long bracket strings are kept as written, since Lua does not resolve escapes in them.
+  pub(crate) fn literal_value(token_type: &TokenType) -> Option<String> {
+    let TokenType::StringLiteral {
+      literal, quote_type, ..
+    } = token_type
+    else {
+      return None;
+    };
+
+    Some(if *quote_type == StringLiteralQuoteType::Brackets {
+      literal.to_string()
+    } else {
+      Self::unescape(literal)
+    })
+  }
+
+  /// Resolve Lua escape sequences, decimal and hexadecimal escapes being read as single byte characters.
+  fn unescape(literal: &str) -> String {
+    let mut value: String = String::with_capacity(literal.len());
+    let mut characters = literal.chars().peekable();
+
+    while let Some(character) = characters.next() {
+      if character != '\\' {
+        value.push(character);
+        continue;
+      }
+
+      let Some(escaped) = characters.next() else {
+        break;
+      };
+
+      match escaped {
+        'n' => value.push('\n'),
+        't' => value.push('\t'),
+        'r' => value.push('\r'),
+        'a' => value.push('\x07'),
+        'b' => value.push('\x08'),
+        'f' => value.push('\x0C'),
+        'v' => value.push('\x0B'),
+        'z' => while characters.next_if(|next| next.is_whitespace()).is_some() {},
+        'x' => {
+          let digits: String = (0..2)
+            .filter_map(|_| characters.next_if(char::is_ascii_hexdigit))
+            .collect();
+
+          value.extend(u8::from_str_radix(&digits, 16).ok().map(char::from));
+        }
+        '0'..='9' => {
+          let mut digits: String = String::from(escaped);
+
+          while digits.len() < 3
+            && let Some(digit) = characters.next_if(char::is_ascii_digit)
+          {
+            digits.push(digit);
+          }
+
+          value.extend(digits.parse::<u8>().ok().map(char::from));
+        }
+        other => value.push(other),
+      }
+    }
+
+    value
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LuaCallArguments;
+
+  #[test]
+  fn resolves_escape_sequences() {
+    assert_eq!(LuaCallArguments::unescape(r"ui\\ui_icon"), r"ui\ui_icon");
+    assert_eq!(LuaCallArguments::unescape(r#"say \"hi\"\n"#), "say \"hi\"\n");
+    assert_eq!(LuaCallArguments::unescape(r"\65\x42\067"), "ABC");
+    assert_eq!(LuaCallArguments::unescape("a\\z   b"), "ab");
+  }
+}
@@ -1,53 +1,33 @@
-use full_moon::ast::{Ast, Call, Expression, FunctionArgs, FunctionCall, Prefix, Suffix};
-use full_moon::tokenizer::TokenType;
+use full_moon::ast::{Ast, Call, FunctionCall, Index, Prefix, Suffix};
 use full_moon::visitors::Visitor;
 
+use crate::lua_call_arguments::LuaCallArguments;
+use crate::xray_lua_function_call::XRayLuaFunctionCall;
 use crate::xray_lua_method_call::XRayLuaMethodCall;
 
 pub(crate) struct LuaMethodCallCollector {
-  method_calls: Vec<XRayLuaMethodCall>,
+  pub(crate) expression_method_calls: Vec<XRayLuaMethodCall>,
+  pub(crate) function_calls: Vec<XRayLuaFunctionCall>,
+  pub(crate) method_calls: Vec<XRayLuaMethodCall>,
 }
 
 impl LuaMethodCallCollector {
-  pub(crate) fn collect(ast: &Ast) -> Vec<XRayLuaMethodCall> {
+  /// Method calls on named receivers with literals as written, method calls on any receiver expression with escapes
+  /// resolved, and calls of named functions.
+  pub(crate) fn collect(ast: &Ast) -> Self {
     let mut collector: Self = Self {
+      expression_method_calls: Vec::new(),
+      function_calls: Vec::new(),
       method_calls: Vec::new(),
     };
 
     collector.visit_ast(ast);
 
-    collector.method_calls
+    collector
   }
 
-  fn literal_string(expression: &Expression) -> Option<String> {
-    let Expression::String(string) = expression else {
-      return None;
-    };
-    let TokenType::StringLiteral { literal, .. } = string.token().token_type() else {
-      return None;
-    };
-
-    Some(literal.to_string())
-  }
-
-  fn literal_string_arguments(arguments: &FunctionArgs) -> Option<Vec<String>> {
-    match arguments {
-      FunctionArgs::Parentheses { arguments, .. } => arguments
-        .iter()
-        .map(Self::literal_string)
-        .collect::<Option<Vec<String>>>(),
-      FunctionArgs::String(string) => match string.token().token_type() {
-        TokenType::StringLiteral { literal, .. } => Some(vec![literal.to_string()]),
-        _ => None,
-      },
-      FunctionArgs::TableConstructor(_) => None,
-      _ => None,
-    }
-  }
-}
-
-impl Visitor for LuaMethodCallCollector {
-  fn visit_function_call(&mut self, function_call: &FunctionCall) {
+  /// Records `name:method(...)` when it starts the call chain.
+  fn collect_method_call(&mut self, function_call: &FunctionCall) {
     let Prefix::Name(receiver) = function_call.prefix() else {
       return;
     };
@@ -59,7 +39,60 @@ impl Visitor for LuaMethodCallCollector {
       method_call.name().token().start_position().line(),
       receiver.token().to_string(),
       method_call.name().token().to_string(),
-      Self::literal_string_arguments(method_call.args()),
+      LuaCallArguments::raw_literal_strings(method_call.args()),
     ));
   }
+
+  /// Records every method call of a chain, the receiver being the source text before it.
+  fn collect_expression_method_calls(&mut self, function_call: &FunctionCall) {
+    let mut receiver: String = LuaCallArguments::source_text(function_call.prefix());
+
+    for suffix in function_call.suffixes() {
+      if let Suffix::Call(Call::MethodCall(method_call)) = suffix {
+        self.expression_method_calls.push(XRayLuaMethodCall::from_parts(
+          method_call.name().token().start_position().line(),
+          receiver.clone(),
+          method_call.name().token().to_string(),
+          LuaCallArguments::literal_strings(method_call.args()),
+        ));
+      }
+
+      receiver.push_str(&LuaCallArguments::source_text(suffix));
+    }
+  }
+
+  /// Records `name(...)` and `name.field(...)`, the path ending at the first call.
+  fn collect_function_call(&mut self, function_call: &FunctionCall) {
+    let Prefix::Name(name) = function_call.prefix() else {
+      return;
+    };
+    let mut function: String = name.token().to_string();
+
+    for suffix in function_call.suffixes() {
+      match suffix {
+        Suffix::Index(Index::Dot { name, .. }) => {
+          function.push('.');
+          function.push_str(&name.token().to_string());
+        }
+        Suffix::Call(Call::AnonymousCall(arguments)) => {
+          self.function_calls.push(XRayLuaFunctionCall::from_parts(
+            name.token().start_position().line(),
+            function,
+            LuaCallArguments::literal_strings(arguments),
+          ));
+
+          return;
+        }
+        _ => return,
+      }
+    }
+  }
+}
+
+impl Visitor for LuaMethodCallCollector {
+  fn visit_function_call(&mut self, function_call: &FunctionCall) {
+    self.collect_function_call(function_call);
+    self.collect_method_call(function_call);
+    self.collect_expression_method_calls(function_call);
+  }
 }
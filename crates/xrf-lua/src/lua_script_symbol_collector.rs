@@ -0,0 +1,556 @@
+use std::collections::HashMap;
+
+use full_moon::ast::{
+  Ast, Block, Call, Expression, Field, FunctionArgs, FunctionBody, FunctionCall, Index, LastStmt, Parameter, Prefix,
+  Stmt, Suffix, Var, VarExpression,
+};
+use full_moon::tokenizer::{TokenReference, TokenType};
+
+use crate::xray_lua_script_symbols::{
+  XRayLuaArguments, XRayLuaArity, XRayLuaArityMismatch, XRayLuaGlobalCall, XRayLuaGlobalDefinition,
+  XRayLuaScriptSymbols,
+};
+
+/// Walks a script with its lexical scopes, recording what it takes from and gives to the global environment.
+///
+/// Locals map to the arity of the function they were initialized with, when they were.
+pub(crate) struct LuaScriptSymbolCollector {
+  scopes: Vec<HashMap<String, Option<XRayLuaArity>>>,
+  exported_table: Option<String>,
+  symbols: XRayLuaScriptSymbols,
+}
+
+impl LuaScriptSymbolCollector {
+  pub(crate) fn collect(ast: &Ast) -> XRayLuaScriptSymbols {
+    let mut collector: Self = Self {
+      scopes: Vec::new(),
+      exported_table: Self::returned_name(ast.nodes()),
+      symbols: XRayLuaScriptSymbols::default(),
+    };
+
+    collector.block(ast.nodes(), Vec::new());
+
+    collector.symbols
+  }
+
+  /// Name of the variable a chunk ends with `return name`.
+  fn returned_name(block: &Block) -> Option<String> {
+    let Some(LastStmt::Return(statement)) = block.last_stmt() else {
+      return None;
+    };
+    let mut returns = statement.returns().iter();
+
+    match (returns.next(), returns.next()) {
+      (Some(Expression::Var(Var::Name(name))), None) => Some(Self::name(name)),
+      _ => None,
+    }
+  }
+
+  fn name(token: &TokenReference) -> String {
+    token.token().to_string()
+  }
+
+  fn line(token: &TokenReference) -> usize {
+    token.token().start_position().line()
+  }
+
+  fn local(&self, name: &str) -> Option<Option<XRayLuaArity>> {
+    self.scopes.iter().rev().find_map(|scope| scope.get(name).copied())
+  }
+
+  fn declare(&mut self, name: String, arity: Option<XRayLuaArity>) {
+    if let Some(scope) = self.scopes.last_mut() {
+      scope.insert(name, arity);
+    }
+  }
+
+  fn arity(body: &FunctionBody) -> XRayLuaArity {
+    XRayLuaArity {
+      parameters: body
+        .parameters()
+        .iter()
+        .filter(|parameter| matches!(parameter, Parameter::Name(_)))
+        .count(),
+      is_vararg: body
+        .parameters()
+        .iter()
+        .any(|parameter| matches!(parameter, Parameter::Ellipsis(_))),
+    }
+  }
+
+  fn expression_arity(expression: &Expression) -> Option<XRayLuaArity> {
+    match expression {
+      Expression::Function(function) => Some(Self::arity(function.body())),
+      _ => None,
+    }
+  }
+
+  fn block(&mut self, block: &Block, locals: Vec<(String, Option<XRayLuaArity>)>) {
+    self.scopes.push(locals.into_iter().collect());
+    self.statements(block);
+    self.scopes.pop();
+  }
+
+  fn statements(&mut self, block: &Block) {
+    for statement in block.stmts() {
+      self.statement(statement);
+    }
+
+    if let Some(LastStmt::Return(statement)) = block.last_stmt() {
+      for expression in statement.returns() {
+        self.expression(expression);
+      }
+    }
+  }
+
+  fn statement(&mut self, statement: &Stmt) {
+    match statement {
+      Stmt::LocalAssignment(assignment) => {
+        let expressions: Vec<&Expression> = assignment.expressions().iter().collect();
+
+        for expression in &expressions {
+          self.expression(expression);
+        }
+
+        for (index, name) in assignment.names().iter().enumerate() {
+          let arity: Option<XRayLuaArity> = expressions.get(index).and_then(|value| Self::expression_arity(value));
+
+          self.declare(Self::name(name), arity);
+        }
+      }
+      Stmt::LocalFunction(function) => {
+        self.declare(Self::name(function.name()), Some(Self::arity(function.body())));
+        self.function_body(function.body(), false);
+      }
+      Stmt::FunctionDeclaration(declaration) => {
+        let names: Vec<&TokenReference> = declaration.name().names().iter().collect();
+        let is_method: bool = declaration.name().method_name().is_some();
+        let arity: XRayLuaArity = Self::arity(declaration.body());
+
+        match names.as_slice() {
+          [name] if !is_method => self.assign_name(name, Some(arity)),
+          [table, field] if !is_method && self.is_exported_table(table) => {
+            self.symbols.exported_fields.push(Self::name(field));
+          }
+          [table, ..] => self.read(table),
+          [] => {}
+        }
+
+        self.function_body(declaration.body(), is_method);
+      }
+      Stmt::Assignment(assignment) => {
+        let expressions: Vec<&Expression> = assignment.expressions().iter().collect();
+
+        for expression in &expressions {
+          self.expression(expression);
+        }
+
+        for (index, variable) in assignment.variables().iter().enumerate() {
+          let arity: Option<XRayLuaArity> = expressions.get(index).and_then(|value| Self::expression_arity(value));
+
+          match variable {
+            Var::Name(name) => self.assign_name(name, arity),
+            Var::Expression(expression) => self.assign_field(expression),
+            _ => {}
+          }
+        }
+      }
+      Stmt::FunctionCall(call) => self.call(call),
+      Stmt::Do(statement) => self.block(statement.block(), Vec::new()),
+      Stmt::While(statement) => {
+        self.expression(statement.condition());
+        self.block(statement.block(), Vec::new());
+      }
+      Stmt::Repeat(statement) => {
+        // The `until` condition sees locals of the loop body.
+        self.scopes.push(HashMap::new());
+        self.statements(statement.block());
+        self.expression(statement.until());
+        self.scopes.pop();
+      }
+      Stmt::If(statement) => {
+        self.expression(statement.condition());
+        self.block(statement.block(), Vec::new());
+
+        for branch in statement.else_if().into_iter().flatten() {
+          self.expression(branch.condition());
+          self.block(branch.block(), Vec::new());
+        }
+
+        if let Some(block) = statement.else_block() {
+          self.block(block, Vec::new());
+        }
+      }
+      Stmt::NumericFor(statement) => {
+        self.expression(statement.start());
+        self.expression(statement.end());
+
+        if let Some(step) = statement.step() {
+          self.expression(step);
+        }
+
+        self.block(statement.block(), vec![(Self::name(statement.index_variable()), None)]);
+      }
+      Stmt::GenericFor(statement) => {
+        for expression in statement.expressions() {
+          self.expression(expression);
+        }
+
+        self.block(
+          statement.block(),
+          statement.names().iter().map(|name| (Self::name(name), None)).collect(),
+        );
+      }
+      _ => {}
+    }
+  }
+
+  fn function_body(&mut self, body: &FunctionBody, is_method: bool) {
+    let mut locals: Vec<(String, Option<XRayLuaArity>)> = body
+      .parameters()
+      .iter()
+      .filter_map(|parameter| match parameter {
+        Parameter::Name(name) => Some((Self::name(name), None)),
+        _ => None,
+      })
+      .collect();
+
+    if is_method {
+      locals.push((String::from("self"), None));
+    }
+
+    self.block(body.block(), locals);
+  }
+
+  fn is_exported_table(&self, token: &TokenReference) -> bool {
+    self.exported_table.as_deref() == Some(Self::name(token).as_str()) && self.local(&Self::name(token)).is_some()
+  }
+
+  /// Assignment of a bare name: a local update, or a global of the script namespace.
+  fn assign_name(&mut self, token: &TokenReference, arity: Option<XRayLuaArity>) {
+    let name: String = Self::name(token);
+
+    if self.local(&name).is_none() {
+      self.symbols.definitions.push(XRayLuaGlobalDefinition {
+        name,
+        line_number: Self::line(token),
+        arity,
+      });
+    }
+  }
+
+  /// Assignment of `table.field`, which defines a global for `_G.name` and an export for the returned table.
+  fn assign_field(&mut self, expression: &VarExpression) {
+    let suffixes: Vec<&Suffix> = expression.suffixes().collect();
+
+    if let (Prefix::Name(table), [Suffix::Index(Index::Dot { name, .. })]) = (expression.prefix(), suffixes.as_slice())
+    {
+      if Self::name(table) == "_G" && self.local("_G").is_none() {
+        self.symbols.shared_globals.push(Self::name(name));
+        return;
+      }
+
+      if self.is_exported_table(table) {
+        self.symbols.exported_fields.push(Self::name(name));
+        return;
+      }
+    }
+
+    self.prefixed(expression.prefix(), expression.suffixes().collect());
+  }
+
+  fn read(&mut self, token: &TokenReference) {
+    let name: String = Self::name(token);
+
+    if self.local(&name).is_none() {
+      self.symbols.global_reads.push((name, Self::line(token)));
+    }
+  }
+
+  fn call(&mut self, call: &FunctionCall) {
+    self.prefixed(call.prefix(), call.suffixes().collect());
+  }
+
+  /// Walk `prefix.suffix(...)...` chains, recording calls of free names and of namespace functions.
+  fn prefixed(&mut self, prefix: &Prefix, suffixes: Vec<&Suffix>) {
+    let mut rest: &[&Suffix] = &suffixes;
+
+    match prefix {
+      Prefix::Name(token) => {
+        let name: String = Self::name(token);
+        let local: Option<Option<XRayLuaArity>> = self.local(&name);
+
+        match (local, suffixes.as_slice()) {
+          (Some(arity), [Suffix::Call(Call::AnonymousCall(arguments)), ..]) => {
+            if let Some(arity) = arity {
+              let arguments: XRayLuaArguments = Self::arguments(arguments);
+
+              if !arity.accepts(&arguments) {
+                self.symbols.local_arity_mismatches.push(XRayLuaArityMismatch {
+                  line_number: Self::line(token),
+                  function: name,
+                  arity,
+                  arguments,
+                });
+              }
+            }
+          }
+          (None, [Suffix::Call(Call::AnonymousCall(arguments)), ..]) => {
+            if name == "class"
+              && let Some(class_name) = Self::string_argument(arguments)
+            {
+              self.symbols.definitions.push(XRayLuaGlobalDefinition {
+                name: class_name,
+                line_number: Self::line(token),
+                arity: None,
+              });
+            }
+
+            self.symbols.global_calls.push(XRayLuaGlobalCall {
+              line_number: Self::line(token),
+              namespace: None,
+              function: name.clone(),
+              arguments: Self::arguments(arguments),
+            });
+            self.symbols.global_reads.push((name, Self::line(token)));
+          }
+          (
+            None,
+            [
+              Suffix::Index(Index::Dot { name: function, .. }),
+              Suffix::Call(Call::AnonymousCall(arguments)),
+              ..,
+            ],
+          ) => {
+            self.symbols.global_calls.push(XRayLuaGlobalCall {
+              line_number: Self::line(token),
+              namespace: Some(name),
+              function: Self::name(function),
+              arguments: Self::arguments(arguments),
+            });
+            rest = &suffixes[1..];
+          }
+          (None, _) => self.symbols.global_reads.push((name, Self::line(token))),
+          (Some(_), _) => {}
+        }
+      }
+      Prefix::Expression(expression) => self.expression(expression),
+      _ => {}
+    }
+
+    for suffix in rest {
+      match suffix {
+        Suffix::Index(Index::Brackets { expression, .. }) => self.expression(expression),
+        Suffix::Call(Call::AnonymousCall(arguments)) => self.arguments_values(arguments),
+        Suffix::Call(Call::MethodCall(call)) => self.arguments_values(call.args()),
+        _ => {}
+      }
+    }
+  }
+
+  fn arguments(arguments: &FunctionArgs) -> XRayLuaArguments {
+    match arguments {
+      FunctionArgs::Parentheses { arguments, .. } => XRayLuaArguments {
+        count: arguments.len(),
+        is_open: matches!(
+          arguments.iter().last(),
+          Some(Expression::FunctionCall(_)) | Some(Expression::Symbol(_))
+        ),
+      },
+      _ => XRayLuaArguments {
+        count: 1,
+        is_open: false,
+      },
+    }
+  }
+
+  fn string_argument(arguments: &FunctionArgs) -> Option<String> {
+    let token: &TokenReference = match arguments {
+      FunctionArgs::String(token) => token,
+      FunctionArgs::Parentheses { arguments, .. } => match arguments.iter().next() {
+        Some(Expression::String(token)) => token,
+        _ => return None,
+      },
+      _ => return None,
+    };
+
+    match token.token().token_type() {
+      TokenType::StringLiteral { literal, .. } => Some(literal.to_string()),
+      _ => None,
+    }
+  }
+
+  fn arguments_values(&mut self, arguments: &FunctionArgs) {
+    match arguments {
+      FunctionArgs::Parentheses { arguments, .. } => {
+        for argument in arguments {
+          self.expression(argument);
+        }
+      }
+      FunctionArgs::TableConstructor(table) => self.table(table.fields().iter().collect()),
+      _ => {}
+    }
+  }
+
+  fn table(&mut self, fields: Vec<&Field>) {
+    for field in fields {
+      match field {
+        Field::ExpressionKey { key, value, .. } => {
+          self.expression(key);
+          self.expression(value);
+        }
+        Field::NameKey { value, .. } | Field::NoKey(value) => self.expression(value),
+        _ => {}
+      }
+    }
+  }
+
+  fn expression(&mut self, expression: &Expression) {
+    match expression {
+      Expression::BinaryOperator { lhs, rhs, .. } => {
+        self.expression(lhs);
+        self.expression(rhs);
+      }
+      Expression::Parentheses { expression, .. } | Expression::UnaryOperator { expression, .. } => {
+        self.expression(expression);
+      }
+      Expression::Function(function) => self.function_body(function.body(), false),
+      Expression::FunctionCall(call) => self.call(call),
+      Expression::TableConstructor(table) => self.table(table.fields().iter().collect()),
+      Expression::Var(Var::Name(name)) => self.read(name),
+      Expression::Var(Var::Expression(expression)) => {
+        self.prefixed(expression.prefix(), expression.suffixes().collect());
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use xrf_error::XrfResult;
+
+  use crate::xray_lua_script_symbols::{XRayLuaArguments, XRayLuaArity};
+  use crate::{XRayLuaScript, XRayLuaScriptSymbols};
+
+  #[test]
+  fn collects_definitions_reads_and_calls() -> XrfResult {
+    let script: XRayLuaScript = XRayLuaScript::parse(
+      Path::new("scripts/sample.script"),
+      r#"
+local cache = {}
+local function pick(a, b) return a or b end
+
+class "sample_binder" (object_binder)
+
+function on_update(object, delta)
+  local value = pick(object, delta, cache)
+  xr_logic.activate(object, value)
+  _G.shared_flag = true
+  counter = (counter or 0) + unknown_value
+  for index, item in pairs(cache) do
+    pick(index)
+  end
+end
+"#,
+    )?;
+    let symbols: &XRayLuaScriptSymbols = script.symbols();
+
+    assert_eq!(
+      symbols.exports().collect::<Vec<&str>>(),
+      vec!["sample_binder", "on_update", "counter"]
+    );
+    assert_eq!(symbols.shared_globals, vec![String::from("shared_flag")]);
+    assert_eq!(
+      symbols
+        .global_reads
+        .iter()
+        .map(|(name, line)| (name.as_str(), *line))
+        .collect::<Vec<(&str, usize)>>(),
+      vec![
+        ("class", 5),
+        ("object_binder", 5),
+        ("counter", 11),
+        ("unknown_value", 11),
+        ("pairs", 12)
+      ]
+    );
+    assert_eq!(
+      symbols
+        .global_calls
+        .iter()
+        .map(|call| (call.namespace.as_deref(), call.function.as_str(), call.arguments.count))
+        .collect::<Vec<(Option<&str>, &str, usize)>>(),
+      vec![
+        (None, "class", 1),
+        (Some("xr_logic"), "activate", 2),
+        (None, "pairs", 1)
+      ]
+    );
+    assert_eq!(
+      symbols
+        .local_arity_mismatches
+        .iter()
+        .map(|mismatch| (
+          mismatch.line_number,
+          mismatch.function.as_str(),
+          mismatch.arguments.count
+        ))
+        .collect::<Vec<(usize, &str, usize)>>(),
+      vec![(8, "pick", 3)]
+    );
+    assert_eq!(
+      symbols.arity_of("on_update"),
+      Some(XRayLuaArity {
+        parameters: 2,
+        is_vararg: false
+      })
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn collects_fields_of_returned_module_table() -> XrfResult {
+    let script: XRayLuaScript = XRayLuaScript::parse(
+      Path::new("scripts/module.script"),
+      "local ____exports = {}\n____exports.VALUE = 1\nfunction ____exports.run(...) end\nreturn ____exports\n",
+    )?;
+
+    assert_eq!(script.symbols().exports().collect::<Vec<&str>>(), vec!["VALUE", "run"]);
+    assert!(
+      XRayLuaArity {
+        parameters: 0,
+        is_vararg: true
+      }
+      .accepts(&XRayLuaArguments {
+        count: 3,
+        is_open: false
+      })
+    );
+    assert!(
+      XRayLuaArity {
+        parameters: 3,
+        is_vararg: false
+      }
+      .accepts(&XRayLuaArguments {
+        count: 1,
+        is_open: false
+      })
+    );
+    assert!(
+      !XRayLuaArity {
+        parameters: 1,
+        is_vararg: false
+      }
+      .accepts(&XRayLuaArguments {
+        count: 3,
+        is_open: true
+      })
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,29 @@
+use full_moon::ast::Ast;
+use full_moon::tokenizer::Token;
+use full_moon::visitors::Visitor;
+
+use crate::lua_call_arguments::LuaCallArguments;
+
+pub(crate) struct LuaStringLiteralCollector {
+  string_literals: Vec<String>,
+}
+
+impl LuaStringLiteralCollector {
+  pub(crate) fn collect(ast: &Ast) -> Vec<String> {
+    let mut collector: Self = Self {
+      string_literals: Vec::new(),
+    };
+
+    collector.visit_ast(ast);
+
+    collector.string_literals
+  }
+}
+
+impl Visitor for LuaStringLiteralCollector {
+  fn visit_string_literal(&mut self, token: &Token) {
+    self
+      .string_literals
+      .extend(LuaCallArguments::literal_value(token.token_type()));
+  }
+}
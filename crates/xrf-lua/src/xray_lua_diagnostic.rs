@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// Kind of a problem found by static analysis of scripts.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum XRayLuaDiagnosticKind {
+  /// Read of a free name no script, engine export or Lua library defines.
+  UndefinedGlobal,
+  /// Call of `namespace.function` where no script or engine namespace has that name.
+  UnknownNamespace,
+  /// Call of `namespace.function` where the script file exists but does not define the function.
+  UnknownFunction,
+  /// Call of a function defined in the same script with more arguments than it has parameters.
+  CallArity,
+}
+
+/// A problem found by static analysis of scripts, located by script path and line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayLuaDiagnostic {
+  pub kind: XRayLuaDiagnosticKind,
+  pub path: PathBuf,
+  pub line_number: usize,
+  pub message: String,
+}
+
+impl Display for XRayLuaDiagnostic {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      formatter,
+      "{}:{}: {}",
+      self.path.display(),
+      self.line_number,
+      self.message
+    )
+  }
+}
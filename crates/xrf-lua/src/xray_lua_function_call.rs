@@ -0,0 +1,31 @@
+/// A call of a function named by a free name or a field path, such as `sound_object(...)` or `xr_sound.set_sound(...)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayLuaFunctionCall {
+  arguments: Vec<Option<String>>,
+  function: String,
+  line_number: usize,
+}
+
+impl XRayLuaFunctionCall {
+  pub(crate) fn from_parts(line_number: usize, function: String, arguments: Vec<Option<String>>) -> Self {
+    Self {
+      arguments,
+      function,
+      line_number,
+    }
+  }
+
+  /// Dotted path of the called function.
+  pub fn function(&self) -> &str {
+    &self.function
+  }
+
+  pub fn line_number(&self) -> usize {
+    self.line_number
+  }
+
+  /// Argument at an index, when it is a string literal.
+  pub fn literal_string_argument(&self, index: usize) -> Option<&str> {
+    self.arguments.get(index)?.as_deref()
+  }
+}
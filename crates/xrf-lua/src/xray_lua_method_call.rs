@@ -1,6 +1,7 @@
 /// A Lua method call with its receiver, method name, source line, and literal string arguments.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct XRayLuaMethodCall {
+  arguments: Vec<Option<String>>,
   line_number: usize,
   literal_string_arguments: Option<Vec<String>>,
   method: String,
@@ -12,11 +13,12 @@ impl XRayLuaMethodCall {
     line_number: usize,
     receiver: String,
     method: String,
-    literal_string_arguments: Option<Vec<String>>,
+    arguments: Vec<Option<String>>,
   ) -> Self {
     Self {
+      literal_string_arguments: arguments.iter().cloned().collect(),
+      arguments,
       line_number,
-      literal_string_arguments,
       method,
       receiver,
     }
@@ -26,10 +28,16 @@ impl XRayLuaMethodCall {
     self.line_number
   }
 
+  /// Arguments, when every one of them is a string literal.
   pub fn literal_string_arguments(&self) -> Option<&[String]> {
     self.literal_string_arguments.as_deref()
   }
 
+  /// Argument at an index, when it is a string literal.
+  pub fn literal_string_argument(&self, index: usize) -> Option<&str> {
+    self.arguments.get(index)?.as_deref()
+  }
+
   pub fn method(&self) -> &str {
     &self.method
   }
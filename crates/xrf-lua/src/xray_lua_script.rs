@@ -4,13 +4,21 @@ use full_moon::{LuaVersion, ast::Ast, parse_fallible};
 use xrf_error::{XrfError, XrfResult};
 
 use crate::lua_method_call_collector::LuaMethodCallCollector;
+use crate::lua_script_symbol_collector::LuaScriptSymbolCollector;
+use crate::lua_string_literal_collector::LuaStringLiteralCollector;
+use crate::xray_lua_function_call::XRayLuaFunctionCall;
 use crate::xray_lua_method_call::XRayLuaMethodCall;
+use crate::xray_lua_script_symbols::XRayLuaScriptSymbols;
 
-/// A parsed LuaJIT script with normalized method calls.
+/// A parsed LuaJIT script with normalized calls, string literals and global symbols.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct XRayLuaScript {
+  expression_method_calls: Vec<XRayLuaMethodCall>,
+  function_calls: Vec<XRayLuaFunctionCall>,
   method_calls: Vec<XRayLuaMethodCall>,
   path: PathBuf,
+  string_literals: Vec<String>,
+  symbols: XRayLuaScriptSymbols,
 }
 
 impl XRayLuaScript {
@@ -21,13 +29,40 @@ impl XRayLuaScript {
   {
     let path: &Path = path.as_ref();
     let ast: Ast = Self::parse_ast(path, source)?;
+    let calls: LuaMethodCallCollector = LuaMethodCallCollector::collect(&ast);
 
     Ok(Self {
-      method_calls: LuaMethodCallCollector::collect(&ast),
+      expression_method_calls: calls.expression_method_calls,
+      function_calls: calls.function_calls,
+      method_calls: calls.method_calls,
       path: path.to_path_buf(),
+      string_literals: LuaStringLiteralCollector::collect(&ast),
+      symbols: LuaScriptSymbolCollector::collect(&ast),
     })
   }
 
+  /// Calls of a function by its dotted path, such as `sound_object` or `xr_sound.set_sound`.
+  pub fn function_calls(&self, function: &str) -> Vec<&XRayLuaFunctionCall> {
+    self
+      .function_calls
+      .iter()
+      .filter(|call| call.function() == function)
+      .collect()
+  }
+
+  /// Calls of a method on any receiver, including calls later in a chain such as `alife():object(id):name()`.
+  ///
+  /// Receivers are the source text before the method without whitespace, such as `alife()` or `self.picture`, and
+  /// literal arguments have their escapes resolved.
+  pub fn expression_method_calls(&self, method: &str) -> Vec<&XRayLuaMethodCall> {
+    self
+      .expression_method_calls
+      .iter()
+      .filter(|call| call.method() == method)
+      .collect()
+  }
+
+  /// Calls of a method on a named receiver at the start of a call chain, with literal arguments as written.
   pub fn method_calls(&self, receiver: &str, method: &str) -> Vec<&XRayLuaMethodCall> {
     self
       .method_calls
@@ -40,6 +75,16 @@ impl XRayLuaScript {
     &self.path
   }
 
+  /// Values of every string literal in the script, in source order, with escapes resolved as at runtime.
+  pub fn string_literals(&self) -> &[String] {
+    &self.string_literals
+  }
+
+  /// Globals the script defines, reads and calls, for analysis across script namespaces.
+  pub fn symbols(&self) -> &XRayLuaScriptSymbols {
+    &self.symbols
+  }
+
   fn parse_ast(path: &Path, source: &str) -> XrfResult<Ast> {
     parse_fallible(source, LuaVersion::luajit())
       .into_result()
@@ -69,17 +114,80 @@ mod tests {
 shader:begin("vertex", "pixel")
 shader:begin(dynamic_vertex, "dynamic_pixel")
 other:begin("ignored_vertex", "ignored_pixel")
+shader:begin("effects\\wallmark", "simple")
+get_shader():begin("chained_vertex", "chained_pixel")
 "#,
     )?;
     let shader_begins: Vec<&_> = script.method_calls("shader", "begin");
 
     assert_eq!(script.path(), Path::new("script.s"));
-    assert_eq!(shader_begins.len(), 2);
+    assert_eq!(shader_begins.len(), 3);
     assert_eq!(
       shader_begins[0].literal_string_arguments(),
       Some([String::from("vertex"), String::from("pixel")].as_slice())
     );
     assert_eq!(shader_begins[1].literal_string_arguments(), None);
+    assert_eq!(shader_begins[1].literal_string_argument(1), Some("dynamic_pixel"));
+    assert_eq!(
+      shader_begins[2].literal_string_arguments(),
+      Some([String::from("effects\\\\wallmark"), String::from("simple")].as_slice())
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn collects_calls_on_expression_receivers_and_named_functions() -> XrfResult {
+    let script: XRayLuaScript = XRayLuaScript::parse(
+      Path::new("script.s"),
+      r#"
+-- spawn the reward
+alife():create("wpn_ak74", position, 0, 0)
+self.picture:InitTexture("ui\\ui_icon")
+local snd = sound_object([[ambient\wind]])
+xr_effects.give_info("quest_done")
+"#,
+    )?;
+    let creates: Vec<&_> = script.expression_method_calls("create");
+    let textures: Vec<&_> = script.expression_method_calls("InitTexture");
+
+    assert_eq!(creates.len(), 1);
+    assert_eq!(creates[0].receiver(), "alife()");
+    assert!(script.method_calls("alife()", "create").is_empty());
+    assert!(script.method_calls("self", "InitTexture").is_empty());
+    assert_eq!(creates[0].literal_string_argument(0), Some("wpn_ak74"));
+    assert_eq!(creates[0].literal_string_argument(1), None);
+    assert_eq!(textures[0].receiver(), "self.picture");
+    assert_eq!(textures[0].literal_string_argument(0), Some("ui\\ui_icon"));
+    assert_eq!(textures[0].line_number(), 4);
+    assert_eq!(
+      script.function_calls("sound_object")[0].literal_string_argument(0),
+      Some("ambient\\wind")
+    );
+    assert_eq!(
+      script.function_calls("xr_effects.give_info")[0].literal_string_argument(0),
+      Some("quest_done")
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn collects_string_literals_in_source_order() -> XrfResult {
+    let script: XRayLuaScript = XRayLuaScript::parse(
+      Path::new("script.s"),
+      r#"
+local section = "wpn_ak74"
+alife():create('stalker_default', position)
+local text = [[long\\path]]
+local icon = "ui\\ui_common"
+"#,
+    )?;
+
+    assert_eq!(
+      script.string_literals(),
+      ["wpn_ak74", "stalker_default", "long\\\\path", "ui\\ui_common"]
+    );
 
     Ok(())
   }
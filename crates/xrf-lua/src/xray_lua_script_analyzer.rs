@@ -0,0 +1,410 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::xray_lua_script_symbols::{XRayLuaArguments, XRayLuaArity, XRayLuaScriptSymbols};
+use crate::{XRayLuaDiagnostic, XRayLuaDiagnosticKind, XRayLuaScript};
+
+/// Globals of LuaJIT and of the luabind layer the engine embeds.
+const LUA_GLOBALS: &[&str] = &[
+  "_G",
+  "_VERSION",
+  "assert",
+  "bit",
+  "class",
+  "collectgarbage",
+  "coroutine",
+  "debug",
+  "dofile",
+  "error",
+  "ffi",
+  "gcinfo",
+  "getfenv",
+  "getmetatable",
+  "io",
+  "ipairs",
+  "jit",
+  "load",
+  "loadfile",
+  "loadstring",
+  "math",
+  "module",
+  "newproxy",
+  "next",
+  "os",
+  "package",
+  "pairs",
+  "pcall",
+  "print",
+  "rawequal",
+  "rawget",
+  "rawset",
+  "require",
+  "select",
+  "setfenv",
+  "setmetatable",
+  "string",
+  "super",
+  "table",
+  "tonumber",
+  "tostring",
+  "type",
+  "unpack",
+  "xpcall",
+];
+
+/// Script whose globals the engine loads into `_G`, so every script sees them without a namespace.
+const GLOBAL_SCRIPT_NAMESPACE: &str = "_g";
+
+#[derive(Clone, Debug)]
+struct XRayLuaNamespace {
+  path: PathBuf,
+  exports: HashSet<String>,
+}
+
+/// Checks runtime scripts against each other, the way X-Ray links them.
+///
+/// Every `scripts/*.script` file is a namespace named after the file, holding the globals the file assigns and loaded
+/// the first time another script reads that name. Engine exports come from `lua_help.script`: without them undefined
+/// globals and unknown namespaces cannot be told from engine API, so those two checks are skipped.
+#[derive(Clone, Debug, Default)]
+pub struct XRayLuaScriptAnalyzer {
+  namespaces: HashMap<String, XRayLuaNamespace>,
+  scripts: Vec<(PathBuf, XRayLuaScriptSymbols)>,
+  shared_globals: HashSet<String>,
+  engine_globals: Option<HashSet<String>>,
+}
+
+impl XRayLuaScriptAnalyzer {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Register a script, its namespace being the file name without extension.
+  pub fn add_script(&mut self, script: &XRayLuaScript) {
+    let symbols: &XRayLuaScriptSymbols = script.symbols();
+
+    if let Some(namespace) = Self::namespace_of(script.path()) {
+      self
+        .namespaces
+        .entry(namespace)
+        .or_insert_with(|| XRayLuaNamespace {
+          path: script.path().to_path_buf(),
+          exports: HashSet::new(),
+        })
+        .exports
+        .extend(symbols.exports().map(String::from));
+    }
+
+    self.shared_globals.extend(symbols.shared_globals.iter().cloned());
+    self.scripts.push((script.path().to_path_buf(), symbols.clone()));
+  }
+
+  /// Declare engine exports, enabling undefined global and unknown namespace checks.
+  pub fn add_engine_globals<I, S>(&mut self, names: I)
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    self
+      .engine_globals
+      .get_or_insert_with(HashSet::new)
+      .extend(names.into_iter().map(Into::into));
+  }
+
+  /// Top-level names declared by the engine API listing `lua_help.script`: classes, functions and namespaces.
+  pub fn engine_globals_from_lua_help(source: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut depth: usize = 0;
+
+    for line in source.lines() {
+      let trimmed: &str = line.trim();
+
+      if depth == 0 {
+        let declaration: Option<&str> = trimmed
+          .strip_prefix("C++ class ")
+          .or_else(|| trimmed.strip_prefix("class "))
+          .or_else(|| trimmed.strip_prefix("namespace "))
+          .or_else(|| trimmed.strip_prefix("function "));
+
+        if let Some(name) = declaration.and_then(|rest| {
+          rest
+            .trim_start()
+            .split(|character: char| !(character.is_ascii_alphanumeric() || character == '_'))
+            .next()
+            .filter(|name| !name.is_empty())
+        }) {
+          names.push(String::from(name));
+        }
+      }
+
+      for character in trimmed.chars() {
+        match character {
+          '{' => depth += 1,
+          '}' => depth = depth.saturating_sub(1),
+          _ => {}
+        }
+      }
+    }
+
+    names
+  }
+
+  /// Check every registered script, returning diagnostics ordered by path and line.
+  pub fn analyze(&self) -> Vec<XRayLuaDiagnostic> {
+    let mut diagnostics: Vec<XRayLuaDiagnostic> = Vec::new();
+
+    for (path, symbols) in &self.scripts {
+      self.analyze_script(path, symbols, &mut diagnostics);
+    }
+
+    diagnostics.sort_by(|left, right| {
+      (&left.path, left.line_number, &left.message).cmp(&(&right.path, right.line_number, &right.message))
+    });
+
+    diagnostics
+  }
+
+  fn analyze_script(&self, path: &Path, symbols: &XRayLuaScriptSymbols, diagnostics: &mut Vec<XRayLuaDiagnostic>) {
+    let own: HashSet<&str> = symbols.exports().collect();
+    let mut reported: HashSet<&str> = HashSet::new();
+
+    for (name, line_number) in &symbols.global_reads {
+      if own.contains(name.as_str())
+        || self.is_global(name) != Some(false)
+        || self.namespace(name).is_some()
+        || !reported.insert(name)
+      {
+        continue;
+      }
+
+      diagnostics.push(XRayLuaDiagnostic {
+        kind: XRayLuaDiagnosticKind::UndefinedGlobal,
+        path: path.to_path_buf(),
+        line_number: *line_number,
+        message: format!("Read of undefined global `{name}`"),
+      });
+    }
+
+    for call in &symbols.global_calls {
+      match &call.namespace {
+        None => {
+          if let Some(arity) = symbols.arity_of(&call.function)
+            && !arity.accepts(&call.arguments)
+          {
+            diagnostics.push(Self::arity_diagnostic(
+              path,
+              call.line_number,
+              &call.function,
+              arity,
+              call.arguments,
+            ));
+          }
+        }
+        Some(namespace) => {
+          if own.contains(namespace.as_str()) || self.is_global(namespace) != Some(false) {
+            continue;
+          }
+
+          match self.namespace(namespace) {
+            Some(target) if !target.exports.contains(&call.function) => diagnostics.push(XRayLuaDiagnostic {
+              kind: XRayLuaDiagnosticKind::UnknownFunction,
+              path: path.to_path_buf(),
+              line_number: call.line_number,
+              message: format!(
+                "Call of `{namespace}.{}`, which {} does not define",
+                call.function,
+                target.path.display()
+              ),
+            }),
+            Some(_) => {}
+            None => diagnostics.push(XRayLuaDiagnostic {
+              kind: XRayLuaDiagnosticKind::UnknownNamespace,
+              path: path.to_path_buf(),
+              line_number: call.line_number,
+              message: format!(
+                "Call of `{namespace}.{}`, but no script or engine namespace `{namespace}` exists",
+                call.function
+              ),
+            }),
+          }
+        }
+      }
+    }
+
+    for mismatch in &symbols.local_arity_mismatches {
+      diagnostics.push(Self::arity_diagnostic(
+        path,
+        mismatch.line_number,
+        &mismatch.function,
+        mismatch.arity,
+        mismatch.arguments,
+      ));
+    }
+  }
+
+  /// Whether a function named by game data, such as a dialog action `dialogs.break_dialog`, exists.
+  ///
+  /// `None` when that depends on engine exports which are not known. Functions of engine namespaces are not listed by
+  /// `lua_help.script` at depth one, so any of them is assumed to exist.
+  pub fn resolves_function(&self, path: &str) -> Option<bool> {
+    let Some((namespace, function)) = path.split_once('.') else {
+      return self.is_global(path);
+    };
+
+    match self.namespace(namespace) {
+      Some(target) => Some(target.exports.contains(function)),
+      None => self.is_global(namespace),
+    }
+  }
+
+  /// Whether a name is global for every script: `None` when engine exports are unknown and it is not a Lua global.
+  fn is_global(&self, name: &str) -> Option<bool> {
+    if LUA_GLOBALS.contains(&name)
+      || self.shared_globals.contains(name)
+      || self
+        .namespace(GLOBAL_SCRIPT_NAMESPACE)
+        .is_some_and(|namespace| namespace.exports.contains(name))
+    {
+      return Some(true);
+    }
+
+    self.engine_globals.as_ref().map(|globals| globals.contains(name))
+  }
+
+  /// Script namespace of a name, matched case-insensitively the way the engine finds files.
+  fn namespace(&self, name: &str) -> Option<&XRayLuaNamespace> {
+    self.namespaces.get(&name.to_ascii_lowercase())
+  }
+
+  fn namespace_of(path: &Path) -> Option<String> {
+    let file_name: &str = path.to_str()?.rsplit(['/', '\\']).next()?;
+    let stem: &str = file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem);
+
+    Some(stem.to_ascii_lowercase())
+  }
+
+  fn arity_diagnostic(
+    path: &Path,
+    line_number: usize,
+    function: &str,
+    arity: XRayLuaArity,
+    arguments: XRayLuaArguments,
+  ) -> XRayLuaDiagnostic {
+    XRayLuaDiagnostic {
+      kind: XRayLuaDiagnosticKind::CallArity,
+      path: path.to_path_buf(),
+      line_number,
+      message: format!(
+        "Call of `{function}` with {}{} arguments, but it declares {}{} parameters",
+        arguments.count,
+        if arguments.is_open { " or more" } else { "" },
+        arity.parameters,
+        if arity.is_vararg { " and varargs" } else { "" }
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use xrf_error::XrfResult;
+
+  use crate::{XRayLuaDiagnosticKind, XRayLuaScript, XRayLuaScriptAnalyzer};
+
+  fn analyzer(scripts: &[(&str, &str)]) -> XrfResult<XRayLuaScriptAnalyzer> {
+    let mut analyzer: XRayLuaScriptAnalyzer = XRayLuaScriptAnalyzer::new();
+
+    for (path, source) in scripts {
+      analyzer.add_script(&XRayLuaScript::parse(Path::new(path), source)?);
+    }
+
+    Ok(analyzer)
+  }
+
+  #[test]
+  fn reports_cross_namespace_problems() -> XrfResult {
+    let mut analyzer: XRayLuaScriptAnalyzer = analyzer(&[
+      ("scripts/_g.script", "function printf(fmt, ...) end\n"),
+      (
+        "scripts/xr_logic.script",
+        "function activate(object, section) end\nfunction helper() return missing_engine_call() end\n",
+      ),
+      (
+        "scripts/bind_stalker.script",
+        "function update(object)\n  printf(\"%s\", object)\n  xr_logic.activate(object, \"logic\")\n\
+           xr_logic.deactivate(object)\n  xr_logc.activate(object)\n  level.object_by_id(1)\n  update(object, 2)\n\
+           Xr_Logic.helper()\nend\n",
+      ),
+    ])?;
+    let engine: Vec<String> = XRayLuaScriptAnalyzer::engine_globals_from_lua_help(
+      "C++ class game_object {\n  function id();\n};\n\nnamespace level {\n  function object_by_id(number);\n};\nfunction time_global();\n",
+    );
+
+    assert_eq!(engine, vec!["game_object", "level", "time_global"]);
+
+    analyzer.add_engine_globals(engine);
+
+    let diagnostics: Vec<(XRayLuaDiagnosticKind, String, usize)> = analyzer
+      .analyze()
+      .into_iter()
+      .map(|diagnostic| {
+        (
+          diagnostic.kind,
+          diagnostic.path.display().to_string(),
+          diagnostic.line_number,
+        )
+      })
+      .collect();
+
+    assert_eq!(
+      diagnostics,
+      vec![
+        (
+          XRayLuaDiagnosticKind::UnknownFunction,
+          String::from("scripts/bind_stalker.script"),
+          4
+        ),
+        (
+          XRayLuaDiagnosticKind::UnknownNamespace,
+          String::from("scripts/bind_stalker.script"),
+          5
+        ),
+        (
+          XRayLuaDiagnosticKind::CallArity,
+          String::from("scripts/bind_stalker.script"),
+          7
+        ),
+        (
+          XRayLuaDiagnosticKind::UndefinedGlobal,
+          String::from("scripts/xr_logic.script"),
+          2
+        ),
+      ]
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn skips_engine_dependent_checks_without_lua_help() -> XrfResult {
+    let analyzer: XRayLuaScriptAnalyzer = analyzer(&[(
+      "scripts/sample.script",
+      "local function run(a) end\nfunction start()\n  run(1, 2)\n  level.object_by_id(unknown)\nend\n",
+    )])?;
+
+    let diagnostics: Vec<(XRayLuaDiagnosticKind, usize)> = analyzer
+      .analyze()
+      .iter()
+      .map(|diagnostic| (diagnostic.kind, diagnostic.line_number))
+      .collect();
+
+    assert_eq!(diagnostics, vec![(XRayLuaDiagnosticKind::CallArity, 3)]);
+    assert_eq!(analyzer.resolves_function("sample.start"), Some(true));
+    assert_eq!(analyzer.resolves_function("sample.stop"), Some(false));
+    assert_eq!(analyzer.resolves_function("level.object_by_id"), None);
+
+    Ok(())
+  }
+}
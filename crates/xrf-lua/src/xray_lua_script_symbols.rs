@@ -0,0 +1,96 @@
+/// Parameters a Lua function declares.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct XRayLuaArity {
+  pub parameters: usize,
+  pub is_vararg: bool,
+}
+
+/// Arguments a Lua call passes.
+///
+/// A call or `...` in the last position may expand to any number of values, so the count is only a lower bound then.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct XRayLuaArguments {
+  pub count: usize,
+  pub is_open: bool,
+}
+
+impl XRayLuaArity {
+  /// Whether a call passes no more values than the function names, extra values being silently dropped.
+  ///
+  /// Fewer values are accepted: missing ones read as `nil`, which is how scripts leave optional trailing
+  /// parameters out.
+  pub fn accepts(&self, arguments: &XRayLuaArguments) -> bool {
+    let minimum: usize = if arguments.is_open {
+      arguments.count.saturating_sub(1)
+    } else {
+      arguments.count
+    };
+
+    self.is_vararg || minimum <= self.parameters
+  }
+}
+
+/// A global assigned by a script, which X-Ray stores in the namespace of the script file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayLuaGlobalDefinition {
+  pub name: String,
+  pub line_number: usize,
+  /// Set when the assigned value is a function.
+  pub arity: Option<XRayLuaArity>,
+}
+
+/// A call of a free name, either `name(...)` or `namespace.name(...)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayLuaGlobalCall {
+  pub line_number: usize,
+  pub namespace: Option<String>,
+  pub function: String,
+  pub arguments: XRayLuaArguments,
+}
+
+/// A call of a local function with more arguments than its parameters take.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayLuaArityMismatch {
+  pub line_number: usize,
+  pub function: String,
+  pub arity: XRayLuaArity,
+  pub arguments: XRayLuaArguments,
+}
+
+/// Names a script defines and uses beyond its own locals, for checks across script namespaces.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XRayLuaScriptSymbols {
+  /// Globals assigned anywhere in the script, including `class "name"` declarations.
+  pub definitions: Vec<XRayLuaGlobalDefinition>,
+  /// Fields of `_G` assigned by the script, visible to every script.
+  pub shared_globals: Vec<String>,
+  /// Fields of the local table the script returns, for modules written as `local M = {} ... return M`.
+  pub exported_fields: Vec<String>,
+  /// Free names read by the script, with the line of each read.
+  pub global_reads: Vec<(String, usize)>,
+  pub global_calls: Vec<XRayLuaGlobalCall>,
+  pub local_arity_mismatches: Vec<XRayLuaArityMismatch>,
+}
+
+impl XRayLuaScriptSymbols {
+  /// Arity of a global function, when every assignment of the name is a function with the same parameters.
+  pub fn arity_of(&self, name: &str) -> Option<XRayLuaArity> {
+    let mut arities = self
+      .definitions
+      .iter()
+      .filter(|definition| definition.name == name)
+      .map(|definition| definition.arity);
+    let first: XRayLuaArity = arities.next()??;
+
+    arities.all(|arity| arity == Some(first)).then_some(first)
+  }
+
+  /// Names the namespace of this script exposes to other scripts.
+  pub fn exports(&self) -> impl Iterator<Item = &str> {
+    self
+      .definitions
+      .iter()
+      .map(|definition| definition.name.as_str())
+      .chain(self.exported_fields.iter().map(String::as_str))
+  }
+}
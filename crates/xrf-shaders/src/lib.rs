@@ -2,6 +2,16 @@ mod shader_renderer;
 mod shader_source_config;
 mod xray_shader;
 mod xray_shader_compiler;
+mod xray_shader_diagnostic;
+mod xray_shader_expanded_source;
+mod xray_shader_glsl_compiler;
+mod xray_shader_hlsl_analyzer;
+mod xray_shader_hlsl_ast;
+mod xray_shader_hlsl_compiler;
+mod xray_shader_hlsl_issue;
+mod xray_shader_hlsl_parser;
+mod xray_shader_hlsl_preprocessor;
+mod xray_shader_hlsl_token;
 mod xray_shader_import;
 mod xray_shader_import_reference;
 mod xray_shader_pass;
@@ -12,6 +22,10 @@ pub use shader_renderer::*;
 pub use shader_source_config::*;
 pub use xray_shader::*;
 pub use xray_shader_compiler::*;
+pub use xray_shader_diagnostic::*;
+pub use xray_shader_expanded_source::*;
+pub use xray_shader_glsl_compiler::*;
+pub use xray_shader_hlsl_compiler::*;
 pub use xray_shader_import::*;
 pub use xray_shader_pass::*;
 pub use xray_shader_script::*;
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+use crate::XRayShaderSourceLocation;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum XRayShaderDiagnosticSeverity {
+  Error,
+  Warning,
+}
+
+/// A problem found by static shader analysis, positioned in the file that wrote it.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct XRayShaderDiagnostic {
+  pub severity: XRayShaderDiagnosticSeverity,
+  pub location: Option<XRayShaderSourceLocation>,
+  pub column: usize,
+  pub message: String,
+}
+
+impl XRayShaderDiagnostic {
+  pub fn is_error(&self) -> bool {
+    self.severity == XRayShaderDiagnosticSeverity::Error
+  }
+}
+
+impl Display for XRayShaderDiagnostic {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    let severity: &str = match self.severity {
+      XRayShaderDiagnosticSeverity::Error => "error",
+      XRayShaderDiagnosticSeverity::Warning => "warning",
+    };
+
+    match &self.location {
+      Some(location) => write!(
+        formatter,
+        "{}:{}:{}: {severity}: {}",
+        location.path.display(),
+        location.line_number,
+        self.column,
+        self.message
+      ),
+      None => write!(formatter, "<generated>: {severity}: {}", self.message),
+    }
+  }
+}
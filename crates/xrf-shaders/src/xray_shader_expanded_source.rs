@@ -0,0 +1,165 @@
+use std::path::{Path, PathBuf};
+
+use crate::XRayShader;
+
+/// Where one line of an expanded shader source was written.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct XRayShaderSourceLocation {
+  pub path: PathBuf,
+  pub line_number: usize,
+}
+
+/// A resolved shader flattened into one source, the way a preprocessor sees it after `#include` expansion.
+///
+/// Compilers report positions in the text they were given, so every line keeps track of the file and line it came from.
+/// Lines a compiler backend adds itself, such as a `#version` preamble, map to no location.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct XRayShaderExpandedSource {
+  lines: Vec<String>,
+  locations: Vec<Option<XRayShaderSourceLocation>>,
+}
+
+impl XRayShaderExpandedSource {
+  /// Expand a shader, replacing each include line by the expanded source it imports.
+  ///
+  /// Every import is expanded where it is written, including repeated ones: include guards are the sources' business, the
+  /// same as for the engine.
+  pub fn expand(shader: &XRayShader) -> Self {
+    let mut expanded: Self = Self::default();
+
+    expanded.push_shader(shader);
+
+    expanded
+  }
+
+  /// Add a line that no shader file holds.
+  pub fn push_generated_line(&mut self, line: &str) {
+    self.lines.push(String::from(line));
+    self.locations.push(None);
+  }
+
+  /// Add a line written in a shader file.
+  pub fn push_line(&mut self, line: &str, path: &Path, line_number: usize) {
+    self.lines.push(String::from(line));
+    self.locations.push(Some(XRayShaderSourceLocation {
+      path: path.to_path_buf(),
+      line_number,
+    }));
+  }
+
+  /// Replace every line a predicate matches by an empty one, keeping line numbers in place.
+  pub fn blank_lines<F>(&mut self, predicate: F)
+  where
+    F: Fn(&str) -> bool,
+  {
+    for line in &mut self.lines {
+      if predicate(line) {
+        line.clear();
+      }
+    }
+  }
+
+  /// Location of a 1-based line of the expanded text.
+  pub fn location(&self, line_number: usize) -> Option<&XRayShaderSourceLocation> {
+    line_number
+      .checked_sub(1)
+      .and_then(|index| self.locations.get(index))
+      .and_then(Option::as_ref)
+  }
+
+  /// Describe a 1-based line and column of the expanded text as a position in the original sources.
+  pub fn describe_position(&self, line_number: usize, column: usize) -> String {
+    match self.location(line_number) {
+      Some(location) => format!("{}:{}:{column}", location.path.display(), location.line_number),
+      None => format!("<generated>:{line_number}:{column}"),
+    }
+  }
+
+  /// The expanded text.
+  pub fn text(&self) -> String {
+    let mut text: String = self.lines.join("\n");
+
+    text.push('\n');
+
+    text
+  }
+
+  /// Add a shader with its imports expanded in place.
+  pub fn push_shader(&mut self, shader: &XRayShader) {
+    let source: String = String::from_utf8_lossy(shader.source()).into_owned();
+
+    for (index, line) in source.lines().enumerate() {
+      let line_number: usize = index + 1;
+      let mut is_include_line: bool = false;
+
+      for import in shader
+        .imports()
+        .iter()
+        .filter(|import| import.line_number() == line_number)
+      {
+        is_include_line = true;
+        self.push_shader(import.shader());
+      }
+
+      if !is_include_line {
+        self.push_line(line, shader.path(), line_number);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::path::{Path, PathBuf};
+
+  use xrf_error::XrfResult;
+
+  use crate::xray_shader_expanded_source::XRayShaderExpandedSource;
+  use crate::{ShaderRenderer, XRayShader, XRayShaderSourceLoader};
+
+  struct TestSourceLoader {
+    sources: HashMap<PathBuf, Vec<u8>>,
+  }
+
+  impl XRayShaderSourceLoader for TestSourceLoader {
+    fn load_source(&self, path: &Path) -> XrfResult<Option<Vec<u8>>> {
+      Ok(self.sources.get(path).cloned())
+    }
+  }
+
+  #[test]
+  fn expands_includes_and_maps_lines_to_their_files() -> XrfResult {
+    let root: &Path = Path::new("shaders");
+    let main_path: PathBuf = root.join("gl/main.ps");
+    let common_path: PathBuf = root.join("gl/common.h");
+    let loader: TestSourceLoader = TestSourceLoader {
+      sources: HashMap::from([
+        (
+          main_path.clone(),
+          b"// main\n#include \"common.h\"\nvoid main() {}\n".to_vec(),
+        ),
+        (common_path.clone(), b"uniform float a;\nuniform float b;\n".to_vec()),
+      ]),
+    };
+
+    let shader: XRayShader = XRayShader::load(&main_path, ShaderRenderer::OpenGl, root, &loader)?;
+    let mut expanded: XRayShaderExpandedSource = XRayShaderExpandedSource::default();
+
+    expanded.push_generated_line("#version 450 core");
+    expanded.push_shader(&shader);
+
+    assert_eq!(
+      expanded.text(),
+      "#version 450 core\n// main\nuniform float a;\nuniform float b;\nvoid main() {}\n"
+    );
+    assert_eq!(expanded.location(1), None);
+    assert_eq!(
+      expanded.describe_position(4, 3),
+      format!("{}:2:3", common_path.display())
+    );
+    assert_eq!(expanded.describe_position(5, 1), format!("{}:3:1", main_path.display()));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,207 @@
+use std::path::Path;
+
+use naga::front::glsl::{Frontend, Options, ParseErrors};
+use naga::valid::{Capabilities, ValidationFlags, Validator};
+use naga::{Module, ShaderStage, SourceLocation};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::xray_shader_expanded_source::XRayShaderExpandedSource;
+use crate::{ShaderRenderer, XRayShader, XRayShaderCompiler};
+
+/// GLSL version the sources are validated as: the oldest one the validator accepts.
+const GLSL_VERSION_DIRECTIVE: &str = "#version 450 core";
+
+/// Validates OpenGL renderer shaders with `naga`, without a GPU driver or the game.
+///
+/// The import tree is flattened into one source, the way the engine hands it to the driver, so reported positions are
+/// mapped back to the file and line that wrote them. Sources declare no `#version` of their own in the engine, and one they
+/// do declare is replaced, since `naga` accepts fewer versions than drivers do.
+///
+/// Headers are not compiled on their own: they are checked through the stage sources that include them.
+#[derive(Clone, Debug, Default)]
+pub struct XRayShaderGlslCompiler {
+  defines: Vec<(String, String)>,
+}
+
+impl XRayShaderGlslCompiler {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Define a preprocessor macro, as the engine does for render options such as shadow map size.
+  pub fn with_define<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+    self.defines.push((name.into(), value.into()));
+    self
+  }
+
+  /// Pipeline stage of a source by its extension, `None` for headers.
+  fn stage(path: &Path) -> XrfResult<Option<ShaderStage>> {
+    let extension: String = path
+      .extension()
+      .and_then(|value| value.to_str())
+      .map(|value| value.to_ascii_lowercase())
+      .unwrap_or_default();
+
+    match extension.as_str() {
+      "vs" => Ok(Some(ShaderStage::Vertex)),
+      "ps" => Ok(Some(ShaderStage::Fragment)),
+      "cs" => Ok(Some(ShaderStage::Compute)),
+      "h" => Ok(None),
+      _ => Err(XrfError::new_not_implemented_error(format!(
+        "OpenGL shader {} has a stage the GLSL validator does not support",
+        path.display()
+      ))),
+    }
+  }
+
+  fn parse(&self, stage: ShaderStage, source: &XRayShaderExpandedSource, text: &str) -> XrfResult<Module> {
+    let mut options: Options = Options::from(stage);
+
+    options.defines.extend(self.defines.iter().cloned());
+
+    Frontend::default()
+      .parse(&options, text)
+      .map_err(|errors: ParseErrors| {
+        XrfError::new_parsing_error(
+          errors
+            .errors
+            .iter()
+            .map(|error| Self::describe(source, error.location(text), &error.kind.to_string()))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        )
+      })
+  }
+
+  fn validate(source: &XRayShaderExpandedSource, text: &str, module: &Module) -> XrfResult {
+    Validator::new(ValidationFlags::all(), Capabilities::all())
+      .validate(module)
+      .map(|_| ())
+      .map_err(|error| {
+        XrfError::new_parsing_error(Self::describe(
+          source,
+          error.location(text),
+          &format!("{}", error.as_inner()),
+        ))
+      })
+  }
+
+  fn describe(source: &XRayShaderExpandedSource, location: Option<SourceLocation>, message: &str) -> String {
+    match location {
+      Some(location) => format!(
+        "{}: {message}",
+        source.describe_position(location.line_number as usize, location.line_position as usize)
+      ),
+      None => String::from(message),
+    }
+  }
+}
+
+impl XRayShaderCompiler for XRayShaderGlslCompiler {
+  fn compile(&self, shader: &XRayShader, renderer: ShaderRenderer) -> XrfResult {
+    if renderer != ShaderRenderer::OpenGl {
+      return Err(XrfError::new_invalid_error(format!(
+        "GLSL compiler cannot compile {} shaders",
+        renderer.display_name()
+      )));
+    }
+
+    let Some(stage) = Self::stage(shader.path())? else {
+      return Ok(());
+    };
+
+    let mut source: XRayShaderExpandedSource = XRayShaderExpandedSource::default();
+
+    source.push_generated_line(GLSL_VERSION_DIRECTIVE);
+    source.push_shader(shader);
+    source.blank_lines(|line| line.trim_start().starts_with("#version"));
+
+    let text: String = source.text();
+    let module: Module = self.parse(stage, &source, &text)?;
+
+    Self::validate(&source, &text, &module)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::path::{Path, PathBuf};
+
+  use xrf_error::{XrfError, XrfResult};
+
+  use crate::{ShaderRenderer, XRayShader, XRayShaderCompiler, XRayShaderGlslCompiler, XRayShaderSourceLoader};
+
+  struct TestSourceLoader {
+    sources: HashMap<PathBuf, Vec<u8>>,
+  }
+
+  impl XRayShaderSourceLoader for TestSourceLoader {
+    fn load_source(&self, path: &Path) -> XrfResult<Option<Vec<u8>>> {
+      Ok(self.sources.get(path).cloned())
+    }
+  }
+
+  fn load(main: &str, common: &str) -> XrfResult<XRayShader> {
+    let root: &Path = Path::new("shaders");
+    let loader: TestSourceLoader = TestSourceLoader {
+      sources: HashMap::from([
+        (root.join("gl/main.ps"), main.as_bytes().to_vec()),
+        (root.join("gl/common.h"), common.as_bytes().to_vec()),
+      ]),
+    };
+
+    XRayShader::load(root.join("gl/main.ps"), ShaderRenderer::OpenGl, root, &loader)
+  }
+
+  #[test]
+  fn compiles_valid_fragment_shader_with_defines() -> XrfResult {
+    let shader: XRayShader = load(
+      "#version 410\n#include \"common.h\"\nlayout(location = 0) out vec4 color;\nvoid main() {\n  color = tint * SCALE;\n}\n",
+      "const vec4 tint = vec4(1.0);\n",
+    )?;
+
+    XRayShaderGlslCompiler::new()
+      .with_define("SCALE", "0.5")
+      .compile(&shader, ShaderRenderer::OpenGl)
+  }
+
+  #[test]
+  fn maps_errors_to_included_file_and_line() -> XrfResult {
+    let shader: XRayShader = load(
+      "#include \"common.h\"\nlayout(location = 0) out vec4 color;\nvoid main() {\n  color = tint;\n}\n",
+      "const vec4 tint = vec4(1.0);\nconst vec4 broken = undefined_value;\n",
+    )?;
+
+    let error: XrfError = XRayShaderGlslCompiler::new()
+      .compile(&shader, ShaderRenderer::OpenGl)
+      .expect_err("undefined identifier is reported");
+
+    assert!(
+      error
+        .to_string()
+        .contains(&format!("{}:2:", Path::new("shaders").join("gl/common.h").display())),
+      "{error}"
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn skips_headers_and_rejects_other_renderers() -> XrfResult {
+    let root: &Path = Path::new("shaders");
+    let loader: TestSourceLoader = TestSourceLoader {
+      sources: HashMap::from([(root.join("gl/common.h"), b"not glsl at all".to_vec())]),
+    };
+    let header: XRayShader = XRayShader::load(root.join("gl/common.h"), ShaderRenderer::OpenGl, root, &loader)?;
+
+    XRayShaderGlslCompiler::new().compile(&header, ShaderRenderer::OpenGl)?;
+
+    assert!(matches!(
+      XRayShaderGlslCompiler::new().compile(&header, ShaderRenderer::DirectX11),
+      Err(XrfError::Invalid { .. })
+    ));
+
+    Ok(())
+  }
+}
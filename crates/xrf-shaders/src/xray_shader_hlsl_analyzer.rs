@@ -0,0 +1,492 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::xray_shader_hlsl_ast::{
+  XRayShaderHlslDeclaration, XRayShaderHlslExpression, XRayShaderHlslStatement, XRayShaderHlslType,
+  XRayShaderHlslTypeArgument, XRayShaderHlslVariable,
+};
+use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+use crate::xray_shader_hlsl_parser::XRayShaderHlslParser;
+use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+/// Shader model 5 intrinsic functions, plus the legacy `tex*` ones `fxc` still accepts.
+const INTRINSICS: &[&str] = &[
+  "abort",
+  "abs",
+  "acos",
+  "all",
+  "AllMemoryBarrier",
+  "AllMemoryBarrierWithGroupSync",
+  "any",
+  "asdouble",
+  "asfloat",
+  "asin",
+  "asint",
+  "asuint",
+  "atan",
+  "atan2",
+  "ceil",
+  "clamp",
+  "clip",
+  "cos",
+  "cosh",
+  "countbits",
+  "cross",
+  "D3DCOLORtoUBYTE4",
+  "ddx",
+  "ddx_coarse",
+  "ddx_fine",
+  "ddy",
+  "ddy_coarse",
+  "ddy_fine",
+  "degrees",
+  "determinant",
+  "DeviceMemoryBarrier",
+  "DeviceMemoryBarrierWithGroupSync",
+  "distance",
+  "dot",
+  "dst",
+  "errorf",
+  "EvaluateAttributeAtCentroid",
+  "EvaluateAttributeAtSample",
+  "EvaluateAttributeSnapped",
+  "exp",
+  "exp2",
+  "f16tof32",
+  "f32tof16",
+  "faceforward",
+  "firstbithigh",
+  "firstbitlow",
+  "floor",
+  "fma",
+  "fmod",
+  "frac",
+  "frexp",
+  "fwidth",
+  "GetRenderTargetSampleCount",
+  "GetRenderTargetSamplePosition",
+  "GroupMemoryBarrier",
+  "GroupMemoryBarrierWithGroupSync",
+  "InterlockedAdd",
+  "InterlockedAnd",
+  "InterlockedCompareExchange",
+  "InterlockedCompareStore",
+  "InterlockedExchange",
+  "InterlockedMax",
+  "InterlockedMin",
+  "InterlockedOr",
+  "InterlockedXor",
+  "isfinite",
+  "isinf",
+  "isnan",
+  "ldexp",
+  "length",
+  "lerp",
+  "lit",
+  "log",
+  "log10",
+  "log2",
+  "mad",
+  "max",
+  "min",
+  "modf",
+  "msad4",
+  "mul",
+  "noise",
+  "normalize",
+  "pow",
+  "printf",
+  "Process2DQuadTessFactorsAvg",
+  "Process2DQuadTessFactorsMax",
+  "Process2DQuadTessFactorsMin",
+  "ProcessIsolineTessFactors",
+  "ProcessQuadTessFactorsAvg",
+  "ProcessQuadTessFactorsMax",
+  "ProcessQuadTessFactorsMin",
+  "ProcessTriTessFactorsAvg",
+  "ProcessTriTessFactorsMax",
+  "ProcessTriTessFactorsMin",
+  "radians",
+  "rcp",
+  "reflect",
+  "refract",
+  "reversebits",
+  "round",
+  "rsqrt",
+  "saturate",
+  "sign",
+  "sin",
+  "sincos",
+  "sinh",
+  "smoothstep",
+  "sqrt",
+  "step",
+  "tan",
+  "tanh",
+  "tex1D",
+  "tex1Dbias",
+  "tex1Dgrad",
+  "tex1Dlod",
+  "tex1Dproj",
+  "tex2D",
+  "tex2Dbias",
+  "tex2Dgrad",
+  "tex2Dlod",
+  "tex2Dproj",
+  "tex3D",
+  "tex3Dbias",
+  "tex3Dgrad",
+  "tex3Dlod",
+  "tex3Dproj",
+  "texCUBE",
+  "texCUBEbias",
+  "texCUBEgrad",
+  "texCUBElod",
+  "texCUBEproj",
+  "transpose",
+  "trunc",
+];
+
+const CONSTANTS: [&str; 2] = ["true", "false"];
+
+/// Result of resolving one preprocessed translation unit.
+#[derive(Clone, Debug, Default)]
+pub struct XRayShaderHlslAnalysis {
+  pub issues: Vec<XRayShaderHlslIssue>,
+  /// Global textures and samplers, with whether any code refers to them.
+  pub resources: Vec<(XRayShaderHlslToken, bool)>,
+}
+
+/// Resolves names of a parsed translation unit in declaration order, as HLSL requires.
+///
+/// Members and swizzles are not checked: that needs type inference, and the engine sources rely on implicit vector
+/// conversions too much for a partial one to be useful.
+#[derive(Default)]
+pub struct XRayShaderHlslAnalyzer {
+  globals: HashSet<String>,
+  scopes: Vec<HashSet<String>>,
+  types: HashSet<String>,
+  resources: HashMap<String, usize>,
+  analysis: XRayShaderHlslAnalysis,
+}
+
+impl XRayShaderHlslAnalyzer {
+  pub fn analyze(declarations: &[XRayShaderHlslDeclaration]) -> XRayShaderHlslAnalysis {
+    let mut analyzer: Self = Self::default();
+
+    for declaration in declarations {
+      analyzer.declaration(declaration);
+    }
+
+    analyzer.analysis
+  }
+
+  fn declaration(&mut self, declaration: &XRayShaderHlslDeclaration) {
+    match declaration {
+      XRayShaderHlslDeclaration::Struct { name, members } => {
+        self.types.insert(name.text.clone());
+
+        for member in members {
+          self.ty(&member.ty);
+          self.dimensions(member);
+        }
+      }
+      XRayShaderHlslDeclaration::Buffer { members, .. } => {
+        for member in members {
+          self.variable(member);
+        }
+      }
+      XRayShaderHlslDeclaration::Typedef { ty, name } => {
+        self.ty(ty);
+        self.types.insert(name.text.clone());
+      }
+      XRayShaderHlslDeclaration::Variables(variables) => {
+        for variable in variables {
+          self.variable(variable);
+        }
+      }
+      XRayShaderHlslDeclaration::Function(function) => {
+        self.ty(&function.return_type);
+        self.globals.insert(function.name.text.clone());
+        self.scopes.push(HashSet::new());
+
+        for parameter in &function.parameters {
+          self.variable(parameter);
+        }
+
+        for statement in function.body.iter().flatten() {
+          self.statement(statement);
+        }
+
+        self.scopes.pop();
+      }
+    }
+  }
+
+  /// Check a variable and declare it in the innermost scope, or globally outside of functions.
+  fn variable(&mut self, variable: &XRayShaderHlslVariable) {
+    self.ty(&variable.ty);
+    self.dimensions(variable);
+
+    if let Some(initializer) = &variable.initializer {
+      self.expression(initializer);
+    }
+
+    match self.scopes.last_mut() {
+      Some(scope) => {
+        scope.insert(variable.name.text.clone());
+      }
+      None => {
+        self.globals.insert(variable.name.text.clone());
+
+        if XRayShaderHlslParser::is_resource_type(&variable.ty.name.text) {
+          self
+            .resources
+            .insert(variable.name.text.clone(), self.analysis.resources.len());
+          self.analysis.resources.push((variable.name.clone(), false));
+        }
+      }
+    }
+  }
+
+  fn dimensions(&mut self, variable: &XRayShaderHlslVariable) {
+    for dimension in variable.dimensions.iter().flatten() {
+      self.expression(dimension);
+    }
+  }
+
+  fn ty(&mut self, ty: &XRayShaderHlslType) {
+    if !XRayShaderHlslParser::is_builtin_type(&ty.name.text) && !self.types.contains(&ty.name.text) {
+      self.analysis.issues.push(XRayShaderHlslIssue::error_at(
+        &ty.name,
+        format!("undefined type `{}`", ty.name.text),
+      ));
+    }
+
+    for argument in &ty.arguments {
+      match argument {
+        XRayShaderHlslTypeArgument::Type(ty) => self.ty(ty),
+        XRayShaderHlslTypeArgument::Expression(expression) => self.expression(expression),
+      }
+    }
+  }
+
+  fn statement(&mut self, statement: &XRayShaderHlslStatement) {
+    match statement {
+      XRayShaderHlslStatement::Block(statements) => self.block(statements),
+      XRayShaderHlslStatement::Switch { value, body } => {
+        self.expression(value);
+        self.block(body);
+      }
+      XRayShaderHlslStatement::Variables(variables) => {
+        for variable in variables {
+          self.variable(variable);
+        }
+      }
+      XRayShaderHlslStatement::Expression(expression) | XRayShaderHlslStatement::Case(expression) => {
+        self.expression(expression);
+      }
+      XRayShaderHlslStatement::Return(expression) => {
+        if let Some(expression) = expression {
+          self.expression(expression);
+        }
+      }
+      XRayShaderHlslStatement::If {
+        condition,
+        then,
+        otherwise,
+      } => {
+        self.expression(condition);
+        self.statement(then);
+
+        if let Some(otherwise) = otherwise {
+          self.statement(otherwise);
+        }
+      }
+      XRayShaderHlslStatement::For {
+        initializer,
+        condition,
+        step,
+        body,
+      } => {
+        self.scopes.push(HashSet::new());
+
+        if let Some(initializer) = initializer {
+          self.statement(initializer);
+        }
+
+        for expression in condition.iter().chain(step.iter()) {
+          self.expression(expression);
+        }
+
+        self.statement(body);
+        self.scopes.pop();
+      }
+      XRayShaderHlslStatement::While { condition, body } | XRayShaderHlslStatement::DoWhile { body, condition } => {
+        self.expression(condition);
+        self.statement(body);
+      }
+      XRayShaderHlslStatement::Break
+      | XRayShaderHlslStatement::Continue
+      | XRayShaderHlslStatement::Discard
+      | XRayShaderHlslStatement::Default
+      | XRayShaderHlslStatement::Empty => {}
+    }
+  }
+
+  fn block(&mut self, statements: &[XRayShaderHlslStatement]) {
+    self.scopes.push(HashSet::new());
+
+    for statement in statements {
+      self.statement(statement);
+    }
+
+    self.scopes.pop();
+  }
+
+  fn expression(&mut self, expression: &XRayShaderHlslExpression) {
+    match expression {
+      XRayShaderHlslExpression::Literal(_) => {}
+      XRayShaderHlslExpression::Identifier(name) => {
+        if !self.resolve(name) && !CONSTANTS.contains(&name.text.as_str()) {
+          self.analysis.issues.push(XRayShaderHlslIssue::error_at(
+            name,
+            format!("undefined identifier `{}`", name.text),
+          ));
+        }
+      }
+      XRayShaderHlslExpression::Call { callee, arguments } => {
+        match callee.as_ref() {
+          XRayShaderHlslExpression::Identifier(name) => {
+            if !self.resolve(name) && !INTRINSICS.contains(&name.text.as_str()) {
+              self.analysis.issues.push(XRayShaderHlslIssue::error_at(
+                name,
+                format!("undefined function `{}`", name.text),
+              ));
+            }
+          }
+          callee => self.expression(callee),
+        }
+
+        for argument in arguments {
+          self.expression(argument);
+        }
+      }
+      XRayShaderHlslExpression::Constructor { ty, arguments } => {
+        self.ty(ty);
+
+        for argument in arguments {
+          self.expression(argument);
+        }
+      }
+      XRayShaderHlslExpression::Cast { ty, value } => {
+        self.ty(ty);
+        self.expression(value);
+      }
+      XRayShaderHlslExpression::Member { value, .. } | XRayShaderHlslExpression::Unary { value, .. } => {
+        self.expression(value);
+      }
+      XRayShaderHlslExpression::Index { value, index } => {
+        self.expression(value);
+        self.expression(index);
+      }
+      XRayShaderHlslExpression::Binary { left, right, .. } => {
+        self.expression(left);
+        self.expression(right);
+      }
+      XRayShaderHlslExpression::Conditional {
+        condition,
+        then,
+        otherwise,
+      } => {
+        self.expression(condition);
+        self.expression(then);
+        self.expression(otherwise);
+      }
+      XRayShaderHlslExpression::List(values) => {
+        for value in values {
+          self.expression(value);
+        }
+      }
+    }
+  }
+
+  /// Look a name up from the innermost scope out, marking global resources as used.
+  fn resolve(&mut self, name: &XRayShaderHlslToken) -> bool {
+    if self.scopes.iter().any(|scope| scope.contains(&name.text)) {
+      return true;
+    }
+
+    if !self.globals.contains(&name.text) {
+      return false;
+    }
+
+    if let Some(index) = self.resources.get(&name.text) {
+      self.analysis.resources[*index].1 = true;
+    }
+
+    true
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::xray_shader_hlsl_analyzer::{XRayShaderHlslAnalysis, XRayShaderHlslAnalyzer};
+  use crate::xray_shader_hlsl_parser::XRayShaderHlslParser;
+  use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+  fn analyze(source: &str) -> XRayShaderHlslAnalysis {
+    let tokens: Vec<XRayShaderHlslToken> = XRayShaderHlslToken::lex(source).expect("source is lexed");
+
+    XRayShaderHlslAnalyzer::analyze(&XRayShaderHlslParser::parse(&tokens).expect("source is parsed"))
+  }
+
+  #[test]
+  fn reports_undefined_names_and_unused_resources() {
+    let analysis: XRayShaderHlslAnalysis = analyze(
+      "Texture2D s_base;\nTexture2D s_bump;\nsampler smp_base;\nstruct p_in { float2 tc : TEXCOORD0; };\n\
+       float4 tint(float4 color) { return color * k_tint; }\n\
+       float4 main(p_in I) : SV_Target {\n  float4 color = s_base.Sample(smp_base, I.tc);\n\
+         v_missing result = shade(color);\n  return tint(saturate(color)) + true;\n}\n",
+    );
+
+    let issues: Vec<(usize, &str)> = analysis
+      .issues
+      .iter()
+      .map(|issue| (issue.line, issue.message.as_str()))
+      .collect();
+    let resources: Vec<(&str, bool)> = analysis
+      .resources
+      .iter()
+      .map(|(name, is_used)| (name.text.as_str(), *is_used))
+      .collect();
+
+    assert_eq!(
+      issues,
+      vec![
+        (5, "undefined identifier `k_tint`"),
+        (8, "undefined type `v_missing`"),
+        (8, "undefined function `shade`"),
+      ]
+    );
+    assert_eq!(resources, vec![("s_base", true), ("s_bump", false), ("smp_base", true)]);
+  }
+
+  #[test]
+  fn resolves_scopes_in_declaration_order() {
+    let analysis: XRayShaderHlslAnalysis = analyze(
+      "float4 early() { return late(); }\nfloat4 late() { return 0; }\n\
+       void main() {\n  for (int i = 0; i < 2; i++) { float j = i; }\n  float k = j;\n}\n",
+    );
+
+    let issues: Vec<(usize, &str)> = analysis
+      .issues
+      .iter()
+      .map(|issue| (issue.line, issue.message.as_str()))
+      .collect();
+
+    assert_eq!(
+      issues,
+      vec![(1, "undefined function `late`"), (5, "undefined identifier `j`")]
+    );
+  }
+}
@@ -0,0 +1,132 @@
+use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+/// Top-level declaration of a preprocessed HLSL translation unit.
+#[derive(Clone, Debug)]
+pub enum XRayShaderHlslDeclaration {
+  Struct {
+    name: XRayShaderHlslToken,
+    members: Vec<XRayShaderHlslVariable>,
+  },
+  /// `cbuffer` or `tbuffer`, whose members are globals.
+  Buffer {
+    name: XRayShaderHlslToken,
+    members: Vec<XRayShaderHlslVariable>,
+  },
+  Typedef {
+    ty: XRayShaderHlslType,
+    name: XRayShaderHlslToken,
+  },
+  Variables(Vec<XRayShaderHlslVariable>),
+  Function(XRayShaderHlslFunction),
+}
+
+#[derive(Clone, Debug)]
+pub struct XRayShaderHlslType {
+  pub name: XRayShaderHlslToken,
+  pub arguments: Vec<XRayShaderHlslTypeArgument>,
+}
+
+/// Template argument, as in `Texture2DMS<float4, MSAA_SAMPLES>`.
+#[derive(Clone, Debug)]
+pub enum XRayShaderHlslTypeArgument {
+  Type(XRayShaderHlslType),
+  Expression(XRayShaderHlslExpression),
+}
+
+#[derive(Clone, Debug)]
+pub struct XRayShaderHlslVariable {
+  pub ty: XRayShaderHlslType,
+  pub name: XRayShaderHlslToken,
+  pub dimensions: Vec<Option<XRayShaderHlslExpression>>,
+  pub initializer: Option<XRayShaderHlslExpression>,
+}
+
+#[derive(Clone, Debug)]
+pub struct XRayShaderHlslFunction {
+  pub return_type: XRayShaderHlslType,
+  pub name: XRayShaderHlslToken,
+  pub parameters: Vec<XRayShaderHlslVariable>,
+  /// `None` for a prototype.
+  pub body: Option<Vec<XRayShaderHlslStatement>>,
+}
+
+#[derive(Clone, Debug)]
+pub enum XRayShaderHlslStatement {
+  Block(Vec<XRayShaderHlslStatement>),
+  Variables(Vec<XRayShaderHlslVariable>),
+  Expression(XRayShaderHlslExpression),
+  If {
+    condition: XRayShaderHlslExpression,
+    then: Box<XRayShaderHlslStatement>,
+    otherwise: Option<Box<XRayShaderHlslStatement>>,
+  },
+  For {
+    initializer: Option<Box<XRayShaderHlslStatement>>,
+    condition: Option<XRayShaderHlslExpression>,
+    step: Option<XRayShaderHlslExpression>,
+    body: Box<XRayShaderHlslStatement>,
+  },
+  While {
+    condition: XRayShaderHlslExpression,
+    body: Box<XRayShaderHlslStatement>,
+  },
+  DoWhile {
+    body: Box<XRayShaderHlslStatement>,
+    condition: XRayShaderHlslExpression,
+  },
+  Switch {
+    value: XRayShaderHlslExpression,
+    body: Vec<XRayShaderHlslStatement>,
+  },
+  Case(XRayShaderHlslExpression),
+  Default,
+  Return(Option<XRayShaderHlslExpression>),
+  Break,
+  Continue,
+  Discard,
+  Empty,
+}
+
+#[derive(Clone, Debug)]
+pub enum XRayShaderHlslExpression {
+  Literal(XRayShaderHlslToken),
+  Identifier(XRayShaderHlslToken),
+  /// `float4(...)`, a built-in type used as a function.
+  Constructor {
+    ty: XRayShaderHlslType,
+    arguments: Vec<XRayShaderHlslExpression>,
+  },
+  Cast {
+    ty: XRayShaderHlslType,
+    value: Box<XRayShaderHlslExpression>,
+  },
+  Call {
+    callee: Box<XRayShaderHlslExpression>,
+    arguments: Vec<XRayShaderHlslExpression>,
+  },
+  /// Member or swizzle access, which is not resolved.
+  Member {
+    value: Box<XRayShaderHlslExpression>,
+    member: XRayShaderHlslToken,
+  },
+  Index {
+    value: Box<XRayShaderHlslExpression>,
+    index: Box<XRayShaderHlslExpression>,
+  },
+  Unary {
+    operator: XRayShaderHlslToken,
+    value: Box<XRayShaderHlslExpression>,
+  },
+  Binary {
+    operator: XRayShaderHlslToken,
+    left: Box<XRayShaderHlslExpression>,
+    right: Box<XRayShaderHlslExpression>,
+  },
+  Conditional {
+    condition: Box<XRayShaderHlslExpression>,
+    then: Box<XRayShaderHlslExpression>,
+    otherwise: Box<XRayShaderHlslExpression>,
+  },
+  /// `{ a, b }` initializer of an aggregate.
+  List(Vec<XRayShaderHlslExpression>),
+}
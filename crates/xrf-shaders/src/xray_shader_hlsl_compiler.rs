@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use xrf_error::{XrfError, XrfResult};
+
+use crate::xray_shader_hlsl_analyzer::{XRayShaderHlslAnalysis, XRayShaderHlslAnalyzer};
+use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+use crate::xray_shader_hlsl_parser::XRayShaderHlslParser;
+use crate::xray_shader_hlsl_preprocessor::XRayShaderHlslPreprocessor;
+use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+use crate::{ShaderRenderer, XRayShader, XRayShaderCompiler, XRayShaderDiagnostic, XRayShaderExpandedSource};
+
+/// Macros the DirectX 11 renderer defines for every shader on default settings.
+const ENGINE_DEFINES: [(&str, &str); 10] = [
+  ("SM_5", "1"),
+  ("SMAP_size", "2048"),
+  ("FP16_FILTER", "1"),
+  ("FP16_BLEND", "1"),
+  ("USE_SOFT_WATER", "1"),
+  ("USE_SOFT_PARTICLES", "1"),
+  ("USE_DOF", "1"),
+  ("SUN_SHAFTS_QUALITY", "2"),
+  ("SSAO_QUALITY", "2"),
+  ("SUN_QUALITY", "2"),
+];
+
+/// Macros added on top of `ENGINE_DEFINES` when MSAA is on.
+const ENGINE_MSAA_DEFINES: [(&str, &str); 3] = [("USE_MSAA", "1"), ("MSAA_SAMPLES", "4"), ("ISAMPLE", "0")];
+
+/// Statically validates DirectX 11 renderer shaders without `fxc`, so it runs anywhere.
+///
+/// Every define set is a permutation: the include-expanded source is preprocessed with it, parsed and name-resolved, and
+/// problems of all permutations are merged. No bytecode is produced, so type errors the real compiler would catch are not
+/// reported.
+///
+/// Headers are not compiled on their own, and unused textures and samplers are only reported for the stage source that
+/// declares them: headers declare resources for all their includers.
+#[derive(Clone, Debug)]
+pub struct XRayShaderHlslCompiler {
+  define_sets: Vec<Vec<(String, String)>>,
+}
+
+impl Default for XRayShaderHlslCompiler {
+  fn default() -> Self {
+    Self {
+      define_sets: Self::engine_define_sets(),
+    }
+  }
+}
+
+impl XRayShaderHlslCompiler {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Replace the permutations shaders are checked with.
+  pub fn with_define_sets(mut self, define_sets: Vec<Vec<(String, String)>>) -> Self {
+    self.define_sets = define_sets;
+    self
+  }
+
+  /// Permutations the engine compiles on default settings, without and with MSAA.
+  pub fn engine_define_sets() -> Vec<Vec<(String, String)>> {
+    let base: Vec<(String, String)> = ENGINE_DEFINES
+      .iter()
+      .map(|(name, value)| (String::from(*name), String::from(*value)))
+      .collect();
+    let mut msaa: Vec<(String, String)> = base.clone();
+
+    msaa.extend(
+      ENGINE_MSAA_DEFINES
+        .iter()
+        .map(|(name, value)| (String::from(*name), String::from(*value))),
+    );
+
+    vec![base, msaa]
+  }
+
+  /// Check a shader in every permutation, returning errors and warnings sorted by position.
+  pub fn analyze(&self, shader: &XRayShader) -> Vec<XRayShaderDiagnostic> {
+    let source: XRayShaderExpandedSource = XRayShaderExpandedSource::expand(shader);
+    let tokens: Vec<XRayShaderHlslToken> = match XRayShaderHlslToken::lex(&source.text()) {
+      Ok(tokens) => tokens,
+      Err(issue) => return vec![issue.into_diagnostic(&source)],
+    };
+    let mut issues: Vec<XRayShaderHlslIssue> = Vec::new();
+    let mut resources: HashMap<(usize, usize, String), (XRayShaderHlslToken, bool)> = HashMap::new();
+
+    for defines in &self.define_sets {
+      let (preprocessed, preprocessor_issues) = XRayShaderHlslPreprocessor::preprocess(&tokens, defines);
+
+      issues.extend(preprocessor_issues);
+
+      match XRayShaderHlslParser::parse(&preprocessed) {
+        Ok(declarations) => {
+          let analysis: XRayShaderHlslAnalysis = XRayShaderHlslAnalyzer::analyze(&declarations);
+
+          issues.extend(analysis.issues);
+
+          for (name, is_used) in analysis.resources {
+            resources
+              .entry((name.line, name.column, name.text.clone()))
+              .or_insert((name, false))
+              .1 |= is_used;
+          }
+        }
+        Err(issue) => issues.push(issue),
+      }
+    }
+
+    issues.extend(
+      resources
+        .into_values()
+        .filter(|(name, is_used)| !is_used && Self::is_declared_in(&source, name, shader.path()))
+        .map(|(name, _)| XRayShaderHlslIssue::warning_at(&name, format!("`{}` is declared but never used", name.text))),
+    );
+
+    let mut diagnostics: Vec<XRayShaderDiagnostic> =
+      issues.into_iter().map(|issue| issue.into_diagnostic(&source)).collect();
+
+    diagnostics.sort_by(|left, right| {
+      (&left.location, left.column, left.severity, &left.message).cmp(&(
+        &right.location,
+        right.column,
+        right.severity,
+        &right.message,
+      ))
+    });
+    diagnostics.dedup();
+
+    diagnostics
+  }
+
+  fn is_declared_in(source: &XRayShaderExpandedSource, name: &XRayShaderHlslToken, path: &Path) -> bool {
+    source.location(name.line).is_some_and(|location| location.path == path)
+  }
+
+  fn is_header(path: &Path) -> bool {
+    path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .is_some_and(|extension| extension.eq_ignore_ascii_case("h"))
+  }
+}
+
+impl XRayShaderCompiler for XRayShaderHlslCompiler {
+  fn compile(&self, shader: &XRayShader, renderer: ShaderRenderer) -> XrfResult {
+    if renderer != ShaderRenderer::DirectX11 {
+      return Err(XrfError::new_invalid_error(format!(
+        "HLSL compiler cannot compile {} shaders",
+        renderer.display_name()
+      )));
+    }
+
+    if Self::is_header(shader.path()) {
+      return Ok(());
+    }
+
+    let errors: Vec<String> = self
+      .analyze(shader)
+      .iter()
+      .filter(|diagnostic| diagnostic.is_error())
+      .map(ToString::to_string)
+      .collect();
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(XrfError::new_parsing_error(errors.join("\n")))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::path::{Path, PathBuf};
+
+  use xrf_error::{XrfError, XrfResult};
+
+  use crate::{
+    ShaderRenderer, XRayShader, XRayShaderCompiler, XRayShaderDiagnostic, XRayShaderHlslCompiler,
+    XRayShaderSourceLoader,
+  };
+
+  struct TestSourceLoader {
+    sources: HashMap<PathBuf, Vec<u8>>,
+  }
+
+  impl XRayShaderSourceLoader for TestSourceLoader {
+    fn load_source(&self, path: &Path) -> XrfResult<Option<Vec<u8>>> {
+      Ok(self.sources.get(path).cloned())
+    }
+  }
+
+  fn load(main: &str, common: &str) -> XrfResult<XRayShader> {
+    let root: &Path = Path::new("shaders");
+    let loader: TestSourceLoader = TestSourceLoader {
+      sources: HashMap::from([
+        (root.join("r3/main.ps"), main.as_bytes().to_vec()),
+        (root.join("r3/common.h"), common.as_bytes().to_vec()),
+      ]),
+    };
+
+    XRayShader::load(root.join("r3/main.ps"), ShaderRenderer::DirectX11, root, &loader)
+  }
+
+  const COMMON: &str = "#ifndef COMMON_H\n#define COMMON_H\nTexture2D s_base;\nsampler smp_base;\n\
+                        #ifdef USE_MSAA\nTexture2DMS<float4, MSAA_SAMPLES> s_position;\n#else\n\
+                        Texture2D s_position;\n#endif\n#endif\n";
+
+  #[test]
+  fn compiles_valid_shader_in_engine_permutations() -> XrfResult {
+    let shader: XRayShader = load(
+      "#include \"common.h\"\nfloat4 main(float2 tc : TEXCOORD0) : SV_Target\n{\n#ifdef USE_MSAA\n\
+       return s_position.Load(int2(tc), ISAMPLE) * s_base.Sample(smp_base, tc);\n#else\n\
+       return s_position.Sample(smp_base, tc) * s_base.Sample(smp_base, tc);\n#endif\n}\n",
+      COMMON,
+    )?;
+
+    XRayShaderHlslCompiler::new().compile(&shader, ShaderRenderer::DirectX11)?;
+
+    assert!(XRayShaderHlslCompiler::new().analyze(&shader).is_empty());
+
+    Ok(())
+  }
+
+  #[test]
+  fn reports_problems_of_every_permutation_in_original_files() -> XrfResult {
+    let shader: XRayShader = load(
+      "#include \"common.h\"\nTexture2D s_unused;\nfloat4 main(float2 tc : TEXCOORD0) : SV_Target\n{\n\
+       #ifdef USE_MSAA\n  return s_position.Load(int2(tc), missing_sample);\n#endif\n\
+       return s_base.Sample(smp_base, tc);\n}\n",
+      COMMON,
+    )?;
+    let main_path: PathBuf = Path::new("shaders").join("r3/main.ps");
+
+    let diagnostics: Vec<String> = XRayShaderHlslCompiler::new()
+      .analyze(&shader)
+      .iter()
+      .map(XRayShaderDiagnostic::to_string)
+      .collect();
+
+    assert_eq!(
+      diagnostics,
+      vec![
+        format!(
+          "{}:2:11: warning: `s_unused` is declared but never used",
+          main_path.display()
+        ),
+        format!(
+          "{}:6:36: error: undefined identifier `missing_sample`",
+          main_path.display()
+        ),
+      ]
+    );
+
+    assert!(matches!(
+      XRayShaderHlslCompiler::new().compile(&shader, ShaderRenderer::DirectX11),
+      Err(XrfError::Parsing { .. })
+    ));
+    assert!(matches!(
+      XRayShaderHlslCompiler::new().compile(&shader, ShaderRenderer::OpenGl),
+      Err(XrfError::Invalid { .. })
+    ));
+
+    Ok(())
+  }
+
+  #[test]
+  fn reports_syntax_errors_and_skips_headers() -> XrfResult {
+    let shader: XRayShader = load(
+      "#include \"common.h\"\nfloat4 main() : SV_Target\n{\n  return 0\n}\n",
+      "float4 broken = ;\n",
+    )?;
+    let root: &Path = Path::new("shaders");
+
+    let error: XrfError = XRayShaderHlslCompiler::new()
+      .compile(&shader, ShaderRenderer::DirectX11)
+      .expect_err("syntax error is reported");
+
+    assert!(
+      error.to_string().ends_with(&format!(
+        "{}:1:17: error: expected an expression, found `;`",
+        root.join("r3/common.h").display()
+      )),
+      "{error}"
+    );
+
+    XRayShaderHlslCompiler::new().compile(shader.imports()[0].shader(), ShaderRenderer::DirectX11)
+  }
+}
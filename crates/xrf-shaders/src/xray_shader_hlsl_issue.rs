@@ -0,0 +1,42 @@
+use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+use crate::{XRayShaderDiagnostic, XRayShaderDiagnosticSeverity, XRayShaderExpandedSource};
+
+/// A diagnostic positioned in the expanded source, before it is mapped back to the file that wrote it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayShaderHlslIssue {
+  pub severity: XRayShaderDiagnosticSeverity,
+  pub line: usize,
+  pub column: usize,
+  pub message: String,
+}
+
+impl XRayShaderHlslIssue {
+  pub fn error<T: Into<String>>(line: usize, column: usize, message: T) -> Self {
+    Self {
+      severity: XRayShaderDiagnosticSeverity::Error,
+      line,
+      column,
+      message: message.into(),
+    }
+  }
+
+  pub fn error_at<T: Into<String>>(token: &XRayShaderHlslToken, message: T) -> Self {
+    Self::error(token.line, token.column, message)
+  }
+
+  pub fn warning_at<T: Into<String>>(token: &XRayShaderHlslToken, message: T) -> Self {
+    Self {
+      severity: XRayShaderDiagnosticSeverity::Warning,
+      ..Self::error_at(token, message)
+    }
+  }
+
+  pub fn into_diagnostic(self, source: &XRayShaderExpandedSource) -> XRayShaderDiagnostic {
+    XRayShaderDiagnostic {
+      severity: self.severity,
+      location: source.location(self.line).cloned(),
+      column: self.column,
+      message: self.message,
+    }
+  }
+}
@@ -0,0 +1,937 @@
+use std::collections::HashSet;
+
+use crate::xray_shader_hlsl_ast::{
+  XRayShaderHlslDeclaration, XRayShaderHlslExpression, XRayShaderHlslFunction, XRayShaderHlslStatement,
+  XRayShaderHlslType, XRayShaderHlslTypeArgument, XRayShaderHlslVariable,
+};
+use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+type XRayShaderHlslParseResult<T> = Result<T, XRayShaderHlslIssue>;
+
+const SCALAR_TYPES: [&str; 14] = [
+  "bool",
+  "int",
+  "uint",
+  "dword",
+  "half",
+  "float",
+  "double",
+  "min16float",
+  "min10float",
+  "min16int",
+  "min12int",
+  "min16uint",
+  "int64_t",
+  "uint64_t",
+];
+
+const RESOURCE_TYPES: [&str; 16] = [
+  "Texture1D",
+  "Texture1DArray",
+  "Texture2D",
+  "Texture2DArray",
+  "Texture2DMS",
+  "Texture2DMSArray",
+  "Texture3D",
+  "TextureCube",
+  "TextureCubeArray",
+  "sampler",
+  "sampler1D",
+  "sampler2D",
+  "sampler3D",
+  "samplerCUBE",
+  "SamplerState",
+  "SamplerComparisonState",
+];
+
+const OBJECT_TYPES: [&str; 22] = [
+  "void",
+  "string",
+  "vector",
+  "matrix",
+  "Buffer",
+  "ByteAddressBuffer",
+  "RWBuffer",
+  "RWByteAddressBuffer",
+  "StructuredBuffer",
+  "RWStructuredBuffer",
+  "AppendStructuredBuffer",
+  "ConsumeStructuredBuffer",
+  "PointStream",
+  "LineStream",
+  "TriangleStream",
+  "InputPatch",
+  "OutputPatch",
+  "RWTexture1D",
+  "RWTexture1DArray",
+  "RWTexture2D",
+  "RWTexture2DArray",
+  "RWTexture3D",
+];
+
+const MODIFIERS: [&str; 20] = [
+  "in",
+  "out",
+  "inout",
+  "uniform",
+  "static",
+  "const",
+  "extern",
+  "shared",
+  "groupshared",
+  "volatile",
+  "row_major",
+  "column_major",
+  "precise",
+  "nointerpolation",
+  "linear",
+  "centroid",
+  "noperspective",
+  "inline",
+  "unorm",
+  "snorm",
+];
+
+/// Modifiers that are also common variable names, so they only count when a type follows.
+const CONTEXTUAL_MODIFIERS: [&str; 6] = ["sample", "point", "line", "triangle", "lineadj", "triangleadj"];
+
+const ASSIGNMENT_OPERATORS: [&str; 11] = ["=", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<=", ">>="];
+
+/// Recursive descent parser for the HLSL subset X-Ray shaders are written in.
+///
+/// Built-in types are known up front, and `struct` and `typedef` names are learned while parsing, which is what tells
+/// declarations from expressions. Parsing stops at the first syntax error.
+pub struct XRayShaderHlslParser<'a> {
+  tokens: &'a [XRayShaderHlslToken],
+  index: usize,
+  type_names: HashSet<String>,
+}
+
+impl<'a> XRayShaderHlslParser<'a> {
+  pub fn parse(tokens: &'a [XRayShaderHlslToken]) -> XRayShaderHlslParseResult<Vec<XRayShaderHlslDeclaration>> {
+    let mut parser: Self = Self {
+      tokens,
+      index: 0,
+      type_names: HashSet::new(),
+    };
+    let mut declarations: Vec<XRayShaderHlslDeclaration> = Vec::new();
+
+    while parser.index < parser.tokens.len() {
+      if let Some(declaration) = parser.declaration()? {
+        declarations.push(declaration);
+      }
+    }
+
+    Ok(declarations)
+  }
+
+  /// Whether a name is a type the compiler knows without a declaration, such as `float4x4` or `Texture2D`.
+  pub fn is_builtin_type(name: &str) -> bool {
+    OBJECT_TYPES.contains(&name)
+      || Self::is_resource_type(name)
+      || SCALAR_TYPES.iter().any(|scalar| {
+        name.strip_prefix(scalar).is_some_and(|rest| {
+          let dimensions: Vec<char> = rest.chars().collect();
+
+          match dimensions.as_slice() {
+            [] => true,
+            [rows] => ('1'..='4').contains(rows),
+            [rows, 'x', columns] => ('1'..='4').contains(rows) && ('1'..='4').contains(columns),
+            _ => false,
+          }
+        })
+      })
+  }
+
+  /// Whether a type is a texture or sampler bound by the engine.
+  pub fn is_resource_type(name: &str) -> bool {
+    RESOURCE_TYPES.contains(&name)
+  }
+
+  fn is_template_type(name: &str) -> bool {
+    matches!(name, "vector" | "matrix" | "InputPatch" | "OutputPatch")
+      || (name.contains("Texture") && Self::is_builtin_type(name))
+      || (name.ends_with("Buffer") && OBJECT_TYPES.contains(&name))
+      || (name.ends_with("Stream") && OBJECT_TYPES.contains(&name))
+  }
+
+  fn is_type(&self, token: Option<&XRayShaderHlslToken>) -> bool {
+    token.is_some_and(|token| {
+      token.is_identifier() && (Self::is_builtin_type(&token.text) || self.type_names.contains(&token.text))
+    })
+  }
+
+  fn is_modifier(&self, offset: usize) -> bool {
+    self.peek(offset).is_some_and(|token| {
+      MODIFIERS.contains(&token.text.as_str())
+        || (CONTEXTUAL_MODIFIERS.contains(&token.text.as_str())
+          && self.peek(offset + 1).is_some_and(XRayShaderHlslToken::is_identifier)
+          && self.peek(offset + 2).is_some_and(XRayShaderHlslToken::is_identifier))
+    })
+  }
+
+  /// Whether a statement starts with a declaration, looking past modifiers at a type followed by a name.
+  fn is_declaration_start(&self) -> bool {
+    let mut offset: usize = 0;
+
+    while self.is_modifier(offset) {
+      offset += 1;
+    }
+
+    let Some(name) = self.peek(offset) else {
+      return false;
+    };
+    let next: Option<&XRayShaderHlslToken> = self.peek(offset + 1);
+
+    if offset > 0 {
+      return true;
+    }
+
+    if Self::is_builtin_type(&name.text) {
+      return next.is_some_and(|next| next.is_identifier() || (next.is("<") && Self::is_template_type(&name.text)));
+    }
+
+    name.is_identifier() && next.is_some_and(XRayShaderHlslToken::is_identifier)
+  }
+
+  fn declaration(&mut self) -> XRayShaderHlslParseResult<Option<XRayShaderHlslDeclaration>> {
+    if self.take(";") {
+      return Ok(None);
+    }
+
+    self.skip_attributes()?;
+
+    let Some(token) = self.peek(0).cloned() else {
+      return Ok(None);
+    };
+
+    match token.text.as_str() {
+      "struct" => {
+        self.index += 1;
+
+        let name: XRayShaderHlslToken = self.identifier()?;
+
+        self.type_names.insert(name.text.clone());
+
+        let members: Vec<XRayShaderHlslVariable> = self.members()?;
+
+        self.expect(";")?;
+
+        Ok(Some(XRayShaderHlslDeclaration::Struct { name, members }))
+      }
+      "cbuffer" | "tbuffer" => {
+        self.index += 1;
+
+        let name: XRayShaderHlslToken = self.identifier()?;
+
+        self.skip_semantics()?;
+
+        let members: Vec<XRayShaderHlslVariable> = self.members()?;
+
+        self.take(";");
+
+        Ok(Some(XRayShaderHlslDeclaration::Buffer { name, members }))
+      }
+      "typedef" => {
+        self.index += 1;
+        self.skip_modifiers();
+
+        let ty: XRayShaderHlslType = self.ty()?;
+        let name: XRayShaderHlslToken = self.identifier()?;
+
+        self.expect(";")?;
+        self.type_names.insert(name.text.clone());
+
+        Ok(Some(XRayShaderHlslDeclaration::Typedef { ty, name }))
+      }
+      _ => {
+        self.skip_modifiers();
+
+        let ty: XRayShaderHlslType = self.ty()?;
+        let name: XRayShaderHlslToken = self.identifier()?;
+
+        if self.peek(0).is_some_and(|next| next.is("(")) {
+          return Ok(Some(XRayShaderHlslDeclaration::Function(self.function(ty, name)?)));
+        }
+
+        let variables: Vec<XRayShaderHlslVariable> = self.declarators(ty, name)?;
+
+        self.expect(";")?;
+
+        Ok(Some(XRayShaderHlslDeclaration::Variables(variables)))
+      }
+    }
+  }
+
+  fn members(&mut self) -> XRayShaderHlslParseResult<Vec<XRayShaderHlslVariable>> {
+    let mut members: Vec<XRayShaderHlslVariable> = Vec::new();
+
+    self.expect("{")?;
+
+    while !self.take("}") {
+      if self.take(";") {
+        continue;
+      }
+
+      self.skip_modifiers();
+
+      let ty: XRayShaderHlslType = self.ty()?;
+      let name: XRayShaderHlslToken = self.identifier()?;
+
+      members.extend(self.declarators(ty, name)?);
+      self.expect(";")?;
+    }
+
+    Ok(members)
+  }
+
+  fn function(
+    &mut self,
+    return_type: XRayShaderHlslType,
+    name: XRayShaderHlslToken,
+  ) -> XRayShaderHlslParseResult<XRayShaderHlslFunction> {
+    let mut parameters: Vec<XRayShaderHlslVariable> = Vec::new();
+
+    self.expect("(")?;
+
+    if self.peek(0).is_some_and(|token| token.is("void")) && self.peek(1).is_some_and(|token| token.is(")")) {
+      self.index += 1;
+    }
+
+    while !self.take(")") {
+      if !parameters.is_empty() {
+        self.expect(",")?;
+      }
+
+      self.skip_modifiers();
+
+      let ty: XRayShaderHlslType = self.ty()?;
+      let name: XRayShaderHlslToken = self.identifier()?;
+
+      parameters.push(self.declarator(ty, name)?);
+    }
+
+    self.skip_semantics()?;
+
+    let body: Option<Vec<XRayShaderHlslStatement>> = if self.take(";") { None } else { Some(self.block()?) };
+
+    Ok(XRayShaderHlslFunction {
+      return_type,
+      name,
+      parameters,
+      body,
+    })
+  }
+
+  fn declarators(
+    &mut self,
+    ty: XRayShaderHlslType,
+    name: XRayShaderHlslToken,
+  ) -> XRayShaderHlslParseResult<Vec<XRayShaderHlslVariable>> {
+    let mut variables: Vec<XRayShaderHlslVariable> = vec![self.declarator(ty.clone(), name)?];
+
+    while self.take(",") {
+      let name: XRayShaderHlslToken = self.identifier()?;
+
+      variables.push(self.declarator(ty.clone(), name)?);
+    }
+
+    Ok(variables)
+  }
+
+  fn declarator(
+    &mut self,
+    ty: XRayShaderHlslType,
+    name: XRayShaderHlslToken,
+  ) -> XRayShaderHlslParseResult<XRayShaderHlslVariable> {
+    let mut dimensions: Vec<Option<XRayShaderHlslExpression>> = Vec::new();
+
+    while self.take("[") {
+      if self.take("]") {
+        dimensions.push(None);
+      } else {
+        dimensions.push(Some(self.expression()?));
+        self.expect("]")?;
+      }
+    }
+
+    self.skip_semantics()?;
+
+    let initializer: Option<XRayShaderHlslExpression> = if self.take("=") {
+      Some(self.initializer()?)
+    } else {
+      None
+    };
+
+    Ok(XRayShaderHlslVariable {
+      ty,
+      name,
+      dimensions,
+      initializer,
+    })
+  }
+
+  fn initializer(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    if !self.take("{") {
+      return self.assignment();
+    }
+
+    let mut values: Vec<XRayShaderHlslExpression> = Vec::new();
+
+    while !self.take("}") {
+      values.push(self.initializer()?);
+
+      if !self.take(",") {
+        self.expect("}")?;
+        break;
+      }
+    }
+
+    Ok(XRayShaderHlslExpression::List(values))
+  }
+
+  fn ty(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslType> {
+    let name: XRayShaderHlslToken = self.identifier()?;
+    let mut arguments: Vec<XRayShaderHlslTypeArgument> = Vec::new();
+
+    if Self::is_template_type(&name.text) && self.take("<") {
+      while !self.take(">") {
+        if !arguments.is_empty() {
+          self.expect(",")?;
+        }
+
+        if self.is_type(self.peek(0)) {
+          arguments.push(XRayShaderHlslTypeArgument::Type(self.ty()?));
+        } else {
+          arguments.push(XRayShaderHlslTypeArgument::Expression(
+            self.binary(Self::SHIFT_PRECEDENCE)?,
+          ));
+        }
+      }
+    }
+
+    Ok(XRayShaderHlslType { name, arguments })
+  }
+
+  fn block(&mut self) -> XRayShaderHlslParseResult<Vec<XRayShaderHlslStatement>> {
+    let mut statements: Vec<XRayShaderHlslStatement> = Vec::new();
+
+    self.expect("{")?;
+
+    while !self.take("}") {
+      statements.push(self.statement()?);
+    }
+
+    Ok(statements)
+  }
+
+  fn statement(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslStatement> {
+    self.skip_attributes()?;
+
+    let Some(token) = self.peek(0).cloned() else {
+      return Err(self.unexpected("a statement"));
+    };
+
+    match token.text.as_str() {
+      "{" => Ok(XRayShaderHlslStatement::Block(self.block()?)),
+      ";" => {
+        self.index += 1;
+        Ok(XRayShaderHlslStatement::Empty)
+      }
+      "if" => {
+        self.index += 1;
+
+        let condition: XRayShaderHlslExpression = self.parenthesized()?;
+        let then: Box<XRayShaderHlslStatement> = Box::new(self.statement()?);
+        let otherwise: Option<Box<XRayShaderHlslStatement>> = if self.take("else") {
+          Some(Box::new(self.statement()?))
+        } else {
+          None
+        };
+
+        Ok(XRayShaderHlslStatement::If {
+          condition,
+          then,
+          otherwise,
+        })
+      }
+      "for" => {
+        self.index += 1;
+        self.expect("(")?;
+
+        let initializer: Option<Box<XRayShaderHlslStatement>> = if self.take(";") {
+          None
+        } else {
+          Some(Box::new(self.simple_statement()?))
+        };
+        let condition: Option<XRayShaderHlslExpression> = self.optional_expression(";")?;
+        let step: Option<XRayShaderHlslExpression> = self.optional_expression(")")?;
+        let body: Box<XRayShaderHlslStatement> = Box::new(self.statement()?);
+
+        Ok(XRayShaderHlslStatement::For {
+          initializer,
+          condition,
+          step,
+          body,
+        })
+      }
+      "while" => {
+        self.index += 1;
+
+        let condition: XRayShaderHlslExpression = self.parenthesized()?;
+        let body: Box<XRayShaderHlslStatement> = Box::new(self.statement()?);
+
+        Ok(XRayShaderHlslStatement::While { condition, body })
+      }
+      "do" => {
+        self.index += 1;
+
+        let body: Box<XRayShaderHlslStatement> = Box::new(self.statement()?);
+
+        self.expect("while")?;
+
+        let condition: XRayShaderHlslExpression = self.parenthesized()?;
+
+        self.expect(";")?;
+
+        Ok(XRayShaderHlslStatement::DoWhile { body, condition })
+      }
+      "switch" => {
+        self.index += 1;
+
+        let value: XRayShaderHlslExpression = self.parenthesized()?;
+        let body: Vec<XRayShaderHlslStatement> = self.block()?;
+
+        Ok(XRayShaderHlslStatement::Switch { value, body })
+      }
+      "case" => {
+        self.index += 1;
+
+        let value: XRayShaderHlslExpression = self.conditional()?;
+
+        self.expect(":")?;
+
+        Ok(XRayShaderHlslStatement::Case(value))
+      }
+      "default" => {
+        self.index += 1;
+        self.expect(":")?;
+
+        Ok(XRayShaderHlslStatement::Default)
+      }
+      "return" => {
+        self.index += 1;
+
+        Ok(XRayShaderHlslStatement::Return(self.optional_expression(";")?))
+      }
+      "break" | "continue" | "discard" => {
+        self.index += 1;
+        self.expect(";")?;
+
+        Ok(match token.text.as_str() {
+          "break" => XRayShaderHlslStatement::Break,
+          "continue" => XRayShaderHlslStatement::Continue,
+          _ => XRayShaderHlslStatement::Discard,
+        })
+      }
+      _ => self.simple_statement(),
+    }
+  }
+
+  /// Declaration or expression statement, including its `;`.
+  fn simple_statement(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslStatement> {
+    if self.is_declaration_start() {
+      self.skip_modifiers();
+
+      let ty: XRayShaderHlslType = self.ty()?;
+      let name: XRayShaderHlslToken = self.identifier()?;
+      let variables: Vec<XRayShaderHlslVariable> = self.declarators(ty, name)?;
+
+      self.expect(";")?;
+
+      return Ok(XRayShaderHlslStatement::Variables(variables));
+    }
+
+    let expression: XRayShaderHlslExpression = self.expression()?;
+
+    self.expect(";")?;
+
+    Ok(XRayShaderHlslStatement::Expression(expression))
+  }
+
+  fn optional_expression(&mut self, terminator: &str) -> XRayShaderHlslParseResult<Option<XRayShaderHlslExpression>> {
+    if self.take(terminator) {
+      return Ok(None);
+    }
+
+    let expression: XRayShaderHlslExpression = self.expression()?;
+
+    self.expect(terminator)?;
+
+    Ok(Some(expression))
+  }
+
+  fn parenthesized(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    self.expect("(")?;
+
+    let expression: XRayShaderHlslExpression = self.expression()?;
+
+    self.expect(")")?;
+
+    Ok(expression)
+  }
+
+  /// Expression with the comma operator.
+  fn expression(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let mut expression: XRayShaderHlslExpression = self.assignment()?;
+
+    while let Some(operator) = self.peek(0).filter(|token| token.is(",")).cloned() {
+      self.index += 1;
+      expression = XRayShaderHlslExpression::Binary {
+        operator,
+        left: Box::new(expression),
+        right: Box::new(self.assignment()?),
+      };
+    }
+
+    Ok(expression)
+  }
+
+  fn assignment(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let target: XRayShaderHlslExpression = self.conditional()?;
+
+    match self
+      .peek(0)
+      .filter(|token| ASSIGNMENT_OPERATORS.contains(&token.text.as_str()))
+      .cloned()
+    {
+      Some(operator) => {
+        self.index += 1;
+
+        Ok(XRayShaderHlslExpression::Binary {
+          operator,
+          left: Box::new(target),
+          right: Box::new(self.assignment()?),
+        })
+      }
+      None => Ok(target),
+    }
+  }
+
+  fn conditional(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let condition: XRayShaderHlslExpression = self.binary(0)?;
+
+    if !self.take("?") {
+      return Ok(condition);
+    }
+
+    let then: XRayShaderHlslExpression = self.assignment()?;
+
+    self.expect(":")?;
+
+    let otherwise: XRayShaderHlslExpression = self.assignment()?;
+
+    Ok(XRayShaderHlslExpression::Conditional {
+      condition: Box::new(condition),
+      then: Box::new(then),
+      otherwise: Box::new(otherwise),
+    })
+  }
+
+  const SHIFT_PRECEDENCE: u8 = 8;
+
+  fn binary(&mut self, minimum_precedence: u8) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let mut left: XRayShaderHlslExpression = self.unary()?;
+
+    while let Some(operator) = self.peek(0).cloned() {
+      let precedence: u8 = match operator.text.as_str() {
+        "||" => 1,
+        "&&" => 2,
+        "|" => 3,
+        "^" => 4,
+        "&" => 5,
+        "==" | "!=" => 6,
+        "<" | ">" | "<=" | ">=" => 7,
+        "<<" | ">>" => Self::SHIFT_PRECEDENCE,
+        "+" | "-" => 9,
+        "*" | "/" | "%" => 10,
+        _ => break,
+      };
+
+      if precedence < minimum_precedence {
+        break;
+      }
+
+      self.index += 1;
+      left = XRayShaderHlslExpression::Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(self.binary(precedence + 1)?),
+      };
+    }
+
+    Ok(left)
+  }
+
+  fn unary(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let Some(token) = self.peek(0).cloned() else {
+      return Err(self.unexpected("an expression"));
+    };
+
+    if ["!", "~", "-", "+", "++", "--"].contains(&token.text.as_str()) {
+      self.index += 1;
+
+      return Ok(XRayShaderHlslExpression::Unary {
+        operator: token,
+        value: Box::new(self.unary()?),
+      });
+    }
+
+    if token.is("(") && self.is_type(self.peek(1)) {
+      let start: usize = self.index;
+
+      self.index += 1;
+
+      let ty: XRayShaderHlslType = self.ty()?;
+
+      if self.take(")") {
+        return Ok(XRayShaderHlslExpression::Cast {
+          ty,
+          value: Box::new(self.unary()?),
+        });
+      }
+
+      self.index = start;
+    }
+
+    self.postfix()
+  }
+
+  fn postfix(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let mut expression: XRayShaderHlslExpression = self.primary()?;
+
+    loop {
+      if self.take("(") {
+        expression = XRayShaderHlslExpression::Call {
+          callee: Box::new(expression),
+          arguments: self.arguments()?,
+        };
+      } else if self.take("[") {
+        let index: XRayShaderHlslExpression = self.expression()?;
+
+        self.expect("]")?;
+        expression = XRayShaderHlslExpression::Index {
+          value: Box::new(expression),
+          index: Box::new(index),
+        };
+      } else if self.take(".") {
+        expression = XRayShaderHlslExpression::Member {
+          value: Box::new(expression),
+          member: self.identifier()?,
+        };
+      } else if let Some(operator) = self.peek(0).filter(|token| token.is("++") || token.is("--")).cloned() {
+        self.index += 1;
+        expression = XRayShaderHlslExpression::Unary {
+          operator,
+          value: Box::new(expression),
+        };
+      } else {
+        return Ok(expression);
+      }
+    }
+  }
+
+  fn primary(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslExpression> {
+    let Some(token) = self.peek(0).cloned() else {
+      return Err(self.unexpected("an expression"));
+    };
+
+    if token.is("(") {
+      return self.parenthesized();
+    }
+
+    if Self::is_builtin_type(&token.text) {
+      let ty: XRayShaderHlslType = self.ty()?;
+
+      self.expect("(")?;
+
+      return Ok(XRayShaderHlslExpression::Constructor {
+        ty,
+        arguments: self.arguments()?,
+      });
+    }
+
+    self.index += 1;
+
+    if token.is_identifier() {
+      Ok(XRayShaderHlslExpression::Identifier(token))
+    } else if token.is_literal() {
+      Ok(XRayShaderHlslExpression::Literal(token))
+    } else {
+      self.index -= 1;
+      Err(self.unexpected("an expression"))
+    }
+  }
+
+  /// Call arguments after the opening parenthesis.
+  fn arguments(&mut self) -> XRayShaderHlslParseResult<Vec<XRayShaderHlslExpression>> {
+    let mut arguments: Vec<XRayShaderHlslExpression> = Vec::new();
+
+    while !self.take(")") {
+      if !arguments.is_empty() {
+        self.expect(",")?;
+      }
+
+      arguments.push(self.assignment()?);
+    }
+
+    Ok(arguments)
+  }
+
+  fn skip_modifiers(&mut self) {
+    while self.is_modifier(0) {
+      self.index += 1;
+    }
+  }
+
+  /// Skip `[attribute(...)]` lists, which are hints for the compiler.
+  fn skip_attributes(&mut self) -> XRayShaderHlslParseResult<()> {
+    while self.peek(0).is_some_and(|token| token.is("[")) {
+      self.skip_balanced("[", "]")?;
+    }
+
+    Ok(())
+  }
+
+  /// Skip `: SEMANTIC`, `: register(t0)` and `: packoffset(c0)` annotations.
+  fn skip_semantics(&mut self) -> XRayShaderHlslParseResult<()> {
+    while self.take(":") {
+      self.identifier()?;
+
+      if self.peek(0).is_some_and(|token| token.is("(")) {
+        self.skip_balanced("(", ")")?;
+      }
+    }
+
+    Ok(())
+  }
+
+  fn skip_balanced(&mut self, open: &str, close: &str) -> XRayShaderHlslParseResult<()> {
+    let start: usize = self.index;
+    let mut depth: usize = 0;
+
+    while let Some(token) = self.peek(0) {
+      self.index += 1;
+
+      if token.is(open) {
+        depth += 1;
+      } else if token.is(close) {
+        depth -= 1;
+
+        if depth == 0 {
+          return Ok(());
+        }
+      }
+    }
+
+    Err(XRayShaderHlslIssue::error_at(
+      &self.tokens[start],
+      format!("unclosed `{open}`"),
+    ))
+  }
+
+  fn identifier(&mut self) -> XRayShaderHlslParseResult<XRayShaderHlslToken> {
+    match self.peek(0) {
+      Some(token) if token.is_identifier() => {
+        self.index += 1;
+        Ok(token.clone())
+      }
+      _ => Err(self.unexpected("an identifier")),
+    }
+  }
+
+  fn expect(&mut self, text: &str) -> XRayShaderHlslParseResult<()> {
+    if self.take(text) {
+      Ok(())
+    } else {
+      Err(self.unexpected(&format!("`{text}`")))
+    }
+  }
+
+  fn take(&mut self, text: &str) -> bool {
+    if self.peek(0).is_some_and(|token| token.is(text)) {
+      self.index += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn peek(&self, offset: usize) -> Option<&'a XRayShaderHlslToken> {
+    self.tokens.get(self.index + offset)
+  }
+
+  fn unexpected(&self, expected: &str) -> XRayShaderHlslIssue {
+    match self.peek(0) {
+      Some(token) => XRayShaderHlslIssue::error_at(token, format!("expected {expected}, found `{}`", token.text)),
+      None => match self.tokens.last() {
+        Some(token) => XRayShaderHlslIssue::error_at(token, format!("expected {expected}, found end of file")),
+        None => XRayShaderHlslIssue::error(1, 1, format!("expected {expected}, found end of file")),
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::xray_shader_hlsl_ast::{XRayShaderHlslDeclaration, XRayShaderHlslStatement};
+  use crate::xray_shader_hlsl_parser::XRayShaderHlslParser;
+  use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+  #[test]
+  fn parses_engine_style_declarations() {
+    let tokens: Vec<XRayShaderHlslToken> = XRayShaderHlslToken::lex(
+      "struct v2p { float4 hpos : SV_Position; float2 tc0[2] : TEXCOORD0; };\n\
+       cbuffer dynamic : register(b0) { float4x4 m_WVP; float4 L_sun_color; }\n\
+       Texture2DMS<float4, 4> s_position;\nsampler smp_base : register(s0);\nstatic const float3 LUM = { 0.3, 0.5, 0.2 };\n\
+       [earlydepthstencil]\nfloat4 main(v2p I, uniform int sample_count) : SV_Target {\n\
+         float4 color = (float4)0, extra;\n  [unroll] for (int i = 0; i < 4; ++i) { color.rgb += I.tc0[0].xyx * LUM; }\n\
+         if (color.a < 0.5) discard; else color = lerp(color, float4(1, 1, 1, 1), saturate(i > 2 ? 0.5h : 1));\n\
+         return color;\n}\n",
+    )
+    .expect("source is lexed");
+
+    let declarations: Vec<XRayShaderHlslDeclaration> = XRayShaderHlslParser::parse(&tokens).expect("source is parsed");
+
+    assert_eq!(declarations.len(), 6);
+
+    let XRayShaderHlslDeclaration::Function(function) = &declarations[5] else {
+      panic!("main is a function");
+    };
+
+    assert_eq!(function.name.text, "main");
+    assert_eq!(function.parameters.len(), 2);
+    assert!(matches!(
+      function.body.as_deref(),
+      Some([
+        XRayShaderHlslStatement::Variables(_),
+        XRayShaderHlslStatement::For { .. },
+        XRayShaderHlslStatement::If { .. },
+        XRayShaderHlslStatement::Return(Some(_))
+      ])
+    ));
+  }
+
+  #[test]
+  fn reports_first_syntax_error() {
+    let tokens: Vec<XRayShaderHlslToken> =
+      XRayShaderHlslToken::lex("float4 main() : SV_Target\n{\n  float4 a = 1\n  return a;\n}\n")
+        .expect("source is lexed");
+
+    let error = XRayShaderHlslParser::parse(&tokens).expect_err("missing semicolon is reported");
+
+    assert_eq!((error.line, error.column), (4, 3));
+    assert_eq!(error.message, "expected `;`, found `return`");
+  }
+}
@@ -0,0 +1,732 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+use crate::xray_shader_hlsl_token::{XRayShaderHlslToken, XRayShaderHlslTokenKind};
+
+#[derive(Clone, Debug)]
+struct XRayShaderHlslMacro {
+  parameters: Option<Vec<String>>,
+  body: Vec<XRayShaderHlslToken>,
+}
+
+#[derive(Clone, Debug)]
+struct XRayShaderHlslCondition {
+  directive: XRayShaderHlslToken,
+  is_active: bool,
+  is_parent_active: bool,
+  is_taken: bool,
+  has_else: bool,
+}
+
+/// A token waiting for macro expansion, with the macros it must not expand again.
+type XRayShaderHlslQueuedToken = (XRayShaderHlslToken, Vec<String>);
+
+/// C preprocessor for the DirectX 11 shader tree, run over an already include-expanded token stream.
+///
+/// Includes are resolved by `XRayShader::load`, so `#include` lines never reach this stage. Other directives behave as
+/// in `fxc`: conditional blocks, object- and function-like macros with `#` and `##`, `#error`. Unknown directives are
+/// reported, `#pragma` and `#line` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct XRayShaderHlslPreprocessor {
+  macros: HashMap<String, XRayShaderHlslMacro>,
+  conditions: Vec<XRayShaderHlslCondition>,
+  pending: Vec<XRayShaderHlslToken>,
+  output: Vec<XRayShaderHlslToken>,
+  issues: Vec<XRayShaderHlslIssue>,
+}
+
+impl XRayShaderHlslPreprocessor {
+  /// Preprocess tokens with a set of predefined macros, returning the remaining code tokens and found issues.
+  pub fn preprocess(
+    tokens: &[XRayShaderHlslToken],
+    defines: &[(String, String)],
+  ) -> (Vec<XRayShaderHlslToken>, Vec<XRayShaderHlslIssue>) {
+    let mut preprocessor: Self = Self::default();
+
+    for (name, value) in defines {
+      preprocessor.macros.insert(
+        name.clone(),
+        XRayShaderHlslMacro {
+          parameters: None,
+          body: XRayShaderHlslToken::lex(value).unwrap_or_default(),
+        },
+      );
+    }
+
+    let mut index: usize = 0;
+
+    while index < tokens.len() {
+      let token: &XRayShaderHlslToken = &tokens[index];
+      let is_line_start: bool = index == 0 || tokens[index - 1].logical_line != token.logical_line;
+
+      if token.is("#") && is_line_start {
+        let end: usize = tokens[index..]
+          .iter()
+          .position(|next| next.logical_line != token.logical_line)
+          .map_or(tokens.len(), |offset| index + offset);
+
+        preprocessor.flush();
+        preprocessor.directive(token, &tokens[index + 1..end]);
+        index = end;
+      } else {
+        if preprocessor.is_active() {
+          preprocessor.pending.push(token.clone());
+        }
+
+        index += 1;
+      }
+    }
+
+    preprocessor.flush();
+
+    for condition in std::mem::take(&mut preprocessor.conditions) {
+      preprocessor.issues.push(XRayShaderHlslIssue::error_at(
+        &condition.directive,
+        "unterminated conditional directive",
+      ));
+    }
+
+    (preprocessor.output, preprocessor.issues)
+  }
+
+  fn is_active(&self) -> bool {
+    self.conditions.last().is_none_or(|condition| condition.is_active)
+  }
+
+  fn flush(&mut self) {
+    if !self.pending.is_empty() {
+      let pending: Vec<XRayShaderHlslToken> = std::mem::take(&mut self.pending);
+      let expanded: Vec<XRayShaderHlslToken> = self.expand(pending);
+
+      self.output.extend(expanded);
+    }
+  }
+
+  fn directive(&mut self, hash: &XRayShaderHlslToken, tokens: &[XRayShaderHlslToken]) {
+    let Some(name) = tokens.first() else {
+      return;
+    };
+
+    match name.text.as_str() {
+      "if" | "ifdef" | "ifndef" => {
+        let is_parent_active: bool = self.is_active();
+        let is_active: bool = is_parent_active && self.condition(name, &tokens[1..]);
+
+        self.conditions.push(XRayShaderHlslCondition {
+          directive: hash.clone(),
+          is_active,
+          is_parent_active,
+          is_taken: is_active,
+          has_else: false,
+        });
+      }
+      "elif" => {
+        let Some(condition) = self.conditions.last().cloned() else {
+          return self
+            .issues
+            .push(XRayShaderHlslIssue::error_at(name, "#elif without #if"));
+        };
+
+        if condition.has_else {
+          self
+            .issues
+            .push(XRayShaderHlslIssue::error_at(name, "#elif after #else"));
+        }
+
+        let is_active: bool = condition.is_parent_active && !condition.is_taken && self.condition(name, &tokens[1..]);
+
+        if let Some(condition) = self.conditions.last_mut() {
+          condition.is_active = is_active;
+          condition.is_taken |= is_active;
+        }
+      }
+      "else" => match self.conditions.last_mut() {
+        Some(condition) if condition.has_else => {
+          self
+            .issues
+            .push(XRayShaderHlslIssue::error_at(name, "#else after #else"));
+        }
+        Some(condition) => {
+          condition.has_else = true;
+          condition.is_active = condition.is_parent_active && !condition.is_taken;
+          condition.is_taken = true;
+        }
+        None => self
+          .issues
+          .push(XRayShaderHlslIssue::error_at(name, "#else without #if")),
+      },
+      "endif" => {
+        if self.conditions.pop().is_none() {
+          self
+            .issues
+            .push(XRayShaderHlslIssue::error_at(name, "#endif without #if"));
+        }
+      }
+      _ if !self.is_active() => {}
+      "define" => self.define(name, &tokens[1..]),
+      "undef" => match tokens.get(1) {
+        Some(target) if target.is_identifier() => {
+          self.macros.remove(&target.text);
+        }
+        _ => self
+          .issues
+          .push(XRayShaderHlslIssue::error_at(name, "#undef expects a macro name")),
+      },
+      "error" => self.issues.push(XRayShaderHlslIssue::error_at(
+        name,
+        format!("#error {}", Self::join(&tokens[1..])),
+      )),
+      "include" | "pragma" | "line" => {}
+      other => self.issues.push(XRayShaderHlslIssue::error_at(
+        name,
+        format!("unknown preprocessor directive `#{other}`"),
+      )),
+    }
+  }
+
+  fn define(&mut self, directive: &XRayShaderHlslToken, tokens: &[XRayShaderHlslToken]) {
+    let Some(name) = tokens.first().filter(|name| name.is_identifier()) else {
+      return self
+        .issues
+        .push(XRayShaderHlslIssue::error_at(directive, "#define expects a macro name"));
+    };
+
+    let is_function: bool = tokens
+      .get(1)
+      .is_some_and(|next| next.is("(") && next.line == name.line && next.column == name.column + name.text.len());
+
+    if !is_function {
+      self.macros.insert(
+        name.text.clone(),
+        XRayShaderHlslMacro {
+          parameters: None,
+          body: tokens[1..].to_vec(),
+        },
+      );
+
+      return;
+    }
+
+    let mut parameters: Vec<String> = Vec::new();
+    let mut index: usize = 2;
+
+    loop {
+      match tokens.get(index) {
+        Some(token) if token.is(")") && (parameters.is_empty() || !tokens[index - 1].is(",")) => break,
+        Some(token) if token.is_identifier() && (parameters.is_empty() || tokens[index - 1].is(",")) => {
+          parameters.push(token.text.clone());
+        }
+        Some(token) if token.is(",") && tokens[index - 1].is_identifier() => {}
+        Some(token) => {
+          return self.issues.push(XRayShaderHlslIssue::error_at(
+            token,
+            format!("unexpected `{}` in macro parameters", token.text),
+          ));
+        }
+        None => {
+          return self
+            .issues
+            .push(XRayShaderHlslIssue::error_at(name, "unterminated macro parameter list"));
+        }
+      }
+
+      index += 1;
+    }
+
+    self.macros.insert(
+      name.text.clone(),
+      XRayShaderHlslMacro {
+        parameters: Some(parameters),
+        body: tokens[index + 1..].to_vec(),
+      },
+    );
+  }
+
+  fn condition(&mut self, directive: &XRayShaderHlslToken, tokens: &[XRayShaderHlslToken]) -> bool {
+    match directive.text.as_str() {
+      "ifdef" | "ifndef" => {
+        let Some(name) = tokens.first().filter(|name| name.is_identifier()) else {
+          self.issues.push(XRayShaderHlslIssue::error_at(
+            directive,
+            format!("#{} expects a macro name", directive.text),
+          ));
+
+          return false;
+        };
+
+        self.macros.contains_key(&name.text) == (directive.text == "ifdef")
+      }
+      _ => {
+        let resolved: Vec<XRayShaderHlslToken> = self.resolve_defined(tokens);
+        let expanded: Vec<XRayShaderHlslToken> = self.expand(resolved);
+        let mut evaluator: XRayShaderHlslConditionEvaluator = XRayShaderHlslConditionEvaluator {
+          tokens: &expanded,
+          index: 0,
+        };
+
+        match evaluator.evaluate() {
+          Ok(value) => value != 0,
+          Err(message) => {
+            self.issues.push(XRayShaderHlslIssue::error_at(directive, message));
+            false
+          }
+        }
+      }
+    }
+  }
+
+  /// Replace `defined NAME` and `defined(NAME)` before macro expansion can touch the name.
+  fn resolve_defined(&self, tokens: &[XRayShaderHlslToken]) -> Vec<XRayShaderHlslToken> {
+    let mut resolved: Vec<XRayShaderHlslToken> = Vec::new();
+    let mut index: usize = 0;
+
+    while index < tokens.len() {
+      let token: &XRayShaderHlslToken = &tokens[index];
+
+      if token.is("defined") {
+        let (name, length) = if tokens.get(index + 1).is_some_and(|next| next.is("(")) {
+          (tokens.get(index + 2), 4)
+        } else {
+          (tokens.get(index + 1), 2)
+        };
+        let is_defined: bool = name.is_some_and(|name| self.macros.contains_key(&name.text));
+
+        resolved.push(Self::relocated(
+          &XRayShaderHlslToken::new(
+            XRayShaderHlslTokenKind::Number,
+            String::from(if is_defined { "1" } else { "0" }),
+            0,
+            0,
+            0,
+          ),
+          token,
+        ));
+        index += length;
+      } else {
+        resolved.push(token.clone());
+        index += 1;
+      }
+    }
+
+    resolved
+  }
+
+  /// Expand macros the way a C preprocessor rescans them: replacements go back to the front of the queue, and every
+  /// token remembers which macros produced it so recursive definitions stop.
+  fn expand(&mut self, tokens: Vec<XRayShaderHlslToken>) -> Vec<XRayShaderHlslToken> {
+    let mut queue: VecDeque<XRayShaderHlslQueuedToken> = tokens.into_iter().map(|token| (token, Vec::new())).collect();
+    let mut expanded: Vec<XRayShaderHlslToken> = Vec::new();
+
+    while let Some((token, hidden)) = queue.pop_front() {
+      let definition: Option<XRayShaderHlslMacro> = if token.is_identifier() && !hidden.contains(&token.text) {
+        self.macros.get(&token.text).cloned()
+      } else {
+        None
+      };
+
+      let Some(definition) = definition else {
+        expanded.push(token);
+        continue;
+      };
+
+      let replacement: Vec<XRayShaderHlslToken> = match &definition.parameters {
+        None => Self::paste(
+          definition
+            .body
+            .iter()
+            .map(|body| Self::relocated(body, &token))
+            .collect(),
+        ),
+        Some(parameters) => {
+          if !queue.front().is_some_and(|(next, _)| next.is("(")) {
+            expanded.push(token);
+            continue;
+          }
+
+          let Some(arguments) = Self::arguments(&mut queue) else {
+            self.issues.push(XRayShaderHlslIssue::error_at(
+              &token,
+              format!("unterminated call of macro `{}`", token.text),
+            ));
+            continue;
+          };
+
+          let is_empty_call: bool = parameters.is_empty() && arguments.len() == 1 && arguments[0].is_empty();
+
+          if !is_empty_call && arguments.len() != parameters.len() {
+            self.issues.push(XRayShaderHlslIssue::error_at(
+              &token,
+              format!(
+                "macro `{}` expects {} arguments, got {}",
+                token.text,
+                parameters.len(),
+                arguments.len()
+              ),
+            ));
+            continue;
+          }
+
+          self.substitute(&token, &definition.body, parameters, &arguments)
+        }
+      };
+
+      let mut replacement_hidden: Vec<String> = hidden;
+
+      replacement_hidden.push(token.text.clone());
+
+      for replaced in replacement.into_iter().rev() {
+        queue.push_front((replaced, replacement_hidden.clone()));
+      }
+    }
+
+    expanded
+  }
+
+  /// Take a parenthesized argument list off the queue, splitting on top-level commas.
+  fn arguments(queue: &mut VecDeque<XRayShaderHlslQueuedToken>) -> Option<Vec<Vec<XRayShaderHlslToken>>> {
+    let mut arguments: Vec<Vec<XRayShaderHlslToken>> = vec![Vec::new()];
+    let mut depth: usize = 0;
+
+    while let Some((token, _)) = queue.pop_front() {
+      if token.is("(") {
+        depth += 1;
+
+        if depth == 1 {
+          continue;
+        }
+      } else if token.is(")") {
+        depth -= 1;
+
+        if depth == 0 {
+          return Some(arguments);
+        }
+      } else if token.is(",") && depth == 1 {
+        arguments.push(Vec::new());
+        continue;
+      }
+
+      if let Some(argument) = arguments.last_mut() {
+        argument.push(token);
+      }
+    }
+
+    None
+  }
+
+  fn substitute(
+    &mut self,
+    invocation: &XRayShaderHlslToken,
+    body: &[XRayShaderHlslToken],
+    parameters: &[String],
+    arguments: &[Vec<XRayShaderHlslToken>],
+  ) -> Vec<XRayShaderHlslToken> {
+    let parameter = |token: &XRayShaderHlslToken| -> Option<usize> {
+      token
+        .is_identifier()
+        .then(|| parameters.iter().position(|name| *name == token.text))
+        .flatten()
+    };
+    let mut substituted: Vec<XRayShaderHlslToken> = Vec::new();
+    let mut index: usize = 0;
+
+    while index < body.len() {
+      let token: &XRayShaderHlslToken = &body[index];
+
+      if token.is("#")
+        && let Some(position) = body.get(index + 1).and_then(parameter)
+      {
+        substituted.push(Self::relocated(
+          &XRayShaderHlslToken::new(
+            XRayShaderHlslTokenKind::String,
+            format!("\"{}\"", Self::join(&arguments[position])),
+            0,
+            0,
+            0,
+          ),
+          invocation,
+        ));
+        index += 2;
+        continue;
+      }
+
+      match parameter(token) {
+        Some(position) => {
+          let is_pasted: bool =
+            body.get(index + 1).is_some_and(|next| next.is("##")) || (index > 0 && body[index - 1].is("##"));
+          let argument: Vec<XRayShaderHlslToken> = if is_pasted {
+            arguments[position].clone()
+          } else {
+            self.expand(arguments[position].clone())
+          };
+
+          substituted.extend(argument.iter().map(|value| Self::relocated(value, invocation)));
+        }
+        None => substituted.push(Self::relocated(token, invocation)),
+      }
+
+      index += 1;
+    }
+
+    Self::paste(substituted)
+  }
+
+  /// Apply `##` operators, re-classifying the glued tokens.
+  fn paste(tokens: Vec<XRayShaderHlslToken>) -> Vec<XRayShaderHlslToken> {
+    let mut pasted: Vec<XRayShaderHlslToken> = Vec::new();
+    let mut is_pasting: bool = false;
+
+    for token in tokens {
+      if token.is("##") {
+        is_pasting = true;
+      } else if is_pasting && let Some(previous) = pasted.last_mut() {
+        previous.text.push_str(&token.text);
+        previous.kind = match previous.text.chars().next() {
+          Some(character) if character.is_ascii_alphabetic() || character == '_' => XRayShaderHlslTokenKind::Identifier,
+          Some(character) if character.is_ascii_digit() => XRayShaderHlslTokenKind::Number,
+          _ => previous.kind,
+        };
+        is_pasting = false;
+      } else {
+        pasted.push(token);
+        is_pasting = false;
+      }
+    }
+
+    pasted
+  }
+
+  /// Macro output is reported where the macro was used.
+  fn relocated(token: &XRayShaderHlslToken, invocation: &XRayShaderHlslToken) -> XRayShaderHlslToken {
+    XRayShaderHlslToken::new(
+      token.kind,
+      token.text.clone(),
+      invocation.line,
+      invocation.column,
+      invocation.logical_line,
+    )
+  }
+
+  fn join(tokens: &[XRayShaderHlslToken]) -> String {
+    tokens
+      .iter()
+      .map(|token| token.text.as_str())
+      .collect::<Vec<&str>>()
+      .join(" ")
+  }
+}
+
+/// Integer constant expression of `#if` and `#elif`, after `defined` and macros are resolved.
+struct XRayShaderHlslConditionEvaluator<'a> {
+  tokens: &'a [XRayShaderHlslToken],
+  index: usize,
+}
+
+impl XRayShaderHlslConditionEvaluator<'_> {
+  fn evaluate(&mut self) -> Result<i64, String> {
+    let value: i64 = self.conditional()?;
+
+    match self.tokens.get(self.index) {
+      None => Ok(value),
+      Some(token) => Err(format!("unexpected `{}` in preprocessor condition", token.text)),
+    }
+  }
+
+  fn conditional(&mut self) -> Result<i64, String> {
+    let condition: i64 = self.binary(0)?;
+
+    if !self.take("?") {
+      return Ok(condition);
+    }
+
+    let then: i64 = self.conditional()?;
+
+    if !self.take(":") {
+      return Err(String::from("expected `:` in preprocessor condition"));
+    }
+
+    let otherwise: i64 = self.conditional()?;
+
+    Ok(if condition != 0 { then } else { otherwise })
+  }
+
+  fn binary(&mut self, minimum_precedence: u8) -> Result<i64, String> {
+    let mut left: i64 = self.unary()?;
+
+    while let Some(operator) = self.tokens.get(self.index).map(|token| token.text.clone()) {
+      let Some(precedence) = Self::precedence(&operator) else {
+        break;
+      };
+
+      if precedence < minimum_precedence {
+        break;
+      }
+
+      self.index += 1;
+
+      let right: i64 = self.binary(precedence + 1)?;
+
+      left = match operator.as_str() {
+        "||" => i64::from(left != 0 || right != 0),
+        "&&" => i64::from(left != 0 && right != 0),
+        "|" => left | right,
+        "^" => left ^ right,
+        "&" => left & right,
+        "==" => i64::from(left == right),
+        "!=" => i64::from(left != right),
+        "<" => i64::from(left < right),
+        ">" => i64::from(left > right),
+        "<=" => i64::from(left <= right),
+        ">=" => i64::from(left >= right),
+        "<<" => left.wrapping_shl(right as u32),
+        ">>" => left.wrapping_shr(right as u32),
+        "+" => left.wrapping_add(right),
+        "-" => left.wrapping_sub(right),
+        "*" => left.wrapping_mul(right),
+        "/" | "%" if right == 0 => return Err(String::from("division by zero in preprocessor condition")),
+        "/" => left.wrapping_div(right),
+        _ => left.wrapping_rem(right),
+      };
+    }
+
+    Ok(left)
+  }
+
+  fn unary(&mut self) -> Result<i64, String> {
+    let Some(token) = self.tokens.get(self.index) else {
+      return Err(String::from("unexpected end of preprocessor condition"));
+    };
+
+    self.index += 1;
+
+    match token.text.as_str() {
+      "!" => Ok(i64::from(self.unary()? == 0)),
+      "~" => Ok(!self.unary()?),
+      "-" => Ok(self.unary()?.wrapping_neg()),
+      "+" => self.unary(),
+      "(" => {
+        let value: i64 = self.conditional()?;
+
+        if self.take(")") {
+          Ok(value)
+        } else {
+          Err(String::from("expected `)` in preprocessor condition"))
+        }
+      }
+      _ if token.is_identifier() => Ok(0),
+      _ if token.kind == XRayShaderHlslTokenKind::Number => Self::number(&token.text),
+      other => Err(format!("unexpected `{other}` in preprocessor condition")),
+    }
+  }
+
+  fn number(text: &str) -> Result<i64, String> {
+    let digits: &str = text.trim_end_matches(['u', 'U', 'l', 'L']);
+    let parsed = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+      Some(hex) => i64::from_str_radix(hex, 16),
+      None if digits.len() > 1 && digits.starts_with('0') => i64::from_str_radix(&digits[1..], 8),
+      None => digits.parse::<i64>(),
+    };
+
+    parsed.map_err(|_| format!("invalid integer `{text}` in preprocessor condition"))
+  }
+
+  fn precedence(operator: &str) -> Option<u8> {
+    match operator {
+      "||" => Some(1),
+      "&&" => Some(2),
+      "|" => Some(3),
+      "^" => Some(4),
+      "&" => Some(5),
+      "==" | "!=" => Some(6),
+      "<" | ">" | "<=" | ">=" => Some(7),
+      "<<" | ">>" => Some(8),
+      "+" | "-" => Some(9),
+      "*" | "/" | "%" => Some(10),
+      _ => None,
+    }
+  }
+
+  fn take(&mut self, text: &str) -> bool {
+    if self.tokens.get(self.index).is_some_and(|token| token.is(text)) {
+      self.index += 1;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+  use crate::xray_shader_hlsl_preprocessor::XRayShaderHlslPreprocessor;
+  use crate::xray_shader_hlsl_token::XRayShaderHlslToken;
+
+  fn preprocess(source: &str, defines: &[(&str, &str)]) -> (String, Vec<XRayShaderHlslIssue>) {
+    let defines: Vec<(String, String)> = defines
+      .iter()
+      .map(|(name, value)| (String::from(*name), String::from(*value)))
+      .collect();
+    let (tokens, issues) =
+      XRayShaderHlslPreprocessor::preprocess(&XRayShaderHlslToken::lex(source).expect("source is lexed"), &defines);
+
+    (
+      tokens
+        .iter()
+        .map(|token| token.text.as_str())
+        .collect::<Vec<&str>>()
+        .join(" "),
+      issues,
+    )
+  }
+
+  #[test]
+  fn selects_conditional_blocks_for_define_sets() {
+    let source: &str = "#if defined(USE_MSAA) && MSAA_SAMPLES > 2\nmsaa\n#elif SMAP_size == 2048\nsmap\n#else\nnone\n\
+                        #endif\n#ifndef USE_MSAA\nsingle\n#endif\n";
+
+    assert_eq!(
+      preprocess(source, &[("USE_MSAA", "1"), ("MSAA_SAMPLES", "4")]),
+      (String::from("msaa"), Vec::new())
+    );
+    assert_eq!(
+      preprocess(source, &[("SMAP_size", "2048")]),
+      (String::from("smap single"), Vec::new())
+    );
+    assert_eq!(preprocess(source, &[]), (String::from("none single"), Vec::new()));
+  }
+
+  #[test]
+  fn expands_object_and_function_macros() {
+    let source: &str = "#define SCALE 2.0\n#define MUL(a, b) ((a) * (b))\n#define NAME(x) s_ ## x\n\
+                        #define TEXT(x) #x\n#define SELF SELF + 1\nMUL(SCALE, f(1, 2))\nNAME(base) TEXT(a b) SELF\n\
+                        #undef SCALE\nSCALE\n";
+
+    assert_eq!(
+      preprocess(source, &[]),
+      (
+        String::from("( ( 2.0 ) * ( f ( 1 , 2 ) ) ) s_base \"a b\" SELF + 1 SCALE"),
+        Vec::new()
+      )
+    );
+  }
+
+  #[test]
+  fn reports_directive_errors() {
+    let (_, issues) = preprocess("#if 1\n#error broken build\n#endif\n#endif\n#foo\n#ifdef A\n", &[]);
+
+    let messages: Vec<(usize, &str)> = issues
+      .iter()
+      .map(|issue| (issue.line, issue.message.as_str()))
+      .collect();
+
+    assert_eq!(
+      messages,
+      vec![
+        (2, "#error broken build"),
+        (4, "#endif without #if"),
+        (5, "unknown preprocessor directive `#foo`"),
+        (6, "unterminated conditional directive"),
+      ]
+    );
+  }
+}
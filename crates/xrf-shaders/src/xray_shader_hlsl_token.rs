@@ -0,0 +1,251 @@
+use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+
+/// Kind of a lexed HLSL token.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum XRayShaderHlslTokenKind {
+  Identifier,
+  Number,
+  String,
+  Punctuation,
+}
+
+/// One HLSL token with its 1-based position in the expanded source.
+///
+/// `logical_line` only advances on line breaks without a `\` continuation, so preprocessor directives can be told apart
+/// from the code that follows them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XRayShaderHlslToken {
+  pub kind: XRayShaderHlslTokenKind,
+  pub text: String,
+  pub line: usize,
+  pub column: usize,
+  pub logical_line: usize,
+}
+
+/// Punctuators, longest first so the lexer can take the first match.
+const PUNCTUATORS: &[&str] = &[
+  "<<=", ">>=", "##", "++", "--", "&&", "||", "==", "!=", "<=", ">=", "<<", ">>", "+=", "-=", "*=", "/=", "%=", "&=",
+  "|=", "^=", "::", "#", "+", "-", "*", "/", "%", "&", "|", "^", "~", "!", "<", ">", "=", "?", ":", ";", ",", ".", "(",
+  ")", "[", "]", "{", "}",
+];
+
+impl XRayShaderHlslToken {
+  pub fn is(&self, text: &str) -> bool {
+    self.kind != XRayShaderHlslTokenKind::String && self.text == text
+  }
+
+  pub fn is_identifier(&self) -> bool {
+    self.kind == XRayShaderHlslTokenKind::Identifier
+  }
+
+  pub fn is_literal(&self) -> bool {
+    matches!(
+      self.kind,
+      XRayShaderHlslTokenKind::Number | XRayShaderHlslTokenKind::String
+    )
+  }
+
+  /// Split source text into tokens, dropping comments and whitespace.
+  pub fn lex(source: &str) -> Result<Vec<Self>, XRayShaderHlslIssue> {
+    let characters: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<Self> = Vec::new();
+    let mut index: usize = 0;
+    let mut line: usize = 1;
+    let mut line_start: usize = 0;
+    let mut logical_line: usize = 1;
+
+    while index < characters.len() {
+      let character: char = characters[index];
+      let column: usize = index - line_start + 1;
+
+      if character == '\n' {
+        index += 1;
+        line += 1;
+        logical_line += 1;
+        line_start = index;
+      } else if character == '\\' && Self::is_line_continuation(&characters, index) {
+        while characters[index] != '\n' {
+          index += 1;
+        }
+
+        index += 1;
+        line += 1;
+        line_start = index;
+      } else if character.is_whitespace() {
+        index += 1;
+      } else if character == '/' && characters.get(index + 1) == Some(&'/') {
+        while index < characters.len() && characters[index] != '\n' {
+          index += 1;
+        }
+      } else if character == '/' && characters.get(index + 1) == Some(&'*') {
+        index += 2;
+
+        loop {
+          match characters.get(index) {
+            None => return Err(XRayShaderHlslIssue::error(line, column, "unterminated block comment")),
+            Some('*') if characters.get(index + 1) == Some(&'/') => {
+              index += 2;
+              break;
+            }
+            Some('\n') => {
+              index += 1;
+              line += 1;
+              logical_line += 1;
+              line_start = index;
+            }
+            Some(_) => index += 1,
+          }
+        }
+      } else if character == '"' {
+        let start: usize = index;
+
+        index += 1;
+
+        while index < characters.len() && characters[index] != '"' {
+          if characters[index] == '\n' {
+            return Err(XRayShaderHlslIssue::error(line, column, "unterminated string literal"));
+          }
+
+          index += if characters[index] == '\\' { 2 } else { 1 };
+        }
+
+        index += 1;
+        tokens.push(Self::new(
+          XRayShaderHlslTokenKind::String,
+          characters[start..index.min(characters.len())].iter().collect(),
+          line,
+          column,
+          logical_line,
+        ));
+      } else if character.is_ascii_alphabetic() || character == '_' {
+        let start: usize = index;
+
+        while index < characters.len() && (characters[index].is_ascii_alphanumeric() || characters[index] == '_') {
+          index += 1;
+        }
+
+        tokens.push(Self::new(
+          XRayShaderHlslTokenKind::Identifier,
+          characters[start..index].iter().collect(),
+          line,
+          column,
+          logical_line,
+        ));
+      } else if character.is_ascii_digit()
+        || (character == '.' && characters.get(index + 1).is_some_and(char::is_ascii_digit))
+      {
+        let start: usize = index;
+
+        while index < characters.len() {
+          let current: char = characters[index];
+          let is_exponent_sign: bool = matches!(current, '+' | '-')
+            && matches!(characters[index - 1], 'e' | 'E')
+            && !characters[start..index].iter().any(|value| matches!(value, 'x' | 'X'));
+
+          if current.is_ascii_alphanumeric() || current == '.' || current == '_' || is_exponent_sign {
+            index += 1;
+          } else {
+            break;
+          }
+        }
+
+        tokens.push(Self::new(
+          XRayShaderHlslTokenKind::Number,
+          characters[start..index].iter().collect(),
+          line,
+          column,
+          logical_line,
+        ));
+      } else if let Some(punctuator) = PUNCTUATORS
+        .iter()
+        .find(|punctuator| Self::starts_with(&characters, index, punctuator))
+      {
+        index += punctuator.len();
+        tokens.push(Self::new(
+          XRayShaderHlslTokenKind::Punctuation,
+          String::from(*punctuator),
+          line,
+          column,
+          logical_line,
+        ));
+      } else {
+        return Err(XRayShaderHlslIssue::error(
+          line,
+          column,
+          format!("unexpected character `{character}`"),
+        ));
+      }
+    }
+
+    Ok(tokens)
+  }
+
+  pub fn new(kind: XRayShaderHlslTokenKind, text: String, line: usize, column: usize, logical_line: usize) -> Self {
+    Self {
+      kind,
+      text,
+      line,
+      column,
+      logical_line,
+    }
+  }
+
+  fn is_line_continuation(characters: &[char], index: usize) -> bool {
+    characters[index + 1..]
+      .iter()
+      .take_while(|character| **character != '\n')
+      .all(|character| character.is_whitespace())
+      && characters[index + 1..].contains(&'\n')
+  }
+
+  fn starts_with(characters: &[char], index: usize, text: &str) -> bool {
+    text
+      .chars()
+      .enumerate()
+      .all(|(offset, character)| characters.get(index + offset) == Some(&character))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::xray_shader_hlsl_issue::XRayShaderHlslIssue;
+  use crate::xray_shader_hlsl_token::{XRayShaderHlslToken, XRayShaderHlslTokenKind};
+
+  #[test]
+  fn lexes_tokens_with_positions_and_continuations() {
+    let tokens: Vec<XRayShaderHlslToken> =
+      XRayShaderHlslToken::lex("#define A \\\n  1.5e-3f // note\n/* a\n b */ x <<= y[0];\n").expect("source is lexed");
+
+    let summary: Vec<(&str, usize, usize)> = tokens
+      .iter()
+      .map(|token| (token.text.as_str(), token.line, token.logical_line))
+      .collect();
+
+    assert_eq!(
+      summary,
+      vec![
+        ("#", 1, 1),
+        ("define", 1, 1),
+        ("A", 1, 1),
+        ("1.5e-3f", 2, 1),
+        ("x", 4, 3),
+        ("<<=", 4, 3),
+        ("y", 4, 3),
+        ("[", 4, 3),
+        ("0", 4, 3),
+        ("]", 4, 3),
+        (";", 4, 3),
+      ]
+    );
+    assert_eq!(tokens[3].kind, XRayShaderHlslTokenKind::Number);
+    assert_eq!(tokens[4].column, 7);
+  }
+
+  #[test]
+  fn reports_unterminated_comment() {
+    assert_eq!(
+      XRayShaderHlslToken::lex("float a;\n/* open"),
+      Err(XRayShaderHlslIssue::error(2, 1, "unterminated block comment"))
+    );
+  }
+}
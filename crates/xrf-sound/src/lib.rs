@@ -1,6 +1,10 @@
 pub(crate) mod sound_file;
 pub(crate) mod sound_file_metadata;
+pub(crate) mod sound_file_patch;
 pub(crate) mod sound_file_vorbis;
+pub(crate) mod sound_parameters_table;
 
 pub use crate::sound_file::SoundFile;
 pub use crate::sound_file_metadata::{SoundMetadata, XRaySoundCommentVersion, XRaySoundParameters};
+pub use crate::sound_file_patch::SoundPatchReport;
+pub use crate::sound_parameters_table::{SoundParametersRow, SoundParametersTable};
@@ -1,19 +1,24 @@
 use std::fs::File;
 use std::io::{Cursor, Read, Seek};
 use std::path::Path;
+use std::time::Duration;
 
 use ogg::reading::PacketReader;
 use xrf_error::{XrfError, XrfResult};
 
 use crate::SoundMetadata;
 use crate::sound_file_metadata::read_sound_metadata;
-use crate::sound_file_vorbis::{VorbisHeaders, decode_vorbis_stream, parse_identification_packet, read_vorbis_headers};
+use crate::sound_file_vorbis::{
+  VorbisHeaders, decode_vorbis_stream, parse_identification_packet, read_vorbis_headers, read_vorbis_samples_count,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SoundFile {
   pub channels: u16,
   pub metadata: SoundMetadata,
   pub sample_rate: u32,
+  /// Samples per channel the stream plays, read off the granule position of its last page.
+  pub samples_count: u64,
 }
 
 impl SoundFile {
@@ -51,6 +56,11 @@ impl SoundFile {
       .map_err(|error| XrfError::new_verify_error(format!("Failed to read sound from memory: {error}")))
   }
 
+  /// How long the sound plays.
+  pub fn get_duration(&self) -> Duration {
+    Duration::from_secs_f64(self.samples_count as f64 / self.sample_rate as f64)
+  }
+
   fn read_from_path_with_strictness<P>(path: P, is_strict: bool) -> XrfResult<Self>
   where
     P: AsRef<Path>,
@@ -79,13 +89,16 @@ where
   let (channels, sample_rate): (u16, u32) = parse_identification_packet(&headers.identification)?;
   let metadata: SoundMetadata = read_sound_metadata(&headers.comment)?;
 
-  if is_strict {
-    decode_vorbis_stream(&mut reader, &headers)?;
-  }
+  let samples_count: u64 = if is_strict {
+    decode_vorbis_stream(&mut reader, &headers)?
+  } else {
+    read_vorbis_samples_count(&mut reader, &headers)
+  };
 
   Ok(SoundFile {
     channels,
     metadata,
     sample_rate,
+    samples_count,
   })
 }
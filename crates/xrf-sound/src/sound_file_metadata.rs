@@ -1,3 +1,6 @@
+/// Signature of the Vorbis comment header packet, which the comment list follows.
+const VORBIS_COMMENT_SIGNATURE: &[u8] = b"\x03vorbis";
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum XRaySoundCommentVersion {
   V1,
@@ -5,6 +8,27 @@ pub enum XRaySoundCommentVersion {
   V3,
 }
 
+impl XRaySoundCommentVersion {
+  /// The version a comment starting with the given number has, if the engine knows it.
+  pub fn from_number(number: u32) -> Option<Self> {
+    match number {
+      0x0001 => Some(Self::V1),
+      0x0002 => Some(Self::V2),
+      0x0003 => Some(Self::V3),
+      _ => None,
+    }
+  }
+
+  /// The version number the comment starts with.
+  pub fn get_number(&self) -> u32 {
+    match self {
+      Self::V1 => 0x0001,
+      Self::V2 => 0x0002,
+      Self::V3 => 0x0003,
+    }
+  }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct XRaySoundParameters {
   pub min_distance: f32,
@@ -14,6 +38,56 @@ pub struct XRaySoundParameters {
   pub max_ai_distance: f32,
 }
 
+impl Default for XRaySoundParameters {
+  /// The parameters the engine gives a sound source with no recognized X-Ray comment.
+  fn default() -> Self {
+    Self {
+      min_distance: 1.0,
+      max_distance: 300.0,
+      base_volume: 1.0,
+      game_type: 0,
+      max_ai_distance: 300.0,
+    }
+  }
+}
+
+impl XRaySoundParameters {
+  /// The parameters as the engine reads them back from a comment of the given version.
+  ///
+  /// Older versions do not store every field: V1 has no base volume and neither V1 nor V2 has an AI
+  /// distance, which the engine then takes from the maximum distance.
+  pub fn as_stored(&self, version: XRaySoundCommentVersion) -> Self {
+    match version {
+      XRaySoundCommentVersion::V1 => Self {
+        base_volume: 1.0,
+        max_ai_distance: self.max_distance,
+        ..self.clone()
+      },
+      XRaySoundCommentVersion::V2 => Self {
+        max_ai_distance: self.max_distance,
+        ..self.clone()
+      },
+      XRaySoundCommentVersion::V3 => self.clone(),
+    }
+  }
+
+  /// Names of the fields a comment of the given version would not keep as they are set.
+  pub fn get_unstored_fields(&self, version: XRaySoundCommentVersion) -> Vec<&'static str> {
+    let stored: Self = self.as_stored(version);
+    let mut fields: Vec<&'static str> = Vec::new();
+
+    if stored.base_volume != self.base_volume {
+      fields.push("base_volume");
+    }
+
+    if stored.max_ai_distance != self.max_ai_distance {
+      fields.push("max_ai_distance");
+    }
+
+    fields
+  }
+}
+
 /// Metadata that controls how X-Ray configures a sound source.
 #[derive(Clone, Debug, PartialEq)]
 pub enum SoundMetadata {
@@ -61,11 +135,8 @@ fn parse_xray_comment(comment: &[u8]) -> Result<Option<(XRaySoundCommentVersion,
   let mut offset: usize = 0;
 
   let version: u32 = read_u32(comment, &mut offset, "X-Ray comment version")?;
-  let comment_version: XRaySoundCommentVersion = match version {
-    0x0001 => XRaySoundCommentVersion::V1,
-    0x0002 => XRaySoundCommentVersion::V2,
-    0x0003 => XRaySoundCommentVersion::V3,
-    _ => return Ok(None),
+  let Some(comment_version) = XRaySoundCommentVersion::from_number(version) else {
+    return Ok(None);
   };
 
   let min_distance: f32 = read_f32(comment, &mut offset, "minimum distance")?;
@@ -97,6 +168,97 @@ fn parse_xray_comment(comment: &[u8]) -> Result<Option<(XRaySoundCommentVersion,
     max_ai_distance,
   };
 
+  validate_xray_parameters(&parameters)?;
+
+  Ok(Some((comment_version, parameters)))
+}
+
+/// Serialize X-Ray sound parameters the way a comment of the given version lays them out.
+///
+/// Parameters the engine would refuse to read are refused here, so a patch cannot write them.
+pub fn write_xray_comment(
+  version: XRaySoundCommentVersion,
+  parameters: &XRaySoundParameters,
+) -> Result<Vec<u8>, String> {
+  validate_xray_parameters(&parameters.as_stored(version))?;
+
+  let mut comment: Vec<u8> = Vec::with_capacity(24);
+
+  comment.extend(version.get_number().to_le_bytes());
+  comment.extend(parameters.min_distance.to_le_bytes());
+  comment.extend(parameters.max_distance.to_le_bytes());
+
+  if version != XRaySoundCommentVersion::V1 {
+    comment.extend(parameters.base_volume.to_le_bytes());
+  }
+
+  comment.extend(parameters.game_type.to_le_bytes());
+
+  if version == XRaySoundCommentVersion::V3 {
+    comment.extend(parameters.max_ai_distance.to_le_bytes());
+  }
+
+  Ok(comment)
+}
+
+/// Rebuild a Vorbis comment header packet with the given X-Ray comment first.
+///
+/// An existing X-Ray comment is replaced, anything else in the first place is moved down one, and
+/// the vendor string, the other comments and the framing bit are kept as they were.
+pub fn rebuild_comment_packet(comment_packet: &[u8], xray_comment: &[u8]) -> Result<Vec<u8>, String> {
+  let packet: &[u8] = comment_packet
+    .strip_prefix(VORBIS_COMMENT_SIGNATURE)
+    .ok_or_else(|| String::from("Ogg stream does not contain a Vorbis comment packet"))?;
+  let mut offset: usize = 0;
+
+  let vendor_length: usize = read_u32(packet, &mut offset, "vendor length")? as usize;
+  let vendor: &[u8] = read_bytes(packet, &mut offset, vendor_length, "vendor string")?;
+  let comments_count: u32 = read_u32(packet, &mut offset, "comments count")?;
+  let mut comments: Vec<&[u8]> = Vec::with_capacity(comments_count as usize + 1);
+
+  for _ in 0..comments_count {
+    let comment_length: usize = read_u32(packet, &mut offset, "comment length")? as usize;
+
+    comments.push(read_bytes(packet, &mut offset, comment_length, "comment")?);
+  }
+
+  if comments.first().is_some_and(|comment| is_xray_comment(comment)) {
+    comments[0] = xray_comment;
+  } else {
+    comments.insert(0, xray_comment);
+  }
+
+  let mut rebuilt: Vec<u8> = Vec::with_capacity(comment_packet.len() + xray_comment.len() + 4);
+
+  rebuilt.extend(VORBIS_COMMENT_SIGNATURE);
+  rebuilt.extend((vendor.len() as u32).to_le_bytes());
+  rebuilt.extend(vendor);
+  rebuilt.extend((comments.len() as u32).to_le_bytes());
+
+  for comment in comments {
+    rebuilt.extend((comment.len() as u32).to_le_bytes());
+    rebuilt.extend(comment);
+  }
+
+  match &packet[offset..] {
+    [] => rebuilt.push(0x01),
+    trailing => rebuilt.extend(trailing),
+  }
+
+  Ok(rebuilt)
+}
+
+/// Whether a comment starts with a version the engine reads as X-Ray parameters.
+///
+/// A text comment starts with printable characters, which never make a version this small.
+fn is_xray_comment(comment: &[u8]) -> bool {
+  comment
+    .get(0..4)
+    .and_then(|version| XRaySoundCommentVersion::from_number(u32::from_le_bytes(version.try_into().unwrap())))
+    .is_some()
+}
+
+fn validate_xray_parameters(parameters: &XRaySoundParameters) -> Result<(), String> {
   if !parameters.min_distance.is_finite()
     || !parameters.max_distance.is_finite()
     || !parameters.base_volume.is_finite()
@@ -111,7 +273,7 @@ fn parse_xray_comment(comment: &[u8]) -> Result<Option<(XRaySoundCommentVersion,
     ));
   }
 
-  Ok(Some((comment_version, parameters)))
+  Ok(())
 }
 
 fn read_u32(bytes: &[u8], offset: &mut usize, field: &str) -> Result<u32, String> {
@@ -141,7 +303,10 @@ fn read_bytes<'a>(bytes: &'a [u8], offset: &mut usize, length: usize, field: &st
 
 #[cfg(test)]
 mod tests {
-  use super::{XRaySoundCommentVersion, XRaySoundParameters, first_vorbis_comment, parse_xray_comment};
+  use super::{
+    XRaySoundCommentVersion, XRaySoundParameters, first_vorbis_comment, parse_xray_comment, rebuild_comment_packet,
+    write_xray_comment,
+  };
 
   fn xray_comment_v3(
     min_distance: f32,
@@ -177,6 +342,32 @@ mod tests {
     );
   }
 
+  #[test]
+  fn lists_fields_older_versions_cannot_store() {
+    let parameters: XRaySoundParameters = XRaySoundParameters {
+      min_distance: 1.0,
+      max_distance: 50.0,
+      base_volume: 0.75,
+      game_type: 7,
+      max_ai_distance: 30.0,
+    };
+
+    assert_eq!(
+      parameters.get_unstored_fields(XRaySoundCommentVersion::V1),
+      vec!["base_volume", "max_ai_distance"]
+    );
+    assert_eq!(
+      parameters.get_unstored_fields(XRaySoundCommentVersion::V2),
+      vec!["max_ai_distance"]
+    );
+    assert!(parameters.get_unstored_fields(XRaySoundCommentVersion::V3).is_empty());
+    assert!(
+      XRaySoundParameters::default()
+        .get_unstored_fields(XRaySoundCommentVersion::V1)
+        .is_empty()
+    );
+  }
+
   #[test]
   fn rejects_xray_comment_with_invalid_distances() {
     let error: String = parse_xray_comment(&xray_comment_v3(1.0, 0.0, 1.0, 0, 0.0))
@@ -212,4 +403,74 @@ mod tests {
 
     assert_eq!(first_vorbis_comment(&comment_packet).unwrap(), None);
   }
+
+  #[test]
+  fn writes_each_xray_comment_version_readably() {
+    let parameters: XRaySoundParameters = XRaySoundParameters {
+      min_distance: 2.0,
+      max_distance: 40.0,
+      base_volume: 0.5,
+      game_type: 3,
+      max_ai_distance: 25.0,
+    };
+
+    for (version, length) in [
+      (XRaySoundCommentVersion::V1, 16),
+      (XRaySoundCommentVersion::V2, 20),
+      (XRaySoundCommentVersion::V3, 24),
+    ] {
+      let comment: Vec<u8> = write_xray_comment(version, &parameters).unwrap();
+
+      assert_eq!(comment.len(), length);
+      assert_eq!(
+        parse_xray_comment(&comment).unwrap(),
+        Some((version, parameters.as_stored(version)))
+      );
+    }
+  }
+
+  #[test]
+  fn refuses_to_write_parameters_the_engine_rejects() {
+    let parameters: XRaySoundParameters = XRaySoundParameters {
+      max_ai_distance: 0.0,
+      ..XRaySoundParameters::default()
+    };
+
+    assert!(write_xray_comment(XRaySoundCommentVersion::V3, &parameters).is_err());
+    // V2 takes the AI distance from the maximum distance, so the same parameters are fine there.
+    assert!(write_xray_comment(XRaySoundCommentVersion::V2, &parameters).is_ok());
+  }
+
+  #[test]
+  fn rebuilds_comment_packet_replacing_or_inserting_the_xray_comment() {
+    let xray: Vec<u8> = xray_comment_v3(1.0, 50.0, 1.0, 0, 50.0);
+    let packet = |comments: &[&[u8]]| -> Vec<u8> {
+      let mut packet: Vec<u8> = b"\x03vorbis".to_vec();
+      packet.extend(6u32.to_le_bytes());
+      packet.extend(b"vendor");
+      packet.extend((comments.len() as u32).to_le_bytes());
+
+      for comment in comments {
+        packet.extend((comment.len() as u32).to_le_bytes());
+        packet.extend(*comment);
+      }
+
+      packet.push(0x01);
+      packet
+    };
+
+    assert_eq!(
+      rebuild_comment_packet(&packet(&[b"TITLE=shot"]), &xray).unwrap(),
+      packet(&[&xray, b"TITLE=shot"])
+    );
+    assert_eq!(
+      rebuild_comment_packet(
+        &packet(&[&xray_comment_v3(2.0, 9.0, 1.0, 0, 9.0), b"TITLE=shot"]),
+        &xray
+      )
+      .unwrap(),
+      packet(&[&xray, b"TITLE=shot"])
+    );
+    assert_eq!(rebuild_comment_packet(&packet(&[]), &xray).unwrap(), packet(&[&xray]));
+  }
 }
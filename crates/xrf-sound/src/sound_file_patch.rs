@@ -0,0 +1,399 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use ogg::Packet as OggPacket;
+use ogg::reading::PacketReader;
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::sound_file::SoundFile;
+use crate::sound_file_metadata::{
+  SoundMetadata, XRaySoundCommentVersion, XRaySoundParameters, read_sound_metadata, rebuild_comment_packet,
+  write_xray_comment,
+};
+use crate::sound_file_vorbis::{VorbisHeaders, read_vorbis_headers};
+
+static STAGED_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Outcome of a guarded X-Ray comment patch.
+///
+/// Returned instead of logging so callers own their own output format, and so a dry run can report
+/// exactly what a real run would have written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SoundPatchReport {
+  /// Size of the source file before patching.
+  pub original_size: usize,
+  /// Size of the patched stream, written unless the patch was a dry run.
+  pub patched_size: usize,
+  /// Metadata the sound carried before patching.
+  pub previous: SoundMetadata,
+  /// Metadata the patched sound carries, as the engine reads it back.
+  pub patched: SoundMetadata,
+  /// Whether the patched stream was actually written to the destination.
+  pub is_dry_run: bool,
+}
+
+impl SoundFile {
+  /// Rewrite or insert the X-Ray comment of a sound held in memory, without re-encoding it.
+  ///
+  /// Only the Vorbis comment header packet is rebuilt. The stream is paged again around it, but every
+  /// audio packet is copied as it was, with the granule position of the page it ended on.
+  ///
+  /// # Errors
+  ///
+  /// Returns a verify error for a stream that is not a readable Ogg/Vorbis sound, for parameters the
+  /// engine would refuse, and for a patched stream that does not read back as requested.
+  pub fn patch_bytes(
+    bytes: &[u8],
+    version: XRaySoundCommentVersion,
+    parameters: &XRaySoundParameters,
+  ) -> XrfResult<Vec<u8>> {
+    patch_xray_comment(bytes, version, parameters)
+      .map_err(|error| XrfError::new_verify_error(format!("Failed to patch sound: {error}")))
+  }
+
+  /// Rewrite or insert the X-Ray comment of a sound file, writing the result into destination.
+  ///
+  /// The destination may be the source itself. Nothing is written before the patched stream has been
+  /// verified, and the stream is staged beside the destination before replacing it, so a failure or an
+  /// interrupted write leaves the previous file as it was.
+  ///
+  /// # Errors
+  ///
+  /// Returns an IO error when either file cannot be accessed, and the errors of [`Self::patch_bytes`].
+  pub fn patch_to_path<S, D>(
+    source: S,
+    destination: D,
+    version: XRaySoundCommentVersion,
+    parameters: &XRaySoundParameters,
+    is_dry_run: bool,
+  ) -> XrfResult<SoundPatchReport>
+  where
+    S: AsRef<Path>,
+    D: AsRef<Path>,
+  {
+    let source: &Path = source.as_ref();
+    let destination: &Path = destination.as_ref();
+
+    let original: Vec<u8> = fs::read(source)?;
+    let previous: SoundMetadata = Self::read_from_bytes(&original)?.metadata;
+    let patched: Vec<u8> = patch_xray_comment(&original, version, parameters)
+      .map_err(|error| XrfError::new_verify_error(format!("Failed to patch sound {}: {error}", source.display())))?;
+
+    let report: SoundPatchReport = SoundPatchReport {
+      original_size: original.len(),
+      patched_size: patched.len(),
+      previous,
+      patched: SoundMetadata::XRay {
+        version,
+        parameters: parameters.as_stored(version),
+      },
+      is_dry_run,
+    };
+
+    if is_dry_run {
+      return Ok(report);
+    }
+
+    if let Some(parent) = destination.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    write_staged(destination, &patched)?;
+
+    Ok(report)
+  }
+}
+
+/// Write a file beside the destination, then rename it over the destination.
+fn write_staged(destination: &Path, bytes: &[u8]) -> XrfResult {
+  let file_name: &OsStr = destination
+    .file_name()
+    .ok_or_else(|| XrfError::new_invalid_error(format!("File has no name: {}", destination.display())))?;
+  let staged: PathBuf = destination.with_file_name(format!(
+    ".{}.xrf-tmp-{}-{}",
+    file_name.to_string_lossy(),
+    std::process::id(),
+    STAGED_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+  ));
+
+  let result: XrfResult = fs::write(&staged, bytes)
+    .and_then(|_| fs::rename(&staged, destination))
+    .map_err(Into::into);
+
+  if result.is_err() {
+    let _ = fs::remove_file(&staged);
+  }
+
+  result
+}
+
+fn patch_xray_comment(
+  bytes: &[u8],
+  version: XRaySoundCommentVersion,
+  parameters: &XRaySoundParameters,
+) -> Result<Vec<u8>, String> {
+  let mut reader: PacketReader<Cursor<&[u8]>> = PacketReader::new(Cursor::new(bytes));
+  let headers: VorbisHeaders = read_vorbis_headers(&mut reader)?;
+  let comment: Vec<u8> = rebuild_comment_packet(&headers.comment, &write_xray_comment(version, parameters)?)?;
+  let mut writer: PacketWriter<Vec<u8>> = PacketWriter::new(Vec::with_capacity(bytes.len() + comment.len()));
+
+  // The identification header sits alone on the first page and audio starts on a fresh one.
+  for (packet, end_info) in [
+    (headers.identification, PacketWriteEndInfo::EndPage),
+    (comment.clone(), PacketWriteEndInfo::NormalPacket),
+    (headers.setup, PacketWriteEndInfo::EndPage),
+  ] {
+    writer
+      .write_packet(packet, headers.stream_serial, end_info, 0)
+      .map_err(|error| format!("Could not write Vorbis header packet: {error}"))?;
+  }
+
+  while let Some(packet) = read_packet(&mut reader)? {
+    let end_info: PacketWriteEndInfo = if packet.last_in_stream() {
+      PacketWriteEndInfo::EndStream
+    } else if packet.last_in_page() {
+      PacketWriteEndInfo::EndPage
+    } else {
+      PacketWriteEndInfo::NormalPacket
+    };
+    let stream_serial: u32 = packet.stream_serial();
+    let absgp: u64 = packet.absgp_page();
+
+    writer
+      .write_packet(packet.data, stream_serial, end_info, absgp)
+      .map_err(|error| format!("Could not write Ogg/Vorbis packet: {error}"))?;
+  }
+
+  let patched: Vec<u8> = writer.into_inner();
+
+  assert_patch_is_lossless(bytes, &patched, &comment)?;
+
+  Ok(patched)
+}
+
+/// Guard that the patched stream differs from the source in the comment header and nothing else.
+fn assert_patch_is_lossless(original: &[u8], patched: &[u8], comment: &[u8]) -> Result<(), String> {
+  let mut original_reader: PacketReader<Cursor<&[u8]>> = PacketReader::new(Cursor::new(original));
+  let mut patched_reader: PacketReader<Cursor<&[u8]>> = PacketReader::new(Cursor::new(patched));
+
+  let original_headers: VorbisHeaders = read_vorbis_headers(&mut original_reader)?;
+  let patched_headers: VorbisHeaders = read_vorbis_headers(&mut patched_reader)?;
+
+  if patched_headers.comment != comment
+    || patched_headers.identification != original_headers.identification
+    || patched_headers.setup != original_headers.setup
+    || patched_headers.stream_serial != original_headers.stream_serial
+  {
+    return Err(String::from("Patched Vorbis headers do not read back as written"));
+  }
+
+  read_sound_metadata(&patched_headers.comment)?;
+
+  loop {
+    match (read_packet(&mut original_reader)?, read_packet(&mut patched_reader)?) {
+      (None, None) => return Ok(()),
+      (Some(original), Some(patched))
+        if original.data == patched.data && original.stream_serial() == patched.stream_serial() => {}
+      _ => return Err(String::from("Patched audio packets do not match the source")),
+    }
+  }
+}
+
+fn read_packet(reader: &mut PacketReader<Cursor<&[u8]>>) -> Result<Option<OggPacket>, String> {
+  reader
+    .read_packet()
+    .map_err(|error| format!("Could not read Ogg/Vorbis packet: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::io::Cursor;
+
+  use ogg::reading::PacketReader;
+  use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+  use xrf_error::XrfResult;
+  use xrf_test_utils::utils::build_absolute_generated_test_resource_path;
+
+  use crate::sound_file::SoundFile;
+  use crate::sound_file_metadata::{SoundMetadata, XRaySoundCommentVersion, XRaySoundParameters, write_xray_comment};
+  use crate::sound_file_patch::SoundPatchReport;
+
+  const STREAM_SERIAL: u32 = 0x5852_4631;
+
+  /// Build a sound with valid Vorbis headers and stand-in audio packets spread over two pages.
+  fn sound(comments: &[&[u8]]) -> Vec<u8> {
+    let mut identification: Vec<u8> = b"\x01vorbis".to_vec();
+    identification.extend(0u32.to_le_bytes());
+    identification.push(1);
+    identification.extend(44_100u32.to_le_bytes());
+    identification.extend([0; 13]);
+
+    let mut comment: Vec<u8> = b"\x03vorbis".to_vec();
+    comment.extend(4u32.to_le_bytes());
+    comment.extend(b"test");
+    comment.extend((comments.len() as u32).to_le_bytes());
+
+    for it in comments {
+      comment.extend((it.len() as u32).to_le_bytes());
+      comment.extend(*it);
+    }
+
+    comment.push(0x01);
+
+    let mut writer: PacketWriter<Vec<u8>> = PacketWriter::new(Vec::new());
+
+    writer
+      .write_packet(identification, STREAM_SERIAL, PacketWriteEndInfo::EndPage, 0)
+      .unwrap();
+    writer
+      .write_packet(comment, STREAM_SERIAL, PacketWriteEndInfo::NormalPacket, 0)
+      .unwrap();
+    writer
+      .write_packet(
+        b"\x05vorbis setup".to_vec(),
+        STREAM_SERIAL,
+        PacketWriteEndInfo::EndPage,
+        0,
+      )
+      .unwrap();
+
+    for (index, end_info, absgp) in [
+      (0u8, PacketWriteEndInfo::NormalPacket, 256),
+      (1, PacketWriteEndInfo::EndPage, 256),
+      (2, PacketWriteEndInfo::NormalPacket, 1024),
+      (3, PacketWriteEndInfo::EndStream, 1024),
+    ] {
+      writer
+        .write_packet(vec![index; 300 + index as usize], STREAM_SERIAL, end_info, absgp)
+        .unwrap();
+    }
+
+    writer.into_inner()
+  }
+
+  fn audio_packets(bytes: &[u8]) -> Vec<(Vec<u8>, u64, bool)> {
+    let mut reader: PacketReader<Cursor<&[u8]>> = PacketReader::new(Cursor::new(bytes));
+    let mut packets: Vec<(Vec<u8>, u64, bool)> = Vec::new();
+
+    while let Some(packet) = reader.read_packet().unwrap() {
+      let (absgp, is_last_in_page): (u64, bool) = (packet.absgp_page(), packet.last_in_page());
+
+      packets.push((packet.data, absgp, is_last_in_page));
+    }
+
+    packets.split_off(3)
+  }
+
+  fn parameters() -> XRaySoundParameters {
+    XRaySoundParameters {
+      min_distance: 2.0,
+      max_distance: 60.0,
+      base_volume: 0.8,
+      game_type: 4,
+      max_ai_distance: 15.0,
+    }
+  }
+
+  #[test]
+  fn inserts_xray_comment_keeping_audio_untouched() -> XrfResult {
+    let original: Vec<u8> = sound(&[b"TITLE=footstep"]);
+
+    assert_eq!(
+      SoundFile::read_from_bytes(&original)?.metadata,
+      SoundMetadata::EngineDefaults
+    );
+
+    let patched: Vec<u8> = SoundFile::patch_bytes(&original, XRaySoundCommentVersion::V3, &parameters())?;
+
+    assert_eq!(
+      SoundFile::read_from_bytes(&patched)?.metadata,
+      SoundMetadata::XRay {
+        version: XRaySoundCommentVersion::V3,
+        parameters: parameters(),
+      }
+    );
+    assert_eq!(audio_packets(&patched), audio_packets(&original));
+    assert_eq!(SoundFile::read_from_bytes(&patched)?.samples_count, 1024);
+
+    Ok(())
+  }
+
+  #[test]
+  fn replaces_existing_xray_comment_with_older_version() -> XrfResult {
+    let existing: Vec<u8> = write_xray_comment(XRaySoundCommentVersion::V3, &XRaySoundParameters::default()).unwrap();
+    let original: Vec<u8> = sound(&[&existing, b"TITLE=footstep"]);
+    let patched: Vec<u8> = SoundFile::patch_bytes(&original, XRaySoundCommentVersion::V1, &parameters())?;
+    let repatched: Vec<u8> = SoundFile::patch_bytes(&patched, XRaySoundCommentVersion::V1, &parameters())?;
+
+    assert_eq!(
+      SoundFile::read_from_bytes(&patched)?.metadata,
+      SoundMetadata::XRay {
+        version: XRaySoundCommentVersion::V1,
+        parameters: parameters().as_stored(XRaySoundCommentVersion::V1),
+      }
+    );
+    // The comment is replaced rather than stacked, so patching twice is the same as patching once.
+    assert_eq!(repatched, patched);
+
+    Ok(())
+  }
+
+  #[test]
+  fn refuses_parameters_the_engine_rejects() {
+    let invalid: XRaySoundParameters = XRaySoundParameters {
+      max_distance: f32::NAN,
+      ..parameters()
+    };
+
+    assert!(SoundFile::patch_bytes(&sound(&[]), XRaySoundCommentVersion::V3, &invalid).is_err());
+    assert!(SoundFile::patch_bytes(b"not an ogg", XRaySoundCommentVersion::V3, &parameters()).is_err());
+  }
+
+  #[test]
+  fn patches_file_into_destination_unless_dry_run() -> XrfResult {
+    let source = build_absolute_generated_test_resource_path("sound_file_patch/footstep.ogg");
+    let destination = build_absolute_generated_test_resource_path("sound_file_patch/patched/footstep.ogg");
+
+    fs::create_dir_all(source.parent().unwrap())?;
+    fs::write(&source, sound(&[]))?;
+
+    if destination.exists() {
+      fs::remove_file(&destination)?;
+    }
+
+    let report: SoundPatchReport =
+      SoundFile::patch_to_path(&source, &destination, XRaySoundCommentVersion::V2, &parameters(), true)?;
+
+    assert!(report.is_dry_run);
+    assert_eq!(report.previous, SoundMetadata::EngineDefaults);
+    assert!(!destination.exists());
+
+    let report: SoundPatchReport =
+      SoundFile::patch_to_path(&source, &destination, XRaySoundCommentVersion::V2, &parameters(), false)?;
+
+    assert_eq!(report.patched_size, fs::read(&destination)?.len());
+    assert_eq!(SoundFile::read_from_path(&destination)?.metadata, report.patched);
+
+    SoundFile::patch_to_path(
+      &destination,
+      &destination,
+      XRaySoundCommentVersion::V3,
+      &parameters(),
+      false,
+    )?;
+
+    assert_eq!(
+      fs::read_dir(destination.parent().unwrap())?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<Result<Vec<_>, _>>()?,
+      [destination.file_name().unwrap()]
+    );
+
+    Ok(())
+  }
+}
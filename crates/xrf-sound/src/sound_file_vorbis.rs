@@ -76,7 +76,8 @@ pub fn parse_identification_packet(packet: &[u8]) -> Result<(u16, u32), String>
   Ok((channels, sample_rate))
 }
 
-pub fn decode_vorbis_stream<R>(reader: &mut PacketReader<R>, headers: &VorbisHeaders) -> Result<(), String>
+/// Decode every audio packet of the stream, returning the samples count it ends at.
+pub fn decode_vorbis_stream<R>(reader: &mut PacketReader<R>, headers: &VorbisHeaders) -> Result<u64, String>
 where
   R: Read + Seek,
 {
@@ -90,6 +91,7 @@ where
   let mut decoder = get_codecs()
     .make_audio_decoder(&codec_parameters, &AudioDecoderOptions::default())
     .map_err(|error| format!("Could not initialize Vorbis decoder: {error}"))?;
+  let mut samples_count: u64 = 0;
 
   loop {
     let packet: OggPacket = match reader.read_packet() {
@@ -102,6 +104,8 @@ where
       continue;
     }
 
+    samples_count = read_packet_samples_count(&packet).unwrap_or(samples_count);
+
     let packet: Packet = Packet::new(0, Timestamp::ZERO, Duration::ZERO, packet.data);
 
     decoder
@@ -109,7 +113,33 @@ where
       .map_err(|error| format!("Could not decode Vorbis audio packet: {error}"))?;
   }
 
-  Ok(())
+  Ok(samples_count)
+}
+
+/// Read the samples count of the stream off the granule position of its last page, without decoding.
+///
+/// Stops at the first page that cannot be read: a lenient read only vouches for the headers, so a
+/// damaged tail shortens the sound rather than failing it.
+pub fn read_vorbis_samples_count<R>(reader: &mut PacketReader<R>, headers: &VorbisHeaders) -> u64
+where
+  R: Read + Seek,
+{
+  let mut samples_count: u64 = 0;
+
+  while let Ok(Some(packet)) = reader.read_packet() {
+    if packet.stream_serial() == headers.stream_serial {
+      samples_count = read_packet_samples_count(&packet).unwrap_or(samples_count);
+    }
+  }
+
+  samples_count
+}
+
+/// The granule position of the page a packet ends on, which for Vorbis counts samples per channel.
+///
+/// A page no packet ends on carries no position, written as all bits set.
+fn read_packet_samples_count(packet: &OggPacket) -> Option<u64> {
+  Some(packet.absgp_page()).filter(|absgp| *absgp != u64::MAX)
 }
 
 fn read_expected_packet<R>(reader: &mut PacketReader<R>, packet_name: &str) -> Result<OggPacket, String>
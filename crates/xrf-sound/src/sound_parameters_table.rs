@@ -0,0 +1,355 @@
+use serde::{Deserialize, Serialize};
+use xrf_error::{XrfError, XrfResult};
+use xrf_utils::{CsvTableReader, ParametersTable, write_csv_table};
+
+use crate::sound_file::SoundFile;
+use crate::sound_file_metadata::{SoundMetadata, XRaySoundCommentVersion, XRaySoundParameters};
+
+/// Columns of a CSV table, in the order they are written.
+const CSV_COLUMNS: [&str; 10] = [
+  "path",
+  "channels",
+  "sample_rate",
+  "duration",
+  "comment_version",
+  "min_distance",
+  "max_distance",
+  "base_volume",
+  "game_type",
+  "max_ai_distance",
+];
+
+/// Columns a CSV table cannot leave out, the descriptive ones and the comment version being optional.
+const CSV_REQUIRED_COLUMNS: [&str; 6] = [
+  "path",
+  "min_distance",
+  "max_distance",
+  "base_volume",
+  "game_type",
+  "max_ai_distance",
+];
+
+/// One sound of a parameters table: what the file is, and the X-Ray parameters it carries.
+///
+/// Channels, sample rate and duration describe the audio and are only there to sort and filter by,
+/// applying a table reads nothing but the path and the parameters.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SoundParametersRow {
+  /// Logical path of the sound, `sounds\weapons\ak74_shot.ogg`.
+  pub path: String,
+  #[serde(default)]
+  pub channels: u16,
+  #[serde(default)]
+  pub sample_rate: u32,
+  /// Seconds the sound plays.
+  #[serde(default)]
+  pub duration: f64,
+  /// Version of the X-Ray comment, none for a sound the engine gives its default parameters.
+  #[serde(default)]
+  pub comment_version: Option<u32>,
+  pub min_distance: f32,
+  pub max_distance: f32,
+  pub base_volume: f32,
+  pub game_type: u32,
+  pub max_ai_distance: f32,
+}
+
+impl SoundParametersRow {
+  pub fn new(path: &str, sound: &SoundFile) -> Self {
+    let (comment_version, parameters): (Option<u32>, XRaySoundParameters) = match &sound.metadata {
+      SoundMetadata::EngineDefaults => (None, XRaySoundParameters::default()),
+      SoundMetadata::XRay { version, parameters } => (Some(version.get_number()), parameters.clone()),
+    };
+
+    Self {
+      path: path.to_owned(),
+      channels: sound.channels,
+      sample_rate: sound.sample_rate,
+      duration: sound.get_duration().as_secs_f64(),
+      comment_version,
+      min_distance: parameters.min_distance,
+      max_distance: parameters.max_distance,
+      base_volume: parameters.base_volume,
+      game_type: parameters.game_type,
+      max_ai_distance: parameters.max_ai_distance,
+    }
+  }
+
+  /// The comment version the row asks for, none when it leaves the choice to the sound.
+  ///
+  /// # Errors
+  ///
+  /// Returns an invalid error for a version the engine does not read.
+  pub fn get_comment_version(&self) -> XrfResult<Option<XRaySoundCommentVersion>> {
+    self
+      .comment_version
+      .map(|number| {
+        XRaySoundCommentVersion::from_number(number).ok_or_else(|| {
+          XrfError::new_invalid_error(format!(
+            "Sound '{}' asks for unknown X-Ray comment version {number}",
+            self.path
+          ))
+        })
+      })
+      .transpose()
+  }
+
+  pub fn get_parameters(&self) -> XRaySoundParameters {
+    XRaySoundParameters {
+      min_distance: self.min_distance,
+      max_distance: self.max_distance,
+      base_volume: self.base_volume,
+      game_type: self.game_type,
+      max_ai_distance: self.max_ai_distance,
+    }
+  }
+
+  /// Comment version and parameters to write onto a sound carrying `current`, none when the engine
+  /// would read the same parameters from it as it does now.
+  ///
+  /// A row without a comment version keeps the one the sound has, raised to 3 when that one cannot
+  /// store a parameter of the row, and a sound left on engine defaults stays without a comment as
+  /// long as its row still lists the defaults.
+  ///
+  /// # Errors
+  ///
+  /// Returns an invalid error for an unknown comment version, or one that cannot store a parameter
+  /// set by the row.
+  pub fn resolve_patch(
+    &self,
+    current: &SoundMetadata,
+  ) -> XrfResult<Option<(XRaySoundCommentVersion, XRaySoundParameters)>> {
+    let parameters: XRaySoundParameters = self.get_parameters();
+
+    let version: XRaySoundCommentVersion = match (self.get_comment_version()?, current) {
+      (Some(version), _) => {
+        let unstored: Vec<&str> = parameters.get_unstored_fields(version);
+
+        if !unstored.is_empty() {
+          return Err(XrfError::new_invalid_error(format!(
+            "X-Ray comment version {} cannot store {} of sound '{}', set comment_version to 3 to keep it",
+            version.get_number(),
+            unstored.join(", "),
+            self.path
+          )));
+        }
+
+        version
+      }
+      (None, SoundMetadata::XRay { version, .. }) if parameters.get_unstored_fields(*version).is_empty() => *version,
+      (None, SoundMetadata::EngineDefaults) if parameters == XRaySoundParameters::default() => return Ok(None),
+      (None, _) => XRaySoundCommentVersion::V3,
+    };
+
+    let requested: SoundMetadata = SoundMetadata::XRay {
+      version,
+      parameters: parameters.as_stored(version),
+    };
+
+    if *current == requested {
+      Ok(None)
+    } else {
+      Ok(Some((version, parameters)))
+    }
+  }
+}
+
+/// Parameters of many sounds, exported to be edited in a spreadsheet and applied back.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SoundParametersTable {
+  pub rows: Vec<SoundParametersRow>,
+}
+
+impl ParametersTable for SoundParametersTable {
+  const TABLE_NAME: &'static str = "sound parameters table";
+
+  /// Read a CSV table by its header, so columns can be moved or the descriptive ones dropped.
+  fn from_csv(content: &str) -> XrfResult<Self> {
+    let reader: CsvTableReader = CsvTableReader::parse(content, Self::TABLE_NAME, &CSV_REQUIRED_COLUMNS)?;
+    let mut rows: Vec<SoundParametersRow> = Vec::new();
+
+    for row in reader.rows() {
+      rows.push(SoundParametersRow {
+        path: row.value("path").to_owned(),
+        channels: row.number("channels")?.unwrap_or_default(),
+        sample_rate: row.number("sample_rate")?.unwrap_or_default(),
+        duration: row.number("duration")?.unwrap_or_default(),
+        comment_version: row.number("comment_version")?,
+        min_distance: row.required_number("min_distance")?,
+        max_distance: row.required_number("max_distance")?,
+        base_volume: row.required_number("base_volume")?,
+        game_type: row.required_number("game_type")?,
+        max_ai_distance: row.required_number("max_ai_distance")?,
+      });
+    }
+
+    Ok(Self { rows })
+  }
+
+  fn to_csv(&self) -> String {
+    write_csv_table(
+      &CSV_COLUMNS,
+      self.rows.iter().map(|row| {
+        vec![
+          row.path.clone(),
+          row.channels.to_string(),
+          row.sample_rate.to_string(),
+          format!("{:.3}", row.duration),
+          row.comment_version.map(|it| it.to_string()).unwrap_or_default(),
+          row.min_distance.to_string(),
+          row.max_distance.to_string(),
+          row.base_volume.to_string(),
+          row.game_type.to_string(),
+          row.max_ai_distance.to_string(),
+        ]
+      }),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+  use xrf_test_utils::utils::build_absolute_generated_test_resource_path;
+  use xrf_utils::ParametersTable;
+
+  use crate::sound_file_metadata::{SoundMetadata, XRaySoundCommentVersion, XRaySoundParameters};
+  use crate::sound_parameters_table::{SoundParametersRow, SoundParametersTable};
+
+  fn table() -> SoundParametersTable {
+    SoundParametersTable {
+      rows: vec![
+        SoundParametersRow {
+          path: String::from("sounds\\actor\\step, dirt.ogg"),
+          channels: 1,
+          sample_rate: 44_100,
+          duration: 0.25,
+          comment_version: Some(3),
+          min_distance: 1.0,
+          max_distance: 40.0,
+          base_volume: 0.75,
+          game_type: 134_217_856,
+          max_ai_distance: 12.5,
+        },
+        SoundParametersRow {
+          path: String::from("sounds\\ambient\\wind.ogg"),
+          channels: 2,
+          sample_rate: 22_050,
+          duration: 12.0,
+          comment_version: None,
+          min_distance: 1.0,
+          max_distance: 300.0,
+          base_volume: 1.0,
+          game_type: 0,
+          max_ai_distance: 300.0,
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn round_trips_csv_and_json() -> XrfResult {
+    assert_eq!(SoundParametersTable::from_csv(&table().to_csv())?, table());
+    assert_eq!(SoundParametersTable::from_json(&table().to_json()?)?, table());
+
+    let path = build_absolute_generated_test_resource_path("sound_parameters_table/sounds.csv");
+
+    table().write_to_path(&path)?;
+
+    assert_eq!(SoundParametersTable::read_from_path(&path)?, table());
+
+    Ok(())
+  }
+
+  #[test]
+  fn reads_csv_columns_by_header() -> XrfResult {
+    let table: SoundParametersTable = SoundParametersTable::from_csv(
+      "\u{feff}max_ai_distance,path,min_distance,max_distance,base_volume,game_type,comment_version\r\n\
+       8,sounds\\actor\\step.ogg,1,20,1,0,2\r\n\r\n",
+    )?;
+    let row: &SoundParametersRow = &table.rows[0];
+
+    assert_eq!(table.rows.len(), 1);
+    assert_eq!(row.path, "sounds\\actor\\step.ogg");
+    assert_eq!(row.get_comment_version()?, Some(XRaySoundCommentVersion::V2));
+    assert_eq!(
+      row.get_parameters(),
+      XRaySoundParameters {
+        min_distance: 1.0,
+        max_distance: 20.0,
+        base_volume: 1.0,
+        game_type: 0,
+        max_ai_distance: 8.0,
+      }
+    );
+
+    Ok(())
+  }
+
+  #[test]
+  fn refuses_incomplete_csv() {
+    assert!(SoundParametersTable::from_csv("path,min_distance\nsounds\\a.ogg,1\n").is_err());
+    assert!(
+      SoundParametersTable::from_csv(
+        "path,min_distance,max_distance,base_volume,game_type,max_ai_distance\nsounds\\a.ogg,1,far,1,0,5\n"
+      )
+      .is_err()
+    );
+    assert!(SoundParametersTable::read_from_path("sounds.xlsx").is_err());
+  }
+
+  #[test]
+  fn refuses_edited_ai_distance_on_v2_row() -> XrfResult {
+    let stored: XRaySoundParameters = XRaySoundParameters {
+      min_distance: 1.0,
+      max_distance: 40.0,
+      base_volume: 0.75,
+      game_type: 0,
+      max_ai_distance: 40.0,
+    };
+    let current: SoundMetadata = SoundMetadata::XRay {
+      version: XRaySoundCommentVersion::V2,
+      parameters: stored.clone(),
+    };
+    let mut row: SoundParametersRow = SoundParametersRow {
+      path: String::from("sounds\\actor\\step.ogg"),
+      channels: 1,
+      sample_rate: 44_100,
+      duration: 0.25,
+      comment_version: Some(2),
+      min_distance: stored.min_distance,
+      max_distance: stored.max_distance,
+      base_volume: stored.base_volume,
+      game_type: stored.game_type,
+      max_ai_distance: stored.max_ai_distance,
+    };
+
+    assert_eq!(row.resolve_patch(&current)?, None);
+
+    row.max_ai_distance = 12.5;
+
+    let error: String = row
+      .resolve_patch(&current)
+      .expect_err("Expected edited AI distance to be refused for version 2")
+      .to_string();
+
+    assert!(error.contains("max_ai_distance"), "{error}");
+
+    row.comment_version = None;
+
+    assert_eq!(
+      row.resolve_patch(&current)?,
+      Some((XRaySoundCommentVersion::V3, row.get_parameters()))
+    );
+
+    row.comment_version = Some(3);
+
+    assert_eq!(
+      row.resolve_patch(&current)?,
+      Some((XRaySoundCommentVersion::V3, row.get_parameters()))
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+use xrf_dds::ImageFormat;
+use xrf_output::OutputOptions;
+
+pub struct BuildAtlasOptions {
+  /// Directory of png sprites, each one named by its texture id.
+  pub source: PathBuf,
+  /// Texture name of the atlas as the description refers to it, such as `ui\ui_hud_custom`.
+  pub name: String,
+  pub output: OutputOptions,
+  /// Textures root the atlas name is resolved against.
+  pub output_path: PathBuf,
+  /// Texture description xml to write.
+  pub description_path: PathBuf,
+  /// Largest atlas side, a power of two. Sprites that do not fit spill into further atlases.
+  pub max_size: u32,
+  /// Transparent pixels kept between sprites, so filtering does not bleed neighbours into each other.
+  pub padding: u32,
+  pub dds_compression_format: ImageFormat,
+}
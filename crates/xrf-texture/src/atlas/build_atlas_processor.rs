@@ -0,0 +1,408 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use image::{GenericImage, ImageReader, RgbaImage};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::atlas::{BuildAtlasOptions, BuildAtlasPage, BuildAtlasResult, SkylinePacker};
+use crate::constants::{DDS_EXTENSION, PNG_EXTENSION, UI_MIPMAPS};
+use crate::data::{TextureFileDescriptor, TextureSpriteDescriptor};
+use crate::description::XmlDescriptionCollection;
+use crate::save_image_as_ui_dds;
+
+/// Smallest atlas side considered, one compression block.
+const ATLAS_MINIMUM_SIZE: u32 = 4;
+
+struct AtlasSprite {
+  id: String,
+  image: RgbaImage,
+}
+
+/// Sprites assigned to one atlas, with their top left corners.
+struct AtlasLayout {
+  width: u32,
+  height: u32,
+  placements: Vec<(usize, u32, u32)>,
+}
+
+/// Lays out loose sprites into new ui atlases and describes them for the engine.
+///
+/// Unlike [`crate::PackDescriptionProcessor`], which rebuilds an atlas from an existing description,
+/// this decides the layout itself.
+pub struct BuildAtlasProcessor {}
+
+impl BuildAtlasProcessor {
+  /// Pack every png of the source directory into power of two atlases and write their description.
+  ///
+  /// Sprites are packed tallest first into the smallest atlas holding all of them. Once an atlas reaches
+  /// `max_size` on both sides, whatever does not fit goes on to the next one, named with an index
+  /// suffix.
+  ///
+  /// # Errors
+  ///
+  /// Fails when options are invalid, when a sprite cannot be read or is larger than an atlas, or when
+  /// writing fails.
+  pub fn build(options: &BuildAtlasOptions) -> XrfResult<BuildAtlasResult> {
+    if !options.max_size.is_power_of_two() || options.max_size < ATLAS_MINIMUM_SIZE {
+      return Err(XrfError::new_invalid_error(format!(
+        "Expected atlas size to be a power of two of at least {ATLAS_MINIMUM_SIZE}, got {}",
+        options.max_size
+      )));
+    }
+
+    let sprites: Vec<AtlasSprite> = Self::read_sprites(options)?;
+
+    if sprites.is_empty() {
+      return Err(XrfError::new_not_found_error(format!(
+        "No png sprites found in {}",
+        options.source.display()
+      )));
+    }
+
+    let layouts: Vec<AtlasLayout> = Self::layout(options, &sprites)?;
+    let mut pages: Vec<BuildAtlasPage> = Vec::with_capacity(layouts.len());
+
+    for (index, layout) in layouts.iter().enumerate() {
+      let name: String = if index == 0 {
+        options.name.clone()
+      } else {
+        format!("{}_{}", options.name, index)
+      };
+
+      let saved_at: PathBuf = Self::get_atlas_path(&options.output_path, &name);
+      let mut image: RgbaImage = RgbaImage::new(layout.width, layout.height);
+      let mut file: TextureFileDescriptor = TextureFileDescriptor::new(name);
+
+      for (sprite_index, x, y) in &layout.placements {
+        let sprite: &AtlasSprite = &sprites[*sprite_index];
+
+        xrf_output::verbose!(
+          options.output,
+          "Placing sprite '{}' ({}x{}) at {}:{} of {}",
+          sprite.id,
+          sprite.image.width(),
+          sprite.image.height(),
+          x,
+          y,
+          file.name
+        );
+
+        image.copy_from(&sprite.image, *x, *y)?;
+        file.add_sprite(TextureSpriteDescriptor::new(
+          sprite.id.as_str(),
+          *x,
+          *y,
+          sprite.image.width(),
+          sprite.image.height(),
+        ));
+      }
+
+      if let Some(parent) = saved_at.parent() {
+        fs::create_dir_all(parent)?;
+      }
+
+      save_image_as_ui_dds(&saved_at, &image, options.dds_compression_format, UI_MIPMAPS)?;
+
+      xrf_output::info!(
+        options.output,
+        "Packed {} sprites into {}x{} atlas {}",
+        file.sprites.len(),
+        layout.width,
+        layout.height,
+        saved_at.display()
+      );
+
+      pages.push(BuildAtlasPage {
+        file,
+        saved_at,
+        width: layout.width,
+        height: layout.height,
+      });
+    }
+
+    XmlDescriptionCollection::write_description(
+      &options.description_path,
+      &pages.iter().map(|page| &page.file).collect::<Vec<_>>(),
+    )?;
+
+    xrf_output::info!(
+      options.output,
+      "Described {} atlases in {}",
+      pages.len(),
+      options.description_path.display()
+    );
+
+    Ok(BuildAtlasResult {
+      pages,
+      description_path: options.description_path.clone(),
+    })
+  }
+
+  /// Path of the dds behind a texture name such as `ui\ui_hud_custom`, relative to the textures root.
+  pub fn get_atlas_path(textures: &Path, name: &str) -> PathBuf {
+    let relative: PathBuf = name.split(['\\', '/']).filter(|part| !part.is_empty()).collect();
+
+    textures.join(format!("{}.{}", relative.display(), DDS_EXTENSION))
+  }
+
+  /// Read png sprites of the source directory sorted by id, so that layouts are reproducible.
+  fn read_sprites(options: &BuildAtlasOptions) -> XrfResult<Vec<AtlasSprite>> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(&options.source)?
+      .flatten()
+      .map(|entry| entry.path())
+      .filter(|path| {
+        path.is_file()
+          && path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case(PNG_EXTENSION))
+      })
+      .collect();
+
+    paths.sort();
+
+    paths
+      .into_iter()
+      .map(|path| {
+        let id: String = path
+          .file_stem()
+          .map(|stem| stem.to_string_lossy().into_owned())
+          .unwrap_or_default();
+        let image: RgbaImage = ImageReader::open(&path)?.decode()?.into_rgba8();
+
+        if !TextureSpriteDescriptor::is_valid_size(image.width(), image.height()) {
+          return Err(XrfError::new_invalid_error(format!(
+            "Sprite {} is empty, expected a non zero size",
+            path.display()
+          )));
+        }
+
+        if image.width() > options.max_size || image.height() > options.max_size {
+          return Err(XrfError::new_invalid_error(format!(
+            "Sprite '{}' of {}x{} does not fit into an atlas of {}x{}",
+            id,
+            image.width(),
+            image.height(),
+            options.max_size,
+            options.max_size
+          )));
+        }
+
+        Ok(AtlasSprite { id, image })
+      })
+      .collect()
+  }
+
+  /// Split sprites into atlases, each as small as the sprites it holds allow.
+  fn layout(options: &BuildAtlasOptions, sprites: &[AtlasSprite]) -> XrfResult<Vec<AtlasLayout>> {
+    let mut remaining: Vec<usize> = (0..sprites.len()).collect();
+    let mut layouts: Vec<AtlasLayout> = Vec::new();
+
+    remaining.sort_by(|a, b| {
+      let (a, b) = (&sprites[*a], &sprites[*b]);
+
+      (b.image.height(), b.image.width(), &a.id).cmp(&(a.image.height(), a.image.width(), &b.id))
+    });
+
+    while !remaining.is_empty() {
+      let (mut width, mut height) = Self::get_initial_size(options, sprites, &remaining);
+
+      loop {
+        let (layout, rest) = Self::layout_page(options, sprites, &remaining, width, height);
+        let is_full_size: bool = width == options.max_size && height == options.max_size;
+
+        if rest.is_empty() || is_full_size {
+          if layout.placements.is_empty() {
+            return Err(XrfError::new_texture_processing_error(format!(
+              "Failed to place any of {} remaining sprites into a {}x{} atlas",
+              rest.len(),
+              width,
+              height
+            )));
+          }
+
+          layouts.push(layout);
+          remaining = rest;
+          break;
+        }
+
+        if width <= height && width < options.max_size {
+          width *= 2;
+        } else {
+          height *= 2;
+        }
+      }
+    }
+
+    Ok(layouts)
+  }
+
+  /// Smallest power of two size holding the largest sprite and the area of all of them.
+  fn get_initial_size(options: &BuildAtlasOptions, sprites: &[AtlasSprite], indices: &[usize]) -> (u32, u32) {
+    let mut width: u32 = ATLAS_MINIMUM_SIZE;
+    let mut height: u32 = ATLAS_MINIMUM_SIZE;
+    let mut area: u64 = 0;
+
+    for index in indices {
+      let image: &RgbaImage = &sprites[*index].image;
+
+      width = width.max(image.width().next_power_of_two());
+      height = height.max(image.height().next_power_of_two());
+      area += u64::from(image.width() + options.padding) * u64::from(image.height() + options.padding);
+    }
+
+    while u64::from(width) * u64::from(height) < area && (width < options.max_size || height < options.max_size) {
+      if width <= height && width < options.max_size {
+        width *= 2;
+      } else {
+        height *= 2;
+      }
+    }
+
+    (width, height)
+  }
+
+  /// Pack as many of the sprites as fit into one atlas, returning the layout and the indices left over.
+  fn layout_page(
+    options: &BuildAtlasOptions,
+    sprites: &[AtlasSprite],
+    indices: &[usize],
+    width: u32,
+    height: u32,
+  ) -> (AtlasLayout, Vec<usize>) {
+    // Every sprite reserves its padding to the right and below, which the sheet edges do not need.
+    let mut packer: SkylinePacker = SkylinePacker::new(width + options.padding, height + options.padding);
+    let mut placements: Vec<(usize, u32, u32)> = Vec::new();
+    let mut rest: Vec<usize> = Vec::new();
+
+    for index in indices {
+      let image: &RgbaImage = &sprites[*index].image;
+
+      match packer.insert(image.width() + options.padding, image.height() + options.padding) {
+        Some((x, y)) => placements.push((*index, x, y)),
+        None => rest.push(*index),
+      }
+    }
+
+    (
+      AtlasLayout {
+        width,
+        height,
+        placements,
+      },
+      rest,
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use image::{Rgba, RgbaImage};
+  use xrf_dds::{DdsFile, ImageFormat};
+  use xrf_test_utils::utils::write_generated_test_resource;
+
+  use super::BuildAtlasProcessor;
+  use crate::utils::test_utils::write_test_image;
+  use crate::{BuildAtlasOptions, BuildAtlasResult};
+
+  fn options_for(name: &str, sprites: &[(&str, u32, u32)], max_size: u32) -> BuildAtlasOptions {
+    let root: PathBuf = write_generated_test_resource(&format!("xrf-texture/atlas/{name}/description.xml"), [])
+      .expect("expect scratch description");
+
+    for (index, (id, width, height)) in sprites.iter().enumerate() {
+      write_test_image(
+        &format!("xrf-texture/atlas/{name}/sprites/{id}.png"),
+        &RgbaImage::from_pixel(*width, *height, Rgba([index as u8 * 60, 0, 0, 255])),
+      );
+    }
+
+    BuildAtlasOptions {
+      source: root.with_file_name("sprites"),
+      name: String::from(r"ui\ui_hud_custom"),
+      output: Default::default(),
+      output_path: root.with_file_name("textures"),
+      description_path: root,
+      max_size,
+      padding: 2,
+      dds_compression_format: ImageFormat::BC3RgbaUnorm,
+    }
+  }
+
+  #[test]
+  fn packs_sprites_into_a_power_of_two_atlas() {
+    let options: BuildAtlasOptions = options_for(
+      "single",
+      &[("icon_a", 20, 12), ("icon_b", 30, 30), ("icon_c", 8, 8)],
+      256,
+    );
+    let result: BuildAtlasResult = BuildAtlasProcessor::build(&options).expect("expect atlas to be built");
+
+    assert_eq!(result.pages.len(), 1);
+
+    let page = &result.pages[0];
+
+    assert!(page.width.is_power_of_two() && page.height.is_power_of_two());
+    assert_eq!(page.saved_at, options.output_path.join("ui").join("ui_hud_custom.dds"));
+
+    let atlas: RgbaImage = DdsFile::read_from_path(&page.saved_at)
+      .expect("expect atlas to be written")
+      .decode_rgba(0)
+      .expect("expect atlas to decode");
+
+    for sprite in &page.file.sprites {
+      assert!(sprite.x + sprite.w <= page.width && sprite.y + sprite.h <= page.height);
+      assert_eq!(
+        atlas.get_pixel(sprite.x, sprite.y).0[3],
+        255,
+        "Expect {} to be drawn",
+        sprite.id
+      );
+
+      for other in page.file.sprites.iter().filter(|other| other.id != sprite.id) {
+        let is_apart: bool = sprite.x + sprite.w + 2 <= other.x
+          || other.x + other.w + 2 <= sprite.x
+          || sprite.y + sprite.h + 2 <= other.y
+          || other.y + other.h + 2 <= sprite.y;
+
+        assert!(is_apart, "Expect {} and {} to be padded apart", sprite.id, other.id);
+      }
+    }
+
+    let description: String = fs::read_to_string(&options.description_path).expect("expect description");
+
+    assert!(description.contains(r#"<file name="ui\ui_hud_custom">"#));
+    assert!(description.contains(r#"<texture id="icon_b" x="0" y="0" width="30" height="30">"#));
+  }
+
+  #[test]
+  fn spills_sprites_over_into_further_atlases() {
+    let options: BuildAtlasOptions = options_for(
+      "spill",
+      &[("icon_a", 30, 30), ("icon_b", 30, 30), ("icon_c", 30, 30)],
+      32,
+    );
+    let result: BuildAtlasResult = BuildAtlasProcessor::build(&options).expect("expect atlases to be built");
+
+    assert_eq!(
+      result
+        .pages
+        .iter()
+        .map(|page| (page.file.name.as_str(), page.file.sprites.len()))
+        .collect::<Vec<_>>(),
+      vec![
+        (r"ui\ui_hud_custom", 1),
+        (r"ui\ui_hud_custom_1", 1),
+        (r"ui\ui_hud_custom_2", 1)
+      ]
+    );
+  }
+
+  #[test]
+  fn rejects_sprites_larger_than_an_atlas() {
+    let options: BuildAtlasOptions = options_for("oversized", &[("icon_a", 40, 8)], 32);
+
+    assert!(BuildAtlasProcessor::build(&options).is_err());
+  }
+}
@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+use crate::data::TextureFileDescriptor;
+
+/// One atlas texture written by the builder.
+pub struct BuildAtlasPage {
+  pub file: TextureFileDescriptor,
+  pub saved_at: PathBuf,
+  pub width: u32,
+  pub height: u32,
+}
+
+pub struct BuildAtlasResult {
+  pub pages: Vec<BuildAtlasPage>,
+  pub description_path: PathBuf,
+}
@@ -0,0 +1,9 @@
+mod build_atlas_options;
+mod build_atlas_processor;
+mod build_atlas_result;
+mod skyline_packer;
+
+pub use build_atlas_options::BuildAtlasOptions;
+pub use build_atlas_processor::BuildAtlasProcessor;
+pub use build_atlas_result::{BuildAtlasPage, BuildAtlasResult};
+pub(crate) use skyline_packer::SkylinePacker;
@@ -0,0 +1,141 @@
+/// Horizontal run of the skyline, the top edge of everything packed below it.
+struct SkylineSegment {
+  x: u32,
+  y: u32,
+  width: u32,
+}
+
+/// Bottom left skyline packer for rectangles on a fixed size sheet.
+///
+/// Only the upper outline of packed rectangles is tracked, so space hidden under an overhang is lost.
+/// Sprites packed tallest first leave little of it, which keeps the packer simple and fast enough to
+/// retry the same set on every candidate sheet size.
+pub(crate) struct SkylinePacker {
+  width: u32,
+  height: u32,
+  skyline: Vec<SkylineSegment>,
+}
+
+impl SkylinePacker {
+  pub(crate) fn new(width: u32, height: u32) -> Self {
+    Self {
+      width,
+      height,
+      skyline: vec![SkylineSegment { x: 0, y: 0, width }],
+    }
+  }
+
+  /// Place a `width`x`height` rectangle as low as possible, then as far left as possible.
+  ///
+  /// Returns the top left corner, or `None` when the rectangle fits nowhere on the sheet.
+  pub(crate) fn insert(&mut self, width: u32, height: u32) -> Option<(u32, u32)> {
+    let mut best: Option<(u32, u32, usize)> = None;
+
+    for index in 0..self.skyline.len() {
+      if let Some(y) = self.fit(index, width, height) {
+        let x: u32 = self.skyline[index].x;
+
+        if best.is_none_or(|(best_y, best_x, _)| (y, x) < (best_y, best_x)) {
+          best = Some((y, x, index));
+        }
+      }
+    }
+
+    let (y, x, index) = best?;
+
+    self.place(index, x, y, width, height);
+
+    Some((x, y))
+  }
+
+  /// Lowest top edge for a rectangle whose left side starts at the segment `index`.
+  fn fit(&self, index: usize, width: u32, height: u32) -> Option<u32> {
+    let x: u32 = self.skyline[index].x;
+
+    if x + width > self.width {
+      return None;
+    }
+
+    let mut y: u32 = 0;
+    let mut covered: u32 = 0;
+
+    for segment in &self.skyline[index..] {
+      if covered >= width {
+        break;
+      }
+
+      y = y.max(segment.y);
+
+      if y + height > self.height {
+        return None;
+      }
+
+      covered += segment.width;
+    }
+
+    Some(y)
+  }
+
+  fn place(&mut self, index: usize, x: u32, y: u32, width: u32, height: u32) {
+    let right: u32 = x + width;
+
+    self.skyline.insert(
+      index,
+      SkylineSegment {
+        x,
+        y: y + height,
+        width,
+      },
+    );
+
+    // Cut the segments now hidden under the placed rectangle.
+    while let Some(segment) = self.skyline.get_mut(index + 1)
+      && segment.x < right
+    {
+      let hidden: u32 = right - segment.x;
+
+      if segment.width <= hidden {
+        self.skyline.remove(index + 1);
+      } else {
+        segment.x += hidden;
+        segment.width -= hidden;
+      }
+    }
+
+    let mut index: usize = 0;
+
+    while index + 1 < self.skyline.len() {
+      if self.skyline[index].y == self.skyline[index + 1].y {
+        self.skyline[index].width += self.skyline[index + 1].width;
+        self.skyline.remove(index + 1);
+      } else {
+        index += 1;
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::SkylinePacker;
+
+  #[test]
+  fn packs_rectangles_bottom_left_without_overlap() {
+    let mut packer: SkylinePacker = SkylinePacker::new(8, 8);
+
+    assert_eq!(packer.insert(4, 4), Some((0, 0)));
+    assert_eq!(packer.insert(4, 2), Some((4, 0)));
+    assert_eq!(packer.insert(4, 2), Some((4, 2)));
+    assert_eq!(packer.insert(8, 4), Some((0, 4)));
+    assert_eq!(packer.insert(1, 1), None);
+  }
+
+  #[test]
+  fn rejects_rectangles_larger_than_the_sheet() {
+    let mut packer: SkylinePacker = SkylinePacker::new(8, 8);
+
+    assert_eq!(packer.insert(9, 1), None);
+    assert_eq!(packer.insert(1, 9), None);
+    assert_eq!(packer.insert(8, 8), Some((0, 0)));
+  }
+}
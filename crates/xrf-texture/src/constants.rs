@@ -28,7 +28,8 @@ pub const INVENTORY_ICON_GRID_SQUARE_BASE: u32 = 50;
 /// Marks a section as owning an inventory icon, gating both packing and unpacking.
 pub const LTX_FIELD_INVENTORY_ICON: &str = "$inventory_icon";
 
-/// Overrides where the icon of a section is read from, instead of `<section>.dds` in the source dir.
+/// Places the icon of a section on another sprite atlas than the equipment sheet, given as a texture
+/// reference such as `ui\ui_icon_equipment_mod`.
 pub const LTX_FIELD_INVENTORY_ICON_PATH: &str = "$inventory_icon_path";
 
 pub const LTX_FIELD_INV_GRID_X: &str = "inv_grid_x";
@@ -62,20 +63,4 @@ pub const DDS_EXTENSION: &str = "dds";
 
 pub const PNG_EXTENSION: &str = "png";
 
-/// Leading token of a custom icon path that resolves against the gamedata root.
-pub const LTX_PATH_GAMEDATA_MARKER: char = '~';
-
-/// The gamedata marker together with the separator that follows it, for stripping the prefix.
-pub const LTX_PATH_GAMEDATA_MARKER_PREFIX: &str = "~\\";
-
-/// Leading token of a custom icon path that resolves against the extensions directory.
-pub const LTX_PATH_EXTENSION_MARKER: char = '#';
-
-/// The extension marker together with the separator that follows it, for stripping the prefix.
-pub const LTX_PATH_EXTENSION_MARKER_PREFIX: &str = "#\\";
-
-pub const RESOURCES_DIRECTORY: &str = "resources";
-
 pub const TEXTURES_DIRECTORY: &str = "textures";
-
-pub const EXTENSIONS_DIRECTORY: &str = "extensions";
@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+use xrf_dds::ImageFormat;
+use xrf_output::OutputOptions;
+
+pub struct ConvertTextureOptions {
+  pub source: PathBuf,
+  pub output_path: PathBuf,
+  pub output: OutputOptions,
+  /// Compression format, BC1 for opaque and BC3 for translucent sources when not set.
+  pub dds_compression_format: Option<ImageFormat>,
+  pub is_mipmapped: bool,
+  /// Whether the texture holds colour, filtered in linear light, rather than data such as normals.
+  pub is_color: bool,
+  /// Alpha test reference whose coverage every mip level keeps, for cut out textures such as foliage.
+  pub alpha_coverage_reference: Option<f32>,
+}
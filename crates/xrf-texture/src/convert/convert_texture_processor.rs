@@ -0,0 +1,158 @@
+use std::path::Path;
+
+use image::{ImageReader, RgbaImage};
+use xrf_dds::{DdsFile, ImageFormat, Quality};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::constants::DDS_EXTENSION;
+use crate::convert::{ConvertTextureOptions, ConvertTextureResult, generate_mipmaps};
+
+pub struct ConvertTextureProcessor {}
+
+impl ConvertTextureProcessor {
+  /// Convert a png, tga or dds source into a dds the engine can load.
+  ///
+  /// # Errors
+  ///
+  /// Fails when the source cannot be read, when the requested format is not one X-Ray reads or when
+  /// encoding fails.
+  pub fn convert(options: &ConvertTextureOptions) -> XrfResult<ConvertTextureResult> {
+    let image: RgbaImage = Self::read_source_image(&options.source)?;
+    let format: ImageFormat = options
+      .dds_compression_format
+      .unwrap_or_else(|| Self::default_format(&image));
+
+    if !Self::is_xray_supported_format(format) {
+      return Err(XrfError::new_texture_processing_error(format!(
+        "Format {format:?} is not one the engine reads, expected BC1, BC2 or BC3"
+      )));
+    }
+
+    let levels: Vec<RgbaImage> = if options.is_mipmapped {
+      generate_mipmaps(&image, options.is_color, options.alpha_coverage_reference)
+    } else {
+      vec![image]
+    };
+
+    xrf_output::verbose!(
+      options.output,
+      "Encoding {} as {:?} with {} mip levels",
+      options.source.display(),
+      format,
+      levels.len()
+    );
+
+    // Normal quality, batches run over whole texture folders and slow encoding triples their time for
+    // differences the engine's filtering hides anyway.
+    let file: DdsFile = DdsFile::encode_rgba_levels(&levels, format, Quality::Normal)?;
+
+    if !file.is_xray_compatible() {
+      return Err(XrfError::new_texture_processing_error(format!(
+        "Encoded texture {} is not compatible with the engine",
+        options.source.display()
+      )));
+    }
+
+    file.write_to_path(&options.output_path)?;
+
+    Ok(ConvertTextureResult {
+      width: levels[0].width(),
+      height: levels[0].height(),
+      mipmap_levels: file.metadata().mipmap_levels,
+      format,
+    })
+  }
+
+  /// Read the base level of a dds, or any image format the `image` crate decodes, as RGBA.
+  pub fn read_source_image(path: &Path) -> XrfResult<RgbaImage> {
+    if path
+      .extension()
+      .is_some_and(|extension| extension.eq_ignore_ascii_case(DDS_EXTENSION))
+    {
+      DdsFile::read_from_path(path)?.decode_rgba(0)
+    } else {
+      Ok(ImageReader::open(path)?.with_guessed_format()?.decode()?.into_rgba8())
+    }
+  }
+
+  /// BC1 for an opaque image and BC3 for one with any translucency, the formats vanilla textures use.
+  pub fn default_format(image: &RgbaImage) -> ImageFormat {
+    if image.pixels().all(|pixel| pixel.0[3] == u8::MAX) {
+      ImageFormat::BC1RgbaUnorm
+    } else {
+      ImageFormat::BC3RgbaUnorm
+    }
+  }
+
+  pub fn is_xray_supported_format(format: ImageFormat) -> bool {
+    matches!(
+      format,
+      ImageFormat::BC1RgbaUnorm
+        | ImageFormat::BC1RgbaUnormSrgb
+        | ImageFormat::BC2RgbaUnorm
+        | ImageFormat::BC2RgbaUnormSrgb
+        | ImageFormat::BC3RgbaUnorm
+        | ImageFormat::BC3RgbaUnormSrgb
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use image::{Rgba, RgbaImage};
+  use xrf_dds::{DdsFile, ImageFormat};
+
+  use super::ConvertTextureProcessor;
+  use crate::utils::test_utils::write_test_image;
+  use crate::{ConvertTextureOptions, ConvertTextureResult};
+
+  fn options_for(name: &str, image: &RgbaImage) -> ConvertTextureOptions {
+    let source: PathBuf = write_test_image(&format!("xrf-texture/convert/{name}-source.png"), image);
+
+    ConvertTextureOptions {
+      output_path: source.with_file_name(format!("{name}-output.dds")),
+      source,
+      output: Default::default(),
+      dds_compression_format: None,
+      is_mipmapped: true,
+      is_color: true,
+      alpha_coverage_reference: None,
+    }
+  }
+
+  #[test]
+  fn converts_a_png_into_a_mipmapped_xray_dds() {
+    let options: ConvertTextureOptions = options_for("opaque", &RgbaImage::from_pixel(16, 8, Rgba([10, 20, 30, 255])));
+    let result: ConvertTextureResult = ConvertTextureProcessor::convert(&options).expect("expect conversion");
+    let output: DdsFile = DdsFile::read_from_path(&options.output_path).expect("expect converted DDS to parse");
+
+    assert_eq!(result.format, ImageFormat::BC1RgbaUnorm);
+    assert_eq!((result.width, result.height, result.mipmap_levels), (16, 8, 5));
+    assert_eq!(output.metadata().mipmap_levels, 5);
+    assert!(output.is_xray_compatible());
+  }
+
+  #[test]
+  fn picks_a_format_with_alpha_for_translucent_sources() {
+    let options: ConvertTextureOptions = options_for("translucent", &RgbaImage::from_pixel(8, 8, Rgba([0, 0, 0, 10])));
+
+    assert_eq!(
+      ConvertTextureProcessor::convert(&options)
+        .expect("expect conversion")
+        .format,
+      ImageFormat::BC3RgbaUnorm
+    );
+  }
+
+  #[test]
+  fn rejects_formats_the_engine_cannot_read() {
+    let mut options: ConvertTextureOptions = options_for("unsupported", &RgbaImage::new(8, 8));
+
+    options.dds_compression_format = Some(ImageFormat::BC7RgbaUnorm);
+
+    assert!(ConvertTextureProcessor::convert(&options).is_err());
+    assert!(!options.output_path.exists());
+  }
+}
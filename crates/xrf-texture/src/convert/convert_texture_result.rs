@@ -0,0 +1,9 @@
+use xrf_dds::ImageFormat;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConvertTextureResult {
+  pub width: u32,
+  pub height: u32,
+  pub mipmap_levels: u32,
+  pub format: ImageFormat,
+}
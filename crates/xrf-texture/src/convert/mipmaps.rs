@@ -0,0 +1,290 @@
+use image::RgbaImage;
+
+/// Iterations of the search for the alpha scale that restores coverage, enough to settle on a single
+/// 8 bit alpha step.
+const ALPHA_COVERAGE_SEARCH_STEPS: u32 = 16;
+
+/// Largest alpha scale the coverage search considers.
+const ALPHA_COVERAGE_MAXIMUM_SCALE: f32 = 64.0;
+
+/// Mip level kept in floating point, so that averaging repeatedly does not accumulate rounding.
+struct MipLevel {
+  width: u32,
+  height: u32,
+  pixels: Vec<[f32; 4]>,
+}
+
+impl MipLevel {
+  fn from_image(image: &RgbaImage, is_color: bool) -> Self {
+    Self {
+      width: image.width(),
+      height: image.height(),
+      pixels: image
+        .pixels()
+        .map(|pixel| {
+          let [red, green, blue, alpha] = pixel.0;
+
+          if is_color {
+            [
+              srgb_to_linear(red),
+              srgb_to_linear(green),
+              srgb_to_linear(blue),
+              f32::from(alpha) / 255.0,
+            ]
+          } else {
+            [red, green, blue, alpha].map(|channel| f32::from(channel) / 255.0)
+          }
+        })
+        .collect(),
+    }
+  }
+
+  fn to_image(&self, is_color: bool, alpha_scale: f32) -> RgbaImage {
+    let mut image: RgbaImage = RgbaImage::new(self.width, self.height);
+
+    for (target, [red, green, blue, alpha]) in image.pixels_mut().zip(&self.pixels) {
+      let alpha: u8 = quantize((alpha * alpha_scale).min(1.0));
+
+      target.0 = if is_color {
+        [
+          linear_to_srgb(*red),
+          linear_to_srgb(*green),
+          linear_to_srgb(*blue),
+          alpha,
+        ]
+      } else {
+        [quantize(*red), quantize(*green), quantize(*blue), alpha]
+      };
+    }
+
+    image
+  }
+
+  /// Box filter down to the next level, each target pixel averaging the source pixels it covers.
+  ///
+  /// Colour is weighted by alpha, so fully transparent texels do not bleed their colour into the
+  /// visible edge of a cut out.
+  fn downsample(&self, is_color: bool) -> Self {
+    let width: u32 = (self.width / 2).max(1);
+    let height: u32 = (self.height / 2).max(1);
+    let mut pixels: Vec<[f32; 4]> = Vec::with_capacity((width * height) as usize);
+
+    for y in 0..height {
+      let (top, bottom) = Self::source_span(y, height, self.height);
+
+      for x in 0..width {
+        let (left, right) = Self::source_span(x, width, self.width);
+        let mut sum: [f32; 4] = [0.0; 4];
+        let mut weighted: [f32; 3] = [0.0; 3];
+
+        for source_y in top..bottom {
+          for source_x in left..right {
+            let pixel: &[f32; 4] = &self.pixels[(source_y * self.width + source_x) as usize];
+
+            for channel in 0..4 {
+              sum[channel] += pixel[channel];
+            }
+
+            for channel in 0..3 {
+              weighted[channel] += pixel[channel] * pixel[3];
+            }
+          }
+        }
+
+        let count: f32 = ((bottom - top) * (right - left)) as f32;
+        let mut pixel: [f32; 4] = sum.map(|channel| channel / count);
+
+        if is_color && sum[3] > 0.0 {
+          for channel in 0..3 {
+            pixel[channel] = weighted[channel] / sum[3];
+          }
+        }
+
+        pixels.push(pixel);
+      }
+    }
+
+    Self { width, height, pixels }
+  }
+
+  fn source_span(target: u32, target_size: u32, source_size: u32) -> (u32, u32) {
+    let start: u32 = target * source_size / target_size;
+
+    (start, ((target + 1) * source_size / target_size).max(start + 1))
+  }
+
+  /// Share of texels passing the alpha test once scaled alpha is stored in 8 bits, as the engine sees it.
+  fn alpha_coverage(&self, reference: f32, alpha_scale: f32) -> f32 {
+    let covered: usize = self
+      .pixels
+      .iter()
+      .filter(|pixel| f32::from(quantize(pixel[3] * alpha_scale)) / 255.0 > reference)
+      .count();
+
+    covered as f32 / self.pixels.len() as f32
+  }
+
+  /// Alpha scale that brings coverage of the level as close to `coverage` as it gets.
+  ///
+  /// Coverage only grows with the scale, so a bisection narrows down the step where it crosses the
+  /// target, and the closer side of that step wins.
+  fn alpha_scale_for_coverage(&self, reference: f32, coverage: f32) -> f32 {
+    let mut lower: f32 = 0.0;
+    let mut upper: f32 = ALPHA_COVERAGE_MAXIMUM_SCALE;
+
+    for _ in 0..ALPHA_COVERAGE_SEARCH_STEPS {
+      let middle: f32 = (lower + upper) / 2.0;
+
+      if self.alpha_coverage(reference, middle) < coverage {
+        lower = middle;
+      } else {
+        upper = middle;
+      }
+    }
+
+    if coverage - self.alpha_coverage(reference, lower) < self.alpha_coverage(reference, upper) - coverage {
+      lower
+    } else {
+      upper
+    }
+  }
+}
+
+/// Build the full mip chain of an image, down to a single texel.
+///
+/// Colour textures are averaged in linear light rather than on their sRGB values, which would darken
+/// every level below the first. Data textures such as normal and bump maps are averaged as stored.
+///
+/// Averaging makes alpha tested texels fade out level by level, which is why distant foliage thins to
+/// nothing. With `alpha_coverage_reference` set, alpha of every level is scaled so that the share of
+/// texels passing the test stays what it is at the base level.
+pub fn generate_mipmaps(image: &RgbaImage, is_color: bool, alpha_coverage_reference: Option<f32>) -> Vec<RgbaImage> {
+  let mut level: MipLevel = MipLevel::from_image(image, is_color);
+  let coverage: Option<(f32, f32)> =
+    alpha_coverage_reference.map(|reference| (reference, level.alpha_coverage(reference, 1.0)));
+  let mut levels: Vec<RgbaImage> = vec![image.clone()];
+
+  while level.width > 1 || level.height > 1 {
+    level = level.downsample(is_color);
+
+    let alpha_scale: f32 = coverage.map_or(1.0, |(reference, coverage)| {
+      level.alpha_scale_for_coverage(reference, coverage)
+    });
+
+    levels.push(level.to_image(is_color, alpha_scale));
+  }
+
+  levels
+}
+
+/// Share of texels of an image passing an alpha test against `reference`.
+pub fn alpha_coverage(image: &RgbaImage, reference: f32) -> f32 {
+  MipLevel::from_image(image, false).alpha_coverage(reference, 1.0)
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+  let value: f32 = f32::from(value) / 255.0;
+
+  if value <= 0.04045 {
+    value / 12.92
+  } else {
+    ((value + 0.055) / 1.055).powf(2.4)
+  }
+}
+
+fn linear_to_srgb(value: f32) -> u8 {
+  let value: f32 = value.clamp(0.0, 1.0);
+
+  quantize(if value <= 0.003_130_8 {
+    value * 12.92
+  } else {
+    1.055 * value.powf(1.0 / 2.4) - 0.055
+  })
+}
+
+fn quantize(value: f32) -> u8 {
+  (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+  use image::{Rgba, RgbaImage};
+
+  use super::{alpha_coverage, generate_mipmaps};
+
+  #[test]
+  fn builds_the_chain_down_to_one_texel() {
+    let levels: Vec<RgbaImage> = generate_mipmaps(&RgbaImage::new(8, 3), true, None);
+
+    assert_eq!(
+      levels.iter().map(RgbaImage::dimensions).collect::<Vec<_>>(),
+      vec![(8, 3), (4, 1), (2, 1), (1, 1)]
+    );
+  }
+
+  #[test]
+  fn averages_colour_in_linear_light() {
+    let image: RgbaImage = RgbaImage::from_fn(2, 1, |x, _| {
+      if x == 0 {
+        Rgba([0, 0, 0, 255])
+      } else {
+        Rgba([255, 255, 255, 255])
+      }
+    });
+
+    // Half of the light is 188 in sRGB, a gamma space average would have given 128.
+    assert_eq!(
+      generate_mipmaps(&image, true, None)[1].get_pixel(0, 0).0,
+      [188, 188, 188, 255]
+    );
+    assert_eq!(
+      generate_mipmaps(&image, false, None)[1].get_pixel(0, 0).0,
+      [128, 128, 128, 255]
+    );
+  }
+
+  #[test]
+  fn keeps_transparent_colour_out_of_the_average() {
+    let image: RgbaImage = RgbaImage::from_fn(2, 1, |x, _| {
+      if x == 0 {
+        Rgba([255, 0, 0, 255])
+      } else {
+        Rgba([0, 0, 255, 0])
+      }
+    });
+
+    assert_eq!(
+      generate_mipmaps(&image, true, None)[1].get_pixel(0, 0).0,
+      [255, 0, 0, 128]
+    );
+  }
+
+  #[test]
+  fn preserves_alpha_coverage() {
+    // Scattered opaque texels, a quarter of them, as on a sheet of grass blades.
+    let image: RgbaImage = RgbaImage::from_fn(16, 16, |x, y| {
+      Rgba([
+        0,
+        128,
+        0,
+        if (x * 31 + y * 17).wrapping_mul(2_654_435_761) >> 30 == 0 {
+          255
+        } else {
+          0
+        },
+      ])
+    });
+
+    let plain: Vec<RgbaImage> = generate_mipmaps(&image, true, None);
+    let preserved: Vec<RgbaImage> = generate_mipmaps(&image, true, Some(0.5));
+
+    let base: f32 = alpha_coverage(&image, 0.5);
+
+    assert!((base - 0.25).abs() < 0.01);
+    assert_eq!(alpha_coverage(&plain[2], 0.5), 0.0);
+
+    for level in &preserved[1..4] {
+      assert!((alpha_coverage(level, 0.5) - base).abs() <= 0.1);
+    }
+  }
+}
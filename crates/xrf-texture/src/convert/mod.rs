@@ -0,0 +1,9 @@
+mod convert_texture_options;
+mod convert_texture_processor;
+mod convert_texture_result;
+mod mipmaps;
+
+pub use convert_texture_options::ConvertTextureOptions;
+pub use convert_texture_processor::ConvertTextureProcessor;
+pub use convert_texture_result::ConvertTextureResult;
+pub use mipmaps::{alpha_coverage, generate_mipmaps};
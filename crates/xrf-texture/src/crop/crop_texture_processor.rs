@@ -71,23 +71,14 @@ mod tests {
   use std::path::PathBuf;
 
   use image::RgbaImage;
-  use xrf_dds::{DdsEncodeOptions, DdsFile, ImageFormat, Mipmaps, Quality};
-  use xrf_test_utils::utils::write_generated_test_resource;
+  use xrf_dds::{DdsFile, ImageFormat, Mipmaps};
 
   use super::CropTextureProcessor;
   use crate::CropTextureOptions;
+  use crate::utils::test_utils::write_test_image;
 
   fn options_for(name: &str) -> CropTextureOptions {
-    let resource: String = format!("xrf-texture/crop/{name}-source.dds");
-    let source: PathBuf = write_generated_test_resource(&resource, []).expect("expect scratch source");
-
-    DdsFile::encode_rgba(
-      &RgbaImage::new(8, 8),
-      DdsEncodeOptions::new(ImageFormat::BC3RgbaUnorm, Quality::Slow, Mipmaps::Disabled),
-    )
-    .expect("expect source DDS to encode")
-    .write_to_path(&source)
-    .expect("expect source DDS to be written");
+    let source: PathBuf = write_test_image(&format!("xrf-texture/crop/{name}-source.dds"), &RgbaImage::new(8, 8));
 
     CropTextureOptions {
       output_path: source.with_file_name(format!("{name}-output.dds")),
@@ -1,4 +1,6 @@
 use std::cmp::max;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use image::{ImageBuffer, Rgba, RgbaImage};
 use serde::Serialize;
@@ -6,8 +8,9 @@ use xrf_error::{XrfError, XrfResult};
 use xrf_ltx::{Ltx, Section};
 
 use crate::constants::{
-  DDS_BLOCK_ALIGNMENT, INVENTORY_ICON_GRID_SQUARE_BASE, LTX_FIELD_INV_GRID_HEIGHT, LTX_FIELD_INV_GRID_WIDTH,
-  LTX_FIELD_INV_GRID_X, LTX_FIELD_INV_GRID_Y, LTX_FIELD_INVENTORY_ICON, LTX_FIELD_INVENTORY_ICON_PATH,
+  DDS_BLOCK_ALIGNMENT, DDS_EXTENSION, INVENTORY_ICON_GRID_SQUARE_BASE, LTX_FIELD_INV_GRID_HEIGHT,
+  LTX_FIELD_INV_GRID_WIDTH, LTX_FIELD_INV_GRID_X, LTX_FIELD_INV_GRID_Y, LTX_FIELD_INVENTORY_ICON,
+  LTX_FIELD_INVENTORY_ICON_PATH, TEXTURES_DIRECTORY,
 };
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -15,6 +18,8 @@ use crate::constants::{
 #[serde(rename_all = "camelCase")]
 pub struct InventorySpriteDescriptor {
   pub section: String,
+  /// Texture reference of the atlas holding the icon, from `$inventory_icon_path`, `None` for the
+  /// equipment sheet.
   pub custom_icon: Option<String>,
   // X/Y/W/H are not absolute pixel units, just inventory boxes.
   pub x: u32,
@@ -45,11 +50,7 @@ impl InventorySpriteDescriptor {
   where
     T: Into<String>,
   {
-    if !section
-      .get(LTX_FIELD_INVENTORY_ICON)
-      .and_then(|value| value.trim().parse::<bool>().ok())
-      .unwrap_or(false)
-    {
+    if !Self::is_opted_in(section) {
       return None;
     }
 
@@ -69,7 +70,7 @@ impl InventorySpriteDescriptor {
     } else {
       Some(Self {
         section: section_name.into(),
-        custom_icon: section.get(LTX_FIELD_INVENTORY_ICON_PATH).map(|value| value.into()),
+        custom_icon: Self::read_custom_icon(section),
         x,
         y,
         w,
@@ -77,6 +78,127 @@ impl InventorySpriteDescriptor {
       })
     }
   }
+
+  /// Describe the inventory icon of a section that opts in and declares its size, but no grid position yet.
+  ///
+  /// The position is left at 0:0 until a slot is allocated for it.
+  pub fn new_unplaced_from_section<T>(section_name: T, section: &Section) -> Option<Self>
+  where
+    T: Into<String>,
+  {
+    if !Self::is_opted_in(section)
+      || (section.contains_key(LTX_FIELD_INV_GRID_X) && section.contains_key(LTX_FIELD_INV_GRID_Y))
+    {
+      return None;
+    }
+
+    let w: u32 = section
+      .get(LTX_FIELD_INV_GRID_WIDTH)?
+      .trim()
+      .parse::<u32>()
+      .ok()
+      .filter(|w| *w > 0)?;
+    let h: u32 = section
+      .get(LTX_FIELD_INV_GRID_HEIGHT)?
+      .trim()
+      .parse::<u32>()
+      .ok()
+      .filter(|h| *h > 0)?;
+
+    Some(Self {
+      section: section_name.into(),
+      custom_icon: Self::read_custom_icon(section),
+      x: 0,
+      y: 0,
+      w,
+      h,
+    })
+  }
+
+  fn is_opted_in(section: &Section) -> bool {
+    section
+      .get(LTX_FIELD_INVENTORY_ICON)
+      .and_then(|value| value.trim().parse::<bool>().ok())
+      .unwrap_or(false)
+  }
+
+  fn read_custom_icon(section: &Section) -> Option<String> {
+    section
+      .get(LTX_FIELD_INVENTORY_ICON_PATH)
+      .map(str::trim)
+      .filter(|value| !value.is_empty())
+      .map(String::from)
+  }
+}
+
+impl InventorySpriteDescriptor {
+  /// Split descriptors by the atlas holding their icons, keeping their order within each atlas.
+  ///
+  /// The equipment sheet always comes first, even without any icon on it, followed by the other atlases
+  /// sorted by their texture references.
+  pub fn group_by_atlas(descriptors: Vec<Self>) -> Vec<(Option<String>, Vec<Self>)> {
+    let mut atlases: BTreeMap<Option<String>, Vec<Self>> = BTreeMap::from([(None, Vec::new())]);
+
+    for descriptor in descriptors {
+      atlases
+        .entry(descriptor.custom_icon.clone())
+        .or_default()
+        .push(descriptor);
+    }
+
+    atlases.into_iter().collect()
+  }
+
+  /// Path of the dds file behind an atlas texture reference, `None` being the equipment sheet itself.
+  ///
+  /// References are relative to the `textures` folder the equipment sheet lives in. A sheet outside of
+  /// any `textures` folder has its atlases looked up beside it, by file name.
+  pub fn resolve_atlas_path(equipment_path: &Path, atlas: Option<&str>) -> PathBuf {
+    let Some(atlas) = atlas else {
+      return equipment_path.to_path_buf();
+    };
+
+    let reference: PathBuf = atlas.split(['\\', '/']).filter(|part| !part.is_empty()).collect();
+    let reference: PathBuf = if reference
+      .extension()
+      .is_some_and(|extension| extension.eq_ignore_ascii_case(DDS_EXTENSION))
+    {
+      reference
+    } else {
+      PathBuf::from(format!("{}.{}", reference.display(), DDS_EXTENSION))
+    };
+
+    let textures: Option<&Path> = equipment_path.ancestors().skip(1).find(|ancestor| {
+      ancestor
+        .file_name()
+        .is_some_and(|name| name.eq_ignore_ascii_case(TEXTURES_DIRECTORY))
+    });
+
+    match textures {
+      Some(textures) => textures.join(reference),
+      None => equipment_path
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(reference.file_name().expect("Expected atlas reference to name a file")),
+    }
+  }
+
+  /// Folder the loose icon of the section is kept in, below the root of unpacked icons.
+  ///
+  /// Icons of the equipment sheet sit in the root itself, icons of another atlas in a folder mirroring
+  /// its texture reference, so sections of different atlases never clash.
+  pub fn get_sprite_directory(&self, root: &Path) -> PathBuf {
+    match self.custom_icon.as_deref() {
+      None => root.to_path_buf(),
+      Some(atlas) => root.join(
+        atlas
+          .split(['\\', '/'])
+          .filter(|part| !part.is_empty())
+          .collect::<PathBuf>()
+          .with_extension(""),
+      ),
+    }
+  }
 }
 
 impl InventorySpriteDescriptor {
@@ -91,9 +213,9 @@ impl InventorySpriteDescriptor {
 }
 
 impl InventorySpriteDescriptor {
-  /// Prepare combined equipment image base with suitable base size.
-  pub fn create_equipment_sprite_base_for_ltx(ltx: &Ltx) -> XrfResult<ImageBuffer<Rgba<u8>, Vec<u8>>> {
-    let (max_width, max_height) = Self::get_equipment_sprite_boundaries_from_ltx(ltx);
+  /// Prepare combined image base with suitable size for the icons of one atlas.
+  pub fn create_equipment_sprite_base(descriptors: &[Self]) -> XrfResult<ImageBuffer<Rgba<u8>, Vec<u8>>> {
+    let (max_width, max_height) = Self::get_equipment_sprite_boundaries(descriptors);
 
     if max_width > 32 * 1024 || max_height > 32 * 1024 {
       Err(XrfError::new_texture_processing_error(format!(
@@ -104,20 +226,18 @@ impl InventorySpriteDescriptor {
     }
   }
 
-  /// Smallest `DDS_BLOCK_ALIGNMENT`-aligned canvas that holds every icon claiming a grid slot.
+  /// Smallest `DDS_BLOCK_ALIGNMENT`-aligned canvas that holds every one of the icons.
   ///
   /// A grid square is 50 pixels, so a slot boundary lands on a whole block only every other column and
   /// row, and the rounding adds at most two pixels per axis. An already aligned canvas is returned
   /// untouched.
-  pub fn get_equipment_sprite_boundaries_from_ltx(ltx: &Ltx) -> (u32, u32) {
+  pub fn get_equipment_sprite_boundaries(descriptors: &[Self]) -> (u32, u32) {
     let mut max_width: u32 = 0;
     let mut max_height: u32 = 0;
 
-    for (section_name, section) in &ltx.sections {
-      if let Some(sprite) = Self::new_optional_from_section(section_name, section) {
-        max_width = max((sprite.x + sprite.w) * INVENTORY_ICON_GRID_SQUARE_BASE, max_width);
-        max_height = max((sprite.y + sprite.h) * INVENTORY_ICON_GRID_SQUARE_BASE, max_height);
-      }
+    for sprite in descriptors {
+      max_width = max((sprite.x + sprite.w) * INVENTORY_ICON_GRID_SQUARE_BASE, max_width);
+      max_height = max((sprite.y + sprite.h) * INVENTORY_ICON_GRID_SQUARE_BASE, max_height);
     }
 
     (
@@ -125,10 +245,22 @@ impl InventorySpriteDescriptor {
       max_height.next_multiple_of(DDS_BLOCK_ALIGNMENT),
     )
   }
+
+  /// Canvas size of the equipment sheet, icons placed on other atlases are left out.
+  pub fn get_equipment_sprite_boundaries_from_ltx(ltx: &Ltx) -> (u32, u32) {
+    let descriptors: Vec<Self> = Self::new_list_from_ltx(ltx)
+      .into_iter()
+      .filter(|descriptor| descriptor.custom_icon.is_none())
+      .collect();
+
+    Self::get_equipment_sprite_boundaries(&descriptors)
+  }
 }
 
 #[cfg(test)]
 mod tests {
+  use std::path::{Path, PathBuf};
+
   use xrf_ltx::Ltx;
 
   use super::InventorySpriteDescriptor;
@@ -237,4 +369,109 @@ mod tests {
       "Expect a section without grid position not to describe an icon"
     );
   }
+
+  #[test]
+  fn describes_opted_in_sections_without_grid_position_as_unplaced() {
+    let ltx: Ltx = Ltx::read_from_str(
+      "[af_new]\n\
+       $inventory_icon = true\n\
+       inv_grid_width = 2\n\
+       inv_grid_height = 1\n\
+       [af_placed]\n\
+       $inventory_icon = true\n\
+       inv_grid_x = 0\n\
+       inv_grid_y = 0\n\
+       inv_grid_width = 1\n\
+       inv_grid_height = 1\n",
+    )
+    .expect("test LTX is valid");
+
+    let unplaced: InventorySpriteDescriptor =
+      InventorySpriteDescriptor::new_unplaced_from_section("af_new", &ltx["af_new"])
+        .expect("expect a section without position to be unplaced");
+
+    assert_eq!((unplaced.w, unplaced.h), (2, 1));
+    assert!(InventorySpriteDescriptor::new_unplaced_from_section("af_placed", &ltx["af_placed"]).is_none());
+  }
+
+  #[test]
+  fn groups_icons_by_their_atlas_with_the_sheet_first() {
+    let ltx: Ltx = Ltx::read_from_str(
+      "[wpn_mod]\n\
+       $inventory_icon = true\n\
+       $inventory_icon_path = ui\\ui_icon_mod\n\
+       inv_grid_x = 0\n\
+       inv_grid_y = 0\n\
+       inv_grid_width = 1\n\
+       inv_grid_height = 1\n",
+    )
+    .expect("test LTX is valid");
+
+    let groups: Vec<(Option<String>, Vec<InventorySpriteDescriptor>)> =
+      InventorySpriteDescriptor::group_by_atlas(InventorySpriteDescriptor::new_list_from_ltx(&ltx));
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!((groups[0].0.as_deref(), groups[0].1.len()), (None, 0));
+    assert_eq!(
+      (groups[1].0.as_deref(), groups[1].1.len()),
+      (Some("ui\\ui_icon_mod"), 1)
+    );
+    assert_eq!(
+      InventorySpriteDescriptor::get_equipment_sprite_boundaries_from_ltx(&ltx),
+      (0, 0)
+    );
+  }
+
+  #[test]
+  fn resolves_atlases_from_the_textures_folder_of_the_sheet() {
+    let sheet: &Path = Path::new("gamedata/textures/ui/ui_icon_equipment.dds");
+
+    assert_eq!(
+      InventorySpriteDescriptor::resolve_atlas_path(sheet, None),
+      PathBuf::from("gamedata/textures/ui/ui_icon_equipment.dds")
+    );
+    assert_eq!(
+      InventorySpriteDescriptor::resolve_atlas_path(sheet, Some("ui\\mod\\ui_icon_mod")),
+      PathBuf::from("gamedata/textures/ui/mod/ui_icon_mod.dds")
+    );
+    assert_eq!(
+      InventorySpriteDescriptor::resolve_atlas_path(Path::new("icons/equipment.dds"), Some("ui\\ui_icon_mod.dds")),
+      PathBuf::from("icons/ui_icon_mod.dds")
+    );
+  }
+
+  #[test]
+  fn keeps_icons_of_other_atlases_in_folders_named_after_them() {
+    let ltx: Ltx = Ltx::read_from_str(
+      "[wpn_a]\n\
+       $inventory_icon = true\n\
+       inv_grid_x = 0\n\
+       inv_grid_y = 0\n\
+       inv_grid_width = 1\n\
+       inv_grid_height = 1\n\
+       [wpn_mod]\n\
+       $inventory_icon = true\n\
+       $inventory_icon_path = ui\\mod\\ui_icon_mod.dds\n\
+       inv_grid_x = 0\n\
+       inv_grid_y = 0\n\
+       inv_grid_width = 1\n\
+       inv_grid_height = 1\n",
+    )
+    .expect("test LTX is valid");
+
+    let root: &Path = Path::new("icons");
+
+    assert_eq!(
+      InventorySpriteDescriptor::new_optional_from_section("wpn_a", &ltx["wpn_a"])
+        .expect("expect a sheet icon")
+        .get_sprite_directory(root),
+      PathBuf::from("icons")
+    );
+    assert_eq!(
+      InventorySpriteDescriptor::new_optional_from_section("wpn_mod", &ltx["wpn_mod"])
+        .expect("expect an atlas icon")
+        .get_sprite_directory(root),
+      PathBuf::from("icons/ui/mod/ui_icon_mod")
+    );
+  }
 }
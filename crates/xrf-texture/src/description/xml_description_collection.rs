@@ -3,8 +3,9 @@ use std::fs;
 use std::fs::ReadDir;
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
 use xrf_error::{XrfError, XrfResult};
-use xrf_xml::{XmlDocument, XmlElement, XmlParseOptions};
+use xrf_xml::{XmlDocument, XmlElement, XmlParseOptions, serialize_xml};
 
 use crate::constants::{XML_ATTRIBUTE_ID, XML_ATTRIBUTE_NAME, XML_TAG_FILE, XML_TAG_TEXTURE, XML_TAG_WINDOW};
 use crate::data::{TextureFileDescriptor, TextureSpriteDescriptor};
@@ -14,6 +15,34 @@ pub struct XmlDescriptionCollection {
   pub files: HashMap<String, TextureFileDescriptor>,
 }
 
+/// A whole texture description document, for serializing only.
+#[derive(Serialize)]
+#[serde(rename = "w")]
+struct TextureDescriptionXml {
+  file: Vec<TextureFileXml>,
+}
+
+#[derive(Serialize)]
+struct TextureFileXml {
+  #[serde(rename = "@name")]
+  name: String,
+  texture: Vec<TextureSpriteXml>,
+}
+
+#[derive(Serialize)]
+struct TextureSpriteXml {
+  #[serde(rename = "@id")]
+  id: String,
+  #[serde(rename = "@x")]
+  x: u32,
+  #[serde(rename = "@y")]
+  y: u32,
+  #[serde(rename = "@width")]
+  width: u32,
+  #[serde(rename = "@height")]
+  height: u32,
+}
+
 impl XmlDescriptionCollection {
   /// Narrow the described files down to the ones requested by name, in the order requested.
   ///
@@ -80,6 +109,41 @@ impl XmlDescriptionCollection {
     described == requested || described.rsplit('/').next().is_some_and(|base| base == requested)
   }
 
+  /// Write a texture description document describing the provided files, replacing the file at `path`.
+  ///
+  /// # Errors
+  ///
+  /// Fails when the document cannot be serialized or written.
+  pub fn write_description(path: &Path, files: &[&TextureFileDescriptor]) -> XrfResult {
+    let document: TextureDescriptionXml = TextureDescriptionXml {
+      file: files
+        .iter()
+        .map(|file| TextureFileXml {
+          name: file.name.clone(),
+          texture: file
+            .sprites
+            .iter()
+            .map(|sprite| TextureSpriteXml {
+              id: sprite.id.clone(),
+              x: sprite.x,
+              y: sprite.y,
+              width: sprite.w,
+              height: sprite.h,
+            })
+            .collect(),
+        })
+        .collect(),
+    };
+
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, format!("{}\n", serialize_xml(&document)?))?;
+
+    Ok(())
+  }
+
   /// Get descriptions from provided options.
   /// Handle both directory and single file as inputs.
   pub fn get_descriptions(options: &PackDescriptionOptions) -> XrfResult<Self> {
@@ -217,13 +281,16 @@ impl XmlDescriptionCollection {
 
 #[cfg(test)]
 mod tests {
+  use std::collections::HashMap;
   use std::fs;
   use std::path::PathBuf;
 
   use xrf_dds::ImageFormat;
+  use xrf_test_utils::utils::write_generated_test_resource;
 
   use super::XmlDescriptionCollection;
   use crate::PackDescriptionOptions;
+  use crate::data::{TextureFileDescriptor, TextureSpriteDescriptor};
 
   #[test]
   fn returns_an_error_for_invalid_xml_in_strict_mode() {
@@ -249,6 +316,37 @@ mod tests {
 
     assert!(result.is_err());
   }
+
+  #[test]
+  fn writes_a_description_it_reads_back() {
+    let path: PathBuf =
+      write_generated_test_resource("xrf-texture/description/written.xml", []).expect("expect scratch description");
+    let mut file: TextureFileDescriptor = TextureFileDescriptor::new(r"ui\ui_hud_custom");
+
+    file.add_sprite(TextureSpriteDescriptor::new("ui_hud_icon", 4, 8, 16, 32));
+
+    XmlDescriptionCollection::write_description(&path, &[&file]).expect("expect description to be written");
+
+    let options: PackDescriptionOptions = PackDescriptionOptions {
+      description: path.clone(),
+      base: PathBuf::new(),
+      output: Default::default(),
+      output_path: PathBuf::new(),
+      dds_compression_format: ImageFormat::BC3RgbaUnorm,
+      files: Vec::new(),
+      is_strict: true,
+      is_parallel: false,
+    };
+
+    let descriptions: HashMap<String, TextureFileDescriptor> =
+      XmlDescriptionCollection::get_description(&options, &path).expect("expect written description to parse");
+    let sprite: &TextureSpriteDescriptor = &descriptions[r"ui\ui_hud_custom"].sprites[0];
+
+    assert_eq!(
+      (sprite.id.as_str(), sprite.x, sprite.y, sprite.w, sprite.h),
+      ("ui_hud_icon", 4, 8, 16, 32)
+    );
+  }
 }
 
 #[cfg(test)]
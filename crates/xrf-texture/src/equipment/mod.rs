@@ -1,13 +1,15 @@
 mod pack_equipment_options;
 mod pack_equipment_processor;
 mod pack_equipment_result;
+mod place_equipment_processor;
 mod unpack_equipment_options;
 mod unpack_equipment_processor;
 mod verify_equipment_grid_processor;
 
 pub use pack_equipment_options::PackEquipmentOptions;
 pub use pack_equipment_processor::PackEquipmentProcessor;
-pub use pack_equipment_result::PackEquipmentResult;
+pub use pack_equipment_result::{PackEquipmentAtlasResult, PackEquipmentResult};
+pub use place_equipment_processor::PlaceEquipmentProcessor;
 pub use unpack_equipment_options::UnpackEquipmentOptions;
 pub use unpack_equipment_processor::UnpackEquipmentProcessor;
 pub use verify_equipment_grid_processor::{EquipmentGridOverlap, VerifyEquipmentGridProcessor};
@@ -8,7 +8,6 @@ pub struct PackEquipmentOptions {
   pub source: PathBuf,
   pub output: xrf_output::OutputOptions,
   pub output_path: PathBuf,
-  pub gamedata: Option<PathBuf>,
   pub dds_compression_format: ImageFormat,
   pub is_strict: bool,
 }
@@ -2,87 +2,114 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
-use image::{DynamicImage, GenericImage, ImageBuffer, ImageReader, Rgba};
-use path_absolutize::*;
+use image::{DynamicImage, GenericImage, ImageReader, RgbaImage};
 use xrf_dds::DdsFile;
 use xrf_error::{XrfError, XrfResult};
 
-use crate::constants::{
-  DDS_EXTENSION, EXTENSIONS_DIRECTORY, LTX_PATH_EXTENSION_MARKER, LTX_PATH_EXTENSION_MARKER_PREFIX,
-  LTX_PATH_GAMEDATA_MARKER, LTX_PATH_GAMEDATA_MARKER_PREFIX, PNG_EXTENSION, RESOURCES_DIRECTORY, TEXTURES_DIRECTORY,
-  UI_MIPMAP_LEVELS, UI_MIPMAPS,
-};
+use crate::constants::{DDS_EXTENSION, PNG_EXTENSION, UI_MIPMAP_LEVELS, UI_MIPMAPS};
 use crate::data::InventorySpriteDescriptor;
 use crate::utils::{fit_image_into_bounds, warn_on_reshaped_ui_dds};
-use crate::{PackEquipmentOptions, PackEquipmentResult, save_image_as_ui_dds};
+use crate::{PackEquipmentAtlasResult, PackEquipmentOptions, PackEquipmentResult, save_image_as_ui_dds};
 
 pub struct PackEquipmentProcessor {}
 
 impl PackEquipmentProcessor {
+  /// Pack icons of every section into the equipment sheet and the other atlases sections point at.
+  ///
+  /// Each atlas is sized by its own icons. With strict checking, a missing icon fails the pack before
+  /// any atlas is written.
   pub fn pack_sprites(options: PackEquipmentOptions) -> XrfResult<PackEquipmentResult> {
     let started_at: Instant = Instant::now();
 
-    let mut count: u32 = 0;
-    let mut skipped_sections: Vec<&str> = Vec::new();
-    let mut image: ImageBuffer<Rgba<u8>, Vec<u8>> =
-      InventorySpriteDescriptor::create_equipment_sprite_base_for_ltx(&options.ltx)?;
-
-    // Variants such as `_nimble`, `_snag` and the `pri_a15_` quest copies inherit their base weapon's
-    // grid position, so several sections legitimately share one slot with inheritance.
-    let mut occupied_slots: HashMap<(u32, u32), (String, Vec<u8>)> = HashMap::new();
-
-    for (section_name, section) in &options.ltx.sections {
-      let Some(sprite_descriptor) = InventorySpriteDescriptor::new_optional_from_section(section_name, section) else {
-        continue;
-      };
-
-      let Some((sprite_path, sprite)) = Self::read_sprite(&options, &sprite_descriptor) else {
-        skipped_sections.push(section_name);
-        continue;
-      };
-
-      let (x, y, w, h) = sprite_descriptor.get_boundaries();
-
-      xrf_output::verbose!(
-        options.output,
-        "Packing icon: '{}':({}:{};{}x{}) as ({}:{};{}x{}), src: {}x{}, {}",
-        sprite_descriptor.section,
-        sprite_descriptor.x,
-        sprite_descriptor.y,
-        sprite_descriptor.w,
-        sprite_descriptor.h,
-        x,
-        y,
-        w,
-        h,
-        sprite.width(),
-        sprite.height(),
-        sprite_path.display(),
-      );
+    let mut skipped_sections: Vec<String> = Vec::new();
+    let mut atlases: Vec<(PackEquipmentAtlasResult, RgbaImage)> = Vec::new();
 
-      Self::warn_on_conflicting_slot(
-        &options,
-        &mut occupied_slots,
-        (x, y),
-        &sprite_descriptor.section,
-        &sprite,
-      );
+    for (atlas, descriptors) in
+      InventorySpriteDescriptor::group_by_atlas(InventorySpriteDescriptor::new_list_from_ltx(&options.ltx))
+    {
+      let saved_at: PathBuf = InventorySpriteDescriptor::resolve_atlas_path(&options.output_path, atlas.as_deref());
+      let mut image: RgbaImage = InventorySpriteDescriptor::create_equipment_sprite_base(&descriptors)?;
+      let mut count: u32 = 0;
+
+      // Variants such as `_nimble`, `_snag` and the `pri_a15_` quest copies inherit their base weapon's
+      // grid position, so several sections legitimately share one slot with inheritance.
+      let mut occupied_slots: HashMap<(u32, u32), (String, Vec<u8>)> = HashMap::new();
+
+      for sprite_descriptor in &descriptors {
+        let Some((sprite_path, sprite)) = Self::read_sprite(&options, sprite_descriptor) else {
+          skipped_sections.push(sprite_descriptor.section.clone());
+          continue;
+        };
+
+        let (x, y, w, h) = sprite_descriptor.get_boundaries();
 
-      image.copy_from(&sprite, x, y)?;
-      count += 1;
+        xrf_output::verbose!(
+          options.output,
+          "Packing icon: '{}':({}:{};{}x{}) as ({}:{};{}x{}), src: {}x{}, {}",
+          sprite_descriptor.section,
+          sprite_descriptor.x,
+          sprite_descriptor.y,
+          sprite_descriptor.w,
+          sprite_descriptor.h,
+          x,
+          y,
+          w,
+          h,
+          sprite.width(),
+          sprite.height(),
+          sprite_path.display(),
+        );
+
+        Self::warn_on_conflicting_slot(
+          &options,
+          &mut occupied_slots,
+          (x, y),
+          &sprite_descriptor.section,
+          &sprite,
+        );
+
+        image.copy_from(&sprite, x, y)?;
+        count += 1;
+      }
+
+      atlases.push((
+        PackEquipmentAtlasResult {
+          atlas,
+          saved_at,
+          saved_width: image.width(),
+          saved_height: image.height(),
+          packed_count: count,
+        },
+        image,
+      ));
     }
 
     Self::assert_every_section_has_an_icon(&options, &skipped_sections)?;
 
-    warn_on_reshaped_ui_dds(
-      &options.output,
-      &options.output_path,
-      image.width(),
-      image.height(),
-      UI_MIPMAP_LEVELS,
-    );
+    for (atlas, image) in &atlases {
+      warn_on_reshaped_ui_dds(
+        &options.output,
+        &atlas.saved_at,
+        image.width(),
+        image.height(),
+        UI_MIPMAP_LEVELS,
+      );
+
+      save_image_as_ui_dds(&atlas.saved_at, image, options.dds_compression_format, UI_MIPMAPS)?;
+
+      if let Some(name) = &atlas.atlas {
+        xrf_output::info!(
+          options.output,
+          "Packed {} icons into atlas '{}': {}",
+          atlas.packed_count,
+          name,
+          atlas.saved_at.display()
+        );
+      }
+    }
 
-    save_image_as_ui_dds(&options.output_path, &image, options.dds_compression_format, UI_MIPMAPS)?;
+    let atlases: Vec<PackEquipmentAtlasResult> = atlases.into_iter().map(|(atlas, _)| atlas).collect();
+    let count: u32 = atlases.iter().map(|atlas| atlas.packed_count).sum();
 
     xrf_output::info!(
       options.output,
@@ -94,10 +121,11 @@ impl PackEquipmentProcessor {
     Ok(PackEquipmentResult {
       duration: started_at.elapsed(),
       saved_at: options.output_path.clone(),
-      saved_width: image.width(),
-      saved_height: image.height(),
+      saved_width: atlases[0].saved_width,
+      saved_height: atlases[0].saved_height,
       packed_count: count,
       skipped_count: skipped_sections.len() as u32,
+      atlases,
     })
   }
 
@@ -133,7 +161,7 @@ impl PackEquipmentProcessor {
   }
 
   /// Fail once with every section that declares inventory grid coordinates but has no icon to pack.
-  fn assert_every_section_has_an_icon(options: &PackEquipmentOptions, skipped_sections: &[&str]) -> XrfResult {
+  fn assert_every_section_has_an_icon(options: &PackEquipmentOptions, skipped_sections: &[String]) -> XrfResult {
     if !options.is_strict || skipped_sections.is_empty() {
       return Ok(());
     }
@@ -179,94 +207,76 @@ impl PackEquipmentProcessor {
     fit_image_into_bounds(image, width, height, path)
   }
 
-  /// Read equipment icon from custom path defined in ltx config directory.
+  /// Path of the unpacked icon of a section, png preferred over dds.
+  ///
+  /// Icons of other atlases are read from a folder named after their `$inventory_icon_path`, as
+  /// unpacking lays them out.
   pub fn read_sprite_path(options: &PackEquipmentOptions, descriptor: &InventorySpriteDescriptor) -> PathBuf {
-    match descriptor.custom_icon.as_deref() {
-      None => {
-        let png_path: PathBuf = options.source.join(format!("{}.{}", descriptor.section, PNG_EXTENSION));
-
-        if png_path.exists() {
-          png_path
-        } else {
-          options.source.join(format!("{}.{}", descriptor.section, DDS_EXTENSION))
-        }
-      }
-      Some(custom_path) => {
-        // Handle custom gamedata source.
-        if let Some(gamedata) = &options.gamedata {
-          if custom_path.starts_with(LTX_PATH_GAMEDATA_MARKER) {
-            PathBuf::from(
-              gamedata
-                .join(custom_path.strip_prefix(LTX_PATH_GAMEDATA_MARKER_PREFIX).unwrap())
-                .absolutize()
-                .unwrap()
-                .to_str()
-                .unwrap(),
-            )
-          } else {
-            PathBuf::from(
-              gamedata
-                .join(TEXTURES_DIRECTORY)
-                .join(custom_path)
-                .absolutize()
-                .unwrap()
-                .to_str()
-                .unwrap(),
-            )
-          }
-          // Handle ~ path for xrf / system.ltx
-        } else if custom_path.starts_with(LTX_PATH_GAMEDATA_MARKER) {
-          PathBuf::from(
-            options
-              .ltx
-              .directory
-              .as_ref()
-              .unwrap()
-              .join("..")
-              .join("..")
-              .join(RESOURCES_DIRECTORY)
-              .join(custom_path.strip_prefix(LTX_PATH_GAMEDATA_MARKER_PREFIX).unwrap())
-              .absolutize()
-              .unwrap()
-              .to_str()
-              .unwrap(),
-          )
-          // Handle relative path for xrf / system.ltx extensions
-        } else if custom_path.starts_with(LTX_PATH_EXTENSION_MARKER) {
-          PathBuf::from(
-            options
-              .ltx
-              .directory
-              .as_ref()
-              .unwrap()
-              .join("..")
-              .join(EXTENSIONS_DIRECTORY)
-              .join(custom_path.strip_prefix(LTX_PATH_EXTENSION_MARKER_PREFIX).unwrap())
-              .absolutize()
-              .unwrap()
-              .to_str()
-              .unwrap(),
-          )
-          // Handle relative path for xrf / system.ltx
-        } else {
-          PathBuf::from(
-            options
-              .ltx
-              .directory
-              .as_ref()
-              .unwrap()
-              .join("..")
-              .join("..")
-              .join(RESOURCES_DIRECTORY)
-              .join(TEXTURES_DIRECTORY)
-              .join(custom_path)
-              .absolutize()
-              .unwrap()
-              .to_str()
-              .unwrap(),
-          )
-        }
-      }
+    let directory: PathBuf = descriptor.get_sprite_directory(&options.source);
+    let png_path: PathBuf = directory.join(format!("{}.{}", descriptor.section, PNG_EXTENSION));
+
+    if png_path.exists() {
+      png_path
+    } else {
+      directory.join(format!("{}.{}", descriptor.section, DDS_EXTENSION))
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use image::{Rgba, RgbaImage};
+  use xrf_dds::DdsFile;
+
+  use super::PackEquipmentProcessor;
+  use crate::utils::test_utils::equipment_options_for;
+  use crate::{PackEquipmentOptions, PackEquipmentResult};
+
+  #[test]
+  fn packs_icons_into_the_atlas_of_their_section() {
+    let mut options: PackEquipmentOptions = equipment_options_for(
+      "xrf-texture/pack/atlases/textures/ui",
+      "[wpn_a]\n$inventory_icon = true\ninv_grid_x = 1\ninv_grid_y = 0\ninv_grid_width = 1\ninv_grid_height = 1\n\
+       [wpn_mod]\n$inventory_icon = true\n$inventory_icon_path = ui\\mod\\ui_icon_mod\n\
+       inv_grid_x = 0\ninv_grid_y = 1\ninv_grid_width = 2\ninv_grid_height = 1\n",
+      &[
+        ("wpn_a", RgbaImage::from_pixel(50, 50, Rgba([255, 0, 0, 255]))),
+        (
+          "ui/mod/ui_icon_mod/wpn_mod",
+          RgbaImage::from_pixel(100, 50, Rgba([255, 0, 0, 255])),
+        ),
+      ],
+    );
+
+    options.is_strict = true;
+
+    fs::create_dir_all(options.output_path.with_file_name("mod")).expect("expect atlas folder");
+
+    let output_path: PathBuf = options.output_path.clone();
+    let result: PackEquipmentResult = PackEquipmentProcessor::pack_sprites(options).expect("expect icons to be packed");
+
+    assert_eq!((result.packed_count, result.skipped_count), (2, 0));
+    assert_eq!((result.saved_width, result.saved_height), (100, 52));
+    assert_eq!(result.atlases.len(), 2);
+    assert_eq!(result.atlases[1].atlas.as_deref(), Some("ui\\mod\\ui_icon_mod"));
+    assert_eq!(
+      result.atlases[1].saved_at,
+      output_path.with_file_name("mod").join("ui_icon_mod.dds")
+    );
+    assert_eq!(
+      (result.atlases[1].saved_width, result.atlases[1].saved_height),
+      (100, 100)
+    );
+
+    let atlas: RgbaImage = DdsFile::read_from_path(&result.atlases[1].saved_at)
+      .expect("expect atlas to be written")
+      .decode_rgba(0)
+      .expect("expect atlas to decode");
+
+    assert_eq!(atlas.get_pixel(60, 60).0, [255, 0, 0, 255]);
+    assert_eq!(atlas.get_pixel(60, 20).0[3], 0);
+  }
+}
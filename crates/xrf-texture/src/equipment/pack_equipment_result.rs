@@ -15,4 +15,19 @@ pub struct PackEquipmentResult {
   pub saved_height: u32,
   pub packed_count: u32,
   pub skipped_count: u32,
+  /// Every atlas written, the equipment sheet first.
+  pub atlases: Vec<PackEquipmentAtlasResult>,
+}
+
+/// One sprite atlas written by a pack, either the equipment sheet or an atlas sections point at with
+/// `$inventory_icon_path`.
+#[derive(Debug, Default, Serialize)]
+#[cfg_attr(feature = "typescript-bindings", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct PackEquipmentAtlasResult {
+  pub atlas: Option<String>,
+  pub saved_at: PathBuf,
+  pub saved_width: u32,
+  pub saved_height: u32,
+  pub packed_count: u32,
 }
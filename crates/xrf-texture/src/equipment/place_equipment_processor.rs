@@ -0,0 +1,422 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use xrf_error::{XrfError, XrfResult};
+use xrf_ltx::Ltx;
+use xrf_utils::{encode_string_to_w1251_bytes, read_as_string_from_w1251_encoded};
+
+use crate::PackEquipmentOptions;
+use crate::constants::{LTX_FIELD_INV_GRID_X, LTX_FIELD_INV_GRID_Y};
+use crate::data::InventorySpriteDescriptor;
+use crate::equipment::PackEquipmentProcessor;
+
+/// Allocates grid slots for inventory icons that do not have one yet.
+///
+/// Sections opting in with `$inventory_icon` but missing `inv_grid_x` or `inv_grid_y` are placed into
+/// free cells of the sheet, so new items do not have to be positioned by hand.
+pub struct PlaceEquipmentProcessor {}
+
+impl PlaceEquipmentProcessor {
+  /// Find a free slot for every unplaced section whose icon image exists.
+  ///
+  /// Slots are taken first fit, row by row, within the columns the sheet of the icon's atlas already
+  /// spans. A sheet too full for an icon grows downwards, since its width is what the ui configuration is laid out for.
+  /// `minimum_columns` sets the width of a sheet that has fewer columns than that, an empty one included.
+  ///
+  /// Larger icons are placed first, so small ones fill the gaps they leave.
+  pub fn place(options: &PackEquipmentOptions, minimum_columns: u32) -> Vec<InventorySpriteDescriptor> {
+    let placed: Vec<InventorySpriteDescriptor> = InventorySpriteDescriptor::new_list_from_ltx(&options.ltx);
+    let mut unplaced: Vec<InventorySpriteDescriptor> = Vec::new();
+
+    for (section_name, section) in &options.ltx.sections {
+      let Some(descriptor) = InventorySpriteDescriptor::new_unplaced_from_section(section_name, section) else {
+        continue;
+      };
+
+      let sprite_path: PathBuf = PackEquipmentProcessor::read_sprite_path(options, &descriptor);
+
+      if sprite_path.is_file() {
+        unplaced.push(descriptor);
+      } else {
+        xrf_output::warning!(
+          options.output,
+          "Skip placing '{}', no icon found at {}",
+          descriptor.section,
+          sprite_path.display()
+        );
+      }
+    }
+
+    unplaced.sort_by(|a, b| (b.h, b.w, &a.section).cmp(&(a.h, a.w, &b.section)));
+
+    // Every atlas is a grid of its own, with its own occupied cells and column count.
+    let mut occupied: HashMap<Option<String>, HashSet<(u32, u32)>> = HashMap::new();
+    let mut columns: HashMap<Option<String>, u32> = HashMap::new();
+
+    for descriptor in placed.iter().chain(&unplaced) {
+      let atlas_columns: &mut u32 = columns.entry(descriptor.custom_icon.clone()).or_insert(minimum_columns);
+
+      *atlas_columns = (*atlas_columns).max(descriptor.x + descriptor.w);
+    }
+
+    for descriptor in &placed {
+      Self::occupy(occupied.entry(descriptor.custom_icon.clone()).or_default(), descriptor);
+    }
+
+    for descriptor in &mut unplaced {
+      let occupied: &mut HashSet<(u32, u32)> = occupied.entry(descriptor.custom_icon.clone()).or_default();
+
+      (descriptor.x, descriptor.y) =
+        Self::find_free_slot(occupied, columns[&descriptor.custom_icon], descriptor.w, descriptor.h);
+
+      Self::occupy(occupied, descriptor);
+
+      xrf_output::verbose!(
+        options.output,
+        "Placing icon: '{}' at {}:{} ({}x{})",
+        descriptor.section,
+        descriptor.x,
+        descriptor.y,
+        descriptor.w,
+        descriptor.h
+      );
+    }
+
+    unplaced
+  }
+
+  /// Set allocated grid positions on the sections of an in memory ltx.
+  pub fn apply(ltx: &mut Ltx, placements: &[InventorySpriteDescriptor]) {
+    for placement in placements {
+      Self::set_position(ltx, placement);
+    }
+  }
+
+  /// Write allocated grid positions into the files declaring their sections.
+  ///
+  /// Shorthand for [`Self::edit_sources`] followed by [`Self::write_sources`].
+  ///
+  /// # Errors
+  ///
+  /// Fails when the edits cannot be prepared or when writing fails.
+  pub fn write(ltx_path: &Path, placements: &[InventorySpriteDescriptor]) -> XrfResult<Vec<PathBuf>> {
+    Self::write_sources(Self::edit_sources(ltx_path, placements)?)
+  }
+
+  /// Edited contents of the files declaring placed sections, without writing anything.
+  ///
+  /// `ltx_path` is the root file the placements were computed from, includes are followed from it to
+  /// find the declaring files. Only the grid position lines of the placed sections are replaced or
+  /// inserted, the rest of every file is kept as it was written, comments included. Preparing the edits
+  /// before other outputs are produced lets a caller fail without leaving anything half written.
+  ///
+  /// # Errors
+  ///
+  /// Fails when the configuration cannot be read or when a section is not declared by any of its files.
+  pub fn edit_sources(ltx_path: &Path, placements: &[InventorySpriteDescriptor]) -> XrfResult<Vec<(PathBuf, Vec<u8>)>> {
+    let sources: Ltx = Ltx::read_sources_from_file(ltx_path)?;
+    let mut files: HashMap<PathBuf, Ltx> = HashMap::new();
+    let mut edited: Vec<(PathBuf, Vec<&InventorySpriteDescriptor>)> = Vec::new();
+
+    for placement in placements {
+      let candidates: Vec<PathBuf> = sources
+        .section(&placement.section)
+        .map(|section| section.iter().map(|(_, path)| PathBuf::from(path)).collect())
+        .unwrap_or_default();
+
+      let mut declaring: Option<PathBuf> = None;
+
+      for candidate in candidates {
+        if !files.contains_key(&candidate) {
+          files.insert(candidate.clone(), Ltx::read_from_path(&candidate)?);
+        }
+
+        if files[&candidate].has_section(&placement.section) {
+          declaring = Some(candidate);
+          break;
+        }
+      }
+
+      let declaring: PathBuf = declaring.ok_or_else(|| {
+        XrfError::new_not_found_error(format!(
+          "Section '{}' is not declared by any file its fields come from, cannot write its grid position",
+          placement.section
+        ))
+      })?;
+
+      let edits: &mut Vec<&InventorySpriteDescriptor> = match edited.iter().position(|(path, _)| *path == declaring) {
+        Some(index) => &mut edited[index].1,
+        None => {
+          edited.push((declaring, Vec::new()));
+          &mut edited.last_mut().expect("Expected edited file to be pushed").1
+        }
+      };
+
+      edits.push(placement);
+    }
+
+    edited
+      .into_iter()
+      .map(|(path, placements)| {
+        let mut source: String = read_as_string_from_w1251_encoded(&mut File::open(&path)?)?;
+
+        for placement in placements {
+          source = Self::set_source_field(
+            &source,
+            &placement.section,
+            LTX_FIELD_INV_GRID_X,
+            &placement.x.to_string(),
+          );
+          source = Self::set_source_field(
+            &source,
+            &placement.section,
+            LTX_FIELD_INV_GRID_Y,
+            &placement.y.to_string(),
+          );
+        }
+
+        Ok((path, encode_string_to_w1251_bytes(&source)?))
+      })
+      .collect()
+  }
+
+  /// Write file contents prepared by [`Self::edit_sources`], returning the written paths.
+  ///
+  /// # Errors
+  ///
+  /// Fails when writing fails.
+  pub fn write_sources(edits: Vec<(PathBuf, Vec<u8>)>) -> XrfResult<Vec<PathBuf>> {
+    edits
+      .into_iter()
+      .map(|(path, contents)| {
+        fs::write(&path, contents)?;
+
+        Ok(path)
+      })
+      .collect()
+  }
+
+  /// Replace the value of a field in the text of an ltx file, or insert it after the last field of its section.
+  ///
+  /// Indentation and trailing comment of a replaced line are kept, an inserted line takes the line
+  /// separator the file uses.
+  fn set_source_field(source: &str, section: &str, key: &str, value: &str) -> String {
+    let separator: &str = if source.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<String> = source.split_inclusive('\n').map(String::from).collect();
+    let mut is_in_section: bool = false;
+    let mut insert_at: Option<usize> = None;
+
+    for (index, line) in lines.iter_mut().enumerate() {
+      let (statement, comment): (&str, Option<&str>) = match line.find(';') {
+        Some(position) => (&line[..position], Some(&line[position..])),
+        None => (line.as_str(), None),
+      };
+      let trimmed: &str = statement.trim();
+
+      if let Some(header) = trimmed.strip_prefix('[') {
+        if is_in_section {
+          break;
+        }
+
+        is_in_section = header.split(']').next() == Some(section);
+        insert_at = is_in_section.then_some(index + 1);
+
+        continue;
+      }
+
+      if !is_in_section || trimmed.is_empty() {
+        continue;
+      }
+
+      insert_at = Some(index + 1);
+
+      if trimmed.split('=').next().map(str::trim) == Some(key) {
+        let indentation: &str = &statement[..statement.len() - statement.trim_start().len()];
+        let ending: &str = &line[line.trim_end_matches(['\r', '\n']).len()..];
+
+        *line = match comment {
+          Some(comment) => format!("{indentation}{key} = {value} {}{ending}", comment.trim_end()),
+          None => format!("{indentation}{key} = {value}{ending}"),
+        };
+
+        return lines.concat();
+      }
+    }
+
+    let Some(insert_at) = insert_at else {
+      return source.to_owned();
+    };
+
+    if let Some(previous) = lines.get_mut(insert_at - 1)
+      && !previous.ends_with('\n')
+    {
+      previous.push_str(separator);
+    }
+
+    lines.insert(insert_at, format!("{key} = {value}{separator}"));
+
+    lines.concat()
+  }
+
+  fn set_position(ltx: &mut Ltx, placement: &InventorySpriteDescriptor) {
+    ltx.set_to(
+      placement.section.as_str(),
+      String::from(LTX_FIELD_INV_GRID_X),
+      placement.x.to_string(),
+    );
+    ltx.set_to(
+      placement.section.as_str(),
+      String::from(LTX_FIELD_INV_GRID_Y),
+      placement.y.to_string(),
+    );
+  }
+
+  fn occupy(occupied: &mut HashSet<(u32, u32)>, descriptor: &InventorySpriteDescriptor) {
+    for y in descriptor.y..descriptor.y + descriptor.h {
+      for x in descriptor.x..descriptor.x + descriptor.w {
+        occupied.insert((x, y));
+      }
+    }
+  }
+
+  /// Top left cell of the first free `w`x`h` rectangle, scanning rows from the top.
+  ///
+  /// Rows are not bounded, the scan ends below every occupied cell at the latest.
+  fn find_free_slot(occupied: &HashSet<(u32, u32)>, columns: u32, w: u32, h: u32) -> (u32, u32) {
+    (0..)
+      .find_map(|y: u32| {
+        (0..=columns - w).find_map(|x| {
+          let is_free: bool = (y..y + h).all(|cell_y| (x..x + w).all(|cell_x| !occupied.contains(&(cell_x, cell_y))));
+
+          is_free.then_some((x, y))
+        })
+      })
+      .expect("Expected an unbounded scan to find a free slot")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::{Path, PathBuf};
+
+  use image::RgbaImage;
+  use xrf_ltx::Ltx;
+  use xrf_test_utils::utils::write_generated_test_resource;
+
+  use super::PlaceEquipmentProcessor;
+  use crate::utils::test_utils::equipment_options_for;
+  use crate::{InventorySpriteDescriptor, PackEquipmentOptions};
+
+  fn positions(placements: &[InventorySpriteDescriptor]) -> Vec<(&str, u32, u32)> {
+    placements
+      .iter()
+      .map(|placement| (placement.section.as_str(), placement.x, placement.y))
+      .collect()
+  }
+
+  #[test]
+  fn places_new_icons_into_free_cells() {
+    let options: PackEquipmentOptions = equipment_options_for(
+      "xrf-texture/place/free",
+      "[wpn_a]\n$inventory_icon = true\ninv_grid_x = 0\ninv_grid_y = 0\ninv_grid_width = 3\ninv_grid_height = 1\n\
+       [af_a]\n$inventory_icon = true\ninv_grid_width = 1\ninv_grid_height = 1\n\
+       [wpn_b]\n$inventory_icon = true\ninv_grid_width = 2\ninv_grid_height = 2\n\
+       [af_missing]\n$inventory_icon = true\ninv_grid_width = 1\ninv_grid_height = 1\n",
+      &[("af_a", RgbaImage::new(50, 50)), ("wpn_b", RgbaImage::new(100, 100))],
+    );
+
+    // Four columns wide: the 2x2 goes below the placed row, the 1x1 fills the cell beside it.
+    assert_eq!(
+      positions(&PlaceEquipmentProcessor::place(&options, 4)),
+      vec![("wpn_b", 0, 1), ("af_a", 3, 0)]
+    );
+  }
+
+  #[test]
+  fn places_icons_on_the_sheet_of_their_atlas() {
+    let options: PackEquipmentOptions = equipment_options_for(
+      "xrf-texture/place/atlas",
+      "[wpn_a]\n$inventory_icon = true\ninv_grid_x = 0\ninv_grid_y = 0\ninv_grid_width = 2\ninv_grid_height = 1\n\
+       [wpn_mod]\n$inventory_icon = true\n$inventory_icon_path = ui\\ui_icon_mod\n\
+       inv_grid_width = 1\ninv_grid_height = 1\n",
+      &[("ui/ui_icon_mod/wpn_mod", RgbaImage::new(50, 50))],
+    );
+
+    assert_eq!(
+      positions(&PlaceEquipmentProcessor::place(&options, 2)),
+      vec![("wpn_mod", 0, 0)]
+    );
+  }
+
+  #[test]
+  fn writes_positions_into_the_declaring_files() {
+    let root: PathBuf = write_generated_test_resource(
+      "xrf-texture/place/write/system.ltx",
+      b"#include \"items.ltx\"\n[actor]\nvalue = 1\n".as_slice(),
+    )
+    .expect("expect scratch ltx");
+    let items: &Path = &root.with_file_name("items.ltx");
+
+    fs::write(
+      items,
+      "; artefacts\n[af_base]\n$inventory_icon = true\ninv_grid_width = 1\ninv_grid_height = 1\n\n\
+       [af_new]:af_base ; new artefact\ncost = 10 ; trader price\n\n[af_old]\ninv_grid_x = 0\n",
+    )
+    .expect("expect items ltx");
+
+    let placement: InventorySpriteDescriptor = InventorySpriteDescriptor {
+      section: String::from("af_new"),
+      custom_icon: None,
+      x: 4,
+      y: 2,
+      w: 1,
+      h: 1,
+    };
+
+    assert_eq!(
+      PlaceEquipmentProcessor::write(&root, &[placement]).expect("expect positions to be written"),
+      vec![items.to_path_buf()]
+    );
+
+    let written: Ltx = Ltx::read_from_path(items).expect("expect written ltx to parse");
+
+    assert_eq!(written.get_from("af_new", "inv_grid_x"), Some("4"));
+    assert_eq!(written.get_from("af_new", "inv_grid_y"), Some("2"));
+    assert_eq!(written.get_from("af_base", "inv_grid_x"), None);
+    assert!(
+      written
+        .section("af_new")
+        .is_some_and(|section| section.inherits_section("af_base"))
+    );
+
+    assert_eq!(
+      fs::read_to_string(items).expect("expect written ltx to be read"),
+      "; artefacts\n[af_base]\n$inventory_icon = true\ninv_grid_width = 1\ninv_grid_height = 1\n\n\
+       [af_new]:af_base ; new artefact\ncost = 10 ; trader price\ninv_grid_x = 4\ninv_grid_y = 2\n\n\
+       [af_old]\ninv_grid_x = 0\n"
+    );
+  }
+
+  #[test]
+  fn replaces_positions_keeping_comments() {
+    assert_eq!(
+      PlaceEquipmentProcessor::set_source_field(
+        "[wpn_a]\r\n  inv_grid_x = 1 ; column\r\ninv_grid_y = 0",
+        "wpn_a",
+        "inv_grid_x",
+        "7"
+      ),
+      "[wpn_a]\r\n  inv_grid_x = 7 ; column\r\ninv_grid_y = 0"
+    );
+    assert_eq!(
+      PlaceEquipmentProcessor::set_source_field("[wpn_a]\r\ninv_grid_x = 1", "wpn_a", "inv_grid_y", "3"),
+      "[wpn_a]\r\ninv_grid_x = 1\r\ninv_grid_y = 3\r\n"
+    );
+    assert_eq!(
+      PlaceEquipmentProcessor::set_source_field("[wpn_a]\ncost = 1\n", "wpn_b", "inv_grid_y", "3"),
+      "[wpn_a]\ncost = 1\n"
+    );
+  }
+}
@@ -1,12 +1,12 @@
 use std::path::PathBuf;
 
-use image::RgbaImage;
 use xrf_dds::ImageFormat;
 use xrf_ltx::Ltx;
 
 pub struct UnpackEquipmentOptions {
   pub ltx: Ltx,
-  pub source: RgbaImage,
+  /// Path of the equipment sheet, other atlases are resolved relative to it.
+  pub source: PathBuf,
   pub output: xrf_output::OutputOptions,
   pub output_path: PathBuf,
   pub dds_compression_format: ImageFormat,
@@ -1,5 +1,8 @@
-use image::GenericImageView;
-use xrf_dds::Mipmaps;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use image::{GenericImageView, RgbaImage};
+use xrf_dds::{DdsFile, Mipmaps};
 use xrf_error::XrfResult;
 
 use crate::constants::DDS_EXTENSION;
@@ -9,14 +12,39 @@ use crate::{UnpackEquipmentOptions, save_image_as_ui_dds};
 pub struct UnpackEquipmentProcessor {}
 
 impl UnpackEquipmentProcessor {
+  /// Slice icons of every section out of the equipment sheet and the other atlases sections point at.
+  ///
+  /// A missing custom atlas only skips its icons, the equipment sheet itself is required.
   pub fn unpack_sprites(options: UnpackEquipmentOptions) -> XrfResult {
     let mut count: u32 = 0;
 
-    for (section_name, section) in &options.ltx.sections {
-      if let Some(sprite) = InventorySpriteDescriptor::new_optional_from_section(section_name, section)
-        && Self::unpack_sprite(&options, &sprite)?
-      {
-        count += 1;
+    for (atlas, descriptors) in
+      InventorySpriteDescriptor::group_by_atlas(InventorySpriteDescriptor::new_list_from_ltx(&options.ltx))
+    {
+      if descriptors.is_empty() {
+        continue;
+      }
+
+      let atlas_path: PathBuf = InventorySpriteDescriptor::resolve_atlas_path(&options.source, atlas.as_deref());
+
+      if atlas.is_some() && !atlas_path.is_file() {
+        xrf_output::warning!(
+          options.output,
+          "Skip {} icons of atlas '{}', no file found at {}",
+          descriptors.len(),
+          atlas.unwrap_or_default(),
+          atlas_path.display()
+        );
+
+        continue;
+      }
+
+      let source: RgbaImage = Self::read_atlas(&options, &atlas_path)?;
+
+      for sprite in &descriptors {
+        if Self::unpack_sprite(&options, &source, sprite)? {
+          count += 1;
+        }
       }
     }
 
@@ -25,7 +53,15 @@ impl UnpackEquipmentProcessor {
     Ok(())
   }
 
-  pub fn unpack_sprite(options: &UnpackEquipmentOptions, sprite: &InventorySpriteDescriptor) -> XrfResult<bool> {
+  /// Slice the icon of one section out of the atlas holding it.
+  ///
+  /// Icons of other atlases go to a folder named after their `$inventory_icon_path`, which is where
+  /// packing reads them back from.
+  pub fn unpack_sprite(
+    options: &UnpackEquipmentOptions,
+    source: &RgbaImage,
+    sprite: &InventorySpriteDescriptor,
+  ) -> XrfResult<bool> {
     let (x, y, w, h) = sprite.get_boundaries();
 
     xrf_output::verbose!(
@@ -38,9 +74,7 @@ impl UnpackEquipmentProcessor {
       sprite.h,
     );
 
-    // todo: Respect custom icon path from LTX file here (sprite.custom_icon).
-
-    if x + w > options.source.width() || y + h > options.source.height() {
+    if x + w > source.width() || y + h > source.height() {
       xrf_output::warning!(
         options.output,
         "Skip for possible section: '{}' - icon is out of source file bonds",
@@ -49,13 +83,15 @@ impl UnpackEquipmentProcessor {
 
       Ok(false)
     } else {
+      let directory: PathBuf = sprite.get_sprite_directory(&options.output_path);
+
+      fs::create_dir_all(&directory)?;
+
       // Unpacked icons are packing input read at their base level, so a mip chain would only cost
       // space.
       save_image_as_ui_dds(
-        &options
-          .output_path
-          .join(format!("{}.{}", sprite.section, DDS_EXTENSION)),
-        &options.source.view(x, y, w, h).to_image(),
+        &directory.join(format!("{}.{}", sprite.section, DDS_EXTENSION)),
+        &source.view(x, y, w, h).to_image(),
         options.dds_compression_format,
         Mipmaps::Disabled,
       )?;
@@ -63,4 +99,83 @@ impl UnpackEquipmentProcessor {
       Ok(true)
     }
   }
+
+  fn read_atlas(options: &UnpackEquipmentOptions, path: &Path) -> XrfResult<RgbaImage> {
+    xrf_output::info!(options.output, "Opening DDS file: {}", path.display());
+
+    let file: DdsFile = DdsFile::read_from_path(path)?;
+    let metadata = file.metadata();
+
+    xrf_output::info!(
+      options.output,
+      "Source DDS file details: {}x{}, mip-maps: {}, format: {:?}",
+      metadata.width,
+      metadata.height,
+      metadata.declared_mipmap_levels.unwrap_or(0),
+      metadata.dx10_format
+    );
+
+    file.decode_rgba(0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+  use std::path::PathBuf;
+
+  use image::{Rgba, RgbaImage};
+  use xrf_dds::{DdsFile, ImageFormat};
+  use xrf_ltx::Ltx;
+  use xrf_test_utils::utils::write_generated_test_resource;
+
+  use super::UnpackEquipmentProcessor;
+  use crate::UnpackEquipmentOptions;
+  use crate::utils::test_utils::write_test_image;
+
+  #[test]
+  fn unpacks_icons_of_other_atlases_into_folders_named_after_them() {
+    let root: PathBuf = write_generated_test_resource(
+      "xrf-texture/unpack/atlases/textures/ui/system.ltx",
+      "[wpn_a]\n$inventory_icon = true\ninv_grid_x = 0\ninv_grid_y = 0\ninv_grid_width = 1\ninv_grid_height = 1\n\
+       [wpn_mod]\n$inventory_icon = true\n$inventory_icon_path = ui\\mod\\ui_icon_mod\n\
+       inv_grid_x = 0\ninv_grid_y = 0\ninv_grid_width = 1\ninv_grid_height = 1\n"
+        .as_bytes(),
+    )
+    .expect("expect scratch ltx");
+    let output_path: PathBuf = root.with_file_name("icons");
+
+    fs::create_dir_all(&output_path).expect("expect icons folder");
+
+    let source: PathBuf = write_test_image(
+      "xrf-texture/unpack/atlases/textures/ui/ui_icon_equipment.dds",
+      &RgbaImage::from_pixel(52, 52, Rgba([255, 0, 0, 255])),
+    );
+
+    write_test_image(
+      "xrf-texture/unpack/atlases/textures/ui/mod/ui_icon_mod.dds",
+      &RgbaImage::from_pixel(52, 52, Rgba([0, 0, 255, 255])),
+    );
+
+    UnpackEquipmentProcessor::unpack_sprites(UnpackEquipmentOptions {
+      ltx: Ltx::read_from_file_full(&root).expect("expect test LTX to parse"),
+      source,
+      output: Default::default(),
+      output_path: output_path.clone(),
+      dds_compression_format: ImageFormat::BC3RgbaUnorm,
+    })
+    .expect("expect icons to be unpacked");
+
+    for (path, color) in [
+      (output_path.join("wpn_a.dds"), [255, 0, 0, 255]),
+      (output_path.join("ui/mod/ui_icon_mod/wpn_mod.dds"), [0, 0, 255, 255]),
+    ] {
+      let icon: RgbaImage = DdsFile::read_from_path(&path)
+        .expect("expect icon to be written")
+        .decode_rgba(0)
+        .expect("expect icon to decode");
+
+      assert_eq!(icon.get_pixel(25, 25).0, color);
+    }
+  }
 }
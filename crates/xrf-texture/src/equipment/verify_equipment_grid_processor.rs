@@ -7,6 +7,8 @@ use crate::data::InventorySpriteDescriptor;
 /// Two sections whose inventory icon rectangles cover a common cell.
 #[derive(Clone, Debug, PartialEq)]
 pub struct EquipmentGridOverlap {
+  /// Atlas both icons are placed on, `None` for the equipment sheet.
+  pub atlas: Option<String>,
   pub first: String,
   pub second: String,
   /// One cell both rectangles cover, enough to locate the clash on the sheet.
@@ -17,18 +19,32 @@ pub struct EquipmentGridOverlap {
 /// Finds inventory icon rectangles that overlap without being identical.
 ///
 /// Identical rectangles are not reported. Sharing a slot is legitimate and expected for `_nimble`,
-/// `_snag` and the `pri_a15_` quest copies.
+/// `_snag` and the `pri_a15_` quest copies. Rectangles on different atlases never overlap, each atlas
+/// being a grid of its own.
 pub struct VerifyEquipmentGridProcessor {}
 
 impl VerifyEquipmentGridProcessor {
   pub fn find_overlaps(ltx: &Ltx) -> Vec<EquipmentGridOverlap> {
-    let descriptors: Vec<InventorySpriteDescriptor> = InventorySpriteDescriptor::new_list_from_ltx(ltx);
+    let mut overlaps: Vec<EquipmentGridOverlap> =
+      InventorySpriteDescriptor::group_by_atlas(InventorySpriteDescriptor::new_list_from_ltx(ltx))
+        .into_iter()
+        .flat_map(|(atlas, descriptors)| Self::find_atlas_overlaps(atlas, &descriptors))
+        .collect();
 
+    overlaps.sort_by(|a, b| (&a.atlas, a.cell.1, a.cell.0, &a.first).cmp(&(&b.atlas, b.cell.1, b.cell.0, &b.first)));
+
+    overlaps
+  }
+
+  fn find_atlas_overlaps(
+    atlas: Option<String>,
+    descriptors: &[InventorySpriteDescriptor],
+  ) -> Vec<EquipmentGridOverlap> {
     // Cell -> the rectangles covering it. Rectangles are small and the sheet is sparse, so mapping
     // cells is cheaper and clearer than comparing every pair of rectangles.
     let mut cells: HashMap<(u32, u32), Vec<&InventorySpriteDescriptor>> = HashMap::new();
 
-    for descriptor in &descriptors {
+    for descriptor in descriptors {
       for y in descriptor.y..descriptor.y.saturating_add(descriptor.h.max(1)) {
         for x in descriptor.x..descriptor.x.saturating_add(descriptor.w.max(1)) {
           cells.entry((x, y)).or_default().push(descriptor);
@@ -62,19 +78,16 @@ impl VerifyEquipmentGridProcessor {
       }
     }
 
-    let mut overlaps: Vec<EquipmentGridOverlap> = counted
+    counted
       .into_iter()
       .map(|((first, second), (overlapping_cells, cell))| EquipmentGridOverlap {
+        atlas: atlas.clone(),
         first,
         second,
         cell,
         overlapping_cells,
       })
-      .collect();
-
-    overlaps.sort_by(|a, b| (a.cell.1, a.cell.0, &a.first).cmp(&(b.cell.1, b.cell.0, &b.first)));
-
-    overlaps
+      .collect()
   }
 
   fn is_same_rect(first: &InventorySpriteDescriptor, second: &InventorySpriteDescriptor) -> bool {
@@ -132,6 +145,26 @@ mod tests {
     assert!(VerifyEquipmentGridProcessor::find_overlaps(&ltx).is_empty());
   }
 
+  #[test]
+  fn ignores_rects_placed_on_different_atlases() {
+    let mut ltx: Ltx = ltx_of(&[("wpn_pm", 18, 0, 2, 1), ("wpn_mod", 18, 0, 1, 1)]);
+
+    ltx
+      .with_section("wpn_mod")
+      .set("$inventory_icon_path", "ui\\ui_icon_mod");
+
+    assert!(VerifyEquipmentGridProcessor::find_overlaps(&ltx).is_empty());
+
+    ltx
+      .with_section("wpn_pm")
+      .set("$inventory_icon_path", "ui\\ui_icon_mod");
+
+    let overlaps = VerifyEquipmentGridProcessor::find_overlaps(&ltx);
+
+    assert_eq!(overlaps.len(), 1);
+    assert_eq!(overlaps[0].atlas.as_deref(), Some("ui\\ui_icon_mod"));
+  }
+
   #[test]
   fn detects_vertical_overlap() {
     let ltx: Ltx = ltx_of(&[("wpn_svd", 20, 0, 6, 2), ("wpn_something", 21, 1, 2, 1)]);
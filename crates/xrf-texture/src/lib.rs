@@ -1,4 +1,6 @@
+mod atlas;
 mod constants;
+mod convert;
 mod crop;
 mod data;
 mod description;
@@ -10,13 +12,17 @@ pub use utils::save_image_as_ui_dds;
 pub use utils::save_image_as_ui_png;
 pub use utils::warn_on_reshaped_ui_dds;
 
+pub use crate::atlas::{BuildAtlasOptions, BuildAtlasPage, BuildAtlasProcessor, BuildAtlasResult};
 pub use crate::constants::DDS_EXTENSION;
 pub use crate::constants::INVENTORY_ICON_GRID_SQUARE_BASE;
 pub use crate::constants::PNG_EXTENSION;
+pub use crate::convert::{
+  ConvertTextureOptions, ConvertTextureProcessor, ConvertTextureResult, alpha_coverage, generate_mipmaps,
+};
 pub use crate::crop::{CropTextureOptions, CropTextureProcessor, CropTextureResult};
 pub use crate::data::{InventorySpriteDescriptor, TextureFileDescriptor, TextureSpriteDescriptor};
 pub use crate::description::{PackDescriptionOptions, PackDescriptionProcessor, UnpackDescriptionProcessor};
 pub use crate::equipment::{
-  EquipmentGridOverlap, PackEquipmentOptions, PackEquipmentProcessor, PackEquipmentResult, UnpackEquipmentOptions,
-  UnpackEquipmentProcessor, VerifyEquipmentGridProcessor,
+  EquipmentGridOverlap, PackEquipmentAtlasResult, PackEquipmentOptions, PackEquipmentProcessor, PackEquipmentResult,
+  PlaceEquipmentProcessor, UnpackEquipmentOptions, UnpackEquipmentProcessor, VerifyEquipmentGridProcessor,
 };
@@ -1,3 +1,6 @@
 mod images;
 
 pub use images::{fit_image_into_bounds, save_image_as_ui_dds, save_image_as_ui_png, warn_on_reshaped_ui_dds};
+
+#[cfg(test)]
+pub mod test_utils;
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use image::RgbaImage;
+use xrf_dds::{ImageFormat, Mipmaps};
+use xrf_ltx::Ltx;
+use xrf_test_utils::utils::write_generated_test_resource;
+
+use crate::PackEquipmentOptions;
+use crate::constants::DDS_EXTENSION;
+use crate::utils::save_image_as_ui_dds;
+
+/// Write an image into a generated test resource, as dds or png by the extension of the resource.
+pub fn write_test_image(resource: &str, image: &RgbaImage) -> PathBuf {
+  let path: PathBuf = write_generated_test_resource(resource, []).expect("expect scratch image");
+
+  if path.extension().is_some_and(|extension| extension == DDS_EXTENSION) {
+    save_image_as_ui_dds(&path, image, ImageFormat::BC3RgbaUnorm, Mipmaps::Disabled).expect("expect DDS image");
+  } else {
+    image.save(&path).expect("expect image to be written");
+  }
+
+  path
+}
+
+/// Equipment options over a generated `system.ltx` of the `folder` resource, with icons written as png
+/// into the `icons` folder beside it.
+///
+/// Icon names are relative to the `icons` folder, so icons of other atlases go into their subfolders.
+pub fn equipment_options_for(folder: &str, ltx: &str, icons: &[(&str, RgbaImage)]) -> PackEquipmentOptions {
+  let root: PathBuf =
+    write_generated_test_resource(&format!("{folder}/system.ltx"), ltx.as_bytes()).expect("expect scratch ltx");
+
+  for (icon, image) in icons {
+    write_test_image(&format!("{folder}/icons/{icon}.png"), image);
+  }
+
+  PackEquipmentOptions {
+    ltx: Ltx::read_from_file_full(&root).expect("expect test LTX to parse"),
+    source: root.with_file_name("icons"),
+    output: Default::default(),
+    output_path: root.with_file_name("ui_icon_equipment.dds"),
+    dds_compression_format: ImageFormat::BC3RgbaUnorm,
+    is_strict: false,
+  }
+}
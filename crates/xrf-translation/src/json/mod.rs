@@ -2,6 +2,7 @@
 
 pub(crate) mod constants;
 pub(crate) mod read;
+pub(crate) mod source_hashes;
 pub(crate) mod write;
 
 pub(crate) use constants::FILE_EXTENSION;
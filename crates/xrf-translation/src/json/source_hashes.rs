@@ -0,0 +1,129 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use xrf_error::{XrfError, XrfResult};
+
+use crate::staged_write::write_file_staged;
+use crate::types::{TranslationEntry, TranslationVariant};
+
+/// Extension of the file stored beside a JSON source, replacing its own.
+///
+/// Anything but `json`, so the build and the verifier skip it as they skip every file they do not know.
+pub(crate) const SOURCE_HASHES_FILE_EXTENSION: &str = "hashes";
+
+/// The language translations are made from.
+pub(crate) const SOURCE_LANGUAGE: &str = "eng";
+
+/// Per id and language, the hash of the source text the translation was last written against.
+pub(crate) type SourceHashes = IndexMap<String, IndexMap<String, String>>;
+
+/// Path of the hashes kept for a JSON source.
+pub(crate) fn source_hashes_path(path: &Path) -> PathBuf {
+  path.with_extension(SOURCE_HASHES_FILE_EXTENSION)
+}
+
+/// Hash of a source text, stable across builds and platforms as it is stored in the project.
+///
+/// FNV-1a: collisions only matter between two revisions of one string, and it needs no dependency.
+pub(crate) fn hash_source_text(value: &TranslationVariant) -> String {
+  let hash: u64 = value
+    .to_single_line()
+    .bytes()
+    .fold(0xcbf2_9ce4_8422_2325, |hash: u64, byte: u8| {
+      (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+  format!("{hash:016x}")
+}
+
+/// Read the hashes kept for a JSON source, none being stored when the file does not exist yet.
+///
+/// # Errors
+///
+/// Returns an IO error when the file exists but cannot be read, and a parsing error when it is not
+/// valid JSON.
+pub(crate) fn read_source_hashes(path: &Path) -> XrfResult<SourceHashes> {
+  let hashes_path: PathBuf = source_hashes_path(path);
+
+  if !hashes_path.is_file() {
+    return Ok(SourceHashes::new());
+  }
+
+  serde_json::from_slice(&fs::read(&hashes_path)?).map_err(|error| {
+    XrfError::new_parsing_error(format!(
+      "Failed to parse translation source hashes '{}': {error}",
+      hashes_path.display()
+    ))
+  })
+}
+
+/// Write the hashes kept for a JSON source.
+///
+/// # Errors
+///
+/// Returns a parsing error when the hashes cannot be serialized and an IO error when the file cannot
+/// be written.
+pub(crate) fn write_source_hashes(path: &Path, hashes: &SourceHashes) -> XrfResult {
+  let hashes_path: PathBuf = source_hashes_path(path);
+  let mut serialized: Vec<u8> = serde_json::to_vec_pretty(hashes).map_err(|error| {
+    XrfError::new_parsing_error(format!(
+      "Failed to serialize translation source hashes '{}': {error}",
+      hashes_path.display()
+    ))
+  })?;
+
+  serialized.push(b'\n');
+
+  if hashes_path.is_file() {
+    write_file_staged(&hashes_path, &serialized)
+  } else {
+    Ok(fs::write(&hashes_path, serialized)?)
+  }
+}
+
+/// Record a translation as written against the current source text, or forget it without one.
+pub(crate) fn touch_source_hash(
+  hashes: &mut SourceHashes,
+  id: &str,
+  language: &str,
+  source: Option<&TranslationVariant>,
+) {
+  match source {
+    Some(source) => {
+      hashes
+        .entry(id.to_owned())
+        .or_default()
+        .insert(language.to_owned(), hash_source_text(source));
+    }
+    None => forget_source_hash(hashes, id, language),
+  }
+}
+
+/// Drop the hash of one language's translation.
+pub(crate) fn forget_source_hash(hashes: &mut SourceHashes, id: &str, language: &str) {
+  if let Some(languages) = hashes.get_mut(id) {
+    languages.shift_remove(language);
+
+    if languages.is_empty() {
+      hashes.shift_remove(id);
+    }
+  }
+}
+
+/// Whether a translation was written against an English text that has changed since.
+///
+/// Without a stored hash nothing says what the translation was written against, so it is not stale.
+pub(crate) fn is_stale_translation(hashes: &SourceHashes, id: &str, entry: &TranslationEntry, language: &str) -> bool {
+  if language == SOURCE_LANGUAGE {
+    return false;
+  }
+
+  match (
+    hashes.get(id).and_then(|languages| languages.get(language)),
+    entry.get(SOURCE_LANGUAGE).and_then(Option::as_ref),
+  ) {
+    (Some(hash), Some(source)) => *hash != hash_source_text(source),
+    _ => false,
+  }
+}
@@ -5,17 +5,25 @@ use xrf_error::{XrfError, XrfResult};
 
 use crate::edit::TranslationEdit;
 use crate::json::read::read_json;
+use crate::json::source_hashes::{
+  SOURCE_LANGUAGE, SourceHashes, forget_source_hash, read_source_hashes, touch_source_hash, write_source_hashes,
+};
 use crate::staged_write::write_file_staged;
-use crate::types::TranslationJson;
+use crate::types::{TranslationJson, TranslationVariant};
 
 /// Apply edits to one language inside a multi-language JSON source.
 ///
+/// Editing a translation records the hash of the English text it was written against, which is what
+/// lets coverage tell a translation made stale by a later English rewrite.
+///
 /// # Errors
 ///
 /// Returns a parsing error when the file cannot be read or re-serialized, and an IO error when it
 /// cannot be replaced.
 pub fn apply_edits(path: &Path, language: &str, edits: &[TranslationEdit]) -> XrfResult {
   let mut parsed: TranslationJson = read_json(path)?;
+  let mut hashes: SourceHashes = read_source_hashes(path)?;
+  let original_hashes: SourceHashes = hashes.clone();
 
   for edit in edits {
     match edit {
@@ -26,10 +34,18 @@ pub fn apply_edits(path: &Path, language: &str, edits: &[TranslationEdit]) -> Xr
           .entry(id.clone())
           .or_default()
           .insert(language.to_owned(), Some(value.clone()));
+
+        if language != SOURCE_LANGUAGE {
+          let source: Option<&TranslationVariant> = parsed[id.as_str()].get(SOURCE_LANGUAGE).and_then(Option::as_ref);
+
+          touch_source_hash(&mut hashes, id, language, source);
+        }
       }
       TranslationEdit::Remove { id } => {
         // One language, matching what removing an entry from an XML file does. Dropping every
         // language at once is a separate action the caller has to ask for by name.
+        forget_source_hash(&mut hashes, id, language);
+
         if let Some(entry) = parsed.get_mut(id) {
           entry.shift_remove(language);
 
@@ -52,5 +68,11 @@ pub fn apply_edits(path: &Path, language: &str, edits: &[TranslationEdit]) -> Xr
     serialized.push(b'\n');
   }
 
-  write_file_staged(path, &serialized)
+  write_file_staged(path, &serialized)?;
+
+  if hashes != original_hashes {
+    write_source_hashes(path, &hashes)?;
+  }
+
+  Ok(())
 }
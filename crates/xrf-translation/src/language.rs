@@ -1,108 +1,203 @@
+use std::fs;
 use std::path::Path;
-use std::str::FromStr;
 
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
 use xrf_error::{XrfError, XrfResult};
-use xrf_utils::{XRayEncoding, new_windows1250_encoder, new_windows1251_encoder, new_windows1252_encoder};
-
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Display)]
-pub enum TranslationLanguage {
-  #[display("all")]
-  All,
-  #[display("eng")]
-  English,
-  #[display("rus")]
-  Russian,
-  #[display("ukr")]
-  Ukrainian,
-  #[display("pol")]
-  Polish,
-  #[display("fra")]
-  French,
-  #[display("ger")]
-  German,
-  #[display("ita")]
-  Italian,
-  #[display("spa")]
-  Spanish,
+use xrf_utils::XRayEncoding;
+use xrf_xml::encoding_from_label;
+
+use crate::project::constants::LANGUAGE_NEUTRAL;
+
+/// A language string tables are built for, and the code page the engine reads it in.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Display, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[display("{code}")]
+pub struct TranslationLanguage {
+  /// Key sources carry the language under: the JSON field and the `name.eng.xml` suffix.
+  pub code: String,
+  /// Label of the code page the language's string tables are written in, `windows-1250` or `utf-8`.
+  pub encoding: String,
+  /// Folder under `configs/text` the language is built into, the code itself when not configured.
+  #[serde(default)]
+  pub folder: String,
+  /// The BCP 47 code translator tools name the language by.
+  #[serde(default)]
+  pub locale: Option<String>,
 }
 
-impl FromStr for TranslationLanguage {
-  type Err = String;
-
-  fn from_str(s: &str) -> Result<Self, Self::Err> {
-    match s {
-      "all" => Ok(Self::All),
-      "eng" => Ok(Self::English),
-      "rus" => Ok(Self::Russian),
-      "ukr" => Ok(Self::Ukrainian),
-      "pol" => Ok(Self::Polish),
-      "fra" => Ok(Self::French),
-      "ger" => Ok(Self::German),
-      "ita" => Ok(Self::Italian),
-      "spa" => Ok(Self::Spanish),
-      _ => Err(format!("Unknown language: {}", s)),
+impl TranslationLanguage {
+  pub fn new(code: &str, encoding: &str, locale: Option<&str>) -> Self {
+    Self {
+      code: code.to_owned(),
+      encoding: encoding.to_owned(),
+      folder: code.to_owned(),
+      locale: locale.map(String::from),
     }
   }
-}
 
-impl TranslationLanguage {
+  /// The language sources are written in, which every other one is translated from.
+  pub fn english() -> Self {
+    Self::new("eng", "windows-1252", Some("en"))
+  }
+
   pub fn get_language_encoding(&self) -> String {
-    match self {
-      Self::Russian | Self::Ukrainian => String::from("windows-1251"),
-      Self::German | Self::Polish => String::from("windows-1250"),
-      _ => String::from("windows-1252"),
+    self.encoding.clone()
+  }
+
+  /// # Errors
+  ///
+  /// Returns an encoding error when the configured code page has no encoder.
+  pub fn new_language_encoder(&self) -> XrfResult<XRayEncoding> {
+    encoding_from_label(&self.encoding)
+  }
+
+  pub fn get_locale_code(&self) -> Option<&str> {
+    self.locale.as_deref()
+  }
+}
+
+/// Every language a project builds, the shipped ones unless a configuration adds or redefines some.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranslationLanguages {
+  languages: Vec<TranslationLanguage>,
+}
+
+impl Default for TranslationLanguages {
+  fn default() -> Self {
+    Self {
+      languages: vec![
+        TranslationLanguage::english(),
+        TranslationLanguage::new("fra", "windows-1252", Some("fr")),
+        TranslationLanguage::new("ger", "windows-1250", Some("de")),
+        TranslationLanguage::new("ita", "windows-1252", Some("it")),
+        TranslationLanguage::new("pol", "windows-1250", Some("pl")),
+        TranslationLanguage::new("rus", "windows-1251", Some("ru")),
+        TranslationLanguage::new("spa", "windows-1252", Some("es")),
+        TranslationLanguage::new("ukr", "windows-1251", Some("uk")),
+      ],
+    }
+  }
+}
+
+impl TranslationLanguages {
+  /// Read a JSON list of languages over the shipped ones.
+  ///
+  /// A listed code replaces the shipped language of that code, so a project can move one to another
+  /// code page or folder, and any other code is added after them.
+  ///
+  /// # Errors
+  ///
+  /// Returns an IO error when the file cannot be read, a parsing error when it is not a list of
+  /// languages, and an invalid error for a language that could never be built.
+  pub fn read<P: AsRef<Path>>(path: P) -> XrfResult<Self> {
+    let path: &Path = path.as_ref();
+    let configured: Vec<TranslationLanguage> = serde_json::from_slice(&fs::read(path)?).map_err(|error| {
+      XrfError::new_parsing_error(format!("Failed to parse languages '{}': {error}", path.display()))
+    })?;
+    let mut languages: Self = Self::default();
+
+    for language in configured {
+      languages.insert(language)?;
     }
+
+    Ok(languages)
   }
 
-  pub fn new_language_encoder(&self) -> XRayEncoding {
-    match self {
-      Self::Russian | Self::Ukrainian => new_windows1251_encoder(),
-      Self::German | Self::Polish => new_windows1250_encoder(),
-      _ => new_windows1252_encoder(),
+  /// Add a language, or replace the one with the same code.
+  ///
+  /// # Errors
+  ///
+  /// Returns an invalid error for an empty or reserved code, and an encoding error for a code page
+  /// there is no encoder for, so a bad configuration fails before anything is built with it.
+  pub fn insert(&mut self, mut language: TranslationLanguage) -> XrfResult {
+    if language.code.is_empty() || language.code == LANGUAGE_NEUTRAL || language.code.contains(['.', '/', '\\']) {
+      return Err(XrfError::new_invalid_error(format!(
+        "'{}' cannot be used as a language code",
+        language.code
+      )));
+    }
+
+    language.new_language_encoder()?;
+
+    if language.folder.is_empty() {
+      language.folder = language.code.clone();
+    }
+
+    match self.languages.iter_mut().find(|known| known.code == language.code) {
+      Some(known) => *known = language,
+      None => self.languages.push(language),
     }
+
+    Ok(())
   }
 
-  pub fn get_all() -> Vec<Self> {
-    vec![
-      Self::English,
-      Self::French,
-      Self::German,
-      Self::Italian,
-      Self::Polish,
-      Self::Russian,
-      Self::Spanish,
-      Self::Ukrainian,
-    ]
+  pub fn get_all(&self) -> &[TranslationLanguage] {
+    &self.languages
   }
 
-  pub fn get_all_strings() -> Vec<String> {
-    Self::get_all().iter().map(|it| it.to_string()).collect()
+  pub fn get_all_strings(&self) -> Vec<String> {
+    self.languages.iter().map(|it| it.to_string()).collect()
   }
 
-  pub fn from_str_single(language: &str) -> XrfResult<Self> {
-    match Self::from_str(language).map_err(|it| XrfError::new_parsing_error(it.to_string()))? {
-      Self::All => Err(XrfError::new_unknown_language_error(String::from(
-        "Unexpected language 'all' provided'",
-      ))),
-      language => Ok(language),
+  pub fn get(&self, code: &str) -> Option<&TranslationLanguage> {
+    self.languages.iter().find(|language| language.code == code)
+  }
+
+  /// Resolve a language given on the command line, `None` standing for `all`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an unknown language error for a code none of the languages has.
+  pub fn select(&self, code: &str) -> XrfResult<Option<TranslationLanguage>> {
+    if code == LANGUAGE_NEUTRAL {
+      return Ok(None);
+    }
+
+    self.get_single(code).map(Some)
+  }
+
+  /// Resolve a language that has to be a single one.
+  ///
+  /// # Errors
+  ///
+  /// Returns an unknown language error for `all` and for a code none of the languages has.
+  pub fn get_single(&self, code: &str) -> XrfResult<TranslationLanguage> {
+    self.get(code).cloned().ok_or_else(|| {
+      XrfError::new_unknown_language_error(format!(
+        "Unexpected language '{code}' provided, expected one of: {}",
+        self.get_all_strings().join(", ")
+      ))
+    })
+  }
+
+  /// Every language a selection stands for, all of them for `None`.
+  pub fn resolve(&self, selected: Option<&TranslationLanguage>) -> Vec<TranslationLanguage> {
+    match selected {
+      Some(language) => vec![language.clone()],
+      None => self.languages.clone(),
     }
   }
 
   /// Read the language off a `name.eng.xml` style filename, which is how sources carry it.
-  pub(crate) fn from_file_name(path: &Path) -> Option<Self> {
+  pub(crate) fn get_by_file_name(&self, path: &Path) -> Option<&TranslationLanguage> {
     let file_name: &str = path.file_name()?.to_str()?;
     let mut parts = file_name.rsplit('.');
 
     parts.next()?;
 
-    Self::from_str_single(parts.next()?).ok()
+    self.get(parts.next()?)
   }
 
   /// Read the language off a `text/rus/st_dialogs.xml` parent directory, which is how gamedata
   /// carries it.
-  pub(crate) fn from_parent_directory(path: &Path) -> Option<Self> {
-    Self::from_str_single(path.parent()?.file_name()?.to_str()?).ok()
+  pub(crate) fn get_by_parent_directory(&self, path: &Path) -> Option<&TranslationLanguage> {
+    self.get_by_folder(path.parent()?.file_name()?.to_str()?)
+  }
+
+  /// Find the language built into a `configs/text` folder.
+  pub(crate) fn get_by_folder(&self, folder: &str) -> Option<&TranslationLanguage> {
+    self.languages.iter().find(|language| language.folder == folder)
   }
 }
 
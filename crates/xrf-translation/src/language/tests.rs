@@ -1,72 +1,125 @@
 use std::path::Path;
-use std::str::FromStr;
 
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::write_generated_test_resource;
 use xrf_utils::{new_windows1250_encoder, new_windows1252_encoder};
 
-use crate::TranslationLanguage;
-use crate::language::find_unencodable_character;
+use crate::language::{TranslationLanguage, TranslationLanguages, find_unencodable_character};
 
 #[test]
-fn test_from_str() {
-  assert_eq!(
-    TranslationLanguage::from_str("eng").unwrap(),
-    TranslationLanguage::English
-  );
-  assert_eq!(
-    TranslationLanguage::from_str("ukr").unwrap(),
-    TranslationLanguage::Ukrainian
-  );
-  assert_eq!(TranslationLanguage::from_str("all").unwrap(), TranslationLanguage::All);
+fn test_select() {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
+  assert_eq!(languages.select("eng").unwrap(), Some(TranslationLanguage::english()));
+  assert_eq!(languages.select("ukr").unwrap().unwrap().code, "ukr");
+  assert_eq!(languages.select("all").unwrap(), None);
+  assert!(languages.select("cze").is_err());
 }
 
 #[test]
-fn test_from_str_single() {
-  assert!(TranslationLanguage::from_str_single("all").is_err());
-  assert_eq!(
-    TranslationLanguage::from_str_single("eng").unwrap(),
-    TranslationLanguage::English
-  );
-  assert_eq!(
-    TranslationLanguage::from_str_single("spa").unwrap(),
-    TranslationLanguage::Spanish
-  );
+fn test_get_single() {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
+  assert!(languages.get_single("all").is_err());
+  assert_eq!(languages.get_single("eng").unwrap(), TranslationLanguage::english());
+  assert_eq!(languages.get_single("spa").unwrap().code, "spa");
 }
 
 #[test]
 fn selects_the_xray_encoding_for_each_language() {
-  assert_eq!(TranslationLanguage::English.get_language_encoding(), "windows-1252");
-  assert_eq!(TranslationLanguage::French.get_language_encoding(), "windows-1252");
-  assert_eq!(TranslationLanguage::Italian.get_language_encoding(), "windows-1252");
-  assert_eq!(TranslationLanguage::Spanish.get_language_encoding(), "windows-1252");
-  assert_eq!(TranslationLanguage::German.get_language_encoding(), "windows-1250");
-  assert_eq!(TranslationLanguage::Polish.get_language_encoding(), "windows-1250");
-  assert_eq!(TranslationLanguage::Russian.get_language_encoding(), "windows-1251");
-  assert_eq!(TranslationLanguage::Ukrainian.get_language_encoding(), "windows-1251");
+  let languages: TranslationLanguages = TranslationLanguages::default();
+  let encoding = |code: &str| languages.get(code).unwrap().get_language_encoding();
+
+  assert_eq!(encoding("eng"), "windows-1252");
+  assert_eq!(encoding("fra"), "windows-1252");
+  assert_eq!(encoding("ita"), "windows-1252");
+  assert_eq!(encoding("spa"), "windows-1252");
+  assert_eq!(encoding("ger"), "windows-1250");
+  assert_eq!(encoding("pol"), "windows-1250");
+  assert_eq!(encoding("rus"), "windows-1251");
+  assert_eq!(encoding("ukr"), "windows-1251");
 }
 
 #[test]
 fn reads_the_language_off_a_source_filename() {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
   assert_eq!(
-    TranslationLanguage::from_file_name(Path::new("translations/dialogs.ukr.xml")),
-    Some(TranslationLanguage::Ukrainian)
+    languages
+      .get_by_file_name(Path::new("translations/dialogs.ukr.xml"))
+      .map(|language| language.code.as_str()),
+    Some("ukr")
   );
   // No suffix is not English, it is no language at all: the build copies such a file everywhere.
-  assert_eq!(TranslationLanguage::from_file_name(Path::new("example.xml")), None);
-  assert_eq!(TranslationLanguage::from_file_name(Path::new("dialogs.all.xml")), None);
+  assert_eq!(languages.get_by_file_name(Path::new("example.xml")), None);
+  assert_eq!(languages.get_by_file_name(Path::new("dialogs.all.xml")), None);
 }
 
 #[test]
 fn reads_the_language_off_a_gamedata_directory() {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
   assert_eq!(
-    TranslationLanguage::from_parent_directory(Path::new("configs/text/rus/st_dialogs.xml")),
-    Some(TranslationLanguage::Russian)
+    languages
+      .get_by_parent_directory(Path::new("configs/text/rus/st_dialogs.xml"))
+      .map(|language| language.code.as_str()),
+    Some("rus")
   );
   assert_eq!(
-    TranslationLanguage::from_parent_directory(Path::new("configs/text/cze/st_dialogs.xml")),
+    languages.get_by_parent_directory(Path::new("configs/text/cze/st_dialogs.xml")),
     None
   );
 }
 
+#[test]
+fn configured_languages_extend_and_replace_the_shipped_ones() -> XrfResult {
+  let path = write_generated_test_resource(
+    "language/languages.json",
+    r#"[
+      {"code": "cze", "encoding": "windows-1250", "locale": "cs"},
+      {"code": "tur", "encoding": "cp1254", "folder": "turkish"},
+      {"code": "chn", "encoding": "utf-8", "locale": "zh"},
+      {"code": "ukr", "encoding": "utf-8", "locale": "uk"}
+    ]"#,
+  )?;
+  let languages: TranslationLanguages = TranslationLanguages::read(&path)?;
+
+  assert_eq!(languages.get_all().len(), 11);
+  assert_eq!(languages.get("cze").unwrap().folder, "cze");
+  assert_eq!(languages.get("ukr").unwrap().get_language_encoding(), "utf-8");
+  assert_eq!(
+    languages
+      .get_by_parent_directory(Path::new("configs/text/turkish/st_dialogs.xml"))
+      .map(|language| language.code.as_str()),
+    Some("tur")
+  );
+  assert_eq!(
+    languages
+      .get_by_file_name(Path::new("dialogs.chn.xml"))
+      .map(|language| language.code.as_str()),
+    Some("chn")
+  );
+
+  Ok(())
+}
+
+#[test]
+fn refuses_a_language_that_could_never_be_built() {
+  let mut languages: TranslationLanguages = TranslationLanguages::default();
+
+  assert!(
+    languages
+      .insert(TranslationLanguage::new("jpn", "shift_jis", None))
+      .is_err()
+  );
+  assert!(
+    languages
+      .insert(TranslationLanguage::new("all", "utf-8", None))
+      .is_err()
+  );
+  assert!(languages.get("jpn").is_none());
+}
+
 #[test]
 fn finds_the_character_an_encoding_cannot_hold() {
   assert_eq!(
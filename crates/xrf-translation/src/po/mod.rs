@@ -0,0 +1,7 @@
+//! Gettext PO and POT catalogs: the form most translation platforms exchange strings in.
+
+pub(crate) mod read;
+pub(crate) mod write;
+
+#[cfg(test)]
+mod tests;
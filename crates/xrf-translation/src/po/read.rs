@@ -0,0 +1,183 @@
+use xrf_error::{XrfError, XrfResult};
+
+use crate::unit::{TranslationCatalog, TranslationUnit};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum PoKeyword {
+  Context,
+  Id,
+  Value,
+}
+
+/// An entry while its lines are being read.
+#[derive(Default)]
+struct PoEntry {
+  line_number: usize,
+  file: Option<String>,
+  is_fuzzy: bool,
+  context: Option<String>,
+  id: Option<String>,
+  value: Option<String>,
+  keyword: Option<PoKeyword>,
+}
+
+/// Read a gettext catalog written by the export, or by whatever tool a translator edited it in.
+///
+/// Obsolete `#~` entries are dropped. Plural forms are refused, as no X-Ray string has them.
+///
+/// # Errors
+///
+/// Returns a parsing error for a malformed line, an entry without `msgctxt`, or a plural entry.
+pub(crate) fn read_po(source: &str) -> XrfResult<TranslationCatalog> {
+  let mut catalog: TranslationCatalog = TranslationCatalog::default();
+  let mut entry: PoEntry = PoEntry::default();
+
+  for (index, line) in source.lines().enumerate() {
+    let line_number: usize = index + 1;
+    let line: &str = line.trim();
+
+    // Comments lead an entry, so one following a value starts the next entry, blank line or not.
+    if line.starts_with('#') && entry.value.is_some() {
+      finish_entry(&mut catalog, std::mem::take(&mut entry))?;
+    }
+
+    if line.is_empty() {
+      finish_entry(&mut catalog, std::mem::take(&mut entry))?;
+    } else if line.starts_with("#~") {
+      continue;
+    } else if let Some(references) = line.strip_prefix("#:") {
+      entry.file = entry
+        .file
+        .or_else(|| references.split_whitespace().next().map(String::from));
+    } else if let Some(flags) = line.strip_prefix("#,") {
+      entry.is_fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+    } else if line.starts_with('#') {
+      continue;
+    } else if line.starts_with("msgid_plural") || line.starts_with("msgstr[") {
+      return Err(XrfError::new_parsing_error(format!(
+        "Plural forms are not supported, found at line {line_number}"
+      )));
+    } else if line.starts_with('"') {
+      let text: String = unquote(line, line_number)?;
+      let target: Option<&mut Option<String>> = match entry.keyword {
+        Some(PoKeyword::Context) => Some(&mut entry.context),
+        Some(PoKeyword::Id) => Some(&mut entry.id),
+        Some(PoKeyword::Value) => Some(&mut entry.value),
+        None => None,
+      };
+
+      match target {
+        Some(Some(value)) => value.push_str(&text),
+        _ => {
+          return Err(XrfError::new_parsing_error(format!(
+            "String continues no keyword at line {line_number}"
+          )));
+        }
+      }
+    } else {
+      let (keyword, rest) = line
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| XrfError::new_parsing_error(format!("Expected a keyword and a string at line {line_number}")))?;
+      let keyword: PoKeyword = match keyword {
+        "msgctxt" => PoKeyword::Context,
+        "msgid" => PoKeyword::Id,
+        "msgstr" => PoKeyword::Value,
+        keyword => {
+          return Err(XrfError::new_parsing_error(format!(
+            "Unknown keyword '{keyword}' at line {line_number}"
+          )));
+        }
+      };
+
+      // A keyword repeated after the entry's value starts the next entry, blank line or not.
+      if keyword != PoKeyword::Value && entry.value.is_some() {
+        finish_entry(&mut catalog, std::mem::take(&mut entry))?;
+      }
+
+      if entry.line_number == 0 {
+        entry.line_number = line_number;
+      }
+
+      let text: Option<String> = Some(unquote(rest.trim(), line_number)?);
+
+      match keyword {
+        PoKeyword::Context => entry.context = text,
+        PoKeyword::Id => entry.id = text,
+        PoKeyword::Value => entry.value = text,
+      }
+
+      entry.keyword = Some(keyword);
+    }
+  }
+
+  finish_entry(&mut catalog, entry)?;
+
+  Ok(catalog)
+}
+
+fn finish_entry(catalog: &mut TranslationCatalog, entry: PoEntry) -> XrfResult {
+  let Some(source) = entry.id else {
+    return Ok(());
+  };
+
+  if source.is_empty() && entry.context.is_none() {
+    catalog.language = entry.value.as_deref().and_then(|header| {
+      header
+        .lines()
+        .find_map(|line| line.strip_prefix("Language:"))
+        .map(|code| code.trim().to_owned())
+        .filter(|code| !code.is_empty())
+    });
+
+    return Ok(());
+  }
+
+  let id: String = entry.context.ok_or_else(|| {
+    XrfError::new_parsing_error(format!(
+      "Entry at line {} has no msgctxt to tell which id it translates",
+      entry.line_number
+    ))
+  })?;
+
+  catalog.units.push(TranslationUnit {
+    file: entry.file.unwrap_or_default(),
+    id,
+    source,
+    target: entry.value.unwrap_or_default(),
+    is_fuzzy: entry.is_fuzzy,
+  });
+
+  Ok(())
+}
+
+fn unquote(value: &str, line_number: usize) -> XrfResult<String> {
+  let inner: &str = value
+    .strip_prefix('"')
+    .and_then(|value| value.strip_suffix('"'))
+    .ok_or_else(|| XrfError::new_parsing_error(format!("Expected a quoted string at line {line_number}")))?;
+  let mut text: String = String::with_capacity(inner.len());
+  let mut characters = inner.chars();
+
+  while let Some(character) = characters.next() {
+    if character != '\\' {
+      text.push(character);
+      continue;
+    }
+
+    match characters.next() {
+      Some('n') => text.push('\n'),
+      Some('r') => text.push('\r'),
+      Some('t') => text.push('\t'),
+      Some('\\') => text.push('\\'),
+      Some('"') => text.push('"'),
+      other => {
+        return Err(XrfError::new_parsing_error(format!(
+          "Unknown escape '\\{}' at line {line_number}",
+          other.map(String::from).unwrap_or_default()
+        )));
+      }
+    }
+  }
+
+  Ok(text)
+}
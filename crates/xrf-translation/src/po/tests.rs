@@ -0,0 +1,78 @@
+use xrf_error::XrfResult;
+
+use crate::language::TranslationLanguages;
+use crate::po::read::read_po;
+use crate::po::write::write_po;
+use crate::unit::{TranslationCatalog, TranslationUnit};
+
+fn unit(id: &str, source: &str, target: &str, is_fuzzy: bool) -> TranslationUnit {
+  TranslationUnit {
+    file: String::from("dialogs/trader.json"),
+    id: String::from(id),
+    source: String::from(source),
+    target: String::from(target),
+    is_fuzzy,
+  }
+}
+
+#[test]
+fn writes_ids_as_context_and_reads_them_back() -> XrfResult {
+  let units: Vec<TranslationUnit> = vec![
+    unit("st_hello", "Say \"hi\"\\n", "Kazhy \"pryvit\"\\n", false),
+    unit("st_lines", "First\nSecond", "Pershyi\nDruhyi", true),
+    unit("st_new", "New", "", false),
+  ];
+  let written: String = write_po(
+    &units,
+    Some(&TranslationLanguages::default().get_single("ukr").unwrap()),
+  );
+
+  assert!(written.contains("\"Language: uk\\n\"\n"));
+  assert!(written.contains("#: dialogs/trader.json\nmsgctxt \"st_hello\"\nmsgid \"Say \\\"hi\\\"\\\\n\"\n"));
+  assert!(written.contains("#, fuzzy\nmsgctxt \"st_lines\"\nmsgid \"\"\n\"First\\n\"\n\"Second\"\n"));
+
+  let catalog: TranslationCatalog = read_po(&written)?;
+
+  assert_eq!(catalog.language.as_deref(), Some("uk"));
+  assert_eq!(catalog.units, units);
+
+  Ok(())
+}
+
+#[test]
+fn a_template_has_no_language_and_no_translations() -> XrfResult {
+  let written: String = write_po(&[unit("st_hello", "Hello", "", false)], None);
+  let catalog: TranslationCatalog = read_po(&written)?;
+
+  assert!(!written.contains("Language:"));
+  assert_eq!(catalog.language, None);
+  assert_eq!(catalog.units[0].target, "");
+
+  Ok(())
+}
+
+#[test]
+fn reads_entries_not_separated_by_blank_lines_and_skips_obsolete_ones() -> XrfResult {
+  let catalog: TranslationCatalog = read_po(
+    "#: a.json\nmsgctxt \"st_a\"\nmsgid \"A\"\nmsgstr \"A ukr\"\n#: a.json\nmsgctxt \"st_b\"\nmsgid \"B\"\nmsgstr \"\"\n\n\
+     #~ msgctxt \"st_old\"\n#~ msgid \"Old\"\n#~ msgstr \"Staryi\"\n",
+  )?;
+
+  assert_eq!(
+    catalog
+      .units
+      .iter()
+      .map(|unit| (unit.id.as_str(), unit.target.as_str()))
+      .collect::<Vec<_>>(),
+    [("st_a", "A ukr"), ("st_b", "")]
+  );
+
+  Ok(())
+}
+
+#[test]
+fn refuses_plurals_and_entries_without_context() {
+  assert!(read_po("msgid \"apple\"\nmsgid_plural \"apples\"\nmsgstr[0] \"\"\n").is_err());
+  assert!(read_po("msgid \"apple\"\nmsgstr \"yabluko\"\n").is_err());
+  assert!(read_po("msgctxt \"st_a\"\nmsgid \"A\nmsgstr \"\"\n").is_err());
+}
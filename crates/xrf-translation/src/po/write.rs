@@ -0,0 +1,67 @@
+use crate::language::TranslationLanguage;
+use crate::unit::TranslationUnit;
+
+/// Write units as a gettext catalog: a PO file for a language, or a POT template without one.
+///
+/// The id goes into `msgctxt` because two ids may share an English text, and gettext tells entries
+/// apart by context and source together. The source file goes into the `#:` reference the import
+/// reads back.
+pub(crate) fn write_po(units: &[TranslationUnit], language: Option<&TranslationLanguage>) -> String {
+  let mut output: String = String::new();
+
+  output.push_str("msgid \"\"\nmsgstr \"\"\n");
+  output.push_str("\"MIME-Version: 1.0\\n\"\n");
+  output.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+  output.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+
+  if let Some(code) = language.and_then(TranslationLanguage::get_locale_code) {
+    output.push_str(&format!("\"Language: {code}\\n\"\n"));
+  }
+
+  for unit in units {
+    output.push('\n');
+    output.push_str(&format!("#: {}\n", unit.file));
+
+    if unit.is_fuzzy {
+      output.push_str("#, fuzzy\n");
+    }
+
+    write_keyword(&mut output, "msgctxt", &unit.id);
+    write_keyword(&mut output, "msgid", &unit.source);
+    write_keyword(&mut output, "msgstr", &unit.target);
+  }
+
+  output
+}
+
+/// Write a keyword with its quoted value, one string per line for multi-line text as gettext tools do.
+fn write_keyword(output: &mut String, keyword: &str, value: &str) {
+  if !value.contains('\n') {
+    output.push_str(&format!("{keyword} \"{}\"\n", escape(value)));
+
+    return;
+  }
+
+  output.push_str(&format!("{keyword} \"\"\n"));
+
+  for line in value.split_inclusive('\n') {
+    output.push_str(&format!("\"{}\"\n", escape(line)));
+  }
+}
+
+fn escape(value: &str) -> String {
+  let mut escaped: String = String::with_capacity(value.len());
+
+  for character in value.chars() {
+    match character {
+      '\\' => escaped.push_str("\\\\"),
+      '"' => escaped.push_str("\\\""),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      character => escaped.push(character),
+    }
+  }
+
+  escaped
+}
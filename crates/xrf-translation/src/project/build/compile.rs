@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use xrf_error::{XrfError, XrfResult};
+use xrf_utils::XRayEncoding;
 use xrf_xml::serialize_xml;
 
 use crate::language::{TranslationLanguage, find_unencodable_character};
@@ -37,12 +38,14 @@ pub(crate) fn compile_by_language(
     source.len(),
   );
 
+  let encoding: XRayEncoding = language.new_language_encoder()?;
+
   for (key, entry) in source {
     let text: String = entry
       .get(&language_key)
       .map_or(key.clone(), |value| value.as_ref().map_or(key.clone(), render_variant));
 
-    validate_entry_encoding(path, language, key, &text)?;
+    validate_entry_encoding(path, language, encoding, key, &text)?;
 
     compiled.string.push(TranslationEntryCompiled { id: key.clone(), text });
   }
@@ -56,9 +59,15 @@ pub(crate) fn compile_by_language(
   Ok(buffer)
 }
 
-fn validate_entry_encoding(path: &Path, language: &TranslationLanguage, id: &str, text: &str) -> XrfResult {
+fn validate_entry_encoding(
+  path: &Path,
+  language: &TranslationLanguage,
+  encoding: XRayEncoding,
+  id: &str,
+  text: &str,
+) -> XrfResult {
   for (field, value) in [("id", id), ("text", text)] {
-    if let Some(character) = find_unencodable_character(value, language.new_language_encoder()) {
+    if let Some(character) = find_unencodable_character(value, encoding) {
       return Err(XrfError::new_encoding_error(format!(
         "Translation '{}' entry '{}' {} cannot be encoded as {}: '{}' (U+{:04X})",
         path.display(),
@@ -74,7 +83,7 @@ fn validate_entry_encoding(path: &Path, language: &TranslationLanguage, id: &str
   Ok(())
 }
 
-fn render_variant(variant: &TranslationVariant) -> String {
+pub(crate) fn render_variant(variant: &TranslationVariant) -> String {
   match variant {
     TranslationVariant::String(value) => value.clone(),
     // The engine reads `\n` in a string table as a line break, so a multi-line entry joins on it.
@@ -1,11 +1,13 @@
 use std::path::PathBuf;
 
-use crate::language::TranslationLanguage;
+use crate::language::{TranslationLanguage, TranslationLanguages};
 
 pub struct ProjectBuildOptions {
   pub output: xrf_output::OutputOptions,
   pub is_sorted: bool,
   pub path: PathBuf,
   pub output_dir: PathBuf,
-  pub language: TranslationLanguage,
+  /// Language to build, every configured one when `None`.
+  pub language: Option<TranslationLanguage>,
+  pub languages: TranslationLanguages,
 }
@@ -110,17 +110,17 @@ pub fn build_file<P: AsRef<Path>>(path: &P, options: &ProjectBuildOptions) -> Xr
 /// Returns an IO error when a source cannot be read or a target cannot be written.
 pub fn build_xml_file<P: AsRef<Path>>(path: &P, options: &ProjectBuildOptions) -> XrfResult {
   let path_display: Display = path.as_ref().display();
-  let locale: Option<TranslationLanguage> = TranslationLanguage::from_file_name(path.as_ref());
+  let locale: Option<&TranslationLanguage> = options.languages.get_by_file_name(path.as_ref());
 
   if let Some(locale) = locale {
     xrf_output::info!(options.output, "Building XML based translations {path_display}");
 
-    if options.language == TranslationLanguage::All || locale == options.language {
+    if options.language.as_ref().is_none_or(|language| language == locale) {
       log::info!("Building dynamic XML file {} ({})", path_display, locale);
 
       copy(
         &mut File::open(path)?,
-        &mut prepare_target_file(path, &options.output_dir, &locale, options)?,
+        &mut prepare_target_file(path, &options.output_dir, locale, options)?,
       )?;
     } else {
       log::info!("Skip dynamic XML file {}", path_display);
@@ -130,17 +130,10 @@ pub fn build_xml_file<P: AsRef<Path>>(path: &P, options: &ProjectBuildOptions) -
 
     xrf_output::info!(options.output, "Copy static XML translations {path_display}");
 
-    if options.language == TranslationLanguage::All {
-      for language in TranslationLanguage::get_all() {
-        copy(
-          &mut File::open(path)?,
-          &mut prepare_target_file(path, &options.output_dir, &language, options)?,
-        )?;
-      }
-    } else {
+    for language in options.languages.resolve(options.language.as_ref()) {
       copy(
         &mut File::open(path)?,
-        &mut prepare_target_file(path, &options.output_dir, &options.language, options)?,
+        &mut prepare_target_file(path, &options.output_dir, &language, options)?,
       )?;
     }
   }
@@ -163,12 +156,8 @@ pub fn build_json_file<P: AsRef<Path>>(path: &P, options: &ProjectBuildOptions)
 
   let parsed: TranslationJson = read_json(path.as_ref())?;
 
-  if options.language == TranslationLanguage::All {
-    for language in TranslationLanguage::get_all() {
-      build_json_by_language(path.as_ref(), &parsed, &language, options)?;
-    }
-  } else {
-    build_json_by_language(path.as_ref(), &parsed, &options.language, options)?;
+  for language in options.languages.resolve(options.language.as_ref()) {
+    build_json_by_language(path.as_ref(), &parsed, &language, options)?;
   }
 
   Ok(())
@@ -182,7 +171,7 @@ fn build_json_by_language(
 ) -> XrfResult {
   let data: Vec<u8> = encode_string_to_bytes(
     &compile_by_language(path, source, language, options)?,
-    language.new_language_encoder(),
+    language.new_language_encoder()?,
   )?;
 
   prepare_target_file(&path, &options.output_dir, language, options)?.write_all(&data)?;
@@ -35,7 +35,7 @@ pub(crate) fn prepare_target_file<P1: AsRef<Path>, P2: AsRef<Path>>(
   Ok(File::options().write(true).create(true).truncate(true).open(target)?)
 }
 
-/// Where one source lands, which is `<output>/<language>/<path relative to the root>.xml`.
+/// Where one source lands, which is `<output>/<language folder>/<path relative to the root>.xml`.
 ///
 /// # Errors
 ///
@@ -62,7 +62,7 @@ pub(crate) fn target_path(
 
   Ok(
     destination
-      .join(language.to_string())
+      .join(&language.folder)
       .join(relative_source)
       .with_extension(xml::FILE_EXTENSION),
   )
@@ -99,18 +99,11 @@ pub(crate) fn validate_targets(source_files: &[PathBuf], options: &ProjectBuildO
 
 pub(crate) fn target_languages_for_source(path: &Path, options: &ProjectBuildOptions) -> Vec<TranslationLanguage> {
   match path.extension().and_then(OsStr::to_str) {
-    Some(json::FILE_EXTENSION) => {
-      if options.language == TranslationLanguage::All {
-        TranslationLanguage::get_all()
-      } else {
-        vec![options.language]
-      }
-    }
-    Some(xml::FILE_EXTENSION) => match TranslationLanguage::from_file_name(path) {
-      Some(locale) if options.language == TranslationLanguage::All || options.language == locale => vec![locale],
+    Some(json::FILE_EXTENSION) => options.languages.resolve(options.language.as_ref()),
+    Some(xml::FILE_EXTENSION) => match options.languages.get_by_file_name(path) {
+      Some(locale) if options.language.as_ref().is_none_or(|language| language == locale) => vec![locale.clone()],
       Some(_) => Vec::new(),
-      None if options.language == TranslationLanguage::All => TranslationLanguage::get_all(),
-      None => vec![options.language],
+      None => options.languages.resolve(options.language.as_ref()),
     },
     _ => Vec::new(),
   }
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use indexmap::IndexMap;
 use xrf_utils::encode_string_to_bytes;
 
-use crate::language::TranslationLanguage;
+use crate::language::{TranslationLanguage, TranslationLanguages};
 use crate::project::build::compile::compile_by_language;
 use crate::project::build::options::ProjectBuildOptions;
 use crate::types::{TranslationEntry, TranslationJson, TranslationVariant};
@@ -14,10 +14,15 @@ fn options(language: TranslationLanguage) -> ProjectBuildOptions {
     path: PathBuf::from("translations"),
     output: xrf_output::OutputOptions::default(),
     output_dir: PathBuf::from("output"),
-    language,
+    language: Some(language),
+    languages: TranslationLanguages::default(),
   }
 }
 
+fn language(code: &str) -> TranslationLanguage {
+  TranslationLanguages::default().get_single(code).unwrap()
+}
+
 fn source(id: &str, language: &str, text: &str) -> TranslationJson {
   IndexMap::from([(
     String::from(id),
@@ -33,13 +38,13 @@ fn compiles_windows_1252_translations() {
   let compiled: String = compile_by_language(
     Path::new("translations/example.json"),
     &source("st_test", "fra", "À bientôt, José !"),
-    &TranslationLanguage::French,
-    &options(TranslationLanguage::French),
+    &language("fra"),
+    &options(language("fra")),
   )
   .unwrap();
 
   assert!(compiled.contains("encoding=\"windows-1252\""));
-  assert!(encode_string_to_bytes(&compiled, TranslationLanguage::French.new_language_encoder()).is_ok());
+  assert!(encode_string_to_bytes(&compiled, language("fra").new_language_encoder().unwrap()).is_ok());
 }
 
 #[test]
@@ -47,8 +52,8 @@ fn reports_unencodable_translation_entries_with_context() {
   let error = compile_by_language(
     Path::new("translations/example.json"),
     &source("st_test", "pol", "Й"),
-    &TranslationLanguage::Polish,
-    &options(TranslationLanguage::Polish),
+    &language("pol"),
+    &options(language("pol")),
   )
   .unwrap_err();
 
@@ -76,12 +81,12 @@ fn preserves_source_order_unless_sorting_is_requested() {
       )]),
     ),
   ]);
-  let mut build_options = options(TranslationLanguage::English);
+  let mut build_options = options(language("eng"));
 
   let source_order = compile_by_language(
     Path::new("translations/example.json"),
     &translations,
-    &TranslationLanguage::English,
+    &language("eng"),
     &build_options,
   )
   .unwrap();
@@ -91,7 +96,7 @@ fn preserves_source_order_unless_sorting_is_requested() {
   let sorted = compile_by_language(
     Path::new("translations/example.json"),
     &translations,
-    &TranslationLanguage::English,
+    &language("eng"),
     &build_options,
   )
   .unwrap();
@@ -105,8 +110,8 @@ fn a_missing_translation_compiles_to_its_own_id() {
   let compiled: String = compile_by_language(
     Path::new("translations/example.json"),
     &source("st_untranslated", "eng", "English only"),
-    &TranslationLanguage::Ukrainian,
-    &options(TranslationLanguage::Ukrainian),
+    &language("ukr"),
+    &options(language("ukr")),
   )
   .unwrap();
 
@@ -131,8 +136,8 @@ fn a_multi_line_entry_joins_on_the_engine_line_break() {
   let compiled: String = compile_by_language(
     Path::new("translations/example.json"),
     &translations,
-    &TranslationLanguage::English,
-    &options(TranslationLanguage::English),
+    &language("eng"),
+    &options(language("eng")),
   )
   .unwrap();
 
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use xrf_error::XrfResult;
 use xrf_test_utils::utils::{build_absolute_generated_test_resource_path, write_generated_test_resource};
 
-use crate::language::TranslationLanguage;
+use crate::language::{TranslationLanguage, TranslationLanguages};
 use crate::project::build::options::ProjectBuildOptions;
 use crate::project::build::run::build_dir;
 
@@ -15,7 +15,8 @@ fn options(path: PathBuf, output_dir: PathBuf) -> ProjectBuildOptions {
   ProjectBuildOptions {
     is_sorted: false,
     output: xrf_output::OutputOptions::default(),
-    language: TranslationLanguage::English,
+    language: Some(TranslationLanguage::english()),
+    languages: TranslationLanguages::default(),
     path,
     output_dir,
   }
@@ -77,3 +78,38 @@ fn directory_builds_reject_output_inside_the_source_tree() -> XrfResult {
 
   Ok(())
 }
+
+#[test]
+fn configured_languages_build_into_their_folder_and_code_page() -> XrfResult {
+  let test_root: &str = "build_targets/configured_language";
+  let source_root = build_absolute_generated_test_resource_path(&format!("{test_root}/source"));
+  let output_root = build_absolute_generated_test_resource_path(&format!("{test_root}/output"));
+  let mut languages: TranslationLanguages = TranslationLanguages::default();
+
+  languages.insert(TranslationLanguage {
+    folder: String::from("turkish"),
+    ..TranslationLanguage::new("tur", "windows-1254", Some("tr"))
+  })?;
+
+  write_generated_test_resource(
+    &format!("{test_root}/source/common.json"),
+    r#"{"st_test":{"eng":"text","tur":"Görüşürüz"}}"#,
+  )?;
+
+  build_dir(
+    &source_root,
+    &ProjectBuildOptions {
+      language: languages.select("tur")?,
+      languages,
+      ..options(source_root.clone(), output_root.clone())
+    },
+  )?;
+
+  let built: Vec<u8> = fs::read(output_root.join("turkish/common.xml"))?;
+
+  assert!(built.starts_with(b"<?xml version=\"1.0\" encoding=\"windows-1254\""));
+  // `ş` is a single byte in 1254 and absent from 1252, so finding it proves the code page was used.
+  assert!(built.contains(&0xFE));
+
+  Ok(())
+}
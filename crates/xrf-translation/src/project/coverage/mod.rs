@@ -0,0 +1,8 @@
+//! Reporting how far each language has come, and which translations an English rewrite left behind.
+
+pub(crate) mod options;
+pub(crate) mod result;
+pub(crate) mod run;
+
+#[cfg(test)]
+mod tests;
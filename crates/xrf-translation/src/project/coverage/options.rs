@@ -0,0 +1,10 @@
+use crate::language::{TranslationLanguage, TranslationLanguages};
+
+pub struct ProjectCoverageOptions {
+  /// Record the current English text as reviewed for every translated entry, clearing stale marks.
+  pub is_accepting: bool,
+  pub output: xrf_output::OutputOptions,
+  /// Language to check, every configured one when `None`.
+  pub language: Option<TranslationLanguage>,
+  pub languages: TranslationLanguages,
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// How one language stands in one source, or across all of them.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationLanguageCoverage {
+  pub language: String,
+  pub translated_count: u32,
+  /// Ids with no text at all, a `null` placeholder included.
+  pub missing_count: u32,
+  /// Ids present with an empty text, which the game shows as a blank line rather than the id.
+  pub empty_count: u32,
+  /// Translated ids whose English text changed since the translation was last written.
+  pub stale_count: u32,
+  /// Stale ids in the order first reported, each listed once.
+  pub stale_ids: Vec<String>,
+}
+
+impl TranslationLanguageCoverage {
+  pub fn new(language: &str) -> Self {
+    Self {
+      language: language.to_owned(),
+      ..Default::default()
+    }
+  }
+
+  /// Share of ids holding a non-empty translation, from 0 to 100.
+  pub fn percent(&self) -> f64 {
+    let total: u32 = self.translated_count + self.missing_count + self.empty_count;
+
+    if total == 0 {
+      100.0
+    } else {
+      f64::from(self.translated_count) * 100.0 / f64::from(total)
+    }
+  }
+
+  pub(crate) fn merge(&mut self, other: &Self) {
+    self.translated_count += other.translated_count;
+    self.missing_count += other.missing_count;
+    self.empty_count += other.empty_count;
+    self.stale_count += other.stale_count;
+
+    for id in &other.stale_ids {
+      if !self.stale_ids.contains(id) {
+        self.stale_ids.push(id.clone());
+      }
+    }
+  }
+}
+
+/// Coverage of every requested language in one JSON source.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationFileCoverage {
+  pub path: String,
+  pub languages: Vec<TranslationLanguageCoverage>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCoverageResult {
+  #[serde(with = "xrf_utils::duration_ms")]
+  pub duration: Duration,
+  pub files: Vec<TranslationFileCoverage>,
+  /// Ids recorded as reviewed against the current English text by an accepting run.
+  pub accepted_count: u32,
+}
+
+impl ProjectCoverageResult {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Totals per language across every file, in the order languages were first seen.
+  pub fn languages(&self) -> Vec<TranslationLanguageCoverage> {
+    let mut totals: Vec<TranslationLanguageCoverage> = Vec::new();
+
+    for coverage in self.files.iter().flat_map(|file| &file.languages) {
+      match totals.iter_mut().find(|total| total.language == coverage.language) {
+        Some(total) => total.merge(coverage),
+        None => {
+          let mut total: TranslationLanguageCoverage = TranslationLanguageCoverage::new(&coverage.language);
+
+          total.merge(coverage);
+          totals.push(total);
+        }
+      }
+    }
+
+    totals
+  }
+
+  pub fn stale_count(&self) -> u32 {
+    self
+      .files
+      .iter()
+      .flat_map(|file| &file.languages)
+      .map(|coverage| coverage.stale_count)
+      .sum()
+  }
+
+  pub(crate) fn merge(&mut self, other: Self) {
+    self.files.extend(other.files);
+    self.accepted_count += other.accepted_count;
+  }
+}
@@ -0,0 +1,154 @@
+use std::path::Path;
+use std::time::Instant;
+
+use walkdir::{DirEntry, WalkDir};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::json;
+use crate::json::read::read_json;
+use crate::json::source_hashes::{
+  SOURCE_LANGUAGE, SourceHashes, hash_source_text, is_stale_translation, read_source_hashes, touch_source_hash,
+  write_source_hashes,
+};
+use crate::language::TranslationLanguage;
+use crate::project::coverage::options::ProjectCoverageOptions;
+use crate::project::coverage::result::{ProjectCoverageResult, TranslationFileCoverage, TranslationLanguageCoverage};
+use crate::types::{TranslationEntry, TranslationJson, TranslationVariant};
+
+/// Report coverage of every JSON source in a directory.
+///
+/// # Errors
+///
+/// Returns a read error when the tree cannot be walked, and whatever reporting one file returns.
+pub fn coverage_dir(dir: &Path, options: &ProjectCoverageOptions) -> XrfResult<ProjectCoverageResult> {
+  log::info!("Reporting coverage of dir {}", dir.display());
+
+  let started_at: Instant = Instant::now();
+  let mut result: ProjectCoverageResult = ProjectCoverageResult::new();
+
+  for entry in WalkDir::new(dir).sort_by_file_name() {
+    let entry: DirEntry = entry.map_err(|error| {
+      XrfError::new_read_error(format!(
+        "Failed to walk translation directory '{}': {error}",
+        dir.display()
+      ))
+    })?;
+
+    if entry.path().is_file() {
+      result.merge(coverage_file(&entry.path(), options)?);
+    }
+  }
+
+  result.duration = started_at.elapsed();
+
+  Ok(result)
+}
+
+/// Report coverage of one source, skipping anything that is not a multi-language JSON.
+///
+/// Only JSON sources have coverage: an XML source holds one language, so there is nothing to compare it with.
+///
+/// # Errors
+///
+/// Returns a parsing error for an unreadable source or hashes file, and an IO error when accepted
+/// hashes cannot be written.
+pub fn coverage_file<P: AsRef<Path>>(path: &P, options: &ProjectCoverageOptions) -> XrfResult<ProjectCoverageResult> {
+  let path: &Path = path.as_ref();
+  let started_at: Instant = Instant::now();
+  let mut result: ProjectCoverageResult = ProjectCoverageResult::new();
+
+  if path
+    .extension()
+    .is_none_or(|extension| extension != json::FILE_EXTENSION)
+  {
+    log::info!("Skip file {}", path.display());
+
+    return Ok(result);
+  }
+
+  xrf_output::verbose!(options.output, "Reporting coverage of {}", path.display());
+
+  let parsed: TranslationJson = read_json(path)?;
+  let mut hashes: SourceHashes = read_source_hashes(path)?;
+
+  let languages: Vec<String> = options
+    .languages
+    .resolve(options.language.as_ref())
+    .iter()
+    .map(TranslationLanguage::to_string)
+    .collect();
+
+  if options.is_accepting {
+    result.accepted_count = accept_translations(&parsed, &mut hashes, &languages);
+
+    if result.accepted_count > 0 {
+      write_source_hashes(path, &hashes)?;
+    }
+  }
+
+  result.files.push(TranslationFileCoverage {
+    path: path.to_string_lossy().replace('\\', "/"),
+    languages: languages
+      .iter()
+      .map(|language| language_coverage(&parsed, &hashes, language))
+      .collect(),
+  });
+  result.duration = started_at.elapsed();
+
+  Ok(result)
+}
+
+fn language_coverage(parsed: &TranslationJson, hashes: &SourceHashes, language: &str) -> TranslationLanguageCoverage {
+  let mut coverage: TranslationLanguageCoverage = TranslationLanguageCoverage::new(language);
+
+  for (id, entry) in parsed {
+    match translation(entry, language) {
+      None => coverage.missing_count += 1,
+      Some(text) if is_empty(text) => coverage.empty_count += 1,
+      Some(_) => {
+        coverage.translated_count += 1;
+
+        if is_stale_translation(hashes, id, entry, language) {
+          coverage.stale_count += 1;
+          coverage.stale_ids.push(id.clone());
+        }
+      }
+    }
+  }
+
+  coverage
+}
+
+/// Record every translated id as written against the current English text, returning how many changed.
+fn accept_translations(parsed: &TranslationJson, hashes: &mut SourceHashes, languages: &[String]) -> u32 {
+  let mut accepted_count: u32 = 0;
+
+  for (id, entry) in parsed {
+    let Some(source) = translation(entry, SOURCE_LANGUAGE) else {
+      continue;
+    };
+
+    for language in languages.iter().filter(|language| *language != SOURCE_LANGUAGE) {
+      let is_translated: bool = translation(entry, language).is_some_and(|text| !is_empty(text));
+      let is_current: bool = hashes
+        .get(id)
+        .and_then(|languages| languages.get(language))
+        .is_some_and(|hash| *hash == hash_source_text(source));
+
+      if is_translated && !is_current {
+        touch_source_hash(hashes, id, language, Some(source));
+        accepted_count += 1;
+      }
+    }
+  }
+
+  accepted_count
+}
+
+fn translation<'a>(entry: &'a TranslationEntry, language: &str) -> Option<&'a TranslationVariant> {
+  entry.get(language).and_then(Option::as_ref)
+}
+
+fn is_empty(text: &TranslationVariant) -> bool {
+  text.to_single_line().trim().is_empty()
+}
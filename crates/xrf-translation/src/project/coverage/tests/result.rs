@@ -0,0 +1,32 @@
+use crate::project::coverage::result::{ProjectCoverageResult, TranslationFileCoverage, TranslationLanguageCoverage};
+
+fn coverage(language: &str, stale_ids: &[&str]) -> TranslationLanguageCoverage {
+  TranslationLanguageCoverage {
+    stale_count: stale_ids.len() as u32,
+    stale_ids: stale_ids.iter().map(|id| String::from(*id)).collect(),
+    ..TranslationLanguageCoverage::new(language)
+  }
+}
+
+#[test]
+fn merges_stale_ids_of_every_file_once() {
+  let result: ProjectCoverageResult = ProjectCoverageResult {
+    files: vec![
+      TranslationFileCoverage {
+        path: String::from("st_a.json"),
+        languages: vec![coverage("ukr", &["st_a", "st_b"]), coverage("rus", &["st_a"])],
+      },
+      TranslationFileCoverage {
+        path: String::from("st_b.json"),
+        languages: vec![coverage("ukr", &["st_c", "st_a"])],
+      },
+    ],
+    ..ProjectCoverageResult::new()
+  };
+  let languages: Vec<TranslationLanguageCoverage> = result.languages();
+
+  assert_eq!(languages[0].language, "ukr");
+  assert_eq!(languages[0].stale_count, 4);
+  assert_eq!(languages[0].stale_ids, ["st_a", "st_b", "st_c"]);
+  assert_eq!(languages[1].stale_ids, ["st_a"]);
+}
@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::PathBuf;
+
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::write_generated_test_resource;
+
+use crate::edit::TranslationEdit;
+use crate::json::source_hashes::source_hashes_path;
+use crate::json::write::apply_edits;
+use crate::language::TranslationLanguages;
+use crate::project::coverage::options::ProjectCoverageOptions;
+use crate::project::coverage::result::{ProjectCoverageResult, TranslationLanguageCoverage};
+use crate::project::coverage::run::coverage_file;
+use crate::types::TranslationVariant;
+
+fn options(language: &str, is_accepting: bool) -> ProjectCoverageOptions {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
+  ProjectCoverageOptions {
+    is_accepting,
+    output: xrf_output::OutputOptions::default(),
+    language: languages.select(language).unwrap(),
+    languages,
+  }
+}
+
+fn set(id: &str, text: &str) -> TranslationEdit {
+  TranslationEdit::Set {
+    id: String::from(id),
+    value: TranslationVariant::String(String::from(text)),
+  }
+}
+
+#[test]
+fn counts_translated_missing_and_empty_entries_per_language() -> XrfResult {
+  let path: PathBuf = write_generated_test_resource(
+    "project_coverage/counts.json",
+    r#"{"st_a":{"eng":"A","ukr":"A ukr"},"st_b":{"eng":"B","ukr":""},"st_c":{"eng":"C","ukr":null},"st_d":{"eng":"D"}}"#,
+  )?;
+
+  let _ = fs::remove_file(source_hashes_path(&path));
+
+  let result: ProjectCoverageResult = coverage_file(&path, &options("all", false))?;
+  let languages: Vec<TranslationLanguageCoverage> = result.languages();
+  let english: &TranslationLanguageCoverage = &languages[0];
+  let ukrainian: &TranslationLanguageCoverage = &languages[7];
+
+  assert_eq!(result.files.len(), 1);
+  assert_eq!((english.language.as_str(), english.translated_count), ("eng", 4));
+  assert_eq!(ukrainian.language, "ukr");
+  assert_eq!(
+    (
+      ukrainian.translated_count,
+      ukrainian.missing_count,
+      ukrainian.empty_count
+    ),
+    (1, 2, 1)
+  );
+  assert_eq!(ukrainian.percent(), 25.0);
+  assert_eq!(result.stale_count(), 0);
+
+  Ok(())
+}
+
+#[test]
+fn marks_translations_stale_after_an_english_rewrite() -> XrfResult {
+  let path: PathBuf = write_generated_test_resource(
+    "project_coverage/stale.json",
+    r#"{"st_a":{"eng":"Hello","ukr":"Pryvit"},"st_b":{"eng":"Bye","ukr":"Bувай"}}"#,
+  )?;
+
+  let _ = fs::remove_file(source_hashes_path(&path));
+
+  apply_edits(&path, "ukr", &[set("st_a", "Vitayu")])?;
+
+  let untouched: ProjectCoverageResult = coverage_file(&path, &options("ukr", false))?;
+
+  assert_eq!(untouched.stale_count(), 0);
+
+  apply_edits(&path, "eng", &[set("st_a", "Hello there"), set("st_b", "Goodbye")])?;
+
+  let rewritten: ProjectCoverageResult = coverage_file(&path, &options("ukr", false))?;
+
+  // Only the edited translation has a hash; the other was never recorded as written against anything.
+  assert_eq!(rewritten.files[0].languages[0].stale_ids, ["st_a"]);
+
+  let accepted: ProjectCoverageResult = coverage_file(&path, &options("ukr", true))?;
+
+  assert_eq!(accepted.accepted_count, 2);
+  assert_eq!(accepted.stale_count(), 0);
+
+  apply_edits(&path, "eng", &[set("st_b", "See you")])?;
+
+  let after: ProjectCoverageResult = coverage_file(&path, &options("ukr", false))?;
+
+  assert_eq!(after.files[0].languages[0].stale_ids, ["st_b"]);
+
+  Ok(())
+}
@@ -6,7 +6,7 @@ use xrf_xml::encoding_from_label;
 
 use crate::edit::TranslationEdit;
 use crate::json;
-use crate::language::{TranslationLanguage, find_unencodable_character};
+use crate::language::{TranslationLanguages, find_unencodable_character};
 use crate::project::constants::LANGUAGE_NEUTRAL;
 use crate::project::descriptor::TranslationProjectDescriptor;
 use crate::xml;
@@ -14,17 +14,23 @@ use crate::xml;
 /// Apply edits to whichever kind of translation file holds them.
 ///
 /// The language matters for JSON, where one file carries all of them, and is already decided by the
-/// path for XML, where the filename or the parent directory says which language it is.
+/// path for XML, where the filename or the parent directory says which language it is, looked up in
+/// `languages` for a file that does not declare its encoding.
 ///
 /// # Errors
 ///
 /// Returns a parsing error for an unreadable file, an encoding error for a value the target cannot
 /// represent, an IO error when the file cannot be replaced, and an invalid error for a file this does
 /// not know how to write.
-pub fn apply_edits(path: &Path, language: &str, edits: &[TranslationEdit]) -> XrfResult {
+pub fn apply_edits(
+  path: &Path,
+  language: &str,
+  edits: &[TranslationEdit],
+  languages: &TranslationLanguages,
+) -> XrfResult {
   match path.extension().and_then(|extension| extension.to_str()) {
     Some(json::FILE_EXTENSION) => json::write::apply_edits(path, language, edits),
-    Some(xml::FILE_EXTENSION) => xml::write::apply_edits(path, edits),
+    Some(xml::FILE_EXTENSION) => xml::write::apply_edits(path, edits, languages),
     _ => Err(XrfError::new_invalid_error(format!(
       "Translation '{}' is not a file this can write",
       path.display()
@@ -34,8 +40,8 @@ pub fn apply_edits(path: &Path, language: &str, edits: &[TranslationEdit]) -> Xr
 
 /// Report the first character a language cannot hold, or nothing when the value is writable.
 ///
-/// Takes the descriptor because the answer depends on what each language's own files declared when
-/// the project was read, which is the only statement that exists for a language XRF does not build.
+/// Takes the descriptor because the answer depends on the code page each language was configured
+/// with when the project was read, or what its own files declared for a language XRF does not build.
 ///
 /// # Errors
 ///
@@ -46,17 +52,13 @@ pub fn find_unwritable_character(
   text: &str,
 ) -> XrfResult<Option<String>> {
   // Neutral text is copied into every language, so it has to survive all of their code pages.
-  let candidates: Vec<(String, XRayEncoding)> = if language == LANGUAGE_NEUTRAL {
-    TranslationLanguage::get_all()
-      .into_iter()
-      .map(|known| (known.to_string(), known.new_language_encoder()))
-      .collect()
-  } else {
-    match descriptor.encodings.get(language) {
-      Some(label) => vec![(language.to_owned(), encoding_from_label(label)?)],
-      None => Vec::new(),
+  let mut candidates: Vec<(String, XRayEncoding)> = Vec::new();
+
+  for (name, label) in &descriptor.encodings {
+    if language == LANGUAGE_NEUTRAL || language == name {
+      candidates.push((name.clone(), encoding_from_label(label)?));
     }
-  };
+  }
 
   for (name, encoding) in candidates {
     if let Some(character) = find_unencodable_character(text, encoding) {
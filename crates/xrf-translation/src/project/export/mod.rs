@@ -0,0 +1,8 @@
+//! Writing a project's strings out for translator tools: gettext PO/POT or XLIFF.
+
+pub(crate) mod options;
+pub(crate) mod result;
+pub(crate) mod run;
+
+#[cfg(test)]
+mod tests;
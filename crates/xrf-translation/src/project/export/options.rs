@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use derive_more::Display;
+
+use crate::language::TranslationLanguage;
+
+/// File format translator tools exchange strings in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Display)]
+pub enum TranslationInterchangeFormat {
+  /// Gettext template: the English text alone, for a platform to start a language from.
+  #[display("pot")]
+  Pot,
+  #[display("po")]
+  Po,
+  #[display("xliff-1.2")]
+  Xliff12,
+  #[display("xliff-2.0")]
+  Xliff20,
+}
+
+impl FromStr for TranslationInterchangeFormat {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "pot" => Ok(Self::Pot),
+      "po" => Ok(Self::Po),
+      "xliff-1.2" | "xliff" => Ok(Self::Xliff12),
+      "xliff-2.0" => Ok(Self::Xliff20),
+      _ => Err(format!("Unknown translation interchange format: {s}")),
+    }
+  }
+}
+
+impl TranslationInterchangeFormat {
+  /// Format a file is written in by its extension, XLIFF defaulting to 1.2 as the most widely read.
+  pub fn from_path(path: &Path) -> Option<Self> {
+    match path.extension()?.to_str()? {
+      "pot" => Some(Self::Pot),
+      "po" => Some(Self::Po),
+      "xlf" | "xliff" => Some(Self::Xliff12),
+      _ => None,
+    }
+  }
+}
+
+pub struct ProjectExportOptions {
+  pub format: TranslationInterchangeFormat,
+  /// Language whose text fills the targets. Ignored for a template, required otherwise.
+  pub language: Option<TranslationLanguage>,
+  pub output: xrf_output::OutputOptions,
+  pub output_file: PathBuf,
+}
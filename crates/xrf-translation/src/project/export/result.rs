@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectExportResult {
+  #[serde(with = "xrf_utils::duration_ms")]
+  pub duration: Duration,
+  pub exported_count: u32,
+  pub translated_count: u32,
+  /// Translations exported for review because their English text changed since.
+  pub stale_count: u32,
+}
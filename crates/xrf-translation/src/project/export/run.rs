@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use walkdir::{DirEntry, WalkDir};
+use xrf_error::{XrfError, XrfResult};
+
+use crate::json;
+use crate::json::read::read_json;
+use crate::json::source_hashes::{SOURCE_LANGUAGE, SourceHashes, is_stale_translation, read_source_hashes};
+use crate::language::TranslationLanguage;
+use crate::po::write::write_po;
+use crate::project::export::options::{ProjectExportOptions, TranslationInterchangeFormat};
+use crate::project::export::result::ProjectExportResult;
+use crate::project::layout::relative;
+use crate::types::TranslationJson;
+use crate::unit::TranslationUnit;
+use crate::xliff::write::{XliffVersion, write_xliff};
+
+/// Export every JSON source in a directory for translator tools.
+///
+/// Ids without English text are left out, as there is nothing to translate from. A translation whose
+/// English text changed since it was written is exported marked for review, so a translator sees
+/// what to redo and the import leaves it stale until they confirm it.
+///
+/// # Errors
+///
+/// Returns an invalid error when a language file is asked for without a single language, a read
+/// error when the tree cannot be walked, a parsing error for an unreadable source, and an IO error
+/// when the output cannot be written.
+pub fn export_dir(dir: &Path, options: &ProjectExportOptions) -> XrfResult<ProjectExportResult> {
+  log::info!("Exporting dir {}", dir.display());
+  xrf_output::info!(options.output, "Exporting dir {}", dir.display());
+
+  let started_at: Instant = Instant::now();
+  let mut result: ProjectExportResult = ProjectExportResult::default();
+  let is_template: bool = options.format == TranslationInterchangeFormat::Pot;
+  let language: Option<&TranslationLanguage> = options.language.as_ref().filter(|_| !is_template);
+
+  if !is_template && language.is_none() {
+    return Err(XrfError::new_invalid_error(format!(
+      "Exporting {} needs a single language",
+      options.format
+    )));
+  }
+
+  let mut units: Vec<TranslationUnit> = Vec::new();
+
+  for entry in WalkDir::new(dir).sort_by_file_name() {
+    let entry: DirEntry = entry.map_err(|error| {
+      XrfError::new_read_error(format!(
+        "Failed to walk translation directory '{}': {error}",
+        dir.display()
+      ))
+    })?;
+    let path: &Path = entry.path();
+
+    if !path.is_file()
+      || path
+        .extension()
+        .is_none_or(|extension| extension != json::FILE_EXTENSION)
+    {
+      continue;
+    }
+
+    xrf_output::verbose!(options.output, "Exporting {}", path.display());
+
+    let parsed: TranslationJson = read_json(path)?;
+    let hashes: SourceHashes = read_source_hashes(path)?;
+    let file: String = relative(dir, path);
+    let language: String = language.map(TranslationLanguage::to_string).unwrap_or_default();
+
+    for (id, entry) in &parsed {
+      let Some(source) = entry.get(SOURCE_LANGUAGE).and_then(Option::as_ref) else {
+        continue;
+      };
+
+      let source: String = TranslationUnit::to_text(source);
+
+      if source.is_empty() {
+        continue;
+      }
+
+      let target: String = if is_template {
+        String::new()
+      } else {
+        entry
+          .get(&language)
+          .and_then(Option::as_ref)
+          .map(TranslationUnit::to_text)
+          .unwrap_or_default()
+      };
+      let is_fuzzy: bool = !target.is_empty() && is_stale_translation(&hashes, id, entry, &language);
+
+      result.exported_count += 1;
+      result.translated_count += u32::from(!target.is_empty());
+      result.stale_count += u32::from(is_fuzzy);
+
+      units.push(TranslationUnit {
+        file: file.clone(),
+        id: id.clone(),
+        source,
+        target,
+        is_fuzzy,
+      });
+    }
+  }
+
+  let document: String = match (options.format, language) {
+    (TranslationInterchangeFormat::Pot, _) | (_, None) => write_po(&units, None),
+    (TranslationInterchangeFormat::Po, Some(language)) => write_po(&units, Some(language)),
+    (TranslationInterchangeFormat::Xliff12, Some(language)) => write_xliff(&units, language, XliffVersion::V1_2),
+    (TranslationInterchangeFormat::Xliff20, Some(language)) => write_xliff(&units, language, XliffVersion::V2_0),
+  };
+
+  if let Some(parent) = options.output_file.parent() {
+    fs::create_dir_all(parent)?;
+  }
+
+  fs::write(&options.output_file, document)?;
+
+  result.duration = started_at.elapsed();
+
+  log::info!(
+    "Exported dir {} in {}",
+    dir.display(),
+    xrf_utils::format_duration(result.duration)
+  );
+
+  Ok(result)
+}
@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::PathBuf;
+
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::{build_absolute_generated_test_resource_path, write_generated_test_resource};
+
+use crate::edit::TranslationEdit;
+use crate::json::source_hashes::source_hashes_path;
+use crate::json::write::apply_edits;
+use crate::language::TranslationLanguages;
+use crate::project::export::options::{ProjectExportOptions, TranslationInterchangeFormat};
+use crate::project::export::result::ProjectExportResult;
+use crate::project::export::run::export_dir;
+use crate::types::TranslationVariant;
+
+fn options(format: TranslationInterchangeFormat, language: Option<&str>, name: &str) -> ProjectExportOptions {
+  ProjectExportOptions {
+    format,
+    language: language.map(|code| TranslationLanguages::default().get_single(code).unwrap()),
+    output: xrf_output::OutputOptions::default(),
+    output_file: build_absolute_generated_test_resource_path(&format!("project_export/output/{name}")),
+  }
+}
+
+#[test]
+fn exports_a_language_marking_stale_translations_for_review() -> XrfResult {
+  let path: PathBuf = write_generated_test_resource(
+    "project_export/source/dialogs.json",
+    r#"{"st_hello":{"eng":"Hello","ukr":"Pryvit"},"st_bye":{"eng":"Bye"},"st_ukr_only":{"ukr":"Lyshe"}}"#,
+  )?;
+
+  let _ = fs::remove_file(source_hashes_path(&path));
+
+  apply_edits(
+    &path,
+    "ukr",
+    &[TranslationEdit::Set {
+      id: String::from("st_hello"),
+      value: TranslationVariant::String(String::from("Pryvit")),
+    }],
+  )?;
+  apply_edits(
+    &path,
+    "eng",
+    &[TranslationEdit::Set {
+      id: String::from("st_hello"),
+      value: TranslationVariant::String(String::from("Hello there")),
+    }],
+  )?;
+
+  let options: ProjectExportOptions = options(TranslationInterchangeFormat::Po, Some("ukr"), "uk.po");
+  let result: ProjectExportResult = export_dir(path.parent().expect("source directory"), &options)?;
+  let written: String = fs::read_to_string(&options.output_file)?;
+
+  assert_eq!(
+    (result.exported_count, result.translated_count, result.stale_count),
+    (2, 1, 1)
+  );
+  assert!(
+    written.contains("#: dialogs.json\n#, fuzzy\nmsgctxt \"st_hello\"\nmsgid \"Hello there\"\nmsgstr \"Pryvit\"\n")
+  );
+  assert!(written.contains("msgctxt \"st_bye\"\nmsgid \"Bye\"\nmsgstr \"\"\n"));
+  assert!(!written.contains("st_ukr_only"));
+
+  Ok(())
+}
+
+#[test]
+fn a_template_needs_no_language_and_a_language_file_does() -> XrfResult {
+  let path: PathBuf = write_generated_test_resource(
+    "project_export/template/items.json",
+    r#"{"st_item":{"eng":"Item","ukr":"Rich"}}"#,
+  )?;
+  let dir: PathBuf = path.parent().expect("source directory").to_path_buf();
+  let template: ProjectExportOptions = options(TranslationInterchangeFormat::Pot, None, "all.pot");
+
+  export_dir(&dir, &template)?;
+
+  assert!(fs::read_to_string(&template.output_file)?.contains("msgid \"Item\"\nmsgstr \"\"\n"));
+  assert!(export_dir(&dir, &options(TranslationInterchangeFormat::Xliff12, None, "all.xlf")).is_err());
+
+  Ok(())
+}
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 
 use xrf_error::XrfResult;
 
-use crate::language::TranslationLanguage;
+use crate::language::TranslationLanguages;
 use crate::project::constants::{MAP_DESC_DIRECTORY, OPENXRAY_XML};
 use crate::project::descriptor::{
   TranslationFile, TranslationFinding, TranslationProjectDescriptor, TranslationProjectMode,
@@ -24,7 +24,10 @@ use crate::xml::read::read_string_table;
 ///
 /// Returns an IO error when the root itself cannot be listed. Individual files never fail the read;
 /// they are reported as findings instead.
-pub fn read_gamedata<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDescriptor> {
+pub fn read_gamedata<P: AsRef<Path>>(
+  root: P,
+  languages: &TranslationLanguages,
+) -> XrfResult<TranslationProjectDescriptor> {
   let root: &Path = root.as_ref();
   let mut descriptor: TranslationProjectDescriptor = TranslationProjectDescriptor {
     mode: TranslationProjectMode::Gamedata,
@@ -32,7 +35,7 @@ pub fn read_gamedata<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDes
     ..Default::default()
   };
 
-  for language in discover_languages(root, &mut descriptor.findings)? {
+  for language in discover_languages(root, languages, &mut descriptor.findings)? {
     let directory: PathBuf = root.join(&language);
 
     // Non-recursive, matching the engine's own `text\<language>\*.xml` mask.
@@ -50,14 +53,14 @@ pub fn read_gamedata<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDes
     names.sort();
 
     for name in names.iter() {
-      merge_file(&directory.join(name), name, &language, &mut descriptor);
+      merge_file(&directory.join(name), name, &language, languages, &mut descriptor);
     }
 
     // Read off the first file rather than assumed from the code: these directories carry languages
-    // the enum has no mapping for, and their own declaration is the only statement that exists.
+    // no configuration maps, and their own declaration is the only statement that exists.
     if let Some(encoding) = names
       .first()
-      .and_then(|name| read_decoded(&directory.join(name)).ok())
+      .and_then(|name| read_decoded(&directory.join(name), languages).ok())
       .map(|(_, encoding, _)| encoding.name().to_lowercase())
     {
       descriptor.encodings.insert(language.clone(), encoding);
@@ -71,10 +74,16 @@ pub fn read_gamedata<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDes
   Ok(descriptor)
 }
 
-fn merge_file(path: &Path, name: &str, language: &str, descriptor: &mut TranslationProjectDescriptor) {
+fn merge_file(
+  path: &Path,
+  name: &str,
+  language: &str,
+  languages: &TranslationLanguages,
+  descriptor: &mut TranslationProjectDescriptor,
+) {
   let subject: String = normalize(path);
 
-  let entries: Vec<(String, String)> = match read_string_table(path) {
+  let entries: Vec<(String, String)> = match read_string_table(path, languages) {
     Ok(entries) => entries,
     Err(error) => {
       descriptor.findings.push(TranslationFinding::new(
@@ -108,8 +117,12 @@ fn merge_file(path: &Path, name: &str, language: &str, descriptor: &mut Translat
   }
 }
 
-fn discover_languages(root: &Path, findings: &mut Vec<TranslationFinding>) -> XrfResult<Vec<String>> {
-  let mut languages: Vec<String> = Vec::new();
+fn discover_languages(
+  root: &Path,
+  languages: &TranslationLanguages,
+  findings: &mut Vec<TranslationFinding>,
+) -> XrfResult<Vec<String>> {
+  let mut discovered: Vec<String> = Vec::new();
 
   for entry in fs::read_dir(root)? {
     let entry: DirEntry = entry?;
@@ -142,7 +155,7 @@ fn discover_languages(root: &Path, findings: &mut Vec<TranslationFinding>) -> Xr
       continue;
     }
 
-    if TranslationLanguage::from_str_single(&name).is_err() {
+    if languages.get_by_folder(&name).is_none() {
       findings.push(TranslationFinding::new(
         "translations.unknown-language",
         Some(normalize(&entry.path())),
@@ -150,10 +163,10 @@ fn discover_languages(root: &Path, findings: &mut Vec<TranslationFinding>) -> Xr
       ));
     }
 
-    languages.push(name);
+    discovered.push(name);
   }
 
-  languages.sort();
+  discovered.sort();
 
-  Ok(languages)
+  Ok(discovered)
 }
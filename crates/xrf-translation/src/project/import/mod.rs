@@ -0,0 +1,8 @@
+//! Reading a translator's PO or XLIFF file back into the JSON sources it was exported from.
+
+pub(crate) mod options;
+pub(crate) mod result;
+pub(crate) mod run;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,9 @@
+use crate::language::{TranslationLanguage, TranslationLanguages};
+
+pub struct ProjectImportOptions {
+  pub output: xrf_output::OutputOptions,
+  /// Language the file translates into.
+  pub language: TranslationLanguage,
+  /// Configured languages, resolving the encoding of XML files that do not declare one.
+  pub languages: TranslationLanguages,
+}
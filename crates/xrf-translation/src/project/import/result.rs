@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::project::descriptor::TranslationFinding;
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectImportResult {
+  #[serde(with = "xrf_utils::duration_ms")]
+  pub duration: Duration,
+  /// Translations written into the sources, unchanged text included, as importing it confirms it.
+  pub imported_count: u32,
+  /// Units left alone because they have no translation yet or are still marked for review.
+  pub skipped_count: u32,
+  /// Units refused, each with the finding saying why.
+  pub rejected_count: u32,
+  pub findings: Vec<TranslationFinding>,
+}
+
+impl ProjectImportResult {
+  pub(crate) fn reject(&mut self, rule: &str, subject: &str, message: String) {
+    self.rejected_count += 1;
+    self
+      .findings
+      .push(TranslationFinding::new(rule, Some(subject.to_owned()), message));
+  }
+}
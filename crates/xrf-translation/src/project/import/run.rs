@@ -0,0 +1,173 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::Instant;
+
+use indexmap::IndexMap;
+use xrf_error::{XrfError, XrfResult};
+
+use crate::edit::TranslationEdit;
+use crate::json;
+use crate::json::read::read_json;
+use crate::json::source_hashes::SOURCE_LANGUAGE;
+use crate::language::TranslationLanguage;
+use crate::po::read::read_po;
+use crate::project::descriptor::TranslationProjectDescriptor;
+use crate::project::edit::{apply_edits, find_unwritable_character};
+use crate::project::export::options::TranslationInterchangeFormat;
+use crate::project::import::options::ProjectImportOptions;
+use crate::project::import::result::ProjectImportResult;
+use crate::types::{TranslationJson, TranslationVariant};
+use crate::unit::{TranslationCatalog, TranslationUnit};
+use crate::xliff::read::read_xliff;
+
+/// Import a translated PO or XLIFF file into the JSON sources of a directory.
+///
+/// Each unit goes back to the source file named by its reference, through the same edits an editor
+/// makes, so ids keep their order, multi-line entries keep their array form and the edit records what
+/// English text the translation was written against. A unit is refused when its id is gone, its
+/// English text changed since the export, or the language's code page cannot hold it.
+///
+/// # Errors
+///
+/// Returns an invalid error for an unknown file kind or a language other than the one the file
+/// declares, a parsing error for a malformed file or source, and an IO error
+/// when a source cannot be written.
+pub fn import_file(dir: &Path, path: &Path, options: &ProjectImportOptions) -> XrfResult<ProjectImportResult> {
+  log::info!("Importing {} into {}", path.display(), dir.display());
+  xrf_output::info!(options.output, "Importing {} into {}", path.display(), dir.display());
+
+  let started_at: Instant = Instant::now();
+  let mut result: ProjectImportResult = ProjectImportResult::default();
+  let language: &TranslationLanguage = &options.language;
+
+  let catalog: TranslationCatalog = match TranslationInterchangeFormat::from_path(path) {
+    Some(TranslationInterchangeFormat::Po | TranslationInterchangeFormat::Pot) => {
+      let source: String = String::from_utf8(fs::read(path)?).map_err(|error| {
+        XrfError::new_parsing_error(format!("Translation '{}' is not UTF-8: {error}", path.display()))
+      })?;
+
+      read_po(&source)?
+    }
+    Some(TranslationInterchangeFormat::Xliff12 | TranslationInterchangeFormat::Xliff20) => {
+      read_xliff(&fs::read(path)?)?
+    }
+    None => {
+      return Err(XrfError::new_invalid_error(format!(
+        "Translation '{}' is not a PO or XLIFF file",
+        path.display()
+      )));
+    }
+  };
+
+  if let Some(declared) = &catalog.language
+    && language
+      .get_locale_code()
+      .is_none_or(|code| !declared.eq_ignore_ascii_case(code))
+  {
+    return Err(XrfError::new_invalid_error(format!(
+      "Translation '{}' is for language '{declared}', not '{language}'",
+      path.display()
+    )));
+  }
+
+  // Only this language's code page matters, and the configured one is what the build writes it in.
+  let descriptor: TranslationProjectDescriptor = TranslationProjectDescriptor {
+    encodings: IndexMap::from([(language.to_string(), language.get_language_encoding())]),
+    ..Default::default()
+  };
+  let mut files: IndexMap<String, Vec<TranslationUnit>> = IndexMap::new();
+
+  for unit in catalog.units {
+    files.entry(unit.file.clone()).or_default().push(unit);
+  }
+
+  for (file, units) in files {
+    let source_path: PathBuf = dir.join(&file);
+
+    // A reference is relative to the project and never leaves it, whatever the file claims.
+    if file.is_empty()
+      || Path::new(&file)
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+      || source_path
+        .extension()
+        .is_none_or(|extension| extension != json::FILE_EXTENSION)
+      || !source_path.is_file()
+    {
+      for unit in &units {
+        result.reject(
+          "translations.import-unknown-file",
+          &file,
+          format!("'{}' references no JSON source of the project", unit.id),
+        );
+      }
+
+      continue;
+    }
+
+    xrf_output::verbose!(options.output, "Importing into {}", source_path.display());
+
+    let parsed: TranslationJson = read_json(&source_path)?;
+    let mut edits: Vec<TranslationEdit> = Vec::new();
+
+    for unit in units {
+      if unit.target.is_empty() || unit.is_fuzzy {
+        result.skipped_count += 1;
+        continue;
+      }
+
+      let Some(source) = parsed
+        .get(&unit.id)
+        .and_then(|entry| entry.get(SOURCE_LANGUAGE))
+        .and_then(Option::as_ref)
+      else {
+        result.reject(
+          "translations.import-unknown-id",
+          &file,
+          format!("'{}' has no English text in the source any more", unit.id),
+        );
+        continue;
+      };
+
+      if TranslationUnit::to_text(source) != unit.source {
+        result.reject(
+          "translations.import-outdated-source",
+          &file,
+          format!(
+            "'{}' was translated from an English text that has changed since the export",
+            unit.id
+          ),
+        );
+        continue;
+      }
+
+      if let Some(problem) = find_unwritable_character(&descriptor, &language.to_string(), &unit.target)? {
+        result.reject(
+          "translations.import-unwritable",
+          &file,
+          format!("'{}': {problem}", unit.id),
+        );
+        continue;
+      }
+
+      let value: TranslationVariant = TranslationUnit::to_variant(&unit.target, source);
+
+      edits.push(TranslationEdit::Set { id: unit.id, value });
+    }
+
+    if !edits.is_empty() {
+      apply_edits(&source_path, &language.to_string(), &edits, &options.languages)?;
+      result.imported_count += edits.len() as u32;
+    }
+  }
+
+  result.duration = started_at.elapsed();
+
+  log::info!(
+    "Imported {} in {}",
+    path.display(),
+    xrf_utils::format_duration(result.duration)
+  );
+
+  Ok(result)
+}
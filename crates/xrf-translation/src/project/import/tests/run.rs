@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::{build_absolute_generated_test_resource_path, write_generated_test_resource};
+
+use crate::json::read::read_json;
+use crate::json::source_hashes::source_hashes_path;
+use crate::language::{TranslationLanguage, TranslationLanguages};
+use crate::project::export::options::{ProjectExportOptions, TranslationInterchangeFormat};
+use crate::project::export::run::export_dir;
+use crate::project::import::options::ProjectImportOptions;
+use crate::project::import::result::ProjectImportResult;
+use crate::project::import::run::import_file;
+use crate::types::{TranslationJson, TranslationVariant};
+
+const SOURCE: &str = r#"{
+  "st_hello": {"eng": "Hello"},
+  "st_lines": {"eng": ["First", "Second"], "ukr": ["Pershyi", "Druhyi"]},
+  "st_pending": {"eng": "Pending"},
+  "st_symbol": {"eng": "Euro"}
+}"#;
+
+fn write_project(name: &str) -> XrfResult<PathBuf> {
+  let path: PathBuf = write_generated_test_resource(&format!("project_import/{name}/source/dialogs.json"), SOURCE)?;
+
+  let _ = fs::remove_file(source_hashes_path(&path));
+
+  Ok(path)
+}
+
+fn import_options() -> ProjectImportOptions {
+  ProjectImportOptions {
+    output: xrf_output::OutputOptions::default(),
+    language: ukrainian(),
+    languages: TranslationLanguages::default(),
+  }
+}
+
+fn ukrainian() -> TranslationLanguage {
+  TranslationLanguages::default().get_single("ukr").unwrap()
+}
+
+fn export(dir: &Path, format: TranslationInterchangeFormat, file: &str) -> XrfResult<PathBuf> {
+  let output_file: PathBuf = build_absolute_generated_test_resource_path(&format!("project_import/{file}"));
+
+  export_dir(
+    dir,
+    &ProjectExportOptions {
+      format,
+      language: Some(ukrainian()),
+      output: xrf_output::OutputOptions::default(),
+      output_file: output_file.clone(),
+    },
+  )?;
+
+  Ok(output_file)
+}
+
+#[test]
+fn imports_a_translated_po_back_into_the_json_source() -> XrfResult {
+  let path: PathBuf = write_project("po")?;
+  let dir: &Path = path.parent().expect("source directory");
+  let exported: PathBuf = export(dir, TranslationInterchangeFormat::Po, "po/uk.po")?;
+  let translated: String = fs::read_to_string(&exported)?
+    .replace("msgid \"Hello\"\nmsgstr \"\"", "msgid \"Hello\"\nmsgstr \"Pryvit\"")
+    .replace("\"Druhyi\"", "\"Druhyi ryadok\"")
+    .replace("msgid \"Euro\"\nmsgstr \"\"", "msgid \"Euro\"\nmsgstr \"Євро Ω\"");
+
+  fs::write(&exported, translated)?;
+
+  let result: ProjectImportResult = import_file(dir, &exported, &import_options())?;
+  let parsed: TranslationJson = read_json(&path)?;
+
+  assert_eq!(
+    (result.imported_count, result.skipped_count, result.rejected_count),
+    (2, 1, 1)
+  );
+  assert_eq!(result.findings[0].rule, "translations.import-unwritable");
+  assert_eq!(
+    parsed["st_hello"]["ukr"],
+    Some(TranslationVariant::String(String::from("Pryvit")))
+  );
+  assert_eq!(
+    parsed["st_lines"]["ukr"],
+    Some(TranslationVariant::MultiString(vec![
+      String::from("Pershyi"),
+      String::from("Druhyi ryadok")
+    ]))
+  );
+  assert_eq!(
+    parsed.keys().collect::<Vec<_>>(),
+    ["st_hello", "st_lines", "st_pending", "st_symbol"]
+  );
+  assert!(parsed["st_symbol"].get("ukr").is_none());
+
+  Ok(())
+}
+
+#[test]
+fn imports_xliff_and_refuses_what_no_longer_matches_the_source() -> XrfResult {
+  let path: PathBuf = write_project("xliff")?;
+  let dir: &Path = path.parent().expect("source directory");
+  let exported: PathBuf = export(dir, TranslationInterchangeFormat::Xliff20, "xliff/uk.xlf")?;
+  let translated: String = fs::read_to_string(&exported)?
+    .replace(
+      "<source xml:space=\"preserve\">Hello</source>\n",
+      "<source xml:space=\"preserve\">Hello</source>\n        <target>Pryvit</target>\n",
+    )
+    .replace(
+      "<source xml:space=\"preserve\">Pending</source>\n",
+      "<source xml:space=\"preserve\">Pending</source>\n        <target>Ochikuye</target>\n",
+    );
+
+  fs::write(&exported, translated)?;
+  fs::write(&path, SOURCE.replace("\"Pending\"", "\"Pending review\""))?;
+
+  let result: ProjectImportResult = import_file(dir, &exported, &import_options())?;
+  let parsed: TranslationJson = read_json(&path)?;
+
+  assert_eq!(result.imported_count, 2);
+  assert_eq!(
+    result
+      .findings
+      .iter()
+      .map(|finding| finding.rule.as_str())
+      .collect::<Vec<_>>(),
+    ["translations.import-outdated-source"]
+  );
+  assert_eq!(
+    parsed["st_hello"]["ukr"],
+    Some(TranslationVariant::String(String::from("Pryvit")))
+  );
+  assert!(parsed["st_pending"].get("ukr").is_none());
+
+  Ok(())
+}
+
+#[test]
+fn refuses_a_file_for_another_language_or_outside_the_project() -> XrfResult {
+  let path: PathBuf = write_project("refused")?;
+  let dir: &Path = path.parent().expect("source directory");
+  let other: PathBuf = write_generated_test_resource(
+    "project_import/refused/pl.po",
+    "msgid \"\"\nmsgstr \"Language: pl\\n\"\n",
+  )?;
+  let escaping: PathBuf = write_generated_test_resource(
+    "project_import/refused/escaping.po",
+    "#: ../other.json\nmsgctxt \"st_a\"\nmsgid \"A\"\nmsgstr \"B\"\n",
+  )?;
+
+  assert!(import_file(dir, &other, &import_options()).is_err());
+  assert_eq!(
+    import_file(dir, &escaping, &import_options())?.findings[0].rule,
+    "translations.import-unknown-file"
+  );
+
+  Ok(())
+}
@@ -1,6 +1,11 @@
 use std::path::PathBuf;
 
+use crate::language::{TranslationLanguage, TranslationLanguages};
+
 pub struct ProjectInitializeOptions {
   pub output: xrf_output::OutputOptions,
   pub path: PathBuf,
+  /// Language to add placeholders for, every configured one when `None`.
+  pub language: Option<TranslationLanguage>,
+  pub languages: TranslationLanguages,
 }
@@ -97,10 +97,15 @@ pub fn initialize_json_file<P: AsRef<Path>>(
   let started_at: Instant = Instant::now();
   let mut parsed: TranslationJson = read_json(path.as_ref())?;
 
-  let all_languages: Vec<String> = TranslationLanguage::get_all_strings();
+  let languages: Vec<String> = options
+    .languages
+    .resolve(options.language.as_ref())
+    .iter()
+    .map(TranslationLanguage::to_string)
+    .collect();
 
   for (key, value) in &mut parsed {
-    for language in &all_languages {
+    for language in &languages {
       if !value.contains_key(language) {
         initialized_count += 1;
 
@@ -4,7 +4,7 @@ use xrf_error::XrfResult;
 use xrf_test_utils::utils::write_generated_test_resource;
 
 use crate::json::read::read_json;
-use crate::language::TranslationLanguage;
+use crate::language::TranslationLanguages;
 use crate::project::initialize::options::ProjectInitializeOptions;
 use crate::project::initialize::run::initialize_json_file;
 use crate::types::TranslationVariant;
@@ -13,6 +13,8 @@ fn options(path: std::path::PathBuf) -> ProjectInitializeOptions {
   ProjectInitializeOptions {
     output: xrf_output::OutputOptions::default(),
     path,
+    language: None,
+    languages: TranslationLanguages::default(),
   }
 }
 
@@ -29,7 +31,8 @@ fn initialization_replaces_json_only_after_writing_a_valid_document() -> XrfResu
     Some(TranslationVariant::String(String::from("original")))
   );
   assert!(
-    TranslationLanguage::get_all_strings()
+    TranslationLanguages::default()
+      .get_all_strings()
       .iter()
       .all(|language| initialized["st_test"].contains_key(language))
   );
@@ -39,7 +42,8 @@ fn initialization_replaces_json_only_after_writing_a_valid_document() -> XrfResu
 
 #[test]
 fn an_already_complete_file_is_left_untouched() -> XrfResult {
-  let languages: String = TranslationLanguage::get_all_strings()
+  let languages: String = TranslationLanguages::default()
+    .get_all_strings()
     .iter()
     .map(|language| format!("\"{language}\":null"))
     .collect::<Vec<_>>()
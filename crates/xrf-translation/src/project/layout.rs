@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::language::TranslationLanguage;
+use crate::language::TranslationLanguages;
 use crate::project::constants::MAP_DESC_DIRECTORY;
 use crate::project::descriptor::TranslationProjectMode;
 use crate::{json, xml};
@@ -10,7 +10,7 @@ use crate::{json, xml};
 ///
 /// Advisory only. The mode a project is opened with is whatever the caller passes, because the two
 /// layouts save to different files and a heuristic must not be what decides that.
-pub fn detect_mode(root: &Path) -> TranslationProjectMode {
+pub fn detect_mode(root: &Path, languages: &TranslationLanguages) -> TranslationProjectMode {
   let Ok(entries) = fs::read_dir(root) else {
     return TranslationProjectMode::Source;
   };
@@ -21,7 +21,7 @@ pub fn detect_mode(root: &Path) -> TranslationProjectMode {
     let path: PathBuf = entry.path();
     let name: String = entry.file_name().to_string_lossy().into_owned();
 
-    if path.is_dir() && name != MAP_DESC_DIRECTORY && TranslationLanguage::from_str_single(&name).is_ok() {
+    if path.is_dir() && name != MAP_DESC_DIRECTORY && languages.get_by_folder(&name).is_some() {
       has_language_directory = true;
     }
 
@@ -29,7 +29,7 @@ pub fn detect_mode(root: &Path) -> TranslationProjectMode {
     if path.is_file() {
       match path.extension().and_then(|extension| extension.to_str()) {
         Some(json::FILE_EXTENSION) => return TranslationProjectMode::Source,
-        Some(xml::FILE_EXTENSION) if TranslationLanguage::from_file_name(&path).is_some() => {
+        Some(xml::FILE_EXTENSION) if languages.get_by_file_name(&path).is_some() => {
           return TranslationProjectMode::Source;
         }
         _ => {}
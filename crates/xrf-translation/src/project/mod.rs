@@ -2,9 +2,12 @@
 
 pub(crate) mod build;
 pub(crate) mod constants;
+pub(crate) mod coverage;
 pub(crate) mod descriptor;
 pub(crate) mod edit;
+pub(crate) mod export;
 pub(crate) mod gamedata_read;
+pub(crate) mod import;
 pub(crate) mod initialize;
 pub(crate) mod layout;
 pub(crate) mod source_read;
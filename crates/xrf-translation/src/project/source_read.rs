@@ -5,7 +5,7 @@ use xrf_error::{XrfError, XrfResult};
 
 use crate::json;
 use crate::json::read::read_json;
-use crate::language::TranslationLanguage;
+use crate::language::TranslationLanguages;
 use crate::project::constants::{LANGUAGE_NEUTRAL, MULTILANGUAGE};
 use crate::project::descriptor::{
   TranslationFile, TranslationFinding, TranslationProjectDescriptor, TranslationProjectMode,
@@ -21,10 +21,16 @@ use crate::xml::read::read_string_table;
 /// several languages and several files can carry one between them. Unlike the build and the verifier,
 /// nothing in here refuses to open on content: problems come back as findings.
 ///
+/// Every configured language gets its code page recorded, present or not, because neutral text is
+/// built into all of them.
+///
 /// # Errors
 ///
 /// Returns an IO error when the tree cannot be walked. Individual files are reported, not fatal.
-pub fn read_source<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDescriptor> {
+pub fn read_source<P: AsRef<Path>>(
+  root: P,
+  languages: &TranslationLanguages,
+) -> XrfResult<TranslationProjectDescriptor> {
   let root: &Path = root.as_ref();
   let mut descriptor: TranslationProjectDescriptor = TranslationProjectDescriptor {
     mode: TranslationProjectMode::Source,
@@ -47,21 +53,19 @@ pub fn read_source<P: AsRef<Path>>(root: P) -> XrfResult<TranslationProjectDescr
 
     match path.extension().and_then(|extension| extension.to_str()) {
       Some(json::FILE_EXTENSION) => merge_json(root, path, &mut descriptor),
-      Some(xml::FILE_EXTENSION) => merge_xml(root, path, &mut descriptor),
+      Some(xml::FILE_EXTENSION) => merge_xml(root, path, languages, &mut descriptor),
       _ => {}
     }
   }
 
   record_cross_file_duplicates(&mut descriptor);
 
-  for language in &descriptor.languages {
-    // Source text is authored, not shipped, so the language decides the code page the build will have
-    // to write it in.
-    if let Ok(known) = TranslationLanguage::from_str_single(language) {
-      descriptor
-        .encodings
-        .insert(language.clone(), known.get_language_encoding());
-    }
+  // Source text is authored, not shipped, so the language decides the code page the build will have
+  // to write it in.
+  for known in languages.get_all() {
+    descriptor
+      .encodings
+      .insert(known.to_string(), known.get_language_encoding());
   }
 
   descriptor.languages.sort_by(|first, second| {
@@ -102,15 +106,21 @@ fn merge_json(root: &Path, path: &Path, descriptor: &mut TranslationProjectDescr
   }
 }
 
-fn merge_xml(root: &Path, path: &Path, descriptor: &mut TranslationProjectDescriptor) {
+fn merge_xml(
+  root: &Path,
+  path: &Path,
+  languages: &TranslationLanguages,
+  descriptor: &mut TranslationProjectDescriptor,
+) {
   let subject: String = normalize(path);
 
   // No language suffix means the build copies this file to every language, so presenting it as
   // English - which a filename fallback would do - would show a change to all of them as one.
-  let language: String =
-    TranslationLanguage::from_file_name(path).map_or_else(|| LANGUAGE_NEUTRAL.to_owned(), |locale| locale.to_string());
+  let language: String = languages
+    .get_by_file_name(path)
+    .map_or_else(|| LANGUAGE_NEUTRAL.to_owned(), |locale| locale.to_string());
 
-  let entries: Vec<(String, String)> = match read_string_table(path) {
+  let entries: Vec<(String, String)> = match read_string_table(path, languages) {
     Ok(entries) => entries,
     Err(error) => {
       descriptor.findings.push(TranslationFinding::new(
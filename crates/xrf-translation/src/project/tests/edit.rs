@@ -6,6 +6,7 @@ use xrf_test_utils::utils::write_generated_test_resource;
 
 use crate::edit::TranslationEdit;
 use crate::json::read::read_json;
+use crate::language::TranslationLanguages;
 use crate::project::constants::LANGUAGE_NEUTRAL;
 use crate::project::descriptor::TranslationProjectDescriptor;
 use crate::project::edit::{apply_edits, find_unwritable_character};
@@ -33,7 +34,7 @@ fn set(id: &str, text: &str) -> TranslationEdit {
 fn routes_a_json_source_to_the_json_writer() -> XrfResult {
   let path = write_generated_test_resource("project_edit/dispatch.json", r#"{"st_a":{"eng":"A"}}"#)?;
 
-  apply_edits(&path, "eng", &[set("st_a", "B")])?;
+  apply_edits(&path, "eng", &[set("st_a", "B")], &TranslationLanguages::default())?;
 
   assert_eq!(
     read_json(&path)?["st_a"]["eng"],
@@ -50,10 +51,10 @@ fn routes_an_xml_source_to_the_splice_writer() -> XrfResult {
     "<string_table><string id=\"st_a\"><text>A</text></string></string_table>",
   )?;
 
-  apply_edits(&path, "eng", &[set("st_a", "B")])?;
+  apply_edits(&path, "eng", &[set("st_a", "B")], &TranslationLanguages::default())?;
 
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![(String::from("st_a"), String::from("B"))]
   );
 
@@ -62,7 +63,13 @@ fn routes_an_xml_source_to_the_splice_writer() -> XrfResult {
 
 #[test]
 fn refuses_a_file_it_has_no_writer_for() {
-  let error = apply_edits(Path::new("translations/notes.txt"), "eng", &[]).unwrap_err();
+  let error = apply_edits(
+    Path::new("translations/notes.txt"),
+    "eng",
+    &[],
+    &TranslationLanguages::default(),
+  )
+  .unwrap_err();
 
   assert!(error.to_string().contains("not a file this can write"));
 }
@@ -89,7 +96,7 @@ fn names_the_character_a_target_cannot_hold() -> XrfResult {
 
 #[test]
 fn neutral_text_must_survive_every_language() -> XrfResult {
-  let project = descriptor(&[]);
+  let project = descriptor(&[("rus", "windows-1251"), ("fra", "windows-1252")]);
 
   // Copied into all of them by the build, so passing in one code page is not enough.
   assert!(find_unwritable_character(&project, LANGUAGE_NEUTRAL, "Привет")?.is_some());
@@ -98,6 +105,17 @@ fn neutral_text_must_survive_every_language() -> XrfResult {
   Ok(())
 }
 
+#[test]
+fn validates_against_a_configured_code_page() -> XrfResult {
+  let project = descriptor(&[("tur", "windows-1254"), ("chn", "utf-8")]);
+
+  assert_eq!(find_unwritable_character(&project, "tur", "Görüşürüz")?, None);
+  assert!(find_unwritable_character(&project, "tur", "Привет")?.is_some());
+  assert_eq!(find_unwritable_character(&project, "chn", "再见")?, None);
+
+  Ok(())
+}
+
 #[test]
 fn a_language_with_no_recorded_encoding_is_not_second_guessed() -> XrfResult {
   let project = descriptor(&[]);
@@ -1,6 +1,7 @@
 use xrf_test_utils::utils::{build_absolute_generated_test_resource_path, write_generated_test_resource};
 
 use super::table;
+use crate::language::TranslationLanguages;
 use crate::project::gamedata_read::read_gamedata;
 use crate::types::TranslationVariant;
 
@@ -13,7 +14,11 @@ fn pivots_language_directories_into_one_entry_per_id() {
   write_generated_test_resource(&format!("{root}/rus/st_test.xml"), table("st_hello", "Privet"))
     .expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
 
   assert_eq!(descriptor.languages, vec![String::from("eng"), String::from("rus")]);
 
@@ -43,7 +48,11 @@ fn skips_the_directories_the_engine_skips() {
   write_generated_test_resource(&format!("{root}/only_openxray/openxray.xml"), table("st_x", "x"))
     .expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
 
   assert_eq!(descriptor.languages, vec![String::from("eng")]);
 }
@@ -57,7 +66,11 @@ fn keeps_a_language_the_enum_does_not_know_and_says_so() {
   write_generated_test_resource(&format!("{root}/cze/st_test.xml"), table("st_hello", "Ahoj"))
     .expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
 
   assert!(descriptor.languages.contains(&String::from("cze")));
   assert!(
@@ -77,7 +90,11 @@ fn keeps_the_duplicate_the_game_uses_and_reports_the_rest() {
     "<string_table>\n\t<string id=\"st_dup\">\n\t\t<text>shadowed</text>\n\t</string>\n\t<string id=\"st_dup\">\n\t\t<text>winning</text>\n\t</string>\n</string_table>",
   ).expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
   let entry = descriptor
     .files
     .get("st_test.xml")
@@ -105,7 +122,11 @@ fn opens_a_project_whose_file_cannot_be_parsed_and_reports_it() {
   write_generated_test_resource(&format!("{root}/eng/st_bad.xml"), "<string_table><string id=")
     .expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
 
   assert!(descriptor.files.contains_key("st_good.xml"));
   assert!(
@@ -123,7 +144,11 @@ fn records_the_encoding_each_language_declares() {
   write_generated_test_resource(&format!("{root}/eng/st_test.xml"), table("st_hello", "Hello"))
     .expect("Expected a written test file");
 
-  let descriptor = read_gamedata(build_absolute_generated_test_resource_path(root)).unwrap();
+  let descriptor = read_gamedata(
+    build_absolute_generated_test_resource_path(root),
+    &TranslationLanguages::default(),
+  )
+  .unwrap();
 
   // Undeclared and named `eng`, so it resolves through the language rather than a declaration.
   assert_eq!(
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use derive_more::Display;
+use xrf_error::XrfResult;
+use xrf_xml::{XmlDocument, XmlParseOptions};
+
+/// Colour the engine resets a `%c[...]` run to, known without any definition.
+const DEFAULT_COLOR: &str = "default";
+
+/// One piece of engine markup inside a string table text.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Display)]
+pub(crate) enum TranslationMarkup {
+  /// A `%s` style argument the game fills in by position.
+  #[display("{_0}")]
+  Format(String),
+  /// A `$$ACTION_USE$$` key binding, replaced by whatever the player bound.
+  #[display("$${_0}$$")]
+  Action(String),
+  #[display("%c[{_0}]")]
+  Color(String),
+  #[display("\\n")]
+  LineBreak,
+}
+
+/// Markup read off one text, and what could not be read because it was left open.
+#[derive(Debug, Default)]
+pub(crate) struct TranslationMarkupScan {
+  pub markup: Vec<TranslationMarkup>,
+  pub unbalanced: Vec<String>,
+}
+
+/// Read the markup of a text the way the engine does when it lays the text out.
+pub(crate) fn scan_markup(text: &str) -> TranslationMarkupScan {
+  let mut scan: TranslationMarkupScan = TranslationMarkupScan::default();
+  let characters: Vec<char> = text.chars().collect();
+  let mut index: usize = 0;
+
+  while index < characters.len() {
+    match (characters[index], characters.get(index + 1).copied()) {
+      ('\\', Some('n')) => {
+        scan.markup.push(TranslationMarkup::LineBreak);
+        index += 2;
+      }
+      ('%', Some('%')) => index += 2,
+      ('%', Some('c')) if characters.get(index + 2) == Some(&'[') => {
+        match characters[index + 3..].iter().position(|character| *character == ']') {
+          Some(length) => {
+            let name: String = characters[index + 3..index + 3 + length].iter().collect();
+
+            scan.markup.push(TranslationMarkup::Color(name));
+            index += length + 4;
+          }
+          None => {
+            scan.unbalanced.push(String::from("%c["));
+            index += 3;
+          }
+        }
+      }
+      ('%', _) => {
+        // Flags, width and precision, then the conversion: `%s`, `%d`, `%.1f`, `%-5d`. The space flag is
+        // left out, X-Ray texts do not use it and prose such as "100% safe" would read as `% s`.
+        let mut end: usize = index + 1;
+
+        while characters
+          .get(end)
+          .is_some_and(|character| matches!(character, '-' | '+' | '#' | '.') || character.is_ascii_digit())
+        {
+          end += 1;
+        }
+
+        match characters.get(end) {
+          Some(conversion) if "sdiufxXgGeEc".contains(*conversion) => {
+            scan
+              .markup
+              .push(TranslationMarkup::Format(characters[index..=end].iter().collect()));
+            index = end + 1;
+          }
+          // A lone percent sign is text, as in "50% off".
+          _ => index += 1,
+        }
+      }
+      ('$', Some('$')) => {
+        let rest: &[char] = &characters[index + 2..];
+
+        match rest.windows(2).position(|pair| pair == ['$', '$']) {
+          Some(length)
+            if length > 0
+              && rest[..length]
+                .iter()
+                .all(|character| character.is_ascii_alphanumeric() || *character == '_') =>
+          {
+            scan
+              .markup
+              .push(TranslationMarkup::Action(rest[..length].iter().collect()));
+            index += length + 4;
+          }
+          _ => {
+            scan.unbalanced.push(String::from("$$"));
+            index += 2;
+          }
+        }
+      }
+      _ => index += 1,
+    }
+  }
+
+  scan
+}
+
+/// Whether a colour names nothing the engine can resolve.
+///
+/// Numeric colours are written inline and the reset colour is built in, so only named ones are looked
+/// up in the definitions.
+pub(crate) fn is_unknown_color(name: &str, color_ids: &HashSet<String>) -> bool {
+  let is_numeric: bool = name
+    .chars()
+    .all(|character| character.is_ascii_digit() || character == ',' || character == ' ');
+
+  !is_numeric && name != DEFAULT_COLOR && !color_ids.contains(name)
+}
+
+/// Read the colour names a `ui/color_defs.xml` style file defines.
+///
+/// # Errors
+///
+/// Returns an IO error when the file cannot be read, and an encoding or parsing error for a document
+/// that is not readable XML.
+pub fn read_color_definitions<P: AsRef<Path>>(path: P) -> XrfResult<HashSet<String>> {
+  let document: XmlDocument = XmlDocument::parse_bytes(&fs::read(path.as_ref())?, XmlParseOptions::default())?;
+
+  Ok(
+    document
+      .elements_named("color")
+      .filter_map(|element| element.attribute("name"))
+      .map(String::from)
+      .collect(),
+  )
+}
@@ -1,5 +1,6 @@
-//! Reporting translations a project is missing, without changing anything.
+//! Reporting translations a project is missing or has broken the markup of, without changing anything.
 
+pub(crate) mod markup;
 pub(crate) mod options;
 pub(crate) mod result;
 pub(crate) mod run;
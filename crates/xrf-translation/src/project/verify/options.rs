@@ -1,10 +1,17 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 
-use crate::language::TranslationLanguage;
+use crate::language::{TranslationLanguage, TranslationLanguages};
 
 pub struct ProjectVerifyOptions {
   pub is_strict: bool,
   pub output: xrf_output::OutputOptions,
   pub path: PathBuf,
-  pub language: TranslationLanguage,
+  /// Language to check, every configured one when `None`.
+  pub language: Option<TranslationLanguage>,
+  pub languages: TranslationLanguages,
+  /// Compare the placeholders, colours and line breaks of each translation against the English text.
+  pub is_checking_markup: bool,
+  /// Colour names `%c[...]` may use, from the UI colour definitions. Colours go unchecked without them.
+  pub color_ids: Option<HashSet<String>>,
 }
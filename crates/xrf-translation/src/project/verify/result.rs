@@ -4,6 +4,18 @@ use std::time::Duration;
 use serde::Serialize;
 use xrf_report::{CheckId, CheckReport, Finding, Report, RuleId, Status};
 
+/// Placeholder, colour or markup problem of one translated string.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationMarkupFinding {
+  pub rule: String,
+  pub subject: Option<String>,
+  /// String id the translation belongs to.
+  pub key: String,
+  pub language: String,
+  pub message: String,
+}
+
 #[derive(Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectVerifyResult {
@@ -11,6 +23,8 @@ pub struct ProjectVerifyResult {
   pub duration: Duration,
   pub checked_translations_count: u32,
   pub missing_translations_count: u32,
+  /// Placeholders, colours and markup a translation dropped, added, reordered or left open.
+  pub markup_findings: Vec<TranslationMarkupFinding>,
   #[serde(skip_serializing)]
   findings: Vec<Finding>,
 }
@@ -21,6 +35,7 @@ impl ProjectVerifyResult {
       duration: Duration::ZERO,
       checked_translations_count: 0,
       missing_translations_count: 0,
+      markup_findings: Vec::new(),
       findings: Vec::new(),
     }
   }
@@ -30,7 +45,7 @@ impl ProjectVerifyResult {
   }
 
   pub fn status(&self) -> Status {
-    Status::from_is_valid(self.missing_translations_count == 0)
+    Status::from_is_valid(self.missing_translations_count == 0 && self.markup_findings.is_empty())
   }
 
   pub fn to_report(&self) -> Report {
@@ -45,6 +60,7 @@ impl ProjectVerifyResult {
   pub(crate) fn merge(&mut self, other: Self) {
     self.checked_translations_count += other.checked_translations_count;
     self.missing_translations_count += other.missing_translations_count;
+    self.markup_findings.extend(other.markup_findings);
     self.findings.extend(other.findings);
   }
 
@@ -56,4 +72,22 @@ impl ProjectVerifyResult {
       format!("Missing translation for key '{key}' in language '{language}'"),
     ));
   }
+
+  pub(crate) fn record_markup_issue(&mut self, path: &Path, rule: &str, key: &str, language: &str, message: &str) {
+    let subject: String = path.to_string_lossy().replace('\\', "/");
+    let message: String = format!("'{key}' in language '{language}' {message}");
+
+    self.findings.push(Finding::new(
+      RuleId::new(rule).expect("Expected a non-empty translation rule ID"),
+      Some(subject.clone()),
+      message.clone(),
+    ));
+    self.markup_findings.push(TranslationMarkupFinding {
+      rule: rule.to_owned(),
+      subject: Some(subject),
+      key: key.to_owned(),
+      language: language.to_owned(),
+      message,
+    });
+  }
 }
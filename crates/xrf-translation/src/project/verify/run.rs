@@ -1,13 +1,18 @@
 use std::ffi::OsStr;
+use std::hash::Hash;
 use std::path::{Display, Path};
 use std::time::Instant;
 
+use indexmap::IndexMap;
 use walkdir::{DirEntry, WalkDir};
 use xrf_error::{XrfError, XrfResult};
 
 use crate::json;
 use crate::json::read::read_json;
+use crate::json::source_hashes::SOURCE_LANGUAGE;
 use crate::language::TranslationLanguage;
+use crate::project::build::compile::render_variant;
+use crate::project::verify::markup::{TranslationMarkup, TranslationMarkupScan, is_unknown_color, scan_markup};
 use crate::project::verify::options::ProjectVerifyOptions;
 use crate::project::verify::result::ProjectVerifyResult;
 use crate::types::TranslationJson;
@@ -70,7 +75,8 @@ pub fn verify_file<P: AsRef<Path>>(path: &P, options: &ProjectVerifyOptions) ->
   Ok(ProjectVerifyResult::new())
 }
 
-/// Record every id a requested language has no text for.
+/// Record every id a requested language has no text for, and with markup checks on, every text
+/// whose markup does not match the English one.
 ///
 /// # Errors
 ///
@@ -84,11 +90,12 @@ pub fn verify_json_file<P: AsRef<Path>>(path: &P, options: &ProjectVerifyOptions
   let started_at: Instant = Instant::now();
   let parsed: TranslationJson = read_json(path.as_ref())?;
 
-  let languages: Vec<String> = if options.language == TranslationLanguage::All {
-    TranslationLanguage::get_all_strings()
-  } else {
-    vec![options.language.to_string()]
-  };
+  let languages: Vec<String> = options
+    .languages
+    .resolve(options.language.as_ref())
+    .iter()
+    .map(TranslationLanguage::to_string)
+    .collect();
 
   for language in languages {
     for (key, entry) in &parsed {
@@ -107,6 +114,10 @@ pub fn verify_json_file<P: AsRef<Path>>(path: &P, options: &ProjectVerifyOptions
         result.record_missing_translation(path.as_ref(), key, &language);
       }
     }
+
+    if options.is_checking_markup {
+      verify_json_markup(path.as_ref(), &parsed, &language, options, &mut result);
+    }
   }
 
   result.checked_translations_count = parsed.len() as u32;
@@ -120,3 +131,125 @@ pub fn verify_json_file<P: AsRef<Path>>(path: &P, options: &ProjectVerifyOptions
 
   Ok(result)
 }
+
+/// Compare the markup of every text of one language against the English text of the same id.
+///
+/// Format arguments are filled in by position, so their order has to match as well as their count.
+/// Colours, key bindings and line breaks only have to be there: a translation is free to move them.
+fn verify_json_markup(
+  path: &Path,
+  parsed: &TranslationJson,
+  language: &str,
+  options: &ProjectVerifyOptions,
+  result: &mut ProjectVerifyResult,
+) {
+  for (key, entry) in parsed {
+    let Some(text) = entry.get(language).and_then(Option::as_ref).map(render_variant) else {
+      continue;
+    };
+    let scan: TranslationMarkupScan = scan_markup(&text);
+
+    for open in &scan.unbalanced {
+      result.record_markup_issue(
+        path,
+        "translations.markup-unbalanced",
+        key,
+        language,
+        &format!("leaves '{open}' unclosed"),
+      );
+    }
+
+    if let Some(color_ids) = &options.color_ids {
+      for markup in &scan.markup {
+        if let TranslationMarkup::Color(name) = markup
+          && is_unknown_color(name, color_ids)
+        {
+          result.record_markup_issue(
+            path,
+            "translations.markup-unknown-color",
+            key,
+            language,
+            &format!("uses colour '{name}' the UI does not define"),
+          );
+        }
+      }
+    }
+
+    if language == SOURCE_LANGUAGE {
+      continue;
+    }
+
+    let Some(source) = entry.get(SOURCE_LANGUAGE).and_then(Option::as_ref).map(render_variant) else {
+      continue;
+    };
+    let expected: Vec<TranslationMarkup> = scan_markup(&source).markup;
+    let expected_counts: IndexMap<&TranslationMarkup, usize> = count_markup(&expected);
+    let actual_counts: IndexMap<&TranslationMarkup, usize> = count_markup(&scan.markup);
+
+    for (markup, count) in &expected_counts {
+      if actual_counts.get(markup).copied().unwrap_or_default() < *count {
+        result.record_markup_issue(
+          path,
+          "translations.markup-missing",
+          key,
+          language,
+          &format!("is missing '{markup}' the English text has"),
+        );
+      }
+    }
+
+    for (markup, count) in &actual_counts {
+      if expected_counts.get(markup).copied().unwrap_or_default() < *count {
+        result.record_markup_issue(
+          path,
+          "translations.markup-extra",
+          key,
+          language,
+          &format!("has '{markup}' the English text does not"),
+        );
+      }
+    }
+
+    let expected_formats: Vec<&TranslationMarkup> = formats(&expected);
+    let actual_formats: Vec<&TranslationMarkup> = formats(&scan.markup);
+
+    if expected_formats != actual_formats && count_markup(&expected_formats) == count_markup(&actual_formats) {
+      result.record_markup_issue(
+        path,
+        "translations.markup-reordered",
+        key,
+        language,
+        &format!(
+          "fills placeholders in a different order: expected '{}', found '{}'",
+          join_markup(&expected_formats),
+          join_markup(&actual_formats)
+        ),
+      );
+    }
+  }
+}
+
+fn count_markup<T: Eq + Hash>(markup: &[T]) -> IndexMap<&T, usize> {
+  let mut counts: IndexMap<&T, usize> = IndexMap::new();
+
+  for item in markup {
+    *counts.entry(item).or_default() += 1;
+  }
+
+  counts
+}
+
+fn formats(markup: &[TranslationMarkup]) -> Vec<&TranslationMarkup> {
+  markup
+    .iter()
+    .filter(|item| matches!(item, TranslationMarkup::Format(_)))
+    .collect()
+}
+
+fn join_markup(markup: &[&TranslationMarkup]) -> String {
+  markup
+    .iter()
+    .map(ToString::to_string)
+    .collect::<Vec<String>>()
+    .join(" ")
+}
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::write_generated_test_resource;
+
+use crate::project::verify::markup::{TranslationMarkup, is_unknown_color, read_color_definitions, scan_markup};
+
+#[test]
+fn reads_every_kind_of_engine_markup() {
+  let scan = scan_markup("%c[ui_gray_2]Press $$ACTION_USE$$\\nto take %d of %s, 50% off%%");
+
+  assert_eq!(
+    scan.markup,
+    vec![
+      TranslationMarkup::Color(String::from("ui_gray_2")),
+      TranslationMarkup::Action(String::from("ACTION_USE")),
+      TranslationMarkup::LineBreak,
+      TranslationMarkup::Format(String::from("%d")),
+      TranslationMarkup::Format(String::from("%s")),
+    ]
+  );
+  assert!(scan.unbalanced.is_empty());
+}
+
+#[test]
+fn reads_percent_signs_in_prose_as_text() {
+  assert!(scan_markup("100% safe, 50% sale").markup.is_empty());
+  assert_eq!(
+    scan_markup("%-5d, %+d").markup,
+    vec![
+      TranslationMarkup::Format(String::from("%-5d")),
+      TranslationMarkup::Format(String::from("%+d")),
+    ]
+  );
+}
+
+#[test]
+fn reports_markup_left_open() {
+  let scan = scan_markup("%c[ui_gray_2 broken and $$ACTION_USE");
+
+  assert_eq!(scan.unbalanced, vec![String::from("%c["), String::from("$$")]);
+}
+
+#[test]
+fn numeric_and_reset_colours_need_no_definition() {
+  let color_ids: HashSet<String> = HashSet::from([String::from("ui_gray_2")]);
+
+  assert!(!is_unknown_color("ui_gray_2", &color_ids));
+  assert!(!is_unknown_color("255,255,0,0", &color_ids));
+  assert!(!is_unknown_color("default", &color_ids));
+  assert!(is_unknown_color("ui_grey_2", &color_ids));
+}
+
+#[test]
+fn reads_colour_names_from_the_ui_definitions() -> XrfResult {
+  let path = write_generated_test_resource(
+    "verify_markup/color_defs.xml",
+    "<?xml version=\"1.0\" encoding=\"windows-1251\"?><colors><color name=\"ui_gray_1\" r=\"1\" g=\"1\" b=\"1\"/>\
+     <color name=\"ui_gray_2\" r=\"2\" g=\"2\" b=\"2\"/></colors>",
+  )?;
+
+  assert_eq!(
+    read_color_definitions(&path)?,
+    HashSet::from([String::from("ui_gray_1"), String::from("ui_gray_2")])
+  );
+
+  Ok(())
+}
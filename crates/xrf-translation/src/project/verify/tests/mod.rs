@@ -1 +1,3 @@
+mod markup;
 mod result;
+mod run;
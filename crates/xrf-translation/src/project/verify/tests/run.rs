@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use xrf_error::XrfResult;
+use xrf_test_utils::utils::write_generated_test_resource;
+
+use crate::language::TranslationLanguages;
+use crate::project::verify::options::ProjectVerifyOptions;
+use crate::project::verify::result::ProjectVerifyResult;
+use crate::project::verify::run::verify_json_file;
+
+fn options(language: &str, is_checking_markup: bool) -> ProjectVerifyOptions {
+  let languages: TranslationLanguages = TranslationLanguages::default();
+
+  ProjectVerifyOptions {
+    is_strict: false,
+    output: xrf_output::OutputOptions::default(),
+    path: Default::default(),
+    language: languages.select(language).unwrap(),
+    languages,
+    is_checking_markup,
+    color_ids: Some(HashSet::from([String::from("ui_gray_2")])),
+  }
+}
+
+fn rules(result: &ProjectVerifyResult) -> Vec<&str> {
+  result
+    .markup_findings
+    .iter()
+    .map(|finding| finding.rule.as_str())
+    .collect()
+}
+
+#[test]
+fn reports_markup_a_translation_broke() -> XrfResult {
+  let path = write_generated_test_resource(
+    "verify_markup/broken.json",
+    r#"{
+      "st_fine": {"eng": "%c[ui_gray_2]Take %d of %s", "ukr": "%c[ui_gray_2]Vzyaty %d z %s"},
+      "st_missing": {"eng": "Press $$ACTION_USE$$\\nnow", "ukr": "Natysnit zaraz"},
+      "st_extra": {"eng": "Hello", "ukr": "Pryvit %s"},
+      "st_reordered": {"eng": "%d of %s", "ukr": "%s z %d"},
+      "st_color": {"eng": "%c[ui_gray_2]Text", "ukr": "%c[ui_grey_2]Tekst"},
+      "st_open": {"eng": "Press $$ACTION_USE$$", "ukr": "Natysnit $$ACTION_USE"}
+    }"#,
+  )?;
+
+  let result: ProjectVerifyResult = verify_json_file(&path, &options("ukr", true))?;
+
+  assert_eq!(
+    rules(&result),
+    vec![
+      "translations.markup-missing",
+      "translations.markup-missing",
+      "translations.markup-extra",
+      "translations.markup-reordered",
+      "translations.markup-unknown-color",
+      "translations.markup-missing",
+      "translations.markup-extra",
+      "translations.markup-unbalanced",
+      "translations.markup-missing",
+    ]
+  );
+  assert!(
+    result
+      .markup_findings
+      .iter()
+      .all(|finding| finding.language == "ukr" && finding.message.contains("in language 'ukr'"))
+  );
+  assert_eq!(result.markup_findings[0].key, "st_missing");
+  assert!(result.markup_findings[0].message.starts_with("'st_missing'"));
+
+  Ok(())
+}
+
+#[test]
+fn markup_is_only_checked_when_asked_for() -> XrfResult {
+  let path = write_generated_test_resource(
+    "verify_markup/unchecked.json",
+    r#"{"st_extra": {"eng": "Hello", "ukr": "Pryvit %s"}}"#,
+  )?;
+
+  assert!(
+    verify_json_file(&path, &options("ukr", false))?
+      .markup_findings
+      .is_empty()
+  );
+
+  Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::types::TranslationVariant;
+
+/// One id as translator tools see it: the English source and one language's text, in one source file.
+///
+/// Text is in interchange form, where the lines of a multi-line entry are joined with a real line
+/// break. The engine's own `\n` stays as the two characters it is in a single-line entry.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct TranslationUnit {
+  /// Source file the id lives in, relative to the project root with `/` separators.
+  pub file: String,
+  pub id: String,
+  pub source: String,
+  /// Empty when the language has no text yet.
+  pub target: String,
+  /// Marked for review, either by the export because the English text changed since the translation
+  /// was written, or by the translator.
+  pub is_fuzzy: bool,
+}
+
+impl TranslationUnit {
+  /// Interchange form of an entry's text.
+  pub(crate) fn to_text(value: &TranslationVariant) -> String {
+    match value {
+      TranslationVariant::String(value) => value.clone(),
+      TranslationVariant::MultiString(values) => values.join("\n"),
+    }
+  }
+
+  /// Entry value for an imported text, keeping the shape of the English entry it translates.
+  pub(crate) fn to_variant(text: &str, source: &TranslationVariant) -> TranslationVariant {
+    match source {
+      TranslationVariant::String(_) => TranslationVariant::String(text.to_owned()),
+      TranslationVariant::MultiString(_) => {
+        TranslationVariant::MultiString(text.split('\n').map(String::from).collect())
+      }
+    }
+  }
+}
+
+/// Units read back from a translator's file, with the language code it declares, if any.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct TranslationCatalog {
+  pub language: Option<String>,
+  pub units: Vec<TranslationUnit>,
+}
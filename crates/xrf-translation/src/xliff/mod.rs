@@ -0,0 +1,7 @@
+//! XLIFF 1.2 and 2.0 documents: the XML interchange format of translation memories and CAT tools.
+
+pub(crate) mod read;
+pub(crate) mod write;
+
+#[cfg(test)]
+mod tests;
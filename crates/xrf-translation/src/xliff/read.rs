@@ -0,0 +1,104 @@
+use xrf_error::{XrfError, XrfResult};
+use xrf_xml::{XmlDocument, XmlElement, XmlParseOptions};
+
+use crate::unit::{TranslationCatalog, TranslationUnit};
+
+/// Read an XLIFF 1.2 or 2.0 document, telling the version by its `version` attribute.
+///
+/// Text is read as plain character data: inline markup a tool may have added is dropped, as the
+/// string tables have nothing to hold it.
+///
+/// # Errors
+///
+/// Returns a parsing error for malformed XML, a root other than `<xliff>`, or an unknown version.
+pub(crate) fn read_xliff(bytes: &[u8]) -> XrfResult<TranslationCatalog> {
+  let document: XmlDocument = XmlDocument::parse_bytes(bytes, XmlParseOptions::default())?;
+  let root: &XmlElement = document.root();
+
+  if root.name() != "xliff" {
+    return Err(XrfError::new_parsing_error(format!(
+      "Expected <xliff> as the document root, found <{}>",
+      root.name()
+    )));
+  }
+
+  match root.attribute("version") {
+    Some("1.2") => Ok(read_xliff_1_2(root)),
+    Some(version) if version.starts_with("2.") => Ok(read_xliff_2(root)),
+    version => Err(XrfError::new_parsing_error(format!(
+      "Unsupported XLIFF version '{}'",
+      version.unwrap_or_default()
+    ))),
+  }
+}
+
+fn read_xliff_1_2(root: &XmlElement) -> TranslationCatalog {
+  let mut catalog: TranslationCatalog = TranslationCatalog::default();
+
+  for file in root.children_named("file") {
+    catalog.language = catalog
+      .language
+      .or_else(|| file.attribute("target-language").map(String::from));
+
+    for unit in file.descendants_named("trans-unit") {
+      let target: Option<&XmlElement> = unit.children_named("target").next();
+
+      catalog.units.push(TranslationUnit {
+        file: file.attribute("original").unwrap_or_default().to_owned(),
+        id: unit
+          .attribute("resname")
+          .or_else(|| unit.attribute("id"))
+          .unwrap_or_default()
+          .to_owned(),
+        source: unit
+          .children_named("source")
+          .next()
+          .map(|source| source.text().to_owned())
+          .unwrap_or_default(),
+        target: target.map(|target| target.text().to_owned()).unwrap_or_default(),
+        // Every state but `translated` and the final ones is work someone still has to look at.
+        is_fuzzy: target
+          .and_then(|target| target.attribute("state"))
+          .is_some_and(|state| state == "new" || state.starts_with("needs-")),
+      });
+    }
+  }
+
+  catalog
+}
+
+fn read_xliff_2(root: &XmlElement) -> TranslationCatalog {
+  let mut catalog: TranslationCatalog = TranslationCatalog {
+    language: root.attribute("trgLang").map(String::from),
+    units: Vec::new(),
+  };
+
+  for file in root.children_named("file") {
+    for unit in file.descendants_named("unit") {
+      let segments: Vec<&XmlElement> = unit.children_named("segment").collect();
+      let text = |name: &str| -> String {
+        segments
+          .iter()
+          .filter_map(|segment| segment.children_named(name).next())
+          .map(XmlElement::text)
+          .collect()
+      };
+
+      catalog.units.push(TranslationUnit {
+        file: file.attribute("original").unwrap_or_default().to_owned(),
+        id: unit
+          .attribute("name")
+          .or_else(|| unit.attribute("id"))
+          .unwrap_or_default()
+          .to_owned(),
+        source: text("source"),
+        target: text("target"),
+        is_fuzzy: segments
+          .iter()
+          .any(|segment| segment.attribute("state") == Some("initial")),
+      });
+    }
+  }
+
+  catalog
+}
@@ -0,0 +1,72 @@
+use xrf_error::XrfResult;
+
+use crate::language::TranslationLanguages;
+use crate::unit::{TranslationCatalog, TranslationUnit};
+use crate::xliff::read::read_xliff;
+use crate::xliff::write::{XliffVersion, write_xliff};
+
+fn units() -> Vec<TranslationUnit> {
+  vec![
+    TranslationUnit {
+      file: String::from("dialogs.json"),
+      id: String::from("st_hello"),
+      source: String::from("Hello & <welcome>"),
+      target: String::from("Pryvit & <vitayu>"),
+      is_fuzzy: false,
+    },
+    TranslationUnit {
+      file: String::from("dialogs.json"),
+      id: String::from("st_lines"),
+      source: String::from("First\nSecond"),
+      target: String::from("Pershyi\nDruhyi"),
+      is_fuzzy: true,
+    },
+    TranslationUnit {
+      file: String::from("items.json"),
+      id: String::from("st_item"),
+      source: String::from("Item"),
+      target: String::new(),
+      is_fuzzy: false,
+    },
+  ]
+}
+
+#[test]
+fn round_trips_units_through_xliff_1_2() -> XrfResult {
+  let written: String = write_xliff(
+    &units(),
+    &TranslationLanguages::default().get_single("ukr").unwrap(),
+    XliffVersion::V1_2,
+  );
+  let catalog: TranslationCatalog = read_xliff(written.as_bytes())?;
+
+  assert!(written.contains("<file original=\"items.json\" source-language=\"en\" target-language=\"uk\""));
+  assert!(written.contains("<target state=\"needs-review-translation\">Pershyi\nDruhyi</target>"));
+  assert_eq!(catalog.language.as_deref(), Some("uk"));
+  assert_eq!(catalog.units, units());
+
+  Ok(())
+}
+
+#[test]
+fn round_trips_units_through_xliff_2_0() -> XrfResult {
+  let written: String = write_xliff(
+    &units(),
+    &TranslationLanguages::default().get_single("ukr").unwrap(),
+    XliffVersion::V2_0,
+  );
+  let catalog: TranslationCatalog = read_xliff(written.as_bytes())?;
+
+  assert!(written.contains("srcLang=\"en\" trgLang=\"uk\""));
+  assert!(written.contains("<file id=\"f2\" original=\"items.json\">"));
+  assert_eq!(catalog.language.as_deref(), Some("uk"));
+  assert_eq!(catalog.units, units());
+
+  Ok(())
+}
+
+#[test]
+fn refuses_other_documents_and_versions() {
+  assert!(read_xliff(b"<string_table/>").is_err());
+  assert!(read_xliff(b"<xliff version=\"1.0\"/>").is_err());
+}
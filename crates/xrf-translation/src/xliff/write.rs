@@ -0,0 +1,113 @@
+use indexmap::IndexMap;
+use xrf_xml::{escape_xml_attribute, escape_xml_text};
+
+use crate::language::TranslationLanguage;
+use crate::unit::TranslationUnit;
+
+const XLIFF_1_2_NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:1.2";
+const XLIFF_2_0_NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:2.0";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum XliffVersion {
+  V1_2,
+  V2_0,
+}
+
+/// Write units as an XLIFF document, one `<file>` per source file so the import knows where each goes.
+///
+/// A unit is named by its id, which is unique within its source file as XLIFF requires. Stale
+/// translations are written with the state each version uses for work waiting on review.
+pub(crate) fn write_xliff(units: &[TranslationUnit], language: &TranslationLanguage, version: XliffVersion) -> String {
+  let english: TranslationLanguage = TranslationLanguage::english();
+  let source_language: &str = english.get_locale_code().unwrap_or_default();
+  let target_language: &str = language.get_locale_code().unwrap_or_default();
+  let mut files: IndexMap<&str, Vec<&TranslationUnit>> = IndexMap::new();
+  let mut output: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+  for unit in units {
+    files.entry(unit.file.as_str()).or_default().push(unit);
+  }
+
+  match version {
+    XliffVersion::V1_2 => {
+      output.push_str(&format!("<xliff version=\"1.2\" xmlns=\"{XLIFF_1_2_NAMESPACE}\">\n"));
+
+      for (file, units) in files {
+        output.push_str(&format!(
+          "  <file original=\"{}\" source-language=\"{source_language}\" target-language=\"{target_language}\" \
+           datatype=\"plaintext\">\n    <body>\n",
+          escape_xml_attribute(file)
+        ));
+
+        for unit in units {
+          output.push_str(&format!(
+            "      <trans-unit id=\"{}\" xml:space=\"preserve\">\n        <source>{}</source>\n",
+            escape_xml_attribute(&unit.id),
+            escape_xml_text(&unit.source)
+          ));
+
+          if !unit.target.is_empty() {
+            output.push_str(&format!(
+              "        <target state=\"{}\">{}</target>\n",
+              if unit.is_fuzzy {
+                "needs-review-translation"
+              } else {
+                "translated"
+              },
+              escape_xml_text(&unit.target)
+            ));
+          }
+
+          output.push_str("      </trans-unit>\n");
+        }
+
+        output.push_str("    </body>\n  </file>\n");
+      }
+    }
+    XliffVersion::V2_0 => {
+      output.push_str(&format!(
+        "<xliff version=\"2.0\" xmlns=\"{XLIFF_2_0_NAMESPACE}\" srcLang=\"{source_language}\" \
+         trgLang=\"{target_language}\">\n"
+      ));
+
+      for (index, (file, units)) in files.into_iter().enumerate() {
+        output.push_str(&format!(
+          "  <file id=\"f{}\" original=\"{}\">\n",
+          index + 1,
+          escape_xml_attribute(file)
+        ));
+
+        for unit in units {
+          // No state on an untranslated segment: `initial` is kept for stale work, which the import skips.
+          let state: &str = if unit.target.is_empty() {
+            ""
+          } else if unit.is_fuzzy {
+            " state=\"initial\""
+          } else {
+            " state=\"translated\""
+          };
+
+          output.push_str(&format!(
+            "    <unit id=\"{}\">\n      <segment{state}>\n        <source xml:space=\"preserve\">{}</source>\n",
+            escape_xml_attribute(&unit.id),
+            escape_xml_text(&unit.source)
+          ));
+
+          if !unit.target.is_empty() {
+            output.push_str(&format!(
+              "        <target xml:space=\"preserve\">{}</target>\n",
+              escape_xml_text(&unit.target)
+            ));
+          }
+
+          output.push_str("      </segment>\n    </unit>\n");
+        }
+
+        output.push_str("  </file>\n");
+      }
+    }
+  }
+
+  output.push_str("</xliff>\n");
+  output
+}
@@ -5,7 +5,7 @@ use xrf_error::{XrfError, XrfResult};
 use xrf_utils::{XRayEncoding, decode_bytes_to_string_without_bom_handling, new_utf8_encoder};
 use xrf_xml::declared_xml_encoding;
 
-use crate::language::TranslationLanguage;
+use crate::language::{TranslationLanguage, TranslationLanguages};
 
 /// Decide which encoding a string table file is written in.
 ///
@@ -17,14 +17,16 @@ use crate::language::TranslationLanguage;
 ///
 /// # Errors
 ///
-/// Returns an encoding error when the declaration names a code page there is no encoder for.
-pub(crate) fn resolve_encoding(path: &Path, data: &[u8]) -> XrfResult<XRayEncoding> {
-  let language: Option<TranslationLanguage> =
-    TranslationLanguage::from_file_name(path).or_else(|| TranslationLanguage::from_parent_directory(path));
+/// Returns an encoding error when the declaration or the language names a code page there is no
+/// encoder for.
+pub(crate) fn resolve_encoding(path: &Path, data: &[u8], languages: &TranslationLanguages) -> XrfResult<XRayEncoding> {
+  let language: Option<&TranslationLanguage> = languages
+    .get_by_file_name(path)
+    .or_else(|| languages.get_by_parent_directory(path));
   let declared: Option<XRayEncoding> = declared_xml_encoding(data)?;
 
   if let (Some(declared), Some(language)) = (declared, language)
-    && declared != language.new_language_encoder()
+    && declared != language.new_language_encoder()?
   {
     log::warn!(
       "Translation XML '{}' declares {}, but '{}' expects {}",
@@ -35,7 +37,13 @@ pub(crate) fn resolve_encoding(path: &Path, data: &[u8]) -> XrfResult<XRayEncodi
     );
   }
 
-  Ok(declared.unwrap_or_else(|| language.unwrap_or(TranslationLanguage::English).new_language_encoder()))
+  match declared {
+    Some(declared) => Ok(declared),
+    None => language.map_or_else(
+      || TranslationLanguage::english().new_language_encoder(),
+      TranslationLanguage::new_language_encoder,
+    ),
+  }
 }
 
 /// Read a string table into text, with the byte order mark and encoding needed to write it back.
@@ -46,16 +54,31 @@ pub(crate) fn resolve_encoding(path: &Path, data: &[u8]) -> XrfResult<XRayEncodi
 ///
 /// Returns an IO error when the file cannot be read, and an encoding error when its bytes do not
 /// decode as the encoding it claims.
-pub(crate) fn read_decoded(path: &Path) -> XrfResult<(Vec<u8>, XRayEncoding, String)> {
-  let data: Vec<u8> = fs::read(path)?;
-  let (mark, body) = split_byte_order_mark(path, &data)?;
+pub(crate) fn read_decoded(
+  path: &Path,
+  languages: &TranslationLanguages,
+) -> XrfResult<(Vec<u8>, XRayEncoding, String)> {
+  decode(path, &fs::read(path)?, languages)
+}
+
+/// Decode string table bytes already read from somewhere, `path` deciding the language as for a file.
+///
+/// # Errors
+///
+/// Returns an encoding error when the bytes do not decode as the encoding they claim.
+pub(crate) fn decode(
+  path: &Path,
+  data: &[u8],
+  languages: &TranslationLanguages,
+) -> XrfResult<(Vec<u8>, XRayEncoding, String)> {
+  let (mark, body) = split_byte_order_mark(path, data)?;
 
   // A byte order mark decides the encoding and outranks the declaration, which shipped files
   // contradict: gamedata-coc's st_items_weapons.xml is UTF-8 marked and declares windows-1251. It is
   // also kept out of the decoded text and put back verbatim, because the usual decode strips it and
   // re-encoding would then drop it from a file that had one.
   let encoding: XRayEncoding = if mark.is_empty() {
-    resolve_encoding(path, body)?
+    resolve_encoding(path, body, languages)?
   } else {
     new_utf8_encoder()
   };
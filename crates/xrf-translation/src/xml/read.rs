@@ -3,7 +3,8 @@ use std::path::Path;
 use xrf_error::XrfResult;
 use xrf_xml::{XmlElementSpan, XmlParseOptions, XmlSourceDocument};
 
-use crate::xml::encoding::read_decoded;
+use crate::language::TranslationLanguages;
+use crate::xml::encoding::{decode, read_decoded};
 
 /// Read one string table into its entries, tolerating whatever the engine tolerates.
 ///
@@ -11,8 +12,32 @@ use crate::xml::encoding::read_decoded;
 ///
 /// Returns an encoding error when the bytes do not decode, and a parsing error when the document is
 /// malformed beyond what the engine accepts.
-pub(crate) fn read_string_table(path: &Path) -> XrfResult<Vec<(String, String)>> {
-  let (_, _, source) = read_decoded(path)?;
+pub(crate) fn read_string_table(path: &Path, languages: &TranslationLanguages) -> XrfResult<Vec<(String, String)>> {
+  let (_, _, source) = read_decoded(path, languages)?;
+
+  parse_string_table(source)
+}
+
+/// Read one string table from bytes a caller already holds, such as an entry of a game archive.
+///
+/// `path` is only used to pick the language encoding of an undeclared file, looked up in `languages`,
+/// and to name it in errors.
+///
+/// # Errors
+///
+/// Returns an encoding error when the bytes do not decode, and a parsing error when the document is
+/// malformed beyond what the engine accepts.
+pub fn read_string_table_bytes(
+  path: &Path,
+  data: &[u8],
+  languages: &TranslationLanguages,
+) -> XrfResult<Vec<(String, String)>> {
+  let (_, _, source) = decode(path, data, languages)?;
+
+  parse_string_table(source)
+}
+
+fn parse_string_table(source: String) -> XrfResult<Vec<(String, String)>> {
   let document: XmlSourceDocument = XmlSourceDocument::parse(source, XmlParseOptions::default())?;
 
   Ok(
@@ -4,6 +4,7 @@ use xrf_error::XrfResult;
 use xrf_test_utils::utils::write_generated_test_resource;
 use xrf_utils::{encode_string_to_bytes, new_windows1251_encoder, new_windows1252_encoder};
 
+use crate::language::TranslationLanguages;
 use crate::xml::read::read_string_table;
 
 fn write_encoded(relative_path: &str, source: &str, encoding: xrf_utils::XRayEncoding) -> XrfResult<PathBuf> {
@@ -20,7 +21,7 @@ fn reads_xml_using_its_declared_windows_1251_encoding() -> XrfResult {
 
   let path: PathBuf = write_encoded(relative_path, source, new_windows1251_encoder())?;
 
-  let entries = read_string_table(&path)?;
+  let entries = read_string_table(&path, &TranslationLanguages::default())?;
 
   assert_eq!(entries, vec![(String::from("st_test"), String::from("Привіт"))]);
 
@@ -34,7 +35,7 @@ fn declarationless_xml_uses_the_language_code_page_fallback() -> XrfResult {
 
   let path: PathBuf = write_encoded(relative_path, source, new_windows1252_encoder())?;
 
-  let entries = read_string_table(&path)?;
+  let entries = read_string_table(&path, &TranslationLanguages::default())?;
 
   assert_eq!(entries, vec![(String::from("st_test"), String::from("À bientôt"))]);
 
@@ -50,7 +51,7 @@ fn a_declaration_outranks_the_filename_suffix() -> XrfResult {
   // declares is what it was written with, so that is what it is read with.
   let path: PathBuf = write_encoded(relative_path, source, new_windows1251_encoder())?;
 
-  let entries = read_string_table(&path)?;
+  let entries = read_string_table(&path, &TranslationLanguages::default())?;
 
   assert_eq!(entries, vec![(String::from("st_test"), String::from("Привет"))]);
 
@@ -65,7 +66,7 @@ fn a_gamedata_directory_supplies_the_encoding_when_nothing_else_does() -> XrfRes
   // No declaration and no suffix, so the only statement of language is the directory it sits in.
   let path: PathBuf = write_encoded(relative_path, source, new_windows1251_encoder())?;
 
-  let entries = read_string_table(&path)?;
+  let entries = read_string_table(&path, &TranslationLanguages::default())?;
 
   assert_eq!(entries, vec![(String::from("st_test"), String::from("Привет"))]);
 
@@ -75,7 +76,7 @@ fn a_gamedata_directory_supplies_the_encoding_when_nothing_else_does() -> XrfRes
 #[test]
 fn utf16_is_refused_rather_than_read_as_a_code_page() -> XrfResult {
   let path: PathBuf = write_generated_test_resource("xml_encoding/utf16.xml", [0xFF, 0xFE, 0x3C, 0x00])?;
-  let error = read_string_table(&path).unwrap_err();
+  let error = read_string_table(&path, &TranslationLanguages::default()).unwrap_err();
 
   assert!(error.to_string().contains("UTF-16"));
 
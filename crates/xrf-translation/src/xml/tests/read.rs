@@ -1,7 +1,10 @@
+use std::path::Path;
+
 use xrf_error::XrfResult;
 use xrf_test_utils::utils::write_generated_test_resource;
 
-use crate::xml::read::read_string_table;
+use crate::language::TranslationLanguages;
+use crate::xml::read::{read_string_table, read_string_table_bytes};
 
 #[test]
 fn returns_entries_in_file_order() -> XrfResult {
@@ -11,7 +14,7 @@ fn returns_entries_in_file_order() -> XrfResult {
   )?;
 
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![
       (String::from("st_b"), String::from("B")),
       (String::from("st_a"), String::from("A")),
@@ -31,7 +34,7 @@ fn keeps_duplicates_instead_of_refusing_them() -> XrfResult {
   // Deciding which one the engine uses is the caller's rule, not the parser's. Real shipped files
   // contain these, and refusing them would make the editor unable to open what it exists to fix.
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![
       (String::from("st_dup"), String::from("first")),
       (String::from("st_dup"), String::from("second")),
@@ -50,7 +53,7 @@ fn skips_an_entry_with_no_text_element() -> XrfResult {
 
   // The engine skips these too, with a message rather than a failure.
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![(String::from("st_ok"), String::from("ok"))]
   );
 
@@ -66,7 +69,7 @@ fn reads_a_comment_banner_that_xml_forbids() -> XrfResult {
 
   // Shipped gamedata is full of these, and X-Ray's own reader accepts them.
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![(String::from("st_a"), String::from("A"))]
   );
 
@@ -81,7 +84,7 @@ fn resolves_entities_in_the_text() -> XrfResult {
   )?;
 
   assert_eq!(
-    read_string_table(&path)?,
+    read_string_table(&path, &TranslationLanguages::default())?,
     vec![(String::from("st_a"), String::from("a & b"))]
   );
 
@@ -92,7 +95,21 @@ fn resolves_entities_in_the_text() -> XrfResult {
 fn a_malformed_document_is_reported_rather_than_silently_empty() -> XrfResult {
   let path = write_generated_test_resource("xml_read/malformed.xml", "<string_table><string id=")?;
 
-  assert!(read_string_table(&path).is_err());
+  assert!(read_string_table(&path, &TranslationLanguages::default()).is_err());
+
+  Ok(())
+}
+
+#[test]
+fn reads_bytes_held_by_the_caller_like_a_file() -> XrfResult {
+  let entries = read_string_table_bytes(
+    Path::new("text/rus/st_dialogs.xml"),
+    "<?xml version=\"1.0\" encoding=\"windows-1251\"?><string_table><string id=\"st_a\"><text>A</text></string></string_table>"
+      .as_bytes(),
+    &TranslationLanguages::default(),
+  )?;
+
+  assert_eq!(entries, vec![(String::from("st_a"), String::from("A"))]);
 
   Ok(())
 }
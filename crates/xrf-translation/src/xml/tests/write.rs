@@ -6,6 +6,7 @@ use xrf_test_utils::utils::write_generated_test_resource;
 use xrf_utils::{encode_string_to_bytes, new_windows1251_encoder, new_windows1252_encoder};
 
 use crate::edit::TranslationEdit;
+use crate::language::TranslationLanguages;
 use crate::types::TranslationVariant;
 use crate::xml::write::{apply_edits, splice_edits};
 
@@ -123,7 +124,7 @@ fn writing_a_file_leaves_every_untouched_byte_alone() -> XrfResult {
     encode_string_to_bytes(SOURCE, new_windows1251_encoder())?,
   )?;
 
-  apply_edits(&path, &[set("st_first", "Привет")])?;
+  apply_edits(&path, &[set("st_first", "Привет")], &TranslationLanguages::default())?;
 
   let expected: Vec<u8> = encode_string_to_bytes(
     &SOURCE.replace("<text>first</text>", "<text>Привет</text>"),
@@ -145,7 +146,7 @@ fn a_byte_order_mark_survives_a_rewrite() -> XrfResult {
 
   let path = write_generated_test_resource(relative_path, marked)?;
 
-  apply_edits(&path, &[set("st_a", "B")])?;
+  apply_edits(&path, &[set("st_a", "B")], &TranslationLanguages::default())?;
 
   let written: Vec<u8> = fs::read(&path)?;
 
@@ -162,7 +163,7 @@ fn an_empty_edit_list_does_not_touch_the_file() -> XrfResult {
   let body: &str = "<string_table><string id=\"st_a\"><text>A</text></string></string_table>";
   let path = write_generated_test_resource(relative_path, body)?;
 
-  apply_edits(&path, &[])?;
+  apply_edits(&path, &[], &TranslationLanguages::default())?;
 
   assert_eq!(fs::read(&path)?, body.as_bytes());
 
@@ -7,7 +7,7 @@ use xrf_utils::{XRayEncoding, encode_string_to_bytes};
 use xrf_xml::{XmlElementSpan, XmlParseOptions, XmlSourceDocument, escape_xml_text};
 
 use crate::edit::TranslationEdit;
-use crate::language::find_unencodable_character;
+use crate::language::{TranslationLanguages, find_unencodable_character};
 use crate::staged_write::write_file_staged;
 use crate::xml::encoding::read_decoded;
 use crate::xml::layout::{XmlLayout, removal_range};
@@ -23,12 +23,12 @@ use crate::xml::layout::{XmlLayout, removal_range};
 /// Returns an encoding error when a value cannot be represented in the file's encoding, a parsing
 /// error when the file is not a well-formed string table, and an IO error when it cannot be read or
 /// replaced.
-pub fn apply_edits(path: &Path, edits: &[TranslationEdit]) -> XrfResult {
+pub fn apply_edits(path: &Path, edits: &[TranslationEdit], languages: &TranslationLanguages) -> XrfResult {
   if edits.is_empty() {
     return Ok(());
   }
 
-  let (mark, encoding, source) = read_decoded(path)?;
+  let (mark, encoding, source) = read_decoded(path, languages)?;
   let edited: String = splice_edits(path, source, edits, encoding)?;
 
   let mut written: Vec<u8> = mark.clone();
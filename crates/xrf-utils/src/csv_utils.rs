@@ -0,0 +1,266 @@
+use std::str::FromStr;
+
+use xrf_error::{XrfError, XrfResult};
+
+/// Split CSV into records the way spreadsheets write it: quoted fields may hold commas, quotes
+/// doubled to escape them and line breaks.
+pub fn parse_csv_records(content: &str) -> Vec<Vec<String>> {
+  parse_csv_numbered_records(content)
+    .into_iter()
+    .map(|(_, record)| record)
+    .collect()
+}
+
+/// Split CSV into records like [`parse_csv_records`], each with the line it starts on, counted from 1
+/// with line breaks inside quoted fields included.
+pub fn parse_csv_numbered_records(content: &str) -> Vec<(usize, Vec<String>)> {
+  let mut records: Vec<(usize, Vec<String>)> = Vec::new();
+  let mut record: Vec<String> = Vec::new();
+  let mut field: String = String::new();
+  let mut is_quoted: bool = false;
+  let mut line: usize = 1;
+  let mut record_line: usize = 1;
+  let mut characters = content.trim_start_matches('\u{feff}').chars().peekable();
+
+  while let Some(character) = characters.next() {
+    match (character, is_quoted) {
+      ('"', true) if characters.peek() == Some(&'"') => {
+        field.push('"');
+        characters.next();
+      }
+      ('"', true) => is_quoted = false,
+      ('"', false) if field.is_empty() => is_quoted = true,
+      (',', false) => record.push(std::mem::take(&mut field)),
+      ('\r', false) => {}
+      ('\n', false) => {
+        line += 1;
+        record.push(std::mem::take(&mut field));
+        records.push((record_line, std::mem::take(&mut record)));
+        record_line = line;
+      }
+      ('\n', true) => {
+        line += 1;
+        field.push(character);
+      }
+      _ => field.push(character),
+    }
+  }
+
+  if !field.is_empty() || !record.is_empty() {
+    record.push(field);
+    records.push((record_line, record));
+  }
+
+  records
+}
+
+/// Quote provided CSV field when it holds anything the parser would split on.
+pub fn escape_csv_field(value: &str) -> String {
+  if value.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", value.replace('"', "\"\""))
+  } else {
+    value.to_owned()
+  }
+}
+
+/// Records of a CSV table addressed by its header, so columns can be moved or optional ones dropped.
+pub struct CsvTableReader {
+  header: Vec<String>,
+  records: Vec<(usize, Vec<String>)>,
+}
+
+impl CsvTableReader {
+  /// Read the header and the records after it, skipping blank lines.
+  ///
+  /// # Errors
+  ///
+  /// Returns a parsing error for a table without a header or missing one of `required_columns`.
+  pub fn parse(content: &str, table_name: &str, required_columns: &[&str]) -> XrfResult<Self> {
+    let mut records = parse_csv_numbered_records(content)
+      .into_iter()
+      .filter(|(_, record)| record.iter().any(|it| !it.is_empty()));
+    let header: Vec<String> = records
+      .next()
+      .ok_or_else(|| XrfError::new_parsing_error(format!("Expected {table_name} to have a header")))?
+      .1
+      .iter()
+      .map(|it| it.trim().to_owned())
+      .collect();
+
+    for required in required_columns {
+      if !header.iter().any(|it| it == required) {
+        return Err(XrfError::new_parsing_error(format!(
+          "Expected {table_name} to have a '{required}' column"
+        )));
+      }
+    }
+
+    Ok(Self {
+      header,
+      records: records.collect(),
+    })
+  }
+
+  /// Records after the header, numbered by the line they were read from.
+  pub fn rows(&self) -> impl Iterator<Item = CsvTableRow<'_>> {
+    self.records.iter().map(|(line, record)| CsvTableRow {
+      header: &self.header,
+      record,
+      line: *line,
+    })
+  }
+}
+
+/// One record of a [`CsvTableReader`].
+pub struct CsvTableRow<'a> {
+  header: &'a [String],
+  record: &'a [String],
+  pub line: usize,
+}
+
+impl<'a> CsvTableRow<'a> {
+  /// Trimmed cell of a column, empty for a column the table does not have.
+  pub fn value(&self, column: &str) -> &'a str {
+    self
+      .header
+      .iter()
+      .position(|it| it == column)
+      .and_then(|position| self.record.get(position))
+      .map_or("", |it| it.trim())
+  }
+
+  /// # Errors
+  ///
+  /// Returns a parsing error for a cell that is not a number.
+  pub fn number<T: FromStr>(&self, column: &str) -> XrfResult<Option<T>> {
+    parse_csv_number(self.value(column), column, self.line)
+  }
+
+  /// # Errors
+  ///
+  /// Returns a parsing error for an empty cell or one that is not a number.
+  pub fn required_number<T: FromStr>(&self, column: &str) -> XrfResult<T> {
+    parse_required_csv_number(self.value(column), column, self.line)
+  }
+}
+
+/// Write a CSV table of the given columns, quoting any field that needs it.
+pub fn write_csv_table<R: IntoIterator<Item = Vec<String>>>(columns: &[&str], rows: R) -> String {
+  let mut output: String = columns.join(",");
+
+  output.push('\n');
+
+  for row in rows {
+    output.push_str(
+      &row
+        .iter()
+        .map(|field| escape_csv_field(field))
+        .collect::<Vec<String>>()
+        .join(","),
+    );
+    output.push('\n');
+  }
+
+  output
+}
+
+/// Parse optional number of a CSV cell, an empty cell being none.
+pub fn parse_csv_number<T: FromStr>(value: &str, column: &str, line: usize) -> XrfResult<Option<T>> {
+  if value.is_empty() {
+    return Ok(None);
+  }
+
+  value.parse::<T>().map(Some).map_err(|_| {
+    XrfError::new_parsing_error(format!(
+      "Expected a number in column '{column}' on line {line}, got '{value}'"
+    ))
+  })
+}
+
+/// Parse number of a CSV cell that cannot be left empty.
+pub fn parse_required_csv_number<T: FromStr>(value: &str, column: &str, line: usize) -> XrfResult<T> {
+  parse_csv_number(value, column, line)?
+    .ok_or_else(|| XrfError::new_parsing_error(format!("Expected a value in column '{column}' on line {line}")))
+}
+
+#[cfg(test)]
+mod tests {
+  use xrf_error::XrfResult;
+
+  use crate::csv_utils::{
+    CsvTableReader, escape_csv_field, parse_csv_numbered_records, parse_csv_records, write_csv_table,
+  };
+
+  #[test]
+  fn test_parse_csv_records() {
+    assert_eq!(
+      parse_csv_records("\u{feff}a,\"b, \"\"c\"\"\"\r\n\"multi\nline\",\n"),
+      vec![
+        vec![String::from("a"), String::from("b, \"c\"")],
+        vec![String::from("multi\nline"), String::new()],
+      ]
+    );
+    assert_eq!(
+      parse_csv_records("a,b"),
+      vec![vec![String::from("a"), String::from("b")]]
+    );
+    assert_eq!(
+      parse_csv_numbered_records("a\n\"multi\nline\"\nb")
+        .into_iter()
+        .map(|(line, _)| line)
+        .collect::<Vec<usize>>(),
+      vec![1, 2, 4]
+    );
+  }
+
+  #[test]
+  fn test_escape_csv_field() {
+    assert_eq!(escape_csv_field("plain\\path"), "plain\\path");
+    assert_eq!(escape_csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+    assert_eq!(
+      parse_csv_records(&escape_csv_field("a, \"b\"\nc")),
+      vec![vec![String::from("a, \"b\"\nc")]]
+    );
+  }
+
+  #[test]
+  fn test_csv_table_reader() -> XrfResult {
+    let table: CsvTableReader = CsvTableReader::parse(
+      " size , path\n\n4,\"a, b\"\n,c\n5,\"d\ne\"\n6,f\n",
+      "test table",
+      &["path"],
+    )?;
+    let rows: Vec<(usize, &str, Option<u32>)> = table
+      .rows()
+      .map(|row| Ok((row.line, row.value("path"), row.number("size")?)))
+      .collect::<XrfResult<_>>()?;
+
+    assert_eq!(
+      rows,
+      vec![
+        (3, "a, b", Some(4)),
+        (4, "c", None),
+        (5, "d\ne", Some(5)),
+        (7, "f", Some(6))
+      ]
+    );
+    assert!(
+      table
+        .rows()
+        .nth(1)
+        .is_some_and(|row| row.required_number::<u32>("size").is_err())
+    );
+    assert!(CsvTableReader::parse("size\n4\n", "test table", &["path"]).is_err());
+    assert!(CsvTableReader::parse("", "test table", &[]).is_err());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_write_csv_table() {
+    assert_eq!(
+      write_csv_table(&["path", "size"], [vec![String::from("a, b"), String::from("4")]]),
+      "path,size\n\"a, b\",4\n"
+    );
+  }
+}
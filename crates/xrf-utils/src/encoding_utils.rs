@@ -4,8 +4,8 @@ use std::io::{ErrorKind, Read};
 
 use base64::engine::{GeneralPurpose, general_purpose};
 use base64::{Engine, alphabet};
-use encoding_rs::{Encoding, UTF_8};
-use encoding_rs::{WINDOWS_1250, WINDOWS_1251, WINDOWS_1252};
+use encoding_rs::{Encoding, GBK, UTF_8};
+use encoding_rs::{WINDOWS_1250, WINDOWS_1251, WINDOWS_1252, WINDOWS_1254};
 use xrf_error::{XrfError, XrfResult};
 
 pub type XRayEncoding = &'static Encoding;
@@ -37,6 +37,18 @@ pub fn new_windows1252_encoder() -> XRayEncoding {
   WINDOWS_1252
 }
 
+/// Return encoding factory for windows1254.
+#[inline]
+pub fn new_windows1254_encoder() -> XRayEncoding {
+  WINDOWS_1254
+}
+
+/// Return encoding factory for GBK, the code page Chinese localisations of the engine ship in.
+#[inline]
+pub fn new_gbk_encoder() -> XRayEncoding {
+  GBK
+}
+
 /// Return encoding factory for UTF-8.
 #[inline]
 pub fn new_utf8_encoder() -> XRayEncoding {
@@ -1,15 +1,19 @@
 pub(crate) mod assertion_utils;
+pub(crate) mod csv_utils;
 pub(crate) mod duration_utils;
 pub(crate) mod encoding_utils;
 pub(crate) mod export_utils;
 pub(crate) mod path_utils;
 pub(crate) mod size_utils;
 pub(crate) mod string_utils;
+pub(crate) mod table_utils;
 
 pub use crate::assertion_utils::*;
+pub use crate::csv_utils::*;
 pub use crate::duration_utils::*;
 pub use crate::encoding_utils::*;
 pub use crate::export_utils::*;
 pub use crate::path_utils::*;
 pub use crate::size_utils::*;
 pub use crate::string_utils::*;
+pub use crate::table_utils::*;
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use xrf_error::{XrfError, XrfResult};
+
+/// Table of asset parameters exported to be edited in a spreadsheet and applied back.
+///
+/// Written as `.csv` or `.json`, told apart by the extension. Implementors only map their columns,
+/// reading and writing files is shared.
+pub trait ParametersTable: Sized + Serialize + DeserializeOwned {
+  /// Name of the table in error messages, `sound parameters table`.
+  const TABLE_NAME: &'static str;
+
+  /// # Errors
+  ///
+  /// Returns a parsing error for a missing column or a value that cannot be read.
+  fn from_csv(content: &str) -> XrfResult<Self>;
+
+  fn to_csv(&self) -> String;
+
+  /// Read a table written as `.csv` or `.json`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an IO error when the file cannot be read, an invalid error for any other extension, and
+  /// a parsing error for a table that cannot be read.
+  fn read_from_path<P: AsRef<Path>>(path: P) -> XrfResult<Self> {
+    let path: &Path = path.as_ref();
+    let is_json: bool = is_json_table_path(path, Self::TABLE_NAME)?;
+    let content: String = fs::read_to_string(path)?;
+
+    if is_json {
+      Self::from_json(&content)
+    } else {
+      Self::from_csv(&content)
+    }
+    .map_err(|error| XrfError::new_parsing_error(format!("Failed to read {}: {error}", path.display())))
+  }
+
+  /// Write the table as `.csv` or `.json`.
+  ///
+  /// # Errors
+  ///
+  /// Returns an IO error when the file cannot be written, and an invalid error for any other extension.
+  fn write_to_path<P: AsRef<Path>>(&self, path: P) -> XrfResult {
+    let path: &Path = path.as_ref();
+    let content: String = if is_json_table_path(path, Self::TABLE_NAME)? {
+      self.to_json()?
+    } else {
+      self.to_csv()
+    };
+
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, content)?;
+
+    Ok(())
+  }
+
+  /// # Errors
+  ///
+  /// Returns a parsing error for a document that is not this table.
+  fn from_json(content: &str) -> XrfResult<Self> {
+    serde_json::from_str(content)
+      .map_err(|error| XrfError::new_parsing_error(format!("Failed to parse {}: {error}", Self::TABLE_NAME)))
+  }
+
+  /// # Errors
+  ///
+  /// Returns a parsing error when the table cannot be serialized.
+  fn to_json(&self) -> XrfResult<String> {
+    serde_json::to_string_pretty(self)
+      .map_err(|error| XrfError::new_parsing_error(format!("Failed to serialize {}: {error}", Self::TABLE_NAME)))
+  }
+}
+
+fn is_json_table_path(path: &Path, table_name: &str) -> XrfResult<bool> {
+  match path.extension().and_then(|it| it.to_str()).map(str::to_ascii_lowercase) {
+    Some(extension) if extension == "json" => Ok(true),
+    Some(extension) if extension == "csv" => Ok(false),
+    _ => Err(XrfError::new_invalid_error(format!(
+      "Expected {table_name} {} to be a .csv or .json file",
+      path.display()
+    ))),
+  }
+}
@@ -1,7 +1,7 @@
 use xrf_error::{XrfError, XrfResult};
 use xrf_utils::{
-  XRayEncoding, decode_bytes_to_string, new_utf8_encoder, new_windows1250_encoder, new_windows1251_encoder,
-  new_windows1252_encoder,
+  XRayEncoding, decode_bytes_to_string, new_gbk_encoder, new_utf8_encoder, new_windows1250_encoder,
+  new_windows1251_encoder, new_windows1252_encoder, new_windows1254_encoder,
 };
 
 /// How far into a document the declaration is looked for.
@@ -37,6 +37,8 @@ pub fn encoding_from_label(label: &str) -> XrfResult<XRayEncoding> {
     "cp1250" | "windows1250" => Ok(new_windows1250_encoder()),
     "cp1251" | "windows1251" => Ok(new_windows1251_encoder()),
     "cp1252" | "windows1252" => Ok(new_windows1252_encoder()),
+    "cp1254" | "windows1254" => Ok(new_windows1254_encoder()),
+    "cp936" | "gbk" | "gb2312" => Ok(new_gbk_encoder()),
     _ => Err(XrfError::new_encoding_error(format!(
       "Unsupported XML encoding '{label}'"
     ))),